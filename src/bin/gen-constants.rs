@@ -0,0 +1,27 @@
+use {
+    halo2_pedersen_commitment::utils::fixed_bases::write_fixed_base_modules,
+    std::{env, path::PathBuf, process},
+};
+
+/**
+ * Regenerate the fixed base tables in constants::fixed_bases from hash to curve
+ * @dev each generator takes minutes to derive, run with --release. Same as pedersen gen-constants
+ *
+ * usage: gen-constants [OUT_DIR] [MODULE...]
+ *   OUT_DIR - directory the modules are written to, defaults to src/constants/fixed_bases
+ *   MODULE - modules to regenerate, defaults to all of FIXED_BASE_MODULES
+ */
+fn main() {
+    let mut args = env::args().skip(1);
+    let out_dir = args
+        .next()
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("src/constants/fixed_bases"));
+    let modules = args.collect::<Vec<_>>();
+    if let Err(e) =
+        write_fixed_base_modules(&out_dir, &modules, |name| eprintln!("generating {}", name))
+    {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
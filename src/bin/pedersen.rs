@@ -0,0 +1,295 @@
+use {
+    halo2_pedersen_commitment::{
+        prover::{
+            envelope::ProofEnvelope,
+            params_file::{ParamsFile, MAX_PARAMS_K},
+            prove_envelope, verify_envelope, CIRCUIT_ID, K,
+        },
+        utils::{
+            commit::{Opening, Trapdoor},
+            fixed_bases::write_fixed_base_modules,
+            from_hex, to_hex,
+        },
+    },
+    halo2_proofs::pasta::{group::ff::PrimeField, pallas},
+    rand::rngs::OsRng,
+    serde::Deserialize,
+    serde_json::json,
+    std::{
+        env,
+        fs::{self, File},
+        io::{self, BufReader, BufWriter, Read, Write},
+        path::PathBuf,
+        process,
+    },
+};
+
+const USAGE: &str = "usage: pedersen <COMMAND> [OPTIONS]
+
+commands:
+  commit [INPUT] [--out FILE]
+      commit to {\"value\", \"trapdoor\"?}, a random trapdoor is sampled if omitted
+  keygen --params FILE [--k K]
+      write a params file, k defaults to 11. the keys are derived from it on every load
+  prove --params FILE [INPUT] [--out FILE]
+      prove knowledge of the opening {\"value\", \"trapdoor\"?} and output a proof envelope
+  verify --params FILE [INPUT]
+      verify a proof envelope against the commitment it carries
+  gen-constants [OUT_DIR] [MODULE...]
+      regenerate the fixed base tables, defaults to src/constants/fixed_bases and all modules
+
+INPUT is a json file, read from stdin if omitted or -. Output is json written to stdout unless
+--out is given. values are decimal integers or 32 byte little endian hex strings.";
+
+// the opening read by commit and prove
+#[derive(Deserialize)]
+struct OpeningInput {
+    value: ValueInput,
+    trapdoor: Option<Trapdoor>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ValueInput {
+    Integer(u64),
+    Hex(String),
+}
+
+impl OpeningInput {
+    fn opening(self) -> Result<Opening, String> {
+        let value = match self.value {
+            ValueInput::Integer(value) => pallas::Base::from(value),
+            ValueInput::Hex(hex) => from_hex(&hex)
+                .and_then(|bytes| bytes.try_into().ok())
+                .and_then(|bytes| Option::from(pallas::Base::from_repr(bytes)))
+                .ok_or_else(|| format!("invalid value {:?}", hex))?,
+        };
+        let trapdoor = self.trapdoor.unwrap_or_else(|| Trapdoor::random(OsRng));
+        Ok(Opening::new(value, trapdoor))
+    }
+}
+
+// positional arguments and --flag value options of a subcommand
+struct Args {
+    positional: Vec<String>,
+    options: Vec<(String, String)>,
+}
+
+impl Args {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut parsed = Args {
+            positional: vec![],
+            options: vec![],
+        };
+        while let Some(arg) = args.next() {
+            match arg.strip_prefix("--") {
+                Some(name) => {
+                    let value = args
+                        .next()
+                        .ok_or_else(|| format!("missing value for --{}", name))?;
+                    parsed.options.push((name.to_string(), value));
+                }
+                None => parsed.positional.push(arg),
+            }
+        }
+        Ok(parsed)
+    }
+
+    fn option(&self, name: &str) -> Option<&str> {
+        self.options
+            .iter()
+            .find(|(option, _)| option == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn required(&self, name: &str) -> Result<&str, String> {
+        self.option(name)
+            .ok_or_else(|| format!("missing required option --{}", name))
+    }
+
+    // read the json input from the first positional argument, or stdin if omitted or -
+    fn input(&self) -> Result<String, String> {
+        let mut input = String::new();
+        match self.positional.first().map(String::as_str) {
+            None | Some("-") => io::stdin().read_to_string(&mut input),
+            Some(path) => File::open(path).and_then(|mut file| file.read_to_string(&mut input)),
+        }
+        .map_err(|e| format!("failed to read input: {}", e))?;
+        Ok(input)
+    }
+
+    // write json output to --out, or stdout if omitted
+    fn output(&self, value: &serde_json::Value) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(value).unwrap();
+        match self.option("out") {
+            Some(path) => fs::write(path, json + "\n"),
+            None => writeln!(io::stdout(), "{}", json),
+        }
+        .map_err(|e| format!("failed to write output: {}", e))
+    }
+}
+
+// read a params file and derive the keys from it
+fn read_params(path: &str) -> Result<ParamsFile, String> {
+    File::open(path)
+        .map_err(|e| e.into())
+        .and_then(|file| ParamsFile::read(&mut BufReader::new(file)))
+        .map_err(|e| format!("failed to read params {}: {}", path, e))
+}
+
+fn read_opening(args: &Args) -> Result<Opening, String> {
+    serde_json::from_str::<OpeningInput>(&args.input()?)
+        .map_err(|e| format!("invalid opening: {}", e))?
+        .opening()
+}
+
+fn commit(args: Args) -> Result<(), String> {
+    let opening = read_opening(&args)?;
+    let commitment = opening.commit();
+    let (x, y) = commitment
+        .coordinates()
+        .ok_or("opening commits to the identity point, which cannot be proven")?;
+    args.output(&json!({
+        "commitment": commitment,
+        "x": to_hex(&x.to_repr()),
+        "y": to_hex(&y.to_repr()),
+        "opening": opening,
+    }))
+}
+
+fn keygen(args: Args) -> Result<(), String> {
+    let path = args.required("params")?;
+    let k = match args.option("k") {
+        Some(k) => k.parse().map_err(|_| format!("invalid k {:?}", k))?,
+        None => K,
+    };
+    if k > MAX_PARAMS_K {
+        return Err(format!("k must be at most {}", MAX_PARAMS_K));
+    }
+    let file = ParamsFile::generate(k).map_err(|e| e.to_string())?;
+    let mut writer = BufWriter::new(
+        File::create(path).map_err(|e| format!("failed to create {}: {}", path, e))?,
+    );
+    file.write(&mut writer)
+        .and_then(|_| writer.flush())
+        .map_err(|e| format!("failed to write params {}: {}", path, e))?;
+    writeln!(io::stdout(), "{}", json!({ "k": k, "params": path }))
+        .map_err(|e| format!("failed to write output: {}", e))
+}
+
+fn prove(args: Args) -> Result<(), String> {
+    let file = read_params(args.required("params")?)?;
+    let opening = read_opening(&args)?;
+    let envelope = prove_envelope(
+        &file.params,
+        &file.pk,
+        opening.value,
+        opening.trapdoor.inner(),
+        OsRng,
+    )
+    .map_err(|e| e.to_string())?;
+    args.output(&serde_json::to_value(&envelope).unwrap())
+}
+
+fn verify(args: Args) -> Result<(), String> {
+    let file = read_params(args.required("params")?)?;
+    let envelope =
+        ProofEnvelope::from_json(&args.input()?, CIRCUIT_ID).map_err(|e| e.to_string())?;
+    verify_envelope(&file.params, &file.vk, &envelope).map_err(|e| e.to_string())?;
+    args.output(&json!({ "valid": true }))
+}
+
+// regenerate the fixed base tables in constants::fixed_bases from hash to curve
+fn gen_constants(args: Args) -> Result<(), String> {
+    let mut positional = args.positional.into_iter();
+    let out_dir = positional
+        .next()
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("src/constants/fixed_bases"));
+    let modules = positional.collect::<Vec<_>>();
+    write_fixed_base_modules(&out_dir, &modules, |name| eprintln!("generating {}", name))
+        .map_err(|e| e.to_string())
+}
+
+/**
+ * Debug pedersen commitments and proofs of opening from the command line
+ * @dev gen-constants takes minutes per generator, run with --release
+ */
+fn main() {
+    let mut args = env::args().skip(1);
+    let command = args.next();
+    let result = Args::parse(args).and_then(|args| match command.as_deref() {
+        Some("commit") => commit(args),
+        Some("keygen") => keygen(args),
+        Some("prove") => prove(args),
+        Some("verify") => verify(args),
+        Some("gen-constants") => gen_constants(args),
+        _ => Err(USAGE.to_string()),
+    });
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, serde_json::Value, std::path::Path};
+
+    fn args(positional: &[&Path], options: &[(&str, &Path)]) -> Args {
+        Args {
+            positional: positional
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect(),
+            options: options
+                .iter()
+                .map(|(name, path)| (name.to_string(), path.display().to_string()))
+                .collect(),
+        }
+    }
+
+    fn read_json(path: &Path) -> Value {
+        serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn commit_prove_verify() {
+        let dir = env::temp_dir().join(format!("pedersen-cli-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let [opening, commitment, params, proof, verified] = [
+            "opening.json",
+            "commitment.json",
+            "params.bin",
+            "proof.json",
+            "verified.json",
+        ]
+        .map(|name| dir.join(name));
+        fs::write(&opening, r#"{ "value": 88675409 }"#).unwrap();
+        // commit samples a trapdoor and outputs the opening it used
+        commit(args(&[&opening], &[("out", &commitment)])).unwrap();
+        let committed = read_json(&commitment);
+        fs::write(&opening, committed["opening"].to_string()).unwrap();
+        // prove the opening under fresh params & verify the envelope
+        keygen(args(&[], &[("params", &params)])).unwrap();
+        prove(args(&[&opening], &[("params", &params), ("out", &proof)])).unwrap();
+        let envelope = read_json(&proof);
+        let instance = [committed["x"].clone(), committed["y"].clone()];
+        assert_eq!(envelope["instance"], Value::from(instance.to_vec()));
+        verify(args(&[&proof], &[("params", &params), ("out", &verified)])).unwrap();
+        assert_eq!(read_json(&verified)["valid"], true);
+        // a proof for another commitment does not verify
+        fs::write(&opening, r#"{ "value": 1 }"#).unwrap();
+        prove(args(&[&opening], &[("params", &params), ("out", &proof)])).unwrap();
+        let mut envelope = read_json(&proof);
+        envelope["instance"] = Value::from(instance.to_vec());
+        fs::write(&proof, envelope.to_string()).unwrap();
+        assert!(verify(args(&[&proof], &[("params", &params)])).is_err());
+        // the zero opening commits to the identity and is reported rather than panicking
+        let zero = format!(r#"{{ "value": 0, "trapdoor": "{}" }}"#, to_hex(&[0; 32]));
+        fs::write(&opening, zero).unwrap();
+        assert!(commit(args(&[&opening], &[])).is_err());
+        assert!(prove(args(&[&opening], &[("params", &params)])).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
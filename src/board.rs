@@ -0,0 +1,243 @@
+//! BattleZips boards: five ships placed on a 10x10 grid, packed into the base field element
+//! bound by BoardCommitV.
+
+pub mod chip;
+
+use {
+    crate::utils::commit::{Opening, Trapdoor},
+    halo2_proofs::pasta::{group::ff::PrimeField, pallas},
+    std::fmt,
+};
+
+/// Width and height of the board
+pub const BOARD_SIZE: u8 = 10;
+
+/// Lengths of the carrier, battleship, cruiser, submarine and destroyer
+pub const SHIP_LENGTHS: [u8; 5] = [5, 4, 3, 3, 2];
+
+/// Bits used by one packed ship, 4 for x, 4 for y and 1 for the orientation
+pub const SHIP_BITS: usize = 9;
+
+/// Errors returned when placing ships on a board
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BoardError {
+    /// the ship at this index extends past the edge of the board
+    OutOfBounds(usize),
+    /// the ships at these indices share a cell
+    Overlap(usize, usize),
+}
+
+impl fmt::Display for BoardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BoardError::OutOfBounds(ship) => write!(f, "ship {} is out of bounds", ship),
+            BoardError::Overlap(first, second) => {
+                write!(f, "ships {} and {} overlap", first, second)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BoardError {}
+
+/// Placement of a ship by its top left cell, extending right or down when vertical
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Ship {
+    pub x: u8,
+    pub y: u8,
+    pub vertical: bool,
+}
+
+impl Ship {
+    pub fn new(x: u8, y: u8, vertical: bool) -> Self {
+        Ship { x, y, vertical }
+    }
+
+    /// Returns the (x, y) cells covered by a ship of the given length
+    pub fn cells(&self, length: u8) -> impl Iterator<Item = (u8, u8)> {
+        let ship = *self;
+        (0..length).map(move |i| match ship.vertical {
+            true => (ship.x, ship.y + i),
+            false => (ship.x + i, ship.y),
+        })
+    }
+
+    /// Returns the ship packed as x + 16y + 256z, with z set when vertical
+    pub fn packed(&self) -> u64 {
+        self.x as u64 + ((self.y as u64) << 4) + ((self.vertical as u64) << 8)
+    }
+
+    // whether a ship of the given length lies entirely on the board
+    fn in_bounds(&self, length: u8) -> bool {
+        let (along, across) = match self.vertical {
+            true => (self.y, self.x),
+            false => (self.x, self.y),
+        };
+        along as u16 + length as u16 <= BOARD_SIZE as u16 && across < BOARD_SIZE
+    }
+}
+
+/**
+ * Pack ship placements into a base field element without checking them
+ * @dev ship i occupies bits [9i, 9i + 9), so packed = sum(ships[i].packed() * 2^(9i))
+ *
+ * @param ships - placements in the order of SHIP_LENGTHS
+ * @return - the packed placements
+ */
+pub fn pack_ships(ships: &[Ship; 5]) -> pallas::Base {
+    let packed = ships.iter().enumerate().fold(0u64, |packed, (i, ship)| {
+        packed | ship.packed() << (SHIP_BITS * i)
+    });
+    pallas::Base::from(packed)
+}
+
+/// A valid board, ships in bounds and not overlapping
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Board {
+    ships: [Ship; 5],
+}
+
+impl Board {
+    /**
+     * Place the fleet on a board
+     *
+     * @param ships - placements in the order of SHIP_LENGTHS
+     * @return - the board, or the first rule the placements break
+     */
+    pub fn new(ships: [Ship; 5]) -> Result<Self, BoardError> {
+        for (i, ship) in ships.iter().enumerate() {
+            if !ship.in_bounds(SHIP_LENGTHS[i]) {
+                return Err(BoardError::OutOfBounds(i));
+            }
+        }
+        for first in 0..ships.len() {
+            for second in first + 1..ships.len() {
+                let mut cells = ships[first].cells(SHIP_LENGTHS[first]);
+                if cells.any(|cell| ships[second].cells(SHIP_LENGTHS[second]).any(|c| c == cell)) {
+                    return Err(BoardError::Overlap(first, second));
+                }
+            }
+        }
+        Ok(Board { ships })
+    }
+
+    /// Returns the ship placements in the order of SHIP_LENGTHS
+    pub fn ships(&self) -> &[Ship; 5] {
+        &self.ships
+    }
+
+    /// Returns the canonical packing of the board committed to with BoardCommitV
+    pub fn pack(&self) -> pallas::Base {
+        pack_ships(&self.ships)
+    }
+
+    /**
+     * Parse a packed board
+     *
+     * @param packed - a base field element produced by Board::pack
+     * @return - the board, or None if the element is not the packing of a valid board
+     */
+    pub fn unpack(packed: &pallas::Base) -> Option<Self> {
+        let repr = packed.to_repr();
+        if repr[8..].iter().any(|byte| *byte != 0) {
+            return None;
+        }
+        let packed = u64::from_le_bytes(repr[..8].try_into().unwrap());
+        if packed >> (SHIP_BITS * 5) != 0 {
+            return None;
+        }
+        let ships = [0, 1, 2, 3, 4].map(|i| {
+            let ship = packed >> (SHIP_BITS * i);
+            Ship::new(
+                (ship & 0xf) as u8,
+                (ship >> 4 & 0xf) as u8,
+                ship >> 8 & 1 == 1,
+            )
+        });
+        Board::new(ships).ok()
+    }
+
+    /**
+     * Check whether a shot lands on a ship
+     *
+     * @param x - the column of the shot
+     * @param y - the row of the shot
+     * @return - true if a ship covers (x, y)
+     */
+    pub fn is_hit(&self, x: u8, y: u8) -> bool {
+        self.ships
+            .iter()
+            .zip(SHIP_LENGTHS)
+            .any(|(ship, length)| ship.cells(length).any(|cell| cell == (x, y)))
+    }
+
+    /**
+     * Commit to the board
+     *
+     * @param trapdoor - the blinding factor for the commitment
+     * @return - the opening of the board commitment
+     */
+    pub fn commit(&self, trapdoor: Trapdoor) -> Opening {
+        Opening::new(self.pack(), trapdoor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example_board() -> Board {
+        Board::new([
+            Ship::new(3, 3, false),
+            Ship::new(5, 4, true),
+            Ship::new(0, 0, false),
+            Ship::new(0, 1, false),
+            Ship::new(6, 1, true),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn placement_rules() {
+        let mut ships = *example_board().ships();
+        // the carrier runs off the right edge
+        ships[0] = Ship::new(6, 3, false);
+        assert_eq!(Board::new(ships), Err(BoardError::OutOfBounds(0)));
+        // the destroyer runs off the bottom edge
+        ships[0] = Ship::new(3, 3, false);
+        ships[4] = Ship::new(6, 9, true);
+        assert_eq!(Board::new(ships), Err(BoardError::OutOfBounds(4)));
+        // the battleship crosses the carrier at (5, 3)
+        ships[4] = Ship::new(6, 1, true);
+        ships[1] = Ship::new(5, 2, true);
+        assert_eq!(Board::new(ships), Err(BoardError::Overlap(0, 1)));
+    }
+
+    #[test]
+    fn shots() {
+        let board = example_board();
+        // the ends of the carrier and a cell beside it
+        assert!(board.is_hit(3, 3));
+        assert!(board.is_hit(7, 3));
+        assert!(!board.is_hit(8, 3));
+        // every ship cell is a hit and the rest of the grid is a miss
+        let hits = (0..BOARD_SIZE)
+            .flat_map(|x| (0..BOARD_SIZE).map(move |y| (x, y)))
+            .filter(|(x, y)| board.is_hit(*x, *y))
+            .count();
+        assert_eq!(hits, SHIP_LENGTHS.iter().sum::<u8>() as usize);
+    }
+
+    #[test]
+    fn pack_round_trip() {
+        let board = example_board();
+        assert_eq!(Board::unpack(&board.pack()), Some(board));
+        // packings of invalid boards and elements with high bits are rejected
+        let mut ships = *board.ships();
+        ships[0] = Ship::new(6, 3, false);
+        assert_eq!(Board::unpack(&pack_ships(&ships)), None);
+        let high = board.pack() + pallas::Base::from(1 << 45);
+        assert_eq!(Board::unpack(&high), None);
+        assert_eq!(Board::unpack(&-board.pack()), None);
+    }
+}
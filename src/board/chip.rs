@@ -0,0 +1,528 @@
+use {
+    super::{Ship, BOARD_SIZE, SHIP_BITS, SHIP_LENGTHS},
+    crate::constants::LOOKUP_SIZE,
+    halo2_gadgets::utilities::{bool_check, lookup_range_check::LookupRangeCheckConfig},
+    halo2_proofs::{
+        arithmetic::Field,
+        circuit::{AssignedCell, Chip, Layouter, Value},
+        pasta::pallas,
+        plonk::{Advice, Column, ConstraintSystem, Constraints, Error, Expression, Selector},
+        poly::Rotation,
+    },
+};
+
+/// Bits needed to range check a coordinate or the room left between a ship and the board edge
+const COORDINATE_BITS: usize = 4;
+
+#[derive(Clone, Debug)]
+pub struct BoardConfig {
+    pub advices: [Column<Advice>; 10],
+    q_ship: Selector,
+    q_cell: Selector,
+    q_pack: Selector,
+    q_distinct: Selector,
+    q_shot: Selector,
+    q_product: Selector,
+    q_hit: Selector,
+    range_check: LookupRangeCheckConfig<pallas::Base, LOOKUP_SIZE>,
+}
+
+/// A ship placement assigned in the circuit, along with the grid index x + 10y of each cell
+#[derive(Clone, Debug)]
+pub struct AssignedShip {
+    pub x: AssignedCell<pallas::Base, pallas::Base>,
+    pub y: AssignedCell<pallas::Base, pallas::Base>,
+    pub z: AssignedCell<pallas::Base, pallas::Base>,
+    pub cells: Vec<AssignedCell<pallas::Base, pallas::Base>>,
+}
+
+/// A shot assigned in the circuit, along with its grid index x + 10y
+#[derive(Clone, Debug)]
+pub struct AssignedShot {
+    pub x: AssignedCell<pallas::Base, pallas::Base>,
+    pub y: AssignedCell<pallas::Base, pallas::Base>,
+    pub index: AssignedCell<pallas::Base, pallas::Base>,
+}
+
+/// Constrains ship placements to a valid board, packs them as the committed board value and
+/// checks shots against them.
+/// Shares its advice columns and lookup range check with the pedersen commitment chip.
+#[derive(Clone, Debug)]
+pub struct BoardChip {
+    config: BoardConfig,
+}
+
+impl Chip<pallas::Base> for BoardChip {
+    type Config = BoardConfig;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+impl BoardChip {
+    pub fn construct(config: BoardConfig) -> Self {
+        BoardChip { config }
+    }
+
+    /**
+     * Configure the board gates
+     * @dev the lookup table of the range check must be loaded by the circuit
+     *
+     * @param advices - equality enabled advice columns, usually the ecc chip's
+     * @param range_check - the lookup range check used to bound coordinates
+     * @return - the board configuration
+     */
+    pub fn configure(
+        meta: &mut ConstraintSystem<pallas::Base>,
+        advices: [Column<Advice>; 10],
+        range_check: LookupRangeCheckConfig<pallas::Base, LOOKUP_SIZE>,
+    ) -> BoardConfig {
+        let constant = |value: u64| Expression::Constant(pallas::Base::from(value));
+        // the head of a ship, its orientation and the cells it covers
+        // | x | y | z | length | along | room | across | across_room | cell | step |
+        let q_ship = meta.selector();
+        meta.create_gate("ship placement", |meta| {
+            let q_ship = meta.query_selector(q_ship);
+            let [x, y, z, length, along, room, across, across_room, cell, step] =
+                advices.map(|column| meta.query_advice(column, Rotation::cur()));
+            Constraints::with_selector(
+                q_ship,
+                [
+                    ("z is boolean", bool_check(z.clone())),
+                    (
+                        "along = z ? y : x",
+                        along.clone() - x.clone() - z.clone() * (y.clone() - x.clone()),
+                    ),
+                    (
+                        "across = z ? x : y",
+                        across.clone() - x.clone() - y.clone() + along.clone(),
+                    ),
+                    (
+                        "room = size - length - along",
+                        room - constant(BOARD_SIZE as u64) + length + along,
+                    ),
+                    (
+                        "across_room = size - 1 - across",
+                        across_room - constant(BOARD_SIZE as u64 - 1) + across,
+                    ),
+                    ("head cell", cell - x - y * constant(BOARD_SIZE as u64)),
+                    (
+                        "step = z ? size : 1",
+                        step - constant(1) - z * constant(BOARD_SIZE as u64 - 1),
+                    ),
+                ],
+            )
+        });
+        // each following cell is one step further along the ship
+        let q_cell = meta.selector();
+        meta.create_gate("ship cells", |meta| {
+            let q_cell = meta.query_selector(q_cell);
+            let cell = meta.query_advice(advices[8], Rotation::cur());
+            let next_cell = meta.query_advice(advices[8], Rotation::next());
+            let step = meta.query_advice(advices[9], Rotation::cur());
+            let next_step = meta.query_advice(advices[9], Rotation::next());
+            Constraints::with_selector(
+                q_cell,
+                [
+                    ("next cell", next_cell - cell - step.clone()),
+                    ("same step", next_step - step),
+                ],
+            )
+        });
+        // horner step packing one ship per row, acc' = acc * 2^9 + x + 16y + 256z
+        let q_pack = meta.selector();
+        meta.create_gate("pack ships", |meta| {
+            let q_pack = meta.query_selector(q_pack);
+            let acc = meta.query_advice(advices[0], Rotation::cur());
+            let next_acc = meta.query_advice(advices[0], Rotation::next());
+            let x = meta.query_advice(advices[1], Rotation::cur());
+            let y = meta.query_advice(advices[2], Rotation::cur());
+            let z = meta.query_advice(advices[3], Rotation::cur());
+            let ship = x + y * constant(1 << 4) + z * constant(1 << 8);
+            Constraints::with_selector(q_pack, [next_acc - acc * constant(1 << SHIP_BITS) - ship])
+        });
+        // two cells differ when their difference has an inverse
+        let q_distinct = meta.selector();
+        meta.create_gate("distinct cells", |meta| {
+            let q_distinct = meta.query_selector(q_distinct);
+            let first = meta.query_advice(advices[0], Rotation::cur());
+            let second = meta.query_advice(advices[1], Rotation::cur());
+            let inverse = meta.query_advice(advices[2], Rotation::cur());
+            Constraints::with_selector(q_distinct, [(first - second) * inverse - constant(1)])
+        });
+        // the grid index of a shot and the room left before the right and bottom edges
+        // | x | y | index | x_room | y_room |
+        let q_shot = meta.selector();
+        meta.create_gate("shot", |meta| {
+            let q_shot = meta.query_selector(q_shot);
+            let [x, y, index, x_room, y_room] =
+                [0, 1, 2, 3, 4].map(|column| meta.query_advice(advices[column], Rotation::cur()));
+            let last = constant(BOARD_SIZE as u64 - 1);
+            Constraints::with_selector(
+                q_shot,
+                [
+                    (
+                        "index",
+                        index - x.clone() - y.clone() * constant(BOARD_SIZE as u64),
+                    ),
+                    ("x_room = size - 1 - x", x_room - last.clone() + x),
+                    ("y_room = size - 1 - y", y_room - last + y),
+                ],
+            )
+        });
+        // running product of (shot - cell) over every ship cell, zero exactly on a hit
+        let q_product = meta.selector();
+        meta.create_gate("miss product", |meta| {
+            let q_product = meta.query_selector(q_product);
+            let cell = meta.query_advice(advices[0], Rotation::cur());
+            let shot = meta.query_advice(advices[1], Rotation::cur());
+            let product = meta.query_advice(advices[2], Rotation::cur());
+            let next_product = meta.query_advice(advices[2], Rotation::next());
+            Constraints::with_selector(q_product, [next_product - product * (shot - cell)])
+        });
+        // hit is set when the product is zero and unset when it has an inverse
+        let q_hit = meta.selector();
+        meta.create_gate("hit", |meta| {
+            let q_hit = meta.query_selector(q_hit);
+            let product = meta.query_advice(advices[2], Rotation::cur());
+            let hit = meta.query_advice(advices[3], Rotation::cur());
+            let inverse = meta.query_advice(advices[4], Rotation::cur());
+            Constraints::with_selector(
+                q_hit,
+                [
+                    ("hit is boolean", bool_check(hit.clone())),
+                    ("hit cell", hit.clone() * product.clone()),
+                    (
+                        "missed cell",
+                        (constant(1) - hit) * (product * inverse - constant(1)),
+                    ),
+                ],
+            )
+        });
+        BoardConfig {
+            advices,
+            q_ship,
+            q_cell,
+            q_pack,
+            q_distinct,
+            q_shot,
+            q_product,
+            q_hit,
+            range_check,
+        }
+    }
+
+    /**
+     * Assign a ship and constrain it to lie on the board
+     *
+     * @param ship - the placement of the ship
+     * @param length - the length of the ship
+     * @return - the assigned placement and the grid indices of its cells
+     */
+    pub fn assign_ship(
+        &self,
+        mut layouter: impl Layouter<pallas::Base>,
+        ship: Value<Ship>,
+        length: u8,
+    ) -> Result<AssignedShip, Error> {
+        let config = &self.config;
+        let [x_col, y_col, z_col, length_col, along_col, room_col, ..] = config.advices;
+        let [.., across_col, across_room_col, cell_col, step_col] = config.advices;
+        let base = |value: u64| pallas::Base::from(value);
+        let (ship, along, room, across, across_room) = layouter.assign_region(
+            || "ship",
+            |mut region| {
+                config.q_ship.enable(&mut region, 0)?;
+                let x =
+                    region.assign_advice(|| "x", x_col, 0, || ship.map(|s| base(s.x as u64)))?;
+                let y =
+                    region.assign_advice(|| "y", y_col, 0, || ship.map(|s| base(s.y as u64)))?;
+                let z = region.assign_advice(
+                    || "z",
+                    z_col,
+                    0,
+                    || ship.map(|s| base(s.vertical as u64)),
+                )?;
+                region.assign_advice_from_constant(
+                    || "length",
+                    length_col,
+                    0,
+                    base(length as u64),
+                )?;
+                // coordinate along the ship and across it
+                let (along, across) = ship
+                    .map(|s| match s.vertical {
+                        true => (base(s.y as u64), base(s.x as u64)),
+                        false => (base(s.x as u64), base(s.y as u64)),
+                    })
+                    .unzip();
+                let room = along.map(|along| base((BOARD_SIZE - length) as u64) - along);
+                let across_room = across.map(|across| base(BOARD_SIZE as u64 - 1) - across);
+                let along = region.assign_advice(|| "along", along_col, 0, || along)?;
+                let room = region.assign_advice(|| "room", room_col, 0, || room)?;
+                let across = region.assign_advice(|| "across", across_col, 0, || across)?;
+                let across_room =
+                    region.assign_advice(|| "across room", across_room_col, 0, || across_room)?;
+                // walk the cells of the ship
+                let step = ship.map(|s| base(if s.vertical { BOARD_SIZE as u64 } else { 1 }));
+                let cells = (0..length as usize)
+                    .map(|i| {
+                        if i + 1 < length as usize {
+                            config.q_cell.enable(&mut region, i)?;
+                        }
+                        region.assign_advice(|| "step", step_col, i, || step)?;
+                        let cell = ship.map(|s| {
+                            let (x, y) = s.cells(length).nth(i).unwrap();
+                            base(x as u64 + y as u64 * BOARD_SIZE as u64)
+                        });
+                        region.assign_advice(|| "cell", cell_col, i, || cell)
+                    })
+                    .collect::<Result<Vec<_>, Error>>()?;
+                let ship = AssignedShip { x, y, z, cells };
+                Ok((ship, along, room, across, across_room))
+            },
+        )?;
+        // along, across and the room left before each edge are non-negative
+        for (name, value) in [
+            ("along", along),
+            ("room", room),
+            ("across", across),
+            ("across room", across_room),
+        ] {
+            config.range_check.copy_short_check(
+                layouter.namespace(|| name),
+                value,
+                COORDINATE_BITS,
+            )?;
+        }
+        Ok(ship)
+    }
+
+    /**
+     * Assign the fleet in the order of SHIP_LENGTHS
+     *
+     * @param ships - the placements of the ships
+     * @return - the assigned ships
+     */
+    pub fn assign_ships(
+        &self,
+        mut layouter: impl Layouter<pallas::Base>,
+        ships: Value<[Ship; 5]>,
+    ) -> Result<Vec<AssignedShip>, Error> {
+        SHIP_LENGTHS
+            .iter()
+            .enumerate()
+            .map(|(i, length)| {
+                self.assign_ship(
+                    layouter.namespace(|| format!("ship {}", i)),
+                    ships.map(|ships| ships[i]),
+                    *length,
+                )
+            })
+            .collect()
+    }
+
+    /**
+     * Constrain no two ships to share a cell
+     *
+     * @param ships - the assigned fleet
+     */
+    pub fn assert_disjoint(
+        &self,
+        mut layouter: impl Layouter<pallas::Base>,
+        ships: &[AssignedShip],
+    ) -> Result<(), Error> {
+        let config = &self.config;
+        // every pair of cells from different ships
+        let pairs = ships
+            .iter()
+            .enumerate()
+            .flat_map(|(i, first)| {
+                ships[i + 1..].iter().flat_map(move |second| {
+                    first
+                        .cells
+                        .iter()
+                        .flat_map(move |a| second.cells.iter().map(move |b| (a, b)))
+                })
+            })
+            .collect::<Vec<_>>();
+        layouter.assign_region(
+            || "disjoint ships",
+            |mut region| {
+                for (row, (first, second)) in pairs.iter().enumerate() {
+                    config.q_distinct.enable(&mut region, row)?;
+                    first.copy_advice(|| "first", &mut region, config.advices[0], row)?;
+                    second.copy_advice(|| "second", &mut region, config.advices[1], row)?;
+                    let inverse = first
+                        .value()
+                        .zip(second.value())
+                        .map(|(a, b)| (*a - *b).invert().unwrap_or(pallas::Base::zero()));
+                    region.assign_advice(|| "inverse", config.advices[2], row, || inverse)?;
+                }
+                Ok(())
+            },
+        )
+    }
+
+    /**
+     * Pack the fleet into the committed board value, matching Board::pack
+     *
+     * @param ships - the assigned fleet in the order of SHIP_LENGTHS
+     * @return - the packed board
+     */
+    pub fn pack(
+        &self,
+        mut layouter: impl Layouter<pallas::Base>,
+        ships: &[AssignedShip],
+    ) -> Result<AssignedCell<pallas::Base, pallas::Base>, Error> {
+        let config = &self.config;
+        let [acc_col, x_col, y_col, z_col, ..] = config.advices;
+        layouter.assign_region(
+            || "pack ships",
+            |mut region| {
+                let mut acc = region.assign_advice_from_constant(
+                    || "empty",
+                    acc_col,
+                    0,
+                    pallas::Base::zero(),
+                )?;
+                // the last ship is absorbed first so ship i ends up multiplied by 2^(9i)
+                for (row, ship) in ships.iter().rev().enumerate() {
+                    config.q_pack.enable(&mut region, row)?;
+                    ship.x.copy_advice(|| "x", &mut region, x_col, row)?;
+                    ship.y.copy_advice(|| "y", &mut region, y_col, row)?;
+                    ship.z.copy_advice(|| "z", &mut region, z_col, row)?;
+                    let packed = acc
+                        .value()
+                        .zip(ship.x.value())
+                        .zip(ship.y.value())
+                        .zip(ship.z.value())
+                        .map(|(((acc, x), y), z)| {
+                            *acc * pallas::Base::from(1 << SHIP_BITS)
+                                + x
+                                + *y * pallas::Base::from(1 << 4)
+                                + *z * pallas::Base::from(1 << 8)
+                        });
+                    acc = region.assign_advice(|| "acc", acc_col, row + 1, || packed)?;
+                }
+                Ok(acc)
+            },
+        )
+    }
+
+    /**
+     * Assign a shot and constrain it to lie on the board
+     *
+     * @param shot - the (x, y) coordinate of the shot
+     * @return - the assigned x, y and grid index x + 10y of the shot
+     */
+    pub fn assign_shot(
+        &self,
+        mut layouter: impl Layouter<pallas::Base>,
+        shot: Value<(u8, u8)>,
+    ) -> Result<AssignedShot, Error> {
+        let config = &self.config;
+        let [x_col, y_col, index_col, x_room_col, y_room_col, ..] = config.advices;
+        let last = pallas::Base::from(BOARD_SIZE as u64 - 1);
+        let (x, y) = shot
+            .map(|(x, y)| (pallas::Base::from(x as u64), pallas::Base::from(y as u64)))
+            .unzip();
+        let (shot, x_room, y_room) = layouter.assign_region(
+            || "shot",
+            |mut region| {
+                config.q_shot.enable(&mut region, 0)?;
+                let index = x
+                    .zip(y)
+                    .map(|(x, y)| x + y * pallas::Base::from(BOARD_SIZE as u64));
+                let x_room =
+                    region.assign_advice(|| "x room", x_room_col, 0, || x.map(|x| last - x))?;
+                let y_room =
+                    region.assign_advice(|| "y room", y_room_col, 0, || y.map(|y| last - y))?;
+                let shot = AssignedShot {
+                    x: region.assign_advice(|| "x", x_col, 0, || x)?,
+                    y: region.assign_advice(|| "y", y_col, 0, || y)?,
+                    index: region.assign_advice(|| "index", index_col, 0, || index)?,
+                };
+                Ok((shot, x_room, y_room))
+            },
+        )?;
+        // both coordinates and the room left before each edge are non-negative
+        for (name, value) in [
+            ("x", shot.x.clone()),
+            ("x room", x_room),
+            ("y", shot.y.clone()),
+            ("y room", y_room),
+        ] {
+            config.range_check.copy_short_check(
+                layouter.namespace(|| name),
+                value,
+                COORDINATE_BITS,
+            )?;
+        }
+        Ok(shot)
+    }
+
+    /**
+     * Constrain whether a shot lands on a ship cell
+     *
+     * @param ships - the assigned fleet
+     * @param shot - the assigned shot
+     * @param hit - whether the shot hits a ship
+     * @return - the assigned hit bit, 1 on a hit and 0 on a miss
+     */
+    pub fn assign_hit(
+        &self,
+        mut layouter: impl Layouter<pallas::Base>,
+        ships: &[AssignedShip],
+        shot: &AssignedShot,
+        hit: Value<bool>,
+    ) -> Result<AssignedCell<pallas::Base, pallas::Base>, Error> {
+        let config = &self.config;
+        let [cell_col, shot_col, product_col, hit_col, inverse_col, ..] = config.advices;
+        let cells = ships
+            .iter()
+            .flat_map(|ship| ship.cells.iter())
+            .collect::<Vec<_>>();
+        layouter.assign_region(
+            || "hit",
+            |mut region| {
+                let mut product = region.assign_advice_from_constant(
+                    || "empty product",
+                    product_col,
+                    0,
+                    pallas::Base::one(),
+                )?;
+                for (row, cell) in cells.iter().enumerate() {
+                    config.q_product.enable(&mut region, row)?;
+                    cell.copy_advice(|| "cell", &mut region, cell_col, row)?;
+                    shot.index
+                        .copy_advice(|| "shot", &mut region, shot_col, row)?;
+                    let next = product
+                        .value()
+                        .zip(shot.index.value())
+                        .zip(cell.value())
+                        .map(|((product, shot), cell)| *product * (*shot - cell));
+                    product = region.assign_advice(|| "product", product_col, row + 1, || next)?;
+                }
+                let row = cells.len();
+                config.q_hit.enable(&mut region, row)?;
+                let inverse = product
+                    .value()
+                    .map(|product| product.invert().unwrap_or(pallas::Base::zero()));
+                region.assign_advice(|| "inverse", inverse_col, row, || inverse)?;
+                region.assign_advice(
+                    || "hit",
+                    hit_col,
+                    row,
+                    || hit.map(|hit| pallas::Base::from(hit as u64)),
+                )
+            },
+        )
+    }
+}
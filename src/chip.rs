@@ -1,88 +1,92 @@
+pub mod builder;
+
 use {
+    self::builder::PedersenConfigBuilder,
     crate::{
         constants::{fixed_bases::BoardFixedBases, LOOKUP_SIZE},
-        gadget::pedersen_commitment,
+        gadget::{pedersen_commitment, short_pedersen_commitment, PedersenInstructions},
     },
     halo2_gadgets::{
         ecc::{
             chip::{EccChip, EccConfig},
             Point, ScalarFixed,
         },
-        utilities::lookup_range_check::LookupRangeCheckConfig,
     },
     halo2_proofs::{
-        arithmetic::FieldExt,
         circuit::{AssignedCell, Chip, Layouter, Value},
         pasta::{EpAffine, pallas},
-        plonk::{Advice, Column, ConstraintSystem, Error, Fixed, TableColumn},
+        plonk::{Advice, Column, ConstraintSystem, Error, Fixed, Instance, TableColumn},
     },
-    std::marker::PhantomData,
 };
 
 #[derive(Clone, Debug)]
 pub struct PedersenCommitmentConfig {
     pub table_idx: TableColumn,
+    /// advice column committed values are witnessed in, see PedersenCommitmentChip::witness_value
+    pub message: Column<Advice>,
     pub ecc: EccConfig<BoardFixedBases>,
 }
 
+/// Proof that the range check lookup table was assigned, obtained from PedersenCommitmentChip::load
+#[derive(Clone, Copy, Debug)]
+pub struct LoadedTable {
+    pub table_idx: TableColumn,
+}
+
+/// Commits to base field elements over the pallas curve in circuits over pallas::Base.
+/// halo2_gadgets' EccChip is specific to pallas, so this chip is too, and BoardFixedBases only
+/// has pallas tables. Vesta commitments are native only, see derive_curve_commitment.
 #[derive(Clone, Debug)]
 pub struct PedersenCommitmentChip {
     config: PedersenCommitmentConfig,
+    loaded: LoadedTable,
 }
 
 impl Chip<pallas::Base> for PedersenCommitmentChip {
     type Config = PedersenCommitmentConfig;
-    type Loaded = ();
+    type Loaded = LoadedTable;
 
     fn config(&self) -> &Self::Config {
         &self.config
     }
 
     fn loaded(&self) -> &Self::Loaded {
-        &()
+        &self.loaded
     }
 }
 
 impl PedersenCommitmentChip {
-    pub fn new(config: PedersenCommitmentConfig) -> Self {
+    pub fn new(config: PedersenCommitmentConfig, loaded: LoadedTable) -> Self {
         PedersenCommitmentChip {
             config,
+            loaded,
         }
     }
 
-    pub fn configure(
-        meta: &mut ConstraintSystem<pallas::Base>,
-        advice: [Column<Advice>; 10],
-        lagrange: [Column<Fixed>; 8],
-        table_idx: TableColumn,
-    ) -> PedersenCommitmentConfig {
-        // configure range check lookup table chip
-        let range_check: LookupRangeCheckConfig<pallas::Base,  LOOKUP_SIZE> =
-            LookupRangeCheckConfig::configure(meta, advice[9], table_idx);
-        // configure ecc chip
-        let ecc = EccChip::<BoardFixedBases>::configure(meta, advice, lagrange, range_check);
-        // return configuration
-        PedersenCommitmentConfig {
-            table_idx,
-            ecc,
-        }
+    /// The ecc chip the commitment is computed with
+    pub fn ecc_chip(&self) -> EccChip<BoardFixedBases> {
+        EccChip::construct(self.config.ecc.clone())
     }
 
-    pub fn synthesize(
-        &self,
-        mut layouter: impl Layouter<pallas::Base>,
-        value: &AssignedCell<pallas::Base, pallas::Base>,
-        trapdoor: Value<pallas::Scalar>,
-    ) -> Result<Point<EpAffine, EccChip<BoardFixedBases>>, Error> {
-        // load the lookup table
+    /**
+     * Load the lookup table used by the range check and ecc chip
+     * @dev must be called exactly once per circuit, before constructing the chip
+     *
+     * @param config - the chip configuration holding the lookup table column
+     * @return - the loaded table used to construct the chip
+     */
+    pub fn load(
+        config: &PedersenCommitmentConfig,
+        layouter: &mut impl Layouter<pallas::Base>,
+    ) -> Result<LoadedTable, Error> {
         layouter.assign_table(
             || "table_idx",
             |mut table| {
                 // We generate the row values lazily (we only need them during keygen).
-                for index in 0..(1 << 10) {
+                for index in 0..(1 << LOOKUP_SIZE) {
                     table.assign_cell(
                         || "table_idx",
-                        self.config.table_idx,
+                        config.table_idx,
                         index,
                         || Value::known(pallas::Base::from(index as u64)),
                     )?;
@@ -90,20 +94,518 @@ impl PedersenCommitmentChip {
                 Ok(())
             },
         )?;
-        // construct ecc chip
-        let ecc_chip = EccChip::construct(self.config.ecc.clone());
-        // instantiate commitment trapdoor as a full-width scalar
-        let trapdoor = ScalarFixed::new(
-            ecc_chip.clone(),
-            layouter.namespace(|| "trapdoor"),
+        Ok(LoadedTable {
+            table_idx: config.table_idx,
+        })
+    }
+
+    /**
+     * Configure the chip over the given columns, with the range check in advice[9]
+     * @dev use PedersenConfigBuilder to share columns or a range check with a host circuit
+     *
+     * @return - the chip configuration
+     */
+    pub fn configure(
+        meta: &mut ConstraintSystem<pallas::Base>,
+        advice: [Column<Advice>; 10],
+        lagrange: [Column<Fixed>; 8],
+        table_idx: TableColumn,
+    ) -> PedersenCommitmentConfig {
+        PedersenConfigBuilder::default()
+            .advices(advice)
+            .lagrange_coeffs(lagrange)
+            .table_column(table_idx)
+            .build(meta)
+    }
+
+    /**
+     * Witness the trapdoor and commit to a witnessed value
+     * @dev shorthand for witness_trapdoor followed by commit
+     *
+     * @param value - the committed value
+     * @param trapdoor - the blinding factor for the commitment
+     * @return - the commitment point
+     */
+    pub fn synthesize(
+        &self,
+        mut layouter: impl Layouter<pallas::Base>,
+        value: &AssignedCell<pallas::Base, pallas::Base>,
+        trapdoor: Value<pallas::Scalar>,
+    ) -> Result<Point<EpAffine, EccChip<BoardFixedBases>>, Error> {
+        let trapdoor = self.witness_trapdoor(layouter.namespace(|| "trapdoor"), trapdoor)?;
+        self.commit(layouter, value, trapdoor)
+    }
+
+    /**
+     * Synthesize a short value commitment to a signed 64 bit value
+     * @dev both cells are witnessed with witness_value, the sign as 1 or -1
+     *
+     * @param magnitude - the absolute value of the committed value, constrained below 2^64
+     * @param sign - the sign of the committed value, constrained to 1 or -1
+     * @param trapdoor - the blinding factor for the commitment
+     * @return - the commitment point [±magnitude] BoardCommitQ + [trapdoor] BoardCommitR
+     */
+    pub fn synthesize_short(
+        &self,
+        mut layouter: impl Layouter<pallas::Base>,
+        magnitude: &AssignedCell<pallas::Base, pallas::Base>,
+        sign: &AssignedCell<pallas::Base, pallas::Base>,
+        trapdoor: Value<pallas::Scalar>,
+    ) -> Result<Point<EpAffine, EccChip<BoardFixedBases>>, Error> {
+        let trapdoor = self.witness_trapdoor(layouter.namespace(|| "trapdoor"), trapdoor)?;
+        short_pedersen_commitment(
+            layouter.namespace(|| "short pedersen commitment"),
+            self.ecc_chip(),
+            magnitude.clone(),
+            sign.clone(),
             trapdoor,
+        )
+    }
+
+    /**
+     * Synthesize a pedersen commitment to a value that is constrained to fit in num_bits bits
+     *
+     * @param value - the committed value
+     * @param trapdoor - the blinding factor for the commitment
+     * @param num_bits - the maximum bit length of value
+     * @return - the commitment point
+     */
+    pub fn synthesize_bounded(
+        &self,
+        mut layouter: impl Layouter<pallas::Base>,
+        value: &AssignedCell<pallas::Base, pallas::Base>,
+        trapdoor: Value<pallas::Scalar>,
+        num_bits: usize,
+    ) -> Result<Point<EpAffine, EccChip<BoardFixedBases>>, Error> {
+        self.range_check(layouter.namespace(|| "range check"), value, num_bits)?;
+        self.synthesize(layouter, value, trapdoor)
+    }
+
+    /**
+     * Constrain a value to fit in num_bits bits using the lookup range check
+     * @dev the lookup table must be loaded for the range check to be satisfied
+     *
+     * @param value - the value to range check
+     * @param num_bits - the maximum bit length of value, at most pallas::Base::CAPACITY
+     */
+    pub fn range_check(
+        &self,
+        mut layouter: impl Layouter<pallas::Base>,
+        value: &AssignedCell<pallas::Base, pallas::Base>,
+        num_bits: usize,
+    ) -> Result<(), Error> {
+        let range_check = self.config.ecc.lookup_config;
+        // decompose the value into LOOKUP_SIZE bit words
+        let num_words = num_bits / LOOKUP_SIZE;
+        let remainder = num_bits % LOOKUP_SIZE;
+        let zs = range_check.copy_check(
+            layouter.namespace(|| "words"),
+            value.clone(),
+            num_words,
+            remainder == 0,
         )?;
+        // constrain the remaining high bits when num_bits is not a multiple of LOOKUP_SIZE
+        if remainder != 0 {
+            range_check.copy_short_check(
+                layouter.namespace(|| "remainder"),
+                zs[num_words].clone(),
+                remainder,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl PedersenInstructions<pallas::Affine> for PedersenCommitmentChip {
+    type Value = AssignedCell<pallas::Base, pallas::Base>;
+    type Trapdoor = ScalarFixed<pallas::Affine, EccChip<BoardFixedBases>>;
+    type Commitment = Point<pallas::Affine, EccChip<BoardFixedBases>>;
+
+    fn witness_value(
+        &self,
+        mut layouter: impl Layouter<pallas::Base>,
+        value: Value<pallas::Base>,
+    ) -> Result<Self::Value, Error> {
+        // committed values live in the configured message column
+        let column = self.config.message;
+        layouter.assign_region(
+            || "witness value",
+            |mut region| region.assign_advice(|| "value", column, 0, || value),
+        )
+    }
+
+    fn witness_trapdoor(
+        &self,
+        layouter: impl Layouter<pallas::Base>,
+        trapdoor: Value<pallas::Scalar>,
+    ) -> Result<Self::Trapdoor, Error> {
+        // instantiate commitment trapdoor as a full-width scalar
+        ScalarFixed::new(self.ecc_chip(), layouter, trapdoor)
+    }
+
+    fn commit(
+        &self,
+        mut layouter: impl Layouter<pallas::Base>,
+        value: &Self::Value,
+        trapdoor: Self::Trapdoor,
+    ) -> Result<Self::Commitment, Error> {
         // synthesize the pedersen commitment computation
-        Ok(pedersen_commitment(
+        pedersen_commitment(
             layouter.namespace(|| "pedersen commitment"),
-            ecc_chip.clone(),
+            self.ecc_chip(),
             value.clone(),
             trapdoor,
-        )?)
+        )
+    }
+
+    fn constrain_equal_commitment(
+        &self,
+        mut layouter: impl Layouter<pallas::Base>,
+        a: &Self::Commitment,
+        b: &Self::Commitment,
+    ) -> Result<(), Error> {
+        // copy a into the region rather than using Point::constrain_equal, whose region has no
+        // assigned rows and makes MockProver panic while locating an unequal commitment
+        let column = self.config.message;
+        let (a, b) = (a.inner(), b.inner());
+        layouter.assign_region(
+            || "constrain equal commitment",
+            |mut region| {
+                let x = a.x().copy_advice(|| "x", &mut region, column, 0)?;
+                let y = a.y().copy_advice(|| "y", &mut region, column, 1)?;
+                region.constrain_equal(x.cell(), b.x().cell())?;
+                region.constrain_equal(y.cell(), b.y().cell())
+            },
+        )
+    }
+
+    fn expose_public(
+        &self,
+        mut layouter: impl Layouter<pallas::Base>,
+        commitment: &Self::Commitment,
+        instance: Column<Instance>,
+        row: usize,
+    ) -> Result<(), Error> {
+        let point = commitment.inner();
+        layouter.constrain_instance(point.x().cell(), instance, row)?;
+        layouter.constrain_instance(point.y().cell(), instance, row + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            constants::fixed_bases::domain::CommitmentDomain,
+            utils::{commit::derive_commitment, get_coordinates},
+        },
+        halo2_gadgets::utilities::lookup_range_check::LookupRangeCheckConfig,
+        halo2_proofs::{
+            arithmetic::{Field, FieldExt},
+            circuit::SimpleFloorPlanner,
+            dev::MockProver,
+            pasta::group::Curve,
+            plonk::Circuit,
+        },
+        rand::rngs::OsRng,
+    };
+
+    // the (x, y) public inputs for the native commitment to (message, trapdoor)
+    fn commitment_coordinates(
+        message: &pallas::Base,
+        trapdoor: &pallas::Scalar,
+    ) -> (pallas::Base, pallas::Base) {
+        let commitment = derive_commitment(&CommitmentDomain::default(), message, trapdoor);
+        get_coordinates(commitment.to_affine())
+    }
+
+    #[derive(Clone, Debug)]
+    struct BoundedCommitmentConfig {
+        instance: Column<Instance>,
+        pedersen: PedersenCommitmentConfig,
+    }
+
+    // the chip over its own columns plus an instance column for the commitment coordinates
+    fn configure_test_chip(meta: &mut ConstraintSystem<pallas::Base>) -> BoundedCommitmentConfig {
+        let advice = [(); 10].map(|_| meta.advice_column());
+        let lagrange_coeffs = [(); 8].map(|_| meta.fixed_column());
+        let table_idx = meta.lookup_table_column();
+        let constants = meta.fixed_column();
+        meta.enable_constant(constants);
+        let instance = meta.instance_column();
+        meta.enable_equality(instance);
+        let pedersen = PedersenCommitmentChip::configure(meta, advice, lagrange_coeffs, table_idx);
+        BoundedCommitmentConfig { instance, pedersen }
+    }
+
+    #[derive(Clone, Debug, Default)]
+    struct BoundedCommitmentCircuit<const NUM_BITS: usize> {
+        message: Value<pallas::Base>,
+        trapdoor: Value<pallas::Scalar>,
+    }
+
+    impl<const NUM_BITS: usize> Circuit<pallas::Base> for BoundedCommitmentCircuit<NUM_BITS> {
+        type Config = BoundedCommitmentConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+            configure_test_chip(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<pallas::Base>,
+        ) -> Result<(), Error> {
+            let loaded = PedersenCommitmentChip::load(&config.pedersen, &mut layouter)?;
+            let chip = PedersenCommitmentChip::new(config.pedersen, loaded);
+            let message = chip.witness_value(layouter.namespace(|| "message"), self.message)?;
+            let commitment = chip.synthesize_bounded(
+                layouter.namespace(|| "bounded pedersen"),
+                &message,
+                self.trapdoor,
+                NUM_BITS,
+            )?;
+            layouter.constrain_instance(commitment.inner().x().cell(), config.instance, 0)?;
+            layouter.constrain_instance(commitment.inner().y().cell(), config.instance, 1)?;
+            Ok(())
+        }
+    }
+
+    #[derive(Clone, Debug, Default)]
+    struct ManyCommitmentsCircuit {
+        messages: [Value<pallas::Base>; 3],
+        trapdoors: [Value<pallas::Scalar>; 3],
+    }
+
+    impl Circuit<pallas::Base> for ManyCommitmentsCircuit {
+        type Config = BoundedCommitmentConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+            configure_test_chip(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<pallas::Base>,
+        ) -> Result<(), Error> {
+            let loaded = PedersenCommitmentChip::load(&config.pedersen, &mut layouter)?;
+            let chip = PedersenCommitmentChip::new(config.pedersen, loaded);
+            for (i, (message, trapdoor)) in self.messages.iter().zip(self.trapdoors).enumerate() {
+                let message = chip
+                    .witness_value(layouter.namespace(|| format!("message {}", i)), *message)?;
+                let commitment = chip.synthesize(
+                    layouter.namespace(|| format!("pedersen {}", i)),
+                    &message,
+                    trapdoor,
+                )?;
+                let (x, y) = (commitment.inner().x(), commitment.inner().y());
+                layouter.constrain_instance(x.cell(), config.instance, 2 * i)?;
+                layouter.constrain_instance(y.cell(), config.instance, 2 * i + 1)?;
+            }
+            Ok(())
+        }
+    }
+
+    // a host circuit owning the range check and witnessing values outside the ecc columns
+    #[derive(Clone, Debug, Default)]
+    struct SharedColumnsCircuit {
+        message: Value<pallas::Base>,
+        trapdoor: Value<pallas::Scalar>,
+    }
+
+    impl Circuit<pallas::Base> for SharedColumnsCircuit {
+        type Config = BoundedCommitmentConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+            let advice = [(); 10].map(|_| meta.advice_column());
+            let host = meta.advice_column();
+            let table_idx = meta.lookup_table_column();
+            let constants = meta.fixed_column();
+            meta.enable_constant(constants);
+            let instance = meta.instance_column();
+            meta.enable_equality(instance);
+            let range_check = LookupRangeCheckConfig::configure(meta, host, table_idx);
+            let pedersen = PedersenConfigBuilder::default()
+                .advices(advice)
+                .range_check(range_check, table_idx)
+                .message_column(host)
+                .build(meta);
+            BoundedCommitmentConfig { instance, pedersen }
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            layouter: impl Layouter<pallas::Base>,
+        ) -> Result<(), Error> {
+            BoundedCommitmentCircuit::<64> {
+                message: self.message,
+                trapdoor: self.trapdoor,
+            }
+            .synthesize(config, layouter)
+        }
+    }
+
+    #[test]
+    fn shared_columns() {
+        let message = pallas::Base::from(u64::MAX);
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        let (x, y) = commitment_coordinates(&message, &trapdoor);
+        let circuit = SharedColumnsCircuit {
+            message: Value::known(message),
+            trapdoor: Value::known(trapdoor),
+        };
+        let prover = MockProver::run(11, &circuit, vec![vec![x, y]]).unwrap();
+        prover.assert_satisfied();
+        // the host's range check still bounds the committed value
+        let message = message + pallas::Base::one();
+        let (x, y) = commitment_coordinates(&message, &trapdoor);
+        let circuit = SharedColumnsCircuit {
+            message: Value::known(message),
+            trapdoor: Value::known(trapdoor),
+        };
+        let prover = MockProver::run(11, &circuit, vec![vec![x, y]]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "table_column conflicts")]
+    fn conflicting_table_columns() {
+        let mut meta = ConstraintSystem::<pallas::Base>::default();
+        let column = meta.advice_column();
+        let table_idx = meta.lookup_table_column();
+        let range_check = LookupRangeCheckConfig::configure(&mut meta, column, table_idx);
+        PedersenConfigBuilder::default()
+            .table_column(meta.lookup_table_column())
+            .range_check(range_check, table_idx)
+            .build(&mut meta);
+    }
+
+    // commit twice through the instructions trait alone and bind both commitments together
+    fn commit_twice<P: PedersenInstructions<pallas::Affine>>(
+        chip: &P,
+        mut layouter: impl Layouter<pallas::Base>,
+        message: Value<pallas::Base>,
+        trapdoors: [Value<pallas::Scalar>; 2],
+        instance: Column<Instance>,
+    ) -> Result<(), Error> {
+        let message = chip.witness_value(layouter.namespace(|| "message"), message)?;
+        let [first, second] = trapdoors;
+        let first = chip.witness_trapdoor(layouter.namespace(|| "r1"), first)?;
+        let first = chip.commit(layouter.namespace(|| "C1"), &message, first)?;
+        let second = chip.witness_trapdoor(layouter.namespace(|| "r2"), second)?;
+        let second = chip.commit(layouter.namespace(|| "C2"), &message, second)?;
+        chip.constrain_equal_commitment(layouter.namespace(|| "C1 = C2"), &first, &second)?;
+        chip.expose_public(layouter.namespace(|| "expose"), &first, instance, 0)
+    }
+
+    #[derive(Clone, Debug, Default)]
+    struct EqualCommitmentsCircuit {
+        message: Value<pallas::Base>,
+        trapdoors: [Value<pallas::Scalar>; 2],
+    }
+
+    impl Circuit<pallas::Base> for EqualCommitmentsCircuit {
+        type Config = BoundedCommitmentConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+            configure_test_chip(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<pallas::Base>,
+        ) -> Result<(), Error> {
+            let loaded = PedersenCommitmentChip::load(&config.pedersen, &mut layouter)?;
+            let chip = PedersenCommitmentChip::new(config.pedersen, loaded);
+            commit_twice(&chip, layouter, self.message, self.trapdoors, config.instance)
+        }
+    }
+
+    #[test]
+    fn instructions() {
+        let message = pallas::Base::random(&mut OsRng);
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        let (x, y) = commitment_coordinates(&message, &trapdoor);
+        let circuit = EqualCommitmentsCircuit {
+            message: Value::known(message),
+            trapdoors: [Value::known(trapdoor); 2],
+        };
+        let prover = MockProver::run(11, &circuit, vec![vec![x, y]]).unwrap();
+        prover.assert_satisfied();
+        // commitments under different trapdoors are different points
+        let circuit = EqualCommitmentsCircuit {
+            message: Value::known(message),
+            trapdoors: [Value::known(trapdoor), Value::known(trapdoor + pallas::Scalar::one())],
+        };
+        let prover = MockProver::run(11, &circuit, vec![vec![x, y]]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    fn many_commitments() {
+        let messages = [(); 3].map(|_| pallas::Base::random(&mut OsRng));
+        let trapdoors = [(); 3].map(|_| pallas::Scalar::random(&mut OsRng));
+        let instance = messages
+            .iter()
+            .zip(trapdoors.iter())
+            .flat_map(|(message, trapdoor)| {
+                let (x, y) = commitment_coordinates(message, trapdoor);
+                [x, y]
+            })
+            .collect::<Vec<_>>();
+        let circuit = ManyCommitmentsCircuit {
+            messages: messages.map(Value::known),
+            trapdoors: trapdoors.map(Value::known),
+        };
+        let prover = MockProver::run(11, &circuit, vec![instance]).unwrap();
+        prover.assert_satisfied();
+    }
+
+    fn prove_bounded<const NUM_BITS: usize>(message: pallas::Base) -> bool {
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        let (x, y) = commitment_coordinates(&message, &trapdoor);
+        let circuit = BoundedCommitmentCircuit::<NUM_BITS> {
+            message: Value::known(message),
+            trapdoor: Value::known(trapdoor),
+        };
+        let prover = MockProver::run(11, &circuit, vec![vec![x, y]]).unwrap();
+        prover.verify().is_ok()
+    }
+
+    #[test]
+    fn bounded_64_bits() {
+        assert!(prove_bounded::<64>(pallas::Base::from(u64::MAX)));
+        assert!(!prove_bounded::<64>(pallas::Base::from(u64::MAX) + pallas::Base::one()));
+    }
+
+    #[test]
+    fn bounded_100_bits() {
+        let max = pallas::Base::from_u128((1 << 100) - 1);
+        assert!(prove_bounded::<100>(max));
+        assert!(!prove_bounded::<100>(max + pallas::Base::one()));
     }
 }
@@ -0,0 +1,133 @@
+use {
+    super::PedersenCommitmentConfig,
+    crate::constants::{fixed_bases::BoardFixedBases, LOOKUP_SIZE},
+    halo2_gadgets::{ecc::chip::EccChip, utilities::lookup_range_check::LookupRangeCheckConfig},
+    halo2_proofs::{
+        pasta::pallas,
+        plonk::{Advice, Column, ConstraintSystem, Fixed, TableColumn},
+    },
+};
+
+/// Builds a PedersenCommitmentConfig over columns shared with a host circuit. Any column that is
+/// not supplied is allocated by build, so PedersenConfigBuilder::default().build(meta) configures
+/// the chip on fresh columns.
+/// The host circuit must still enable a constants column with meta.enable_constant.
+#[derive(Clone, Debug, Default)]
+pub struct PedersenConfigBuilder {
+    advices: Option<[Column<Advice>; 10]>,
+    lagrange_coeffs: Option<[Column<Fixed>; 8]>,
+    table_idx: Option<TableColumn>,
+    range_check: RangeCheck,
+    message: Option<Column<Advice>>,
+}
+
+// where the lookup range check used by the ecc chip comes from
+#[derive(Clone, Debug, Default)]
+enum RangeCheck {
+    // configured by build on the last advice column
+    #[default]
+    Fresh,
+    // configured by build on a column chosen by the host
+    Column(Column<Advice>),
+    // configured by the host over its table column
+    Shared(LookupRangeCheckConfig<pallas::Base, LOOKUP_SIZE>, TableColumn),
+}
+
+impl PedersenConfigBuilder {
+    /// Use the host's advice columns for the ecc chip, equality is enabled on all of them
+    pub fn advices(mut self, advices: [Column<Advice>; 10]) -> Self {
+        self.advices = Some(advices);
+        self
+    }
+
+    /// Use the host's fixed columns for the fixed base lagrange coefficients
+    pub fn lagrange_coeffs(mut self, lagrange_coeffs: [Column<Fixed>; 8]) -> Self {
+        self.lagrange_coeffs = Some(lagrange_coeffs);
+        self
+    }
+
+    /// Use the host's lookup table column for the 10 bit range check table.
+    /// Conflicts with range_check, which brings its own table column.
+    pub fn table_column(mut self, table_idx: TableColumn) -> Self {
+        self.table_idx = Some(table_idx);
+        self
+    }
+
+    /// Run the range check's running sum in this column instead of the last advice column.
+    /// Replaces a shared range check set with range_check.
+    pub fn range_check_column(mut self, column: Column<Advice>) -> Self {
+        self.range_check = RangeCheck::Column(column);
+        self
+    }
+
+    /**
+     * Reuse a range check the host already configured, such as the one of its Sinsemilla chip
+     * @dev the host loads the table itself, so the chip is built with LoadedTable { table_idx }
+     *      rather than PedersenCommitmentChip::load. LookupRangeCheckConfig does not expose its
+     *      table column, so table_idx cannot be checked against it. Replaces a column set with
+     *      range_check_column
+     *
+     * @param range_check - the host's lookup range check
+     * @param table_idx - the table column range_check was configured with
+     */
+    pub fn range_check(
+        mut self,
+        range_check: LookupRangeCheckConfig<pallas::Base, LOOKUP_SIZE>,
+        table_idx: TableColumn,
+    ) -> Self {
+        self.range_check = RangeCheck::Shared(range_check, table_idx);
+        self
+    }
+
+    /// Witness committed values in this column instead of the first advice column
+    pub fn message_column(mut self, column: Column<Advice>) -> Self {
+        self.message = Some(column);
+        self
+    }
+
+    /**
+     * Configure the range check and ecc chip over the chosen columns
+     * @dev panics if table_column differs from the table of a shared range check
+     *
+     * @return - the chip configuration
+     */
+    pub fn build(self, meta: &mut ConstraintSystem<pallas::Base>) -> PedersenCommitmentConfig {
+        // allocate whatever the host did not supply
+        let advices = self
+            .advices
+            .unwrap_or_else(|| [(); 10].map(|_| meta.advice_column()));
+        let lagrange_coeffs = self
+            .lagrange_coeffs
+            .unwrap_or_else(|| [(); 8].map(|_| meta.fixed_column()));
+        // configure range check lookup table chip unless the host shares one
+        let (range_check, table_idx) = match self.range_check {
+            RangeCheck::Shared(range_check, table_idx) => {
+                assert!(
+                    self.table_idx.is_none_or(|column| column == table_idx),
+                    "table_column conflicts with the table of the shared range check"
+                );
+                (range_check, table_idx)
+            }
+            fresh => {
+                let column = match fresh {
+                    RangeCheck::Column(column) => column,
+                    _ => advices[9],
+                };
+                let table_idx = self.table_idx.unwrap_or_else(|| meta.lookup_table_column());
+                let range_check = LookupRangeCheckConfig::configure(meta, column, table_idx);
+                (range_check, table_idx)
+            }
+        };
+        // configure ecc chip
+        let ecc =
+            EccChip::<BoardFixedBases>::configure(meta, advices, lagrange_coeffs, range_check);
+        // committed values are copied into the ecc chip's regions
+        let message = self.message.unwrap_or(advices[0]);
+        meta.enable_equality(message);
+        PedersenCommitmentConfig {
+            table_idx,
+            message,
+            ecc,
+        }
+    }
+}
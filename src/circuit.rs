@@ -1,16 +1,24 @@
+pub mod board;
+pub mod compressed;
+pub mod equality;
+pub mod hashed;
+pub mod membership;
+pub mod message;
+pub mod rerandomize;
+pub mod short;
+pub mod shot;
+pub mod variable;
+
 use {
     crate::{
         chip::{PedersenCommitmentChip, PedersenCommitmentConfig},
-        constants::fixed_bases::BoardFixedBases,
+        gadget::PedersenInstructions,
     },
-    halo2_gadgets::ecc::chip::EccConfig,
     halo2_proofs::{
-        arithmetic::FieldExt,
         circuit::{Layouter, SimpleFloorPlanner, Value},
-        pasta::{pallas, Fq},
-        plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Instance},
+        pasta::pallas,
+        plonk::{Circuit, Column, ConstraintSystem, Error, Instance},
     },
-    std::marker::PhantomData,
 };
 
 #[derive(Clone, Debug)]
@@ -81,28 +89,15 @@ impl Circuit<pallas::Base> for PedersenCommitmentCircuit {
         config: Self::Config,
         mut layouter: impl Layouter<pallas::Base>,
     ) -> Result<(), Error> {
-        // retrieve advice column from inside ecc chip to witness message
-        // column management is intended to be handled at higher level circuit & wont need such references in prod use
-        let advice = config.pedersen.ecc.advices[0];
-        // witness private inputs
-        let message = &layouter.assign_region(
-            || "witness message",
-            |mut region| {
-                let message =
-                    region.assign_advice(|| "witness message", advice, 0, || self.message)?;
-                Ok(message)
-            },
-        )?;
-        // synthesize pedersen commitment
-        let chip = PedersenCommitmentChip::new(config.pedersen.clone());
-        let commitment =
-            chip.synthesize(layouter.namespace(|| "pedersen"), message, self.trapdoor)?;
+        // load the lookup table & construct the chip
+        let loaded = PedersenCommitmentChip::load(&config.pedersen, &mut layouter)?;
+        let chip = PedersenCommitmentChip::new(config.pedersen.clone(), loaded);
+        // witness private inputs & synthesize pedersen commitment
+        let message = &chip.witness_value(layouter.namespace(|| "message"), self.message)?;
+        let trapdoor = chip.witness_trapdoor(layouter.namespace(|| "trapdoor"), self.trapdoor)?;
+        let commitment = chip.commit(layouter.namespace(|| "pedersen"), message, trapdoor)?;
         // export constrained pedersen commitment to instance column
-        let x = commitment.clone().inner().x().cell();
-        let y = commitment.clone().inner().y().cell();
-        layouter.constrain_instance(x, config.instance, 0)?;
-        layouter.constrain_instance(y, config.instance, 1)?;
-        Ok(())
+        chip.expose_public(layouter.namespace(|| "expose"), &commitment, config.instance, 0)
     }
 }
 
@@ -110,13 +105,13 @@ impl Circuit<pallas::Base> for PedersenCommitmentCircuit {
 mod tests {
     use {
         super::*,
-        crate::utils::{
-            commit::derive_commitment,
-            get_coordinates
+        crate::{
+            constants::fixed_bases::domain::CommitmentDomain,
+            utils::{commit::derive_commitment, get_coordinates},
         },
         halo2_proofs::{
             dev::MockProver,
-            arithmetic::{Field, CurveAffine},
+            arithmetic::Field,
             pasta::group::Curve
         },
         rand::rngs::OsRng,
@@ -129,12 +124,9 @@ mod tests {
         // marshall entropy sample for trapdoor into scalar field element
         let trapdoor = pallas::Scalar::random(&mut OsRng);
         // compute pedersen commitment
-        let commitment = derive_commitment(&message, &trapdoor).to_affine();
-        let (x, y) = {
-            let x = commitment.clone().coordinates().unwrap().x().to_owned();
-            let y = commitment.clone().coordinates().unwrap().y().to_owned();
-            (x, y)
-        };
+        let domain = CommitmentDomain::default();
+        let commitment = derive_commitment(&domain, &message, &trapdoor).to_affine();
+        let (x, y) = get_coordinates(commitment);
         // instantiate circuit
         let circuit = PedersenCommitmentCircuit { 
             message: Value::known(message),
@@ -144,7 +136,7 @@ mod tests {
         prover.assert_satisfied()
     }
 
-    #[cfg(feature = "test-dev-graph")]
+    #[cfg(feature = "dev-graph")]
     #[test]
     fn print_ecc_chip() {
         use plotters::prelude::*;
@@ -153,9 +145,12 @@ mod tests {
         root.fill(&WHITE).unwrap();
         let root = root.titled("Ecc Chip Layout", ("sans-serif", 60)).unwrap();
 
-        let circuit = MyCircuit { test_errors: false };
+        let circuit = PedersenCommitmentCircuit {
+            message: Value::unknown(),
+            trapdoor: Value::unknown(),
+        };
         halo2_proofs::dev::CircuitLayout::default()
-            .render(13, &circuit, &root)
+            .render(11, &circuit, &root)
             .unwrap();
     }
 }
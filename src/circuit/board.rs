@@ -0,0 +1,162 @@
+use {
+    super::{PedersenCircuitConfig, PedersenCommitmentCircuit},
+    crate::{
+        board::{
+            chip::{BoardChip, BoardConfig},
+            Board, Ship,
+        },
+        chip::PedersenCommitmentChip,
+        gadget::PedersenInstructions,
+        utils::commit::Trapdoor,
+    },
+    halo2_proofs::{
+        circuit::{Layouter, SimpleFloorPlanner, Value},
+        pasta::pallas,
+        plonk::{Circuit, ConstraintSystem, Error},
+    },
+};
+
+#[derive(Clone, Debug)]
+pub struct BoardCircuitConfig {
+    pub base: PedersenCircuitConfig,
+    pub board: BoardConfig,
+}
+
+/// Proves the board committed to as [board] BoardCommitV + [r] BoardCommitR is valid: every ship
+/// has its length, lies on the 10x10 grid and shares no cell with another ship. The instance
+/// column holds the commitment (x, y).
+#[derive(Clone, Debug)]
+pub struct BoardCircuit {
+    pub ships: Value<[Ship; 5]>,
+    pub trapdoor: Value<pallas::Scalar>,
+}
+
+impl BoardCircuit {
+    /**
+     * Build the witness for a board commitment
+     *
+     * @param board - the committed board
+     * @param trapdoor - the blinding factor of the commitment
+     * @return - the board validity circuit
+     */
+    pub fn new(board: &Board, trapdoor: &Trapdoor) -> Self {
+        BoardCircuit {
+            ships: Value::known(*board.ships()),
+            trapdoor: Value::known(trapdoor.inner()),
+        }
+    }
+
+    /// Configure the pedersen commitment and board chips over the same columns
+    pub(crate) fn configure_board(meta: &mut ConstraintSystem<pallas::Base>) -> BoardCircuitConfig {
+        let base = PedersenCommitmentCircuit::configure(meta);
+        let ecc = &base.pedersen.ecc;
+        let board = BoardChip::configure(meta, ecc.advices, ecc.lookup_config);
+        BoardCircuitConfig { base, board }
+    }
+}
+
+impl Circuit<pallas::Base> for BoardCircuit {
+    type Config = BoardCircuitConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        BoardCircuit {
+            ships: Value::unknown(),
+            trapdoor: Value::unknown(),
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+        BoardCircuit::configure_board(meta)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<pallas::Base>,
+    ) -> Result<(), Error> {
+        // load the lookup table used by the coordinate range checks and the ecc chip
+        let loaded = PedersenCommitmentChip::load(&config.base.pedersen, &mut layouter)?;
+        let pedersen = PedersenCommitmentChip::new(config.base.pedersen.clone(), loaded);
+        // constrain the fleet to a valid board
+        let board = BoardChip::construct(config.board.clone());
+        let ships = board.assign_ships(layouter.namespace(|| "ships"), self.ships)?;
+        board.assert_disjoint(layouter.namespace(|| "disjoint"), &ships)?;
+        // commit to the packed board
+        let packed = board.pack(layouter.namespace(|| "pack"), &ships)?;
+        let commitment =
+            pedersen.synthesize(layouter.namespace(|| "pedersen"), &packed, self.trapdoor)?;
+        // export the board commitment to the instance column
+        let instance = config.base.instance;
+        pedersen.expose_public(layouter.namespace(|| "expose"), &commitment, instance, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{board::pack_ships, utils::commit::Opening},
+        halo2_proofs::dev::MockProver,
+        rand::rngs::OsRng,
+    };
+
+    fn fleet() -> [Ship; 5] {
+        [
+            Ship::new(3, 3, false),
+            Ship::new(5, 4, true),
+            Ship::new(0, 0, false),
+            Ship::new(0, 1, false),
+            Ship::new(9, 8, true),
+        ]
+    }
+
+    // whether the circuit accepts the ships as the opening of their packed commitment
+    fn prove_board(ships: [Ship; 5]) -> bool {
+        let trapdoor = Trapdoor::random(OsRng);
+        let commitment = Opening::new(pack_ships(&ships), trapdoor).commit();
+        let (x, y) = commitment.coordinates().unwrap();
+        let circuit = BoardCircuit {
+            ships: Value::known(ships),
+            trapdoor: Value::known(trapdoor.inner()),
+        };
+        let prover = MockProver::run(11, &circuit, vec![vec![x, y]]).unwrap();
+        prover.verify().is_ok()
+    }
+
+    #[test]
+    fn valid_board() {
+        let board = Board::new(fleet()).unwrap();
+        let opening = board.commit(Trapdoor::random(OsRng));
+        let (x, y) = opening.commit().coordinates().unwrap();
+        let circuit = BoardCircuit::new(&board, &opening.trapdoor);
+        let prover = MockProver::run(11, &circuit, vec![vec![x, y]]).unwrap();
+        prover.assert_satisfied();
+        // the proof does not open a commitment to another board
+        let mut ships = fleet();
+        ships[2] = Ship::new(0, 5, true);
+        let other = Board::new(ships).unwrap().commit(opening.trapdoor);
+        let (x, y) = other.commit().coordinates().unwrap();
+        let prover = MockProver::run(11, &circuit, vec![vec![x, y]]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    fn invalid_boards() {
+        // ships running off the right and bottom edges
+        let mut ships = fleet();
+        ships[0] = Ship::new(6, 3, false);
+        assert!(!prove_board(ships));
+        let mut ships = fleet();
+        ships[4] = Ship::new(9, 9, true);
+        assert!(!prove_board(ships));
+        // a ship wrapping from one row onto the next would otherwise look contiguous
+        let mut ships = fleet();
+        ships[3] = Ship::new(9, 1, false);
+        assert!(!prove_board(ships));
+        // overlapping ships
+        let mut ships = fleet();
+        ships[1] = Ship::new(5, 2, true);
+        assert!(!prove_board(ships));
+    }
+}
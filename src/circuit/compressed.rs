@@ -0,0 +1,140 @@
+use {
+    super::{PedersenCircuitConfig, PedersenCommitmentCircuit},
+    crate::{chip::PedersenCommitmentChip, gadget::PedersenInstructions, utils::commit::Opening},
+    halo2_gadgets::utilities::bool_check,
+    halo2_proofs::{
+        arithmetic::FieldExt,
+        circuit::{Layouter, SimpleFloorPlanner, Value},
+        pasta::{group::ff::PrimeField, pallas},
+        plonk::{Circuit, ConstraintSystem, Constraints, Error, Selector},
+        poly::Rotation,
+    },
+};
+
+/// Bit length of floor(y / 2), which keeps the decomposition y = 2 * half + sign canonical
+const HALF_Y_BITS: usize = 253;
+
+#[derive(Clone, Debug)]
+pub struct CompressedCommitmentConfig {
+    pub base: PedersenCircuitConfig,
+    pub q_sign: Selector,
+}
+
+/// Proves knowledge of the opening of a pedersen commitment exposed as (x, sign), where sign is
+/// the parity of y, halving the public input footprint of PedersenCommitmentCircuit.
+/// y is decomposed as 2 * half + sign with half < 2^253, so an honest proof fails for the
+/// ~2^-129 fraction of commitments with y >= 2^254.
+#[derive(Clone, Debug)]
+pub struct CompressedCommitmentCircuit {
+    pub message: Value<pallas::Base>,
+    pub trapdoor: Value<pallas::Scalar>,
+}
+
+impl From<&Opening> for CompressedCommitmentCircuit {
+    fn from(opening: &Opening) -> Self {
+        CompressedCommitmentCircuit {
+            message: Value::known(opening.value),
+            trapdoor: Value::known(opening.trapdoor.inner()),
+        }
+    }
+}
+
+impl Circuit<pallas::Base> for CompressedCommitmentCircuit {
+    type Config = CompressedCommitmentConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        CompressedCommitmentCircuit {
+            message: Value::unknown(),
+            trapdoor: Value::unknown(),
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+        let base = PedersenCommitmentCircuit::configure(meta);
+        let advices = base.pedersen.ecc.advices;
+        // y = 2 * half + sign with sign boolean
+        let q_sign = meta.selector();
+        meta.create_gate("commitment sign", |meta| {
+            let q_sign = meta.query_selector(q_sign);
+            let y = meta.query_advice(advices[0], Rotation::cur());
+            let half = meta.query_advice(advices[1], Rotation::cur());
+            let sign = meta.query_advice(advices[2], Rotation::cur());
+            Constraints::with_selector(
+                q_sign,
+                [
+                    (
+                        "decomposition",
+                        y - half * pallas::Base::from(2) - sign.clone(),
+                    ),
+                    ("sign is boolean", bool_check(sign)),
+                ],
+            )
+        });
+        CompressedCommitmentConfig { base, q_sign }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<pallas::Base>,
+    ) -> Result<(), Error> {
+        let advices = config.base.pedersen.ecc.advices;
+        // load the lookup table & synthesize the commitment
+        let loaded = PedersenCommitmentChip::load(&config.base.pedersen, &mut layouter)?;
+        let chip = PedersenCommitmentChip::new(config.base.pedersen.clone(), loaded);
+        let message = &chip.witness_value(layouter.namespace(|| "message"), self.message)?;
+        let commitment =
+            chip.synthesize(layouter.namespace(|| "pedersen"), message, self.trapdoor)?;
+        let (x, y) = (commitment.inner().x(), commitment.inner().y());
+        // split off the parity of y
+        let (half, sign) = layouter.assign_region(
+            || "commitment sign",
+            |mut region| {
+                config.q_sign.enable(&mut region, 0)?;
+                let y = y.copy_advice(|| "y", &mut region, advices[0], 0)?;
+                let sign = y
+                    .value()
+                    .map(|y| pallas::Base::from((y.to_repr()[0] & 1) as u64));
+                let half = y
+                    .value()
+                    .zip(sign)
+                    .map(|(y, sign)| (*y - sign) * pallas::Base::TWO_INV);
+                let half = region.assign_advice(|| "half", advices[1], 0, || half)?;
+                let sign = region.assign_advice(|| "sign", advices[2], 0, || sign)?;
+                Ok((half, sign))
+            },
+        )?;
+        // 2 * half + sign < p only has the canonical solution when half is small
+        chip.range_check(layouter.namespace(|| "half range"), &half, HALF_Y_BITS)?;
+        // export (x, sign) to the instance column
+        layouter.constrain_instance(x.cell(), config.base.instance, 0)?;
+        layouter.constrain_instance(sign.cell(), config.base.instance, 1)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::utils::commit::Trapdoor,
+        halo2_proofs::{arithmetic::Field, dev::MockProver},
+        rand::rngs::OsRng,
+    };
+
+    #[test]
+    fn compressed_commitment() {
+        for _ in 0..4 {
+            let opening = Opening::new(pallas::Base::random(OsRng), Trapdoor::random(OsRng));
+            let circuit = CompressedCommitmentCircuit::from(&opening);
+            let (x, sign) = opening.commit().compressed_coordinates().unwrap();
+            let prover = MockProver::run(11, &circuit, vec![vec![x, sign]]).unwrap();
+            prover.assert_satisfied();
+            // the flipped sign names the negated commitment and is rejected
+            let flipped = pallas::Base::one() - sign;
+            let prover = MockProver::run(11, &circuit, vec![vec![x, flipped]]).unwrap();
+            assert!(prover.verify().is_err());
+        }
+    }
+}
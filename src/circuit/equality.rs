@@ -0,0 +1,125 @@
+use {
+    super::{PedersenCircuitConfig, PedersenCommitmentCircuit},
+    crate::{
+        chip::PedersenCommitmentChip,
+        gadget::PedersenInstructions,
+        utils::commit::{Commitment, Opening},
+    },
+    halo2_proofs::{
+        circuit::{Layouter, SimpleFloorPlanner, Value},
+        pasta::pallas,
+        plonk::{Circuit, ConstraintSystem, Error},
+    },
+};
+
+/// Proves two pedersen commitments C1 = [v]V + [r1]R and C2 = [v]V + [r2]R hide the same value
+/// without revealing it. The instance column holds (C1.x, C1.y, C2.x, C2.y).
+#[derive(Clone, Debug)]
+pub struct CommitmentEqualityCircuit {
+    pub message: Value<pallas::Base>,
+    pub trapdoor1: Value<pallas::Scalar>,
+    pub trapdoor2: Value<pallas::Scalar>,
+}
+
+impl CommitmentEqualityCircuit {
+    /**
+     * Build the witness proving two openings commit to the same value
+     *
+     * @param first - opening of the first commitment
+     * @param second - opening of the second commitment
+     * @return - the circuit, or None if the openings commit to different values
+     */
+    pub fn from_openings(first: &Opening, second: &Opening) -> Option<Self> {
+        if first.value != second.value {
+            return None;
+        }
+        Some(CommitmentEqualityCircuit {
+            message: Value::known(first.value),
+            trapdoor1: Value::known(first.trapdoor.inner()),
+            trapdoor2: Value::known(second.trapdoor.inner()),
+        })
+    }
+}
+
+/**
+ * Build the public inputs for CommitmentEqualityCircuit
+ *
+ * @param first - the first commitment
+ * @param second - the second commitment
+ * @return - instance column values (C1.x, C1.y, C2.x, C2.y), or None if either commitment is
+ *           the identity
+ */
+pub fn equality_instance(first: &Commitment, second: &Commitment) -> Option<Vec<pallas::Base>> {
+    let (x1, y1) = first.coordinates()?;
+    let (x2, y2) = second.coordinates()?;
+    Some(vec![x1, y1, x2, y2])
+}
+
+impl Circuit<pallas::Base> for CommitmentEqualityCircuit {
+    type Config = PedersenCircuitConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        CommitmentEqualityCircuit {
+            message: Value::unknown(),
+            trapdoor1: Value::unknown(),
+            trapdoor2: Value::unknown(),
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+        // same column layout as the single commitment circuit
+        PedersenCommitmentCircuit::configure(meta)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<pallas::Base>,
+    ) -> Result<(), Error> {
+        // load the lookup table & construct the chip
+        let loaded = PedersenCommitmentChip::load(&config.pedersen, &mut layouter)?;
+        let chip = PedersenCommitmentChip::new(config.pedersen.clone(), loaded);
+        // witness the shared message once so both commitments are bound to the same cell
+        let message = &chip.witness_value(layouter.namespace(|| "message"), self.message)?;
+        // synthesize both commitments
+        let first = chip.synthesize(layouter.namespace(|| "C1"), message, self.trapdoor1)?;
+        let second = chip.synthesize(layouter.namespace(|| "C2"), message, self.trapdoor2)?;
+        // export both commitments to the instance column
+        chip.expose_public(layouter.namespace(|| "C1"), &first, config.instance, 0)?;
+        chip.expose_public(layouter.namespace(|| "C2"), &second, config.instance, 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*, crate::utils::commit::Trapdoor, halo2_proofs::dev::MockProver, rand::rngs::OsRng,
+    };
+
+    #[test]
+    fn equal_values() {
+        let first = Opening::new(pallas::Base::from(88675409), Trapdoor::random(OsRng));
+        let second = Opening::new(first.value, Trapdoor::random(OsRng));
+        let circuit = CommitmentEqualityCircuit::from_openings(&first, &second).unwrap();
+        let instance = equality_instance(&first.commit(), &second.commit()).unwrap();
+        let prover = MockProver::run(11, &circuit, vec![instance]).unwrap();
+        prover.assert_satisfied();
+    }
+
+    #[test]
+    fn different_values() {
+        let first = Opening::new(pallas::Base::from(1), Trapdoor::random(OsRng));
+        let second = Opening::new(pallas::Base::from(2), Trapdoor::random(OsRng));
+        assert!(CommitmentEqualityCircuit::from_openings(&first, &second).is_none());
+        // a witness for the first value cannot open a commitment to the second
+        let circuit = CommitmentEqualityCircuit {
+            message: Value::known(first.value),
+            trapdoor1: Value::known(first.trapdoor.inner()),
+            trapdoor2: Value::known(second.trapdoor.inner()),
+        };
+        let instance = equality_instance(&first.commit(), &second.commit()).unwrap();
+        let prover = MockProver::run(11, &circuit, vec![instance]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+}
@@ -0,0 +1,130 @@
+use {
+    super::{PedersenCircuitConfig, PedersenCommitmentCircuit},
+    crate::{chip::PedersenCommitmentChip, gadget::PedersenInstructions, utils::commit::Opening},
+    halo2_gadgets::poseidon::{
+        primitives::{ConstantLength, P128Pow5T3},
+        Hash, Pow5Chip, Pow5Config,
+    },
+    halo2_proofs::{
+        circuit::{AssignedCell, Layouter, SimpleFloorPlanner, Value},
+        pasta::pallas,
+        plonk::{Circuit, ConstraintSystem, Error},
+    },
+};
+
+#[derive(Clone, Debug)]
+pub struct HashedCommitmentConfig {
+    pub base: PedersenCircuitConfig,
+    pub poseidon: Pow5Config<pallas::Base, 3, 2>,
+}
+
+/// Proves knowledge of the opening of a pedersen commitment exposed only as Poseidon(x, y), a
+/// single public input suited to storing commitments as Merkle tree leaves.
+#[derive(Clone, Debug)]
+pub struct HashedCommitmentCircuit {
+    pub message: Value<pallas::Base>,
+    pub trapdoor: Value<pallas::Scalar>,
+}
+
+impl From<&Opening> for HashedCommitmentCircuit {
+    fn from(opening: &Opening) -> Self {
+        HashedCommitmentCircuit {
+            message: Value::known(opening.value),
+            trapdoor: Value::known(opening.trapdoor.inner()),
+        }
+    }
+}
+
+/**
+ * Synthesize Poseidon(left, right), matching the native Commitment::hash and merkle::hash_nodes
+ *
+ * @param config - the poseidon chip configuration
+ * @param pair - the assigned (left, right) inputs
+ * @return - the assigned digest
+ */
+pub(crate) fn hash_pair(
+    config: &Pow5Config<pallas::Base, 3, 2>,
+    mut layouter: impl Layouter<pallas::Base>,
+    pair: [AssignedCell<pallas::Base, pallas::Base>; 2],
+) -> Result<AssignedCell<pallas::Base, pallas::Base>, Error> {
+    let hasher = Hash::<_, _, P128Pow5T3, ConstantLength<2>, 3, 2>::init(
+        Pow5Chip::construct(config.clone()),
+        layouter.namespace(|| "init"),
+    )?;
+    hasher.hash(layouter.namespace(|| "hash"), pair)
+}
+
+impl Circuit<pallas::Base> for HashedCommitmentCircuit {
+    type Config = HashedCommitmentConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        HashedCommitmentCircuit {
+            message: Value::unknown(),
+            trapdoor: Value::unknown(),
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+        let base = PedersenCommitmentCircuit::configure(meta);
+        let advices = base.pedersen.ecc.advices;
+        // poseidon shares the ecc advice columns and gets its own round constant columns
+        let rc_a = [(); 3].map(|_| meta.fixed_column());
+        let rc_b = [(); 3].map(|_| meta.fixed_column());
+        let poseidon = Pow5Chip::configure::<P128Pow5T3>(
+            meta,
+            [advices[6], advices[7], advices[8]],
+            advices[5],
+            rc_a,
+            rc_b,
+        );
+        HashedCommitmentConfig { base, poseidon }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<pallas::Base>,
+    ) -> Result<(), Error> {
+        // load the lookup table & synthesize the commitment
+        let loaded = PedersenCommitmentChip::load(&config.base.pedersen, &mut layouter)?;
+        let chip = PedersenCommitmentChip::new(config.base.pedersen.clone(), loaded);
+        let message = &chip.witness_value(layouter.namespace(|| "message"), self.message)?;
+        let commitment =
+            chip.synthesize(layouter.namespace(|| "pedersen"), message, self.trapdoor)?;
+        // hash the commitment coordinates
+        let point = commitment.inner();
+        let digest = hash_pair(
+            &config.poseidon,
+            layouter.namespace(|| "poseidon(x, y)"),
+            [point.x(), point.y()],
+        )?;
+        // export the digest to the instance column
+        layouter.constrain_instance(digest.cell(), config.base.instance, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::utils::commit::Trapdoor,
+        halo2_proofs::{arithmetic::Field, dev::MockProver},
+        rand::rngs::OsRng,
+    };
+
+    #[test]
+    fn hashed_commitment() {
+        let opening = Opening::new(pallas::Base::from(88675409), Trapdoor::random(OsRng));
+        let circuit = HashedCommitmentCircuit::from(&opening);
+        let digest = opening.commit().hash().unwrap();
+        let prover = MockProver::run(11, &circuit, vec![vec![digest]]).unwrap();
+        prover.assert_satisfied();
+        // the hash of another commitment to the same value is rejected
+        let other = Opening::new(opening.value, Trapdoor::random(OsRng)).commit();
+        let prover = MockProver::run(11, &circuit, vec![vec![other.hash().unwrap()]]).unwrap();
+        assert!(prover.verify().is_err());
+        let prover = MockProver::run(11, &circuit, vec![vec![pallas::Base::random(OsRng)]]);
+        assert!(prover.unwrap().verify().is_err());
+    }
+}
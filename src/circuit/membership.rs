@@ -0,0 +1,165 @@
+use {
+    super::hashed::{hash_pair, HashedCommitmentCircuit, HashedCommitmentConfig},
+    crate::{
+        chip::PedersenCommitmentChip,
+        gadget::PedersenInstructions,
+        utils::{
+            commit::Opening,
+            merkle::{MerklePath, MAX_DEPTH},
+        },
+    },
+    halo2_gadgets::utilities::cond_swap::{CondSwapChip, CondSwapConfig, CondSwapInstructions},
+    halo2_proofs::{
+        circuit::{Layouter, SimpleFloorPlanner, Value},
+        pasta::pallas,
+        plonk::{Circuit, ConstraintSystem, Error},
+    },
+};
+
+#[derive(Clone, Debug)]
+pub struct MembershipConfig {
+    pub hashed: HashedCommitmentConfig,
+    pub cond_swap: CondSwapConfig,
+}
+
+/// Proves knowledge of an opening (message, trapdoor) whose commitment, hashed with
+/// Poseidon(x, y), is a leaf of a Poseidon Merkle tree of depth DEPTH. The instance column holds
+/// only the root, so neither the commitment nor its position is revealed. Positions are u32, so
+/// DEPTH is at most MAX_DEPTH.
+/// halo2_gadgets' MerkleChip is built on Sinsemilla, which needs hash domains and generator
+/// tables this crate does not define, so the path is hashed with the Poseidon chip instead.
+#[derive(Clone, Debug)]
+pub struct MembershipCircuit<const DEPTH: usize> {
+    pub message: Value<pallas::Base>,
+    pub trapdoor: Value<pallas::Scalar>,
+    pub position: Value<u32>,
+    pub siblings: Value<[pallas::Base; DEPTH]>,
+}
+
+impl<const DEPTH: usize> MembershipCircuit<DEPTH> {
+    /**
+     * Build the witness for a committed leaf
+     *
+     * @param opening - the opening of the committed leaf
+     * @param path - the authentication path of the leaf, from MerkleTree::path
+     * @return - the membership circuit
+     */
+    pub fn new(opening: &Opening, path: &MerklePath<DEPTH>) -> Self {
+        MembershipCircuit {
+            message: Value::known(opening.value),
+            trapdoor: Value::known(opening.trapdoor.inner()),
+            position: Value::known(path.position),
+            siblings: Value::known(path.siblings),
+        }
+    }
+}
+
+impl<const DEPTH: usize> Circuit<pallas::Base> for MembershipCircuit<DEPTH> {
+    type Config = MembershipConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        MembershipCircuit {
+            message: Value::unknown(),
+            trapdoor: Value::unknown(),
+            position: Value::unknown(),
+            siblings: Value::unknown(),
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+        // every level of the path is selected by one bit of the u32 position
+        assert!(
+            DEPTH <= MAX_DEPTH,
+            "membership circuit depth exceeds MAX_DEPTH"
+        );
+        let hashed = HashedCommitmentCircuit::configure(meta);
+        // order each (node, sibling) pair by the position bit before hashing
+        let advices = hashed.base.pedersen.ecc.advices;
+        let cond_swap = CondSwapChip::configure(
+            meta,
+            [advices[0], advices[1], advices[2], advices[3], advices[4]],
+        );
+        MembershipConfig { hashed, cond_swap }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<pallas::Base>,
+    ) -> Result<(), Error> {
+        let base = &config.hashed.base;
+        // load the lookup table & synthesize the commitment
+        let loaded = PedersenCommitmentChip::load(&base.pedersen, &mut layouter)?;
+        let chip = PedersenCommitmentChip::new(base.pedersen.clone(), loaded);
+        let message = &chip.witness_value(layouter.namespace(|| "message"), self.message)?;
+        let commitment =
+            chip.synthesize(layouter.namespace(|| "pedersen"), message, self.trapdoor)?;
+        // the leaf is Poseidon(x, y)
+        let point = commitment.inner();
+        let mut node = hash_pair(
+            &config.hashed.poseidon,
+            layouter.namespace(|| "leaf"),
+            [point.x(), point.y()],
+        )?;
+        // hash up the authentication path
+        let cond_swap = CondSwapChip::construct(config.cond_swap.clone());
+        for level in 0..DEPTH {
+            let sibling = self.siblings.map(|siblings| siblings[level]);
+            let is_right = self.position.map(|position| (position >> level) & 1 == 1);
+            let (left, right) = cond_swap.swap(
+                layouter.namespace(|| format!("order level {}", level)),
+                (node, sibling),
+                is_right,
+            )?;
+            node = hash_pair(
+                &config.hashed.poseidon,
+                layouter.namespace(|| format!("hash level {}", level)),
+                [left, right],
+            )?;
+        }
+        // export the root to the instance column
+        layouter.constrain_instance(node.cell(), base.instance, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::utils::{commit::Trapdoor, merkle::MerkleTree},
+        halo2_proofs::dev::MockProver,
+        rand::rngs::OsRng,
+    };
+
+    const DEPTH: usize = 4;
+
+    #[test]
+    fn membership() {
+        let openings = (0..10u64)
+            .map(|value| Opening::new(pallas::Base::from(value), Trapdoor::random(OsRng)))
+            .collect::<Vec<_>>();
+        let commitments = openings.iter().map(Opening::commit).collect::<Vec<_>>();
+        let tree = MerkleTree::<DEPTH>::from_commitments(&commitments).unwrap();
+        for position in [0, 5, 9] {
+            let path = tree.path(position).unwrap();
+            let circuit = MembershipCircuit::new(&openings[position], &path);
+            let prover = MockProver::run(11, &circuit, vec![vec![tree.root()]]).unwrap();
+            prover.assert_satisfied();
+        }
+        // an opening that is not a leaf, or a leaf at the wrong position, is rejected
+        let outsider = Opening::new(pallas::Base::from(3), Trapdoor::random(OsRng));
+        let circuit = MembershipCircuit::new(&outsider, &tree.path(3).unwrap());
+        let prover = MockProver::run(11, &circuit, vec![vec![tree.root()]]).unwrap();
+        assert!(prover.verify().is_err());
+        let circuit = MembershipCircuit::new(&openings[2], &tree.path(3).unwrap());
+        let prover = MockProver::run(11, &circuit, vec![vec![tree.root()]]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "membership circuit depth exceeds MAX_DEPTH")]
+    fn oversized_depth() {
+        MembershipCircuit::<{ MAX_DEPTH + 1 }>::configure(&mut ConstraintSystem::default());
+    }
+}
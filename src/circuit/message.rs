@@ -0,0 +1,268 @@
+use {
+    super::hashed::{hash_pair, HashedCommitmentCircuit, HashedCommitmentConfig},
+    crate::{chip::PedersenCommitmentChip, gadget::PedersenInstructions, utils::commit::Trapdoor},
+    halo2_gadgets::{
+        poseidon::{
+            primitives::{ConstantLength, P128Pow5T3},
+            Hash, Pow5Chip,
+        },
+        utilities::bool_check,
+    },
+    halo2_proofs::{
+        circuit::{AssignedCell, Layouter, SimpleFloorPlanner, Value},
+        pasta::pallas,
+        plonk::{
+            Advice, Circuit, Column, ConstraintSystem, Constraints, Error, Expression, Selector,
+        },
+        poly::Rotation,
+    },
+};
+
+#[derive(Clone, Debug)]
+pub struct MessageCommitmentConfig {
+    pub hashed: HashedCommitmentConfig,
+    pub advices: [Column<Advice>; 3],
+    q_padding: Selector,
+}
+
+/// Proves knowledge of a message of up to MAX_LEN field elements and a trapdoor opening the
+/// pedersen commitment to utils::message::hash_message(message). The message is absorbed zero
+/// padded to MAX_LEN elements, so one circuit serves every length from 0 to MAX_LEN, which must
+/// be at least 1. A boolean flag marks each slot holding a message element, the flags never go
+/// from unset to set, slots past the message must be zero and the length hashed is the number of
+/// set flags. The instance column holds the commitment (x, y).
+#[derive(Clone, Debug)]
+pub struct MessageCommitmentCircuit<const MAX_LEN: usize> {
+    pub length: Value<usize>,
+    pub elements: Value<[pallas::Base; MAX_LEN]>,
+    pub trapdoor: Value<pallas::Scalar>,
+}
+
+impl<const MAX_LEN: usize> MessageCommitmentCircuit<MAX_LEN> {
+    /**
+     * Build the witness for a message commitment
+     *
+     * @param elements - the message, i.e. from utils::message::encode_bytes
+     * @param trapdoor - the blinding factor of the commitment
+     * @return - the message commitment circuit, or None if the message is longer than MAX_LEN
+     */
+    pub fn new(elements: &[pallas::Base], trapdoor: &Trapdoor) -> Option<Self> {
+        if elements.len() > MAX_LEN {
+            return None;
+        }
+        let mut padded = [pallas::Base::zero(); MAX_LEN];
+        padded[..elements.len()].copy_from_slice(elements);
+        Some(MessageCommitmentCircuit {
+            length: Value::known(elements.len()),
+            elements: Value::known(padded),
+            trapdoor: Value::known(trapdoor.inner()),
+        })
+    }
+}
+
+impl<const MAX_LEN: usize> Circuit<pallas::Base> for MessageCommitmentCircuit<MAX_LEN> {
+    type Config = MessageCommitmentConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        MessageCommitmentCircuit {
+            length: Value::unknown(),
+            elements: Value::unknown(),
+            trapdoor: Value::unknown(),
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+        // same column layout as the hashed commitment circuit
+        let hashed = HashedCommitmentCircuit::configure(meta);
+        let advices = [0, 1, 2].map(|i| hashed.base.pedersen.ecc.advices[i]);
+        // one row per message slot with a running count of the set flags
+        // | flag | element | length |
+        let q_padding = meta.selector();
+        meta.create_gate("message padding", |meta| {
+            let q_padding = meta.query_selector(q_padding);
+            let one = Expression::Constant(pallas::Base::one());
+            let flag = meta.query_advice(advices[0], Rotation::cur());
+            let next_flag = meta.query_advice(advices[0], Rotation::next());
+            let element = meta.query_advice(advices[1], Rotation::cur());
+            let length = meta.query_advice(advices[2], Rotation::cur());
+            let next_length = meta.query_advice(advices[2], Rotation::next());
+            Constraints::with_selector(
+                q_padding,
+                [
+                    ("flag is boolean", bool_check(flag.clone())),
+                    (
+                        "flags never increase",
+                        next_flag * (one.clone() - flag.clone()),
+                    ),
+                    ("padding is zero", (one - flag.clone()) * element),
+                    ("count the flags", next_length - length - flag),
+                ],
+            )
+        });
+        MessageCommitmentConfig {
+            hashed,
+            advices,
+            q_padding,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<pallas::Base>,
+    ) -> Result<(), Error> {
+        let MessageCommitmentConfig {
+            hashed: config,
+            advices: [flag_col, element_col, length_col],
+            q_padding,
+        } = config;
+        // load the lookup table & construct the chip
+        let loaded = PedersenCommitmentChip::load(&config.base.pedersen, &mut layouter)?;
+        let chip = PedersenCommitmentChip::new(config.base.pedersen.clone(), loaded);
+        // witness the padded message, constraining the padding to zero & counting its length
+        let (elements, length) = layouter.assign_region(
+            || "padded message",
+            |mut region| {
+                let mut length = region.assign_advice_from_constant(
+                    || "empty",
+                    length_col,
+                    0,
+                    pallas::Base::zero(),
+                )?;
+                let mut elements = Vec::with_capacity(MAX_LEN);
+                for i in 0..MAX_LEN {
+                    q_padding.enable(&mut region, i)?;
+                    let flag = self
+                        .length
+                        .map(|length| pallas::Base::from((i < length) as u64));
+                    region.assign_advice(|| format!("flag_{}", i), flag_col, i, || flag)?;
+                    let element = self.elements.map(|elements| elements[i]);
+                    elements.push(region.assign_advice(
+                        || format!("m_{}", i),
+                        element_col,
+                        i,
+                        || element,
+                    )?);
+                    length = region.assign_advice(
+                        || format!("length_{}", i + 1),
+                        length_col,
+                        i + 1,
+                        || length.value().copied() + flag,
+                    )?;
+                }
+                // no flag past the last slot
+                region.assign_advice_from_constant(
+                    || "end",
+                    flag_col,
+                    MAX_LEN,
+                    pallas::Base::zero(),
+                )?;
+                Ok((elements, length))
+            },
+        )?;
+        let elements: [AssignedCell<pallas::Base, pallas::Base>; MAX_LEN] =
+            elements.try_into().map_err(|_| Error::Synthesis)?;
+        // absorb the padded message, then bind its length
+        let hasher = Hash::<_, _, P128Pow5T3, ConstantLength<MAX_LEN>, 3, 2>::init(
+            Pow5Chip::construct(config.poseidon.clone()),
+            layouter.namespace(|| "init"),
+        )?;
+        let digest = hasher.hash(layouter.namespace(|| "message"), elements)?;
+        let digest = hash_pair(
+            &config.poseidon,
+            layouter.namespace(|| "length"),
+            [length, digest],
+        )?;
+        // commit to the digest & export the commitment to the instance column
+        let commitment =
+            chip.synthesize(layouter.namespace(|| "pedersen"), &digest, self.trapdoor)?;
+        let instance = config.base.instance;
+        chip.expose_public(layouter.namespace(|| "expose"), &commitment, instance, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::utils::{
+            commit::Opening,
+            message::{commit_message, encode_bytes, BYTES_PER_ELEMENT},
+        },
+        halo2_gadgets::poseidon::primitives as poseidon,
+        halo2_proofs::{arithmetic::Field, dev::MockProver},
+        rand::rngs::OsRng,
+    };
+
+    const MAX_LEN: usize = 4;
+
+    // whether the circuit accepts the message as the opening of its commitment
+    fn prove_message(elements: &[pallas::Base]) -> bool {
+        let opening = commit_message::<MAX_LEN>(elements, Trapdoor::random(OsRng)).unwrap();
+        let (x, y) = opening.commit().coordinates().unwrap();
+        let circuit = MessageCommitmentCircuit::<MAX_LEN>::new(elements, &opening.trapdoor);
+        let prover = MockProver::run(11, &circuit.unwrap(), vec![vec![x, y]]).unwrap();
+        prover.verify().is_ok()
+    }
+
+    #[test]
+    fn message_lengths() {
+        let message = [(); MAX_LEN].map(|_| pallas::Base::random(&mut OsRng));
+        for length in 0..=MAX_LEN {
+            assert!(prove_message(&message[..length]));
+        }
+        let trapdoor = Trapdoor::random(OsRng);
+        assert!(MessageCommitmentCircuit::<MAX_LEN>::new(&[message[0]; 5], &trapdoor).is_none());
+    }
+
+    #[test]
+    fn byte_messages() {
+        let longest = [0xab; MAX_LEN * BYTES_PER_ELEMENT - 1];
+        for bytes in [&b""[..], b"battlezips", &longest] {
+            assert!(prove_message(&encode_bytes(bytes)));
+        }
+    }
+
+    #[test]
+    fn wrong_length() {
+        // a message padded with a trailing zero does not open the commitment to the message
+        let message = [pallas::Base::random(&mut OsRng), pallas::Base::zero()];
+        let opening = commit_message::<MAX_LEN>(&message[..1], Trapdoor::random(OsRng)).unwrap();
+        let (x, y) = opening.commit().coordinates().unwrap();
+        let circuit = MessageCommitmentCircuit::<MAX_LEN>::new(&message, &opening.trapdoor);
+        let prover = MockProver::run(11, &circuit.unwrap(), vec![vec![x, y]]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    fn tampered_padding() {
+        // commit to a (length, padded message) pair that hash_message never produces
+        let commit = |length: usize, padded: [pallas::Base; MAX_LEN], trapdoor: &Trapdoor| {
+            let digest =
+                poseidon::Hash::<_, P128Pow5T3, ConstantLength<MAX_LEN>, 3, 2>::init().hash(padded);
+            let length = pallas::Base::from(length as u64);
+            let digest = poseidon::Hash::<_, P128Pow5T3, ConstantLength<2>, 3, 2>::init()
+                .hash([length, digest]);
+            Opening::new(digest, *trapdoor)
+                .commit()
+                .coordinates()
+                .unwrap()
+        };
+        let trapdoor = Trapdoor::random(OsRng);
+        let mut padded = [pallas::Base::zero(); MAX_LEN];
+        padded[0] = pallas::Base::random(&mut OsRng);
+        padded[1] = pallas::Base::random(&mut OsRng);
+        // a nonzero element past the message length
+        let mut circuit = MessageCommitmentCircuit::<MAX_LEN>::new(&padded, &trapdoor).unwrap();
+        circuit.length = Value::known(1);
+        let (x, y) = commit(1, padded, &trapdoor);
+        let prover = MockProver::run(11, &circuit, vec![vec![x, y]]).unwrap();
+        assert!(prover.verify().is_err());
+        // a length beyond MAX_LEN
+        circuit.length = Value::known(MAX_LEN + 1);
+        let (x, y) = commit(MAX_LEN + 1, padded, &trapdoor);
+        let prover = MockProver::run(11, &circuit, vec![vec![x, y]]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+}
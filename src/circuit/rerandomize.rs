@@ -0,0 +1,121 @@
+use {
+    super::{PedersenCircuitConfig, PedersenCommitmentCircuit},
+    crate::{
+        chip::PedersenCommitmentChip,
+        gadget::rerandomize_commitment,
+        utils::commit::{rerandomize, Commitment, Trapdoor},
+    },
+    halo2_gadgets::ecc::{chip::EccChip, Point, ScalarFixed},
+    halo2_proofs::{
+        circuit::{Layouter, SimpleFloorPlanner, Value},
+        pasta::{group::Curve, pallas},
+        plonk::{Circuit, ConstraintSystem, Error},
+    },
+};
+
+/// Proves C' = C + [delta] BoardCommitR for a published commitment C, so C' opens to the same
+/// value as C. The instance column holds (C.x, C.y, C'.x, C'.y).
+#[derive(Clone, Debug)]
+pub struct RerandomizationCircuit {
+    pub commitment: Value<pallas::Affine>,
+    pub delta: Value<pallas::Scalar>,
+}
+
+impl RerandomizationCircuit {
+    /**
+     * Build the witness for refreshing a commitment
+     *
+     * @param commitment - the published commitment C
+     * @param delta - the trapdoor offset
+     * @return - the circuit and its public inputs (C.x, C.y, C'.x, C'.y), or None if C or C' is
+     *           the identity
+     */
+    pub fn new(commitment: &Commitment, delta: &Trapdoor) -> Option<(Self, Vec<pallas::Base>)> {
+        let refreshed = rerandomize(commitment, delta);
+        let (x, y) = commitment.coordinates()?;
+        let (x_prime, y_prime) = refreshed.coordinates()?;
+        let circuit = RerandomizationCircuit {
+            commitment: Value::known(commitment.inner().to_affine()),
+            delta: Value::known(delta.inner()),
+        };
+        Some((circuit, vec![x, y, x_prime, y_prime]))
+    }
+}
+
+impl Circuit<pallas::Base> for RerandomizationCircuit {
+    type Config = PedersenCircuitConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        RerandomizationCircuit {
+            commitment: Value::unknown(),
+            delta: Value::unknown(),
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+        // same column layout as the single commitment circuit
+        PedersenCommitmentCircuit::configure(meta)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<pallas::Base>,
+    ) -> Result<(), Error> {
+        // the ecc chip's scalar decomposition uses the lookup table
+        PedersenCommitmentChip::load(&config.pedersen, &mut layouter)?;
+        let ecc_chip = EccChip::construct(config.pedersen.ecc.clone());
+        // witness the published commitment and bind it to instance rows 0 and 1
+        let commitment = Point::new(
+            ecc_chip.clone(),
+            layouter.namespace(|| "witness C"),
+            self.commitment,
+        )?;
+        layouter.constrain_instance(commitment.inner().x().cell(), config.instance, 0)?;
+        layouter.constrain_instance(commitment.inner().y().cell(), config.instance, 1)?;
+        // C' = C + [delta] BoardCommitR
+        let delta = ScalarFixed::new(ecc_chip.clone(), layouter.namespace(|| "delta"), self.delta)?;
+        let refreshed = rerandomize_commitment(
+            layouter.namespace(|| "rerandomize"),
+            ecc_chip,
+            &commitment,
+            delta,
+        )?;
+        // export C' to instance rows 2 and 3
+        layouter.constrain_instance(refreshed.inner().x().cell(), config.instance, 2)?;
+        layouter.constrain_instance(refreshed.inner().y().cell(), config.instance, 3)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*, crate::utils::commit::Opening, halo2_proofs::dev::MockProver, rand::rngs::OsRng,
+    };
+
+    #[test]
+    fn rerandomize_commitment() {
+        let opening = Opening::new(pallas::Base::from(88675409), Trapdoor::random(OsRng));
+        let delta = Trapdoor::random(OsRng);
+        let (circuit, instance) = RerandomizationCircuit::new(&opening.commit(), &delta).unwrap();
+        let prover = MockProver::run(11, &circuit, vec![instance.clone()]).unwrap();
+        prover.assert_satisfied();
+        // the refreshed commitment opens with the offset trapdoor
+        let refreshed = opening.rerandomize(&delta).commit().coordinates().unwrap();
+        assert_eq!((instance[2], instance[3]), refreshed);
+        // a refresh to an unrelated commitment fails
+        let other = Opening::new(opening.value, Trapdoor::random(OsRng)).commit();
+        let (x, y) = other.coordinates().unwrap();
+        let instance = vec![instance[0], instance[1], x, y];
+        let prover = MockProver::run(11, &circuit, vec![instance]).unwrap();
+        assert!(prover.verify().is_err());
+        // a zero offset leaves the commitment unchanged
+        let zero = Trapdoor::from(pallas::Scalar::zero());
+        let (circuit, instance) = RerandomizationCircuit::new(&opening.commit(), &zero).unwrap();
+        assert_eq!(instance[..2], instance[2..]);
+        let prover = MockProver::run(11, &circuit, vec![instance]).unwrap();
+        prover.assert_satisfied();
+    }
+}
@@ -0,0 +1,144 @@
+use {
+    super::{PedersenCircuitConfig, PedersenCommitmentCircuit},
+    crate::{chip::PedersenCommitmentChip, gadget::PedersenInstructions, utils::commit::Trapdoor},
+    halo2_proofs::{
+        circuit::{Layouter, SimpleFloorPlanner, Value},
+        pasta::pallas,
+        plonk::{Circuit, ConstraintSystem, Error},
+    },
+};
+
+/// Proves knowledge of a signed 64 bit value and trapdoor opening the short value commitment
+/// [±magnitude] BoardCommitQ + [trapdoor] BoardCommitR. The magnitude is decomposed into 22 three
+/// bit windows rather than the 85 of a base field element, so the commitment takes far fewer
+/// rows. The instance column holds the commitment (x, y).
+#[derive(Clone, Debug)]
+pub struct ShortCommitmentCircuit {
+    pub magnitude: Value<pallas::Base>,
+    pub sign: Value<pallas::Base>,
+    pub trapdoor: Value<pallas::Scalar>,
+}
+
+impl ShortCommitmentCircuit {
+    /**
+     * Build the witness for a short value commitment
+     *
+     * @param magnitude - the absolute value of the committed value
+     * @param negative - whether the committed value is negative
+     * @param trapdoor - the blinding factor of the commitment
+     * @return - the short commitment circuit
+     */
+    pub fn new(magnitude: u64, negative: bool, trapdoor: &Trapdoor) -> Self {
+        let sign = match negative {
+            true => -pallas::Base::one(),
+            false => pallas::Base::one(),
+        };
+        ShortCommitmentCircuit {
+            magnitude: Value::known(pallas::Base::from(magnitude)),
+            sign: Value::known(sign),
+            trapdoor: Value::known(trapdoor.inner()),
+        }
+    }
+}
+
+impl Circuit<pallas::Base> for ShortCommitmentCircuit {
+    type Config = PedersenCircuitConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        ShortCommitmentCircuit {
+            magnitude: Value::unknown(),
+            sign: Value::unknown(),
+            trapdoor: Value::unknown(),
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+        // same column layout as the single commitment circuit
+        PedersenCommitmentCircuit::configure(meta)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<pallas::Base>,
+    ) -> Result<(), Error> {
+        // load the lookup table & construct the chip
+        let loaded = PedersenCommitmentChip::load(&config.pedersen, &mut layouter)?;
+        let chip = PedersenCommitmentChip::new(config.pedersen.clone(), loaded);
+        // witness the magnitude and sign & synthesize the short commitment
+        let magnitude = chip.witness_value(layouter.namespace(|| "magnitude"), self.magnitude)?;
+        let sign = chip.witness_value(layouter.namespace(|| "sign"), self.sign)?;
+        let commitment = chip.synthesize_short(
+            layouter.namespace(|| "short pedersen"),
+            &magnitude,
+            &sign,
+            self.trapdoor,
+        )?;
+        // export the commitment to the instance column
+        chip.expose_public(
+            layouter.namespace(|| "expose"),
+            &commitment,
+            config.instance,
+            0,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            constants::fixed_bases::{board_commit_q, board_commit_r},
+            utils::{commit::derive_short_commitment, get_coordinates},
+        },
+        halo2_proofs::{dev::MockProver, pasta::group::Curve},
+        rand::rngs::OsRng,
+    };
+
+    // whether the circuit accepts the witness as an opening of the given commitment
+    fn prove_short(circuit: &ShortCommitmentCircuit, commitment: pallas::Point) -> bool {
+        let (x, y) = get_coordinates(commitment.to_affine());
+        let prover = MockProver::run(11, circuit, vec![vec![x, y]]).unwrap();
+        prover.verify().is_ok()
+    }
+
+    #[test]
+    fn short_commitment() {
+        for magnitude in [0, 100, u64::MAX] {
+            for negative in [false, true] {
+                let trapdoor = Trapdoor::random(OsRng);
+                let circuit = ShortCommitmentCircuit::new(magnitude, negative, &trapdoor);
+                let commitment = derive_short_commitment(magnitude, negative, &trapdoor.inner());
+                assert!(prove_short(&circuit, commitment));
+                // the sign is bound by the commitment of a non-zero value
+                let flipped = derive_short_commitment(magnitude, !negative, &trapdoor.inner());
+                assert_eq!(prove_short(&circuit, flipped), magnitude == 0);
+            }
+        }
+    }
+
+    #[test]
+    fn out_of_range() {
+        let trapdoor = Trapdoor::random(OsRng);
+        let (q, r) = (board_commit_q::generator(), board_commit_r::generator());
+        let blind = pallas::Point::from(r) * trapdoor.inner();
+        // magnitudes of 64 bits or more are rejected by the window decomposition
+        let mut circuit = ShortCommitmentCircuit::new(0, false, &trapdoor);
+        circuit.magnitude = Value::known(pallas::Base::from(u64::MAX) + pallas::Base::one());
+        let scalar = pallas::Scalar::from(u64::MAX) + pallas::Scalar::one();
+        assert!(!prove_short(
+            &circuit,
+            pallas::Point::from(q) * scalar + blind
+        ));
+        // the sign must be 1 or -1
+        let mut circuit = ShortCommitmentCircuit::new(100, false, &trapdoor);
+        circuit.sign = Value::known(pallas::Base::from(2));
+        let scalar = pallas::Scalar::from(200);
+        assert!(!prove_short(
+            &circuit,
+            pallas::Point::from(q) * scalar + blind
+        ));
+    }
+}
@@ -0,0 +1,164 @@
+use {
+    super::board::{BoardCircuit, BoardCircuitConfig},
+    crate::{
+        board::{chip::BoardChip, Board, Ship},
+        chip::PedersenCommitmentChip,
+        gadget::PedersenInstructions,
+        utils::commit::{Commitment, Trapdoor},
+    },
+    halo2_proofs::{
+        circuit::{Layouter, SimpleFloorPlanner, Value},
+        pasta::pallas,
+        plonk::{Circuit, ConstraintSystem, Error},
+    },
+};
+
+/// Proves a shot at a public (x, y) hits or misses the board behind a public board commitment.
+/// The instance column holds (C.x, C.y, x, y, hit). Placement rules are checked by BoardCircuit
+/// when the commitment is published, so only the cells of the opened board are recomputed here.
+#[derive(Clone, Debug)]
+pub struct ShotCircuit {
+    pub ships: Value<[Ship; 5]>,
+    pub trapdoor: Value<pallas::Scalar>,
+    pub shot: Value<(u8, u8)>,
+    pub hit: Value<bool>,
+}
+
+impl ShotCircuit {
+    /**
+     * Build the witness answering a shot against a committed board
+     *
+     * @param board - the committed board
+     * @param trapdoor - the blinding factor of the board commitment
+     * @param x - the column of the shot
+     * @param y - the row of the shot
+     * @return - the shot circuit, reporting Board::is_hit
+     */
+    pub fn new(board: &Board, trapdoor: &Trapdoor, x: u8, y: u8) -> Self {
+        ShotCircuit {
+            ships: Value::known(*board.ships()),
+            trapdoor: Value::known(trapdoor.inner()),
+            shot: Value::known((x, y)),
+            hit: Value::known(board.is_hit(x, y)),
+        }
+    }
+}
+
+/**
+ * Build the public inputs for ShotCircuit
+ *
+ * @param commitment - the board commitment
+ * @param x - the column of the shot
+ * @param y - the row of the shot
+ * @param hit - whether the shot was reported as a hit
+ * @return - instance column values (C.x, C.y, x, y, hit), or None if the commitment is the
+ *           identity
+ */
+pub fn shot_instance(
+    commitment: &Commitment,
+    x: u8,
+    y: u8,
+    hit: bool,
+) -> Option<Vec<pallas::Base>> {
+    let (commitment_x, commitment_y) = commitment.coordinates()?;
+    Some(vec![
+        commitment_x,
+        commitment_y,
+        pallas::Base::from(x as u64),
+        pallas::Base::from(y as u64),
+        pallas::Base::from(hit as u64),
+    ])
+}
+
+impl Circuit<pallas::Base> for ShotCircuit {
+    type Config = BoardCircuitConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        ShotCircuit {
+            ships: Value::unknown(),
+            trapdoor: Value::unknown(),
+            shot: Value::unknown(),
+            hit: Value::unknown(),
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+        BoardCircuit::configure_board(meta)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<pallas::Base>,
+    ) -> Result<(), Error> {
+        let instance = config.base.instance;
+        // load the lookup table used by the coordinate range checks and the ecc chip
+        let loaded = PedersenCommitmentChip::load(&config.base.pedersen, &mut layouter)?;
+        let pedersen = PedersenCommitmentChip::new(config.base.pedersen.clone(), loaded);
+        // open the board commitment
+        let board = BoardChip::construct(config.board.clone());
+        let ships = board.assign_ships(layouter.namespace(|| "ships"), self.ships)?;
+        let packed = board.pack(layouter.namespace(|| "pack"), &ships)?;
+        let commitment =
+            pedersen.synthesize(layouter.namespace(|| "pedersen"), &packed, self.trapdoor)?;
+        pedersen.expose_public(layouter.namespace(|| "expose"), &commitment, instance, 0)?;
+        // check the public shot against the ship cells
+        let shot = board.assign_shot(layouter.namespace(|| "shot"), self.shot)?;
+        let hit = board.assign_hit(layouter.namespace(|| "hit"), &ships, &shot, self.hit)?;
+        layouter.constrain_instance(shot.x.cell(), instance, 2)?;
+        layouter.constrain_instance(shot.y.cell(), instance, 3)?;
+        layouter.constrain_instance(hit.cell(), instance, 4)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, halo2_proofs::dev::MockProver, rand::rngs::OsRng};
+
+    fn board() -> Board {
+        Board::new([
+            Ship::new(3, 3, false),
+            Ship::new(5, 4, true),
+            Ship::new(0, 0, false),
+            Ship::new(0, 1, false),
+            Ship::new(9, 8, true),
+        ])
+        .unwrap()
+    }
+
+    // whether the circuit accepts the reported result of a shot
+    fn prove_shot(x: u8, y: u8, hit: bool) -> bool {
+        let board = board();
+        let opening = board.commit(Trapdoor::random(OsRng));
+        let mut circuit = ShotCircuit::new(&board, &opening.trapdoor, x, y);
+        circuit.hit = Value::known(hit);
+        let instance = shot_instance(&opening.commit(), x, y, hit).unwrap();
+        let prover = MockProver::run(11, &circuit, vec![instance]).unwrap();
+        prover.verify().is_ok()
+    }
+
+    #[test]
+    fn hits_and_misses() {
+        let board = board();
+        for (x, y) in [(3, 3), (5, 7), (9, 9), (8, 3), (4, 9)] {
+            let hit = board.is_hit(x, y);
+            assert!(prove_shot(x, y, hit));
+            // lying about the result is rejected
+            assert!(!prove_shot(x, y, !hit));
+        }
+    }
+
+    #[test]
+    fn off_board_shot() {
+        // (13, 2) has the grid index of the hit at (3, 3) and would otherwise alias it
+        let board = board();
+        let opening = board.commit(Trapdoor::random(OsRng));
+        let mut circuit = ShotCircuit::new(&board, &opening.trapdoor, 13, 2);
+        circuit.hit = Value::known(true);
+        let instance = shot_instance(&opening.commit(), 13, 2, true).unwrap();
+        let prover = MockProver::run(11, &circuit, vec![instance]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+}
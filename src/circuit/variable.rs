@@ -0,0 +1,190 @@
+use {
+    super::{PedersenCircuitConfig, PedersenCommitmentCircuit},
+    crate::{
+        chip::PedersenCommitmentChip,
+        gadget::{variable_pedersen_commitment, PedersenInstructions},
+    },
+    halo2_gadgets::ecc::NonIdentityPoint,
+    halo2_proofs::{
+        circuit::{Layouter, SimpleFloorPlanner, Value},
+        pasta::pallas,
+        plonk::{Circuit, ConstraintSystem, Error},
+    },
+};
+
+/// Proves knowledge of the opening of [message] V + [trapdoor] R under generators V and R that
+/// are only known at proving time, such as per-game generators chosen by a lobby. The instance
+/// column holds (V.x, V.y, R.x, R.y, C.x, C.y). Variable-base multiplication takes base field
+/// scalars, so the trapdoor is a base field element.
+#[derive(Clone, Debug)]
+pub struct VariableBaseCommitmentCircuit {
+    pub generators: Value<(pallas::Affine, pallas::Affine)>,
+    pub message: Value<pallas::Base>,
+    pub trapdoor: Value<pallas::Base>,
+}
+
+impl VariableBaseCommitmentCircuit {
+    /**
+     * Build the witness for a commitment under the given generators
+     *
+     * @param value_generator - the generator V the message is placed over
+     * @param blinding_generator - the generator R the trapdoor is placed over
+     * @param message - the committed value
+     * @param trapdoor - the blinding factor of the commitment
+     * @return - the variable-base commitment circuit
+     */
+    pub fn new(
+        value_generator: pallas::Affine,
+        blinding_generator: pallas::Affine,
+        message: pallas::Base,
+        trapdoor: pallas::Base,
+    ) -> Self {
+        VariableBaseCommitmentCircuit {
+            generators: Value::known((value_generator, blinding_generator)),
+            message: Value::known(message),
+            trapdoor: Value::known(trapdoor),
+        }
+    }
+}
+
+impl Circuit<pallas::Base> for VariableBaseCommitmentCircuit {
+    type Config = PedersenCircuitConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        VariableBaseCommitmentCircuit {
+            generators: Value::unknown(),
+            message: Value::unknown(),
+            trapdoor: Value::unknown(),
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+        // same column layout as the single commitment circuit
+        PedersenCommitmentCircuit::configure(meta)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<pallas::Base>,
+    ) -> Result<(), Error> {
+        // load the lookup table used by the ecc chip & construct the chip
+        let loaded = PedersenCommitmentChip::load(&config.pedersen, &mut layouter)?;
+        let chip = PedersenCommitmentChip::new(config.pedersen.clone(), loaded);
+        let ecc_chip = chip.ecc_chip();
+        // witness the generators and export them to the instance column
+        let value_generator = NonIdentityPoint::new(
+            ecc_chip.clone(),
+            layouter.namespace(|| "V"),
+            self.generators.map(|(v, _)| v),
+        )?;
+        let blinding_generator = NonIdentityPoint::new(
+            ecc_chip.clone(),
+            layouter.namespace(|| "R"),
+            self.generators.map(|(_, r)| r),
+        )?;
+        for (i, generator) in [&value_generator, &blinding_generator].iter().enumerate() {
+            let point = generator.inner();
+            layouter.constrain_instance(point.x().cell(), config.instance, 2 * i)?;
+            layouter.constrain_instance(point.y().cell(), config.instance, 2 * i + 1)?;
+        }
+        // witness private inputs & synthesize the variable-base commitment
+        let message = chip.witness_value(layouter.namespace(|| "message"), self.message)?;
+        let trapdoor = chip.witness_value(layouter.namespace(|| "trapdoor"), self.trapdoor)?;
+        let commitment = variable_pedersen_commitment(
+            layouter.namespace(|| "variable pedersen"),
+            ecc_chip,
+            &value_generator,
+            &blinding_generator,
+            message,
+            trapdoor,
+        )?;
+        chip.expose_public(
+            layouter.namespace(|| "expose"),
+            &commitment,
+            config.instance,
+            4,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            constants::{
+                fixed_bases::{board_commit_r, board_commit_v, domain::CommitmentDomain},
+                BOARD_COMMITMENT_PERSONALIZATION,
+            },
+            utils::{
+                commit::{derive_commitment, derive_variable_commitment},
+                get_coordinates,
+            },
+        },
+        halo2_proofs::{
+            arithmetic::{CurveExt, Field},
+            dev::MockProver,
+            pasta::group::{ff::PrimeField, Curve},
+        },
+        rand::rngs::OsRng,
+    };
+
+    // instance column values (V.x, V.y, R.x, R.y, C.x, C.y)
+    fn instance(
+        v: pallas::Affine,
+        r: pallas::Affine,
+        commitment: pallas::Point,
+    ) -> Vec<pallas::Base> {
+        let (vx, vy) = get_coordinates(v);
+        let (rx, ry) = get_coordinates(r);
+        let (x, y) = get_coordinates(commitment.to_affine());
+        vec![vx, vy, rx, ry, x, y]
+    }
+
+    #[test]
+    fn agrees_with_fixed_base() {
+        let (v, r) = (board_commit_v::generator(), board_commit_r::generator());
+        let message = pallas::Base::random(&mut OsRng);
+        let trapdoor = pallas::Base::random(&mut OsRng);
+        // under the fixed generators the variable-base commitment is the fixed-base commitment
+        let scalar = pallas::Scalar::from_repr(trapdoor.to_repr()).unwrap();
+        let commitment = derive_commitment(&CommitmentDomain::default(), &message, &scalar);
+        assert_eq!(
+            derive_variable_commitment(v, r, &message, &trapdoor),
+            commitment
+        );
+        let circuit = VariableBaseCommitmentCircuit::new(v, r, message, trapdoor);
+        let prover = MockProver::run(11, &circuit, vec![instance(v, r, commitment)]).unwrap();
+        prover.assert_satisfied();
+        // and the fixed-base circuit accepts the same commitment
+        let circuit = PedersenCommitmentCircuit {
+            message: Value::known(message),
+            trapdoor: Value::known(scalar),
+        };
+        let (x, y) = get_coordinates(commitment.to_affine());
+        let prover = MockProver::run(11, &circuit, vec![vec![x, y]]).unwrap();
+        prover.assert_satisfied();
+    }
+
+    #[test]
+    fn per_game_generators() {
+        let hasher = pallas::Point::hash_to_curve(BOARD_COMMITMENT_PERSONALIZATION);
+        let v = hasher(b"game 1 v").to_affine();
+        let r = hasher(b"game 1 r").to_affine();
+        let message = pallas::Base::from(88675409);
+        let trapdoor = pallas::Base::random(&mut OsRng);
+        let commitment = derive_variable_commitment(v, r, &message, &trapdoor);
+        let circuit = VariableBaseCommitmentCircuit::new(v, r, message, trapdoor);
+        let prover = MockProver::run(11, &circuit, vec![instance(v, r, commitment)]).unwrap();
+        prover.assert_satisfied();
+        // the commitment is bound to the public generators
+        let other = hasher(b"game 2 v").to_affine();
+        let prover = MockProver::run(11, &circuit, vec![instance(other, r, commitment)]).unwrap();
+        assert!(prover.verify().is_err());
+        let circuit = VariableBaseCommitmentCircuit::new(other, r, message, trapdoor);
+        let prover = MockProver::run(11, &circuit, vec![instance(other, r, commitment)]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+}
@@ -0,0 +1,128 @@
+//! Checks tying the checked-in fixed base tables to hash to curve, and the in-circuit
+//! commitment to the native derive_commitment.
+
+use {
+    crate::{
+        circuit::PedersenCommitmentCircuit,
+        constants::{
+            fixed_bases::{
+                domain::CommitmentDomain, BoardCommitQ, BoardCommitR, BoardCommitV, VectorCommitV,
+            },
+            vector_commitment_v_bytes, BOARD_COMMITMENT_PERSONALIZATION, BOARD_COMMITMENT_Q_BYTES,
+            BOARD_COMMITMENT_R_BYTES, BOARD_COMMITMENT_V_BYTES, NUM_WINDOWS,
+            VECTOR_COMMITMENT_SIZE,
+        },
+        utils::{commit::derive_commitment, get_coordinates},
+    },
+    halo2_gadgets::ecc::chip::{
+        constants::{test_zs_and_us, NUM_WINDOWS_SHORT},
+        FixedPoint,
+    },
+    halo2_proofs::{
+        arithmetic::{CurveAffine, CurveExt, Field},
+        circuit::Value,
+        dev::MockProver,
+        pasta::{
+            group::{ff::PrimeField, Curve},
+            pallas,
+        },
+    },
+    rand::rngs::OsRng,
+};
+
+// assert a fixed base uses the hash to curve generator and window tables valid for it
+// @dev checks z + y = u^2 and z - y non square for every window multiple rather than searching
+//      for the z-values again, which takes minutes per generator
+fn assert_tables<C: CurveAffine>(base: impl FixedPoint<C>, bytes: &[u8], num_windows: usize)
+where
+    C::Base: PrimeField<Repr = [u8; 32]>,
+{
+    assert_generator(base.clone(), bytes);
+    let (z, u) = (base.z(), base.u());
+    assert_eq!((z.len(), u.len()), (num_windows, num_windows));
+    test_zs_and_us(base.generator(), &z, &u, num_windows);
+}
+
+// assert a fixed base uses the generator selected by hash to curve
+fn assert_generator<C: CurveAffine>(base: impl FixedPoint<C>, bytes: &[u8]) {
+    let hasher = C::CurveExt::hash_to_curve(BOARD_COMMITMENT_PERSONALIZATION);
+    assert_eq!(base.generator(), hasher(bytes).to_affine());
+}
+
+// assert the in-circuit commitment to (message, trapdoor) equals derive_commitment
+fn assert_commitment(message: pallas::Base, trapdoor: pallas::Scalar) {
+    let (x, y) = get_coordinates(
+        derive_commitment(&CommitmentDomain::default(), &message, &trapdoor).to_affine(),
+    );
+    let circuit = PedersenCommitmentCircuit {
+        message: Value::known(message),
+        trapdoor: Value::known(trapdoor),
+    };
+    let prover = MockProver::run(11, &circuit, vec![vec![x, y]]).unwrap();
+    assert_eq!(prover.verify(), Ok(()));
+}
+
+#[test]
+fn board_commit_v_generator() {
+    assert_generator::<pallas::Affine>(BoardCommitV, &BOARD_COMMITMENT_V_BYTES);
+}
+
+#[test]
+fn board_commit_r_generator() {
+    assert_generator::<pallas::Affine>(BoardCommitR, &BOARD_COMMITMENT_R_BYTES);
+}
+
+#[test]
+fn board_commit_v_tables() {
+    assert_tables::<pallas::Affine>(BoardCommitV, &BOARD_COMMITMENT_V_BYTES, NUM_WINDOWS);
+}
+
+#[test]
+fn board_commit_r_tables() {
+    assert_tables::<pallas::Affine>(BoardCommitR, &BOARD_COMMITMENT_R_BYTES, NUM_WINDOWS);
+}
+
+#[test]
+fn board_commit_q_tables() {
+    assert_tables::<pallas::Affine>(BoardCommitQ, &BOARD_COMMITMENT_Q_BYTES, NUM_WINDOWS_SHORT);
+}
+
+#[test]
+fn vector_commit_v_tables() {
+    for index in 0..VECTOR_COMMITMENT_SIZE {
+        assert_tables(
+            VectorCommitV(index),
+            &vector_commitment_v_bytes(index),
+            NUM_WINDOWS,
+        );
+    }
+}
+
+#[test]
+fn native_commitment_uses_fixed_bases() {
+    let message = pallas::Base::random(&mut OsRng);
+    let trapdoor = pallas::Scalar::random(&mut OsRng);
+    let value = pallas::Scalar::from_repr(message.to_repr()).unwrap();
+    let v: pallas::Affine = BoardCommitV.generator();
+    let r: pallas::Affine = BoardCommitR.generator();
+    let expected = v * value + r * trapdoor;
+    assert_eq!(
+        derive_commitment(&CommitmentDomain::default(), &message, &trapdoor),
+        expected
+    );
+}
+
+#[test]
+fn circuit_commitment_matches_native() {
+    // random openings
+    for _ in 0..3 {
+        assert_commitment(
+            pallas::Base::random(&mut OsRng),
+            pallas::Scalar::random(&mut OsRng),
+        );
+    }
+    // edges of the base and scalar fields
+    assert_commitment(pallas::Base::zero(), pallas::Scalar::random(&mut OsRng));
+    assert_commitment(-pallas::Base::one(), pallas::Scalar::random(&mut OsRng));
+    assert_commitment(pallas::Base::random(&mut OsRng), -pallas::Scalar::one());
+}
@@ -13,4 +13,19 @@ pub const LOOKUP_SIZE: usize = 10;
 pub const BOARD_COMMITMENT_V_BYTES: [u8; 1] = *b"v";
 
 /// SWU hash-to-curve value for the board commitment generator
-pub const BOARD_COMMITMENT_R_BYTES: [u8; 1] = *b"r";
\ No newline at end of file
+pub const BOARD_COMMITMENT_R_BYTES: [u8; 1] = *b"r";
+
+/// SWU hash-to-curve value for the short value commitment generator
+pub const BOARD_COMMITMENT_Q_BYTES: [u8; 1] = *b"q";
+
+/// Number of values that can be bound by the vector board commitment
+/// @dev each generator carries its own precomputed fixed base table, so the cap is kept small;
+///      vector_pedersen_commitment and derive_vector_commitment reject longer vectors
+pub const VECTOR_COMMITMENT_SIZE: usize = 4;
+
+/// SWU hash-to-curve value for the i-th vector board commitment generator
+/// @dev the index is encoded in a single byte, so it must be below 256
+pub const fn vector_commitment_v_bytes(index: usize) -> [u8; 3] {
+    assert!(index < 256, "vector generator index does not fit in a byte");
+    [b'v', b'_', index as u8]
+}
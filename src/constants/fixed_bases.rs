@@ -1,14 +1,61 @@
 use {
+    super::{BOARD_COMMITMENT_PERSONALIZATION, BOARD_COMMITMENT_R_BYTES, BOARD_COMMITMENT_V_BYTES},
     halo2_gadgets::ecc::{
         chip::{constants::H, BaseFieldElem, FixedPoint, FullScalar, ShortScalar },
         FixedPoints,
     },
-    halo2_proofs::pasta::pallas,
+    halo2_proofs::{
+        arithmetic::{CurveAffine, CurveExt},
+        pasta::{group::Curve, pallas, vesta},
+    },
 };
 
 pub mod board_commit_v;
 pub mod board_commit_r;
+pub mod board_commit_q;
+pub mod domain;
+pub mod vector_commit_v;
+
+/// A curve of the Pasta cycle with board commitment generators for the native
+/// derive_curve_commitment. halo2_gadgets 0.2 only has a pallas ECC chip, so only pallas has
+/// fixed base tables and the commitment chip stays pallas-only. Vesta generators are derived
+/// with hash to curve on demand.
+pub trait BoardCurve: CurveAffine {
+    /// name of the curve module in halo2_proofs::pasta
+    const NAME: &'static str;
+
+    /// Returns the generator the committed value is placed over
+    fn value_generator() -> Self;
+
+    /// Returns the generator the trapdoor is placed over
+    fn blinding_generator() -> Self;
+}
+
+impl BoardCurve for pallas::Affine {
+    const NAME: &'static str = "pallas";
+
+    fn value_generator() -> Self {
+        board_commit_v::generator()
+    }
+
+    fn blinding_generator() -> Self {
+        board_commit_r::generator()
+    }
+}
 
+impl BoardCurve for vesta::Affine {
+    const NAME: &'static str = "vesta";
+
+    fn value_generator() -> Self {
+        vesta::Point::hash_to_curve(BOARD_COMMITMENT_PERSONALIZATION)(&BOARD_COMMITMENT_V_BYTES)
+            .to_affine()
+    }
+
+    fn blinding_generator() -> Self {
+        vesta::Point::hash_to_curve(BOARD_COMMITMENT_PERSONALIZATION)(&BOARD_COMMITMENT_R_BYTES)
+            .to_affine()
+    }
+}
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 // A sum type for both full-width and short bases. This enables us to use the
@@ -28,10 +75,24 @@ pub struct BoardCommitV;
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub struct BoardCommitR;
 
-/// VESTIGIAL / NOT NEEDED ASIDES FROM ITEM SIGNATURE FOR FIXED POINTS
+/// BoardCommitQ is used in scalar mul with a signed 64 bit short scalar.
+/// This is used for the short value commitment [±m] BoardCommitQ + [rcv] BoardCommitR
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub struct BoardCommitQ;
 
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+// Fixed bases for committing to a vector of base field elements. Each element is placed over
+// its own generator while the blinding factor reuses BoardCommitR.
+pub enum VectorFixedBases {
+    VectorCommitV(usize),
+    BoardCommitR,
+}
+
+/// VectorCommitV(i) is used in scalar mul with the i-th base field element of a vector.
+/// This is used for the witnessed board state binding commitment over many values
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct VectorCommitV(pub usize);
+
 impl FixedPoints<pallas::Affine> for BoardFixedBases {
     type Base = BoardCommitV;
     type FullScalar = BoardCommitR;
@@ -74,14 +135,36 @@ impl FixedPoint<pallas::Affine> for BoardCommitQ {
     type FixedScalarKind = ShortScalar;
 
     fn generator(&self) -> pallas::Affine {
-        board_commit_r::generator()
+        board_commit_q::generator()
     }
 
     fn u(&self) -> Vec<[[u8; 32]; H]> {
-        board_commit_r::U.to_vec()
+        board_commit_q::U_SHORT.to_vec()
     }
 
     fn z(&self) -> Vec<u64> {
-        board_commit_r::Z.to_vec()
+        board_commit_q::Z_SHORT.to_vec()
+    }
+}
+
+impl FixedPoints<pallas::Affine> for VectorFixedBases {
+    type Base = VectorCommitV;
+    type FullScalar = BoardCommitR;
+    type ShortScalar = BoardCommitQ;
+}
+
+impl FixedPoint<pallas::Affine> for VectorCommitV {
+    type FixedScalarKind = BaseFieldElem;
+
+    fn generator(&self) -> pallas::Affine {
+        vector_commit_v::generator(self.0)
+    }
+
+    fn u(&self) -> Vec<[[u8; 32]; H]> {
+        vector_commit_v::U[self.0].to_vec()
+    }
+
+    fn z(&self) -> Vec<u64> {
+        vector_commit_v::Z[self.0].to_vec()
     }
 }
@@ -0,0 +1,815 @@
+use halo2_gadgets::ecc::chip::constants::{H, NUM_WINDOWS_SHORT};
+use halo2_proofs::pasta::{arithmetic::CurveAffine, group::ff::PrimeField, pallas};
+
+/// The short value commitment places a signed 64 bit value over this generator, which only has
+/// tables for short scalars.
+pub const GENERATOR: ([u8; 32], [u8; 32]) = (
+    [
+        204, 18, 29, 186, 106, 142, 14, 5, 109, 131, 219, 213, 148, 90, 37, 236, 194, 225, 125,
+        186, 81, 146, 125, 36, 84, 118, 93, 2, 9, 188, 137, 48,
+    ],
+    [
+        91, 179, 41, 13, 120, 88, 55, 36, 126, 9, 95, 108, 240, 82, 171, 85, 78, 4, 157, 176, 25,
+        130, 4, 52, 2, 8, 135, 89, 120, 44, 59, 17,
+    ],
+);
+
+/// Short signed z-values for GENERATOR
+pub const Z_SHORT: [u64; NUM_WINDOWS_SHORT] = [
+    34133, 51927, 48883, 20901, 90219, 11032, 11122, 113809, 12846, 42037, 136351, 8818, 32996,
+    29212, 31400, 111349, 34874, 252425, 44120, 13566, 7256, 30,
+];
+
+// Short signed u-values for GENERATOR
+pub static U_SHORT: [[[u8; 32]; H]; NUM_WINDOWS_SHORT] = [
+    [
+        [
+            246, 221, 246, 198, 42, 179, 106, 64, 63, 102, 43, 106, 153, 169, 58, 59, 246, 12, 134,
+            142, 38, 142, 59, 187, 189, 163, 40, 181, 14, 253, 170, 59,
+        ],
+        [
+            218, 153, 209, 65, 95, 229, 15, 65, 150, 115, 203, 66, 185, 42, 105, 181, 101, 250,
+            122, 32, 98, 151, 55, 97, 3, 208, 41, 54, 9, 112, 173, 21,
+        ],
+        [
+            39, 10, 107, 148, 163, 165, 13, 31, 50, 118, 63, 32, 140, 242, 129, 214, 149, 225, 232,
+            159, 79, 114, 190, 161, 216, 149, 213, 102, 178, 12, 192, 49,
+        ],
+        [
+            75, 233, 26, 103, 72, 246, 11, 149, 89, 158, 22, 76, 44, 175, 213, 14, 33, 39, 209, 69,
+            203, 126, 95, 125, 95, 1, 29, 136, 17, 195, 5, 46,
+        ],
+        [
+            119, 172, 134, 148, 36, 156, 47, 43, 235, 149, 232, 193, 176, 162, 177, 172, 240, 108,
+            183, 107, 100, 187, 36, 177, 62, 76, 83, 245, 1, 208, 164, 53,
+        ],
+        [
+            122, 9, 101, 183, 137, 140, 19, 62, 153, 177, 223, 56, 106, 26, 47, 129, 254, 52, 215,
+            60, 146, 19, 127, 82, 105, 66, 177, 69, 83, 14, 239, 46,
+        ],
+        [
+            100, 173, 108, 36, 87, 166, 214, 125, 59, 102, 255, 153, 13, 6, 196, 93, 180, 35, 251,
+            1, 76, 244, 248, 6, 144, 174, 27, 5, 55, 43, 228, 54,
+        ],
+        [
+            13, 138, 211, 121, 186, 19, 73, 244, 11, 174, 210, 239, 209, 10, 228, 197, 168, 189,
+            27, 102, 248, 147, 23, 93, 188, 4, 158, 222, 232, 8, 189, 54,
+        ],
+    ],
+    [
+        [
+            63, 122, 7, 188, 26, 145, 198, 184, 208, 24, 54, 92, 85, 173, 46, 33, 135, 195, 80, 51,
+            205, 15, 170, 23, 47, 75, 83, 17, 139, 81, 137, 19,
+        ],
+        [
+            20, 174, 15, 248, 56, 106, 157, 27, 211, 179, 139, 173, 140, 183, 93, 81, 37, 206, 193,
+            102, 87, 0, 66, 182, 49, 118, 46, 181, 187, 58, 43, 26,
+        ],
+        [
+            204, 190, 127, 211, 174, 171, 169, 18, 98, 99, 21, 27, 58, 6, 47, 179, 66, 206, 161,
+            53, 19, 4, 80, 190, 116, 41, 180, 118, 114, 83, 190, 55,
+        ],
+        [
+            210, 213, 73, 254, 135, 237, 13, 21, 63, 74, 152, 165, 123, 172, 172, 79, 212, 57, 69,
+            121, 227, 190, 68, 154, 208, 43, 65, 185, 178, 248, 127, 14,
+        ],
+        [
+            185, 33, 150, 74, 69, 146, 223, 7, 201, 145, 124, 245, 41, 114, 56, 46, 171, 161, 160,
+            243, 197, 217, 77, 177, 218, 249, 82, 122, 60, 217, 171, 59,
+        ],
+        [
+            168, 90, 207, 60, 232, 230, 137, 241, 106, 195, 80, 52, 202, 69, 0, 26, 83, 195, 121,
+            247, 217, 102, 193, 148, 178, 225, 249, 30, 232, 168, 127, 34,
+        ],
+        [
+            20, 75, 149, 9, 9, 232, 109, 209, 41, 14, 35, 2, 203, 187, 96, 56, 134, 174, 190, 208,
+            209, 28, 241, 81, 78, 249, 228, 118, 252, 131, 66, 36,
+        ],
+        [
+            179, 200, 243, 209, 186, 120, 44, 86, 7, 190, 88, 26, 114, 30, 143, 214, 46, 151, 31,
+            18, 226, 209, 244, 215, 28, 54, 134, 39, 81, 78, 213, 22,
+        ],
+    ],
+    [
+        [
+            45, 80, 229, 160, 206, 196, 25, 98, 126, 25, 137, 185, 9, 23, 170, 238, 127, 179, 224,
+            17, 55, 27, 58, 251, 143, 119, 78, 92, 48, 2, 183, 15,
+        ],
+        [
+            253, 129, 240, 218, 154, 142, 156, 81, 132, 199, 112, 167, 64, 42, 156, 187, 230, 149,
+            115, 19, 176, 221, 58, 38, 97, 34, 161, 60, 188, 128, 122, 29,
+        ],
+        [
+            209, 13, 50, 41, 106, 14, 182, 239, 203, 191, 169, 111, 132, 0, 208, 179, 117, 188, 26,
+            153, 164, 37, 40, 214, 74, 201, 108, 19, 223, 202, 233, 0,
+        ],
+        [
+            139, 63, 185, 220, 159, 9, 92, 213, 40, 178, 242, 23, 100, 38, 203, 26, 191, 0, 48,
+            249, 97, 156, 138, 88, 137, 128, 39, 138, 179, 48, 164, 36,
+        ],
+        [
+            54, 81, 143, 59, 17, 5, 147, 33, 35, 46, 118, 97, 168, 76, 98, 41, 114, 44, 156, 98,
+            43, 64, 166, 133, 216, 230, 52, 192, 245, 66, 124, 41,
+        ],
+        [
+            182, 177, 60, 76, 2, 82, 41, 190, 150, 229, 145, 199, 227, 64, 18, 15, 199, 178, 93,
+            165, 205, 32, 123, 37, 201, 210, 120, 45, 134, 243, 102, 23,
+        ],
+        [
+            146, 102, 66, 110, 80, 30, 38, 108, 169, 3, 62, 137, 75, 154, 122, 220, 247, 79, 102,
+            47, 88, 113, 192, 102, 59, 220, 226, 190, 243, 178, 5, 27,
+        ],
+        [
+            91, 214, 191, 158, 31, 202, 92, 44, 162, 249, 251, 94, 141, 199, 208, 165, 63, 187,
+            157, 135, 209, 128, 213, 182, 158, 17, 66, 164, 83, 60, 64, 9,
+        ],
+    ],
+    [
+        [
+            137, 84, 69, 18, 125, 31, 212, 208, 160, 186, 233, 192, 79, 244, 47, 48, 211, 220, 18,
+            54, 23, 48, 186, 124, 223, 16, 9, 75, 24, 123, 192, 41,
+        ],
+        [
+            79, 238, 115, 231, 233, 180, 224, 72, 61, 148, 234, 245, 249, 253, 238, 110, 190, 237,
+            97, 143, 223, 136, 51, 90, 51, 200, 179, 238, 214, 112, 204, 54,
+        ],
+        [
+            224, 36, 4, 149, 23, 189, 17, 177, 100, 120, 155, 165, 230, 247, 148, 100, 246, 191,
+            163, 48, 106, 252, 107, 39, 197, 73, 94, 205, 86, 44, 246, 13,
+        ],
+        [
+            154, 43, 32, 36, 180, 187, 195, 238, 186, 192, 103, 78, 187, 0, 120, 35, 241, 8, 78,
+            184, 55, 92, 141, 219, 22, 81, 216, 58, 204, 126, 250, 60,
+        ],
+        [
+            249, 6, 199, 186, 181, 64, 153, 179, 226, 166, 167, 246, 200, 165, 175, 68, 225, 210,
+            137, 185, 162, 152, 179, 203, 110, 196, 53, 118, 106, 240, 183, 59,
+        ],
+        [
+            216, 3, 95, 134, 93, 117, 139, 170, 211, 74, 185, 113, 39, 244, 1, 100, 191, 120, 123,
+            254, 170, 254, 195, 164, 201, 1, 249, 120, 27, 182, 195, 27,
+        ],
+        [
+            36, 6, 215, 7, 135, 168, 162, 86, 28, 41, 6, 171, 164, 0, 155, 92, 78, 74, 182, 219,
+            99, 188, 37, 103, 59, 207, 51, 200, 139, 132, 243, 20,
+        ],
+        [
+            140, 179, 230, 207, 178, 178, 61, 248, 78, 178, 170, 237, 3, 106, 80, 249, 59, 111,
+            107, 75, 157, 116, 237, 143, 76, 149, 203, 105, 51, 70, 23, 63,
+        ],
+    ],
+    [
+        [
+            201, 107, 119, 135, 217, 11, 50, 254, 231, 47, 74, 118, 90, 102, 226, 102, 92, 65, 157,
+            76, 49, 16, 239, 178, 4, 90, 172, 203, 190, 10, 216, 14,
+        ],
+        [
+            204, 247, 172, 186, 247, 187, 155, 186, 93, 141, 206, 27, 13, 101, 169, 210, 113, 53,
+            164, 159, 135, 146, 114, 3, 231, 36, 191, 50, 150, 145, 3, 55,
+        ],
+        [
+            146, 189, 70, 126, 132, 78, 176, 131, 51, 124, 104, 8, 181, 60, 235, 148, 242, 109, 70,
+            248, 30, 81, 222, 91, 198, 26, 136, 202, 181, 199, 8, 34,
+        ],
+        [
+            110, 118, 36, 180, 225, 128, 19, 214, 112, 149, 55, 123, 46, 205, 184, 5, 81, 205, 221,
+            220, 34, 59, 136, 163, 27, 155, 4, 214, 209, 3, 104, 32,
+        ],
+        [
+            63, 227, 140, 63, 98, 147, 177, 101, 130, 105, 82, 155, 110, 169, 132, 74, 185, 169,
+            156, 248, 121, 81, 140, 24, 204, 82, 12, 228, 86, 234, 121, 11,
+        ],
+        [
+            187, 47, 247, 185, 185, 202, 179, 110, 251, 9, 234, 186, 152, 1, 120, 173, 91, 33, 249,
+            63, 38, 133, 94, 47, 186, 176, 60, 139, 43, 57, 129, 24,
+        ],
+        [
+            106, 17, 241, 65, 196, 11, 146, 69, 100, 98, 204, 79, 184, 67, 13, 206, 213, 87, 16,
+            40, 117, 159, 75, 103, 154, 144, 65, 160, 64, 141, 152, 48,
+        ],
+        [
+            126, 52, 106, 131, 237, 171, 2, 161, 181, 210, 137, 86, 172, 171, 203, 134, 69, 45, 99,
+            34, 117, 12, 210, 80, 142, 147, 149, 102, 218, 86, 128, 1,
+        ],
+    ],
+    [
+        [
+            172, 211, 147, 194, 59, 24, 134, 196, 186, 138, 210, 240, 108, 117, 63, 137, 29, 83,
+            135, 167, 102, 36, 164, 55, 107, 144, 29, 148, 25, 121, 225, 31,
+        ],
+        [
+            252, 162, 7, 245, 31, 206, 13, 199, 170, 23, 142, 43, 57, 185, 1, 121, 217, 193, 51,
+            160, 249, 243, 138, 246, 8, 38, 50, 33, 23, 17, 145, 23,
+        ],
+        [
+            214, 40, 119, 21, 44, 154, 198, 109, 59, 72, 216, 232, 184, 109, 239, 143, 233, 255,
+            199, 152, 87, 35, 243, 52, 173, 190, 203, 190, 76, 63, 255, 25,
+        ],
+        [
+            241, 23, 203, 41, 9, 176, 107, 85, 101, 94, 146, 195, 244, 238, 115, 37, 190, 41, 231,
+            232, 230, 160, 175, 25, 125, 236, 255, 117, 223, 97, 148, 48,
+        ],
+        [
+            66, 129, 24, 123, 28, 14, 146, 245, 89, 143, 221, 109, 197, 208, 126, 164, 244, 4, 73,
+            111, 240, 96, 21, 244, 111, 37, 19, 173, 47, 24, 108, 38,
+        ],
+        [
+            114, 89, 32, 43, 232, 214, 251, 6, 249, 179, 225, 102, 55, 75, 119, 77, 177, 3, 123,
+            138, 43, 63, 218, 106, 64, 64, 222, 52, 177, 117, 227, 1,
+        ],
+        [
+            110, 39, 185, 110, 40, 170, 75, 106, 203, 204, 96, 105, 188, 26, 145, 10, 114, 241, 92,
+            192, 137, 162, 115, 185, 244, 192, 255, 125, 162, 103, 226, 1,
+        ],
+        [
+            215, 204, 85, 80, 115, 104, 19, 197, 233, 238, 178, 229, 139, 33, 164, 126, 97, 12, 66,
+            220, 149, 43, 63, 78, 108, 221, 22, 19, 188, 21, 235, 24,
+        ],
+    ],
+    [
+        [
+            211, 6, 192, 255, 47, 223, 179, 236, 148, 166, 7, 191, 163, 98, 252, 61, 46, 133, 220,
+            254, 111, 24, 244, 219, 101, 38, 137, 237, 62, 195, 157, 13,
+        ],
+        [
+            249, 11, 74, 12, 216, 69, 6, 112, 184, 252, 246, 155, 138, 43, 42, 96, 48, 124, 27, 44,
+            10, 127, 116, 239, 79, 10, 98, 188, 124, 134, 133, 60,
+        ],
+        [
+            117, 92, 37, 124, 241, 58, 254, 196, 165, 80, 58, 59, 247, 107, 3, 60, 205, 230, 60,
+            241, 2, 21, 4, 224, 6, 43, 224, 30, 72, 253, 150, 19,
+        ],
+        [
+            110, 84, 235, 211, 87, 4, 79, 129, 51, 112, 180, 1, 191, 65, 209, 12, 155, 7, 77, 163,
+            21, 247, 31, 130, 227, 238, 4, 227, 90, 141, 77, 60,
+        ],
+        [
+            167, 237, 215, 86, 190, 184, 217, 108, 73, 150, 144, 36, 226, 97, 125, 14, 43, 155, 20,
+            42, 43, 249, 135, 24, 24, 23, 223, 186, 229, 166, 97, 4,
+        ],
+        [
+            207, 2, 42, 217, 198, 52, 187, 61, 70, 22, 28, 60, 77, 42, 36, 212, 136, 194, 42, 246,
+            62, 39, 137, 178, 118, 189, 211, 244, 121, 56, 199, 14,
+        ],
+        [
+            54, 165, 217, 48, 63, 145, 218, 98, 172, 134, 42, 183, 128, 170, 251, 1, 51, 9, 53,
+            158, 130, 143, 15, 237, 159, 229, 48, 28, 144, 126, 197, 16,
+        ],
+        [
+            3, 199, 174, 133, 221, 39, 71, 223, 120, 255, 212, 244, 142, 17, 29, 198, 8, 5, 7, 76,
+            61, 20, 106, 246, 146, 152, 128, 148, 79, 199, 115, 45,
+        ],
+    ],
+    [
+        [
+            80, 177, 70, 37, 158, 111, 237, 52, 88, 253, 117, 230, 110, 113, 67, 115, 143, 2, 191,
+            16, 165, 106, 193, 147, 15, 180, 172, 146, 93, 66, 202, 10,
+        ],
+        [
+            205, 49, 255, 129, 202, 162, 74, 178, 189, 26, 0, 186, 225, 157, 19, 54, 230, 174, 87,
+            39, 176, 103, 39, 255, 57, 35, 134, 81, 115, 52, 52, 57,
+        ],
+        [
+            156, 6, 189, 210, 210, 189, 28, 48, 34, 22, 143, 16, 248, 215, 82, 138, 187, 3, 190,
+            37, 169, 142, 156, 160, 201, 206, 4, 81, 106, 180, 34, 32,
+        ],
+        [
+            77, 100, 61, 119, 19, 225, 80, 27, 41, 93, 102, 166, 103, 98, 181, 30, 82, 245, 178,
+            247, 75, 183, 252, 33, 3, 47, 102, 97, 225, 81, 222, 3,
+        ],
+        [
+            147, 183, 47, 35, 161, 76, 226, 149, 210, 248, 104, 66, 29, 237, 56, 227, 55, 201, 216,
+            128, 250, 177, 31, 170, 96, 199, 144, 204, 106, 72, 130, 10,
+        ],
+        [
+            104, 152, 191, 55, 21, 147, 147, 60, 124, 25, 192, 169, 145, 35, 182, 13, 132, 179,
+            105, 250, 37, 31, 167, 125, 245, 44, 23, 238, 29, 139, 126, 10,
+        ],
+        [
+            91, 203, 8, 179, 43, 58, 134, 100, 95, 185, 151, 212, 156, 203, 182, 32, 72, 81, 216,
+            51, 30, 235, 53, 63, 116, 251, 171, 249, 123, 193, 120, 50,
+        ],
+        [
+            115, 50, 139, 163, 206, 222, 254, 37, 74, 250, 137, 143, 26, 79, 40, 88, 203, 241, 232,
+            183, 116, 181, 12, 11, 117, 197, 71, 249, 76, 243, 148, 44,
+        ],
+    ],
+    [
+        [
+            115, 19, 199, 172, 194, 101, 141, 198, 21, 222, 94, 42, 55, 64, 221, 182, 15, 211, 99,
+            84, 111, 217, 238, 242, 33, 62, 60, 146, 204, 14, 85, 7,
+        ],
+        [
+            81, 223, 129, 200, 196, 243, 26, 187, 3, 70, 6, 165, 15, 171, 47, 237, 153, 8, 219,
+            194, 213, 7, 238, 93, 229, 173, 53, 46, 82, 205, 32, 29,
+        ],
+        [
+            23, 247, 130, 67, 59, 103, 166, 119, 78, 22, 189, 204, 71, 170, 19, 212, 32, 188, 127,
+            71, 109, 186, 114, 75, 206, 108, 85, 7, 249, 71, 3, 0,
+        ],
+        [
+            204, 50, 160, 88, 221, 189, 137, 205, 214, 117, 141, 200, 177, 172, 205, 53, 35, 162,
+            211, 6, 237, 13, 247, 174, 46, 79, 214, 57, 184, 227, 94, 20,
+        ],
+        [
+            232, 156, 61, 151, 253, 169, 217, 149, 24, 73, 16, 21, 216, 220, 223, 20, 20, 127, 90,
+            2, 146, 214, 9, 177, 183, 27, 88, 138, 191, 27, 234, 33,
+        ],
+        [
+            192, 139, 22, 58, 216, 86, 91, 155, 145, 197, 135, 237, 71, 141, 194, 199, 2, 238, 27,
+            243, 65, 35, 183, 38, 102, 37, 51, 106, 166, 186, 203, 10,
+        ],
+        [
+            30, 251, 8, 24, 72, 75, 159, 33, 245, 127, 247, 192, 102, 98, 92, 184, 210, 47, 51, 61,
+            195, 98, 150, 72, 173, 77, 94, 150, 123, 213, 129, 51,
+        ],
+        [
+            229, 87, 117, 245, 62, 79, 163, 185, 116, 147, 238, 75, 139, 59, 87, 74, 118, 229, 136,
+            183, 194, 18, 157, 31, 50, 233, 24, 141, 213, 57, 93, 53,
+        ],
+    ],
+    [
+        [
+            21, 135, 228, 122, 92, 62, 195, 134, 160, 248, 45, 137, 190, 217, 204, 105, 8, 247,
+            122, 210, 35, 247, 191, 144, 128, 62, 85, 123, 82, 218, 104, 47,
+        ],
+        [
+            129, 162, 184, 125, 84, 240, 250, 216, 128, 65, 198, 126, 108, 97, 189, 204, 180, 22,
+            40, 153, 236, 168, 197, 179, 100, 76, 237, 61, 199, 76, 65, 2,
+        ],
+        [
+            219, 46, 206, 238, 162, 128, 239, 255, 220, 13, 25, 232, 85, 8, 37, 206, 220, 247, 94,
+            124, 251, 28, 167, 16, 225, 212, 206, 145, 92, 140, 243, 37,
+        ],
+        [
+            74, 245, 186, 250, 188, 82, 76, 101, 253, 248, 202, 68, 79, 180, 161, 152, 154, 150,
+            138, 242, 60, 96, 131, 207, 198, 224, 176, 122, 225, 26, 58, 48,
+        ],
+        [
+            170, 235, 99, 15, 187, 245, 162, 226, 21, 8, 61, 155, 101, 44, 237, 4, 96, 2, 138, 234,
+            105, 99, 115, 139, 101, 228, 229, 181, 29, 147, 72, 57,
+        ],
+        [
+            39, 249, 5, 5, 100, 77, 150, 44, 65, 8, 202, 141, 226, 214, 67, 235, 174, 220, 158,
+            131, 3, 255, 223, 164, 140, 70, 172, 59, 153, 48, 146, 43,
+        ],
+        [
+            66, 215, 113, 93, 174, 139, 55, 175, 80, 111, 190, 170, 38, 156, 125, 237, 131, 12,
+            103, 133, 96, 241, 204, 202, 250, 26, 59, 135, 233, 233, 149, 23,
+        ],
+        [
+            135, 143, 115, 119, 94, 7, 181, 53, 217, 203, 71, 221, 4, 183, 107, 7, 175, 145, 242,
+            9, 255, 127, 95, 75, 116, 242, 130, 6, 68, 227, 20, 43,
+        ],
+    ],
+    [
+        [
+            68, 145, 111, 91, 24, 244, 168, 118, 6, 176, 96, 122, 126, 222, 198, 72, 233, 179, 217,
+            225, 64, 193, 203, 224, 88, 9, 25, 53, 151, 160, 28, 34,
+        ],
+        [
+            163, 208, 198, 194, 128, 155, 222, 239, 112, 69, 46, 174, 114, 96, 41, 191, 241, 84,
+            109, 165, 163, 141, 114, 171, 243, 62, 227, 48, 122, 131, 103, 40,
+        ],
+        [
+            106, 3, 151, 49, 174, 53, 247, 19, 158, 194, 223, 229, 121, 2, 90, 209, 100, 76, 199,
+            13, 225, 230, 131, 236, 18, 252, 208, 175, 238, 150, 67, 27,
+        ],
+        [
+            209, 165, 57, 224, 17, 88, 210, 66, 132, 233, 197, 54, 113, 99, 43, 71, 222, 10, 25,
+            72, 186, 45, 224, 25, 218, 255, 168, 109, 176, 137, 48, 38,
+        ],
+        [
+            20, 73, 62, 191, 133, 222, 115, 215, 180, 96, 195, 62, 125, 239, 73, 223, 36, 222, 2,
+            106, 196, 244, 221, 54, 37, 14, 254, 236, 174, 122, 37, 5,
+        ],
+        [
+            139, 26, 173, 168, 205, 126, 209, 120, 11, 255, 99, 244, 160, 198, 65, 105, 11, 165,
+            91, 230, 226, 22, 17, 69, 59, 183, 229, 82, 247, 146, 32, 30,
+        ],
+        [
+            201, 57, 31, 138, 49, 148, 99, 10, 7, 110, 207, 36, 158, 94, 224, 81, 39, 69, 122, 3,
+            54, 200, 219, 156, 246, 161, 146, 73, 255, 192, 122, 2,
+        ],
+        [
+            63, 154, 210, 182, 103, 166, 61, 181, 245, 88, 194, 238, 15, 190, 239, 225, 145, 11,
+            244, 13, 87, 111, 24, 56, 237, 32, 186, 6, 62, 118, 147, 15,
+        ],
+    ],
+    [
+        [
+            37, 72, 167, 90, 224, 238, 63, 13, 112, 1, 174, 70, 48, 64, 172, 221, 209, 26, 98, 158,
+            196, 60, 90, 17, 212, 83, 238, 198, 230, 80, 106, 46,
+        ],
+        [
+            125, 202, 243, 78, 223, 244, 155, 124, 164, 252, 66, 50, 54, 34, 130, 36, 172, 23, 98,
+            58, 160, 231, 128, 255, 112, 62, 192, 235, 86, 150, 136, 2,
+        ],
+        [
+            216, 184, 246, 175, 103, 168, 153, 69, 230, 5, 67, 153, 201, 251, 115, 33, 65, 235,
+            146, 88, 173, 235, 27, 226, 18, 52, 176, 127, 179, 173, 149, 59,
+        ],
+        [
+            66, 154, 3, 23, 10, 199, 75, 163, 228, 97, 253, 32, 83, 223, 69, 165, 215, 98, 98, 218,
+            59, 136, 50, 43, 132, 231, 52, 133, 238, 107, 153, 57,
+        ],
+        [
+            74, 223, 128, 140, 139, 97, 195, 219, 86, 56, 103, 114, 179, 75, 110, 33, 167, 208, 6,
+            217, 220, 171, 255, 76, 95, 207, 46, 223, 1, 56, 65, 23,
+        ],
+        [
+            164, 78, 1, 90, 189, 4, 179, 214, 95, 82, 222, 44, 80, 58, 101, 233, 41, 182, 150, 13,
+            153, 145, 216, 104, 19, 244, 36, 202, 248, 62, 150, 3,
+        ],
+        [
+            65, 226, 83, 102, 86, 204, 56, 87, 80, 5, 128, 169, 152, 69, 120, 79, 175, 87, 154, 15,
+            250, 126, 79, 118, 1, 40, 151, 204, 55, 210, 32, 48,
+        ],
+        [
+            23, 143, 62, 113, 196, 35, 73, 231, 120, 228, 209, 120, 136, 116, 97, 164, 166, 96,
+            210, 236, 143, 229, 101, 134, 98, 1, 83, 67, 21, 66, 217, 63,
+        ],
+    ],
+    [
+        [
+            13, 103, 129, 80, 230, 158, 46, 89, 57, 198, 131, 22, 246, 137, 203, 38, 187, 191, 7,
+            215, 219, 157, 111, 25, 49, 145, 198, 77, 89, 158, 192, 51,
+        ],
+        [
+            25, 99, 159, 168, 54, 123, 78, 57, 209, 188, 170, 209, 79, 125, 40, 114, 96, 126, 85,
+            82, 113, 14, 101, 255, 102, 215, 247, 239, 241, 180, 5, 61,
+        ],
+        [
+            96, 23, 136, 62, 22, 65, 23, 5, 177, 81, 60, 109, 54, 7, 123, 183, 171, 106, 98, 12,
+            10, 88, 152, 192, 162, 53, 134, 175, 27, 64, 32, 32,
+        ],
+        [
+            116, 89, 200, 162, 155, 24, 191, 115, 198, 183, 10, 43, 50, 139, 110, 221, 82, 129,
+            194, 95, 242, 17, 196, 156, 150, 43, 187, 230, 100, 137, 72, 53,
+        ],
+        [
+            70, 216, 129, 107, 23, 115, 126, 186, 218, 123, 173, 143, 42, 137, 168, 66, 83, 54,
+            223, 61, 38, 116, 108, 235, 8, 117, 221, 178, 192, 244, 46, 37,
+        ],
+        [
+            107, 111, 117, 18, 61, 39, 14, 135, 214, 133, 186, 81, 211, 17, 68, 192, 205, 194, 122,
+            252, 86, 40, 120, 224, 140, 36, 74, 140, 154, 191, 133, 27,
+        ],
+        [
+            229, 73, 136, 202, 139, 34, 109, 186, 132, 157, 162, 46, 187, 196, 198, 153, 82, 230,
+            115, 157, 56, 211, 36, 72, 118, 144, 37, 118, 207, 151, 132, 34,
+        ],
+        [
+            87, 16, 123, 47, 233, 57, 179, 27, 134, 119, 41, 80, 83, 60, 198, 140, 53, 121, 241,
+            136, 97, 164, 197, 33, 17, 171, 57, 77, 170, 66, 89, 23,
+        ],
+    ],
+    [
+        [
+            76, 187, 219, 103, 50, 138, 70, 21, 82, 189, 114, 55, 199, 162, 16, 65, 196, 243, 124,
+            251, 168, 245, 117, 126, 120, 205, 35, 152, 101, 25, 28, 30,
+        ],
+        [
+            125, 189, 156, 34, 222, 166, 59, 139, 26, 154, 236, 22, 188, 121, 246, 191, 142, 208,
+            0, 175, 100, 206, 25, 105, 238, 97, 140, 81, 176, 82, 193, 62,
+        ],
+        [
+            8, 54, 49, 195, 154, 251, 243, 73, 145, 158, 13, 130, 72, 171, 4, 11, 114, 228, 21,
+            217, 117, 121, 16, 144, 88, 221, 196, 91, 97, 214, 14, 42,
+        ],
+        [
+            50, 176, 137, 77, 2, 113, 156, 164, 99, 94, 79, 171, 174, 91, 148, 80, 207, 43, 147,
+            174, 82, 228, 128, 2, 62, 168, 107, 124, 174, 74, 65, 36,
+        ],
+        [
+            70, 20, 52, 204, 34, 35, 34, 10, 170, 246, 156, 3, 238, 25, 30, 226, 203, 59, 104, 8,
+            113, 94, 138, 100, 120, 82, 37, 186, 178, 87, 101, 10,
+        ],
+        [
+            107, 67, 213, 154, 143, 185, 34, 131, 97, 59, 91, 111, 7, 131, 82, 194, 252, 121, 11,
+            25, 40, 209, 56, 164, 160, 8, 118, 155, 42, 209, 20, 37,
+        ],
+        [
+            193, 177, 196, 13, 157, 111, 51, 233, 193, 169, 161, 228, 52, 227, 209, 6, 207, 143,
+            52, 129, 92, 64, 184, 230, 8, 134, 232, 198, 14, 201, 229, 22,
+        ],
+        [
+            136, 94, 193, 161, 178, 124, 63, 101, 41, 161, 33, 235, 35, 61, 206, 89, 116, 211, 59,
+            164, 193, 59, 203, 160, 147, 122, 228, 2, 205, 218, 56, 25,
+        ],
+    ],
+    [
+        [
+            50, 51, 144, 190, 236, 197, 67, 143, 241, 173, 39, 95, 99, 227, 75, 149, 232, 174, 209,
+            97, 255, 251, 70, 87, 142, 34, 228, 145, 164, 118, 179, 34,
+        ],
+        [
+            87, 52, 42, 102, 45, 193, 110, 187, 69, 74, 29, 115, 126, 135, 9, 224, 217, 77, 197,
+            202, 75, 203, 239, 115, 158, 84, 207, 117, 48, 0, 250, 53,
+        ],
+        [
+            227, 39, 82, 125, 132, 160, 71, 53, 97, 254, 242, 179, 79, 126, 80, 108, 219, 27, 241,
+            132, 244, 206, 180, 176, 61, 199, 117, 172, 133, 106, 172, 44,
+        ],
+        [
+            225, 223, 126, 213, 117, 155, 213, 215, 77, 183, 223, 159, 47, 38, 78, 234, 117, 57,
+            134, 120, 241, 255, 55, 212, 61, 21, 173, 68, 47, 237, 234, 32,
+        ],
+        [
+            33, 63, 68, 2, 234, 251, 255, 181, 189, 98, 61, 169, 31, 223, 241, 233, 92, 63, 143,
+            216, 194, 92, 243, 222, 142, 181, 36, 119, 141, 242, 39, 45,
+        ],
+        [
+            188, 77, 62, 85, 112, 236, 102, 66, 193, 14, 249, 80, 176, 94, 65, 41, 183, 70, 109,
+            137, 75, 60, 51, 236, 235, 180, 183, 62, 171, 170, 233, 23,
+        ],
+        [
+            178, 146, 185, 175, 170, 154, 102, 236, 190, 76, 161, 192, 84, 48, 79, 166, 76, 172,
+            156, 216, 107, 113, 9, 175, 131, 107, 147, 182, 17, 16, 15, 6,
+        ],
+        [
+            146, 185, 71, 72, 72, 35, 154, 82, 100, 214, 216, 77, 204, 88, 6, 167, 83, 114, 124,
+            226, 75, 104, 89, 107, 33, 78, 30, 150, 10, 191, 185, 5,
+        ],
+    ],
+    [
+        [
+            209, 8, 222, 106, 84, 19, 146, 125, 163, 135, 144, 86, 73, 87, 0, 50, 244, 36, 101,
+            212, 105, 174, 185, 244, 149, 83, 196, 199, 89, 90, 27, 25,
+        ],
+        [
+            190, 151, 48, 12, 21, 255, 15, 117, 236, 174, 204, 128, 28, 242, 213, 250, 158, 26,
+            133, 141, 255, 164, 85, 150, 45, 41, 10, 11, 172, 48, 169, 15,
+        ],
+        [
+            40, 33, 186, 162, 90, 17, 203, 212, 109, 28, 127, 101, 187, 169, 200, 167, 119, 84, 76,
+            209, 145, 66, 112, 66, 208, 172, 80, 8, 32, 14, 91, 16,
+        ],
+        [
+            254, 117, 120, 40, 34, 139, 5, 23, 225, 5, 237, 252, 8, 79, 49, 107, 62, 191, 100, 80,
+            102, 93, 66, 248, 77, 43, 38, 220, 151, 109, 117, 0,
+        ],
+        [
+            24, 141, 17, 254, 126, 163, 32, 204, 116, 88, 245, 190, 167, 222, 124, 147, 235, 147,
+            72, 208, 163, 26, 45, 51, 171, 69, 24, 33, 96, 30, 72, 55,
+        ],
+        [
+            60, 149, 133, 131, 243, 54, 241, 190, 87, 117, 8, 234, 167, 228, 161, 216, 207, 201,
+            156, 24, 161, 169, 226, 84, 102, 85, 2, 72, 194, 178, 168, 52,
+        ],
+        [
+            135, 94, 141, 169, 183, 35, 112, 172, 203, 201, 140, 190, 166, 181, 41, 131, 212, 32,
+            241, 69, 17, 85, 39, 100, 227, 88, 21, 227, 157, 32, 96, 27,
+        ],
+        [
+            18, 190, 248, 58, 216, 153, 174, 241, 252, 77, 86, 134, 238, 116, 99, 178, 171, 92,
+            141, 236, 62, 35, 44, 107, 117, 130, 49, 239, 135, 57, 142, 53,
+        ],
+    ],
+    [
+        [
+            12, 29, 94, 173, 238, 120, 239, 57, 148, 132, 28, 112, 208, 138, 158, 198, 169, 203,
+            13, 246, 116, 28, 216, 168, 193, 5, 234, 10, 93, 126, 80, 61,
+        ],
+        [
+            92, 45, 160, 103, 28, 96, 77, 57, 177, 204, 11, 86, 99, 2, 231, 51, 62, 36, 135, 99,
+            25, 111, 122, 55, 160, 5, 77, 101, 76, 77, 153, 19,
+        ],
+        [
+            50, 254, 9, 71, 10, 155, 207, 247, 101, 12, 223, 227, 238, 73, 84, 117, 74, 146, 56,
+            245, 235, 194, 105, 189, 72, 100, 33, 106, 45, 56, 76, 56,
+        ],
+        [
+            158, 154, 227, 20, 163, 166, 151, 229, 26, 128, 204, 155, 156, 17, 240, 80, 46, 119,
+            99, 201, 176, 246, 199, 161, 11, 162, 87, 249, 211, 182, 30, 9,
+        ],
+        [
+            2, 134, 123, 14, 188, 156, 249, 35, 74, 112, 255, 12, 136, 73, 189, 177, 123, 67, 236,
+            249, 224, 133, 119, 111, 155, 94, 7, 123, 87, 49, 5, 22,
+        ],
+        [
+            9, 166, 74, 18, 173, 17, 131, 58, 212, 252, 87, 144, 63, 176, 11, 183, 176, 148, 159,
+            191, 117, 37, 81, 77, 9, 216, 150, 137, 248, 13, 243, 42,
+        ],
+        [
+            228, 226, 76, 104, 109, 18, 81, 143, 64, 12, 160, 20, 55, 140, 217, 198, 108, 183, 60,
+            7, 42, 241, 0, 84, 115, 157, 25, 19, 48, 122, 208, 39,
+        ],
+        [
+            222, 137, 61, 103, 206, 64, 98, 19, 221, 17, 246, 109, 214, 224, 53, 128, 46, 79, 136,
+            37, 148, 17, 56, 237, 42, 77, 12, 241, 42, 211, 91, 60,
+        ],
+    ],
+    [
+        [
+            252, 78, 147, 101, 174, 212, 6, 122, 177, 19, 206, 38, 221, 89, 103, 108, 232, 252,
+            178, 148, 107, 230, 206, 75, 125, 193, 94, 102, 72, 109, 10, 15,
+        ],
+        [
+            155, 216, 12, 205, 48, 122, 77, 193, 143, 36, 140, 105, 43, 143, 167, 28, 70, 189, 55,
+            176, 36, 100, 112, 16, 187, 243, 46, 36, 6, 175, 154, 55,
+        ],
+        [
+            71, 228, 85, 177, 180, 189, 48, 91, 184, 136, 65, 179, 225, 212, 31, 226, 150, 246,
+            239, 127, 56, 84, 122, 18, 115, 150, 238, 9, 182, 254, 246, 11,
+        ],
+        [
+            80, 24, 156, 212, 84, 217, 91, 36, 30, 121, 70, 99, 114, 80, 16, 27, 83, 18, 156, 239,
+            78, 186, 90, 43, 233, 246, 91, 90, 59, 179, 131, 59,
+        ],
+        [
+            38, 141, 241, 87, 49, 123, 133, 88, 231, 150, 240, 124, 165, 240, 99, 180, 43, 79, 254,
+            104, 73, 207, 177, 21, 143, 177, 129, 155, 237, 74, 91, 32,
+        ],
+        [
+            137, 89, 157, 197, 187, 144, 105, 250, 248, 43, 65, 138, 12, 10, 78, 214, 204, 133,
+            215, 204, 119, 1, 30, 146, 180, 107, 1, 27, 212, 174, 21, 48,
+        ],
+        [
+            191, 27, 74, 58, 198, 251, 204, 87, 243, 38, 27, 41, 203, 78, 60, 223, 91, 49, 99, 21,
+            37, 204, 53, 184, 33, 20, 243, 113, 115, 218, 232, 62,
+        ],
+        [
+            207, 236, 157, 231, 11, 252, 45, 131, 90, 173, 10, 102, 181, 226, 214, 47, 41, 18, 170,
+            231, 144, 19, 200, 92, 16, 90, 254, 92, 239, 222, 248, 57,
+        ],
+    ],
+    [
+        [
+            76, 32, 36, 147, 221, 77, 2, 34, 59, 198, 216, 47, 176, 251, 18, 146, 115, 34, 222,
+            160, 232, 220, 195, 207, 59, 172, 97, 145, 251, 147, 126, 44,
+        ],
+        [
+            138, 51, 47, 178, 237, 124, 104, 188, 243, 242, 90, 174, 103, 141, 225, 91, 132, 144,
+            59, 13, 108, 44, 203, 112, 241, 169, 13, 189, 3, 207, 54, 16,
+        ],
+        [
+            1, 16, 140, 11, 15, 186, 151, 215, 184, 138, 157, 236, 123, 102, 178, 50, 62, 229, 192,
+            107, 41, 23, 52, 164, 80, 250, 84, 147, 36, 6, 234, 62,
+        ],
+        [
+            93, 5, 180, 67, 96, 59, 122, 244, 67, 172, 122, 143, 141, 228, 113, 38, 125, 20, 191,
+            252, 73, 47, 102, 199, 49, 40, 65, 211, 114, 225, 58, 51,
+        ],
+        [
+            94, 37, 77, 35, 241, 3, 181, 188, 237, 235, 123, 191, 33, 158, 244, 161, 180, 97, 26,
+            92, 233, 56, 74, 64, 219, 207, 253, 210, 28, 180, 63, 37,
+        ],
+        [
+            215, 173, 103, 196, 246, 254, 236, 64, 119, 196, 67, 149, 248, 1, 50, 80, 242, 97, 129,
+            105, 138, 79, 150, 68, 246, 217, 104, 47, 151, 112, 8, 8,
+        ],
+        [
+            138, 41, 229, 212, 157, 243, 87, 3, 190, 123, 86, 15, 228, 179, 234, 144, 196, 196, 31,
+            47, 92, 159, 3, 117, 3, 166, 128, 131, 14, 213, 60, 43,
+        ],
+        [
+            200, 32, 113, 181, 114, 80, 235, 166, 94, 155, 220, 37, 91, 89, 110, 73, 87, 233, 74,
+            43, 142, 254, 166, 19, 77, 11, 14, 54, 162, 191, 205, 50,
+        ],
+    ],
+    [
+        [
+            48, 59, 67, 226, 175, 209, 169, 59, 130, 149, 236, 169, 120, 57, 250, 220, 19, 151,
+            227, 16, 75, 84, 173, 58, 6, 240, 117, 87, 101, 0, 105, 15,
+        ],
+        [
+            220, 150, 179, 113, 82, 68, 93, 42, 4, 253, 156, 226, 39, 162, 55, 197, 79, 189, 49,
+            31, 15, 236, 35, 15, 1, 41, 38, 25, 41, 149, 6, 9,
+        ],
+        [
+            145, 181, 72, 172, 203, 235, 67, 8, 72, 79, 192, 192, 74, 124, 188, 205, 53, 66, 27,
+            217, 58, 225, 103, 173, 135, 200, 156, 230, 10, 76, 242, 45,
+        ],
+        [
+            222, 76, 118, 248, 140, 161, 162, 93, 233, 156, 75, 191, 0, 204, 159, 100, 60, 97, 125,
+            237, 97, 42, 49, 50, 189, 65, 217, 158, 7, 44, 91, 1,
+        ],
+        [
+            209, 83, 160, 70, 48, 246, 212, 142, 173, 32, 59, 215, 46, 172, 190, 112, 8, 203, 8,
+            118, 174, 241, 167, 123, 9, 54, 76, 103, 186, 143, 208, 25,
+        ],
+        [
+            37, 10, 235, 35, 92, 196, 30, 35, 151, 197, 56, 88, 53, 171, 21, 149, 0, 161, 169, 219,
+            216, 164, 172, 203, 44, 156, 160, 111, 89, 12, 24, 30,
+        ],
+        [
+            153, 196, 71, 67, 191, 150, 139, 249, 155, 237, 29, 185, 187, 143, 109, 202, 37, 174,
+            96, 130, 124, 252, 136, 53, 153, 239, 192, 101, 252, 95, 234, 19,
+        ],
+        [
+            90, 216, 211, 169, 101, 181, 18, 92, 115, 196, 63, 207, 98, 46, 62, 138, 77, 19, 127,
+            97, 73, 182, 98, 125, 176, 151, 20, 20, 69, 117, 219, 48,
+        ],
+    ],
+    [
+        [
+            13, 69, 24, 252, 255, 248, 78, 45, 59, 209, 217, 194, 15, 70, 68, 59, 60, 30, 239, 237,
+            92, 122, 97, 224, 174, 82, 180, 40, 21, 106, 32, 19,
+        ],
+        [
+            92, 242, 21, 171, 146, 114, 94, 178, 203, 191, 89, 0, 227, 39, 205, 204, 203, 59, 241,
+            0, 26, 235, 110, 22, 39, 128, 73, 76, 146, 227, 151, 12,
+        ],
+        [
+            60, 2, 68, 215, 229, 146, 166, 170, 41, 219, 211, 224, 68, 19, 196, 229, 44, 98, 20,
+            11, 146, 72, 37, 52, 203, 21, 105, 238, 136, 248, 19, 22,
+        ],
+        [
+            109, 215, 58, 10, 255, 235, 89, 246, 109, 122, 159, 78, 209, 16, 127, 159, 107, 171,
+            47, 139, 43, 100, 224, 148, 5, 84, 188, 225, 18, 70, 132, 47,
+        ],
+        [
+            0, 218, 183, 32, 5, 201, 225, 175, 10, 238, 89, 119, 130, 99, 122, 94, 71, 195, 236,
+            244, 116, 214, 37, 177, 60, 228, 203, 83, 128, 76, 154, 40,
+        ],
+        [
+            73, 102, 196, 212, 174, 161, 54, 119, 43, 217, 182, 159, 222, 35, 6, 199, 167, 215,
+            119, 160, 107, 213, 172, 48, 38, 18, 120, 123, 237, 148, 141, 56,
+        ],
+        [
+            214, 24, 196, 223, 213, 144, 190, 182, 244, 9, 40, 2, 112, 141, 147, 254, 77, 120, 35,
+            109, 74, 236, 243, 133, 13, 51, 150, 224, 161, 131, 151, 42,
+        ],
+        [
+            60, 65, 55, 114, 19, 166, 169, 18, 81, 12, 62, 85, 156, 16, 168, 116, 72, 206, 161,
+            187, 232, 213, 197, 37, 232, 224, 210, 251, 137, 152, 151, 16,
+        ],
+    ],
+    [
+        [
+            199, 90, 26, 238, 172, 230, 154, 102, 134, 62, 134, 7, 192, 3, 180, 119, 191, 30, 196,
+            42, 241, 162, 167, 10, 13, 176, 97, 98, 75, 217, 87, 42,
+        ],
+        [
+            61, 76, 9, 158, 201, 157, 117, 213, 194, 33, 59, 201, 148, 76, 217, 82, 67, 222, 13,
+            28, 37, 179, 217, 119, 30, 10, 142, 37, 185, 228, 156, 15,
+        ],
+        [
+            250, 174, 194, 10, 237, 125, 205, 54, 128, 44, 36, 11, 65, 240, 167, 240, 190, 15, 250,
+            252, 170, 113, 80, 32, 119, 44, 109, 180, 26, 248, 26, 63,
+        ],
+        [
+            130, 123, 206, 242, 78, 98, 113, 115, 22, 6, 135, 197, 100, 194, 153, 176, 158, 6, 83,
+            211, 59, 204, 203, 128, 231, 159, 19, 37, 53, 43, 158, 42,
+        ],
+        [
+            165, 167, 32, 127, 141, 187, 224, 167, 235, 14, 174, 9, 140, 129, 240, 160, 121, 248,
+            201, 212, 71, 69, 8, 183, 192, 254, 57, 172, 139, 4, 50, 2,
+        ],
+        [
+            175, 198, 138, 59, 196, 136, 246, 20, 180, 104, 84, 65, 105, 130, 125, 91, 68, 104,
+            148, 24, 160, 104, 31, 215, 16, 12, 74, 57, 158, 135, 191, 25,
+        ],
+        [
+            98, 216, 151, 36, 232, 25, 109, 55, 199, 138, 36, 132, 30, 115, 254, 94, 55, 67, 172,
+            72, 178, 12, 16, 124, 42, 11, 237, 127, 65, 69, 28, 31,
+        ],
+        [
+            105, 30, 190, 46, 212, 80, 94, 7, 154, 132, 203, 224, 223, 89, 109, 132, 109, 208, 17,
+            73, 201, 61, 59, 181, 158, 169, 175, 98, 148, 98, 251, 4,
+        ],
+    ],
+];
+
+pub fn generator() -> pallas::Affine {
+    pallas::Affine::from_xy(
+        pallas::Base::from_repr(GENERATOR.0).unwrap(),
+        pallas::Base::from_repr(GENERATOR.1).unwrap(),
+    )
+    .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::{BOARD_COMMITMENT_PERSONALIZATION, BOARD_COMMITMENT_Q_BYTES};
+    use super::*;
+    use halo2_gadgets::ecc::chip::constants::{test_lagrange_coeffs, test_zs_and_us};
+    use halo2_proofs::pasta::{
+        arithmetic::{CurveAffine, CurveExt},
+        group::Curve,
+        pallas,
+    };
+
+    #[test]
+    fn generator() {
+        let hasher = pallas::Point::hash_to_curve(BOARD_COMMITMENT_PERSONALIZATION);
+        let point = hasher(&BOARD_COMMITMENT_Q_BYTES);
+        let coords = point.to_affine().coordinates().unwrap();
+
+        assert_eq!(*coords.x(), pallas::Base::from_repr(GENERATOR.0).unwrap());
+        assert_eq!(*coords.y(), pallas::Base::from_repr(GENERATOR.1).unwrap());
+    }
+
+    #[test]
+    fn lagrange_coeffs() {
+        let base = super::generator();
+        test_lagrange_coeffs(base, NUM_WINDOWS_SHORT);
+    }
+
+    #[test]
+    fn z() {
+        let base = super::generator();
+        test_zs_and_us(base, &Z_SHORT, &U_SHORT, NUM_WINDOWS_SHORT);
+    }
+}
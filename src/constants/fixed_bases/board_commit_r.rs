@@ -1,7 +1,7 @@
 use halo2_gadgets::ecc::chip::constants::{H, NUM_WINDOWS};
 use halo2_proofs::pasta::{arithmetic::CurveAffine, group::ff::PrimeField, pallas};
 
-/// The ship commitment is used to bind and blind a board configuration . The value is
+/// The blinding generator hides a board configuration in the ship commitment. The trapdoor is
 /// placed over this generator.
 pub const GENERATOR: ([u8; 32], [u8; 32]) = (
     [
@@ -26,7 +26,7 @@ pub const Z: [u64; NUM_WINDOWS] = [
 ];
 
 // Full-width u-values for GENERATOR
-pub const U: [[[u8; 32]; H]; NUM_WINDOWS] = [
+pub static U: [[[u8; 32]; H]; NUM_WINDOWS] = [
     [
         [
             101, 216, 191, 250, 171, 30, 12, 126, 118, 21, 242, 100, 168, 82, 30, 227, 7, 199, 145,
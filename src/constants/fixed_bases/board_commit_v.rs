@@ -1,8 +1,8 @@
 use halo2_gadgets::ecc::chip::constants::{H, NUM_WINDOWS};
 use halo2_proofs::pasta::{arithmetic::CurveAffine, group::ff::PrimeField, pallas};
 
-/// The ship commitment is used to bind and blind a board configuration . The value is
-/// placed over this generator.
+/// The ship commitment is used to bind a board configuration. The value is placed over this
+/// generator.
 pub const GENERATOR: ([u8; 32], [u8; 32]) = (
     [
         164, 44, 105, 166, 153, 98, 175, 10, 215, 133, 19, 174, 92, 101, 125, 189, 163, 103, 132,
@@ -26,7 +26,7 @@ pub const Z: [u64; NUM_WINDOWS] = [
 ];
 
 // Full-width u-values for GENERATOR
-pub const U: [[[u8; 32]; H]; NUM_WINDOWS] = [
+pub static U: [[[u8; 32]; H]; NUM_WINDOWS] = [
     [
         [
             99, 190, 62, 10, 79, 25, 56, 47, 135, 108, 86, 173, 149, 177, 123, 17, 164, 180, 176,
@@ -2929,8 +2929,8 @@ pub fn generator() -> pallas::Affine {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
     use super::super::super::{BOARD_COMMITMENT_PERSONALIZATION, BOARD_COMMITMENT_V_BYTES};
+    use super::*;
     use halo2_gadgets::ecc::chip::constants::{test_lagrange_coeffs, test_zs_and_us};
     use halo2_proofs::pasta::{
         arithmetic::{CurveAffine, CurveExt},
@@ -0,0 +1,468 @@
+use {
+    super::{board_commit_r, board_commit_v, BoardCommitQ},
+    crate::{
+        constants::{
+            BOARD_COMMITMENT_PERSONALIZATION, BOARD_COMMITMENT_R_BYTES, BOARD_COMMITMENT_V_BYTES,
+        },
+        utils::fixed_bases::FixedBaseTable,
+    },
+    halo2_gadgets::ecc::{
+        chip::{
+            constants::{H, NUM_WINDOWS},
+            BaseFieldElem, FixedPoint, FullScalar,
+        },
+        FixedPoints,
+    },
+    halo2_proofs::{
+        arithmetic::CurveExt,
+        pasta::{group::Curve, pallas},
+    },
+    std::{
+        collections::HashMap,
+        fmt,
+        sync::{Arc, Mutex, MutexGuard, OnceLock, PoisonError},
+    },
+};
+
+// domains that have been instantiated in this process, so window tables are only derived once
+static DOMAINS: OnceLock<Mutex<HashMap<String, CommitmentDomain>>> = OnceLock::new();
+
+// lock the domain registry, a panic while it was held cannot leave a domain half inserted
+fn domains() -> MutexGuard<'static, HashMap<String, CommitmentDomain>> {
+    DOMAINS
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Errors returned when registering a commitment domain with precomputed window tables
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DomainError {
+    /// the tables are for a different generator than the domain selects
+    GeneratorMismatch,
+    /// the tables have this many windows rather than NUM_WINDOWS
+    WindowCount(usize),
+    /// a domain with this personalization is already in use in this process
+    AlreadyRegistered(String),
+}
+
+impl fmt::Display for DomainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DomainError::GeneratorMismatch => write!(f, "tables are for another generator"),
+            DomainError::WindowCount(count) => {
+                write!(f, "expected {} windows, found {}", NUM_WINDOWS, count)
+            }
+            DomainError::AlreadyRegistered(personalization) => {
+                write!(f, "domain {:?} is already registered", personalization)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DomainError {}
+
+/// A generator selected by hash to curve in a caller supplied domain. The window tables used
+/// for fixed-base scalar multiplication are derived the first time they are needed.
+#[derive(Debug)]
+pub struct DomainGenerator {
+    personalization: String,
+    bytes: Vec<u8>,
+    generator: pallas::Affine,
+    table: OnceLock<FixedBaseTable>,
+}
+
+impl DomainGenerator {
+    fn new(personalization: &str, bytes: &[u8]) -> Self {
+        DomainGenerator {
+            personalization: personalization.to_string(),
+            bytes: bytes.to_vec(),
+            generator: pallas::Point::hash_to_curve(personalization)(bytes).to_affine(),
+            table: OnceLock::new(),
+        }
+    }
+
+    fn with_table(
+        personalization: &str,
+        bytes: &[u8],
+        table: FixedBaseTable,
+    ) -> Result<Self, DomainError> {
+        let generator = DomainGenerator::new(personalization, bytes);
+        if generator.generator != table.generator {
+            return Err(DomainError::GeneratorMismatch);
+        }
+        for count in [table.z.len(), table.u.len()] {
+            if count != NUM_WINDOWS {
+                return Err(DomainError::WindowCount(count));
+            }
+        }
+        generator.table.set(table).unwrap();
+        Ok(generator)
+    }
+
+    /// Returns the generator point
+    pub fn generator(&self) -> pallas::Affine {
+        self.generator
+    }
+
+    /// Returns the window tables, deriving them with find_zs_and_us if not yet computed
+    pub fn table(&self) -> &FixedBaseTable {
+        self.table
+            .get_or_init(|| FixedBaseTable::derive(&self.personalization, &self.bytes))
+    }
+}
+
+impl PartialEq for DomainGenerator {
+    fn eq(&self, other: &Self) -> bool {
+        self.personalization == other.personalization && self.bytes == other.bytes
+    }
+}
+
+impl Eq for DomainGenerator {}
+
+/// Pedersen commitment generators domain separated by a caller supplied personalization.
+/// The value generator is hash_to_curve(personalization)(BOARD_COMMITMENT_V_BYTES) and the
+/// blinding generator is hash_to_curve(personalization)(BOARD_COMMITMENT_R_BYTES).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommitmentDomain {
+    v: Arc<DomainGenerator>,
+    r: Arc<DomainGenerator>,
+}
+
+impl CommitmentDomain {
+    /**
+     * Get the commitment domain for a personalization
+     * @dev window tables are derived lazily on first in-circuit use and cached for the process.
+     *      Deriving the tables for a new domain takes minutes, see CommitmentDomain::from_tables
+     *
+     * @param personalization - the domain separator for hash to curve
+     * @return - the commitment domain
+     */
+    pub fn new(personalization: &str) -> Self {
+        let mut domains = domains();
+        domains
+            .entry(personalization.to_string())
+            .or_insert_with(
+                || match personalization == BOARD_COMMITMENT_PERSONALIZATION {
+                    // the default domain is seeded with the checked-in tables
+                    true => CommitmentDomain::battlezips(),
+                    false => CommitmentDomain {
+                        v: Arc::new(DomainGenerator::new(
+                            personalization,
+                            &BOARD_COMMITMENT_V_BYTES,
+                        )),
+                        r: Arc::new(DomainGenerator::new(
+                            personalization,
+                            &BOARD_COMMITMENT_R_BYTES,
+                        )),
+                    },
+                },
+            )
+            .clone()
+    }
+
+    /**
+     * Register a commitment domain with previously derived window tables
+     * @dev must be called before the personalization is first used with CommitmentDomain::new,
+     *      a domain in use is never replaced
+     *
+     * @param personalization - the domain separator for hash to curve
+     * @param v - generator and window tables for the value generator
+     * @param r - generator and window tables for the blinding generator
+     * @return - the commitment domain, or an error if the tables do not match the domain
+     *           generators or the personalization is already registered
+     */
+    pub fn from_tables(
+        personalization: &str,
+        v: FixedBaseTable,
+        r: FixedBaseTable,
+    ) -> Result<Self, DomainError> {
+        let domain = CommitmentDomain {
+            v: Arc::new(DomainGenerator::with_table(
+                personalization,
+                &BOARD_COMMITMENT_V_BYTES,
+                v,
+            )?),
+            r: Arc::new(DomainGenerator::with_table(
+                personalization,
+                &BOARD_COMMITMENT_R_BYTES,
+                r,
+            )?),
+        };
+        let mut domains = domains();
+        // the battlezips domain is seeded on first use, so it is always taken
+        if personalization == BOARD_COMMITMENT_PERSONALIZATION
+            || domains.contains_key(personalization)
+        {
+            return Err(DomainError::AlreadyRegistered(personalization.to_string()));
+        }
+        domains.insert(personalization.to_string(), domain.clone());
+        Ok(domain)
+    }
+
+    // the battlezips domain using the tables in board_commit_v and board_commit_r
+    fn battlezips() -> Self {
+        let v = FixedBaseTable {
+            generator: board_commit_v::generator(),
+            z: board_commit_v::Z.to_vec(),
+            u: board_commit_v::U.to_vec(),
+        };
+        let r = FixedBaseTable {
+            generator: board_commit_r::generator(),
+            z: board_commit_r::Z.to_vec(),
+            u: board_commit_r::U.to_vec(),
+        };
+        CommitmentDomain {
+            v: Arc::new(
+                DomainGenerator::with_table(
+                    BOARD_COMMITMENT_PERSONALIZATION,
+                    &BOARD_COMMITMENT_V_BYTES,
+                    v,
+                )
+                .unwrap(),
+            ),
+            r: Arc::new(
+                DomainGenerator::with_table(
+                    BOARD_COMMITMENT_PERSONALIZATION,
+                    &BOARD_COMMITMENT_R_BYTES,
+                    r,
+                )
+                .unwrap(),
+            ),
+        }
+    }
+
+    /// Returns the domain separator used for hash to curve
+    pub fn personalization(&self) -> &str {
+        &self.v.personalization
+    }
+
+    /// Returns the generator the committed value is placed over
+    pub fn value_generator(&self) -> pallas::Affine {
+        self.v.generator()
+    }
+
+    /// Returns the generator the trapdoor is placed over
+    pub fn blinding_generator(&self) -> pallas::Affine {
+        self.r.generator()
+    }
+
+    /// Returns the fixed base for the committed value
+    pub fn value_base(&self) -> DomainCommitV {
+        DomainCommitV(self.v.clone())
+    }
+
+    /// Returns the fixed base for the trapdoor
+    pub fn blinding_base(&self) -> DomainCommitR {
+        DomainCommitR(self.r.clone())
+    }
+}
+
+impl Default for CommitmentDomain {
+    fn default() -> Self {
+        CommitmentDomain::new(BOARD_COMMITMENT_PERSONALIZATION)
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+// A sum type for the fixed bases of a commitment domain. Unlike BoardFixedBases the bases carry
+// their generators, so they are obtained from a CommitmentDomain instead of constructed directly.
+// FixedPoints requires a short scalar base, which is the global BoardCommitQ: short commitments
+// are not domain separated, and no domain commitment multiplies by it.
+pub enum DomainFixedBases {
+    DomainCommitV,
+    DomainCommitR,
+}
+
+/// DomainCommitV is used in scalar mul with a base field element.
+/// This is used for the witnessed binding commitment in a commitment domain
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DomainCommitV(Arc<DomainGenerator>);
+
+/// DomainCommitR is used in scalar mul with a full width scalar.
+/// This is used as a blinding commitment in a commitment domain
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DomainCommitR(Arc<DomainGenerator>);
+
+impl FixedPoints<pallas::Affine> for DomainFixedBases {
+    type Base = DomainCommitV;
+    type FullScalar = DomainCommitR;
+    type ShortScalar = BoardCommitQ;
+}
+
+impl FixedPoint<pallas::Affine> for DomainCommitV {
+    type FixedScalarKind = BaseFieldElem;
+
+    fn generator(&self) -> pallas::Affine {
+        self.0.generator()
+    }
+
+    fn u(&self) -> Vec<[[u8; 32]; H]> {
+        self.0.table().u.clone()
+    }
+
+    fn z(&self) -> Vec<u64> {
+        self.0.table().z.clone()
+    }
+}
+
+impl FixedPoint<pallas::Affine> for DomainCommitR {
+    type FixedScalarKind = FullScalar;
+
+    fn generator(&self) -> pallas::Affine {
+        self.0.generator()
+    }
+
+    fn u(&self) -> Vec<[[u8; 32]; H]> {
+        self.0.table().u.clone()
+    }
+
+    fn z(&self) -> Vec<u64> {
+        self.0.table().z.clone()
+    }
+}
+
+// personalization of the domain with checked-in z-values used by tests
+#[cfg(test)]
+pub(crate) const TEST_PERSONALIZATION: &str = "battlezips:custom";
+
+// z-values found by find_zs_and_us for the TEST_PERSONALIZATION value generator
+#[cfg(test)]
+const TEST_Z_V: [u64; NUM_WINDOWS] = [
+    49457, 18229, 59861, 125028, 134011, 9650, 5075, 26798, 75358, 61274, 52858, 43917, 124840,
+    43892, 24168, 202333, 32597, 60838, 18419, 48999, 18303, 60350, 79883, 68858, 59105, 174445,
+    12043, 12727, 112886, 6444, 263993, 171960, 32917, 125389, 101057, 232423, 7102, 73215, 3016,
+    2113, 147669, 29757, 30375, 95723, 11658, 186993, 28874, 50586, 63230, 7334, 4466, 27953, 35340,
+    91442, 657, 13902, 279784, 1410, 56229, 108638, 102617, 51231, 60733, 75500, 84612, 25782,
+    217590, 44440, 139989, 33988, 25455, 76631, 142170, 16135, 32274, 266244, 4932, 28266, 33039,
+    38100, 19442, 28588, 28331, 43294, 14407,
+];
+
+// z-values found by find_zs_and_us for the TEST_PERSONALIZATION blinding generator
+#[cfg(test)]
+const TEST_Z_R: [u64; NUM_WINDOWS] = [
+    24418, 61799, 15282, 216072, 16122, 17605, 93289, 44940, 3129, 32269, 377715, 196008, 41988,
+    300254, 70954, 5800, 338890, 10174, 55921, 48176, 18152, 13725, 15522, 28588, 194135, 4827,
+    22493, 20799, 10672, 12776, 252592, 58676, 12578, 58524, 103096, 89983, 14932, 9018, 87241,
+    43553, 49979, 35682, 98936, 43845, 100229, 23758, 44463, 83380, 3488, 107590, 84394, 97556,
+    142391, 228336, 111551, 35664, 17653, 18966, 293748, 7456, 46138, 24710, 138513, 130170, 26692,
+    22248, 15277, 21590, 181227, 67417, 118292, 157930, 120959, 17614, 12508, 20253, 15532, 118815,
+    74765, 39891, 155509, 11090, 30025, 58942, 3038,
+];
+
+// window tables of the TEST_PERSONALIZATION generators, recomputed from the z-values in seconds
+#[cfg(test)]
+pub(crate) fn test_tables() -> (FixedBaseTable, FixedBaseTable) {
+    let hasher = pallas::Point::hash_to_curve(TEST_PERSONALIZATION);
+    let v = hasher(&BOARD_COMMITMENT_V_BYTES).to_affine();
+    let r = hasher(&BOARD_COMMITMENT_R_BYTES).to_affine();
+    (
+        FixedBaseTable::from_zs(v, TEST_Z_V.to_vec()).unwrap(),
+        FixedBaseTable::from_zs(r, TEST_Z_R.to_vec()).unwrap(),
+    )
+}
+
+// the TEST_PERSONALIZATION domain, registered once for the test process
+#[cfg(test)]
+pub(crate) fn test_domain() -> CommitmentDomain {
+    static TEST_DOMAIN: OnceLock<CommitmentDomain> = OnceLock::new();
+    TEST_DOMAIN
+        .get_or_init(|| {
+            let (v, r) = test_tables();
+            CommitmentDomain::from_tables(TEST_PERSONALIZATION, v, r).unwrap()
+        })
+        .clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        halo2_gadgets::ecc::chip::constants::test_zs_and_us,
+    };
+
+    #[test]
+    fn default_domain() {
+        let domain = CommitmentDomain::default();
+        assert_eq!(domain.personalization(), BOARD_COMMITMENT_PERSONALIZATION);
+        assert_eq!(domain.value_generator(), board_commit_v::generator());
+        assert_eq!(domain.blinding_generator(), board_commit_r::generator());
+        assert_eq!(domain.value_base().z(), board_commit_v::Z.to_vec());
+        assert_eq!(domain.blinding_base().u(), board_commit_r::U.to_vec());
+    }
+
+    #[test]
+    fn poisoned_registry() {
+        // a panic while the registry is locked does not disable domains for the process
+        let poisoned = std::thread::spawn(|| {
+            let _domains = domains();
+            panic!("poisoning the domain registry");
+        })
+        .join();
+        assert!(poisoned.is_err());
+        let domain = CommitmentDomain::new("battlezips:poisoned");
+        assert_eq!(CommitmentDomain::new("battlezips:poisoned"), domain);
+    }
+
+    #[test]
+    fn domain_separation() {
+        let domain = CommitmentDomain::new("battlezips:test");
+        let hasher = pallas::Point::hash_to_curve("battlezips:test");
+        assert_eq!(
+            domain.value_generator(),
+            hasher(&BOARD_COMMITMENT_V_BYTES).to_affine()
+        );
+        assert_eq!(
+            domain.blinding_generator(),
+            hasher(&BOARD_COMMITMENT_R_BYTES).to_affine()
+        );
+        assert_ne!(domain.value_generator(), board_commit_v::generator());
+        // domains are cached for the process
+        assert_eq!(domain, CommitmentDomain::new("battlezips:test"));
+        // tables for another domain's generator are rejected
+        let table = FixedBaseTable {
+            generator: board_commit_v::generator(),
+            z: board_commit_v::Z.to_vec(),
+            u: board_commit_v::U.to_vec(),
+        };
+        assert_eq!(
+            CommitmentDomain::from_tables("battlezips:test", table.clone(), table),
+            Err(DomainError::GeneratorMismatch)
+        );
+    }
+
+    #[test]
+    fn domain_tables() {
+        let domain = test_domain();
+        assert_eq!(domain.personalization(), TEST_PERSONALIZATION);
+        let v = domain.value_base();
+        test_zs_and_us(v.generator(), &v.z(), &v.u(), NUM_WINDOWS);
+        let r = domain.blinding_base();
+        test_zs_and_us(r.generator(), &r.z(), &r.u(), NUM_WINDOWS);
+        // a registered domain is never replaced
+        let (v, r) = test_tables();
+        assert_eq!(
+            CommitmentDomain::from_tables(TEST_PERSONALIZATION, v.clone(), r.clone()),
+            Err(DomainError::AlreadyRegistered(TEST_PERSONALIZATION.to_string()))
+        );
+        let battlezips = CommitmentDomain::default();
+        let tables = (
+            battlezips.v.table().clone(),
+            battlezips.r.table().clone(),
+        );
+        assert_eq!(
+            CommitmentDomain::from_tables(BOARD_COMMITMENT_PERSONALIZATION, tables.0, tables.1),
+            Err(DomainError::AlreadyRegistered(
+                BOARD_COMMITMENT_PERSONALIZATION.to_string()
+            ))
+        );
+        // truncated tables are rejected
+        let mut truncated = v;
+        truncated.z.pop();
+        truncated.u.pop();
+        assert_eq!(
+            CommitmentDomain::from_tables(TEST_PERSONALIZATION, truncated, r),
+            Err(DomainError::WindowCount(NUM_WINDOWS - 1))
+        );
+    }
+}
@@ -0,0 +1,11709 @@
+use halo2_gadgets::ecc::chip::constants::{H, NUM_WINDOWS};
+use halo2_proofs::pasta::{arithmetic::CurveAffine, group::ff::PrimeField, pallas};
+
+use super::super::VECTOR_COMMITMENT_SIZE;
+
+/// The vector commitment generators bind each element of a board state vector. The i-th value
+/// is placed over the i-th generator.
+pub const GENERATORS: [([u8; 32], [u8; 32]); VECTOR_COMMITMENT_SIZE] = [
+    (
+        [
+            85, 251, 163, 94, 40, 127, 138, 154, 209, 156, 37, 81, 70, 157, 188, 234, 38, 81, 2,
+            136, 23, 33, 229, 2, 158, 30, 210, 254, 163, 76, 1, 39,
+        ],
+        [
+            0, 95, 175, 215, 24, 125, 144, 139, 243, 226, 129, 138, 218, 252, 242, 153, 89, 240,
+            141, 45, 25, 161, 96, 75, 221, 234, 234, 183, 94, 209, 203, 46,
+        ],
+    ),
+    (
+        [
+            78, 157, 62, 68, 76, 89, 114, 31, 96, 39, 120, 185, 248, 183, 146, 126, 1, 106, 18,
+            106, 220, 241, 83, 69, 176, 138, 172, 18, 152, 35, 53, 59,
+        ],
+        [
+            30, 127, 109, 180, 164, 243, 197, 165, 20, 226, 56, 96, 192, 179, 47, 126, 109, 233,
+            242, 182, 119, 3, 118, 203, 6, 112, 32, 89, 50, 140, 63, 31,
+        ],
+    ),
+    (
+        [
+            139, 171, 60, 182, 228, 51, 22, 176, 253, 75, 85, 129, 5, 2, 49, 64, 122, 219, 28, 249,
+            173, 33, 202, 189, 180, 105, 64, 149, 252, 1, 172, 3,
+        ],
+        [
+            166, 65, 207, 248, 217, 147, 176, 112, 251, 196, 252, 70, 246, 191, 220, 132, 188, 40,
+            130, 231, 108, 192, 234, 22, 91, 143, 7, 51, 113, 52, 184, 35,
+        ],
+    ),
+    (
+        [
+            237, 93, 9, 113, 15, 191, 52, 224, 153, 10, 98, 20, 159, 240, 69, 118, 216, 78, 178,
+            255, 109, 171, 135, 210, 48, 181, 201, 249, 49, 181, 167, 9,
+        ],
+        [
+            162, 26, 149, 248, 130, 158, 59, 81, 158, 178, 231, 166, 97, 41, 134, 254, 26, 158,
+            119, 80, 150, 106, 170, 149, 90, 50, 188, 97, 22, 42, 142, 33,
+        ],
+    ),
+];
+
+/// Full-width z-values for each of GENERATORS
+pub const Z: [[u64; NUM_WINDOWS]; VECTOR_COMMITMENT_SIZE] = [
+    [
+        36824, 5869, 6895, 15107, 5149, 257815, 68207, 67341, 120107, 84840, 214923, 65800, 127578,
+        69743, 41005, 8564, 54669, 22198, 127525, 67135, 76341, 87698, 5242, 114820, 349679, 5590,
+        18823, 34405, 125396, 4806, 56286, 13518, 222828, 9456, 9349, 6795, 134304, 63525, 9548,
+        15065, 146267, 39762, 183675, 21858, 36310, 75389, 33339, 71162, 66158, 106325, 30285,
+        28135, 16907, 33287, 4578, 117015, 4872, 7659, 43254, 27957, 4894, 85177, 99949, 101694,
+        10481, 2821, 38339, 30721, 4136, 129442, 98600, 83293, 52039, 45196, 100086, 133287, 39936,
+        37584, 29552, 43200, 110226, 28020, 75539, 187520, 33886,
+    ],
+    [
+        41775, 61403, 65411, 164292, 18270, 134252, 112438, 77077, 44964, 5622, 17722, 33484,
+        136093, 4675, 80422, 22554, 102672, 903, 132818, 190118, 33582, 10795, 188271, 60137, 6047,
+        69942, 865, 354332, 24530, 89082, 39735, 22017, 83442, 39614, 140827, 189872, 42384, 3859,
+        1671, 61753, 71493, 18302, 26116, 81463, 48323, 198236, 56511, 63881, 269568, 3537, 72822,
+        23504, 114134, 88733, 4556, 183145, 46550, 24176, 96, 58582, 108610, 97312, 72360, 10231,
+        79316, 9012, 24999, 110193, 98312, 139660, 33673, 44593, 5511, 64566, 5045, 66543, 236794,
+        27199, 100949, 127170, 5650, 8367, 26477, 83704, 212803,
+    ],
+    [
+        208498, 88656, 67228, 13310, 44830, 27929, 90431, 91390, 65581, 33352, 10756, 8180, 34053,
+        48865, 8126, 28760, 141527, 8237, 406680, 12909, 32003, 69652, 52742, 169831, 165864,
+        54343, 149783, 3790, 21577, 34659, 1488, 85874, 28612, 125169, 123785, 47389, 157843,
+        41524, 77795, 5685, 72528, 45250, 36967, 25791, 23452, 17005, 27951, 144060, 8246, 28634,
+        26996, 34860, 1982, 217158, 29632, 176673, 83984, 13101, 39644, 46413, 1123, 11916, 19067,
+        69113, 26899, 90239, 114213, 18435, 2275, 13874, 7076, 14332, 92195, 50794, 59010, 36590,
+        3667, 14576, 30122, 1512, 45488, 13927, 142578, 63904, 65263,
+    ],
+    [
+        8216, 52705, 17834, 36069, 306744, 3712, 14565, 8399, 26788, 19724, 17234, 67781, 33580,
+        76729, 26038, 65164, 15943, 3044, 14139, 29188, 34429, 278484, 8989, 35529, 41784, 22313,
+        53330, 2089, 21155, 97947, 7244, 170789, 94596, 306706, 70956, 208512, 20481, 42839, 23434,
+        169720, 42898, 23960, 13974, 114173, 35984, 32287, 65344, 46671, 25915, 11518, 37717,
+        24513, 62214, 356863, 66825, 113188, 2960, 61820, 89687, 6126, 85759, 4042, 24356, 141239,
+        48229, 56194, 80044, 15164, 42411, 376638, 18777, 154578, 118804, 204221, 45875, 35985,
+        108624, 70987, 29811, 4986, 11125, 14670, 10618, 188423, 13036,
+    ],
+];
+
+/// Full-width u-values for each of GENERATORS
+pub static U: [[[[u8; 32]; H]; NUM_WINDOWS]; VECTOR_COMMITMENT_SIZE] = [
+    [
+        [
+            [
+                242, 253, 73, 212, 181, 132, 139, 195, 57, 185, 185, 32, 138, 228, 64, 57, 105,
+                182, 203, 217, 12, 223, 156, 190, 152, 253, 215, 88, 239, 36, 126, 0,
+            ],
+            [
+                129, 91, 164, 21, 25, 1, 140, 61, 204, 89, 109, 237, 154, 122, 239, 160, 47, 107,
+                189, 244, 230, 130, 109, 159, 79, 239, 102, 121, 97, 224, 37, 46,
+            ],
+            [
+                21, 46, 211, 217, 224, 21, 141, 97, 110, 132, 200, 106, 66, 243, 240, 168, 55, 16,
+                130, 246, 23, 71, 176, 26, 178, 110, 136, 248, 126, 22, 218, 13,
+            ],
+            [
+                205, 205, 120, 218, 140, 152, 161, 136, 226, 204, 120, 23, 204, 17, 2, 90, 197,
+                174, 118, 209, 152, 128, 208, 216, 30, 121, 83, 182, 247, 4, 84, 61,
+            ],
+            [
+                75, 104, 230, 70, 249, 74, 101, 88, 198, 51, 121, 85, 127, 31, 84, 114, 64, 187,
+                42, 35, 212, 16, 38, 108, 146, 204, 131, 251, 188, 143, 255, 53,
+            ],
+            [
+                28, 67, 241, 8, 75, 98, 184, 232, 178, 176, 225, 58, 244, 194, 82, 217, 4, 155, 50,
+                112, 156, 12, 10, 47, 232, 116, 46, 83, 190, 204, 254, 34,
+            ],
+            [
+                253, 179, 60, 90, 86, 127, 150, 203, 171, 54, 104, 157, 85, 202, 79, 154, 123, 170,
+                73, 175, 92, 112, 26, 197, 236, 234, 186, 194, 85, 173, 49, 17,
+            ],
+            [
+                77, 63, 211, 32, 123, 140, 86, 86, 102, 8, 61, 3, 232, 179, 53, 203, 95, 95, 197,
+                242, 121, 125, 240, 126, 193, 187, 88, 200, 122, 223, 151, 23,
+            ],
+        ],
+        [
+            [
+                29, 10, 254, 35, 118, 105, 232, 15, 247, 29, 120, 107, 235, 30, 75, 191, 170, 16,
+                38, 50, 254, 216, 166, 58, 9, 14, 67, 169, 126, 86, 51, 19,
+            ],
+            [
+                221, 148, 40, 39, 127, 138, 5, 175, 101, 174, 131, 238, 72, 15, 73, 184, 134, 219,
+                97, 163, 47, 45, 103, 173, 154, 218, 27, 132, 162, 68, 166, 37,
+            ],
+            [
+                149, 75, 55, 237, 207, 201, 3, 161, 86, 21, 120, 110, 229, 22, 219, 219, 195, 124,
+                85, 235, 225, 26, 199, 213, 107, 159, 29, 193, 152, 54, 222, 39,
+            ],
+            [
+                20, 179, 180, 131, 129, 41, 1, 51, 134, 111, 9, 191, 209, 7, 220, 83, 155, 13, 182,
+                207, 163, 220, 3, 116, 135, 31, 91, 240, 135, 132, 56, 43,
+            ],
+            [
+                253, 178, 125, 70, 43, 122, 198, 51, 65, 154, 120, 238, 247, 211, 140, 156, 48, 89,
+                151, 116, 202, 208, 227, 73, 241, 216, 196, 5, 75, 213, 189, 45,
+            ],
+            [
+                224, 161, 79, 13, 176, 1, 8, 179, 246, 82, 121, 65, 49, 113, 135, 125, 177, 139,
+                219, 255, 51, 106, 250, 227, 40, 129, 66, 147, 46, 177, 227, 45,
+            ],
+            [
+                145, 82, 240, 224, 6, 229, 205, 197, 133, 2, 64, 96, 132, 23, 187, 74, 71, 115,
+                103, 29, 205, 172, 209, 14, 11, 13, 40, 208, 30, 88, 91, 26,
+            ],
+            [
+                151, 108, 5, 101, 0, 99, 242, 100, 135, 66, 91, 44, 117, 114, 215, 64, 254, 89,
+                252, 223, 151, 168, 152, 188, 215, 155, 0, 140, 24, 165, 234, 29,
+            ],
+        ],
+        [
+            [
+                174, 243, 234, 188, 142, 183, 134, 157, 211, 139, 178, 12, 45, 83, 144, 83, 65,
+                179, 111, 211, 10, 42, 245, 241, 18, 175, 179, 218, 92, 79, 58, 24,
+            ],
+            [
+                177, 21, 5, 14, 241, 155, 74, 1, 16, 3, 141, 112, 253, 141, 159, 226, 98, 86, 85,
+                235, 20, 97, 159, 53, 82, 165, 123, 11, 221, 87, 109, 12,
+            ],
+            [
+                205, 224, 179, 111, 251, 220, 15, 187, 90, 203, 26, 83, 118, 97, 144, 80, 146, 208,
+                32, 52, 66, 49, 125, 48, 166, 63, 8, 10, 241, 150, 246, 23,
+            ],
+            [
+                165, 197, 82, 18, 165, 62, 232, 215, 103, 205, 236, 88, 80, 237, 166, 172, 45, 38,
+                94, 194, 187, 28, 39, 253, 213, 147, 222, 189, 176, 145, 62, 27,
+            ],
+            [
+                22, 206, 112, 26, 140, 212, 23, 208, 20, 168, 185, 228, 39, 31, 54, 96, 216, 178,
+                94, 234, 43, 10, 131, 99, 141, 119, 116, 12, 152, 73, 238, 16,
+            ],
+            [
+                100, 153, 189, 25, 166, 58, 51, 245, 139, 11, 248, 15, 187, 179, 234, 161, 1, 78,
+                24, 129, 240, 167, 63, 75, 64, 36, 105, 134, 252, 129, 81, 1,
+            ],
+            [
+                138, 60, 142, 210, 13, 60, 61, 214, 112, 232, 61, 211, 182, 78, 146, 183, 165, 220,
+                150, 238, 164, 80, 155, 175, 239, 231, 132, 122, 31, 11, 0, 60,
+            ],
+            [
+                214, 19, 164, 152, 204, 135, 76, 58, 11, 40, 48, 12, 114, 167, 231, 137, 86, 216,
+                115, 170, 123, 218, 143, 32, 6, 179, 175, 233, 34, 216, 51, 62,
+            ],
+        ],
+        [
+            [
+                147, 71, 170, 2, 233, 21, 203, 231, 179, 94, 163, 166, 127, 226, 123, 205, 109,
+                202, 206, 50, 148, 197, 186, 74, 96, 93, 167, 81, 147, 86, 85, 35,
+            ],
+            [
+                156, 150, 251, 69, 85, 203, 235, 8, 104, 113, 143, 158, 17, 230, 111, 59, 106, 91,
+                26, 127, 218, 68, 168, 95, 43, 79, 114, 190, 45, 175, 171, 47,
+            ],
+            [
+                142, 189, 174, 40, 238, 112, 150, 33, 145, 255, 21, 116, 190, 164, 179, 60, 254,
+                81, 92, 112, 204, 180, 108, 70, 106, 30, 10, 20, 140, 241, 172, 5,
+            ],
+            [
+                157, 9, 28, 227, 41, 64, 227, 64, 82, 57, 136, 215, 124, 227, 180, 108, 193, 252,
+                92, 125, 7, 163, 149, 161, 35, 184, 208, 213, 179, 48, 134, 7,
+            ],
+            [
+                109, 174, 88, 226, 93, 32, 185, 39, 216, 128, 28, 51, 91, 240, 82, 58, 149, 55,
+                111, 40, 37, 129, 2, 232, 187, 152, 132, 226, 182, 143, 139, 21,
+            ],
+            [
+                237, 42, 125, 168, 78, 41, 188, 111, 244, 231, 74, 193, 236, 189, 71, 98, 143, 54,
+                64, 101, 162, 102, 86, 6, 148, 107, 7, 216, 93, 132, 58, 53,
+            ],
+            [
+                30, 186, 21, 233, 146, 249, 186, 188, 94, 88, 77, 112, 173, 123, 224, 49, 175, 141,
+                223, 95, 106, 77, 61, 40, 125, 204, 63, 85, 188, 98, 235, 8,
+            ],
+            [
+                218, 40, 22, 177, 137, 100, 117, 0, 195, 100, 184, 167, 80, 184, 23, 33, 193, 176,
+                124, 5, 178, 80, 204, 141, 17, 205, 115, 190, 101, 75, 228, 7,
+            ],
+        ],
+        [
+            [
+                97, 8, 227, 193, 233, 5, 150, 167, 44, 218, 189, 71, 179, 3, 69, 85, 101, 159, 52,
+                103, 182, 108, 157, 109, 153, 179, 198, 40, 30, 191, 237, 23,
+            ],
+            [
+                103, 252, 222, 197, 125, 207, 225, 66, 233, 154, 49, 47, 90, 20, 66, 134, 45, 31,
+                160, 202, 115, 178, 124, 253, 17, 163, 9, 56, 165, 138, 33, 12,
+            ],
+            [
+                197, 61, 41, 151, 10, 151, 104, 116, 235, 252, 6, 213, 233, 174, 201, 188, 164,
+                214, 88, 249, 22, 197, 44, 104, 183, 238, 23, 104, 217, 45, 154, 49,
+            ],
+            [
+                66, 195, 39, 204, 123, 175, 132, 177, 84, 121, 240, 217, 220, 130, 240, 108, 177,
+                244, 52, 76, 65, 50, 42, 50, 193, 157, 35, 115, 153, 254, 141, 30,
+            ],
+            [
+                245, 17, 235, 80, 145, 215, 206, 51, 225, 61, 182, 199, 207, 132, 181, 62, 168, 73,
+                129, 119, 143, 45, 96, 224, 178, 201, 205, 34, 158, 55, 14, 9,
+            ],
+            [
+                161, 71, 178, 128, 13, 80, 102, 21, 56, 215, 172, 50, 222, 108, 10, 141, 15, 244,
+                236, 132, 16, 199, 244, 100, 206, 83, 138, 149, 207, 190, 97, 26,
+            ],
+            [
+                170, 110, 183, 187, 21, 57, 229, 24, 197, 104, 86, 80, 157, 121, 134, 55, 96, 181,
+                229, 126, 20, 214, 35, 141, 249, 121, 137, 160, 8, 139, 42, 42,
+            ],
+            [
+                29, 233, 173, 218, 69, 103, 191, 0, 78, 26, 29, 163, 35, 233, 106, 244, 124, 129,
+                125, 82, 191, 198, 205, 109, 31, 6, 188, 146, 29, 79, 135, 10,
+            ],
+        ],
+        [
+            [
+                169, 203, 140, 159, 80, 134, 163, 76, 46, 131, 228, 88, 92, 249, 173, 188, 81, 230,
+                206, 90, 152, 51, 239, 241, 58, 231, 76, 218, 244, 43, 187, 2,
+            ],
+            [
+                232, 75, 212, 85, 7, 64, 255, 181, 88, 125, 13, 163, 147, 120, 69, 174, 226, 133,
+                115, 158, 27, 103, 211, 41, 192, 115, 150, 117, 158, 218, 170, 18,
+            ],
+            [
+                64, 55, 111, 79, 120, 21, 98, 246, 162, 83, 191, 93, 7, 59, 60, 163, 80, 247, 58,
+                142, 63, 214, 153, 17, 250, 153, 159, 1, 132, 216, 189, 7,
+            ],
+            [
+                213, 120, 179, 224, 139, 197, 218, 122, 138, 87, 83, 12, 91, 227, 141, 232, 211,
+                186, 222, 191, 220, 132, 17, 108, 107, 70, 30, 122, 251, 74, 209, 0,
+            ],
+            [
+                246, 182, 150, 95, 243, 184, 197, 21, 193, 0, 5, 3, 117, 163, 165, 79, 9, 136, 161,
+                52, 27, 180, 63, 38, 160, 164, 160, 196, 141, 7, 192, 57,
+            ],
+            [
+                161, 150, 188, 148, 228, 51, 8, 251, 102, 103, 48, 74, 49, 85, 249, 125, 182, 182,
+                251, 145, 89, 244, 186, 135, 230, 166, 105, 219, 18, 121, 48, 42,
+            ],
+            [
+                70, 35, 199, 77, 244, 40, 246, 255, 223, 219, 235, 226, 75, 195, 31, 156, 190, 153,
+                218, 35, 103, 204, 215, 199, 111, 122, 113, 102, 25, 138, 184, 54,
+            ],
+            [
+                34, 186, 99, 135, 213, 243, 217, 82, 255, 147, 144, 73, 149, 224, 83, 121, 203, 37,
+                115, 205, 175, 74, 57, 40, 34, 220, 92, 224, 207, 239, 8, 45,
+            ],
+        ],
+        [
+            [
+                163, 147, 176, 253, 187, 125, 226, 33, 80, 6, 18, 225, 99, 107, 118, 114, 155, 55,
+                61, 77, 30, 128, 19, 121, 137, 52, 150, 148, 65, 230, 62, 19,
+            ],
+            [
+                220, 203, 47, 201, 9, 33, 9, 16, 88, 153, 242, 164, 160, 172, 135, 184, 138, 248,
+                62, 22, 227, 171, 18, 158, 60, 208, 213, 201, 21, 23, 142, 32,
+            ],
+            [
+                11, 149, 150, 125, 168, 225, 121, 69, 111, 133, 123, 7, 85, 125, 84, 193, 116, 212,
+                82, 212, 34, 193, 22, 38, 160, 51, 191, 246, 118, 109, 227, 2,
+            ],
+            [
+                195, 79, 176, 164, 244, 151, 147, 125, 131, 229, 49, 250, 12, 218, 45, 160, 245,
+                238, 125, 72, 175, 69, 17, 91, 90, 84, 155, 81, 136, 239, 139, 33,
+            ],
+            [
+                95, 78, 237, 233, 84, 70, 137, 157, 87, 104, 226, 132, 67, 225, 25, 218, 42, 77, 5,
+                12, 23, 55, 35, 67, 184, 69, 228, 86, 36, 85, 114, 9,
+            ],
+            [
+                43, 239, 15, 204, 60, 104, 117, 39, 196, 138, 217, 170, 153, 20, 154, 178, 156, 6,
+                250, 109, 141, 209, 189, 242, 184, 196, 77, 36, 241, 166, 183, 22,
+            ],
+            [
+                85, 226, 245, 206, 190, 174, 150, 237, 179, 57, 134, 158, 219, 107, 40, 14, 213,
+                63, 88, 112, 249, 197, 167, 79, 83, 93, 7, 47, 135, 47, 195, 36,
+            ],
+            [
+                241, 97, 87, 234, 246, 121, 242, 136, 196, 12, 18, 38, 44, 190, 207, 203, 162, 211,
+                89, 27, 115, 53, 188, 69, 143, 244, 151, 187, 1, 6, 48, 26,
+            ],
+        ],
+        [
+            [
+                221, 240, 11, 114, 229, 86, 66, 232, 189, 121, 10, 238, 181, 114, 201, 161, 201,
+                47, 53, 212, 59, 243, 211, 126, 230, 152, 129, 87, 179, 230, 10, 11,
+            ],
+            [
+                225, 228, 33, 170, 218, 239, 171, 144, 152, 86, 47, 97, 44, 204, 140, 202, 20, 43,
+                0, 31, 69, 170, 233, 234, 39, 23, 32, 181, 159, 165, 183, 22,
+            ],
+            [
+                66, 202, 239, 184, 221, 196, 115, 1, 49, 25, 26, 178, 149, 124, 6, 99, 140, 14, 48,
+                95, 45, 7, 76, 88, 212, 159, 118, 186, 9, 175, 205, 31,
+            ],
+            [
+                56, 30, 101, 251, 135, 213, 225, 26, 179, 168, 230, 242, 29, 244, 251, 92, 67, 148,
+                27, 18, 229, 252, 42, 99, 253, 214, 202, 82, 249, 232, 185, 4,
+            ],
+            [
+                204, 80, 239, 254, 76, 130, 88, 37, 144, 9, 54, 130, 52, 87, 68, 45, 45, 50, 64,
+                120, 4, 49, 53, 106, 177, 87, 88, 79, 135, 6, 63, 44,
+            ],
+            [
+                90, 73, 64, 170, 217, 181, 124, 138, 39, 132, 78, 0, 14, 48, 230, 141, 221, 172,
+                66, 164, 83, 144, 213, 253, 71, 251, 243, 204, 173, 154, 105, 29,
+            ],
+            [
+                93, 160, 101, 179, 187, 121, 39, 170, 121, 169, 140, 114, 137, 164, 85, 54, 215,
+                157, 12, 23, 225, 168, 183, 68, 29, 150, 165, 24, 0, 160, 174, 8,
+            ],
+            [
+                17, 76, 126, 62, 255, 154, 32, 205, 130, 232, 235, 78, 220, 182, 83, 255, 222, 170,
+                155, 249, 27, 36, 215, 246, 239, 146, 15, 215, 145, 84, 174, 33,
+            ],
+        ],
+        [
+            [
+                78, 182, 9, 172, 212, 250, 3, 248, 148, 0, 23, 68, 91, 140, 236, 247, 44, 155, 123,
+                184, 6, 222, 169, 123, 19, 4, 7, 157, 218, 168, 111, 40,
+            ],
+            [
+                34, 242, 126, 190, 172, 116, 111, 245, 69, 37, 47, 48, 214, 4, 134, 157, 107, 7,
+                192, 57, 91, 66, 57, 75, 52, 132, 228, 72, 97, 34, 189, 12,
+            ],
+            [
+                229, 38, 127, 82, 126, 239, 245, 16, 251, 5, 2, 48, 228, 48, 111, 220, 195, 118,
+                207, 171, 174, 4, 51, 149, 80, 125, 77, 124, 18, 158, 38, 23,
+            ],
+            [
+                78, 14, 61, 54, 149, 78, 81, 178, 187, 41, 247, 123, 99, 178, 254, 11, 228, 238,
+                58, 98, 121, 240, 3, 82, 221, 57, 178, 247, 112, 175, 177, 41,
+            ],
+            [
+                157, 107, 180, 117, 222, 76, 240, 71, 253, 11, 255, 36, 224, 82, 70, 21, 147, 167,
+                89, 174, 1, 192, 124, 31, 47, 61, 78, 193, 10, 195, 49, 37,
+            ],
+            [
+                254, 108, 107, 201, 207, 194, 42, 254, 68, 53, 221, 241, 150, 28, 139, 131, 122,
+                137, 30, 89, 53, 142, 41, 235, 248, 55, 195, 150, 123, 61, 20, 10,
+            ],
+            [
+                222, 110, 196, 63, 135, 247, 197, 168, 160, 238, 144, 202, 206, 201, 239, 240, 82,
+                175, 72, 99, 24, 79, 128, 239, 162, 118, 140, 12, 107, 23, 62, 17,
+            ],
+            [
+                97, 151, 119, 137, 97, 69, 185, 60, 164, 116, 88, 142, 190, 1, 248, 213, 91, 11,
+                114, 161, 118, 21, 141, 163, 246, 245, 90, 22, 210, 84, 176, 29,
+            ],
+        ],
+        [
+            [
+                65, 175, 218, 97, 47, 18, 201, 147, 17, 1, 133, 99, 221, 59, 9, 233, 130, 253, 102,
+                248, 109, 245, 124, 22, 2, 228, 255, 160, 244, 52, 126, 37,
+            ],
+            [
+                197, 181, 228, 55, 86, 127, 0, 248, 190, 66, 246, 247, 19, 149, 50, 192, 159, 110,
+                179, 134, 221, 254, 118, 55, 156, 190, 142, 1, 199, 72, 31, 59,
+            ],
+            [
+                34, 199, 44, 109, 169, 220, 185, 169, 63, 135, 173, 231, 68, 9, 233, 188, 200, 173,
+                251, 71, 193, 221, 249, 171, 202, 6, 27, 247, 164, 7, 196, 31,
+            ],
+            [
+                154, 97, 34, 64, 156, 219, 245, 77, 179, 18, 146, 200, 187, 110, 214, 34, 142, 52,
+                120, 121, 80, 123, 5, 235, 188, 199, 191, 195, 11, 218, 86, 15,
+            ],
+            [
+                50, 170, 120, 176, 0, 156, 50, 77, 162, 188, 78, 185, 250, 109, 78, 157, 42, 172,
+                5, 205, 200, 187, 64, 37, 142, 54, 175, 129, 76, 81, 51, 48,
+            ],
+            [
+                227, 221, 38, 150, 210, 150, 229, 9, 1, 195, 145, 179, 227, 246, 223, 218, 146,
+                140, 148, 60, 210, 183, 182, 189, 235, 120, 59, 216, 233, 112, 29, 4,
+            ],
+            [
+                114, 197, 45, 139, 133, 191, 78, 38, 20, 207, 137, 217, 184, 81, 50, 254, 91, 153,
+                206, 190, 210, 102, 193, 161, 100, 117, 169, 251, 127, 45, 208, 7,
+            ],
+            [
+                6, 189, 75, 200, 135, 207, 163, 158, 133, 187, 192, 104, 166, 111, 128, 62, 67,
+                155, 228, 148, 2, 244, 119, 49, 194, 193, 34, 7, 185, 103, 222, 34,
+            ],
+        ],
+        [
+            [
+                97, 75, 114, 143, 255, 194, 107, 116, 18, 231, 56, 74, 124, 245, 23, 211, 233, 151,
+                119, 67, 83, 96, 44, 48, 238, 209, 192, 11, 223, 125, 254, 59,
+            ],
+            [
+                197, 41, 243, 76, 215, 86, 50, 143, 122, 250, 244, 98, 187, 238, 30, 104, 101, 55,
+                140, 174, 79, 198, 232, 46, 87, 184, 183, 30, 66, 248, 24, 57,
+            ],
+            [
+                25, 9, 50, 163, 126, 219, 59, 73, 110, 43, 250, 26, 198, 4, 158, 129, 79, 36, 9,
+                165, 46, 229, 36, 182, 67, 0, 41, 147, 86, 19, 68, 41,
+            ],
+            [
+                152, 158, 50, 83, 92, 254, 69, 163, 227, 181, 171, 110, 254, 203, 237, 29, 67, 168,
+                115, 87, 251, 130, 207, 179, 137, 241, 163, 14, 200, 183, 170, 58,
+            ],
+            [
+                10, 123, 215, 101, 36, 109, 14, 127, 195, 91, 103, 27, 166, 43, 103, 58, 26, 204,
+                121, 108, 28, 178, 192, 91, 141, 241, 148, 173, 41, 123, 183, 2,
+            ],
+            [
+                64, 194, 188, 32, 84, 153, 141, 37, 22, 44, 14, 10, 92, 253, 15, 167, 107, 91, 137,
+                136, 3, 61, 165, 76, 70, 129, 245, 209, 74, 196, 56, 18,
+            ],
+            [
+                104, 165, 174, 41, 167, 226, 220, 190, 211, 39, 6, 17, 209, 176, 61, 122, 178, 138,
+                124, 180, 216, 81, 240, 241, 10, 53, 191, 154, 252, 185, 187, 47,
+            ],
+            [
+                15, 20, 8, 121, 248, 77, 27, 127, 172, 75, 130, 198, 243, 25, 170, 27, 8, 11, 177,
+                113, 124, 214, 16, 236, 86, 126, 55, 254, 83, 181, 16, 9,
+            ],
+        ],
+        [
+            [
+                70, 239, 55, 69, 199, 153, 36, 121, 173, 228, 196, 94, 254, 4, 21, 180, 144, 26,
+                42, 248, 51, 53, 237, 179, 9, 247, 186, 9, 48, 37, 165, 59,
+            ],
+            [
+                182, 11, 24, 253, 167, 103, 39, 223, 224, 243, 238, 83, 79, 36, 255, 205, 45, 28,
+                75, 126, 173, 153, 97, 199, 207, 191, 76, 73, 134, 200, 58, 50,
+            ],
+            [
+                231, 84, 88, 121, 137, 135, 103, 92, 184, 229, 9, 61, 159, 21, 186, 48, 215, 163,
+                113, 91, 102, 247, 65, 192, 92, 129, 219, 87, 140, 237, 19, 41,
+            ],
+            [
+                117, 224, 196, 153, 85, 29, 249, 130, 84, 160, 42, 127, 91, 97, 238, 230, 27, 218,
+                67, 216, 251, 1, 99, 222, 52, 234, 144, 63, 20, 195, 244, 55,
+            ],
+            [
+                37, 0, 128, 54, 56, 87, 51, 137, 32, 215, 38, 74, 224, 164, 229, 131, 200, 237, 2,
+                205, 112, 93, 27, 216, 186, 146, 80, 63, 204, 138, 25, 13,
+            ],
+            [
+                62, 120, 79, 209, 96, 98, 94, 217, 220, 195, 198, 5, 22, 24, 218, 28, 179, 246,
+                115, 224, 233, 154, 39, 31, 80, 165, 58, 164, 28, 251, 133, 27,
+            ],
+            [
+                160, 87, 204, 176, 95, 54, 156, 72, 57, 205, 138, 24, 124, 4, 156, 239, 86, 156,
+                233, 29, 78, 21, 103, 117, 58, 89, 160, 48, 233, 99, 64, 19,
+            ],
+            [
+                170, 113, 192, 168, 88, 223, 144, 228, 243, 32, 102, 71, 25, 102, 159, 114, 212,
+                248, 244, 137, 79, 210, 214, 157, 199, 114, 41, 73, 84, 170, 237, 40,
+            ],
+        ],
+        [
+            [
+                158, 16, 17, 63, 191, 15, 186, 184, 15, 170, 53, 106, 98, 177, 104, 157, 47, 205,
+                121, 17, 125, 206, 225, 5, 67, 236, 124, 91, 48, 185, 27, 1,
+            ],
+            [
+                66, 126, 8, 149, 238, 225, 175, 140, 85, 57, 68, 156, 225, 32, 235, 47, 201, 121,
+                189, 246, 178, 71, 207, 186, 239, 31, 22, 255, 138, 10, 208, 3,
+            ],
+            [
+                214, 92, 196, 114, 69, 150, 150, 30, 35, 181, 5, 249, 225, 157, 192, 100, 228, 232,
+                188, 232, 95, 180, 125, 104, 72, 240, 123, 97, 82, 129, 242, 50,
+            ],
+            [
+                184, 11, 196, 209, 95, 108, 170, 84, 47, 113, 11, 123, 88, 48, 164, 104, 17, 142,
+                104, 113, 116, 183, 31, 1, 55, 25, 131, 237, 160, 206, 191, 22,
+            ],
+            [
+                241, 18, 52, 148, 144, 20, 198, 99, 71, 206, 178, 32, 230, 20, 235, 174, 59, 42,
+                100, 223, 223, 194, 88, 51, 114, 90, 29, 104, 178, 189, 231, 59,
+            ],
+            [
+                103, 2, 79, 20, 49, 120, 52, 130, 62, 120, 50, 117, 25, 248, 229, 240, 149, 152,
+                194, 16, 158, 50, 185, 95, 139, 135, 4, 143, 229, 34, 24, 33,
+            ],
+            [
+                231, 135, 135, 218, 199, 111, 93, 208, 40, 77, 253, 216, 86, 48, 73, 171, 207, 100,
+                202, 54, 235, 202, 43, 229, 143, 124, 53, 159, 160, 232, 34, 62,
+            ],
+            [
+                32, 215, 202, 138, 193, 27, 184, 41, 83, 58, 157, 74, 64, 165, 101, 130, 66, 255,
+                128, 45, 124, 27, 84, 32, 139, 244, 39, 103, 115, 4, 238, 31,
+            ],
+        ],
+        [
+            [
+                23, 71, 134, 121, 5, 35, 30, 74, 163, 134, 103, 53, 25, 148, 116, 206, 93, 36, 152,
+                147, 23, 29, 128, 215, 69, 176, 26, 74, 62, 222, 182, 4,
+            ],
+            [
+                48, 237, 19, 154, 38, 241, 112, 188, 93, 143, 89, 183, 74, 90, 44, 102, 60, 191,
+                56, 109, 118, 229, 29, 195, 168, 48, 109, 177, 207, 66, 138, 37,
+            ],
+            [
+                22, 245, 164, 95, 235, 22, 201, 103, 185, 170, 240, 111, 116, 71, 7, 21, 190, 215,
+                13, 10, 120, 179, 143, 101, 59, 25, 77, 156, 203, 217, 119, 7,
+            ],
+            [
+                188, 228, 56, 68, 142, 166, 174, 123, 226, 189, 168, 224, 236, 61, 194, 7, 236,
+                162, 180, 115, 82, 113, 4, 194, 125, 43, 204, 239, 123, 213, 115, 20,
+            ],
+            [
+                220, 98, 117, 193, 119, 218, 27, 102, 249, 36, 137, 180, 155, 14, 211, 213, 13,
+                255, 20, 98, 172, 189, 204, 31, 149, 211, 49, 213, 104, 23, 57, 27,
+            ],
+            [
+                156, 111, 21, 83, 24, 2, 212, 66, 186, 100, 207, 74, 204, 181, 87, 185, 125, 159,
+                139, 45, 202, 234, 198, 191, 107, 26, 7, 48, 234, 184, 186, 6,
+            ],
+            [
+                133, 179, 218, 46, 208, 159, 81, 76, 0, 221, 209, 240, 92, 181, 152, 220, 215, 44,
+                109, 194, 92, 78, 208, 226, 14, 50, 91, 40, 61, 4, 29, 28,
+            ],
+            [
+                11, 121, 103, 58, 7, 215, 9, 211, 92, 203, 23, 102, 169, 213, 26, 241, 205, 215,
+                78, 208, 151, 221, 23, 5, 99, 0, 202, 154, 73, 158, 137, 60,
+            ],
+        ],
+        [
+            [
+                78, 29, 196, 154, 123, 85, 48, 170, 97, 89, 29, 251, 114, 125, 50, 70, 116, 227,
+                58, 189, 16, 137, 65, 223, 243, 247, 214, 86, 161, 230, 223, 22,
+            ],
+            [
+                210, 215, 96, 121, 162, 35, 88, 234, 68, 9, 143, 230, 184, 198, 255, 178, 178, 73,
+                242, 220, 242, 220, 191, 103, 173, 129, 232, 16, 232, 215, 0, 6,
+            ],
+            [
+                186, 223, 225, 138, 121, 190, 230, 79, 11, 100, 150, 69, 8, 147, 235, 184, 210,
+                200, 125, 209, 57, 151, 2, 137, 56, 252, 163, 233, 254, 203, 226, 5,
+            ],
+            [
+                15, 55, 216, 26, 88, 18, 240, 240, 53, 196, 152, 241, 37, 182, 81, 199, 186, 13,
+                169, 97, 206, 70, 66, 91, 53, 7, 201, 109, 101, 80, 188, 35,
+            ],
+            [
+                48, 39, 150, 246, 109, 236, 68, 226, 120, 4, 100, 46, 86, 29, 12, 130, 66, 252,
+                175, 0, 114, 72, 173, 30, 168, 44, 217, 243, 113, 68, 111, 43,
+            ],
+            [
+                225, 80, 174, 174, 112, 52, 250, 184, 110, 201, 179, 248, 66, 11, 228, 208, 21, 25,
+                136, 91, 233, 87, 246, 233, 247, 74, 39, 78, 102, 181, 49, 50,
+            ],
+            [
+                114, 198, 197, 155, 167, 185, 98, 18, 6, 144, 109, 222, 147, 239, 69, 186, 221,
+                161, 145, 164, 140, 92, 154, 231, 113, 159, 171, 22, 242, 240, 196, 22,
+            ],
+            [
+                123, 162, 235, 31, 93, 194, 125, 224, 57, 132, 168, 176, 88, 66, 245, 233, 196,
+                184, 115, 58, 141, 90, 202, 190, 189, 225, 31, 162, 119, 74, 135, 49,
+            ],
+        ],
+        [
+            [
+                153, 158, 184, 93, 54, 48, 178, 160, 143, 116, 217, 104, 154, 168, 214, 210, 43,
+                117, 214, 11, 134, 70, 232, 99, 234, 57, 202, 150, 82, 195, 174, 6,
+            ],
+            [
+                121, 219, 173, 32, 227, 10, 43, 51, 5, 194, 205, 135, 141, 111, 7, 222, 181, 247,
+                80, 183, 186, 101, 197, 125, 189, 145, 183, 66, 162, 208, 155, 10,
+            ],
+            [
+                126, 22, 228, 231, 85, 87, 231, 188, 53, 204, 246, 242, 193, 183, 13, 183, 242,
+                151, 138, 80, 201, 137, 28, 61, 203, 123, 14, 230, 247, 98, 237, 29,
+            ],
+            [
+                78, 102, 63, 227, 209, 94, 225, 129, 248, 160, 39, 29, 38, 60, 126, 174, 238, 56,
+                243, 206, 132, 107, 30, 31, 254, 166, 76, 112, 72, 233, 144, 15,
+            ],
+            [
+                96, 243, 15, 198, 187, 252, 229, 42, 131, 153, 25, 217, 60, 240, 96, 29, 28, 192,
+                17, 87, 173, 10, 208, 210, 215, 218, 233, 6, 179, 36, 45, 0,
+            ],
+            [
+                59, 32, 128, 203, 44, 111, 145, 125, 208, 61, 227, 59, 122, 47, 110, 235, 236, 134,
+                245, 157, 252, 32, 19, 79, 245, 245, 65, 227, 61, 169, 156, 11,
+            ],
+            [
+                248, 231, 172, 99, 145, 251, 39, 129, 14, 135, 190, 112, 168, 219, 169, 89, 162,
+                143, 102, 225, 229, 206, 253, 86, 253, 221, 31, 63, 49, 36, 216, 7,
+            ],
+            [
+                65, 41, 246, 149, 243, 148, 87, 26, 56, 143, 160, 243, 48, 228, 60, 205, 243, 29,
+                86, 45, 219, 254, 37, 84, 6, 130, 37, 246, 164, 100, 69, 23,
+            ],
+        ],
+        [
+            [
+                164, 169, 107, 194, 191, 90, 128, 104, 86, 232, 20, 216, 179, 0, 18, 39, 193, 136,
+                83, 58, 185, 203, 45, 145, 142, 85, 195, 42, 207, 69, 253, 15,
+            ],
+            [
+                201, 224, 160, 110, 173, 169, 216, 61, 59, 231, 178, 92, 202, 21, 98, 248, 246, 60,
+                161, 124, 244, 34, 160, 254, 131, 189, 107, 214, 77, 28, 34, 58,
+            ],
+            [
+                168, 188, 67, 18, 186, 246, 252, 238, 138, 33, 40, 114, 252, 174, 164, 7, 97, 110,
+                70, 101, 83, 163, 178, 156, 39, 27, 131, 67, 27, 12, 214, 47,
+            ],
+            [
+                110, 63, 15, 178, 191, 104, 220, 26, 38, 107, 232, 239, 161, 190, 194, 10, 87, 143,
+                19, 89, 145, 199, 236, 20, 44, 255, 152, 218, 240, 103, 174, 56,
+            ],
+            [
+                196, 213, 40, 217, 146, 155, 238, 217, 33, 180, 81, 82, 18, 255, 202, 61, 232, 225,
+                20, 82, 36, 10, 161, 81, 34, 132, 39, 24, 98, 5, 71, 22,
+            ],
+            [
+                193, 217, 35, 65, 206, 15, 177, 89, 11, 193, 194, 229, 75, 23, 67, 246, 41, 155,
+                170, 57, 172, 160, 13, 252, 47, 16, 36, 1, 56, 1, 29, 51,
+            ],
+            [
+                100, 96, 244, 8, 40, 11, 20, 103, 129, 201, 219, 66, 70, 37, 160, 181, 178, 71, 47,
+                39, 205, 219, 112, 25, 141, 58, 86, 11, 64, 45, 244, 61,
+            ],
+            [
+                220, 166, 207, 8, 67, 167, 128, 164, 248, 105, 231, 60, 199, 219, 139, 180, 237,
+                199, 241, 49, 157, 53, 152, 40, 191, 247, 44, 64, 78, 221, 88, 52,
+            ],
+        ],
+        [
+            [
+                167, 203, 170, 39, 161, 121, 31, 152, 4, 203, 46, 133, 35, 29, 105, 186, 20, 47,
+                215, 234, 79, 169, 32, 26, 12, 47, 217, 243, 194, 216, 57, 38,
+            ],
+            [
+                36, 245, 10, 111, 211, 109, 170, 246, 165, 171, 245, 93, 73, 74, 115, 63, 238, 176,
+                143, 117, 54, 173, 180, 153, 85, 131, 51, 6, 245, 38, 179, 52,
+            ],
+            [
+                217, 32, 180, 105, 11, 211, 57, 173, 209, 114, 231, 122, 10, 15, 193, 175, 157, 58,
+                249, 128, 130, 196, 170, 133, 123, 194, 63, 12, 78, 134, 185, 56,
+            ],
+            [
+                58, 142, 27, 99, 127, 214, 128, 53, 43, 76, 158, 18, 182, 126, 52, 78, 161, 186,
+                167, 193, 62, 140, 16, 9, 58, 4, 31, 159, 46, 92, 206, 28,
+            ],
+            [
+                102, 49, 158, 89, 140, 62, 30, 84, 68, 74, 245, 74, 234, 106, 162, 50, 105, 54,
+                127, 246, 67, 90, 164, 25, 94, 54, 210, 55, 34, 190, 53, 12,
+            ],
+            [
+                183, 182, 91, 17, 163, 246, 184, 7, 184, 210, 64, 160, 103, 196, 1, 87, 2, 26, 42,
+                253, 54, 71, 163, 190, 253, 170, 25, 203, 237, 121, 165, 26,
+            ],
+            [
+                83, 206, 61, 35, 207, 76, 114, 120, 252, 187, 174, 161, 208, 234, 45, 226, 29, 130,
+                141, 131, 19, 220, 90, 69, 211, 182, 25, 88, 111, 57, 44, 14,
+            ],
+            [
+                155, 46, 227, 169, 48, 5, 210, 189, 2, 251, 13, 142, 122, 209, 76, 254, 64, 42,
+                140, 154, 200, 181, 20, 103, 182, 100, 11, 252, 173, 97, 177, 18,
+            ],
+        ],
+        [
+            [
+                150, 233, 225, 142, 250, 13, 158, 134, 244, 128, 134, 109, 113, 130, 245, 74, 210,
+                96, 221, 58, 14, 7, 126, 240, 1, 191, 43, 13, 215, 133, 204, 7,
+            ],
+            [
+                70, 224, 222, 56, 84, 113, 126, 152, 198, 129, 5, 65, 139, 17, 117, 57, 163, 58,
+                226, 72, 237, 183, 49, 152, 79, 233, 212, 168, 128, 26, 50, 30,
+            ],
+            [
+                249, 102, 193, 55, 156, 198, 208, 209, 55, 89, 12, 127, 89, 23, 59, 7, 42, 132,
+                101, 114, 71, 106, 11, 145, 167, 72, 163, 99, 230, 170, 151, 23,
+            ],
+            [
+                235, 78, 189, 134, 170, 218, 206, 1, 179, 161, 210, 116, 246, 117, 168, 31, 10,
+                199, 21, 133, 3, 132, 2, 148, 77, 85, 69, 157, 65, 227, 13, 53,
+            ],
+            [
+                199, 221, 89, 201, 74, 197, 188, 3, 234, 75, 46, 69, 22, 49, 84, 209, 110, 82, 127,
+                15, 223, 218, 125, 198, 189, 217, 97, 165, 74, 115, 61, 12,
+            ],
+            [
+                149, 23, 16, 25, 248, 184, 184, 85, 152, 114, 174, 30, 70, 107, 104, 207, 51, 253,
+                113, 50, 62, 231, 246, 57, 13, 135, 43, 46, 198, 60, 62, 61,
+            ],
+            [
+                231, 71, 230, 247, 113, 245, 77, 164, 253, 122, 119, 69, 134, 39, 83, 127, 226, 9,
+                168, 100, 217, 62, 200, 226, 61, 22, 26, 82, 217, 205, 69, 3,
+            ],
+            [
+                109, 6, 27, 31, 56, 147, 140, 171, 226, 213, 57, 5, 47, 35, 36, 13, 21, 58, 214,
+                48, 134, 159, 77, 196, 62, 115, 145, 26, 120, 235, 33, 52,
+            ],
+        ],
+        [
+            [
+                86, 166, 151, 67, 84, 172, 157, 99, 159, 229, 252, 105, 149, 145, 125, 75, 212,
+                160, 222, 172, 111, 44, 119, 202, 80, 173, 63, 47, 108, 80, 194, 42,
+            ],
+            [
+                216, 118, 50, 207, 109, 126, 146, 73, 252, 182, 193, 0, 141, 14, 199, 97, 248, 26,
+                166, 122, 30, 160, 246, 125, 117, 72, 76, 50, 50, 124, 5, 61,
+            ],
+            [
+                195, 110, 97, 137, 60, 84, 178, 74, 226, 219, 206, 80, 150, 92, 240, 60, 26, 128,
+                35, 5, 76, 164, 250, 240, 153, 204, 113, 126, 194, 157, 60, 54,
+            ],
+            [
+                58, 175, 35, 59, 176, 145, 251, 104, 242, 206, 103, 253, 78, 7, 194, 158, 15, 76,
+                118, 139, 197, 62, 20, 1, 83, 88, 209, 254, 136, 10, 54, 22,
+            ],
+            [
+                234, 55, 211, 156, 64, 197, 139, 185, 156, 88, 75, 117, 220, 210, 39, 17, 56, 214,
+                179, 24, 91, 207, 190, 52, 186, 86, 78, 110, 60, 34, 250, 49,
+            ],
+            [
+                202, 203, 73, 151, 77, 236, 231, 233, 45, 208, 155, 153, 157, 112, 167, 28, 207,
+                103, 10, 37, 115, 61, 80, 85, 186, 39, 130, 172, 35, 43, 104, 12,
+            ],
+            [
+                55, 51, 19, 8, 70, 7, 35, 163, 34, 161, 20, 11, 219, 167, 33, 120, 142, 135, 51,
+                222, 184, 90, 244, 56, 39, 93, 200, 125, 41, 118, 197, 59,
+            ],
+            [
+                235, 249, 104, 170, 102, 55, 96, 123, 15, 106, 52, 133, 221, 70, 76, 58, 217, 206,
+                25, 179, 244, 236, 20, 66, 10, 60, 16, 70, 255, 102, 151, 53,
+            ],
+        ],
+        [
+            [
+                247, 136, 237, 163, 7, 65, 57, 40, 104, 167, 102, 203, 127, 234, 53, 70, 103, 21,
+                50, 155, 132, 253, 148, 100, 33, 26, 69, 146, 255, 5, 242, 24,
+            ],
+            [
+                10, 128, 12, 56, 24, 152, 165, 89, 138, 235, 120, 162, 87, 142, 158, 142, 147, 51,
+                89, 198, 83, 181, 142, 69, 148, 8, 249, 227, 185, 94, 170, 50,
+            ],
+            [
+                72, 84, 137, 177, 62, 119, 152, 45, 21, 85, 131, 243, 221, 33, 189, 46, 197, 140,
+                108, 224, 154, 154, 87, 6, 187, 194, 122, 158, 43, 30, 77, 62,
+            ],
+            [
+                221, 207, 252, 238, 131, 24, 171, 191, 195, 162, 30, 17, 215, 192, 90, 194, 220,
+                68, 223, 127, 116, 254, 46, 249, 181, 48, 216, 46, 207, 79, 185, 12,
+            ],
+            [
+                233, 91, 242, 14, 4, 147, 214, 59, 178, 144, 63, 43, 46, 207, 222, 139, 43, 25,
+                142, 149, 46, 53, 70, 68, 242, 140, 20, 241, 238, 90, 7, 58,
+            ],
+            [
+                28, 242, 146, 79, 98, 177, 79, 10, 148, 33, 184, 56, 19, 74, 174, 214, 73, 53, 23,
+                147, 193, 178, 28, 1, 222, 148, 43, 47, 191, 245, 105, 27,
+            ],
+            [
+                105, 247, 93, 158, 115, 165, 255, 222, 213, 29, 24, 116, 204, 101, 13, 50, 20, 164,
+                117, 204, 36, 195, 38, 223, 188, 130, 15, 68, 226, 110, 84, 38,
+            ],
+            [
+                118, 121, 122, 148, 166, 50, 90, 6, 122, 67, 126, 227, 109, 33, 117, 198, 92, 40,
+                71, 87, 106, 61, 246, 70, 15, 181, 39, 139, 170, 221, 250, 54,
+            ],
+        ],
+        [
+            [
+                46, 198, 209, 107, 47, 220, 90, 135, 4, 243, 85, 214, 135, 134, 56, 122, 58, 116,
+                2, 37, 36, 106, 89, 147, 41, 143, 197, 11, 91, 26, 80, 2,
+            ],
+            [
+                130, 238, 251, 162, 50, 137, 123, 135, 61, 9, 88, 115, 180, 112, 12, 131, 134, 130,
+                203, 222, 86, 47, 153, 35, 122, 101, 119, 236, 205, 239, 150, 4,
+            ],
+            [
+                216, 52, 145, 145, 45, 203, 122, 117, 167, 14, 70, 148, 177, 158, 205, 125, 205,
+                177, 81, 121, 73, 59, 69, 83, 201, 30, 135, 128, 58, 42, 201, 37,
+            ],
+            [
+                130, 84, 141, 169, 12, 234, 117, 1, 162, 90, 133, 157, 136, 232, 229, 172, 0, 2,
+                245, 111, 201, 99, 54, 193, 24, 209, 201, 140, 67, 137, 109, 57,
+            ],
+            [
+                133, 155, 134, 236, 159, 47, 195, 23, 234, 153, 110, 235, 138, 22, 20, 147, 139,
+                160, 211, 38, 17, 30, 154, 167, 2, 108, 247, 235, 83, 252, 221, 26,
+            ],
+            [
+                215, 82, 222, 251, 247, 130, 61, 203, 161, 105, 159, 234, 17, 218, 101, 79, 209,
+                118, 95, 81, 39, 131, 60, 76, 175, 152, 51, 231, 32, 94, 108, 24,
+            ],
+            [
+                109, 74, 141, 88, 140, 200, 92, 251, 30, 163, 145, 206, 76, 13, 177, 255, 194, 158,
+                146, 57, 43, 88, 45, 11, 98, 178, 228, 132, 77, 74, 108, 35,
+            ],
+            [
+                135, 239, 49, 15, 229, 108, 68, 36, 36, 120, 196, 21, 50, 79, 185, 11, 254, 241,
+                110, 56, 102, 155, 223, 19, 122, 140, 158, 76, 88, 138, 90, 17,
+            ],
+        ],
+        [
+            [
+                16, 207, 37, 194, 43, 183, 239, 27, 86, 222, 184, 150, 119, 160, 226, 40, 119, 1,
+                189, 111, 31, 254, 39, 171, 70, 245, 171, 39, 43, 28, 184, 37,
+            ],
+            [
+                102, 206, 207, 32, 39, 43, 0, 225, 98, 87, 93, 225, 242, 113, 16, 132, 231, 102,
+                61, 24, 90, 83, 35, 165, 67, 114, 0, 48, 186, 236, 193, 32,
+            ],
+            [
+                130, 252, 197, 93, 7, 215, 54, 210, 56, 121, 149, 249, 140, 202, 219, 106, 45, 48,
+                236, 150, 168, 218, 185, 68, 202, 120, 175, 153, 218, 2, 1, 61,
+            ],
+            [
+                71, 212, 246, 160, 106, 104, 9, 209, 8, 249, 201, 41, 166, 150, 136, 139, 18, 30,
+                158, 25, 168, 117, 9, 41, 218, 186, 195, 202, 93, 152, 44, 14,
+            ],
+            [
+                34, 58, 7, 47, 125, 67, 97, 190, 98, 42, 68, 0, 244, 206, 61, 48, 243, 246, 137,
+                204, 234, 108, 203, 255, 193, 65, 188, 175, 200, 51, 84, 37,
+            ],
+            [
+                95, 250, 82, 246, 53, 195, 21, 187, 46, 198, 208, 115, 21, 62, 47, 96, 244, 135,
+                184, 221, 21, 94, 55, 235, 2, 58, 244, 158, 207, 79, 49, 14,
+            ],
+            [
+                37, 52, 198, 173, 137, 227, 11, 151, 11, 50, 163, 120, 17, 11, 208, 99, 31, 245,
+                240, 92, 10, 119, 76, 33, 39, 239, 76, 221, 254, 212, 140, 50,
+            ],
+            [
+                53, 89, 206, 249, 70, 215, 60, 11, 122, 126, 91, 109, 18, 69, 166, 220, 36, 43, 55,
+                98, 45, 17, 125, 238, 13, 144, 34, 149, 178, 39, 115, 38,
+            ],
+        ],
+        [
+            [
+                58, 151, 211, 16, 181, 2, 113, 106, 103, 175, 165, 4, 78, 207, 25, 132, 149, 6, 34,
+                8, 231, 243, 199, 81, 202, 9, 112, 233, 57, 80, 15, 32,
+            ],
+            [
+                175, 181, 185, 37, 115, 102, 232, 3, 119, 219, 107, 165, 50, 169, 249, 221, 44,
+                125, 18, 235, 139, 116, 24, 210, 47, 103, 186, 115, 9, 106, 84, 7,
+            ],
+            [
+                42, 171, 112, 62, 232, 89, 145, 94, 148, 68, 240, 183, 245, 243, 218, 193, 89, 176,
+                45, 42, 222, 203, 36, 59, 18, 193, 250, 40, 202, 196, 56, 49,
+            ],
+            [
+                206, 160, 241, 192, 92, 204, 67, 157, 70, 41, 97, 8, 63, 122, 203, 111, 26, 80, 68,
+                101, 61, 15, 37, 73, 246, 245, 34, 139, 43, 27, 105, 50,
+            ],
+            [
+                12, 7, 79, 220, 226, 223, 247, 134, 242, 163, 44, 74, 85, 189, 75, 18, 219, 87, 76,
+                79, 72, 236, 98, 245, 79, 250, 150, 225, 169, 116, 31, 50,
+            ],
+            [
+                114, 247, 98, 88, 153, 204, 143, 253, 123, 82, 223, 38, 50, 157, 241, 195, 2, 139,
+                112, 213, 37, 250, 190, 135, 211, 164, 178, 8, 84, 20, 55, 15,
+            ],
+            [
+                212, 23, 96, 145, 218, 41, 11, 104, 69, 246, 196, 133, 170, 69, 103, 149, 142, 194,
+                220, 17, 96, 226, 28, 58, 140, 188, 56, 181, 85, 3, 157, 25,
+            ],
+            [
+                1, 75, 167, 81, 145, 114, 15, 176, 98, 246, 186, 116, 61, 6, 13, 238, 102, 227, 78,
+                231, 45, 22, 232, 188, 54, 215, 174, 174, 117, 194, 249, 24,
+            ],
+        ],
+        [
+            [
+                5, 165, 10, 218, 167, 147, 29, 162, 40, 252, 187, 86, 73, 179, 69, 171, 141, 250,
+                58, 64, 2, 238, 64, 191, 115, 61, 218, 112, 251, 92, 58, 41,
+            ],
+            [
+                57, 212, 158, 206, 160, 176, 81, 176, 147, 118, 34, 57, 168, 116, 91, 223, 248,
+                135, 152, 205, 140, 167, 53, 231, 19, 175, 226, 169, 225, 204, 13, 32,
+            ],
+            [
+                55, 161, 148, 31, 238, 177, 26, 240, 190, 59, 234, 172, 85, 49, 157, 219, 75, 181,
+                130, 253, 141, 139, 210, 182, 253, 182, 236, 8, 239, 229, 3, 50,
+            ],
+            [
+                137, 14, 77, 56, 200, 158, 217, 194, 71, 255, 141, 36, 254, 232, 95, 248, 162, 136,
+                254, 13, 184, 172, 83, 156, 168, 65, 237, 246, 122, 71, 255, 7,
+            ],
+            [
+                119, 129, 133, 144, 233, 62, 123, 129, 34, 236, 194, 86, 64, 226, 152, 121, 93,
+                146, 148, 233, 90, 181, 154, 57, 189, 132, 201, 249, 167, 27, 178, 0,
+            ],
+            [
+                133, 248, 154, 199, 166, 21, 117, 129, 231, 200, 79, 12, 229, 28, 97, 115, 45, 240,
+                5, 224, 13, 137, 123, 5, 66, 230, 123, 255, 203, 147, 118, 45,
+            ],
+            [
+                201, 217, 177, 23, 130, 198, 196, 81, 252, 241, 84, 65, 141, 197, 193, 49, 127,
+                171, 245, 92, 245, 254, 168, 96, 8, 107, 151, 139, 197, 205, 9, 18,
+            ],
+            [
+                207, 125, 168, 38, 171, 139, 254, 37, 214, 243, 99, 37, 163, 65, 69, 141, 54, 47,
+                88, 133, 157, 8, 190, 13, 187, 198, 36, 71, 200, 45, 50, 32,
+            ],
+        ],
+        [
+            [
+                25, 111, 89, 89, 254, 94, 207, 105, 234, 242, 177, 158, 147, 100, 249, 118, 112,
+                112, 101, 97, 115, 224, 106, 75, 3, 159, 26, 185, 240, 201, 218, 21,
+            ],
+            [
+                57, 11, 154, 182, 221, 41, 145, 59, 113, 105, 221, 80, 185, 113, 134, 193, 128, 64,
+                164, 128, 251, 237, 199, 9, 185, 21, 96, 108, 160, 106, 34, 21,
+            ],
+            [
+                215, 55, 115, 28, 230, 123, 124, 213, 138, 73, 163, 250, 157, 185, 46, 82, 196,
+                159, 153, 136, 0, 174, 178, 146, 8, 36, 58, 21, 117, 228, 54, 23,
+            ],
+            [
+                117, 160, 208, 122, 24, 74, 171, 22, 61, 4, 186, 70, 77, 183, 236, 223, 138, 30,
+                14, 208, 29, 34, 126, 173, 53, 118, 24, 120, 201, 52, 157, 4,
+            ],
+            [
+                150, 211, 0, 195, 13, 134, 80, 55, 244, 82, 88, 180, 80, 19, 24, 239, 200, 49, 109,
+                245, 45, 181, 195, 97, 84, 113, 21, 98, 85, 217, 233, 51,
+            ],
+            [
+                49, 74, 57, 172, 208, 40, 252, 171, 106, 214, 233, 46, 49, 6, 47, 125, 14, 87, 114,
+                37, 85, 36, 2, 124, 227, 159, 126, 225, 185, 70, 36, 15,
+            ],
+            [
+                77, 149, 136, 176, 141, 178, 185, 139, 52, 195, 95, 76, 145, 169, 205, 2, 204, 127,
+                141, 247, 63, 73, 142, 111, 151, 150, 194, 84, 202, 210, 82, 57,
+            ],
+            [
+                112, 203, 138, 10, 119, 217, 226, 58, 164, 120, 231, 129, 128, 252, 222, 13, 74,
+                237, 62, 54, 81, 24, 86, 57, 167, 136, 173, 141, 127, 192, 172, 32,
+            ],
+        ],
+        [
+            [
+                143, 204, 181, 208, 100, 176, 232, 200, 220, 91, 7, 151, 233, 102, 51, 47, 198,
+                139, 65, 62, 134, 60, 188, 254, 95, 20, 219, 82, 205, 115, 214, 10,
+            ],
+            [
+                200, 158, 218, 160, 171, 181, 106, 148, 235, 182, 91, 152, 165, 143, 71, 232, 93,
+                160, 217, 195, 207, 137, 181, 233, 154, 214, 157, 87, 224, 73, 43, 47,
+            ],
+            [
+                134, 33, 147, 0, 137, 134, 251, 143, 231, 45, 156, 29, 169, 141, 135, 42, 118, 49,
+                119, 198, 173, 215, 2, 113, 86, 134, 187, 57, 133, 246, 107, 7,
+            ],
+            [
+                247, 61, 72, 168, 222, 207, 116, 78, 146, 203, 174, 211, 89, 116, 47, 80, 224, 189,
+                7, 78, 247, 214, 7, 11, 240, 70, 185, 104, 16, 103, 64, 41,
+            ],
+            [
+                72, 73, 107, 57, 224, 142, 155, 210, 160, 95, 68, 106, 46, 57, 27, 54, 65, 146,
+                246, 220, 36, 18, 168, 31, 11, 17, 156, 166, 105, 61, 152, 55,
+            ],
+            [
+                206, 1, 182, 189, 222, 118, 233, 215, 77, 50, 194, 18, 0, 85, 100, 185, 33, 176,
+                212, 205, 196, 130, 145, 122, 253, 247, 23, 202, 88, 116, 32, 21,
+            ],
+            [
+                212, 97, 232, 216, 22, 138, 203, 158, 18, 212, 51, 97, 207, 61, 171, 148, 87, 163,
+                255, 63, 134, 203, 103, 201, 27, 14, 80, 110, 49, 179, 114, 61,
+            ],
+            [
+                211, 211, 46, 255, 0, 215, 24, 197, 232, 239, 69, 123, 238, 238, 127, 134, 71, 163,
+                98, 71, 128, 114, 210, 246, 92, 185, 66, 60, 78, 231, 182, 26,
+            ],
+        ],
+        [
+            [
+                139, 78, 67, 174, 181, 145, 79, 1, 2, 244, 86, 132, 201, 86, 5, 220, 247, 198, 177,
+                218, 116, 4, 182, 188, 45, 12, 86, 207, 104, 25, 172, 8,
+            ],
+            [
+                16, 229, 144, 133, 117, 89, 250, 221, 127, 57, 118, 48, 248, 69, 28, 233, 46, 58,
+                143, 112, 54, 91, 111, 46, 252, 56, 160, 239, 98, 155, 252, 60,
+            ],
+            [
+                5, 242, 29, 88, 240, 148, 33, 25, 80, 124, 224, 207, 164, 90, 12, 240, 152, 16,
+                228, 182, 180, 150, 245, 96, 129, 169, 223, 75, 247, 49, 1, 56,
+            ],
+            [
+                133, 152, 66, 155, 217, 98, 109, 67, 190, 116, 192, 189, 5, 182, 109, 45, 49, 235,
+                10, 69, 42, 217, 51, 220, 36, 57, 98, 25, 206, 138, 79, 20,
+            ],
+            [
+                146, 116, 156, 161, 171, 99, 200, 208, 253, 84, 254, 133, 235, 135, 96, 228, 67,
+                253, 132, 210, 43, 113, 187, 117, 129, 47, 112, 192, 236, 108, 10, 5,
+            ],
+            [
+                183, 82, 205, 158, 104, 8, 158, 157, 13, 200, 86, 183, 252, 80, 185, 35, 119, 12,
+                32, 51, 184, 6, 55, 142, 74, 63, 49, 84, 36, 47, 37, 18,
+            ],
+            [
+                133, 79, 200, 128, 234, 42, 227, 60, 113, 3, 12, 187, 228, 251, 69, 103, 167, 18,
+                243, 183, 35, 31, 177, 203, 140, 76, 231, 99, 83, 180, 72, 42,
+            ],
+            [
+                140, 128, 64, 125, 27, 250, 170, 189, 164, 58, 205, 41, 8, 242, 217, 159, 160, 60,
+                119, 23, 136, 35, 121, 248, 75, 36, 15, 116, 210, 183, 48, 46,
+            ],
+        ],
+        [
+            [
+                239, 151, 157, 218, 86, 82, 62, 188, 18, 240, 73, 36, 238, 6, 103, 70, 54, 63, 51,
+                78, 226, 197, 108, 168, 207, 34, 195, 23, 180, 56, 44, 28,
+            ],
+            [
+                77, 24, 103, 152, 195, 176, 177, 99, 14, 16, 160, 136, 225, 47, 14, 144, 86, 57,
+                126, 95, 6, 136, 37, 231, 74, 188, 176, 87, 109, 105, 72, 13,
+            ],
+            [
+                74, 217, 158, 234, 56, 8, 105, 81, 2, 106, 245, 150, 187, 161, 126, 14, 254, 239,
+                217, 15, 207, 79, 11, 128, 181, 238, 88, 6, 172, 184, 65, 0,
+            ],
+            [
+                93, 219, 81, 56, 92, 85, 11, 225, 1, 199, 146, 219, 228, 183, 206, 191, 92, 152,
+                190, 136, 155, 17, 82, 171, 86, 45, 66, 153, 113, 205, 83, 10,
+            ],
+            [
+                102, 44, 235, 38, 41, 235, 80, 162, 12, 190, 237, 169, 190, 44, 16, 172, 242, 40,
+                83, 38, 172, 10, 213, 152, 41, 105, 122, 151, 84, 197, 159, 44,
+            ],
+            [
+                155, 168, 33, 187, 58, 198, 243, 18, 62, 219, 192, 123, 199, 12, 182, 97, 230, 111,
+                230, 235, 102, 195, 18, 104, 247, 198, 57, 82, 150, 227, 165, 62,
+            ],
+            [
+                95, 61, 132, 160, 223, 249, 70, 204, 254, 241, 149, 122, 128, 104, 105, 42, 108,
+                200, 251, 10, 133, 98, 172, 13, 60, 211, 200, 146, 47, 3, 229, 35,
+            ],
+            [
+                134, 46, 65, 203, 77, 218, 15, 76, 233, 127, 4, 187, 161, 215, 45, 53, 52, 251, 82,
+                164, 120, 217, 33, 12, 42, 30, 182, 139, 113, 106, 192, 17,
+            ],
+        ],
+        [
+            [
+                115, 192, 135, 69, 48, 231, 252, 135, 52, 166, 152, 239, 128, 113, 246, 202, 42,
+                108, 94, 250, 152, 151, 243, 52, 122, 99, 69, 230, 135, 83, 129, 3,
+            ],
+            [
+                27, 205, 255, 86, 255, 13, 130, 81, 80, 57, 187, 98, 119, 147, 61, 163, 62, 40, 80,
+                251, 235, 131, 77, 117, 211, 241, 250, 93, 225, 140, 117, 58,
+            ],
+            [
+                48, 114, 189, 159, 253, 27, 182, 205, 20, 73, 99, 34, 215, 92, 102, 150, 125, 154,
+                238, 119, 61, 102, 99, 217, 63, 67, 142, 51, 206, 203, 12, 38,
+            ],
+            [
+                50, 190, 218, 198, 142, 84, 73, 39, 227, 174, 239, 25, 214, 165, 10, 55, 235, 252,
+                197, 230, 215, 110, 184, 102, 168, 98, 225, 155, 43, 139, 96, 60,
+            ],
+            [
+                235, 126, 17, 45, 212, 70, 16, 209, 157, 89, 102, 173, 166, 115, 67, 247, 86, 240,
+                50, 140, 96, 246, 26, 25, 96, 159, 249, 119, 228, 140, 74, 41,
+            ],
+            [
+                253, 164, 107, 242, 6, 13, 118, 18, 192, 74, 144, 182, 129, 186, 245, 177, 48, 224,
+                120, 255, 139, 144, 131, 115, 208, 59, 62, 38, 74, 12, 25, 51,
+            ],
+            [
+                107, 170, 153, 253, 186, 166, 173, 39, 163, 118, 40, 74, 85, 74, 69, 118, 204, 13,
+                26, 246, 195, 113, 223, 166, 227, 178, 223, 7, 10, 79, 235, 57,
+            ],
+            [
+                141, 251, 173, 104, 175, 74, 192, 243, 158, 239, 151, 184, 117, 195, 192, 252, 55,
+                194, 202, 132, 238, 251, 161, 79, 6, 218, 97, 219, 233, 199, 127, 41,
+            ],
+        ],
+        [
+            [
+                74, 143, 43, 15, 24, 136, 6, 95, 165, 107, 170, 245, 61, 198, 69, 135, 219, 78,
+                223, 111, 110, 29, 119, 72, 129, 253, 146, 180, 171, 126, 133, 36,
+            ],
+            [
+                22, 45, 245, 157, 83, 138, 82, 242, 233, 232, 32, 117, 50, 101, 222, 94, 149, 210,
+                74, 241, 196, 28, 112, 49, 46, 104, 137, 185, 72, 54, 11, 48,
+            ],
+            [
+                37, 125, 88, 221, 145, 153, 248, 246, 82, 93, 231, 49, 118, 174, 204, 55, 186, 234,
+                231, 35, 149, 18, 59, 223, 42, 26, 105, 48, 89, 151, 139, 26,
+            ],
+            [
+                105, 139, 73, 163, 17, 86, 201, 202, 179, 219, 40, 2, 112, 32, 76, 78, 5, 101, 42,
+                133, 10, 99, 94, 153, 109, 149, 98, 175, 171, 139, 220, 23,
+            ],
+            [
+                72, 173, 103, 7, 215, 231, 165, 185, 83, 244, 45, 202, 255, 162, 213, 255, 89, 236,
+                9, 115, 59, 172, 119, 101, 177, 4, 200, 185, 116, 67, 23, 19,
+            ],
+            [
+                92, 44, 165, 115, 22, 10, 93, 91, 14, 99, 141, 24, 192, 156, 177, 192, 213, 203,
+                164, 122, 107, 247, 152, 173, 52, 92, 248, 220, 82, 194, 47, 8,
+            ],
+            [
+                78, 146, 138, 161, 33, 98, 239, 137, 37, 205, 129, 5, 95, 195, 240, 97, 9, 8, 34,
+                78, 206, 221, 130, 193, 144, 195, 39, 48, 69, 125, 43, 7,
+            ],
+            [
+                91, 18, 80, 6, 111, 62, 164, 173, 2, 2, 32, 94, 120, 18, 151, 94, 182, 195, 160,
+                99, 223, 29, 251, 44, 26, 247, 24, 121, 102, 107, 8, 43,
+            ],
+        ],
+        [
+            [
+                164, 194, 219, 24, 223, 87, 54, 160, 140, 58, 68, 70, 199, 159, 79, 182, 91, 68,
+                128, 154, 157, 190, 209, 249, 82, 239, 5, 80, 74, 147, 120, 29,
+            ],
+            [
+                148, 180, 45, 121, 200, 175, 14, 162, 56, 54, 68, 105, 67, 59, 122, 138, 144, 8,
+                178, 54, 170, 85, 18, 187, 192, 169, 186, 248, 114, 234, 56, 21,
+            ],
+            [
+                120, 123, 143, 167, 151, 190, 27, 114, 7, 181, 175, 80, 168, 44, 62, 45, 118, 12,
+                215, 169, 115, 87, 14, 81, 80, 68, 209, 222, 63, 215, 120, 62,
+            ],
+            [
+                237, 25, 221, 61, 4, 213, 191, 55, 171, 198, 195, 60, 119, 199, 196, 244, 186, 137,
+                89, 64, 86, 237, 70, 76, 154, 179, 25, 121, 210, 10, 219, 39,
+            ],
+            [
+                117, 97, 157, 159, 235, 97, 84, 110, 54, 93, 199, 253, 151, 253, 169, 9, 102, 36,
+                50, 107, 40, 102, 87, 194, 15, 126, 203, 32, 125, 76, 39, 14,
+            ],
+            [
+                156, 29, 119, 173, 31, 106, 23, 38, 183, 149, 224, 73, 193, 11, 199, 209, 7, 20,
+                52, 237, 51, 139, 50, 145, 147, 117, 32, 249, 4, 12, 87, 31,
+            ],
+            [
+                158, 53, 149, 126, 193, 213, 229, 234, 204, 211, 93, 130, 24, 134, 28, 109, 4, 188,
+                137, 237, 140, 96, 88, 201, 52, 75, 35, 78, 192, 166, 17, 12,
+            ],
+            [
+                27, 73, 248, 236, 60, 72, 87, 174, 27, 28, 137, 108, 5, 151, 208, 91, 31, 187, 127,
+                242, 174, 13, 186, 166, 126, 192, 245, 153, 40, 145, 40, 46,
+            ],
+        ],
+        [
+            [
+                237, 34, 89, 20, 147, 243, 191, 3, 102, 255, 146, 244, 113, 201, 250, 168, 49, 139,
+                238, 109, 204, 228, 50, 202, 230, 23, 2, 173, 75, 247, 23, 6,
+            ],
+            [
+                75, 103, 6, 107, 77, 232, 226, 243, 32, 96, 89, 164, 28, 188, 26, 142, 64, 36, 62,
+                121, 221, 158, 219, 24, 155, 246, 10, 20, 186, 171, 103, 8,
+            ],
+            [
+                15, 201, 154, 57, 128, 86, 251, 149, 151, 230, 182, 101, 212, 23, 69, 179, 105,
+                198, 146, 212, 80, 10, 126, 194, 109, 169, 62, 53, 150, 62, 163, 45,
+            ],
+            [
+                212, 211, 187, 3, 27, 199, 207, 123, 76, 178, 97, 41, 254, 114, 25, 116, 122, 200,
+                71, 18, 168, 220, 25, 129, 145, 64, 51, 201, 241, 241, 151, 41,
+            ],
+            [
+                76, 133, 107, 109, 151, 173, 219, 169, 157, 63, 174, 254, 158, 84, 145, 251, 47, 4,
+                9, 111, 196, 148, 98, 94, 201, 61, 204, 227, 111, 114, 229, 38,
+            ],
+            [
+                5, 254, 149, 9, 86, 152, 66, 199, 34, 112, 3, 26, 118, 139, 70, 57, 125, 83, 179,
+                3, 120, 207, 72, 30, 216, 59, 27, 241, 134, 13, 150, 31,
+            ],
+            [
+                175, 41, 140, 71, 204, 64, 223, 238, 172, 30, 253, 27, 111, 64, 192, 124, 205, 170,
+                185, 137, 69, 119, 48, 82, 209, 118, 204, 245, 60, 135, 56, 28,
+            ],
+            [
+                93, 107, 103, 42, 89, 51, 205, 120, 34, 122, 30, 157, 46, 65, 35, 235, 36, 130, 60,
+                49, 89, 224, 134, 122, 50, 11, 167, 69, 183, 236, 70, 12,
+            ],
+        ],
+        [
+            [
+                157, 111, 251, 181, 48, 242, 8, 255, 0, 46, 47, 98, 107, 4, 144, 172, 38, 217, 202,
+                178, 22, 180, 89, 5, 100, 22, 119, 216, 171, 232, 224, 53,
+            ],
+            [
+                215, 55, 202, 7, 1, 106, 129, 244, 205, 242, 239, 254, 105, 131, 248, 232, 25, 243,
+                112, 47, 146, 58, 103, 105, 52, 69, 27, 69, 196, 8, 124, 20,
+            ],
+            [
+                61, 191, 29, 200, 114, 193, 170, 248, 194, 114, 123, 194, 11, 94, 80, 193, 43, 148,
+                61, 119, 118, 9, 12, 206, 208, 194, 199, 133, 30, 46, 44, 51,
+            ],
+            [
+                65, 143, 36, 33, 232, 69, 130, 193, 194, 73, 184, 104, 232, 1, 190, 70, 215, 46,
+                158, 38, 116, 172, 110, 50, 78, 248, 66, 142, 152, 80, 183, 3,
+            ],
+            [
+                53, 246, 194, 84, 191, 187, 49, 95, 75, 151, 72, 223, 22, 179, 221, 218, 101, 5,
+                244, 181, 168, 135, 126, 89, 9, 246, 209, 242, 131, 223, 53, 33,
+            ],
+            [
+                39, 19, 114, 184, 64, 188, 90, 89, 55, 131, 239, 131, 26, 25, 236, 184, 40, 64,
+                167, 147, 69, 178, 26, 24, 135, 231, 17, 186, 249, 130, 11, 8,
+            ],
+            [
+                26, 79, 3, 169, 20, 179, 144, 240, 209, 155, 182, 17, 192, 245, 253, 116, 250, 215,
+                164, 15, 20, 170, 140, 36, 190, 194, 1, 44, 103, 88, 70, 4,
+            ],
+            [
+                207, 37, 243, 227, 181, 54, 254, 200, 175, 49, 78, 57, 7, 166, 252, 17, 14, 238,
+                177, 163, 174, 33, 86, 154, 91, 97, 153, 77, 237, 7, 67, 49,
+            ],
+        ],
+        [
+            [
+                163, 1, 29, 49, 194, 71, 226, 117, 156, 42, 182, 103, 214, 87, 74, 150, 56, 208,
+                38, 157, 216, 244, 129, 177, 22, 33, 27, 116, 234, 101, 15, 44,
+            ],
+            [
+                217, 223, 116, 83, 48, 30, 200, 136, 135, 168, 65, 233, 206, 43, 145, 221, 85, 2,
+                50, 66, 174, 138, 29, 213, 239, 81, 236, 88, 47, 50, 245, 26,
+            ],
+            [
+                237, 107, 90, 99, 150, 186, 48, 9, 232, 101, 27, 19, 106, 91, 138, 184, 254, 97,
+                255, 42, 183, 94, 153, 55, 190, 136, 1, 229, 57, 53, 184, 21,
+            ],
+            [
+                186, 110, 181, 181, 105, 70, 82, 118, 80, 149, 207, 70, 176, 176, 253, 89, 139, 74,
+                147, 133, 160, 102, 233, 37, 28, 162, 167, 61, 47, 244, 32, 21,
+            ],
+            [
+                147, 129, 177, 47, 107, 238, 204, 144, 139, 100, 36, 136, 226, 48, 221, 17, 113,
+                183, 77, 98, 134, 156, 96, 212, 184, 212, 241, 179, 214, 191, 231, 16,
+            ],
+            [
+                177, 125, 49, 88, 2, 104, 223, 237, 134, 115, 254, 30, 202, 198, 132, 192, 231,
+                171, 64, 181, 69, 171, 200, 123, 15, 103, 195, 113, 6, 97, 210, 0,
+            ],
+            [
+                183, 21, 242, 22, 66, 170, 57, 222, 66, 235, 144, 76, 36, 101, 46, 84, 209, 183,
+                144, 239, 76, 254, 190, 89, 116, 63, 94, 232, 167, 228, 47, 60,
+            ],
+            [
+                73, 176, 150, 164, 208, 7, 71, 38, 212, 123, 50, 78, 190, 166, 38, 70, 80, 148,
+                150, 158, 48, 125, 10, 32, 38, 3, 28, 20, 170, 138, 229, 10,
+            ],
+        ],
+        [
+            [
+                119, 38, 151, 71, 106, 205, 175, 196, 224, 47, 83, 98, 101, 196, 237, 125, 199, 15,
+                203, 2, 84, 227, 137, 38, 116, 17, 212, 155, 100, 165, 33, 50,
+            ],
+            [
+                67, 249, 102, 249, 96, 108, 111, 204, 48, 61, 142, 240, 89, 221, 114, 246, 107,
+                151, 174, 206, 102, 44, 213, 81, 23, 252, 194, 226, 45, 200, 126, 13,
+            ],
+            [
+                160, 60, 224, 74, 218, 74, 67, 222, 192, 155, 53, 102, 228, 229, 131, 2, 87, 46,
+                239, 149, 214, 194, 247, 168, 81, 253, 22, 128, 113, 16, 174, 40,
+            ],
+            [
+                70, 185, 252, 228, 107, 86, 11, 220, 140, 185, 216, 255, 83, 43, 253, 159, 98, 234,
+                161, 172, 230, 198, 250, 179, 177, 60, 36, 224, 68, 70, 193, 11,
+            ],
+            [
+                14, 1, 241, 86, 188, 42, 54, 210, 206, 152, 127, 9, 50, 116, 79, 213, 161, 3, 246,
+                4, 96, 150, 155, 234, 79, 59, 164, 105, 220, 188, 143, 63,
+            ],
+            [
+                206, 230, 4, 75, 79, 144, 63, 148, 254, 147, 133, 123, 209, 67, 204, 219, 95, 236,
+                6, 253, 52, 135, 12, 206, 121, 62, 116, 220, 188, 147, 246, 37,
+            ],
+            [
+                205, 5, 31, 21, 8, 41, 242, 36, 65, 17, 163, 204, 212, 34, 38, 227, 207, 190, 229,
+                205, 114, 69, 240, 140, 64, 233, 246, 72, 255, 126, 30, 57,
+            ],
+            [
+                231, 94, 77, 211, 241, 198, 118, 120, 14, 132, 35, 123, 210, 75, 47, 163, 49, 146,
+                212, 55, 196, 9, 2, 94, 45, 41, 246, 58, 164, 76, 248, 53,
+            ],
+        ],
+        [
+            [
+                129, 96, 121, 37, 8, 9, 222, 73, 124, 17, 162, 47, 95, 91, 246, 72, 30, 174, 197,
+                118, 68, 68, 47, 102, 241, 68, 56, 147, 135, 22, 169, 26,
+            ],
+            [
+                14, 68, 52, 103, 150, 25, 66, 56, 90, 154, 56, 233, 175, 56, 239, 237, 39, 150, 93,
+                210, 243, 194, 226, 245, 190, 200, 90, 148, 31, 219, 87, 54,
+            ],
+            [
+                57, 185, 34, 233, 63, 129, 75, 212, 217, 200, 233, 112, 210, 86, 147, 128, 201,
+                157, 172, 155, 28, 207, 137, 133, 235, 35, 234, 45, 33, 36, 97, 12,
+            ],
+            [
+                53, 121, 245, 139, 88, 221, 241, 32, 38, 140, 165, 250, 19, 100, 174, 72, 142, 106,
+                96, 157, 42, 126, 104, 105, 28, 174, 180, 255, 47, 62, 204, 48,
+            ],
+            [
+                92, 209, 109, 28, 169, 33, 78, 8, 171, 145, 39, 4, 178, 116, 189, 228, 132, 129,
+                25, 81, 118, 17, 6, 119, 206, 49, 248, 212, 7, 64, 12, 17,
+            ],
+            [
+                254, 199, 42, 47, 83, 2, 114, 156, 209, 171, 17, 150, 106, 255, 77, 72, 80, 146,
+                30, 83, 246, 12, 55, 92, 106, 110, 113, 65, 246, 57, 130, 56,
+            ],
+            [
+                99, 63, 54, 189, 157, 210, 56, 217, 4, 193, 240, 185, 32, 111, 156, 137, 52, 240,
+                147, 30, 114, 251, 205, 105, 123, 189, 233, 204, 244, 104, 239, 53,
+            ],
+            [
+                103, 246, 232, 21, 85, 154, 104, 107, 24, 107, 66, 123, 18, 165, 121, 219, 174, 63,
+                58, 3, 2, 188, 127, 122, 223, 207, 5, 138, 79, 212, 174, 24,
+            ],
+        ],
+        [
+            [
+                51, 6, 254, 204, 234, 93, 105, 118, 21, 179, 180, 33, 74, 240, 178, 115, 22, 137,
+                64, 144, 217, 102, 133, 86, 150, 117, 41, 243, 60, 73, 208, 21,
+            ],
+            [
+                188, 49, 61, 29, 191, 223, 66, 225, 231, 193, 101, 189, 27, 114, 115, 2, 140, 162,
+                16, 130, 105, 156, 115, 229, 0, 108, 217, 92, 9, 239, 218, 18,
+            ],
+            [
+                180, 121, 238, 123, 237, 16, 216, 144, 200, 43, 37, 231, 30, 186, 127, 143, 78,
+                175, 130, 8, 110, 199, 168, 125, 196, 45, 194, 220, 104, 84, 75, 51,
+            ],
+            [
+                95, 84, 96, 32, 179, 117, 195, 169, 246, 50, 204, 42, 58, 179, 155, 226, 202, 126,
+                204, 84, 8, 190, 156, 234, 64, 103, 193, 230, 22, 43, 21, 25,
+            ],
+            [
+                76, 191, 224, 203, 145, 174, 193, 36, 254, 133, 215, 54, 12, 22, 224, 71, 155, 159,
+                39, 240, 63, 86, 227, 77, 144, 9, 143, 137, 237, 41, 30, 11,
+            ],
+            [
+                136, 149, 25, 144, 248, 201, 151, 128, 113, 32, 212, 56, 48, 173, 222, 48, 191, 51,
+                3, 77, 60, 248, 1, 153, 13, 50, 40, 249, 234, 176, 92, 0,
+            ],
+            [
+                176, 52, 32, 101, 59, 164, 137, 186, 167, 113, 243, 73, 28, 197, 47, 171, 185, 202,
+                234, 108, 79, 204, 154, 178, 237, 15, 3, 87, 21, 170, 24, 42,
+            ],
+            [
+                128, 92, 66, 29, 36, 134, 21, 246, 181, 3, 110, 21, 152, 117, 106, 132, 61, 232,
+                239, 82, 84, 218, 34, 61, 133, 184, 195, 233, 234, 9, 170, 29,
+            ],
+        ],
+        [
+            [
+                42, 21, 237, 112, 4, 77, 64, 154, 52, 55, 172, 212, 146, 182, 173, 131, 153, 11,
+                19, 187, 2, 54, 243, 221, 229, 237, 78, 82, 15, 94, 248, 7,
+            ],
+            [
+                152, 214, 71, 51, 183, 154, 130, 182, 203, 20, 232, 250, 200, 41, 213, 242, 214,
+                60, 166, 211, 206, 232, 190, 248, 40, 176, 199, 69, 184, 90, 143, 54,
+            ],
+            [
+                33, 91, 69, 7, 185, 118, 254, 152, 199, 16, 241, 148, 158, 59, 218, 249, 142, 229,
+                2, 250, 235, 62, 58, 91, 226, 157, 183, 131, 110, 50, 151, 23,
+            ],
+            [
+                81, 153, 125, 154, 108, 28, 235, 75, 195, 126, 172, 236, 138, 25, 55, 224, 167, 16,
+                226, 29, 104, 86, 59, 181, 157, 114, 112, 150, 215, 70, 198, 38,
+            ],
+            [
+                172, 121, 166, 213, 191, 177, 216, 227, 200, 197, 155, 3, 75, 194, 231, 153, 80,
+                29, 7, 237, 168, 150, 201, 241, 112, 58, 215, 80, 154, 163, 233, 16,
+            ],
+            [
+                226, 221, 151, 13, 183, 180, 188, 234, 219, 245, 9, 75, 191, 246, 89, 99, 171, 66,
+                90, 15, 255, 144, 251, 132, 236, 143, 118, 211, 172, 201, 56, 33,
+            ],
+            [
+                87, 64, 151, 83, 182, 170, 14, 168, 24, 212, 41, 68, 215, 163, 234, 199, 161, 31,
+                65, 208, 186, 90, 166, 156, 64, 240, 21, 3, 194, 3, 142, 1,
+            ],
+            [
+                140, 192, 118, 111, 92, 245, 18, 124, 181, 214, 97, 46, 33, 77, 164, 160, 141, 121,
+                47, 84, 82, 220, 200, 40, 242, 183, 238, 60, 207, 184, 69, 12,
+            ],
+        ],
+        [
+            [
+                64, 129, 244, 29, 36, 199, 42, 221, 18, 112, 224, 108, 48, 1, 13, 225, 40, 146,
+                171, 199, 150, 254, 213, 201, 55, 106, 186, 58, 136, 60, 186, 50,
+            ],
+            [
+                64, 146, 199, 26, 217, 87, 15, 77, 204, 69, 148, 54, 179, 112, 57, 57, 59, 121,
+                197, 41, 136, 21, 204, 139, 108, 216, 186, 74, 183, 90, 136, 57,
+            ],
+            [
+                85, 181, 119, 45, 147, 132, 238, 49, 28, 165, 255, 255, 149, 114, 19, 23, 14, 221,
+                106, 208, 75, 230, 228, 38, 50, 54, 118, 90, 193, 2, 46, 10,
+            ],
+            [
+                246, 112, 184, 227, 19, 202, 230, 175, 205, 66, 105, 31, 76, 36, 16, 107, 234, 138,
+                70, 211, 48, 126, 1, 2, 144, 107, 112, 51, 110, 179, 206, 42,
+            ],
+            [
+                243, 63, 196, 227, 35, 40, 4, 140, 108, 143, 95, 62, 9, 126, 245, 173, 55, 76, 210,
+                88, 183, 95, 173, 242, 77, 200, 109, 85, 118, 221, 6, 2,
+            ],
+            [
+                209, 104, 121, 137, 237, 252, 195, 2, 9, 235, 66, 219, 225, 209, 52, 153, 173, 250,
+                209, 101, 12, 135, 46, 171, 143, 206, 175, 100, 145, 161, 227, 48,
+            ],
+            [
+                157, 9, 192, 239, 66, 98, 166, 75, 44, 171, 152, 160, 121, 230, 202, 25, 241, 144,
+                125, 124, 203, 207, 54, 170, 195, 77, 83, 206, 81, 16, 99, 43,
+            ],
+            [
+                191, 219, 215, 24, 33, 203, 85, 71, 148, 176, 45, 50, 102, 97, 125, 150, 5, 1, 105,
+                239, 219, 104, 38, 187, 107, 202, 221, 27, 18, 118, 186, 23,
+            ],
+        ],
+        [
+            [
+                128, 236, 194, 35, 58, 79, 136, 126, 1, 40, 94, 76, 137, 101, 221, 161, 78, 95,
+                224, 126, 105, 51, 227, 183, 16, 159, 27, 21, 45, 20, 32, 21,
+            ],
+            [
+                163, 198, 5, 220, 162, 115, 106, 236, 194, 33, 86, 164, 184, 157, 88, 154, 70, 165,
+                39, 211, 93, 2, 30, 24, 55, 73, 152, 16, 188, 169, 183, 20,
+            ],
+            [
+                169, 31, 249, 5, 233, 166, 2, 0, 64, 16, 122, 246, 75, 107, 25, 84, 163, 164, 125,
+                189, 96, 94, 165, 60, 34, 105, 130, 20, 173, 61, 254, 15,
+            ],
+            [
+                223, 29, 203, 151, 30, 153, 188, 249, 9, 62, 69, 27, 214, 195, 170, 115, 201, 224,
+                147, 76, 231, 196, 110, 8, 14, 229, 126, 2, 67, 12, 136, 35,
+            ],
+            [
+                185, 103, 31, 62, 15, 119, 45, 212, 103, 173, 194, 9, 214, 35, 48, 194, 97, 143,
+                101, 220, 43, 88, 91, 244, 180, 193, 143, 46, 47, 232, 181, 5,
+            ],
+            [
+                6, 170, 118, 89, 109, 198, 252, 89, 194, 125, 40, 51, 81, 1, 21, 152, 38, 5, 165,
+                104, 167, 50, 50, 104, 53, 156, 212, 156, 98, 224, 156, 22,
+            ],
+            [
+                65, 201, 176, 181, 132, 145, 176, 44, 231, 169, 78, 148, 214, 245, 226, 106, 164,
+                122, 180, 243, 184, 97, 165, 117, 6, 165, 146, 151, 17, 78, 75, 24,
+            ],
+            [
+                208, 158, 52, 97, 34, 127, 236, 33, 236, 102, 129, 242, 17, 173, 192, 174, 92, 81,
+                198, 176, 39, 185, 115, 67, 155, 179, 125, 187, 254, 157, 37, 51,
+            ],
+        ],
+        [
+            [
+                26, 224, 30, 174, 164, 62, 224, 115, 208, 210, 147, 183, 24, 26, 254, 165, 65, 137,
+                121, 203, 232, 26, 158, 40, 49, 179, 250, 196, 254, 249, 16, 59,
+            ],
+            [
+                159, 229, 179, 128, 180, 61, 200, 15, 82, 170, 52, 162, 50, 30, 59, 45, 233, 200,
+                195, 238, 227, 59, 6, 95, 20, 87, 47, 153, 21, 112, 73, 30,
+            ],
+            [
+                131, 148, 220, 195, 208, 10, 178, 44, 132, 63, 151, 138, 182, 235, 163, 78, 49,
+                155, 188, 58, 115, 216, 250, 175, 167, 237, 9, 98, 138, 205, 139, 55,
+            ],
+            [
+                111, 41, 159, 207, 207, 117, 223, 238, 146, 151, 222, 61, 72, 99, 126, 200, 242,
+                124, 196, 175, 231, 16, 116, 50, 112, 103, 144, 168, 221, 87, 18, 30,
+            ],
+            [
+                16, 87, 51, 116, 213, 59, 73, 47, 1, 17, 225, 116, 38, 87, 202, 119, 80, 3, 179,
+                206, 133, 118, 217, 114, 129, 182, 23, 68, 246, 208, 252, 10,
+            ],
+            [
+                30, 27, 183, 209, 179, 113, 163, 170, 123, 87, 234, 233, 148, 151, 4, 109, 181, 95,
+                173, 63, 230, 248, 250, 240, 24, 212, 191, 140, 39, 202, 139, 19,
+            ],
+            [
+                103, 206, 137, 231, 77, 109, 214, 100, 159, 104, 162, 4, 117, 241, 161, 140, 233,
+                16, 64, 103, 253, 156, 102, 206, 42, 17, 54, 223, 110, 242, 51, 2,
+            ],
+            [
+                75, 117, 150, 69, 155, 173, 161, 226, 214, 209, 80, 131, 44, 126, 109, 186, 91, 64,
+                127, 146, 255, 176, 230, 8, 82, 185, 38, 19, 14, 128, 20, 33,
+            ],
+        ],
+        [
+            [
+                9, 101, 97, 112, 58, 249, 82, 97, 203, 107, 172, 117, 254, 143, 14, 8, 35, 116,
+                109, 197, 103, 25, 225, 181, 141, 120, 239, 157, 30, 211, 246, 48,
+            ],
+            [
+                83, 165, 37, 210, 13, 172, 136, 245, 36, 108, 249, 225, 189, 93, 231, 112, 255, 30,
+                192, 38, 64, 154, 120, 89, 86, 157, 224, 215, 11, 158, 242, 18,
+            ],
+            [
+                23, 198, 10, 119, 121, 118, 111, 209, 119, 221, 234, 120, 86, 61, 188, 185, 23,
+                129, 32, 106, 213, 37, 60, 232, 176, 251, 150, 82, 232, 17, 228, 0,
+            ],
+            [
+                173, 74, 23, 132, 236, 172, 255, 195, 40, 77, 127, 13, 161, 189, 8, 83, 74, 210,
+                63, 84, 196, 110, 104, 97, 167, 203, 181, 197, 122, 67, 249, 50,
+            ],
+            [
+                91, 135, 157, 26, 193, 48, 33, 4, 176, 115, 122, 220, 83, 9, 64, 131, 88, 61, 45,
+                231, 71, 33, 212, 127, 207, 64, 117, 31, 99, 142, 227, 16,
+            ],
+            [
+                220, 73, 7, 47, 174, 1, 123, 236, 7, 233, 203, 110, 147, 207, 236, 10, 1, 176, 250,
+                166, 130, 112, 8, 181, 56, 157, 183, 178, 172, 127, 85, 31,
+            ],
+            [
+                29, 249, 160, 220, 50, 77, 2, 63, 164, 151, 239, 30, 195, 62, 12, 41, 68, 235, 143,
+                217, 119, 20, 153, 117, 18, 162, 40, 236, 232, 245, 136, 57,
+            ],
+            [
+                62, 182, 168, 26, 80, 37, 155, 243, 176, 91, 91, 144, 37, 103, 156, 136, 230, 88,
+                213, 178, 121, 201, 28, 226, 155, 228, 239, 41, 255, 164, 0, 43,
+            ],
+        ],
+        [
+            [
+                64, 175, 84, 119, 45, 144, 127, 244, 59, 207, 247, 40, 176, 75, 36, 223, 23, 205,
+                104, 187, 127, 115, 93, 40, 209, 66, 64, 31, 149, 230, 94, 4,
+            ],
+            [
+                225, 157, 211, 3, 138, 118, 151, 182, 236, 149, 121, 220, 191, 237, 232, 240, 60,
+                117, 86, 110, 215, 96, 75, 9, 220, 207, 168, 208, 6, 92, 38, 45,
+            ],
+            [
+                158, 136, 138, 158, 245, 243, 159, 192, 153, 36, 177, 144, 40, 221, 221, 255, 39,
+                205, 69, 178, 13, 233, 221, 105, 159, 96, 79, 194, 132, 254, 164, 22,
+            ],
+            [
+                111, 192, 210, 194, 157, 179, 80, 193, 58, 150, 162, 40, 206, 64, 104, 227, 56, 82,
+                44, 202, 55, 151, 15, 150, 123, 24, 94, 222, 104, 150, 239, 17,
+            ],
+            [
+                200, 251, 107, 4, 51, 141, 6, 207, 154, 21, 126, 186, 144, 193, 158, 107, 167, 209,
+                98, 106, 143, 169, 6, 193, 48, 228, 86, 194, 247, 110, 210, 12,
+            ],
+            [
+                120, 120, 221, 41, 126, 132, 201, 15, 139, 26, 192, 235, 65, 19, 39, 91, 158, 231,
+                111, 155, 65, 94, 109, 150, 98, 99, 186, 186, 53, 12, 69, 7,
+            ],
+            [
+                131, 122, 247, 90, 211, 60, 137, 234, 149, 253, 84, 160, 30, 22, 248, 111, 140, 47,
+                162, 15, 19, 201, 240, 76, 111, 107, 202, 87, 113, 100, 159, 45,
+            ],
+            [
+                91, 185, 37, 148, 200, 137, 161, 65, 254, 121, 222, 40, 92, 203, 61, 210, 141, 209,
+                64, 72, 157, 81, 81, 97, 122, 207, 64, 14, 243, 61, 3, 3,
+            ],
+        ],
+        [
+            [
+                150, 130, 147, 163, 157, 32, 119, 158, 188, 27, 148, 50, 187, 31, 154, 52, 74, 55,
+                232, 60, 191, 112, 199, 108, 121, 205, 103, 62, 171, 58, 105, 8,
+            ],
+            [
+                43, 227, 42, 45, 169, 112, 111, 193, 70, 179, 205, 200, 16, 215, 237, 0, 237, 82,
+                207, 59, 32, 46, 93, 207, 102, 251, 147, 227, 7, 139, 204, 28,
+            ],
+            [
+                174, 212, 96, 83, 240, 159, 24, 60, 199, 209, 150, 150, 209, 151, 136, 211, 165,
+                210, 108, 235, 112, 82, 166, 118, 243, 3, 47, 90, 73, 72, 115, 16,
+            ],
+            [
+                119, 249, 157, 89, 174, 9, 143, 219, 241, 49, 197, 231, 82, 65, 163, 169, 124, 59,
+                211, 193, 236, 46, 77, 146, 179, 92, 49, 87, 150, 161, 221, 55,
+            ],
+            [
+                4, 141, 47, 133, 113, 39, 180, 254, 192, 82, 36, 23, 157, 230, 154, 224, 150, 149,
+                253, 68, 166, 178, 183, 212, 91, 68, 100, 16, 126, 11, 6, 34,
+            ],
+            [
+                119, 30, 138, 26, 204, 81, 68, 58, 133, 237, 149, 27, 243, 31, 90, 136, 164, 51,
+                15, 39, 103, 200, 118, 223, 116, 214, 232, 16, 243, 205, 106, 50,
+            ],
+            [
+                233, 13, 116, 241, 236, 113, 187, 255, 171, 12, 224, 169, 236, 100, 97, 205, 215,
+                60, 37, 5, 81, 180, 217, 208, 79, 236, 252, 216, 5, 242, 2, 2,
+            ],
+            [
+                173, 112, 117, 118, 177, 168, 37, 109, 207, 72, 242, 127, 230, 183, 30, 87, 141,
+                70, 149, 121, 193, 174, 226, 179, 223, 66, 45, 152, 159, 32, 237, 23,
+            ],
+        ],
+        [
+            [
+                60, 168, 42, 184, 180, 164, 65, 176, 61, 151, 175, 64, 121, 112, 76, 79, 127, 157,
+                148, 92, 115, 89, 242, 167, 130, 226, 248, 78, 222, 103, 212, 52,
+            ],
+            [
+                198, 177, 131, 203, 102, 210, 114, 17, 254, 6, 65, 188, 25, 94, 59, 155, 64, 84,
+                52, 84, 199, 109, 195, 137, 107, 12, 218, 199, 41, 3, 238, 35,
+            ],
+            [
+                3, 118, 184, 250, 191, 146, 28, 72, 74, 100, 177, 51, 59, 136, 178, 173, 3, 220,
+                63, 69, 13, 208, 139, 168, 27, 53, 209, 23, 46, 88, 203, 6,
+            ],
+            [
+                195, 68, 83, 214, 220, 54, 23, 27, 38, 174, 41, 50, 116, 245, 55, 179, 172, 20, 46,
+                132, 202, 71, 202, 241, 94, 232, 73, 109, 213, 43, 254, 19,
+            ],
+            [
+                87, 137, 218, 87, 148, 166, 87, 134, 113, 60, 226, 19, 180, 11, 155, 186, 174, 207,
+                190, 92, 164, 6, 31, 244, 183, 188, 215, 140, 165, 10, 73, 34,
+            ],
+            [
+                137, 246, 15, 153, 100, 59, 6, 5, 182, 26, 246, 218, 213, 109, 73, 116, 224, 138,
+                119, 52, 36, 87, 57, 79, 170, 131, 32, 17, 61, 250, 84, 43,
+            ],
+            [
+                108, 220, 112, 48, 247, 168, 23, 97, 227, 225, 166, 4, 188, 59, 58, 189, 220, 242,
+                90, 138, 119, 108, 204, 176, 211, 207, 167, 116, 43, 0, 184, 8,
+            ],
+            [
+                194, 189, 225, 162, 199, 149, 151, 0, 118, 137, 69, 135, 248, 174, 59, 226, 252,
+                179, 16, 147, 169, 23, 138, 28, 174, 238, 74, 201, 22, 214, 151, 63,
+            ],
+        ],
+        [
+            [
+                165, 92, 211, 247, 232, 198, 105, 182, 92, 70, 21, 188, 186, 0, 138, 197, 178, 138,
+                167, 151, 255, 49, 249, 221, 210, 62, 126, 118, 135, 45, 117, 16,
+            ],
+            [
+                134, 134, 47, 171, 62, 241, 184, 112, 31, 197, 45, 42, 173, 176, 207, 173, 145,
+                164, 4, 143, 142, 97, 252, 226, 182, 255, 51, 24, 75, 40, 28, 49,
+            ],
+            [
+                107, 17, 73, 143, 121, 213, 140, 66, 93, 26, 216, 78, 17, 193, 214, 117, 121, 165,
+                241, 12, 32, 212, 171, 41, 182, 92, 60, 165, 217, 200, 118, 52,
+            ],
+            [
+                89, 40, 211, 150, 218, 165, 129, 200, 250, 13, 30, 237, 138, 100, 50, 36, 205, 77,
+                183, 251, 147, 0, 197, 155, 153, 10, 73, 42, 230, 28, 213, 22,
+            ],
+            [
+                79, 232, 237, 220, 10, 164, 112, 245, 251, 69, 216, 64, 208, 250, 103, 68, 74, 30,
+                19, 243, 240, 161, 27, 97, 155, 102, 214, 174, 42, 113, 114, 48,
+            ],
+            [
+                225, 230, 72, 107, 77, 190, 50, 133, 140, 249, 36, 22, 254, 101, 142, 190, 171, 34,
+                158, 200, 195, 9, 233, 19, 131, 147, 139, 125, 139, 145, 90, 62,
+            ],
+            [
+                97, 83, 28, 160, 24, 100, 127, 200, 0, 61, 37, 194, 73, 244, 7, 191, 227, 192, 87,
+                193, 81, 250, 176, 48, 14, 214, 246, 159, 148, 85, 106, 23,
+            ],
+            [
+                139, 140, 151, 174, 164, 203, 72, 4, 123, 159, 223, 95, 174, 126, 58, 242, 251,
+                139, 34, 119, 171, 186, 65, 32, 254, 130, 173, 81, 156, 185, 185, 51,
+            ],
+        ],
+        [
+            [
+                238, 147, 25, 150, 212, 147, 227, 134, 146, 13, 42, 164, 163, 214, 141, 243, 39,
+                78, 203, 162, 169, 199, 126, 176, 68, 121, 191, 171, 29, 50, 53, 8,
+            ],
+            [
+                47, 132, 206, 70, 174, 200, 188, 210, 237, 153, 224, 154, 243, 53, 74, 18, 54, 162,
+                51, 52, 84, 217, 254, 140, 92, 118, 217, 29, 177, 245, 142, 16,
+            ],
+            [
+                141, 194, 168, 110, 221, 161, 120, 219, 73, 129, 146, 215, 108, 79, 242, 56, 191,
+                91, 77, 121, 250, 27, 105, 105, 246, 190, 122, 70, 2, 133, 98, 46,
+            ],
+            [
+                177, 71, 218, 187, 89, 239, 255, 6, 42, 72, 81, 223, 214, 151, 30, 177, 67, 211,
+                110, 246, 56, 22, 178, 192, 136, 96, 37, 220, 124, 187, 160, 13,
+            ],
+            [
+                34, 21, 66, 148, 208, 194, 106, 60, 109, 46, 132, 97, 200, 158, 74, 165, 207, 101,
+                79, 5, 17, 7, 167, 22, 79, 209, 117, 19, 181, 203, 9, 9,
+            ],
+            [
+                209, 167, 75, 122, 61, 173, 150, 239, 31, 250, 40, 146, 63, 193, 224, 43, 76, 229,
+                160, 250, 205, 125, 27, 24, 200, 220, 131, 59, 27, 225, 85, 9,
+            ],
+            [
+                114, 100, 8, 201, 198, 119, 57, 63, 198, 7, 1, 181, 161, 3, 36, 13, 106, 68, 188,
+                147, 73, 66, 187, 127, 173, 243, 160, 250, 185, 201, 104, 6,
+            ],
+            [
+                103, 45, 170, 235, 80, 108, 64, 194, 95, 140, 203, 28, 83, 9, 104, 170, 230, 114,
+                133, 211, 3, 9, 112, 212, 214, 146, 241, 20, 105, 73, 17, 54,
+            ],
+        ],
+        [
+            [
+                148, 249, 186, 45, 168, 92, 25, 8, 92, 11, 113, 90, 162, 47, 228, 53, 15, 55, 199,
+                4, 64, 19, 172, 114, 69, 247, 162, 12, 209, 66, 233, 5,
+            ],
+            [
+                217, 70, 132, 55, 151, 180, 68, 195, 62, 212, 247, 163, 155, 106, 241, 191, 66, 78,
+                186, 183, 106, 140, 230, 18, 94, 120, 184, 75, 22, 162, 122, 48,
+            ],
+            [
+                91, 211, 31, 41, 165, 81, 179, 47, 156, 217, 66, 216, 50, 225, 12, 232, 202, 157,
+                107, 197, 183, 216, 41, 252, 114, 143, 122, 61, 246, 224, 9, 40,
+            ],
+            [
+                247, 145, 244, 148, 53, 229, 208, 207, 126, 104, 159, 12, 220, 212, 182, 242, 35,
+                226, 19, 219, 215, 182, 4, 205, 179, 127, 145, 68, 145, 124, 201, 53,
+            ],
+            [
+                152, 218, 83, 59, 103, 157, 166, 193, 254, 243, 45, 15, 70, 36, 140, 129, 213, 45,
+                198, 143, 197, 26, 223, 48, 96, 61, 192, 38, 31, 205, 180, 6,
+            ],
+            [
+                201, 113, 13, 130, 58, 131, 157, 252, 168, 41, 61, 82, 215, 198, 5, 64, 21, 117,
+                230, 243, 40, 165, 220, 28, 218, 134, 36, 68, 58, 236, 1, 4,
+            ],
+            [
+                53, 77, 48, 223, 53, 6, 137, 36, 234, 209, 96, 118, 25, 77, 173, 24, 85, 132, 121,
+                67, 235, 60, 77, 181, 46, 32, 84, 95, 238, 21, 75, 12,
+            ],
+            [
+                179, 93, 138, 200, 66, 7, 136, 73, 71, 213, 179, 86, 231, 228, 132, 54, 188, 223,
+                86, 53, 131, 69, 117, 10, 207, 109, 181, 130, 237, 56, 227, 20,
+            ],
+        ],
+        [
+            [
+                63, 209, 133, 23, 121, 80, 171, 255, 230, 39, 173, 106, 70, 213, 24, 46, 158, 159,
+                159, 55, 254, 147, 179, 226, 189, 145, 255, 219, 187, 87, 14, 34,
+            ],
+            [
+                46, 192, 116, 214, 50, 82, 97, 90, 57, 93, 208, 155, 217, 199, 194, 190, 184, 122,
+                199, 132, 159, 37, 49, 8, 115, 216, 46, 121, 197, 179, 188, 58,
+            ],
+            [
+                67, 228, 91, 192, 181, 71, 44, 131, 36, 201, 228, 244, 159, 60, 165, 253, 42, 227,
+                126, 123, 222, 14, 8, 143, 12, 24, 20, 107, 75, 87, 29, 55,
+            ],
+            [
+                30, 182, 249, 182, 164, 83, 11, 11, 117, 17, 199, 213, 4, 87, 241, 247, 176, 129,
+                7, 119, 43, 70, 127, 217, 109, 24, 176, 234, 14, 245, 218, 8,
+            ],
+            [
+                206, 246, 69, 189, 151, 25, 200, 24, 151, 90, 37, 209, 55, 72, 134, 186, 252, 232,
+                157, 191, 216, 233, 158, 117, 126, 112, 217, 234, 253, 204, 244, 26,
+            ],
+            [
+                232, 69, 160, 113, 144, 32, 161, 108, 244, 158, 250, 68, 215, 124, 228, 45, 1, 105,
+                140, 33, 104, 111, 130, 247, 136, 25, 137, 177, 50, 167, 67, 5,
+            ],
+            [
+                174, 246, 140, 171, 40, 110, 106, 62, 253, 204, 167, 221, 238, 14, 236, 47, 57, 91,
+                215, 253, 147, 79, 165, 178, 245, 59, 147, 204, 18, 148, 215, 24,
+            ],
+            [
+                100, 238, 25, 98, 181, 178, 88, 244, 153, 99, 153, 127, 81, 192, 245, 11, 230, 31,
+                188, 153, 147, 197, 114, 171, 138, 238, 229, 39, 134, 145, 0, 34,
+            ],
+        ],
+        [
+            [
+                47, 176, 53, 247, 221, 13, 21, 34, 125, 91, 100, 166, 67, 65, 243, 162, 105, 107,
+                109, 240, 21, 225, 121, 76, 174, 168, 200, 24, 59, 234, 245, 57,
+            ],
+            [
+                209, 19, 148, 70, 232, 236, 109, 13, 92, 62, 243, 158, 211, 184, 220, 41, 144, 149,
+                0, 177, 242, 72, 42, 80, 155, 110, 228, 225, 242, 55, 207, 4,
+            ],
+            [
+                127, 37, 80, 237, 127, 227, 68, 192, 233, 143, 142, 237, 133, 238, 145, 246, 122,
+                213, 76, 143, 217, 99, 161, 196, 175, 175, 86, 85, 62, 74, 197, 30,
+            ],
+            [
+                90, 13, 241, 245, 240, 73, 63, 138, 96, 145, 227, 133, 221, 66, 34, 165, 227, 20,
+                223, 196, 254, 64, 171, 32, 85, 11, 39, 246, 163, 168, 248, 41,
+            ],
+            [
+                48, 149, 20, 2, 210, 62, 14, 208, 117, 199, 208, 10, 141, 149, 208, 23, 129, 247,
+                140, 208, 78, 29, 11, 229, 252, 160, 209, 72, 120, 252, 25, 32,
+            ],
+            [
+                252, 110, 48, 90, 130, 166, 123, 17, 76, 95, 62, 100, 237, 50, 203, 103, 73, 67,
+                118, 132, 26, 170, 202, 231, 127, 221, 44, 239, 45, 171, 130, 0,
+            ],
+            [
+                188, 101, 225, 67, 67, 241, 110, 59, 155, 59, 168, 105, 192, 122, 248, 32, 7, 49,
+                143, 231, 9, 7, 136, 88, 84, 50, 40, 59, 145, 69, 217, 46,
+            ],
+            [
+                126, 165, 176, 139, 68, 100, 112, 166, 31, 187, 146, 41, 177, 50, 18, 200, 34, 199,
+                244, 223, 19, 88, 128, 238, 226, 186, 212, 122, 151, 61, 155, 57,
+            ],
+        ],
+        [
+            [
+                29, 14, 140, 32, 84, 124, 118, 22, 17, 55, 39, 3, 193, 204, 14, 169, 190, 128, 32,
+                153, 159, 248, 186, 164, 221, 81, 0, 118, 133, 68, 49, 14,
+            ],
+            [
+                100, 29, 65, 18, 22, 134, 110, 135, 8, 41, 233, 93, 1, 232, 15, 65, 161, 32, 182,
+                243, 253, 18, 77, 146, 43, 0, 76, 79, 173, 44, 228, 30,
+            ],
+            [
+                234, 164, 157, 86, 70, 63, 117, 223, 126, 105, 94, 85, 226, 202, 77, 206, 10, 242,
+                126, 220, 141, 33, 193, 178, 228, 239, 71, 0, 63, 23, 185, 3,
+            ],
+            [
+                178, 7, 59, 255, 11, 196, 28, 17, 115, 60, 253, 57, 179, 212, 56, 234, 88, 210,
+                227, 187, 13, 27, 188, 98, 131, 175, 211, 172, 90, 177, 201, 58,
+            ],
+            [
+                189, 248, 191, 38, 92, 253, 27, 2, 218, 0, 19, 79, 186, 14, 254, 89, 10, 74, 242,
+                133, 147, 10, 184, 192, 152, 178, 18, 67, 51, 152, 205, 43,
+            ],
+            [
+                31, 41, 2, 12, 4, 100, 118, 74, 14, 137, 243, 154, 16, 184, 131, 226, 200, 241, 59,
+                206, 55, 125, 12, 71, 208, 184, 97, 231, 168, 105, 137, 21,
+            ],
+            [
+                142, 9, 231, 129, 91, 49, 182, 242, 154, 138, 235, 218, 104, 36, 169, 108, 145,
+                174, 194, 76, 186, 65, 68, 75, 252, 46, 130, 114, 185, 142, 113, 40,
+            ],
+            [
+                162, 146, 77, 207, 4, 195, 170, 7, 224, 200, 226, 92, 122, 33, 245, 80, 207, 75,
+                120, 150, 227, 174, 81, 200, 220, 93, 51, 243, 141, 59, 87, 11,
+            ],
+        ],
+        [
+            [
+                173, 74, 85, 254, 29, 208, 230, 217, 105, 39, 236, 188, 227, 24, 37, 31, 102, 245,
+                70, 147, 164, 15, 17, 161, 49, 58, 246, 54, 144, 182, 24, 31,
+            ],
+            [
+                191, 239, 109, 106, 195, 61, 123, 201, 26, 83, 89, 194, 165, 143, 113, 63, 76, 62,
+                196, 15, 107, 128, 183, 198, 72, 37, 180, 170, 255, 44, 94, 57,
+            ],
+            [
+                241, 79, 208, 160, 153, 64, 11, 232, 111, 21, 51, 161, 79, 135, 160, 158, 101, 107,
+                203, 75, 231, 217, 201, 201, 59, 226, 25, 130, 4, 208, 124, 29,
+            ],
+            [
+                44, 210, 213, 27, 97, 130, 2, 202, 74, 59, 74, 92, 168, 89, 176, 97, 64, 52, 178,
+                187, 41, 243, 65, 196, 231, 61, 156, 1, 204, 184, 228, 24,
+            ],
+            [
+                58, 251, 150, 246, 15, 90, 31, 42, 249, 199, 128, 117, 124, 10, 251, 22, 7, 109,
+                147, 218, 241, 39, 243, 34, 10, 221, 154, 196, 117, 196, 25, 27,
+            ],
+            [
+                226, 17, 178, 172, 252, 36, 19, 73, 106, 219, 43, 17, 8, 193, 5, 159, 84, 217, 207,
+                59, 14, 145, 32, 69, 22, 212, 105, 86, 238, 36, 234, 23,
+            ],
+            [
+                52, 29, 207, 142, 158, 151, 71, 10, 156, 181, 75, 189, 34, 249, 18, 243, 216, 62,
+                182, 149, 210, 45, 28, 5, 27, 179, 201, 152, 219, 124, 188, 41,
+            ],
+            [
+                68, 17, 194, 134, 200, 254, 250, 220, 171, 40, 154, 48, 198, 197, 242, 31, 20, 72,
+                183, 159, 76, 212, 106, 77, 211, 197, 186, 227, 167, 198, 150, 2,
+            ],
+        ],
+        [
+            [
+                109, 71, 37, 90, 63, 89, 64, 66, 137, 215, 15, 250, 139, 73, 126, 30, 69, 146, 214,
+                108, 87, 84, 45, 23, 39, 59, 76, 80, 96, 99, 250, 45,
+            ],
+            [
+                56, 142, 165, 215, 13, 241, 32, 24, 81, 201, 247, 153, 50, 185, 26, 224, 76, 89,
+                225, 48, 95, 120, 45, 219, 10, 153, 3, 93, 209, 176, 158, 30,
+            ],
+            [
+                88, 194, 167, 252, 29, 137, 173, 43, 56, 63, 35, 232, 35, 87, 60, 130, 216, 6, 197,
+                227, 207, 147, 7, 225, 118, 33, 195, 117, 40, 103, 121, 7,
+            ],
+            [
+                236, 51, 49, 12, 152, 87, 93, 58, 126, 129, 168, 193, 240, 251, 115, 97, 7, 82, 94,
+                169, 11, 67, 187, 40, 146, 53, 169, 162, 167, 114, 45, 15,
+            ],
+            [
+                166, 182, 172, 237, 150, 21, 128, 109, 193, 58, 26, 51, 168, 100, 138, 12, 76, 65,
+                246, 229, 165, 167, 125, 141, 146, 219, 107, 48, 121, 225, 13, 51,
+            ],
+            [
+                175, 194, 66, 204, 164, 188, 87, 109, 87, 214, 45, 229, 34, 123, 48, 158, 41, 87,
+                251, 40, 147, 118, 240, 169, 137, 174, 195, 109, 172, 154, 222, 50,
+            ],
+            [
+                177, 183, 188, 239, 148, 204, 24, 100, 60, 116, 134, 150, 241, 161, 220, 199, 25,
+                166, 237, 20, 108, 152, 50, 69, 223, 18, 22, 214, 179, 132, 245, 22,
+            ],
+            [
+                116, 48, 3, 45, 23, 187, 243, 39, 75, 44, 167, 195, 20, 199, 174, 247, 107, 133,
+                22, 210, 107, 132, 79, 237, 66, 36, 100, 29, 154, 22, 70, 20,
+            ],
+        ],
+        [
+            [
+                94, 174, 130, 5, 13, 33, 84, 29, 233, 148, 210, 142, 191, 31, 175, 180, 166, 6,
+                163, 30, 136, 155, 187, 4, 24, 103, 109, 147, 71, 232, 39, 24,
+            ],
+            [
+                254, 38, 19, 248, 241, 160, 189, 243, 84, 14, 143, 176, 68, 57, 49, 3, 239, 29,
+                253, 21, 201, 143, 12, 164, 147, 88, 100, 217, 173, 78, 196, 42,
+            ],
+            [
+                48, 211, 207, 215, 215, 206, 132, 27, 5, 64, 84, 58, 134, 26, 36, 150, 6, 50, 130,
+                240, 181, 158, 52, 119, 245, 122, 13, 79, 84, 29, 78, 20,
+            ],
+            [
+                218, 39, 78, 136, 248, 192, 129, 102, 200, 72, 136, 171, 138, 40, 235, 195, 68,
+                129, 204, 69, 195, 196, 27, 40, 65, 192, 12, 67, 194, 77, 126, 23,
+            ],
+            [
+                48, 143, 179, 124, 196, 221, 169, 85, 188, 35, 14, 222, 41, 35, 32, 222, 206, 102,
+                167, 214, 129, 87, 12, 36, 35, 6, 70, 20, 34, 62, 194, 52,
+            ],
+            [
+                228, 45, 237, 146, 246, 213, 152, 79, 74, 151, 150, 74, 238, 246, 209, 94, 249,
+                124, 119, 15, 61, 94, 139, 111, 34, 127, 163, 208, 120, 151, 134, 28,
+            ],
+            [
+                206, 51, 200, 19, 246, 113, 109, 127, 135, 145, 41, 66, 91, 15, 112, 208, 29, 67,
+                252, 159, 95, 240, 238, 240, 202, 109, 33, 170, 48, 125, 228, 26,
+            ],
+            [
+                173, 61, 240, 125, 234, 192, 25, 202, 209, 59, 249, 63, 176, 254, 161, 149, 185,
+                31, 85, 191, 252, 185, 26, 46, 40, 64, 222, 54, 74, 243, 208, 44,
+            ],
+        ],
+        [
+            [
+                70, 55, 74, 43, 162, 198, 254, 186, 70, 0, 20, 66, 230, 180, 150, 127, 42, 241,
+                174, 134, 49, 31, 14, 61, 147, 247, 37, 105, 217, 210, 99, 47,
+            ],
+            [
+                105, 69, 32, 74, 209, 201, 54, 14, 248, 247, 59, 237, 144, 234, 223, 50, 103, 252,
+                133, 64, 252, 45, 10, 213, 88, 148, 190, 129, 244, 144, 161, 0,
+            ],
+            [
+                127, 209, 198, 236, 222, 249, 12, 227, 37, 34, 227, 49, 169, 228, 154, 128, 151,
+                94, 117, 76, 2, 145, 222, 52, 96, 137, 138, 120, 56, 33, 144, 18,
+            ],
+            [
+                155, 227, 224, 169, 63, 237, 174, 152, 203, 223, 172, 214, 78, 116, 157, 34, 245,
+                33, 57, 159, 240, 17, 160, 56, 229, 240, 199, 38, 29, 79, 166, 56,
+            ],
+            [
+                238, 206, 112, 199, 117, 24, 45, 154, 156, 69, 112, 102, 172, 67, 190, 19, 45, 233,
+                144, 140, 243, 226, 90, 122, 65, 201, 246, 43, 166, 172, 171, 8,
+            ],
+            [
+                185, 26, 61, 118, 171, 150, 122, 191, 3, 87, 92, 153, 96, 172, 25, 68, 116, 110,
+                17, 118, 31, 114, 168, 193, 92, 162, 251, 162, 154, 251, 6, 38,
+            ],
+            [
+                175, 138, 54, 177, 124, 141, 27, 80, 118, 174, 232, 201, 139, 106, 242, 155, 10,
+                53, 67, 242, 138, 94, 150, 33, 215, 127, 233, 170, 122, 211, 97, 39,
+            ],
+            [
+                180, 150, 7, 30, 43, 255, 190, 151, 212, 89, 58, 65, 122, 179, 31, 160, 181, 30,
+                19, 65, 179, 210, 169, 167, 34, 67, 209, 139, 68, 49, 156, 49,
+            ],
+        ],
+        [
+            [
+                48, 151, 112, 177, 153, 158, 53, 100, 170, 135, 93, 147, 109, 148, 136, 70, 143,
+                32, 190, 159, 49, 46, 85, 144, 122, 216, 29, 25, 155, 42, 77, 19,
+            ],
+            [
+                35, 177, 100, 81, 159, 154, 13, 202, 94, 81, 25, 248, 48, 146, 233, 103, 103, 150,
+                69, 134, 170, 94, 211, 208, 241, 10, 78, 27, 57, 125, 21, 14,
+            ],
+            [
+                229, 37, 196, 245, 78, 213, 145, 131, 53, 43, 92, 199, 86, 2, 87, 181, 246, 234,
+                162, 117, 40, 153, 79, 134, 17, 139, 30, 213, 101, 221, 251, 29,
+            ],
+            [
+                92, 171, 224, 243, 89, 83, 38, 245, 16, 11, 139, 17, 16, 88, 15, 126, 157, 205,
+                179, 228, 235, 20, 150, 223, 176, 53, 11, 98, 61, 126, 195, 56,
+            ],
+            [
+                251, 71, 42, 196, 166, 255, 81, 136, 29, 141, 155, 65, 242, 157, 15, 149, 69, 51,
+                224, 193, 64, 145, 13, 18, 243, 238, 243, 88, 199, 135, 176, 63,
+            ],
+            [
+                135, 64, 226, 169, 93, 195, 134, 215, 250, 186, 106, 240, 18, 123, 108, 32, 231,
+                197, 60, 54, 144, 255, 197, 166, 217, 3, 30, 93, 223, 153, 98, 33,
+            ],
+            [
+                153, 41, 206, 202, 181, 67, 88, 235, 45, 48, 119, 74, 141, 250, 53, 211, 248, 25,
+                110, 43, 198, 145, 194, 137, 70, 60, 165, 122, 251, 57, 203, 46,
+            ],
+            [
+                10, 88, 191, 179, 148, 229, 67, 139, 73, 73, 111, 123, 18, 90, 4, 204, 129, 219,
+                147, 134, 6, 255, 84, 197, 187, 201, 29, 130, 52, 238, 113, 21,
+            ],
+        ],
+        [
+            [
+                206, 241, 73, 246, 61, 174, 122, 12, 37, 133, 136, 202, 8, 69, 123, 19, 126, 92,
+                84, 121, 255, 193, 96, 18, 248, 74, 33, 14, 138, 35, 39, 56,
+            ],
+            [
+                106, 118, 6, 248, 154, 149, 236, 210, 61, 23, 36, 83, 206, 13, 160, 72, 95, 140,
+                24, 94, 80, 207, 180, 36, 247, 114, 15, 57, 170, 231, 222, 37,
+            ],
+            [
+                4, 78, 26, 147, 195, 219, 155, 164, 161, 223, 222, 18, 88, 229, 67, 147, 43, 158,
+                217, 83, 40, 31, 250, 169, 152, 108, 128, 51, 241, 209, 143, 35,
+            ],
+            [
+                54, 250, 159, 133, 226, 249, 49, 56, 129, 131, 33, 248, 104, 167, 209, 85, 29, 67,
+                102, 127, 89, 216, 93, 18, 86, 149, 89, 24, 210, 249, 128, 34,
+            ],
+            [
+                4, 178, 132, 225, 20, 153, 178, 179, 133, 107, 11, 149, 209, 201, 113, 140, 77, 59,
+                92, 228, 113, 131, 2, 20, 53, 53, 8, 253, 177, 64, 150, 48,
+            ],
+            [
+                89, 201, 110, 137, 223, 250, 165, 117, 78, 171, 158, 18, 31, 131, 152, 42, 8, 9,
+                179, 193, 252, 176, 225, 157, 161, 30, 25, 81, 46, 101, 237, 2,
+            ],
+            [
+                228, 229, 130, 243, 183, 154, 0, 34, 28, 10, 35, 87, 160, 203, 197, 160, 169, 192,
+                115, 160, 94, 122, 150, 187, 40, 170, 211, 22, 237, 82, 196, 48,
+            ],
+            [
+                164, 8, 161, 169, 31, 215, 251, 151, 44, 140, 18, 89, 195, 137, 70, 224, 137, 123,
+                108, 179, 64, 89, 13, 112, 93, 66, 66, 61, 80, 165, 253, 35,
+            ],
+        ],
+        [
+            [
+                249, 220, 126, 173, 23, 38, 222, 253, 52, 41, 211, 140, 3, 67, 144, 138, 141, 55,
+                125, 133, 141, 211, 34, 231, 146, 148, 184, 15, 136, 173, 5, 37,
+            ],
+            [
+                154, 4, 98, 147, 184, 196, 236, 82, 132, 155, 67, 164, 252, 177, 202, 75, 1, 182,
+                11, 12, 44, 3, 111, 62, 118, 76, 251, 160, 60, 104, 34, 38,
+            ],
+            [
+                17, 7, 122, 147, 178, 187, 240, 93, 183, 64, 172, 24, 20, 238, 252, 82, 216, 49,
+                146, 58, 25, 114, 229, 180, 186, 183, 88, 184, 186, 136, 76, 40,
+            ],
+            [
+                164, 147, 0, 221, 0, 176, 11, 80, 223, 31, 80, 18, 161, 173, 210, 74, 45, 152, 222,
+                215, 207, 63, 189, 249, 84, 19, 98, 164, 98, 193, 210, 9,
+            ],
+            [
+                124, 237, 127, 17, 85, 190, 14, 222, 41, 224, 111, 168, 111, 253, 94, 229, 91, 183,
+                200, 144, 40, 205, 233, 138, 67, 136, 117, 101, 119, 29, 21, 46,
+            ],
+            [
+                96, 72, 98, 236, 5, 186, 63, 214, 150, 142, 142, 52, 99, 163, 188, 13, 24, 69, 155,
+                63, 175, 177, 5, 239, 139, 102, 172, 176, 160, 163, 75, 56,
+            ],
+            [
+                142, 157, 80, 125, 121, 163, 91, 237, 62, 165, 95, 23, 27, 226, 96, 121, 5, 85, 83,
+                36, 233, 106, 30, 97, 116, 164, 252, 77, 126, 99, 204, 16,
+            ],
+            [
+                239, 227, 187, 100, 65, 148, 127, 249, 96, 161, 207, 243, 138, 26, 235, 224, 205,
+                189, 32, 37, 52, 139, 42, 77, 240, 232, 181, 170, 198, 241, 127, 49,
+            ],
+        ],
+        [
+            [
+                166, 180, 129, 155, 242, 189, 159, 196, 112, 130, 162, 160, 91, 93, 236, 19, 0,
+                234, 154, 242, 218, 44, 144, 232, 95, 224, 247, 67, 47, 193, 198, 21,
+            ],
+            [
+                23, 247, 128, 199, 183, 22, 35, 27, 172, 248, 158, 253, 207, 220, 55, 153, 89, 130,
+                155, 254, 212, 110, 222, 200, 177, 159, 54, 163, 88, 226, 189, 29,
+            ],
+            [
+                145, 63, 236, 56, 2, 250, 230, 210, 46, 182, 153, 29, 33, 233, 142, 35, 139, 18,
+                137, 91, 241, 21, 82, 26, 7, 188, 111, 131, 64, 83, 225, 56,
+            ],
+            [
+                58, 201, 104, 19, 79, 33, 13, 184, 58, 247, 167, 179, 189, 3, 242, 131, 12, 233,
+                20, 242, 159, 100, 146, 156, 143, 102, 183, 27, 185, 123, 233, 36,
+            ],
+            [
+                43, 149, 125, 112, 241, 6, 68, 84, 107, 198, 44, 202, 18, 223, 74, 30, 27, 228,
+                189, 189, 0, 142, 183, 95, 245, 114, 100, 195, 44, 205, 101, 25,
+            ],
+            [
+                21, 121, 248, 154, 99, 191, 75, 135, 155, 99, 122, 96, 51, 117, 134, 126, 164, 187,
+                226, 165, 44, 44, 61, 127, 129, 114, 89, 94, 77, 47, 255, 3,
+            ],
+            [
+                212, 45, 161, 220, 239, 38, 22, 10, 22, 41, 56, 227, 62, 6, 72, 4, 52, 138, 227,
+                218, 174, 119, 131, 130, 114, 141, 115, 102, 242, 196, 160, 25,
+            ],
+            [
+                222, 222, 247, 35, 182, 81, 235, 199, 220, 234, 61, 156, 214, 186, 66, 167, 207,
+                131, 248, 95, 42, 87, 237, 253, 63, 201, 46, 48, 27, 208, 73, 47,
+            ],
+        ],
+        [
+            [
+                229, 246, 68, 183, 34, 23, 115, 34, 82, 148, 252, 167, 214, 215, 187, 140, 97, 116,
+                24, 21, 169, 208, 54, 35, 90, 145, 175, 40, 204, 106, 42, 27,
+            ],
+            [
+                126, 95, 184, 109, 208, 53, 191, 201, 57, 81, 48, 7, 59, 230, 27, 240, 169, 132,
+                83, 87, 235, 229, 142, 31, 12, 61, 0, 173, 22, 42, 55, 19,
+            ],
+            [
+                99, 80, 251, 185, 148, 146, 119, 23, 85, 157, 234, 213, 52, 55, 118, 58, 206, 63,
+                11, 99, 222, 187, 25, 169, 20, 118, 157, 34, 76, 9, 79, 14,
+            ],
+            [
+                20, 96, 161, 168, 221, 6, 190, 51, 254, 49, 243, 62, 81, 94, 136, 35, 2, 113, 241,
+                168, 217, 239, 145, 15, 132, 45, 182, 236, 240, 227, 9, 52,
+            ],
+            [
+                87, 12, 232, 185, 75, 86, 26, 53, 190, 196, 97, 72, 185, 97, 31, 43, 224, 176, 176,
+                147, 214, 182, 206, 204, 51, 151, 53, 38, 251, 78, 76, 48,
+            ],
+            [
+                204, 193, 185, 229, 158, 223, 58, 198, 167, 50, 196, 139, 133, 222, 193, 88, 237,
+                56, 154, 57, 181, 140, 117, 211, 230, 53, 212, 97, 118, 241, 66, 35,
+            ],
+            [
+                87, 88, 122, 191, 31, 18, 134, 166, 188, 80, 243, 123, 55, 47, 250, 76, 72, 159,
+                201, 153, 116, 176, 32, 219, 212, 16, 176, 237, 221, 143, 53, 41,
+            ],
+            [
+                146, 42, 163, 76, 10, 95, 140, 177, 88, 136, 110, 15, 226, 221, 67, 36, 186, 180,
+                8, 188, 54, 121, 44, 120, 94, 163, 76, 177, 148, 196, 51, 55,
+            ],
+        ],
+        [
+            [
+                199, 251, 175, 153, 14, 202, 48, 47, 17, 72, 39, 69, 196, 190, 80, 210, 159, 29,
+                106, 222, 108, 1, 199, 18, 48, 217, 87, 43, 7, 159, 178, 47,
+            ],
+            [
+                202, 86, 202, 33, 7, 143, 179, 173, 158, 151, 67, 248, 114, 143, 225, 48, 254, 159,
+                96, 43, 184, 7, 161, 95, 221, 94, 255, 247, 115, 39, 16, 22,
+            ],
+            [
+                236, 154, 204, 33, 68, 62, 50, 57, 65, 189, 176, 194, 24, 38, 111, 97, 59, 14, 165,
+                110, 18, 249, 52, 177, 160, 58, 150, 152, 89, 127, 122, 23,
+            ],
+            [
+                115, 87, 74, 130, 105, 237, 205, 126, 158, 130, 60, 241, 103, 4, 242, 10, 213, 232,
+                128, 202, 242, 70, 25, 70, 189, 67, 156, 38, 223, 156, 115, 63,
+            ],
+            [
+                81, 4, 85, 224, 85, 16, 138, 153, 162, 201, 118, 233, 150, 216, 132, 235, 115, 12,
+                184, 113, 169, 75, 105, 114, 228, 91, 234, 59, 199, 236, 32, 43,
+            ],
+            [
+                37, 64, 163, 89, 160, 227, 250, 203, 120, 230, 182, 163, 108, 201, 139, 255, 223,
+                151, 154, 85, 213, 252, 45, 196, 106, 184, 217, 56, 177, 208, 65, 38,
+            ],
+            [
+                61, 164, 199, 3, 25, 217, 78, 111, 12, 156, 165, 122, 105, 95, 19, 105, 94, 39,
+                239, 59, 232, 56, 94, 248, 200, 51, 70, 248, 9, 4, 48, 34,
+            ],
+            [
+                220, 115, 124, 221, 47, 5, 31, 160, 65, 139, 70, 107, 180, 63, 95, 250, 93, 114,
+                225, 214, 227, 175, 230, 197, 24, 167, 33, 130, 224, 49, 166, 17,
+            ],
+        ],
+        [
+            [
+                217, 96, 56, 186, 153, 59, 53, 193, 254, 241, 116, 164, 181, 159, 54, 3, 103, 104,
+                242, 34, 80, 170, 227, 63, 41, 140, 92, 219, 82, 54, 129, 40,
+            ],
+            [
+                84, 14, 138, 97, 187, 191, 186, 84, 117, 28, 155, 91, 208, 143, 132, 165, 100, 67,
+                143, 94, 206, 44, 122, 255, 178, 98, 214, 2, 59, 205, 230, 29,
+            ],
+            [
+                46, 85, 99, 65, 183, 95, 131, 99, 43, 71, 104, 63, 9, 151, 37, 148, 74, 93, 234,
+                37, 172, 170, 249, 189, 223, 21, 83, 214, 227, 225, 27, 23,
+            ],
+            [
+                174, 119, 118, 186, 185, 193, 10, 72, 167, 75, 219, 106, 239, 20, 145, 25, 158,
+                117, 22, 81, 104, 213, 49, 0, 115, 245, 227, 120, 13, 14, 61, 57,
+            ],
+            [
+                48, 64, 254, 35, 92, 93, 12, 106, 200, 40, 37, 222, 84, 88, 69, 122, 41, 128, 134,
+                150, 100, 236, 98, 159, 115, 194, 188, 61, 127, 34, 186, 19,
+            ],
+            [
+                251, 106, 146, 238, 67, 137, 9, 98, 190, 249, 67, 194, 111, 89, 233, 96, 103, 176,
+                216, 175, 26, 32, 215, 243, 86, 235, 7, 244, 53, 103, 3, 37,
+            ],
+            [
+                143, 213, 87, 223, 101, 101, 7, 189, 73, 211, 182, 187, 194, 1, 51, 37, 44, 163,
+                127, 229, 128, 34, 90, 223, 37, 12, 66, 247, 209, 147, 208, 21,
+            ],
+            [
+                44, 96, 185, 151, 40, 58, 196, 41, 55, 113, 96, 49, 130, 75, 186, 210, 160, 116,
+                75, 174, 147, 175, 211, 60, 146, 111, 178, 169, 124, 154, 243, 60,
+            ],
+        ],
+        [
+            [
+                45, 118, 139, 171, 30, 230, 124, 172, 58, 107, 32, 213, 91, 153, 244, 87, 245, 218,
+                179, 206, 95, 21, 201, 80, 175, 93, 203, 79, 14, 210, 184, 5,
+            ],
+            [
+                110, 124, 114, 243, 245, 244, 102, 195, 124, 202, 124, 36, 50, 57, 43, 46, 94, 109,
+                115, 169, 88, 147, 28, 171, 103, 34, 170, 93, 28, 231, 25, 11,
+            ],
+            [
+                93, 154, 182, 131, 211, 10, 43, 174, 114, 201, 72, 100, 231, 2, 222, 198, 131, 166,
+                97, 105, 226, 37, 19, 30, 164, 143, 106, 242, 250, 201, 62, 23,
+            ],
+            [
+                87, 80, 251, 96, 157, 14, 76, 227, 39, 186, 23, 135, 190, 166, 80, 166, 8, 85, 48,
+                1, 170, 67, 23, 250, 33, 232, 42, 104, 40, 45, 133, 0,
+            ],
+            [
+                200, 249, 86, 19, 154, 235, 130, 151, 43, 136, 84, 157, 69, 65, 14, 182, 29, 117,
+                126, 20, 19, 161, 241, 152, 32, 29, 213, 53, 105, 183, 56, 56,
+            ],
+            [
+                37, 156, 113, 189, 234, 143, 20, 49, 18, 73, 46, 106, 209, 21, 79, 97, 145, 27,
+                192, 89, 65, 209, 46, 42, 60, 218, 238, 173, 209, 216, 9, 0,
+            ],
+            [
+                156, 27, 229, 246, 141, 15, 87, 190, 120, 25, 15, 34, 130, 100, 96, 86, 73, 46, 50,
+                34, 8, 196, 126, 19, 98, 47, 177, 250, 187, 38, 141, 58,
+            ],
+            [
+                224, 47, 190, 108, 226, 124, 82, 13, 253, 172, 90, 129, 217, 46, 241, 94, 10, 35,
+                83, 63, 5, 97, 126, 193, 142, 128, 132, 99, 249, 42, 14, 59,
+            ],
+        ],
+        [
+            [
+                162, 151, 175, 251, 142, 2, 169, 77, 175, 37, 196, 135, 24, 137, 201, 246, 230, 82,
+                101, 73, 204, 35, 76, 170, 85, 129, 8, 130, 188, 209, 244, 54,
+            ],
+            [
+                154, 49, 33, 214, 113, 135, 68, 96, 7, 199, 181, 45, 153, 136, 244, 138, 238, 59,
+                83, 232, 63, 224, 159, 195, 28, 75, 146, 194, 95, 151, 242, 42,
+            ],
+            [
+                62, 10, 149, 161, 69, 156, 100, 192, 234, 108, 71, 76, 227, 226, 182, 25, 154, 31,
+                93, 49, 47, 141, 59, 72, 14, 140, 120, 52, 40, 213, 202, 42,
+            ],
+            [
+                204, 193, 77, 30, 152, 170, 40, 5, 86, 185, 49, 129, 103, 26, 136, 169, 254, 227,
+                198, 178, 73, 91, 160, 176, 4, 127, 66, 138, 86, 17, 198, 27,
+            ],
+            [
+                244, 137, 112, 214, 149, 35, 87, 255, 210, 152, 154, 63, 6, 40, 224, 141, 221, 8,
+                167, 72, 199, 46, 154, 28, 132, 84, 21, 24, 255, 238, 19, 47,
+            ],
+            [
+                248, 235, 214, 64, 210, 25, 7, 209, 29, 108, 75, 108, 75, 118, 138, 1, 205, 209,
+                250, 120, 213, 102, 199, 196, 211, 175, 38, 219, 90, 231, 239, 45,
+            ],
+            [
+                196, 65, 130, 195, 226, 207, 251, 194, 177, 1, 246, 166, 185, 255, 137, 172, 173,
+                56, 140, 160, 51, 44, 76, 35, 106, 209, 246, 28, 156, 66, 42, 15,
+            ],
+            [
+                226, 33, 185, 192, 52, 171, 221, 150, 253, 210, 9, 220, 176, 120, 154, 53, 255,
+                178, 63, 151, 0, 99, 91, 108, 247, 135, 177, 114, 44, 213, 60, 7,
+            ],
+        ],
+        [
+            [
+                119, 119, 64, 182, 159, 14, 44, 196, 212, 62, 145, 76, 132, 40, 167, 6, 212, 101,
+                216, 188, 138, 236, 183, 48, 5, 145, 111, 2, 116, 158, 149, 11,
+            ],
+            [
+                236, 224, 133, 103, 29, 136, 4, 147, 70, 50, 163, 121, 165, 164, 251, 6, 99, 126,
+                29, 13, 122, 200, 174, 253, 120, 56, 184, 108, 171, 0, 185, 7,
+            ],
+            [
+                209, 109, 216, 153, 160, 99, 178, 79, 102, 170, 221, 40, 135, 223, 7, 242, 69, 150,
+                71, 114, 75, 227, 38, 160, 91, 248, 195, 13, 147, 239, 56, 30,
+            ],
+            [
+                119, 177, 218, 16, 11, 212, 188, 175, 237, 62, 146, 40, 215, 143, 104, 29, 187,
+                181, 213, 212, 171, 166, 35, 11, 198, 34, 236, 224, 141, 244, 77, 57,
+            ],
+            [
+                35, 129, 240, 121, 81, 84, 6, 248, 238, 24, 80, 50, 67, 106, 145, 83, 190, 143, 67,
+                121, 102, 126, 252, 231, 156, 178, 86, 17, 214, 32, 102, 43,
+            ],
+            [
+                197, 197, 9, 85, 74, 175, 181, 228, 62, 132, 148, 22, 173, 188, 69, 63, 168, 145,
+                152, 50, 32, 114, 132, 85, 120, 147, 74, 46, 223, 71, 77, 23,
+            ],
+            [
+                171, 219, 247, 88, 224, 135, 243, 158, 146, 210, 31, 124, 168, 88, 104, 175, 91,
+                188, 73, 46, 215, 67, 105, 244, 24, 104, 178, 71, 151, 234, 227, 60,
+            ],
+            [
+                112, 250, 123, 120, 145, 16, 101, 187, 16, 196, 55, 120, 12, 224, 234, 22, 46, 238,
+                214, 148, 114, 38, 239, 32, 110, 40, 66, 212, 144, 231, 22, 22,
+            ],
+        ],
+        [
+            [
+                208, 69, 22, 184, 244, 196, 38, 160, 231, 74, 187, 116, 51, 244, 24, 225, 147, 157,
+                118, 220, 181, 115, 124, 57, 213, 163, 104, 48, 225, 100, 139, 42,
+            ],
+            [
+                158, 199, 200, 144, 5, 35, 157, 184, 149, 233, 135, 164, 229, 143, 154, 199, 106,
+                59, 210, 92, 60, 210, 106, 81, 152, 211, 188, 50, 216, 210, 124, 9,
+            ],
+            [
+                169, 188, 21, 60, 13, 42, 25, 214, 52, 87, 15, 0, 25, 120, 169, 155, 161, 146, 210,
+                2, 121, 228, 233, 197, 214, 111, 53, 184, 95, 199, 170, 35,
+            ],
+            [
+                225, 74, 192, 232, 94, 136, 42, 85, 42, 232, 233, 236, 182, 167, 18, 155, 106, 155,
+                153, 132, 184, 83, 14, 0, 22, 27, 2, 168, 53, 16, 91, 14,
+            ],
+            [
+                175, 249, 82, 212, 127, 94, 32, 55, 80, 169, 42, 32, 214, 252, 118, 141, 254, 159,
+                73, 129, 72, 130, 94, 238, 139, 222, 67, 63, 33, 42, 175, 7,
+            ],
+            [
+                85, 156, 194, 122, 187, 189, 179, 121, 215, 207, 88, 245, 202, 136, 203, 126, 9,
+                83, 227, 145, 50, 14, 77, 246, 167, 241, 143, 42, 147, 254, 91, 2,
+            ],
+            [
+                167, 26, 202, 55, 78, 84, 232, 242, 39, 22, 17, 233, 202, 33, 134, 242, 211, 117,
+                16, 68, 105, 197, 33, 98, 205, 133, 54, 57, 243, 17, 5, 32,
+            ],
+            [
+                4, 210, 47, 231, 81, 64, 195, 178, 43, 64, 120, 212, 0, 227, 58, 163, 252, 234, 92,
+                192, 2, 185, 167, 88, 41, 153, 27, 229, 203, 26, 74, 53,
+            ],
+        ],
+        [
+            [
+                137, 88, 58, 144, 84, 183, 97, 51, 211, 212, 42, 204, 22, 139, 228, 5, 241, 10,
+                175, 164, 212, 77, 183, 251, 55, 155, 121, 35, 254, 84, 28, 53,
+            ],
+            [
+                57, 108, 87, 131, 54, 31, 239, 7, 184, 24, 12, 199, 107, 56, 177, 250, 162, 171,
+                241, 223, 115, 19, 233, 105, 237, 78, 9, 212, 111, 249, 249, 14,
+            ],
+            [
+                237, 43, 218, 55, 62, 242, 212, 47, 90, 4, 231, 161, 34, 72, 34, 234, 163, 254, 57,
+                18, 152, 209, 106, 86, 124, 188, 185, 112, 205, 187, 153, 36,
+            ],
+            [
+                236, 128, 104, 26, 81, 0, 109, 227, 111, 204, 216, 157, 57, 67, 158, 99, 143, 203,
+                222, 187, 132, 229, 105, 70, 117, 83, 180, 249, 60, 125, 62, 13,
+            ],
+            [
+                125, 146, 1, 153, 125, 169, 203, 121, 199, 17, 50, 101, 187, 40, 20, 89, 197, 35,
+                62, 21, 242, 108, 209, 179, 115, 243, 234, 94, 4, 67, 107, 6,
+            ],
+            [
+                237, 27, 226, 253, 13, 149, 192, 123, 104, 139, 108, 130, 230, 234, 88, 77, 228,
+                220, 177, 103, 248, 250, 248, 118, 25, 95, 84, 220, 33, 178, 143, 54,
+            ],
+            [
+                241, 213, 255, 193, 58, 220, 166, 190, 237, 151, 95, 39, 42, 29, 198, 150, 41, 2,
+                18, 239, 192, 254, 8, 109, 135, 2, 218, 34, 79, 226, 26, 60,
+            ],
+            [
+                162, 43, 169, 249, 186, 33, 141, 177, 37, 200, 172, 200, 69, 214, 102, 132, 58,
+                136, 27, 210, 137, 183, 41, 97, 125, 122, 63, 135, 163, 114, 66, 54,
+            ],
+        ],
+        [
+            [
+                207, 112, 248, 230, 76, 153, 50, 187, 204, 188, 14, 238, 30, 75, 8, 172, 6, 67,
+                243, 110, 40, 128, 220, 84, 28, 197, 26, 9, 67, 167, 70, 60,
+            ],
+            [
+                255, 85, 159, 103, 127, 136, 111, 163, 72, 30, 180, 90, 147, 249, 72, 53, 60, 44,
+                229, 3, 211, 134, 136, 49, 48, 200, 3, 251, 38, 166, 126, 25,
+            ],
+            [
+                140, 7, 60, 134, 128, 156, 185, 157, 221, 129, 149, 14, 252, 123, 70, 124, 158,
+                160, 179, 122, 102, 94, 231, 189, 45, 194, 1, 48, 167, 164, 224, 54,
+            ],
+            [
+                10, 153, 63, 21, 23, 155, 131, 239, 101, 70, 148, 185, 81, 145, 18, 5, 176, 157,
+                177, 166, 139, 19, 202, 106, 146, 18, 176, 102, 147, 230, 154, 63,
+            ],
+            [
+                79, 149, 30, 49, 7, 138, 208, 195, 137, 73, 21, 93, 215, 240, 243, 2, 88, 128, 74,
+                166, 27, 214, 106, 55, 51, 153, 25, 127, 119, 75, 61, 44,
+            ],
+            [
+                214, 182, 111, 68, 148, 22, 66, 89, 146, 174, 11, 213, 238, 142, 58, 61, 91, 54,
+                131, 131, 232, 214, 224, 165, 151, 245, 171, 162, 137, 98, 217, 7,
+            ],
+            [
+                192, 220, 162, 110, 60, 66, 20, 74, 31, 161, 95, 81, 120, 181, 99, 6, 219, 167, 54,
+                166, 172, 195, 58, 35, 252, 72, 36, 4, 3, 216, 1, 19,
+            ],
+            [
+                18, 6, 188, 167, 46, 250, 69, 237, 166, 35, 145, 199, 188, 157, 10, 84, 69, 250,
+                231, 198, 143, 166, 212, 25, 148, 183, 220, 152, 240, 96, 236, 51,
+            ],
+        ],
+        [
+            [
+                86, 67, 254, 215, 186, 20, 229, 56, 179, 205, 207, 113, 41, 208, 253, 88, 199, 225,
+                187, 238, 90, 20, 213, 56, 159, 28, 97, 132, 107, 63, 0, 14,
+            ],
+            [
+                143, 138, 245, 234, 177, 154, 123, 5, 173, 174, 204, 220, 90, 108, 129, 154, 120,
+                209, 154, 187, 174, 197, 31, 143, 230, 147, 42, 55, 31, 80, 121, 39,
+            ],
+            [
+                179, 125, 188, 248, 82, 119, 28, 107, 45, 37, 16, 7, 23, 50, 120, 227, 46, 170,
+                212, 24, 23, 100, 156, 207, 12, 36, 169, 163, 252, 229, 97, 36,
+            ],
+            [
+                2, 169, 162, 3, 12, 198, 15, 217, 159, 184, 205, 152, 51, 174, 231, 9, 0, 130, 182,
+                217, 173, 249, 187, 50, 81, 230, 18, 217, 147, 189, 192, 63,
+            ],
+            [
+                65, 131, 32, 122, 18, 136, 42, 44, 191, 134, 232, 157, 81, 76, 185, 87, 126, 162,
+                32, 60, 148, 78, 109, 61, 143, 67, 58, 140, 134, 83, 216, 20,
+            ],
+            [
+                66, 155, 69, 178, 254, 164, 59, 90, 9, 40, 72, 77, 252, 215, 168, 95, 58, 88, 71,
+                84, 213, 234, 107, 218, 233, 138, 209, 229, 231, 59, 60, 46,
+            ],
+            [
+                134, 251, 157, 90, 42, 99, 94, 121, 123, 99, 34, 228, 95, 226, 21, 51, 214, 24, 34,
+                143, 116, 176, 249, 72, 66, 215, 152, 239, 221, 170, 101, 21,
+            ],
+            [
+                99, 3, 47, 55, 248, 69, 15, 114, 248, 130, 249, 26, 185, 48, 21, 14, 30, 246, 119,
+                113, 135, 168, 124, 33, 39, 26, 140, 34, 5, 6, 23, 21,
+            ],
+        ],
+        [
+            [
+                176, 254, 230, 5, 157, 107, 57, 19, 148, 138, 173, 234, 179, 63, 38, 223, 236, 176,
+                30, 88, 45, 93, 252, 217, 67, 149, 61, 93, 171, 98, 254, 6,
+            ],
+            [
+                68, 193, 61, 22, 40, 89, 49, 242, 147, 94, 248, 189, 131, 174, 60, 62, 171, 244,
+                23, 82, 223, 162, 84, 63, 253, 195, 137, 236, 182, 209, 163, 19,
+            ],
+            [
+                190, 91, 134, 192, 147, 253, 93, 125, 133, 117, 201, 196, 30, 35, 244, 152, 1, 18,
+                232, 195, 57, 178, 53, 95, 242, 202, 54, 21, 87, 111, 87, 39,
+            ],
+            [
+                160, 228, 153, 59, 208, 250, 173, 116, 46, 250, 139, 92, 37, 192, 161, 244, 241,
+                215, 152, 116, 128, 48, 60, 99, 32, 34, 11, 100, 189, 58, 111, 23,
+            ],
+            [
+                159, 112, 188, 6, 152, 164, 23, 67, 115, 76, 168, 78, 132, 139, 144, 167, 96, 144,
+                32, 211, 222, 224, 54, 71, 156, 243, 7, 187, 210, 190, 38, 13,
+            ],
+            [
+                222, 51, 179, 243, 110, 65, 221, 40, 197, 144, 42, 26, 197, 229, 40, 141, 216, 69,
+                208, 12, 228, 34, 234, 199, 146, 130, 152, 15, 136, 43, 144, 47,
+            ],
+            [
+                55, 123, 101, 163, 189, 58, 50, 75, 0, 22, 225, 99, 255, 168, 244, 15, 49, 130, 2,
+                86, 30, 126, 6, 146, 163, 49, 67, 219, 25, 110, 202, 5,
+            ],
+            [
+                128, 63, 116, 226, 4, 41, 74, 68, 199, 134, 93, 91, 102, 185, 64, 122, 206, 23, 41,
+                119, 192, 209, 130, 152, 116, 229, 57, 205, 251, 233, 217, 8,
+            ],
+        ],
+        [
+            [
+                85, 60, 110, 232, 11, 224, 183, 127, 63, 163, 245, 239, 228, 215, 160, 12, 21, 18,
+                30, 143, 134, 9, 245, 45, 156, 170, 55, 198, 53, 207, 29, 13,
+            ],
+            [
+                54, 212, 201, 241, 135, 78, 140, 64, 40, 131, 191, 250, 246, 76, 145, 222, 133,
+                165, 94, 126, 119, 53, 211, 114, 252, 30, 123, 167, 202, 117, 197, 35,
+            ],
+            [
+                20, 131, 110, 71, 243, 71, 164, 169, 121, 232, 204, 213, 204, 148, 57, 77, 95, 178,
+                117, 33, 244, 148, 227, 152, 67, 194, 248, 215, 144, 116, 248, 20,
+            ],
+            [
+                155, 136, 76, 251, 175, 122, 66, 56, 104, 82, 240, 177, 20, 25, 145, 113, 104, 192,
+                32, 18, 112, 127, 195, 90, 146, 26, 77, 59, 122, 70, 60, 16,
+            ],
+            [
+                84, 48, 138, 41, 141, 129, 74, 169, 190, 165, 111, 253, 161, 103, 205, 112, 74, 36,
+                85, 200, 133, 247, 120, 78, 83, 182, 254, 151, 234, 229, 70, 2,
+            ],
+            [
+                227, 252, 222, 172, 35, 58, 58, 37, 236, 110, 190, 50, 127, 152, 23, 137, 64, 164,
+                83, 1, 215, 95, 90, 185, 65, 1, 241, 142, 26, 54, 30, 1,
+            ],
+            [
+                149, 76, 245, 134, 151, 13, 75, 61, 197, 73, 32, 192, 15, 58, 176, 169, 43, 27, 2,
+                5, 180, 205, 107, 225, 194, 125, 126, 98, 37, 246, 201, 32,
+            ],
+            [
+                169, 41, 160, 22, 234, 119, 42, 197, 219, 225, 202, 233, 205, 158, 20, 32, 197,
+                145, 100, 125, 109, 212, 82, 157, 37, 41, 105, 116, 94, 54, 149, 38,
+            ],
+        ],
+        [
+            [
+                254, 221, 153, 60, 132, 179, 25, 38, 8, 159, 28, 114, 226, 194, 34, 21, 183, 3,
+                228, 142, 244, 64, 209, 72, 78, 19, 209, 108, 30, 4, 215, 24,
+            ],
+            [
+                36, 233, 96, 194, 132, 125, 199, 19, 117, 25, 176, 72, 19, 45, 226, 38, 36, 254,
+                128, 248, 119, 222, 64, 14, 234, 202, 167, 98, 207, 80, 95, 23,
+            ],
+            [
+                214, 40, 253, 159, 107, 192, 1, 124, 239, 157, 2, 172, 192, 174, 80, 186, 135, 213,
+                0, 236, 212, 151, 121, 229, 23, 240, 251, 95, 55, 49, 20, 40,
+            ],
+            [
+                75, 73, 29, 255, 125, 250, 106, 24, 199, 104, 162, 216, 79, 10, 1, 10, 224, 43, 59,
+                41, 196, 184, 195, 181, 117, 106, 214, 188, 136, 74, 171, 60,
+            ],
+            [
+                190, 74, 82, 226, 203, 200, 113, 20, 168, 198, 190, 250, 180, 251, 210, 16, 217,
+                71, 201, 36, 57, 83, 69, 132, 81, 69, 159, 135, 217, 91, 230, 48,
+            ],
+            [
+                224, 62, 174, 106, 83, 142, 36, 70, 86, 225, 190, 134, 170, 142, 44, 58, 70, 133,
+                119, 70, 251, 238, 168, 113, 209, 221, 243, 1, 195, 145, 116, 22,
+            ],
+            [
+                44, 37, 91, 197, 254, 38, 251, 71, 62, 191, 2, 41, 123, 42, 156, 129, 223, 59, 9,
+                217, 67, 213, 4, 112, 199, 249, 110, 198, 203, 232, 42, 27,
+            ],
+            [
+                217, 201, 116, 62, 72, 25, 65, 43, 198, 23, 87, 56, 203, 25, 106, 144, 201, 88,
+                145, 42, 132, 33, 96, 37, 246, 6, 213, 55, 64, 158, 252, 37,
+            ],
+        ],
+        [
+            [
+                91, 241, 254, 123, 84, 172, 178, 140, 163, 164, 134, 220, 11, 164, 155, 229, 212,
+                61, 140, 125, 12, 199, 218, 143, 104, 6, 234, 48, 61, 222, 115, 57,
+            ],
+            [
+                228, 169, 108, 204, 66, 173, 177, 40, 6, 157, 53, 97, 93, 33, 182, 184, 245, 247,
+                212, 41, 68, 129, 116, 95, 107, 28, 89, 147, 104, 80, 212, 56,
+            ],
+            [
+                66, 245, 109, 137, 206, 199, 136, 63, 206, 62, 202, 205, 38, 201, 47, 209, 151,
+                197, 81, 176, 172, 60, 88, 117, 243, 107, 72, 215, 202, 151, 121, 58,
+            ],
+            [
+                175, 83, 41, 245, 222, 248, 220, 176, 151, 65, 70, 57, 15, 90, 254, 156, 30, 196,
+                84, 33, 23, 219, 176, 192, 207, 168, 254, 58, 46, 233, 187, 60,
+            ],
+            [
+                237, 132, 110, 108, 37, 34, 132, 103, 19, 86, 98, 37, 60, 138, 103, 10, 134, 65,
+                162, 63, 160, 99, 177, 83, 238, 217, 126, 221, 37, 19, 236, 10,
+            ],
+            [
+                251, 193, 53, 0, 246, 250, 100, 4, 67, 197, 85, 172, 248, 173, 28, 110, 168, 228,
+                84, 33, 171, 143, 244, 97, 88, 233, 29, 103, 240, 109, 96, 26,
+            ],
+            [
+                204, 234, 5, 148, 230, 212, 143, 192, 192, 88, 149, 193, 105, 177, 210, 168, 193,
+                215, 54, 130, 148, 19, 178, 43, 140, 71, 137, 1, 132, 137, 166, 41,
+            ],
+            [
+                120, 81, 141, 127, 53, 198, 122, 183, 86, 163, 224, 80, 77, 93, 90, 7, 57, 82, 218,
+                156, 27, 54, 183, 127, 18, 96, 17, 246, 178, 204, 149, 15,
+            ],
+        ],
+        [
+            [
+                31, 65, 68, 236, 193, 96, 201, 19, 35, 6, 81, 155, 54, 56, 52, 243, 47, 123, 109,
+                106, 113, 167, 141, 126, 208, 61, 77, 3, 62, 34, 140, 54,
+            ],
+            [
+                249, 147, 117, 22, 98, 37, 105, 19, 84, 246, 105, 124, 19, 121, 68, 221, 125, 85,
+                246, 232, 69, 149, 16, 149, 193, 121, 247, 2, 126, 111, 145, 2,
+            ],
+            [
+                132, 42, 218, 16, 38, 35, 169, 255, 50, 159, 144, 151, 64, 254, 4, 212, 28, 68,
+                115, 167, 120, 7, 15, 153, 83, 17, 210, 240, 234, 100, 255, 20,
+            ],
+            [
+                30, 207, 185, 105, 141, 30, 61, 217, 247, 244, 201, 71, 192, 227, 218, 17, 75, 63,
+                56, 234, 189, 215, 46, 34, 161, 136, 201, 135, 184, 133, 81, 58,
+            ],
+            [
+                46, 136, 105, 218, 248, 33, 194, 233, 192, 72, 223, 155, 4, 174, 15, 203, 159, 61,
+                20, 71, 92, 244, 24, 48, 73, 228, 35, 211, 181, 245, 237, 8,
+            ],
+            [
+                64, 87, 148, 149, 27, 207, 243, 22, 140, 244, 46, 97, 182, 97, 221, 15, 17, 244,
+                223, 180, 143, 208, 82, 148, 235, 56, 176, 179, 7, 235, 51, 35,
+            ],
+            [
+                193, 186, 137, 13, 241, 156, 105, 119, 107, 171, 147, 72, 111, 102, 184, 64, 206,
+                240, 113, 102, 166, 111, 39, 92, 11, 13, 211, 90, 48, 254, 193, 62,
+            ],
+            [
+                218, 171, 95, 156, 97, 83, 56, 113, 103, 203, 189, 52, 203, 160, 204, 199, 186,
+                212, 209, 105, 204, 201, 250, 243, 69, 180, 140, 180, 46, 175, 121, 6,
+            ],
+        ],
+        [
+            [
+                78, 42, 164, 112, 186, 236, 187, 74, 23, 102, 132, 130, 98, 223, 241, 113, 52, 251,
+                179, 200, 122, 194, 250, 97, 122, 129, 39, 224, 119, 242, 116, 54,
+            ],
+            [
+                195, 94, 135, 110, 211, 228, 7, 134, 32, 64, 14, 66, 147, 67, 48, 58, 229, 198,
+                211, 181, 127, 17, 126, 0, 13, 14, 40, 227, 218, 151, 83, 3,
+            ],
+            [
+                114, 119, 59, 114, 155, 224, 202, 167, 7, 183, 151, 171, 104, 252, 182, 50, 94,
+                211, 120, 35, 25, 220, 32, 104, 100, 135, 136, 71, 128, 110, 10, 28,
+            ],
+            [
+                210, 95, 0, 15, 61, 96, 143, 180, 217, 9, 157, 191, 20, 223, 38, 50, 66, 167, 91,
+                254, 8, 95, 83, 121, 199, 146, 237, 137, 112, 72, 247, 38,
+            ],
+            [
+                56, 226, 161, 247, 114, 80, 150, 11, 75, 66, 49, 56, 37, 227, 145, 94, 86, 94, 149,
+                187, 149, 82, 191, 247, 136, 13, 186, 77, 207, 159, 54, 19,
+            ],
+            [
+                186, 34, 100, 250, 224, 36, 221, 77, 65, 46, 155, 218, 208, 36, 223, 153, 184, 243,
+                239, 186, 135, 215, 90, 70, 105, 94, 161, 102, 131, 199, 222, 54,
+            ],
+            [
+                54, 186, 81, 210, 194, 191, 152, 55, 139, 111, 251, 203, 2, 212, 179, 119, 93, 20,
+                153, 112, 22, 84, 243, 104, 231, 32, 61, 192, 220, 237, 50, 50,
+            ],
+            [
+                60, 10, 25, 52, 189, 89, 119, 224, 228, 34, 198, 177, 29, 70, 12, 219, 133, 111,
+                185, 5, 52, 247, 139, 102, 46, 112, 38, 102, 128, 29, 80, 37,
+            ],
+        ],
+        [
+            [
+                75, 115, 186, 106, 75, 232, 179, 168, 167, 63, 204, 216, 162, 179, 174, 107, 146,
+                18, 225, 36, 167, 94, 193, 180, 226, 117, 172, 212, 114, 223, 15, 50,
+            ],
+            [
+                176, 211, 56, 107, 227, 231, 148, 217, 53, 110, 20, 136, 62, 14, 75, 4, 179, 147,
+                193, 143, 225, 32, 245, 54, 107, 107, 83, 207, 201, 170, 108, 35,
+            ],
+            [
+                135, 232, 137, 131, 239, 201, 107, 214, 2, 206, 248, 161, 94, 157, 231, 53, 240,
+                118, 115, 87, 100, 84, 240, 4, 35, 37, 72, 160, 10, 137, 21, 43,
+            ],
+            [
+                216, 143, 183, 255, 47, 203, 122, 10, 130, 17, 0, 136, 25, 226, 110, 174, 215, 138,
+                123, 214, 56, 215, 95, 147, 218, 124, 76, 247, 73, 32, 245, 1,
+            ],
+            [
+                247, 84, 174, 26, 119, 61, 170, 26, 77, 181, 193, 172, 194, 13, 211, 223, 248, 30,
+                241, 87, 103, 224, 179, 69, 192, 130, 253, 42, 231, 106, 127, 26,
+            ],
+            [
+                192, 126, 230, 15, 54, 199, 75, 28, 72, 125, 234, 99, 235, 94, 52, 46, 194, 4, 219,
+                7, 94, 16, 206, 64, 88, 59, 174, 71, 70, 55, 99, 33,
+            ],
+            [
+                196, 15, 132, 108, 84, 130, 151, 57, 157, 182, 158, 255, 33, 76, 11, 148, 119, 174,
+                168, 96, 99, 169, 233, 15, 146, 186, 25, 5, 216, 202, 109, 32,
+            ],
+            [
+                188, 57, 67, 252, 15, 89, 248, 173, 254, 76, 155, 45, 206, 158, 68, 224, 212, 53,
+                173, 36, 91, 216, 184, 42, 41, 108, 207, 44, 196, 45, 94, 18,
+            ],
+        ],
+        [
+            [
+                218, 172, 92, 81, 16, 179, 229, 193, 186, 132, 67, 250, 255, 195, 159, 70, 212,
+                250, 138, 250, 3, 69, 119, 10, 55, 50, 206, 5, 246, 151, 215, 20,
+            ],
+            [
+                51, 230, 133, 158, 182, 219, 171, 119, 102, 3, 52, 12, 52, 76, 113, 130, 202, 207,
+                93, 139, 240, 26, 169, 7, 233, 116, 96, 3, 223, 185, 50, 31,
+            ],
+            [
+                36, 139, 13, 229, 129, 138, 69, 87, 50, 206, 84, 231, 61, 16, 68, 84, 48, 147, 149,
+                173, 220, 1, 65, 89, 142, 32, 187, 253, 76, 37, 61, 54,
+            ],
+            [
+                167, 165, 144, 196, 88, 82, 129, 72, 238, 36, 112, 122, 62, 244, 41, 132, 64, 87,
+                204, 72, 131, 4, 246, 255, 219, 43, 114, 201, 156, 234, 137, 29,
+            ],
+            [
+                179, 218, 231, 235, 232, 232, 155, 166, 98, 254, 141, 172, 220, 96, 146, 138, 115,
+                171, 187, 5, 195, 32, 196, 186, 235, 86, 101, 57, 46, 21, 101, 46,
+            ],
+            [
+                205, 112, 207, 28, 140, 73, 173, 97, 152, 138, 142, 158, 218, 158, 235, 234, 119,
+                234, 84, 136, 123, 67, 159, 138, 181, 46, 25, 243, 57, 7, 174, 6,
+            ],
+            [
+                253, 96, 50, 177, 240, 0, 10, 201, 122, 89, 197, 202, 171, 92, 154, 223, 157, 134,
+                62, 105, 208, 11, 25, 165, 182, 177, 95, 108, 97, 60, 77, 52,
+            ],
+            [
+                131, 4, 229, 207, 112, 205, 220, 61, 106, 161, 46, 155, 188, 236, 59, 140, 231,
+                127, 159, 20, 15, 155, 172, 147, 23, 167, 246, 125, 3, 174, 155, 60,
+            ],
+        ],
+        [
+            [
+                60, 158, 89, 240, 52, 32, 102, 9, 62, 229, 95, 225, 137, 241, 131, 149, 78, 33, 67,
+                213, 138, 228, 225, 162, 70, 44, 182, 236, 211, 32, 184, 27,
+            ],
+            [
+                208, 179, 211, 136, 254, 3, 196, 17, 199, 94, 65, 14, 224, 8, 118, 12, 20, 28, 56,
+                162, 197, 182, 218, 85, 104, 98, 176, 85, 44, 11, 217, 36,
+            ],
+            [
+                114, 112, 115, 36, 131, 118, 69, 121, 152, 205, 90, 156, 37, 57, 190, 72, 153, 123,
+                116, 18, 254, 126, 198, 172, 234, 153, 7, 233, 27, 61, 249, 29,
+            ],
+            [
+                80, 205, 216, 120, 18, 194, 102, 182, 193, 47, 131, 164, 106, 47, 31, 145, 110, 39,
+                76, 187, 191, 41, 5, 70, 214, 1, 27, 176, 155, 130, 139, 58,
+            ],
+            [
+                48, 190, 74, 99, 75, 161, 21, 79, 84, 45, 242, 201, 139, 100, 178, 182, 182, 40,
+                102, 56, 127, 4, 182, 21, 246, 159, 72, 162, 209, 115, 117, 17,
+            ],
+            [
+                254, 195, 186, 13, 205, 216, 180, 215, 26, 221, 66, 145, 141, 113, 12, 251, 137,
+                66, 61, 170, 127, 59, 184, 170, 68, 84, 160, 242, 90, 237, 93, 51,
+            ],
+            [
+                230, 101, 81, 100, 212, 59, 170, 185, 11, 226, 128, 90, 18, 194, 159, 171, 161, 30,
+                93, 185, 104, 196, 169, 206, 192, 48, 117, 155, 43, 142, 203, 24,
+            ],
+            [
+                98, 248, 199, 148, 60, 199, 224, 235, 70, 78, 58, 110, 85, 219, 241, 91, 161, 1,
+                125, 104, 43, 97, 199, 204, 244, 112, 212, 172, 101, 49, 197, 60,
+            ],
+        ],
+        [
+            [
+                109, 4, 145, 42, 217, 54, 90, 192, 21, 82, 139, 153, 77, 23, 205, 184, 81, 100,
+                131, 81, 238, 45, 14, 133, 143, 149, 66, 69, 220, 51, 123, 32,
+            ],
+            [
+                212, 97, 85, 176, 230, 154, 3, 189, 57, 5, 170, 90, 114, 250, 164, 4, 175, 67, 30,
+                138, 159, 174, 211, 96, 201, 120, 96, 59, 187, 108, 131, 25,
+            ],
+            [
+                123, 188, 70, 231, 152, 80, 137, 165, 235, 45, 253, 51, 206, 236, 80, 32, 70, 128,
+                56, 177, 64, 215, 124, 180, 110, 219, 59, 216, 84, 132, 120, 35,
+            ],
+            [
+                119, 227, 185, 33, 254, 109, 7, 252, 51, 149, 113, 153, 142, 83, 145, 246, 136,
+                169, 42, 29, 129, 215, 203, 187, 130, 192, 96, 68, 60, 105, 73, 33,
+            ],
+            [
+                14, 135, 143, 81, 148, 82, 70, 91, 214, 125, 221, 104, 39, 29, 66, 160, 46, 59,
+                119, 201, 87, 18, 213, 197, 170, 193, 159, 210, 68, 234, 38, 44,
+            ],
+            [
+                192, 197, 34, 115, 60, 192, 8, 2, 63, 169, 68, 7, 188, 43, 115, 11, 93, 84, 72,
+                169, 68, 170, 0, 48, 141, 9, 99, 59, 230, 173, 252, 61,
+            ],
+            [
+                157, 101, 14, 19, 196, 104, 78, 228, 225, 141, 74, 129, 216, 70, 102, 239, 126,
+                148, 62, 73, 170, 159, 139, 245, 171, 71, 155, 143, 135, 115, 239, 33,
+            ],
+            [
+                151, 87, 59, 240, 249, 193, 214, 47, 190, 21, 31, 2, 170, 11, 37, 75, 114, 196, 13,
+                143, 158, 51, 222, 85, 134, 110, 93, 114, 143, 30, 225, 3,
+            ],
+        ],
+        [
+            [
+                222, 164, 22, 4, 69, 7, 68, 98, 183, 56, 138, 131, 13, 30, 184, 38, 64, 118, 210,
+                190, 106, 148, 233, 222, 229, 112, 211, 160, 28, 85, 143, 38,
+            ],
+            [
+                246, 229, 91, 106, 203, 25, 10, 152, 221, 43, 17, 7, 222, 202, 168, 217, 250, 13,
+                146, 222, 211, 170, 34, 188, 71, 80, 69, 144, 9, 196, 148, 4,
+            ],
+            [
+                138, 41, 153, 59, 99, 59, 32, 147, 160, 95, 51, 122, 135, 237, 242, 203, 56, 237,
+                253, 38, 76, 123, 214, 49, 183, 207, 92, 193, 195, 1, 95, 17,
+            ],
+            [
+                10, 233, 123, 190, 67, 59, 105, 101, 38, 46, 108, 145, 73, 172, 49, 90, 48, 182,
+                217, 173, 154, 56, 148, 200, 102, 65, 95, 255, 168, 7, 63, 11,
+            ],
+            [
+                16, 22, 96, 185, 19, 231, 41, 212, 40, 75, 30, 181, 249, 137, 216, 223, 140, 190,
+                248, 68, 124, 17, 194, 202, 222, 24, 47, 131, 219, 186, 0, 4,
+            ],
+            [
+                231, 152, 43, 21, 27, 204, 197, 142, 77, 161, 196, 61, 191, 71, 206, 193, 185, 14,
+                81, 88, 235, 250, 171, 165, 86, 194, 209, 103, 221, 137, 104, 21,
+            ],
+            [
+                160, 138, 17, 224, 249, 68, 131, 16, 183, 34, 124, 18, 211, 23, 86, 226, 129, 191,
+                20, 26, 194, 209, 225, 108, 195, 143, 73, 217, 229, 227, 224, 22,
+            ],
+            [
+                113, 175, 133, 4, 208, 64, 59, 184, 21, 52, 30, 229, 253, 188, 97, 151, 4, 127,
+                116, 244, 57, 237, 205, 104, 203, 29, 86, 81, 242, 74, 74, 22,
+            ],
+        ],
+        [
+            [
+                46, 232, 98, 87, 156, 72, 142, 90, 95, 85, 31, 251, 230, 74, 53, 248, 25, 35, 115,
+                224, 189, 117, 174, 204, 97, 136, 81, 85, 190, 127, 147, 2,
+            ],
+            [
+                106, 193, 219, 146, 211, 127, 128, 18, 171, 175, 219, 20, 190, 157, 13, 196, 37,
+                84, 130, 11, 53, 223, 177, 34, 91, 86, 242, 163, 40, 43, 83, 50,
+            ],
+            [
+                76, 156, 196, 254, 65, 216, 124, 40, 2, 247, 119, 19, 178, 117, 191, 85, 178, 115,
+                158, 31, 20, 211, 179, 160, 184, 194, 229, 247, 253, 75, 209, 32,
+            ],
+            [
+                49, 98, 166, 243, 137, 31, 85, 12, 223, 84, 155, 169, 94, 240, 116, 252, 22, 79,
+                144, 172, 247, 197, 213, 223, 206, 44, 198, 236, 183, 73, 127, 43,
+            ],
+            [
+                174, 87, 142, 141, 246, 21, 198, 24, 25, 34, 136, 254, 123, 57, 211, 48, 153, 105,
+                163, 46, 107, 17, 16, 211, 18, 57, 216, 2, 153, 200, 149, 63,
+            ],
+            [
+                48, 78, 164, 14, 101, 237, 139, 93, 46, 230, 201, 184, 81, 146, 227, 188, 19, 77,
+                22, 120, 143, 94, 48, 186, 47, 254, 230, 100, 94, 79, 126, 30,
+            ],
+            [
+                41, 45, 174, 40, 45, 115, 65, 203, 202, 18, 51, 244, 185, 229, 140, 90, 136, 1,
+                201, 250, 40, 7, 234, 12, 181, 131, 135, 90, 214, 43, 72, 61,
+            ],
+            [
+                129, 80, 182, 29, 25, 82, 225, 155, 130, 179, 110, 176, 126, 13, 189, 93, 211, 167,
+                199, 188, 242, 180, 241, 139, 72, 36, 96, 61, 221, 218, 97, 22,
+            ],
+        ],
+        [
+            [
+                202, 105, 150, 99, 31, 220, 99, 169, 247, 66, 126, 82, 68, 169, 190, 19, 139, 5,
+                240, 62, 28, 81, 0, 57, 64, 217, 61, 214, 105, 141, 14, 14,
+            ],
+            [
+                121, 237, 69, 70, 155, 153, 79, 121, 183, 4, 198, 210, 33, 93, 15, 17, 42, 240,
+                125, 12, 207, 75, 63, 217, 243, 171, 150, 179, 37, 213, 90, 22,
+            ],
+            [
+                137, 189, 242, 163, 178, 40, 191, 238, 241, 111, 231, 143, 197, 116, 184, 169, 250,
+                127, 87, 27, 58, 34, 43, 131, 214, 33, 114, 7, 25, 124, 188, 54,
+            ],
+            [
+                166, 26, 160, 179, 187, 7, 1, 146, 97, 39, 72, 246, 182, 126, 233, 163, 159, 199,
+                226, 107, 84, 133, 116, 55, 0, 157, 108, 224, 111, 121, 37, 15,
+            ],
+            [
+                1, 224, 227, 181, 81, 157, 195, 16, 177, 109, 97, 12, 126, 5, 213, 88, 199, 54, 97,
+                176, 160, 130, 130, 164, 32, 154, 43, 248, 89, 118, 94, 37,
+            ],
+            [
+                16, 24, 90, 101, 40, 88, 228, 47, 169, 19, 204, 93, 176, 99, 118, 226, 56, 132, 46,
+                138, 97, 206, 65, 152, 193, 255, 182, 122, 110, 252, 235, 51,
+            ],
+            [
+                208, 116, 9, 158, 104, 131, 193, 184, 135, 202, 73, 134, 68, 225, 95, 94, 48, 43,
+                127, 175, 145, 250, 253, 121, 191, 16, 127, 1, 225, 223, 129, 25,
+            ],
+            [
+                207, 42, 192, 229, 100, 225, 147, 163, 160, 206, 110, 104, 16, 216, 207, 247, 85,
+                157, 55, 143, 22, 162, 102, 100, 229, 207, 20, 196, 117, 16, 15, 29,
+            ],
+        ],
+        [
+            [
+                232, 94, 159, 130, 222, 34, 95, 121, 209, 248, 55, 226, 101, 243, 69, 90, 139, 165,
+                190, 152, 248, 198, 138, 75, 22, 112, 241, 26, 123, 111, 74, 21,
+            ],
+            [
+                104, 84, 76, 57, 77, 77, 219, 63, 140, 58, 115, 219, 82, 119, 195, 83, 115, 210,
+                254, 223, 16, 163, 92, 213, 8, 118, 129, 132, 36, 219, 201, 17,
+            ],
+            [
+                247, 98, 185, 94, 66, 191, 6, 55, 101, 82, 93, 163, 151, 246, 90, 90, 60, 9, 212,
+                123, 242, 130, 100, 116, 31, 253, 76, 38, 50, 109, 127, 54,
+            ],
+            [
+                242, 136, 27, 210, 37, 137, 217, 220, 142, 196, 115, 99, 28, 189, 108, 169, 132,
+                58, 191, 128, 113, 51, 44, 57, 85, 30, 84, 52, 11, 103, 246, 42,
+            ],
+            [
+                18, 20, 119, 176, 68, 140, 4, 70, 188, 250, 227, 85, 213, 226, 103, 67, 3, 195, 85,
+                214, 178, 188, 67, 31, 157, 155, 36, 237, 97, 159, 200, 18,
+            ],
+            [
+                233, 67, 202, 247, 179, 216, 23, 83, 71, 183, 14, 90, 122, 58, 222, 185, 105, 121,
+                180, 64, 47, 123, 112, 52, 185, 114, 144, 19, 253, 182, 146, 37,
+            ],
+            [
+                60, 146, 1, 14, 19, 27, 251, 195, 108, 115, 84, 21, 80, 120, 175, 42, 103, 165, 93,
+                169, 9, 233, 33, 193, 176, 241, 194, 199, 13, 200, 247, 23,
+            ],
+            [
+                8, 235, 173, 245, 43, 73, 185, 148, 228, 177, 17, 37, 138, 247, 51, 119, 59, 225,
+                32, 176, 221, 175, 72, 35, 204, 14, 2, 170, 70, 121, 22, 21,
+            ],
+        ],
+        [
+            [
+                196, 56, 4, 122, 229, 165, 139, 39, 173, 36, 146, 166, 222, 126, 212, 105, 105,
+                104, 141, 252, 228, 41, 32, 124, 238, 146, 250, 130, 148, 211, 187, 37,
+            ],
+            [
+                42, 164, 118, 50, 29, 154, 132, 203, 153, 183, 105, 219, 188, 79, 25, 205, 251, 15,
+                52, 81, 117, 210, 34, 1, 100, 6, 10, 221, 72, 192, 0, 38,
+            ],
+            [
+                233, 28, 75, 17, 134, 252, 20, 140, 80, 96, 38, 98, 179, 209, 42, 175, 189, 0, 251,
+                46, 245, 188, 93, 230, 196, 50, 106, 219, 33, 200, 121, 18,
+            ],
+            [
+                117, 212, 115, 101, 197, 167, 170, 235, 187, 239, 164, 92, 63, 65, 76, 160, 98,
+                224, 8, 164, 5, 190, 22, 79, 238, 224, 129, 54, 2, 140, 88, 31,
+            ],
+            [
+                126, 248, 70, 110, 248, 116, 71, 254, 38, 215, 236, 237, 250, 165, 111, 141, 254,
+                48, 76, 249, 173, 26, 183, 151, 248, 7, 138, 16, 50, 42, 49, 48,
+            ],
+            [
+                203, 229, 19, 227, 165, 159, 27, 89, 147, 105, 223, 255, 253, 46, 217, 34, 195,
+                123, 65, 71, 25, 231, 105, 129, 173, 89, 22, 138, 74, 58, 148, 59,
+            ],
+            [
+                204, 80, 234, 12, 28, 118, 71, 49, 72, 148, 41, 105, 90, 156, 249, 238, 154, 224,
+                208, 96, 110, 4, 203, 248, 110, 85, 138, 25, 238, 196, 168, 7,
+            ],
+            [
+                47, 188, 110, 84, 128, 148, 199, 214, 242, 37, 65, 80, 211, 120, 15, 14, 108, 44,
+                89, 222, 4, 76, 78, 155, 182, 35, 191, 188, 37, 60, 26, 49,
+            ],
+        ],
+    ],
+    [
+        [
+            [
+                254, 139, 166, 35, 65, 218, 48, 238, 75, 174, 253, 60, 0, 181, 233, 147, 227, 36,
+                30, 80, 167, 194, 23, 202, 219, 5, 120, 82, 143, 165, 127, 4,
+            ],
+            [
+                196, 164, 7, 51, 179, 145, 130, 79, 233, 228, 6, 41, 60, 147, 171, 3, 38, 84, 116,
+                109, 54, 19, 6, 197, 98, 104, 183, 242, 43, 191, 19, 5,
+            ],
+            [
+                126, 207, 204, 112, 121, 87, 245, 223, 36, 2, 108, 110, 37, 232, 161, 64, 114, 21,
+                197, 222, 66, 97, 75, 182, 148, 38, 113, 32, 71, 13, 188, 2,
+            ],
+            [
+                12, 60, 122, 207, 144, 142, 197, 62, 41, 240, 90, 92, 109, 242, 141, 73, 93, 149,
+                187, 84, 40, 173, 211, 229, 74, 226, 248, 214, 70, 225, 15, 32,
+            ],
+            [
+                19, 27, 59, 231, 174, 165, 180, 161, 39, 118, 147, 179, 245, 66, 26, 235, 26, 60,
+                113, 27, 164, 25, 157, 169, 41, 184, 51, 85, 63, 224, 224, 32,
+            ],
+            [
+                213, 76, 36, 25, 224, 29, 233, 69, 181, 235, 196, 176, 178, 149, 253, 161, 186, 38,
+                110, 89, 62, 223, 154, 144, 160, 121, 247, 218, 157, 22, 103, 45,
+            ],
+            [
+                115, 102, 15, 215, 146, 69, 242, 58, 213, 17, 238, 42, 195, 170, 253, 163, 250,
+                220, 4, 163, 48, 178, 192, 37, 194, 20, 94, 188, 148, 148, 123, 38,
+            ],
+            [
+                68, 143, 29, 20, 141, 13, 194, 28, 131, 108, 161, 46, 236, 197, 83, 212, 136, 102,
+                66, 96, 193, 211, 44, 59, 163, 72, 205, 232, 20, 71, 193, 39,
+            ],
+        ],
+        [
+            [
+                252, 199, 219, 138, 128, 184, 179, 159, 73, 173, 114, 8, 31, 2, 70, 145, 66, 150,
+                186, 67, 26, 14, 132, 72, 61, 62, 102, 88, 243, 188, 192, 58,
+            ],
+            [
+                217, 88, 207, 15, 146, 100, 164, 101, 184, 243, 152, 84, 22, 115, 144, 113, 252,
+                228, 249, 176, 218, 106, 121, 175, 62, 220, 249, 122, 60, 6, 89, 55,
+            ],
+            [
+                107, 126, 221, 136, 16, 190, 108, 97, 110, 87, 243, 243, 66, 2, 211, 22, 222, 115,
+                145, 66, 95, 126, 219, 192, 235, 8, 35, 58, 9, 1, 28, 58,
+            ],
+            [
+                248, 163, 36, 37, 136, 174, 24, 46, 243, 111, 225, 31, 3, 119, 225, 136, 48, 127,
+                191, 47, 212, 114, 176, 201, 148, 152, 211, 220, 254, 6, 101, 45,
+            ],
+            [
+                226, 213, 198, 52, 90, 27, 251, 62, 91, 87, 187, 237, 139, 185, 64, 128, 113, 28,
+                31, 63, 214, 176, 206, 84, 130, 25, 106, 48, 116, 233, 49, 54,
+            ],
+            [
+                184, 10, 220, 32, 43, 205, 218, 110, 97, 215, 210, 157, 28, 166, 234, 225, 109,
+                238, 71, 66, 177, 61, 33, 245, 111, 175, 250, 104, 73, 7, 97, 56,
+            ],
+            [
+                181, 225, 33, 158, 195, 198, 169, 129, 197, 194, 178, 154, 78, 45, 63, 86, 214, 97,
+                78, 215, 174, 95, 212, 44, 239, 186, 255, 56, 76, 153, 146, 4,
+            ],
+            [
+                19, 214, 125, 118, 191, 184, 77, 7, 25, 151, 144, 165, 7, 129, 231, 91, 100, 175,
+                167, 27, 105, 39, 239, 192, 85, 29, 62, 88, 126, 196, 243, 2,
+            ],
+        ],
+        [
+            [
+                158, 85, 208, 176, 43, 9, 227, 21, 0, 233, 120, 14, 156, 213, 11, 195, 136, 149, 6,
+                26, 51, 92, 87, 188, 191, 19, 38, 144, 157, 94, 205, 33,
+            ],
+            [
+                107, 181, 23, 95, 176, 199, 48, 77, 96, 242, 142, 9, 97, 228, 15, 171, 245, 63,
+                218, 154, 38, 165, 160, 120, 216, 41, 171, 41, 184, 126, 4, 51,
+            ],
+            [
+                156, 113, 163, 208, 63, 132, 183, 129, 72, 151, 187, 221, 87, 108, 119, 136, 149,
+                77, 196, 179, 179, 51, 120, 208, 100, 99, 50, 231, 81, 170, 115, 23,
+            ],
+            [
+                194, 203, 159, 200, 252, 51, 73, 201, 128, 220, 197, 230, 190, 166, 227, 228, 119,
+                206, 156, 24, 235, 70, 42, 169, 181, 179, 46, 243, 54, 17, 60, 15,
+            ],
+            [
+                229, 120, 49, 7, 226, 142, 145, 176, 64, 89, 158, 168, 13, 163, 133, 27, 64, 144,
+                183, 180, 220, 80, 253, 226, 195, 189, 166, 153, 249, 128, 184, 31,
+            ],
+            [
+                230, 73, 161, 244, 76, 164, 113, 36, 52, 2, 120, 192, 234, 183, 4, 20, 23, 175,
+                167, 160, 124, 30, 180, 59, 223, 96, 226, 154, 154, 66, 179, 7,
+            ],
+            [
+                130, 89, 92, 124, 56, 199, 48, 201, 244, 28, 248, 245, 105, 206, 230, 255, 188, 86,
+                59, 91, 144, 83, 69, 198, 28, 134, 217, 193, 48, 219, 75, 36,
+            ],
+            [
+                121, 81, 22, 188, 206, 13, 163, 224, 253, 243, 180, 183, 182, 197, 234, 243, 53,
+                17, 205, 245, 187, 138, 24, 233, 241, 24, 136, 61, 23, 237, 85, 13,
+            ],
+        ],
+        [
+            [
+                152, 34, 168, 200, 18, 134, 160, 168, 34, 224, 65, 171, 167, 254, 164, 210, 52, 53,
+                253, 222, 229, 23, 80, 82, 206, 187, 181, 231, 154, 161, 146, 53,
+            ],
+            [
+                163, 145, 0, 44, 202, 15, 91, 65, 39, 30, 87, 129, 94, 18, 224, 243, 90, 212, 212,
+                116, 112, 78, 155, 75, 97, 219, 56, 165, 75, 175, 148, 17,
+            ],
+            [
+                118, 52, 28, 13, 145, 194, 82, 209, 66, 107, 222, 64, 116, 148, 51, 225, 11, 77,
+                60, 182, 234, 71, 100, 45, 195, 159, 223, 177, 247, 90, 105, 44,
+            ],
+            [
+                244, 231, 5, 122, 63, 112, 43, 214, 43, 98, 87, 54, 47, 139, 167, 19, 189, 177,
+                116, 193, 139, 222, 250, 125, 64, 91, 108, 68, 123, 229, 203, 50,
+            ],
+            [
+                221, 97, 3, 51, 157, 245, 235, 142, 10, 21, 44, 235, 187, 183, 154, 190, 48, 27, 9,
+                28, 236, 247, 160, 137, 22, 240, 206, 77, 70, 217, 37, 10,
+            ],
+            [
+                57, 251, 8, 193, 130, 150, 109, 53, 246, 231, 220, 187, 168, 110, 230, 15, 199, 22,
+                117, 109, 244, 118, 158, 132, 109, 252, 56, 138, 57, 130, 209, 1,
+            ],
+            [
+                203, 156, 137, 44, 188, 7, 164, 213, 176, 253, 32, 124, 224, 24, 55, 248, 235, 158,
+                114, 240, 58, 234, 102, 34, 96, 69, 168, 89, 194, 154, 44, 59,
+            ],
+            [
+                15, 39, 46, 231, 221, 201, 99, 158, 199, 234, 0, 193, 32, 253, 146, 73, 157, 106,
+                74, 31, 109, 158, 231, 207, 153, 171, 107, 193, 143, 56, 154, 59,
+            ],
+        ],
+        [
+            [
+                31, 31, 163, 177, 87, 247, 1, 28, 80, 113, 83, 18, 60, 74, 183, 219, 136, 112, 20,
+                87, 107, 16, 11, 177, 114, 74, 132, 9, 250, 94, 187, 4,
+            ],
+            [
+                125, 36, 151, 242, 63, 244, 144, 134, 5, 89, 109, 43, 208, 140, 121, 141, 243, 171,
+                42, 196, 77, 27, 143, 55, 98, 156, 179, 57, 68, 174, 206, 24,
+            ],
+            [
+                46, 151, 167, 72, 50, 31, 149, 83, 152, 244, 126, 193, 137, 219, 101, 104, 107, 9,
+                126, 227, 160, 50, 128, 153, 217, 34, 29, 156, 158, 182, 86, 49,
+            ],
+            [
+                186, 69, 72, 149, 119, 231, 165, 51, 116, 20, 42, 30, 50, 186, 85, 151, 189, 67,
+                128, 57, 24, 141, 75, 108, 138, 230, 74, 141, 15, 79, 84, 8,
+            ],
+            [
+                106, 232, 110, 7, 32, 128, 153, 194, 176, 186, 224, 115, 146, 123, 170, 18, 187,
+                100, 73, 176, 82, 239, 12, 16, 129, 156, 43, 134, 98, 235, 246, 63,
+            ],
+            [
+                206, 83, 163, 113, 248, 191, 226, 42, 42, 254, 2, 203, 134, 12, 7, 116, 167, 121,
+                149, 152, 154, 149, 247, 201, 37, 147, 143, 94, 123, 164, 228, 47,
+            ],
+            [
+                175, 142, 99, 243, 10, 17, 2, 193, 19, 63, 155, 173, 153, 66, 174, 221, 108, 137,
+                216, 68, 168, 171, 102, 10, 127, 223, 227, 86, 40, 63, 42, 42,
+            ],
+            [
+                46, 32, 103, 152, 28, 115, 142, 142, 233, 106, 197, 68, 134, 151, 68, 247, 44, 187,
+                110, 97, 238, 208, 243, 148, 129, 123, 165, 152, 126, 59, 250, 28,
+            ],
+        ],
+        [
+            [
+                121, 223, 14, 97, 245, 32, 60, 5, 65, 125, 58, 160, 107, 67, 1, 84, 82, 175, 209,
+                81, 118, 243, 226, 34, 95, 225, 246, 176, 49, 114, 92, 62,
+            ],
+            [
+                22, 147, 98, 107, 166, 175, 234, 151, 113, 155, 19, 33, 251, 66, 135, 149, 126,
+                207, 166, 0, 232, 122, 162, 5, 64, 106, 175, 119, 99, 127, 83, 54,
+            ],
+            [
+                163, 152, 95, 196, 167, 255, 226, 245, 82, 21, 71, 56, 247, 188, 26, 17, 35, 143,
+                87, 237, 181, 45, 142, 176, 9, 14, 249, 9, 222, 125, 237, 35,
+            ],
+            [
+                187, 169, 94, 214, 107, 17, 238, 63, 37, 253, 25, 38, 186, 152, 232, 125, 183, 214,
+                159, 38, 185, 7, 119, 118, 228, 50, 53, 205, 83, 150, 173, 14,
+            ],
+            [
+                161, 42, 13, 64, 207, 229, 226, 100, 9, 126, 123, 56, 220, 99, 255, 17, 100, 248,
+                49, 9, 174, 102, 160, 241, 100, 233, 48, 50, 170, 195, 211, 7,
+            ],
+            [
+                111, 197, 64, 194, 126, 5, 184, 2, 147, 127, 161, 57, 133, 147, 53, 85, 19, 68,
+                248, 251, 61, 124, 199, 28, 109, 112, 224, 5, 222, 191, 163, 45,
+            ],
+            [
+                63, 103, 113, 235, 39, 204, 218, 10, 20, 175, 202, 42, 244, 122, 2, 42, 178, 29,
+                223, 253, 159, 106, 54, 222, 77, 171, 83, 95, 52, 130, 170, 54,
+            ],
+            [
+                206, 74, 229, 50, 229, 105, 141, 55, 142, 80, 39, 11, 193, 21, 98, 39, 53, 46, 90,
+                86, 42, 234, 250, 118, 93, 18, 12, 89, 12, 157, 11, 34,
+            ],
+        ],
+        [
+            [
+                17, 123, 139, 214, 161, 28, 226, 56, 239, 29, 164, 50, 111, 164, 61, 181, 227, 166,
+                6, 93, 114, 127, 157, 80, 245, 193, 158, 133, 166, 212, 198, 35,
+            ],
+            [
+                33, 235, 17, 1, 92, 176, 73, 244, 144, 148, 94, 245, 101, 155, 102, 40, 103, 236,
+                218, 224, 41, 97, 209, 105, 39, 11, 84, 119, 216, 47, 35, 46,
+            ],
+            [
+                96, 106, 14, 34, 157, 184, 139, 172, 111, 199, 48, 102, 19, 148, 95, 42, 225, 100,
+                9, 220, 96, 197, 216, 46, 154, 54, 103, 125, 232, 214, 219, 47,
+            ],
+            [
+                253, 201, 191, 93, 116, 105, 2, 237, 115, 30, 140, 148, 217, 180, 77, 222, 75, 54,
+                202, 177, 120, 85, 240, 243, 6, 122, 72, 54, 103, 30, 42, 48,
+            ],
+            [
+                117, 187, 129, 47, 174, 159, 141, 26, 254, 27, 4, 239, 179, 143, 93, 147, 184, 187,
+                134, 234, 133, 206, 59, 6, 131, 154, 18, 241, 238, 180, 220, 61,
+            ],
+            [
+                112, 243, 60, 250, 122, 144, 149, 154, 47, 255, 221, 46, 165, 113, 56, 53, 123,
+                208, 32, 1, 237, 60, 16, 235, 153, 245, 14, 111, 166, 54, 9, 3,
+            ],
+            [
+                108, 33, 46, 163, 131, 80, 226, 1, 61, 88, 44, 94, 169, 241, 217, 12, 225, 195,
+                227, 194, 166, 200, 67, 154, 213, 5, 95, 95, 134, 118, 58, 24,
+            ],
+            [
+                121, 194, 238, 185, 126, 87, 7, 45, 112, 111, 60, 106, 63, 199, 24, 3, 146, 197,
+                233, 138, 214, 162, 254, 181, 226, 67, 62, 27, 70, 13, 206, 43,
+            ],
+        ],
+        [
+            [
+                11, 115, 235, 167, 76, 79, 53, 226, 161, 10, 129, 210, 249, 87, 9, 219, 216, 221,
+                32, 146, 39, 134, 125, 112, 244, 4, 203, 255, 186, 205, 221, 13,
+            ],
+            [
+                121, 122, 15, 80, 19, 78, 54, 202, 127, 224, 244, 21, 98, 129, 0, 148, 137, 88, 33,
+                17, 205, 31, 247, 161, 105, 158, 4, 57, 232, 158, 30, 18,
+            ],
+            [
+                244, 213, 221, 10, 8, 160, 148, 156, 82, 203, 133, 221, 83, 182, 102, 94, 176, 230,
+                166, 248, 73, 170, 140, 60, 231, 156, 209, 236, 108, 87, 133, 38,
+            ],
+            [
+                146, 43, 100, 91, 92, 165, 19, 253, 190, 216, 66, 159, 85, 228, 176, 35, 213, 45,
+                188, 161, 57, 135, 125, 40, 211, 53, 175, 99, 52, 193, 72, 27,
+            ],
+            [
+                168, 212, 21, 124, 132, 138, 209, 102, 185, 77, 138, 94, 250, 184, 55, 190, 71, 42,
+                222, 234, 111, 180, 164, 212, 214, 180, 142, 55, 149, 215, 103, 35,
+            ],
+            [
+                76, 63, 19, 190, 203, 109, 140, 73, 1, 236, 97, 205, 1, 210, 53, 163, 83, 181, 196,
+                162, 57, 41, 195, 137, 16, 4, 221, 89, 117, 189, 155, 58,
+            ],
+            [
+                193, 57, 238, 23, 81, 146, 94, 91, 189, 99, 117, 233, 83, 227, 162, 129, 158, 71,
+                16, 64, 242, 63, 65, 212, 86, 75, 49, 238, 123, 172, 86, 17,
+            ],
+            [
+                51, 34, 6, 41, 167, 113, 29, 136, 207, 232, 184, 8, 210, 34, 173, 113, 46, 29, 22,
+                46, 94, 15, 67, 76, 190, 211, 162, 210, 176, 170, 132, 59,
+            ],
+        ],
+        [
+            [
+                131, 105, 254, 144, 156, 140, 46, 48, 76, 4, 239, 155, 133, 220, 182, 46, 43, 255,
+                34, 136, 119, 204, 196, 10, 239, 150, 207, 73, 223, 108, 242, 42,
+            ],
+            [
+                132, 20, 192, 86, 42, 24, 86, 68, 213, 113, 214, 222, 27, 186, 17, 42, 239, 134,
+                85, 213, 53, 39, 244, 90, 8, 175, 106, 248, 60, 50, 53, 61,
+            ],
+            [
+                90, 229, 30, 158, 211, 234, 232, 69, 35, 30, 249, 96, 189, 182, 28, 156, 107, 177,
+                176, 158, 123, 240, 61, 72, 85, 198, 192, 18, 103, 59, 134, 47,
+            ],
+            [
+                60, 146, 40, 53, 186, 153, 20, 86, 164, 121, 199, 227, 199, 163, 50, 145, 217, 104,
+                64, 17, 231, 62, 80, 220, 175, 234, 41, 25, 74, 244, 25, 53,
+            ],
+            [
+                38, 141, 207, 161, 128, 116, 115, 25, 177, 201, 121, 82, 128, 238, 53, 35, 244, 55,
+                126, 19, 52, 199, 57, 186, 122, 220, 75, 219, 162, 83, 236, 31,
+            ],
+            [
+                142, 242, 139, 204, 76, 39, 54, 207, 139, 249, 54, 184, 46, 130, 89, 32, 96, 234,
+                96, 140, 66, 22, 3, 142, 130, 91, 206, 197, 139, 112, 157, 25,
+            ],
+            [
+                157, 187, 123, 8, 100, 88, 16, 69, 14, 71, 54, 189, 84, 1, 188, 58, 11, 136, 77, 2,
+                188, 166, 64, 185, 247, 153, 14, 118, 88, 133, 2, 55,
+            ],
+            [
+                17, 13, 11, 124, 81, 192, 226, 240, 132, 26, 10, 2, 81, 0, 162, 125, 96, 41, 85,
+                25, 116, 220, 30, 194, 246, 103, 239, 215, 83, 135, 203, 61,
+            ],
+        ],
+        [
+            [
+                70, 135, 8, 246, 141, 140, 182, 198, 9, 188, 215, 178, 68, 53, 163, 240, 246, 251,
+                63, 2, 90, 110, 3, 167, 233, 100, 174, 123, 69, 133, 231, 26,
+            ],
+            [
+                38, 39, 200, 236, 62, 147, 105, 142, 181, 129, 101, 41, 96, 72, 80, 251, 86, 219,
+                162, 10, 23, 166, 104, 200, 113, 136, 39, 44, 252, 36, 4, 41,
+            ],
+            [
+                27, 55, 232, 100, 50, 88, 65, 157, 58, 232, 254, 161, 165, 108, 5, 17, 117, 96,
+                124, 17, 114, 87, 2, 210, 154, 43, 199, 102, 160, 235, 168, 43,
+            ],
+            [
+                117, 190, 49, 223, 125, 21, 93, 51, 100, 251, 70, 153, 200, 181, 91, 205, 159, 208,
+                48, 216, 228, 44, 13, 190, 48, 141, 94, 58, 85, 146, 170, 46,
+            ],
+            [
+                44, 123, 107, 110, 18, 39, 159, 195, 84, 123, 243, 56, 80, 238, 49, 95, 49, 238,
+                143, 174, 255, 124, 35, 192, 170, 18, 116, 76, 70, 150, 110, 16,
+            ],
+            [
+                62, 128, 78, 101, 130, 4, 202, 88, 207, 103, 250, 40, 123, 183, 192, 175, 189, 9,
+                35, 127, 81, 247, 85, 78, 232, 2, 62, 226, 127, 251, 30, 16,
+            ],
+            [
+                124, 51, 185, 78, 85, 140, 149, 14, 234, 14, 123, 214, 201, 247, 231, 225, 36, 149,
+                26, 105, 171, 1, 255, 198, 23, 177, 140, 129, 99, 136, 38, 59,
+            ],
+            [
+                39, 158, 100, 151, 130, 102, 97, 53, 93, 236, 81, 97, 176, 71, 82, 76, 155, 33,
+                234, 168, 203, 135, 89, 48, 27, 60, 254, 22, 13, 109, 206, 37,
+            ],
+        ],
+        [
+            [
+                73, 153, 225, 234, 49, 31, 143, 104, 106, 238, 85, 30, 180, 199, 41, 102, 118, 249,
+                30, 57, 226, 210, 114, 141, 0, 229, 19, 89, 229, 81, 114, 57,
+            ],
+            [
+                175, 166, 220, 238, 136, 246, 118, 248, 69, 136, 52, 225, 202, 186, 0, 236, 131,
+                144, 155, 125, 128, 142, 183, 46, 243, 85, 207, 118, 14, 11, 221, 25,
+            ],
+            [
+                196, 76, 215, 126, 93, 197, 12, 85, 108, 141, 152, 143, 107, 149, 65, 246, 236, 53,
+                146, 234, 243, 129, 151, 181, 176, 226, 14, 50, 208, 36, 198, 13,
+            ],
+            [
+                209, 159, 45, 93, 154, 171, 144, 250, 113, 29, 110, 215, 130, 2, 136, 47, 101, 207,
+                191, 241, 200, 206, 246, 46, 39, 33, 180, 244, 254, 85, 181, 9,
+            ],
+            [
+                60, 65, 126, 221, 208, 253, 73, 95, 131, 67, 1, 137, 188, 129, 139, 13, 189, 117,
+                217, 141, 56, 32, 160, 208, 130, 186, 129, 172, 199, 214, 253, 46,
+            ],
+            [
+                117, 209, 159, 199, 126, 142, 139, 10, 179, 156, 231, 26, 114, 54, 71, 67, 121,
+                158, 42, 158, 166, 236, 165, 167, 126, 220, 30, 192, 127, 63, 15, 22,
+            ],
+            [
+                182, 250, 184, 11, 143, 209, 91, 255, 8, 189, 149, 237, 233, 220, 237, 94, 160,
+                150, 225, 99, 215, 93, 88, 90, 79, 138, 33, 111, 250, 158, 228, 54,
+            ],
+            [
+                242, 213, 40, 170, 135, 40, 65, 180, 73, 49, 241, 167, 250, 25, 31, 39, 238, 18,
+                59, 137, 221, 142, 102, 180, 111, 92, 209, 15, 245, 170, 150, 48,
+            ],
+        ],
+        [
+            [
+                233, 72, 150, 200, 45, 16, 150, 112, 194, 32, 76, 202, 213, 57, 172, 241, 245, 71,
+                203, 20, 235, 186, 48, 157, 31, 94, 14, 122, 181, 89, 29, 14,
+            ],
+            [
+                189, 220, 206, 71, 218, 38, 1, 47, 88, 185, 25, 33, 252, 52, 141, 165, 133, 37,
+                184, 46, 177, 250, 250, 182, 149, 224, 219, 41, 210, 204, 112, 34,
+            ],
+            [
+                59, 47, 0, 136, 4, 13, 192, 126, 115, 92, 239, 207, 158, 7, 48, 113, 223, 98, 169,
+                246, 16, 211, 212, 72, 223, 178, 16, 221, 109, 154, 138, 11,
+            ],
+            [
+                214, 165, 247, 88, 197, 157, 215, 6, 5, 134, 192, 239, 90, 144, 169, 90, 179, 106,
+                188, 20, 250, 126, 1, 240, 254, 167, 71, 6, 26, 154, 111, 44,
+            ],
+            [
+                95, 42, 22, 160, 152, 37, 168, 48, 81, 6, 149, 33, 202, 243, 215, 249, 32, 117,
+                214, 11, 11, 191, 103, 72, 253, 157, 106, 142, 15, 135, 11, 33,
+            ],
+            [
+                149, 42, 131, 248, 53, 210, 193, 172, 22, 33, 149, 208, 28, 105, 218, 190, 187,
+                189, 60, 48, 131, 86, 10, 163, 131, 110, 176, 90, 185, 11, 86, 39,
+            ],
+            [
+                39, 18, 196, 210, 113, 102, 169, 1, 101, 85, 229, 75, 202, 65, 66, 58, 17, 243,
+                237, 3, 88, 55, 95, 145, 69, 15, 60, 111, 120, 65, 248, 8,
+            ],
+            [
+                250, 102, 166, 120, 63, 43, 72, 107, 54, 244, 215, 220, 11, 173, 207, 56, 35, 124,
+                251, 225, 132, 209, 154, 22, 131, 227, 162, 66, 29, 170, 117, 22,
+            ],
+        ],
+        [
+            [
+                76, 19, 227, 53, 121, 165, 143, 133, 80, 197, 204, 198, 224, 154, 56, 68, 113, 176,
+                237, 39, 168, 224, 159, 19, 132, 25, 20, 3, 17, 114, 133, 15,
+            ],
+            [
+                172, 128, 17, 230, 45, 95, 109, 23, 116, 222, 169, 24, 117, 19, 50, 244, 233, 138,
+                141, 184, 15, 237, 199, 118, 5, 81, 172, 76, 111, 52, 203, 56,
+            ],
+            [
+                252, 105, 30, 135, 131, 76, 244, 75, 101, 92, 78, 153, 149, 24, 155, 158, 89, 178,
+                28, 216, 98, 73, 111, 74, 144, 0, 66, 70, 35, 113, 246, 42,
+            ],
+            [
+                185, 139, 116, 209, 233, 153, 6, 27, 207, 144, 127, 41, 137, 183, 193, 90, 100, 2,
+                138, 176, 66, 84, 29, 145, 7, 154, 60, 66, 22, 208, 179, 52,
+            ],
+            [
+                164, 85, 27, 149, 130, 159, 88, 83, 149, 100, 28, 106, 188, 61, 210, 32, 37, 152,
+                205, 210, 103, 17, 241, 116, 226, 94, 181, 94, 186, 219, 13, 50,
+            ],
+            [
+                236, 97, 16, 225, 0, 201, 210, 75, 39, 132, 251, 134, 105, 242, 200, 63, 22, 170,
+                41, 36, 134, 51, 191, 124, 196, 68, 179, 161, 186, 219, 3, 43,
+            ],
+            [
+                166, 166, 85, 116, 237, 202, 71, 137, 158, 71, 70, 137, 58, 39, 171, 114, 200, 8,
+                145, 4, 227, 80, 171, 58, 181, 247, 197, 83, 240, 231, 223, 17,
+            ],
+            [
+                214, 129, 68, 67, 213, 254, 69, 165, 2, 236, 62, 86, 26, 23, 180, 223, 246, 1, 50,
+                63, 149, 89, 213, 92, 54, 180, 25, 0, 198, 15, 232, 24,
+            ],
+        ],
+        [
+            [
+                193, 54, 6, 133, 103, 153, 159, 140, 197, 137, 9, 204, 141, 136, 137, 85, 60, 192,
+                4, 35, 98, 191, 214, 240, 18, 222, 245, 223, 155, 55, 230, 12,
+            ],
+            [
+                77, 106, 226, 4, 138, 157, 92, 118, 235, 89, 225, 97, 242, 225, 184, 221, 95, 182,
+                95, 167, 4, 253, 74, 113, 224, 47, 179, 18, 11, 146, 32, 39,
+            ],
+            [
+                8, 143, 8, 250, 194, 142, 55, 89, 192, 57, 211, 27, 181, 153, 251, 214, 25, 60,
+                207, 132, 224, 141, 155, 218, 165, 46, 252, 212, 233, 61, 40, 8,
+            ],
+            [
+                29, 88, 158, 64, 155, 184, 243, 98, 212, 21, 170, 217, 58, 88, 211, 137, 131, 46,
+                245, 165, 211, 218, 90, 45, 35, 16, 233, 92, 209, 185, 247, 35,
+            ],
+            [
+                84, 47, 233, 29, 249, 83, 79, 143, 100, 81, 38, 103, 161, 228, 166, 171, 100, 225,
+                3, 16, 184, 211, 75, 185, 159, 70, 44, 40, 240, 165, 140, 15,
+            ],
+            [
+                38, 90, 219, 22, 26, 84, 167, 10, 128, 45, 48, 161, 161, 84, 22, 139, 98, 22, 84,
+                74, 48, 116, 116, 125, 102, 57, 207, 105, 194, 32, 132, 36,
+            ],
+            [
+                237, 133, 155, 34, 2, 176, 154, 128, 26, 152, 15, 235, 34, 173, 194, 186, 254, 180,
+                244, 77, 12, 182, 143, 169, 19, 119, 38, 190, 125, 26, 184, 45,
+            ],
+            [
+                161, 255, 51, 64, 36, 143, 85, 17, 220, 73, 234, 195, 150, 221, 243, 138, 151, 129,
+                238, 135, 30, 6, 122, 104, 77, 153, 95, 165, 126, 252, 126, 32,
+            ],
+        ],
+        [
+            [
+                235, 84, 174, 153, 227, 237, 135, 88, 125, 24, 16, 75, 198, 139, 217, 92, 36, 197,
+                238, 17, 106, 114, 80, 180, 35, 222, 202, 199, 112, 151, 217, 17,
+            ],
+            [
+                205, 254, 143, 14, 216, 93, 6, 148, 132, 17, 89, 102, 39, 113, 61, 101, 105, 183,
+                32, 23, 42, 160, 74, 118, 131, 226, 140, 76, 15, 208, 225, 4,
+            ],
+            [
+                139, 100, 75, 187, 204, 181, 181, 109, 109, 195, 222, 74, 163, 195, 185, 44, 126,
+                39, 68, 178, 16, 95, 88, 90, 113, 65, 23, 52, 75, 134, 0, 46,
+            ],
+            [
+                66, 56, 89, 213, 201, 247, 170, 173, 46, 225, 207, 129, 67, 224, 58, 174, 196, 30,
+                175, 135, 209, 238, 123, 68, 47, 213, 96, 172, 22, 223, 218, 44,
+            ],
+            [
+                226, 225, 249, 240, 2, 61, 16, 215, 38, 5, 190, 241, 113, 58, 41, 185, 217, 8, 75,
+                172, 15, 203, 248, 147, 41, 100, 141, 24, 34, 247, 195, 60,
+            ],
+            [
+                208, 80, 238, 164, 20, 83, 193, 140, 65, 255, 125, 153, 121, 35, 74, 186, 241, 166,
+                188, 123, 108, 242, 129, 243, 170, 84, 215, 100, 112, 102, 237, 43,
+            ],
+            [
+                73, 123, 187, 15, 251, 108, 65, 72, 124, 229, 90, 224, 242, 236, 87, 180, 108, 28,
+                232, 111, 41, 237, 33, 62, 69, 19, 118, 48, 5, 147, 92, 36,
+            ],
+            [
+                107, 161, 54, 237, 118, 254, 246, 84, 129, 192, 10, 53, 188, 24, 12, 209, 67, 51,
+                67, 65, 207, 240, 176, 105, 140, 124, 117, 80, 210, 46, 12, 23,
+            ],
+        ],
+        [
+            [
+                224, 37, 101, 60, 84, 47, 232, 133, 236, 96, 248, 123, 169, 204, 78, 154, 218, 21,
+                239, 169, 240, 215, 212, 108, 53, 184, 198, 134, 43, 7, 0, 34,
+            ],
+            [
+                152, 46, 191, 165, 144, 62, 254, 176, 238, 63, 227, 219, 28, 166, 63, 249, 61, 126,
+                96, 209, 187, 25, 232, 80, 199, 77, 170, 164, 245, 189, 180, 19,
+            ],
+            [
+                247, 227, 139, 97, 177, 142, 169, 207, 31, 216, 243, 157, 107, 141, 136, 107, 248,
+                22, 251, 143, 176, 208, 47, 34, 230, 52, 209, 61, 98, 59, 34, 51,
+            ],
+            [
+                181, 8, 151, 145, 32, 186, 127, 245, 13, 168, 180, 189, 187, 82, 51, 218, 25, 86,
+                197, 11, 44, 214, 112, 41, 134, 237, 238, 229, 159, 94, 254, 40,
+            ],
+            [
+                144, 34, 37, 157, 107, 106, 20, 19, 113, 127, 55, 163, 92, 242, 136, 22, 249, 219,
+                34, 162, 117, 84, 124, 83, 182, 138, 238, 144, 102, 26, 61, 4,
+            ],
+            [
+                80, 240, 177, 26, 59, 7, 232, 248, 178, 192, 224, 69, 36, 115, 153, 240, 172, 110,
+                128, 117, 116, 255, 217, 153, 75, 93, 70, 234, 100, 48, 32, 22,
+            ],
+            [
+                16, 99, 85, 212, 99, 114, 205, 28, 200, 118, 19, 124, 229, 238, 138, 176, 84, 161,
+                136, 94, 153, 218, 0, 217, 201, 21, 31, 56, 56, 193, 100, 43,
+            ],
+            [
+                177, 79, 37, 138, 37, 51, 187, 150, 181, 196, 238, 240, 251, 37, 214, 10, 3, 114,
+                114, 2, 113, 131, 167, 162, 71, 53, 142, 66, 156, 167, 147, 8,
+            ],
+        ],
+        [
+            [
+                223, 150, 243, 250, 79, 107, 131, 155, 42, 64, 36, 145, 211, 24, 216, 117, 54, 57,
+                8, 115, 58, 10, 97, 128, 163, 52, 84, 194, 222, 140, 41, 48,
+            ],
+            [
+                152, 248, 42, 168, 113, 202, 248, 51, 96, 91, 56, 103, 254, 251, 188, 46, 144, 172,
+                4, 157, 158, 30, 255, 209, 85, 67, 100, 183, 126, 34, 98, 6,
+            ],
+            [
+                122, 128, 223, 224, 197, 39, 26, 161, 215, 93, 75, 175, 79, 97, 5, 152, 194, 136,
+                246, 90, 206, 85, 102, 230, 202, 189, 39, 101, 39, 167, 41, 47,
+            ],
+            [
+                61, 120, 4, 161, 171, 147, 169, 21, 253, 199, 253, 29, 197, 10, 161, 250, 78, 251,
+                135, 47, 85, 85, 244, 39, 148, 16, 64, 146, 107, 86, 132, 29,
+            ],
+            [
+                180, 64, 254, 96, 33, 65, 11, 14, 18, 210, 190, 125, 61, 100, 31, 40, 64, 116, 218,
+                163, 255, 120, 72, 239, 153, 195, 234, 81, 140, 203, 167, 55,
+            ],
+            [
+                11, 223, 92, 140, 116, 123, 48, 20, 208, 214, 179, 75, 148, 105, 24, 114, 209, 229,
+                185, 3, 221, 86, 10, 217, 139, 6, 193, 172, 159, 9, 23, 29,
+            ],
+            [
+                25, 245, 126, 171, 61, 68, 231, 191, 83, 221, 169, 21, 137, 121, 146, 246, 28, 101,
+                52, 148, 38, 202, 152, 118, 1, 181, 38, 37, 104, 242, 254, 7,
+            ],
+            [
+                214, 242, 132, 229, 190, 162, 7, 164, 13, 178, 38, 198, 65, 154, 117, 241, 68, 86,
+                234, 109, 116, 251, 87, 26, 105, 110, 3, 79, 70, 165, 199, 49,
+            ],
+        ],
+        [
+            [
+                213, 9, 175, 142, 202, 24, 149, 196, 60, 229, 128, 243, 213, 51, 97, 101, 196, 238,
+                106, 61, 176, 114, 31, 110, 33, 93, 241, 253, 240, 80, 200, 57,
+            ],
+            [
+                160, 216, 235, 12, 121, 130, 78, 193, 149, 35, 90, 115, 91, 116, 149, 68, 83, 177,
+                218, 48, 25, 135, 114, 248, 22, 195, 65, 132, 58, 29, 6, 12,
+            ],
+            [
+                151, 57, 39, 214, 38, 119, 98, 28, 40, 62, 241, 218, 119, 83, 204, 221, 175, 194,
+                93, 187, 103, 144, 202, 19, 11, 130, 118, 22, 182, 78, 41, 62,
+            ],
+            [
+                181, 9, 134, 37, 231, 154, 210, 184, 20, 29, 233, 47, 161, 194, 226, 218, 82, 90,
+                124, 47, 112, 99, 4, 3, 72, 239, 164, 40, 5, 72, 161, 43,
+            ],
+            [
+                73, 37, 88, 3, 135, 88, 98, 167, 171, 151, 51, 162, 228, 16, 94, 127, 60, 172, 166,
+                188, 128, 62, 193, 25, 82, 212, 59, 239, 155, 132, 27, 27,
+            ],
+            [
+                225, 190, 207, 247, 189, 125, 23, 62, 113, 222, 187, 31, 96, 252, 107, 131, 229,
+                64, 26, 205, 86, 27, 44, 239, 239, 109, 147, 5, 112, 223, 99, 55,
+            ],
+            [
+                217, 178, 238, 217, 73, 33, 231, 201, 70, 15, 114, 232, 207, 108, 228, 89, 190,
+                134, 58, 208, 106, 101, 117, 195, 233, 162, 139, 112, 61, 24, 5, 37,
+            ],
+            [
+                197, 0, 225, 190, 146, 93, 67, 198, 232, 34, 30, 158, 18, 128, 65, 180, 72, 68, 76,
+                132, 239, 204, 181, 86, 159, 5, 14, 17, 42, 214, 108, 50,
+            ],
+        ],
+        [
+            [
+                90, 40, 107, 215, 183, 96, 156, 223, 40, 228, 27, 224, 180, 237, 57, 145, 102, 57,
+                45, 119, 180, 103, 245, 246, 28, 220, 255, 62, 243, 225, 125, 7,
+            ],
+            [
+                176, 201, 46, 237, 48, 139, 146, 16, 4, 161, 95, 109, 125, 165, 219, 70, 247, 219,
+                144, 72, 244, 237, 16, 172, 1, 154, 193, 186, 240, 40, 169, 15,
+            ],
+            [
+                106, 93, 216, 59, 92, 74, 238, 226, 165, 149, 89, 136, 245, 122, 53, 139, 29, 179,
+                215, 171, 127, 168, 148, 31, 40, 138, 119, 250, 147, 27, 154, 49,
+            ],
+            [
+                134, 93, 98, 254, 178, 49, 37, 201, 98, 63, 131, 123, 227, 107, 40, 154, 216, 155,
+                133, 23, 197, 164, 109, 228, 176, 195, 180, 239, 156, 165, 62, 3,
+            ],
+            [
+                100, 32, 96, 110, 152, 8, 242, 70, 238, 180, 226, 163, 65, 226, 88, 172, 38, 220,
+                192, 219, 113, 201, 167, 154, 64, 175, 224, 245, 36, 167, 230, 53,
+            ],
+            [
+                55, 179, 223, 132, 84, 241, 218, 30, 165, 143, 247, 152, 97, 148, 50, 166, 68, 19,
+                203, 157, 123, 184, 27, 174, 245, 124, 248, 127, 115, 126, 134, 44,
+            ],
+            [
+                212, 203, 186, 21, 51, 17, 196, 255, 219, 176, 190, 124, 160, 149, 251, 111, 191,
+                137, 86, 72, 84, 38, 191, 181, 21, 40, 205, 78, 202, 90, 39, 53,
+            ],
+            [
+                157, 135, 234, 19, 99, 159, 195, 18, 244, 161, 223, 113, 213, 142, 40, 101, 230,
+                214, 27, 105, 12, 124, 62, 245, 163, 191, 211, 59, 39, 204, 155, 19,
+            ],
+        ],
+        [
+            [
+                219, 121, 188, 230, 73, 195, 229, 63, 151, 208, 216, 215, 224, 161, 207, 92, 84,
+                22, 250, 134, 147, 53, 52, 191, 28, 35, 142, 96, 43, 115, 40, 56,
+            ],
+            [
+                165, 180, 153, 127, 125, 79, 137, 158, 249, 65, 237, 47, 30, 51, 219, 188, 199,
+                146, 135, 236, 45, 50, 37, 29, 166, 112, 169, 203, 140, 36, 242, 61,
+            ],
+            [
+                121, 53, 187, 135, 135, 77, 176, 125, 247, 6, 137, 118, 58, 207, 174, 150, 143,
+                185, 111, 54, 21, 247, 104, 250, 80, 73, 104, 153, 168, 168, 65, 34,
+            ],
+            [
+                198, 99, 75, 161, 125, 28, 24, 22, 216, 8, 134, 109, 49, 10, 134, 129, 149, 13, 32,
+                204, 254, 36, 181, 187, 68, 38, 190, 40, 27, 16, 39, 10,
+            ],
+            [
+                72, 63, 20, 139, 199, 121, 198, 51, 245, 217, 27, 19, 67, 21, 219, 64, 75, 64, 221,
+                18, 104, 85, 156, 61, 29, 234, 103, 235, 219, 36, 175, 23,
+            ],
+            [
+                134, 94, 5, 45, 90, 8, 99, 19, 100, 144, 118, 250, 218, 240, 64, 132, 124, 114,
+                131, 201, 217, 136, 110, 137, 94, 123, 73, 172, 92, 95, 121, 14,
+            ],
+            [
+                169, 51, 227, 33, 200, 116, 147, 112, 61, 28, 132, 251, 221, 16, 15, 237, 196, 184,
+                85, 235, 85, 142, 174, 53, 39, 87, 221, 68, 22, 203, 150, 33,
+            ],
+            [
+                35, 200, 38, 237, 75, 144, 39, 10, 229, 33, 87, 215, 135, 253, 82, 184, 214, 227,
+                238, 247, 42, 173, 47, 81, 200, 229, 6, 107, 229, 153, 149, 21,
+            ],
+        ],
+        [
+            [
+                127, 213, 177, 71, 11, 132, 15, 255, 72, 249, 176, 137, 104, 77, 178, 5, 178, 123,
+                78, 41, 76, 21, 143, 104, 140, 94, 196, 248, 187, 138, 35, 33,
+            ],
+            [
+                10, 37, 197, 125, 149, 147, 141, 95, 31, 138, 122, 162, 78, 84, 177, 170, 82, 44,
+                80, 220, 235, 23, 112, 35, 68, 165, 51, 141, 109, 139, 150, 33,
+            ],
+            [
+                69, 132, 140, 143, 142, 3, 92, 82, 8, 188, 245, 99, 67, 8, 17, 198, 172, 163, 199,
+                1, 247, 87, 223, 62, 122, 20, 50, 160, 74, 232, 64, 5,
+            ],
+            [
+                17, 99, 0, 21, 220, 137, 143, 202, 176, 168, 82, 241, 5, 242, 147, 88, 159, 198,
+                28, 114, 130, 70, 62, 214, 110, 108, 105, 36, 53, 243, 2, 12,
+            ],
+            [
+                134, 184, 81, 95, 215, 47, 214, 240, 36, 180, 0, 1, 223, 199, 88, 170, 74, 230, 89,
+                201, 111, 199, 135, 204, 109, 122, 220, 114, 250, 116, 207, 45,
+            ],
+            [
+                83, 72, 68, 1, 15, 228, 220, 244, 10, 185, 250, 254, 233, 124, 44, 9, 207, 36, 146,
+                89, 176, 95, 220, 48, 240, 134, 82, 39, 193, 78, 24, 26,
+            ],
+            [
+                240, 11, 143, 76, 45, 200, 129, 170, 84, 81, 112, 35, 62, 75, 95, 86, 7, 236, 102,
+                154, 171, 19, 100, 232, 91, 8, 223, 128, 56, 191, 148, 20,
+            ],
+            [
+                181, 204, 239, 120, 246, 18, 204, 61, 123, 79, 218, 76, 97, 51, 35, 122, 224, 39,
+                217, 74, 31, 210, 40, 72, 205, 84, 92, 88, 232, 76, 121, 14,
+            ],
+        ],
+        [
+            [
+                150, 78, 27, 148, 238, 179, 64, 246, 220, 227, 168, 209, 34, 239, 154, 98, 71, 86,
+                114, 172, 17, 203, 145, 49, 193, 207, 38, 192, 217, 142, 112, 43,
+            ],
+            [
+                73, 97, 37, 64, 233, 89, 35, 155, 9, 234, 77, 124, 66, 7, 110, 77, 130, 199, 185,
+                167, 133, 8, 219, 35, 66, 29, 89, 49, 173, 38, 180, 35,
+            ],
+            [
+                236, 135, 223, 146, 198, 17, 46, 229, 125, 16, 22, 43, 120, 223, 212, 185, 105,
+                237, 169, 240, 217, 195, 183, 198, 99, 225, 80, 76, 126, 223, 60, 18,
+            ],
+            [
+                142, 243, 95, 70, 172, 60, 206, 26, 231, 193, 31, 163, 62, 60, 65, 149, 222, 152,
+                175, 20, 202, 231, 112, 202, 9, 173, 31, 103, 125, 241, 164, 45,
+            ],
+            [
+                242, 131, 217, 167, 172, 116, 215, 236, 11, 254, 2, 18, 60, 69, 214, 55, 255, 105,
+                34, 201, 144, 143, 79, 183, 159, 205, 212, 216, 90, 149, 10, 41,
+            ],
+            [
+                97, 29, 232, 135, 124, 4, 55, 158, 62, 224, 143, 172, 98, 80, 154, 104, 230, 197,
+                18, 182, 219, 75, 154, 97, 76, 43, 46, 90, 127, 29, 47, 61,
+            ],
+            [
+                162, 52, 213, 22, 214, 122, 235, 116, 119, 58, 3, 7, 255, 182, 56, 122, 202, 34,
+                50, 19, 219, 122, 246, 234, 87, 108, 200, 145, 236, 65, 94, 56,
+            ],
+            [
+                135, 182, 109, 76, 149, 197, 115, 248, 86, 164, 233, 72, 30, 197, 171, 133, 240,
+                107, 177, 139, 8, 97, 141, 132, 220, 32, 89, 210, 98, 100, 73, 52,
+            ],
+        ],
+        [
+            [
+                58, 213, 214, 97, 52, 12, 222, 134, 201, 214, 238, 29, 55, 198, 165, 234, 213, 255,
+                237, 197, 23, 235, 109, 65, 92, 69, 116, 176, 228, 168, 250, 21,
+            ],
+            [
+                88, 80, 13, 217, 139, 183, 177, 224, 36, 12, 19, 147, 80, 148, 31, 47, 230, 24,
+                185, 195, 249, 163, 176, 46, 12, 231, 158, 53, 53, 214, 97, 46,
+            ],
+            [
+                109, 38, 128, 5, 47, 126, 100, 59, 157, 75, 218, 142, 15, 151, 115, 121, 236, 68,
+                130, 174, 139, 253, 21, 157, 100, 67, 106, 81, 56, 47, 129, 52,
+            ],
+            [
+                75, 1, 28, 133, 169, 243, 135, 213, 251, 76, 115, 113, 217, 68, 199, 231, 140, 33,
+                101, 16, 46, 241, 206, 194, 233, 213, 131, 126, 31, 41, 120, 57,
+            ],
+            [
+                36, 40, 62, 9, 240, 233, 94, 137, 137, 175, 239, 244, 212, 77, 238, 25, 121, 45,
+                171, 154, 92, 62, 13, 48, 160, 123, 107, 180, 34, 5, 180, 25,
+            ],
+            [
+                244, 132, 71, 221, 128, 164, 70, 216, 197, 106, 48, 200, 168, 75, 231, 199, 62,
+                148, 65, 24, 115, 192, 175, 41, 143, 189, 70, 81, 166, 52, 197, 23,
+            ],
+            [
+                253, 240, 234, 13, 177, 108, 213, 197, 33, 12, 176, 231, 226, 81, 38, 139, 223,
+                121, 22, 70, 190, 212, 207, 145, 60, 44, 24, 154, 231, 108, 118, 14,
+            ],
+            [
+                13, 84, 11, 118, 135, 198, 67, 146, 88, 60, 63, 181, 43, 64, 219, 183, 159, 181,
+                21, 126, 32, 136, 228, 244, 5, 75, 119, 72, 12, 101, 22, 25,
+            ],
+        ],
+        [
+            [
+                206, 63, 134, 168, 95, 30, 150, 10, 243, 47, 216, 172, 92, 170, 232, 125, 238, 65,
+                17, 128, 137, 87, 31, 170, 168, 189, 173, 252, 101, 23, 112, 48,
+            ],
+            [
+                95, 21, 145, 179, 110, 162, 176, 35, 120, 136, 73, 174, 135, 44, 47, 15, 186, 7,
+                131, 211, 4, 176, 223, 113, 199, 44, 187, 197, 226, 230, 72, 29,
+            ],
+            [
+                30, 97, 46, 13, 5, 175, 67, 27, 49, 50, 72, 7, 122, 155, 7, 236, 196, 41, 172, 158,
+                197, 165, 8, 143, 34, 88, 92, 21, 12, 223, 180, 4,
+            ],
+            [
+                15, 134, 184, 23, 225, 249, 251, 172, 127, 53, 32, 169, 153, 119, 65, 24, 63, 228,
+                162, 168, 100, 96, 19, 231, 148, 68, 200, 109, 119, 190, 105, 41,
+            ],
+            [
+                28, 207, 179, 186, 104, 149, 198, 197, 152, 241, 227, 245, 92, 189, 171, 42, 146,
+                116, 166, 143, 68, 63, 222, 144, 120, 98, 247, 74, 144, 213, 157, 49,
+            ],
+            [
+                158, 26, 149, 240, 216, 219, 42, 220, 234, 90, 98, 96, 205, 226, 92, 95, 55, 49,
+                209, 233, 251, 114, 54, 158, 77, 203, 82, 27, 237, 85, 5, 35,
+            ],
+            [
+                128, 188, 103, 88, 189, 21, 113, 249, 49, 44, 154, 44, 180, 227, 24, 78, 42, 147,
+                200, 58, 154, 254, 8, 237, 92, 113, 165, 116, 119, 61, 9, 36,
+            ],
+            [
+                242, 16, 89, 124, 22, 164, 42, 189, 36, 166, 130, 143, 90, 98, 132, 169, 63, 190,
+                182, 221, 120, 252, 33, 215, 151, 21, 219, 16, 54, 151, 203, 15,
+            ],
+        ],
+        [
+            [
+                110, 13, 181, 102, 177, 225, 100, 80, 170, 145, 136, 61, 227, 91, 54, 219, 18, 216,
+                210, 25, 2, 231, 254, 255, 99, 242, 4, 13, 169, 243, 178, 34,
+            ],
+            [
+                203, 155, 149, 165, 12, 148, 240, 89, 177, 88, 39, 84, 200, 156, 221, 67, 136, 35,
+                206, 228, 132, 186, 172, 114, 39, 247, 209, 186, 201, 187, 223, 46,
+            ],
+            [
+                154, 209, 225, 126, 228, 138, 103, 1, 111, 248, 181, 95, 143, 168, 76, 254, 52, 84,
+                132, 121, 3, 162, 184, 241, 116, 252, 254, 112, 1, 72, 118, 10,
+            ],
+            [
+                196, 172, 42, 66, 237, 209, 28, 61, 15, 205, 122, 73, 94, 38, 229, 4, 254, 181,
+                127, 6, 1, 57, 241, 41, 145, 110, 27, 23, 245, 88, 67, 16,
+            ],
+            [
+                192, 33, 172, 68, 116, 214, 242, 156, 232, 62, 232, 165, 48, 150, 117, 101, 53,
+                178, 71, 139, 129, 251, 235, 157, 218, 254, 158, 49, 137, 177, 229, 22,
+            ],
+            [
+                167, 201, 47, 152, 136, 238, 179, 54, 13, 34, 242, 166, 218, 237, 26, 135, 210,
+                112, 79, 78, 192, 80, 37, 88, 142, 199, 24, 218, 224, 39, 37, 16,
+            ],
+            [
+                188, 155, 105, 116, 21, 186, 159, 205, 55, 61, 35, 212, 34, 180, 3, 44, 57, 58, 45,
+                218, 151, 14, 165, 188, 129, 118, 160, 140, 12, 85, 112, 43,
+            ],
+            [
+                207, 164, 191, 60, 172, 103, 134, 69, 244, 22, 42, 115, 95, 237, 181, 127, 39, 145,
+                120, 189, 251, 76, 13, 173, 90, 177, 138, 63, 46, 49, 93, 10,
+            ],
+        ],
+        [
+            [
+                135, 119, 223, 171, 55, 126, 82, 180, 67, 62, 176, 89, 178, 156, 162, 233, 58, 16,
+                232, 219, 10, 102, 16, 143, 61, 53, 157, 167, 127, 128, 2, 20,
+            ],
+            [
+                21, 189, 167, 68, 245, 127, 113, 116, 233, 174, 186, 32, 135, 74, 1, 245, 66, 3,
+                93, 21, 101, 103, 215, 186, 252, 207, 86, 103, 207, 253, 63, 53,
+            ],
+            [
+                189, 52, 54, 100, 54, 36, 147, 43, 186, 77, 70, 26, 245, 208, 18, 130, 100, 152, 6,
+                180, 83, 174, 131, 113, 238, 45, 93, 231, 119, 3, 149, 63,
+            ],
+            [
+                57, 166, 254, 33, 151, 118, 162, 80, 251, 248, 79, 81, 34, 184, 87, 224, 186, 185,
+                63, 106, 68, 191, 129, 217, 132, 202, 115, 141, 106, 111, 150, 23,
+            ],
+            [
+                147, 188, 183, 199, 105, 193, 186, 168, 137, 150, 107, 11, 217, 155, 139, 159, 102,
+                143, 235, 62, 190, 2, 205, 10, 50, 211, 199, 158, 64, 254, 202, 39,
+            ],
+            [
+                139, 11, 1, 229, 230, 90, 17, 67, 156, 245, 203, 146, 48, 174, 123, 163, 202, 190,
+                218, 105, 85, 205, 203, 38, 209, 182, 154, 83, 163, 63, 152, 50,
+            ],
+            [
+                134, 209, 25, 222, 35, 115, 42, 169, 155, 158, 119, 9, 224, 231, 46, 176, 6, 208,
+                197, 254, 1, 1, 3, 44, 104, 54, 123, 148, 134, 70, 255, 45,
+            ],
+            [
+                25, 67, 238, 30, 25, 146, 165, 39, 211, 220, 246, 227, 92, 68, 241, 176, 142, 106,
+                216, 40, 230, 38, 247, 108, 30, 185, 107, 60, 107, 128, 203, 13,
+            ],
+        ],
+        [
+            [
+                231, 126, 62, 186, 186, 234, 174, 124, 63, 119, 203, 20, 219, 90, 162, 156, 230,
+                201, 114, 93, 102, 236, 147, 100, 55, 205, 103, 52, 231, 154, 81, 4,
+            ],
+            [
+                140, 164, 179, 248, 180, 45, 77, 82, 255, 199, 48, 38, 152, 194, 50, 103, 137, 170,
+                29, 120, 174, 206, 221, 161, 11, 56, 110, 159, 76, 139, 188, 5,
+            ],
+            [
+                114, 188, 35, 16, 128, 187, 33, 148, 194, 59, 143, 207, 127, 111, 143, 7, 207, 115,
+                98, 239, 248, 244, 154, 232, 189, 43, 90, 120, 236, 109, 82, 63,
+            ],
+            [
+                56, 214, 43, 35, 173, 42, 172, 219, 159, 126, 2, 118, 194, 142, 78, 114, 228, 140,
+                35, 48, 235, 59, 148, 222, 172, 193, 70, 48, 241, 45, 152, 33,
+            ],
+            [
+                69, 241, 119, 19, 226, 251, 35, 211, 139, 103, 18, 33, 164, 132, 17, 234, 218, 12,
+                21, 11, 113, 242, 229, 208, 110, 156, 36, 62, 105, 57, 243, 21,
+            ],
+            [
+                128, 232, 18, 160, 55, 181, 26, 198, 229, 71, 191, 69, 25, 169, 229, 82, 176, 6, 7,
+                201, 175, 166, 54, 234, 209, 108, 54, 233, 89, 186, 205, 52,
+            ],
+            [
+                255, 173, 105, 145, 144, 173, 136, 157, 95, 73, 25, 15, 24, 224, 76, 126, 71, 102,
+                119, 140, 43, 72, 218, 88, 3, 142, 28, 197, 4, 39, 153, 52,
+            ],
+            [
+                110, 148, 227, 176, 201, 24, 107, 72, 87, 36, 193, 3, 107, 165, 188, 42, 136, 202,
+                233, 83, 179, 98, 252, 150, 86, 237, 12, 220, 28, 142, 178, 52,
+            ],
+        ],
+        [
+            [
+                118, 59, 247, 213, 5, 249, 208, 132, 81, 128, 106, 211, 89, 180, 57, 19, 236, 163,
+                242, 237, 67, 183, 215, 69, 11, 44, 149, 227, 36, 170, 67, 8,
+            ],
+            [
+                186, 88, 112, 146, 197, 177, 5, 178, 192, 71, 19, 118, 200, 180, 87, 5, 109, 254,
+                153, 132, 61, 5, 111, 132, 98, 11, 83, 80, 254, 29, 34, 41,
+            ],
+            [
+                48, 245, 254, 117, 93, 83, 160, 24, 176, 249, 138, 153, 100, 245, 103, 37, 139, 65,
+                112, 180, 88, 99, 131, 47, 47, 141, 138, 104, 244, 117, 166, 47,
+            ],
+            [
+                122, 182, 66, 64, 91, 83, 235, 28, 61, 242, 19, 68, 247, 158, 138, 74, 210, 213, 7,
+                6, 161, 231, 37, 118, 152, 60, 58, 177, 14, 105, 165, 11,
+            ],
+            [
+                69, 51, 210, 138, 166, 252, 158, 51, 181, 95, 64, 224, 8, 76, 53, 115, 16, 72, 69,
+                71, 102, 21, 238, 83, 243, 85, 11, 195, 133, 92, 230, 53,
+            ],
+            [
+                199, 21, 41, 112, 128, 167, 33, 26, 101, 196, 134, 192, 84, 41, 165, 29, 118, 28,
+                221, 32, 189, 207, 222, 186, 80, 29, 71, 72, 40, 20, 23, 47,
+            ],
+            [
+                174, 113, 3, 129, 214, 99, 249, 56, 172, 24, 200, 42, 195, 254, 144, 30, 216, 62,
+                217, 73, 182, 10, 43, 226, 168, 68, 138, 4, 228, 148, 11, 2,
+            ],
+            [
+                205, 96, 213, 113, 60, 69, 153, 197, 254, 99, 91, 11, 117, 86, 230, 1, 49, 189, 99,
+                204, 56, 63, 207, 187, 168, 93, 130, 122, 136, 228, 110, 49,
+            ],
+        ],
+        [
+            [
+                93, 112, 73, 33, 85, 52, 120, 157, 128, 45, 3, 253, 192, 93, 110, 230, 169, 248, 3,
+                222, 251, 238, 3, 25, 180, 201, 86, 196, 101, 220, 76, 35,
+            ],
+            [
+                125, 10, 189, 123, 21, 242, 54, 241, 181, 195, 223, 19, 24, 129, 156, 60, 223, 24,
+                252, 105, 184, 180, 189, 65, 240, 89, 25, 26, 203, 97, 246, 15,
+            ],
+            [
+                115, 83, 166, 31, 154, 143, 37, 179, 120, 151, 233, 54, 94, 86, 172, 71, 84, 148,
+                178, 143, 4, 154, 190, 96, 157, 188, 126, 81, 136, 255, 162, 17,
+            ],
+            [
+                56, 59, 68, 26, 225, 94, 132, 11, 211, 47, 43, 44, 201, 96, 169, 170, 3, 134, 111,
+                8, 43, 247, 50, 186, 80, 102, 240, 174, 181, 158, 150, 60,
+            ],
+            [
+                49, 254, 253, 28, 247, 98, 70, 26, 195, 73, 97, 56, 77, 194, 77, 177, 122, 3, 226,
+                6, 237, 194, 204, 198, 74, 28, 95, 175, 133, 0, 178, 60,
+            ],
+            [
+                164, 239, 239, 238, 70, 20, 114, 121, 129, 209, 179, 249, 93, 88, 33, 84, 84, 125,
+                237, 21, 197, 147, 243, 57, 180, 58, 231, 115, 33, 178, 217, 23,
+            ],
+            [
+                168, 81, 181, 126, 129, 210, 38, 5, 26, 16, 229, 31, 15, 124, 118, 254, 197, 121,
+                128, 22, 0, 2, 245, 218, 70, 19, 59, 252, 235, 149, 202, 12,
+            ],
+            [
+                95, 148, 105, 161, 117, 63, 6, 255, 26, 185, 16, 56, 192, 206, 123, 87, 18, 207,
+                27, 207, 100, 36, 235, 51, 142, 150, 5, 179, 213, 218, 8, 55,
+            ],
+        ],
+        [
+            [
+                171, 53, 183, 79, 203, 45, 159, 28, 239, 122, 172, 250, 226, 56, 3, 63, 159, 250,
+                142, 112, 171, 60, 212, 115, 39, 30, 128, 116, 135, 46, 230, 28,
+            ],
+            [
+                187, 14, 67, 7, 97, 56, 236, 52, 201, 212, 131, 102, 93, 193, 89, 159, 48, 202,
+                166, 132, 115, 206, 39, 245, 255, 51, 170, 113, 138, 141, 227, 54,
+            ],
+            [
+                27, 153, 105, 67, 151, 239, 175, 32, 190, 25, 219, 49, 63, 53, 231, 203, 122, 161,
+                243, 44, 14, 245, 166, 72, 109, 21, 147, 30, 204, 64, 52, 20,
+            ],
+            [
+                61, 150, 155, 233, 6, 250, 177, 161, 159, 136, 206, 225, 149, 184, 101, 70, 47,
+                160, 46, 185, 161, 55, 236, 94, 212, 65, 4, 118, 32, 253, 108, 63,
+            ],
+            [
+                127, 111, 181, 62, 118, 203, 131, 172, 103, 234, 236, 99, 59, 232, 93, 201, 191,
+                64, 198, 14, 156, 36, 165, 182, 220, 33, 135, 196, 120, 17, 230, 60,
+            ],
+            [
+                158, 241, 212, 1, 214, 169, 243, 113, 213, 39, 181, 190, 43, 162, 224, 229, 87,
+                163, 112, 94, 126, 78, 94, 77, 243, 15, 140, 67, 236, 231, 67, 57,
+            ],
+            [
+                9, 105, 35, 161, 239, 41, 158, 139, 45, 223, 87, 25, 151, 116, 182, 184, 89, 207,
+                119, 32, 186, 203, 131, 154, 20, 12, 75, 143, 66, 193, 144, 56,
+            ],
+            [
+                89, 95, 149, 23, 120, 213, 50, 59, 178, 8, 83, 150, 221, 183, 243, 10, 166, 112,
+                231, 223, 140, 41, 218, 36, 176, 230, 136, 211, 160, 34, 179, 24,
+            ],
+        ],
+        [
+            [
+                11, 101, 238, 38, 145, 70, 138, 51, 54, 179, 154, 87, 59, 204, 248, 215, 30, 206,
+                91, 183, 122, 243, 224, 217, 184, 217, 155, 148, 55, 65, 209, 21,
+            ],
+            [
+                94, 191, 90, 7, 155, 107, 212, 123, 120, 24, 207, 226, 130, 176, 225, 157, 192, 73,
+                190, 121, 11, 92, 69, 48, 183, 156, 155, 123, 70, 26, 0, 50,
+            ],
+            [
+                34, 178, 57, 155, 48, 53, 228, 53, 148, 49, 160, 30, 46, 106, 209, 77, 102, 124,
+                226, 62, 188, 78, 231, 88, 133, 32, 138, 228, 2, 158, 49, 62,
+            ],
+            [
+                0, 223, 100, 144, 57, 105, 186, 228, 158, 147, 59, 91, 206, 113, 228, 101, 45, 224,
+                153, 249, 183, 242, 133, 19, 176, 165, 42, 56, 190, 9, 101, 17,
+            ],
+            [
+                81, 238, 53, 177, 13, 158, 58, 220, 169, 41, 93, 220, 51, 162, 62, 149, 59, 106,
+                161, 3, 104, 50, 133, 248, 54, 226, 217, 61, 217, 238, 189, 20,
+            ],
+            [
+                46, 60, 156, 243, 1, 227, 155, 139, 240, 67, 140, 160, 66, 241, 21, 30, 12, 238,
+                242, 191, 202, 169, 179, 111, 160, 117, 187, 85, 6, 251, 163, 8,
+            ],
+            [
+                51, 222, 74, 84, 97, 190, 143, 217, 156, 57, 208, 180, 210, 130, 32, 52, 74, 153,
+                175, 60, 83, 86, 214, 255, 217, 38, 216, 61, 140, 21, 110, 25,
+            ],
+            [
+                204, 15, 54, 76, 177, 197, 164, 248, 124, 179, 84, 211, 3, 164, 28, 143, 91, 202,
+                243, 29, 214, 40, 102, 162, 181, 53, 145, 77, 155, 196, 152, 12,
+            ],
+        ],
+        [
+            [
+                165, 82, 183, 97, 173, 23, 86, 142, 35, 26, 128, 68, 163, 216, 89, 53, 21, 87, 146,
+                219, 114, 29, 99, 2, 74, 86, 105, 189, 70, 210, 17, 46,
+            ],
+            [
+                217, 135, 60, 103, 137, 119, 85, 15, 238, 35, 64, 119, 122, 66, 224, 175, 30, 165,
+                255, 195, 97, 128, 195, 123, 76, 141, 135, 174, 142, 193, 223, 42,
+            ],
+            [
+                133, 182, 217, 247, 193, 22, 163, 131, 131, 218, 253, 116, 66, 10, 136, 254, 23,
+                234, 197, 213, 123, 11, 214, 86, 96, 213, 93, 13, 7, 87, 70, 27,
+            ],
+            [
+                7, 247, 252, 93, 13, 30, 20, 60, 122, 177, 164, 131, 253, 10, 72, 24, 170, 214,
+                151, 20, 142, 79, 126, 133, 167, 132, 30, 57, 206, 148, 250, 5,
+            ],
+            [
+                74, 182, 208, 48, 78, 34, 170, 100, 5, 14, 90, 114, 157, 0, 67, 248, 176, 247, 209,
+                72, 170, 189, 142, 96, 142, 218, 69, 83, 140, 254, 145, 6,
+            ],
+            [
+                181, 176, 231, 218, 13, 169, 25, 67, 51, 92, 89, 232, 96, 103, 64, 130, 2, 236,
+                176, 157, 212, 226, 102, 78, 214, 156, 21, 210, 254, 53, 58, 21,
+            ],
+            [
+                207, 83, 82, 26, 186, 227, 219, 141, 123, 220, 172, 128, 140, 87, 244, 195, 160,
+                152, 210, 217, 7, 198, 112, 201, 229, 152, 52, 229, 30, 245, 146, 0,
+            ],
+            [
+                91, 8, 207, 32, 4, 77, 175, 223, 169, 81, 157, 142, 199, 48, 135, 90, 23, 52, 33,
+                167, 34, 218, 119, 155, 155, 84, 64, 69, 155, 224, 219, 39,
+            ],
+        ],
+        [
+            [
+                215, 178, 216, 115, 190, 193, 154, 154, 168, 193, 207, 22, 77, 83, 161, 49, 245,
+                81, 74, 107, 233, 31, 80, 245, 9, 5, 177, 99, 182, 152, 81, 7,
+            ],
+            [
+                68, 2, 150, 246, 47, 78, 17, 162, 173, 209, 225, 169, 234, 91, 133, 165, 123, 66,
+                60, 185, 221, 141, 79, 136, 54, 91, 4, 178, 85, 52, 82, 8,
+            ],
+            [
+                205, 222, 36, 141, 113, 187, 97, 22, 110, 163, 241, 206, 108, 112, 145, 35, 172,
+                103, 18, 234, 63, 99, 46, 147, 36, 248, 247, 232, 11, 192, 113, 55,
+            ],
+            [
+                0, 36, 96, 112, 98, 57, 50, 89, 116, 111, 71, 31, 83, 33, 156, 38, 183, 144, 78,
+                142, 231, 38, 155, 44, 216, 58, 245, 162, 196, 99, 143, 3,
+            ],
+            [
+                169, 163, 179, 48, 12, 240, 46, 183, 214, 78, 25, 224, 206, 18, 157, 205, 148, 119,
+                114, 233, 3, 41, 236, 36, 24, 127, 140, 143, 179, 95, 3, 59,
+            ],
+            [
+                210, 241, 102, 196, 44, 248, 254, 185, 46, 74, 49, 122, 12, 81, 65, 230, 205, 47,
+                124, 197, 106, 215, 90, 9, 133, 55, 163, 127, 57, 227, 80, 41,
+            ],
+            [
+                195, 136, 154, 110, 37, 69, 250, 6, 203, 78, 75, 155, 226, 81, 33, 156, 245, 230,
+                172, 126, 165, 23, 20, 154, 211, 247, 194, 17, 141, 253, 115, 58,
+            ],
+            [
+                129, 200, 164, 144, 186, 157, 52, 197, 12, 108, 241, 80, 122, 153, 135, 11, 91, 98,
+                160, 226, 125, 29, 173, 223, 53, 250, 144, 171, 84, 197, 95, 22,
+            ],
+        ],
+        [
+            [
+                125, 149, 61, 163, 54, 170, 12, 6, 147, 111, 149, 164, 14, 48, 214, 89, 72, 142,
+                181, 6, 193, 159, 150, 187, 56, 51, 185, 99, 56, 190, 44, 1,
+            ],
+            [
+                121, 219, 98, 227, 49, 54, 25, 47, 98, 114, 225, 79, 16, 191, 118, 148, 74, 2, 68,
+                178, 95, 133, 100, 31, 17, 231, 72, 187, 81, 227, 41, 42,
+            ],
+            [
+                216, 98, 31, 61, 235, 242, 175, 21, 67, 129, 231, 40, 253, 136, 199, 231, 77, 71,
+                129, 187, 166, 151, 185, 233, 202, 125, 29, 26, 155, 187, 167, 63,
+            ],
+            [
+                114, 101, 57, 8, 117, 25, 12, 187, 163, 36, 168, 84, 37, 239, 238, 3, 198, 123,
+                165, 73, 221, 137, 254, 62, 120, 170, 151, 82, 144, 161, 54, 42,
+            ],
+            [
+                56, 178, 87, 42, 232, 253, 86, 245, 244, 88, 5, 196, 101, 32, 21, 120, 92, 133,
+                128, 178, 43, 6, 24, 114, 85, 46, 138, 133, 183, 86, 100, 17,
+            ],
+            [
+                22, 148, 207, 52, 163, 244, 111, 211, 27, 196, 229, 69, 83, 37, 7, 54, 242, 139,
+                251, 240, 152, 35, 235, 237, 217, 218, 147, 38, 184, 42, 57, 13,
+            ],
+            [
+                228, 22, 236, 248, 124, 183, 161, 172, 76, 125, 88, 15, 232, 158, 17, 219, 138,
+                180, 195, 46, 91, 52, 51, 55, 32, 1, 234, 100, 30, 204, 123, 26,
+            ],
+            [
+                121, 166, 233, 7, 92, 224, 114, 119, 47, 250, 196, 245, 244, 7, 53, 150, 222, 109,
+                244, 87, 242, 82, 168, 7, 166, 224, 20, 72, 120, 29, 156, 33,
+            ],
+        ],
+        [
+            [
+                244, 190, 6, 89, 160, 22, 158, 81, 11, 72, 226, 228, 214, 139, 131, 155, 38, 91,
+                26, 67, 204, 92, 115, 49, 118, 209, 201, 37, 44, 73, 30, 10,
+            ],
+            [
+                77, 162, 67, 0, 133, 40, 225, 1, 152, 96, 77, 210, 149, 137, 42, 238, 236, 95, 46,
+                177, 213, 61, 112, 191, 113, 178, 138, 100, 53, 9, 184, 33,
+            ],
+            [
+                201, 97, 201, 36, 174, 37, 99, 212, 35, 36, 8, 142, 247, 82, 166, 181, 131, 159,
+                135, 219, 110, 184, 126, 48, 188, 18, 151, 211, 191, 20, 106, 9,
+            ],
+            [
+                3, 157, 78, 21, 206, 5, 49, 93, 1, 101, 212, 18, 152, 0, 95, 171, 216, 171, 64,
+                193, 188, 26, 0, 97, 41, 109, 33, 7, 203, 248, 237, 50,
+            ],
+            [
+                216, 118, 54, 113, 19, 109, 83, 154, 177, 207, 98, 33, 84, 85, 182, 252, 83, 151,
+                24, 2, 69, 17, 24, 80, 99, 118, 50, 132, 206, 224, 8, 26,
+            ],
+            [
+                65, 204, 242, 188, 116, 85, 157, 226, 15, 128, 148, 162, 157, 71, 240, 34, 126,
+                118, 170, 26, 57, 236, 115, 70, 178, 144, 173, 209, 80, 125, 114, 3,
+            ],
+            [
+                158, 49, 156, 160, 242, 80, 237, 5, 160, 197, 196, 216, 45, 137, 253, 179, 61, 73,
+                134, 116, 226, 26, 212, 118, 141, 72, 100, 39, 182, 221, 219, 37,
+            ],
+            [
+                187, 73, 5, 75, 142, 22, 19, 225, 241, 157, 11, 182, 11, 87, 217, 194, 171, 224,
+                222, 31, 190, 111, 99, 44, 129, 140, 144, 76, 162, 105, 191, 28,
+            ],
+        ],
+        [
+            [
+                253, 69, 87, 62, 66, 47, 21, 7, 156, 243, 219, 183, 152, 142, 144, 225, 183, 140,
+                233, 179, 87, 193, 224, 37, 37, 207, 155, 187, 201, 41, 63, 53,
+            ],
+            [
+                20, 214, 85, 43, 153, 10, 215, 27, 88, 233, 105, 173, 73, 24, 235, 77, 105, 12, 64,
+                24, 109, 197, 108, 29, 53, 39, 55, 108, 145, 213, 70, 20,
+            ],
+            [
+                248, 3, 39, 5, 227, 76, 231, 47, 128, 232, 53, 24, 35, 148, 57, 234, 15, 121, 42,
+                159, 23, 84, 43, 35, 124, 158, 39, 113, 137, 199, 159, 42,
+            ],
+            [
+                212, 81, 40, 141, 62, 188, 31, 133, 241, 79, 219, 119, 6, 4, 18, 212, 139, 245,
+                210, 143, 235, 157, 88, 232, 118, 247, 148, 186, 180, 210, 244, 40,
+            ],
+            [
+                175, 67, 251, 65, 188, 54, 136, 97, 222, 226, 14, 239, 92, 54, 224, 116, 211, 60,
+                114, 188, 181, 202, 190, 199, 203, 156, 117, 147, 1, 153, 179, 59,
+            ],
+            [
+                250, 115, 243, 186, 118, 16, 52, 143, 24, 173, 15, 54, 2, 71, 149, 25, 72, 8, 76,
+                167, 243, 49, 136, 54, 67, 180, 144, 0, 167, 75, 190, 34,
+            ],
+            [
+                218, 94, 57, 250, 139, 69, 91, 31, 222, 114, 59, 254, 250, 20, 111, 186, 45, 202,
+                16, 61, 20, 75, 139, 45, 20, 96, 158, 139, 156, 94, 223, 15,
+            ],
+            [
+                232, 24, 92, 176, 172, 123, 198, 29, 4, 172, 65, 233, 241, 179, 252, 226, 170, 151,
+                161, 54, 71, 201, 21, 41, 178, 142, 20, 147, 68, 132, 246, 19,
+            ],
+        ],
+        [
+            [
+                145, 107, 181, 66, 87, 185, 252, 244, 137, 25, 177, 11, 17, 37, 175, 182, 191, 103,
+                129, 177, 231, 129, 43, 146, 40, 205, 103, 69, 113, 121, 143, 56,
+            ],
+            [
+                217, 62, 63, 60, 22, 208, 150, 180, 170, 5, 150, 151, 29, 34, 8, 84, 152, 173, 129,
+                33, 163, 125, 181, 84, 33, 72, 197, 4, 230, 225, 251, 42,
+            ],
+            [
+                249, 48, 105, 173, 78, 118, 188, 168, 198, 88, 170, 69, 52, 155, 204, 218, 103,
+                151, 11, 87, 245, 14, 169, 41, 149, 81, 220, 229, 96, 57, 135, 33,
+            ],
+            [
+                77, 116, 138, 172, 149, 10, 182, 44, 85, 153, 137, 54, 233, 168, 22, 180, 216, 235,
+                208, 138, 111, 63, 105, 55, 46, 230, 164, 162, 120, 48, 223, 2,
+            ],
+            [
+                129, 171, 210, 217, 126, 31, 12, 101, 10, 57, 0, 25, 124, 107, 240, 216, 253, 50,
+                112, 195, 93, 16, 8, 38, 108, 168, 247, 26, 55, 86, 246, 3,
+            ],
+            [
+                100, 208, 106, 162, 51, 202, 229, 111, 147, 80, 209, 115, 83, 119, 194, 207, 198,
+                31, 216, 163, 187, 207, 7, 185, 83, 27, 238, 226, 237, 118, 240, 40,
+            ],
+            [
+                90, 211, 194, 138, 95, 153, 10, 209, 67, 185, 151, 118, 51, 12, 4, 20, 138, 118,
+                48, 165, 10, 76, 177, 231, 56, 101, 224, 169, 90, 14, 126, 49,
+            ],
+            [
+                36, 117, 35, 227, 46, 2, 253, 47, 86, 241, 61, 245, 182, 214, 76, 121, 245, 83, 78,
+                13, 116, 195, 73, 162, 175, 5, 162, 24, 207, 138, 32, 55,
+            ],
+        ],
+        [
+            [
+                43, 243, 124, 16, 154, 204, 95, 77, 45, 48, 96, 102, 220, 67, 161, 239, 28, 93,
+                199, 218, 25, 184, 112, 121, 108, 2, 185, 150, 133, 148, 38, 35,
+            ],
+            [
+                118, 76, 41, 126, 249, 106, 15, 15, 93, 2, 2, 156, 66, 29, 77, 193, 124, 90, 203,
+                64, 83, 32, 171, 238, 74, 19, 58, 171, 93, 8, 178, 55,
+            ],
+            [
+                140, 89, 207, 145, 32, 148, 58, 217, 62, 254, 172, 96, 179, 102, 30, 77, 52, 48,
+                242, 115, 134, 197, 87, 62, 177, 38, 35, 173, 95, 121, 146, 17,
+            ],
+            [
+                242, 163, 137, 91, 161, 206, 129, 115, 47, 167, 95, 226, 5, 141, 235, 234, 28, 181,
+                229, 219, 227, 115, 181, 73, 195, 74, 102, 186, 26, 192, 201, 28,
+            ],
+            [
+                118, 192, 150, 86, 76, 137, 75, 36, 174, 34, 11, 34, 117, 70, 123, 215, 199, 143,
+                207, 184, 52, 217, 224, 118, 139, 106, 217, 72, 93, 218, 253, 62,
+            ],
+            [
+                182, 124, 139, 242, 40, 36, 219, 126, 194, 186, 80, 9, 132, 192, 191, 176, 225, 2,
+                216, 131, 175, 1, 55, 44, 17, 92, 82, 172, 148, 133, 112, 27,
+            ],
+            [
+                83, 185, 113, 179, 7, 35, 15, 155, 41, 216, 10, 236, 190, 155, 99, 190, 227, 4, 94,
+                242, 78, 147, 38, 242, 224, 205, 136, 229, 238, 39, 44, 57,
+            ],
+            [
+                21, 187, 70, 42, 63, 195, 129, 153, 174, 228, 241, 86, 137, 120, 101, 6, 30, 22,
+                202, 245, 5, 114, 125, 194, 29, 203, 178, 230, 245, 39, 212, 52,
+            ],
+        ],
+        [
+            [
+                137, 14, 58, 197, 249, 219, 82, 92, 30, 68, 45, 199, 105, 146, 190, 242, 73, 116,
+                64, 16, 147, 194, 76, 180, 252, 251, 199, 214, 105, 192, 151, 48,
+            ],
+            [
+                111, 255, 160, 51, 148, 234, 2, 88, 53, 200, 46, 154, 195, 73, 91, 101, 181, 233,
+                250, 16, 158, 78, 150, 52, 233, 95, 47, 172, 250, 122, 119, 22,
+            ],
+            [
+                157, 123, 34, 15, 229, 144, 252, 35, 200, 66, 86, 134, 223, 159, 46, 75, 162, 253,
+                66, 175, 70, 196, 124, 55, 135, 6, 245, 147, 161, 142, 250, 60,
+            ],
+            [
+                197, 5, 210, 208, 150, 29, 192, 181, 0, 229, 7, 225, 11, 111, 157, 10, 253, 41,
+                170, 220, 163, 225, 205, 246, 180, 182, 175, 91, 3, 245, 9, 23,
+            ],
+            [
+                134, 54, 43, 151, 223, 51, 163, 183, 12, 61, 148, 111, 182, 148, 209, 113, 135,
+                233, 138, 99, 196, 63, 203, 169, 29, 63, 238, 230, 134, 152, 23, 26,
+            ],
+            [
+                139, 53, 10, 51, 34, 243, 53, 240, 221, 161, 17, 115, 141, 98, 26, 195, 44, 126,
+                155, 13, 26, 166, 45, 87, 206, 98, 149, 14, 18, 24, 84, 15,
+            ],
+            [
+                159, 237, 20, 130, 199, 12, 94, 95, 21, 17, 27, 76, 47, 240, 140, 157, 21, 21, 216,
+                59, 114, 1, 228, 182, 51, 242, 102, 21, 179, 226, 34, 43,
+            ],
+            [
+                22, 86, 113, 83, 68, 89, 211, 200, 101, 74, 152, 146, 115, 28, 190, 81, 105, 66,
+                165, 185, 0, 122, 20, 95, 194, 100, 203, 15, 120, 157, 3, 46,
+            ],
+        ],
+        [
+            [
+                38, 200, 142, 17, 134, 127, 183, 15, 118, 28, 166, 186, 97, 143, 119, 242, 9, 160,
+                130, 22, 179, 234, 50, 25, 225, 222, 186, 186, 95, 10, 122, 12,
+            ],
+            [
+                164, 211, 206, 156, 251, 156, 200, 207, 144, 189, 74, 116, 236, 188, 11, 170, 178,
+                248, 193, 167, 86, 193, 72, 199, 148, 231, 169, 206, 214, 9, 3, 40,
+            ],
+            [
+                67, 230, 110, 148, 52, 244, 246, 165, 102, 156, 174, 53, 132, 87, 145, 220, 82,
+                232, 123, 101, 253, 224, 47, 148, 196, 39, 247, 228, 75, 0, 102, 26,
+            ],
+            [
+                0, 116, 178, 205, 165, 83, 68, 200, 85, 32, 72, 49, 49, 198, 160, 24, 18, 109, 154,
+                67, 184, 31, 75, 236, 175, 223, 48, 168, 158, 222, 178, 8,
+            ],
+            [
+                249, 165, 120, 181, 47, 179, 156, 228, 233, 165, 18, 242, 253, 147, 110, 128, 207,
+                223, 207, 151, 79, 132, 152, 230, 24, 27, 3, 87, 53, 211, 113, 61,
+            ],
+            [
+                19, 186, 255, 89, 84, 43, 245, 129, 213, 141, 162, 30, 82, 66, 7, 5, 217, 184, 58,
+                200, 225, 236, 239, 220, 178, 242, 151, 109, 211, 152, 178, 29,
+            ],
+            [
+                187, 206, 207, 148, 163, 190, 181, 65, 62, 209, 123, 86, 24, 75, 178, 252, 126, 65,
+                84, 160, 127, 149, 74, 153, 61, 250, 73, 193, 128, 176, 248, 56,
+            ],
+            [
+                126, 138, 59, 163, 41, 48, 172, 248, 215, 166, 250, 95, 39, 160, 201, 34, 90, 235,
+                120, 74, 37, 95, 43, 252, 164, 193, 66, 39, 161, 23, 197, 30,
+            ],
+        ],
+        [
+            [
+                212, 152, 117, 55, 29, 44, 175, 15, 167, 59, 139, 73, 10, 182, 21, 157, 152, 241,
+                199, 39, 190, 100, 158, 10, 67, 181, 125, 209, 28, 232, 246, 33,
+            ],
+            [
+                236, 76, 227, 207, 140, 92, 172, 122, 138, 209, 16, 63, 147, 158, 171, 155, 130,
+                43, 115, 64, 225, 85, 31, 198, 144, 196, 222, 145, 11, 164, 51, 37,
+            ],
+            [
+                14, 156, 88, 46, 46, 6, 253, 23, 194, 1, 242, 140, 97, 10, 255, 171, 106, 99, 121,
+                45, 162, 83, 224, 137, 203, 252, 191, 112, 9, 211, 163, 14,
+            ],
+            [
+                81, 57, 86, 222, 59, 166, 200, 84, 212, 172, 46, 22, 132, 248, 71, 151, 235, 90,
+                169, 224, 223, 82, 122, 61, 149, 44, 160, 39, 221, 76, 83, 11,
+            ],
+            [
+                104, 63, 194, 12, 84, 22, 61, 42, 53, 192, 182, 66, 126, 118, 195, 202, 169, 173,
+                54, 170, 164, 81, 138, 58, 18, 219, 143, 214, 208, 109, 67, 21,
+            ],
+            [
+                250, 114, 11, 52, 61, 42, 135, 235, 130, 10, 28, 70, 224, 176, 45, 195, 183, 3,
+                248, 82, 177, 22, 230, 160, 94, 3, 50, 178, 173, 219, 68, 18,
+            ],
+            [
+                204, 80, 51, 132, 222, 138, 93, 99, 135, 207, 104, 54, 191, 225, 57, 202, 225, 196,
+                75, 27, 121, 26, 10, 73, 227, 17, 125, 151, 146, 13, 172, 24,
+            ],
+            [
+                41, 235, 252, 235, 183, 209, 206, 96, 224, 117, 196, 143, 182, 180, 241, 202, 149,
+                140, 194, 101, 25, 72, 21, 27, 167, 109, 56, 169, 38, 245, 162, 12,
+            ],
+        ],
+        [
+            [
+                142, 33, 108, 29, 214, 20, 66, 178, 173, 197, 163, 71, 193, 243, 145, 138, 44, 51,
+                14, 22, 12, 35, 113, 238, 215, 199, 66, 218, 244, 91, 166, 10,
+            ],
+            [
+                189, 152, 234, 62, 98, 183, 241, 242, 188, 190, 79, 203, 139, 148, 149, 213, 133,
+                238, 171, 191, 177, 118, 50, 173, 251, 61, 193, 142, 118, 182, 74, 20,
+            ],
+            [
+                199, 50, 153, 23, 21, 229, 142, 11, 4, 36, 254, 173, 116, 196, 130, 101, 3, 149,
+                119, 221, 124, 117, 8, 94, 247, 235, 205, 23, 7, 154, 60, 4,
+            ],
+            [
+                115, 230, 187, 185, 13, 26, 104, 164, 101, 210, 70, 196, 9, 182, 159, 57, 88, 54,
+                29, 213, 219, 34, 147, 159, 165, 237, 209, 114, 118, 104, 253, 43,
+            ],
+            [
+                238, 53, 70, 203, 47, 84, 191, 100, 163, 131, 149, 210, 253, 14, 35, 207, 1, 36,
+                211, 245, 192, 121, 248, 251, 229, 100, 110, 117, 118, 106, 117, 5,
+            ],
+            [
+                191, 227, 226, 159, 69, 250, 139, 237, 208, 174, 99, 130, 131, 162, 241, 165, 165,
+                48, 48, 63, 142, 84, 180, 19, 155, 124, 199, 242, 143, 0, 121, 26,
+            ],
+            [
+                17, 48, 219, 14, 200, 172, 11, 251, 17, 132, 219, 95, 206, 227, 47, 112, 35, 204,
+                219, 166, 34, 255, 222, 227, 51, 90, 148, 1, 191, 177, 23, 48,
+            ],
+            [
+                239, 36, 23, 82, 9, 53, 154, 146, 90, 43, 139, 142, 151, 102, 32, 114, 49, 187,
+                159, 36, 102, 17, 207, 59, 18, 190, 206, 37, 224, 171, 94, 15,
+            ],
+        ],
+        [
+            [
+                53, 179, 213, 103, 49, 233, 227, 29, 158, 193, 178, 148, 170, 36, 72, 104, 244,
+                216, 38, 167, 145, 28, 214, 246, 114, 54, 101, 253, 70, 57, 92, 21,
+            ],
+            [
+                175, 154, 71, 102, 174, 1, 176, 41, 15, 121, 135, 174, 34, 167, 119, 27, 1, 119,
+                245, 232, 79, 100, 165, 73, 126, 146, 183, 101, 210, 216, 59, 33,
+            ],
+            [
+                185, 226, 112, 163, 26, 182, 4, 228, 103, 249, 140, 114, 190, 140, 12, 246, 235,
+                52, 31, 91, 57, 130, 110, 36, 67, 145, 13, 243, 60, 209, 46, 22,
+            ],
+            [
+                123, 144, 61, 254, 176, 235, 68, 204, 139, 16, 215, 65, 27, 38, 128, 61, 110, 181,
+                30, 70, 203, 62, 251, 119, 42, 70, 210, 233, 78, 93, 190, 2,
+            ],
+            [
+                179, 48, 53, 248, 239, 138, 94, 106, 71, 83, 181, 149, 201, 233, 97, 146, 102, 113,
+                51, 205, 96, 237, 105, 52, 203, 97, 91, 176, 99, 23, 248, 8,
+            ],
+            [
+                65, 58, 186, 201, 161, 232, 47, 64, 159, 7, 240, 206, 184, 193, 174, 52, 151, 184,
+                109, 27, 187, 124, 70, 33, 75, 183, 73, 154, 237, 2, 203, 53,
+            ],
+            [
+                186, 11, 101, 54, 226, 161, 244, 6, 149, 231, 31, 159, 210, 180, 42, 249, 200, 84,
+                77, 62, 252, 212, 92, 0, 195, 196, 168, 54, 17, 200, 93, 16,
+            ],
+            [
+                181, 226, 154, 72, 198, 196, 23, 30, 175, 109, 102, 94, 221, 244, 171, 228, 188,
+                222, 46, 136, 159, 43, 99, 213, 141, 80, 234, 90, 230, 162, 181, 63,
+            ],
+        ],
+        [
+            [
+                154, 33, 232, 248, 110, 142, 18, 225, 97, 204, 115, 183, 158, 140, 42, 216, 189,
+                86, 225, 164, 135, 3, 202, 95, 42, 111, 92, 157, 176, 133, 120, 6,
+            ],
+            [
+                69, 180, 75, 199, 95, 12, 22, 79, 251, 69, 97, 27, 251, 70, 76, 100, 120, 216, 7,
+                228, 207, 240, 96, 41, 161, 234, 140, 88, 92, 225, 144, 19,
+            ],
+            [
+                48, 126, 251, 164, 124, 167, 65, 158, 23, 228, 215, 124, 98, 118, 146, 61, 65, 222,
+                186, 28, 45, 121, 194, 37, 59, 70, 153, 114, 213, 27, 36, 35,
+            ],
+            [
+                63, 95, 88, 163, 202, 178, 219, 123, 227, 213, 119, 151, 95, 103, 81, 76, 92, 190,
+                142, 26, 105, 145, 129, 141, 250, 154, 86, 53, 175, 174, 170, 41,
+            ],
+            [
+                124, 142, 196, 108, 243, 201, 160, 1, 191, 50, 115, 31, 10, 91, 11, 125, 116, 194,
+                201, 121, 113, 211, 238, 151, 124, 100, 59, 6, 144, 104, 179, 8,
+            ],
+            [
+                94, 114, 133, 6, 193, 24, 33, 119, 164, 15, 60, 143, 177, 105, 134, 48, 155, 152,
+                82, 73, 35, 218, 61, 214, 22, 53, 87, 72, 16, 136, 61, 35,
+            ],
+            [
+                219, 45, 27, 159, 16, 189, 171, 238, 31, 161, 54, 195, 159, 193, 226, 88, 213, 153,
+                29, 167, 48, 167, 247, 22, 192, 70, 236, 177, 124, 4, 198, 46,
+            ],
+            [
+                54, 218, 33, 76, 227, 107, 5, 165, 213, 247, 75, 54, 165, 38, 35, 121, 206, 176,
+                121, 221, 45, 73, 223, 148, 223, 118, 88, 80, 241, 110, 135, 36,
+            ],
+        ],
+        [
+            [
+                57, 80, 40, 166, 144, 238, 92, 59, 50, 194, 194, 253, 138, 156, 131, 255, 57, 97,
+                231, 86, 185, 96, 69, 88, 124, 89, 130, 0, 3, 23, 22, 54,
+            ],
+            [
+                71, 139, 108, 12, 104, 84, 234, 45, 111, 140, 81, 46, 131, 112, 55, 125, 55, 121,
+                76, 252, 234, 212, 76, 62, 246, 247, 252, 6, 211, 104, 119, 7,
+            ],
+            [
+                106, 157, 29, 100, 242, 125, 207, 67, 120, 6, 122, 72, 242, 99, 241, 114, 64, 128,
+                246, 208, 152, 176, 203, 231, 147, 160, 197, 182, 118, 184, 208, 1,
+            ],
+            [
+                116, 7, 232, 111, 186, 85, 231, 69, 55, 164, 168, 243, 238, 254, 138, 200, 153, 21,
+                67, 18, 131, 64, 155, 128, 243, 147, 178, 79, 93, 177, 211, 53,
+            ],
+            [
+                6, 157, 196, 61, 234, 23, 62, 218, 237, 240, 23, 193, 148, 237, 68, 246, 59, 234,
+                194, 191, 252, 132, 184, 203, 228, 248, 136, 16, 229, 16, 142, 57,
+            ],
+            [
+                95, 236, 147, 207, 10, 253, 69, 118, 250, 178, 216, 85, 235, 54, 24, 181, 145, 45,
+                0, 172, 142, 15, 37, 246, 163, 35, 25, 83, 191, 189, 154, 37,
+            ],
+            [
+                38, 227, 131, 15, 160, 254, 180, 219, 111, 147, 33, 202, 52, 22, 94, 177, 174, 38,
+                62, 37, 132, 66, 202, 32, 101, 49, 169, 82, 3, 179, 183, 4,
+            ],
+            [
+                123, 33, 253, 204, 205, 52, 45, 205, 35, 65, 5, 70, 121, 24, 125, 198, 142, 244, 6,
+                59, 238, 246, 165, 91, 169, 220, 32, 206, 253, 28, 59, 52,
+            ],
+        ],
+        [
+            [
+                114, 93, 53, 10, 216, 53, 202, 141, 165, 207, 21, 50, 155, 43, 126, 75, 78, 58, 47,
+                205, 149, 162, 7, 251, 170, 144, 176, 128, 222, 184, 17, 53,
+            ],
+            [
+                174, 123, 189, 33, 181, 237, 168, 251, 81, 3, 97, 85, 236, 76, 166, 192, 156, 106,
+                28, 176, 162, 212, 203, 135, 54, 194, 14, 201, 50, 50, 46, 30,
+            ],
+            [
+                42, 114, 141, 131, 47, 32, 14, 188, 96, 148, 243, 87, 116, 215, 95, 172, 190, 121,
+                200, 0, 172, 85, 178, 229, 141, 24, 23, 235, 188, 34, 46, 39,
+            ],
+            [
+                162, 150, 233, 204, 216, 221, 152, 144, 228, 238, 58, 184, 209, 16, 162, 117, 239,
+                78, 206, 174, 123, 156, 175, 246, 192, 172, 105, 147, 184, 127, 175, 38,
+            ],
+            [
+                11, 243, 213, 71, 154, 109, 200, 141, 253, 73, 37, 111, 210, 106, 25, 127, 155,
+                158, 85, 97, 155, 29, 239, 11, 135, 175, 196, 62, 165, 112, 150, 15,
+            ],
+            [
+                176, 157, 130, 206, 156, 165, 11, 11, 226, 77, 77, 233, 100, 3, 205, 10, 203, 135,
+                94, 96, 90, 250, 135, 185, 97, 243, 212, 193, 25, 5, 150, 44,
+            ],
+            [
+                166, 152, 168, 44, 129, 29, 117, 6, 51, 73, 45, 23, 230, 111, 153, 88, 52, 218,
+                126, 120, 29, 103, 21, 62, 198, 199, 131, 250, 103, 205, 233, 4,
+            ],
+            [
+                69, 136, 252, 192, 7, 162, 124, 219, 113, 59, 251, 165, 213, 119, 160, 60, 74, 155,
+                62, 148, 26, 160, 209, 207, 166, 4, 240, 9, 168, 202, 119, 34,
+            ],
+        ],
+        [
+            [
+                93, 41, 24, 206, 47, 150, 127, 12, 250, 223, 34, 154, 253, 224, 189, 169, 120, 51,
+                31, 140, 187, 56, 91, 183, 207, 254, 73, 57, 54, 202, 251, 59,
+            ],
+            [
+                24, 59, 96, 221, 119, 226, 243, 249, 97, 139, 84, 249, 65, 73, 204, 221, 171, 62,
+                212, 221, 51, 20, 200, 62, 206, 130, 163, 205, 51, 20, 71, 16,
+            ],
+            [
+                193, 157, 201, 73, 183, 187, 197, 244, 47, 233, 86, 241, 96, 110, 77, 26, 40, 247,
+                229, 60, 132, 248, 214, 244, 214, 19, 160, 177, 244, 42, 18, 61,
+            ],
+            [
+                26, 59, 214, 47, 253, 27, 179, 1, 177, 33, 49, 151, 136, 152, 6, 138, 35, 11, 194,
+                32, 179, 37, 219, 201, 85, 226, 27, 196, 122, 163, 224, 3,
+            ],
+            [
+                97, 7, 32, 192, 82, 221, 132, 32, 151, 252, 150, 24, 225, 112, 141, 137, 137, 135,
+                149, 219, 98, 156, 155, 1, 78, 111, 17, 21, 221, 202, 129, 44,
+            ],
+            [
+                117, 147, 214, 225, 151, 99, 209, 182, 12, 224, 82, 181, 120, 67, 0, 80, 83, 139,
+                110, 252, 64, 63, 233, 54, 236, 109, 180, 242, 128, 109, 239, 31,
+            ],
+            [
+                11, 219, 52, 216, 143, 198, 85, 58, 68, 249, 13, 132, 26, 244, 135, 126, 97, 79,
+                102, 240, 66, 149, 236, 96, 175, 0, 9, 99, 121, 222, 240, 46,
+            ],
+            [
+                119, 58, 164, 60, 76, 63, 130, 105, 78, 4, 222, 91, 114, 84, 50, 26, 251, 211, 228,
+                142, 128, 200, 70, 235, 151, 26, 210, 157, 129, 178, 4, 8,
+            ],
+        ],
+        [
+            [
+                96, 106, 244, 119, 158, 252, 188, 79, 153, 116, 163, 150, 142, 221, 129, 230, 8,
+                119, 140, 131, 137, 241, 121, 68, 171, 49, 207, 61, 210, 31, 230, 53,
+            ],
+            [
+                205, 185, 100, 46, 98, 99, 117, 198, 163, 51, 132, 10, 84, 155, 117, 181, 225, 35,
+                141, 132, 196, 225, 94, 51, 134, 69, 222, 137, 40, 245, 58, 29,
+            ],
+            [
+                221, 134, 136, 150, 215, 128, 33, 158, 121, 115, 162, 216, 144, 34, 2, 33, 15, 233,
+                184, 181, 49, 185, 115, 72, 95, 105, 234, 157, 220, 126, 198, 19,
+            ],
+            [
+                55, 80, 252, 89, 46, 70, 10, 182, 221, 165, 151, 181, 202, 209, 233, 201, 158, 140,
+                239, 102, 245, 0, 105, 26, 121, 15, 217, 225, 227, 100, 30, 49,
+            ],
+            [
+                234, 183, 227, 15, 144, 183, 222, 243, 137, 41, 72, 138, 93, 184, 186, 158, 112,
+                176, 103, 176, 101, 21, 56, 11, 45, 89, 254, 196, 189, 186, 61, 4,
+            ],
+            [
+                209, 196, 35, 178, 185, 104, 200, 16, 158, 240, 22, 71, 181, 84, 123, 53, 226, 68,
+                51, 16, 89, 129, 231, 3, 30, 112, 98, 44, 44, 90, 69, 20,
+            ],
+            [
+                213, 62, 249, 212, 9, 145, 236, 136, 73, 46, 57, 124, 47, 107, 161, 214, 142, 115,
+                67, 226, 33, 243, 102, 11, 152, 94, 203, 200, 202, 214, 242, 15,
+            ],
+            [
+                17, 91, 88, 206, 215, 170, 84, 19, 148, 121, 216, 88, 235, 227, 90, 230, 115, 214,
+                112, 197, 139, 92, 233, 27, 3, 114, 212, 47, 154, 32, 218, 25,
+            ],
+        ],
+        [
+            [
+                0, 237, 159, 226, 148, 29, 32, 32, 221, 165, 111, 36, 21, 195, 71, 187, 179, 244,
+                103, 40, 254, 66, 249, 60, 67, 69, 49, 3, 188, 197, 242, 56,
+            ],
+            [
+                42, 7, 4, 241, 12, 9, 188, 123, 254, 67, 209, 46, 82, 89, 206, 5, 176, 91, 154, 53,
+                146, 115, 246, 74, 53, 78, 44, 139, 38, 12, 1, 54,
+            ],
+            [
+                185, 249, 163, 122, 58, 212, 120, 16, 62, 183, 237, 99, 70, 134, 100, 215, 11, 254,
+                255, 30, 20, 41, 223, 153, 107, 63, 243, 144, 239, 13, 22, 23,
+            ],
+            [
+                92, 147, 125, 35, 100, 176, 255, 198, 245, 146, 30, 163, 135, 2, 176, 143, 219,
+                172, 211, 97, 220, 69, 56, 244, 83, 156, 186, 127, 220, 127, 165, 46,
+            ],
+            [
+                122, 166, 218, 189, 112, 161, 218, 10, 93, 102, 254, 11, 122, 70, 127, 161, 133,
+                126, 22, 104, 56, 226, 245, 62, 62, 29, 124, 119, 2, 207, 40, 62,
+            ],
+            [
+                57, 127, 81, 17, 82, 235, 10, 17, 68, 81, 15, 94, 48, 67, 69, 175, 49, 130, 170,
+                170, 199, 151, 202, 76, 237, 247, 114, 115, 210, 211, 68, 20,
+            ],
+            [
+                70, 18, 199, 221, 127, 158, 255, 225, 147, 78, 32, 85, 64, 2, 75, 88, 176, 5, 163,
+                0, 125, 159, 233, 112, 61, 98, 34, 251, 135, 122, 124, 12,
+            ],
+            [
+                146, 77, 211, 235, 166, 240, 31, 238, 100, 242, 190, 41, 163, 40, 8, 173, 156, 120,
+                217, 169, 79, 216, 67, 93, 206, 59, 193, 162, 121, 118, 1, 50,
+            ],
+        ],
+        [
+            [
+                100, 60, 16, 153, 93, 2, 15, 228, 96, 143, 159, 225, 173, 251, 171, 70, 159, 166,
+                67, 177, 118, 185, 180, 51, 99, 69, 150, 105, 169, 178, 4, 41,
+            ],
+            [
+                225, 252, 69, 197, 122, 242, 68, 10, 166, 221, 79, 174, 226, 246, 231, 30, 115,
+                174, 94, 204, 47, 3, 209, 240, 110, 87, 8, 162, 77, 167, 108, 39,
+            ],
+            [
+                63, 210, 217, 108, 198, 9, 247, 18, 147, 191, 240, 119, 206, 61, 23, 199, 22, 11,
+                129, 127, 246, 135, 204, 201, 25, 108, 4, 169, 17, 10, 242, 24,
+            ],
+            [
+                94, 135, 7, 147, 194, 161, 251, 10, 97, 59, 240, 187, 112, 60, 44, 89, 67, 157, 23,
+                222, 86, 234, 189, 192, 114, 254, 50, 81, 165, 107, 11, 14,
+            ],
+            [
+                8, 166, 143, 243, 221, 101, 58, 12, 250, 40, 68, 80, 208, 188, 121, 119, 63, 140,
+                194, 136, 200, 243, 208, 91, 239, 45, 71, 112, 139, 49, 136, 40,
+            ],
+            [
+                159, 78, 141, 63, 77, 55, 150, 234, 141, 82, 147, 32, 130, 213, 52, 105, 138, 53,
+                143, 11, 6, 65, 194, 196, 46, 82, 176, 225, 32, 196, 27, 20,
+            ],
+            [
+                36, 102, 65, 86, 136, 252, 107, 225, 38, 243, 36, 136, 8, 5, 128, 216, 181, 132,
+                170, 222, 183, 13, 22, 27, 17, 223, 230, 142, 88, 249, 199, 31,
+            ],
+            [
+                42, 159, 190, 246, 44, 222, 10, 215, 10, 211, 197, 231, 116, 53, 158, 107, 181,
+                115, 102, 17, 107, 140, 109, 119, 18, 151, 77, 195, 252, 229, 128, 21,
+            ],
+        ],
+        [
+            [
+                226, 249, 163, 143, 53, 149, 111, 22, 45, 63, 186, 75, 37, 16, 236, 54, 187, 57,
+                165, 74, 242, 234, 230, 24, 190, 139, 172, 86, 111, 51, 36, 19,
+            ],
+            [
+                148, 140, 222, 68, 237, 184, 7, 233, 221, 227, 132, 11, 114, 106, 136, 173, 40, 66,
+                60, 18, 125, 85, 119, 91, 97, 76, 63, 198, 177, 210, 84, 38,
+            ],
+            [
+                248, 157, 70, 244, 149, 202, 17, 199, 132, 53, 81, 134, 35, 155, 213, 5, 97, 79, 6,
+                37, 211, 255, 209, 57, 66, 28, 241, 134, 106, 74, 28, 41,
+            ],
+            [
+                254, 139, 173, 177, 225, 143, 167, 32, 98, 120, 188, 249, 61, 21, 161, 225, 47, 83,
+                138, 22, 228, 182, 53, 25, 44, 110, 163, 8, 107, 212, 213, 47,
+            ],
+            [
+                158, 102, 231, 178, 47, 122, 56, 198, 53, 19, 83, 192, 180, 164, 103, 38, 59, 113,
+                145, 51, 47, 143, 29, 192, 63, 244, 177, 1, 208, 111, 15, 39,
+            ],
+            [
+                13, 80, 80, 29, 52, 194, 217, 24, 61, 126, 89, 200, 68, 219, 149, 69, 2, 34, 45,
+                105, 18, 252, 73, 104, 138, 142, 219, 234, 63, 219, 131, 34,
+            ],
+            [
+                28, 82, 197, 227, 190, 235, 206, 67, 235, 47, 198, 100, 37, 82, 231, 34, 65, 153,
+                121, 168, 115, 181, 189, 133, 197, 69, 30, 142, 57, 179, 101, 2,
+            ],
+            [
+                56, 57, 228, 137, 177, 129, 185, 241, 218, 32, 120, 85, 104, 194, 14, 102, 125, 98,
+                136, 26, 191, 10, 92, 72, 35, 93, 144, 85, 223, 149, 20, 25,
+            ],
+        ],
+        [
+            [
+                4, 9, 20, 51, 207, 192, 148, 219, 181, 39, 86, 81, 191, 186, 63, 249, 130, 206,
+                101, 95, 218, 245, 190, 20, 236, 58, 226, 255, 229, 183, 250, 3,
+            ],
+            [
+                242, 225, 238, 60, 92, 57, 38, 184, 133, 38, 239, 188, 1, 3, 228, 86, 30, 198, 181,
+                113, 169, 107, 229, 238, 40, 202, 118, 150, 54, 253, 217, 30,
+            ],
+            [
+                143, 164, 189, 125, 128, 136, 29, 243, 42, 199, 113, 221, 18, 241, 7, 161, 184,
+                161, 66, 75, 5, 10, 160, 241, 199, 170, 73, 221, 208, 254, 89, 40,
+            ],
+            [
+                161, 45, 81, 240, 204, 62, 4, 200, 35, 1, 235, 168, 78, 245, 151, 66, 99, 215, 192,
+                225, 164, 13, 108, 49, 65, 204, 55, 254, 147, 238, 101, 25,
+            ],
+            [
+                44, 162, 210, 47, 19, 28, 144, 44, 183, 0, 46, 132, 44, 249, 210, 208, 233, 53, 2,
+                67, 178, 83, 8, 134, 224, 198, 6, 153, 209, 183, 118, 48,
+            ],
+            [
+                118, 168, 89, 190, 107, 112, 45, 0, 23, 59, 63, 58, 244, 189, 31, 232, 29, 137,
+                183, 76, 184, 2, 215, 63, 50, 172, 170, 228, 238, 122, 13, 16,
+            ],
+            [
+                66, 219, 225, 132, 221, 37, 199, 188, 43, 98, 185, 110, 71, 164, 193, 108, 163,
+                223, 147, 139, 145, 227, 68, 191, 118, 112, 150, 23, 170, 192, 61, 24,
+            ],
+            [
+                113, 181, 198, 225, 65, 1, 188, 207, 57, 178, 162, 220, 146, 6, 205, 118, 131, 61,
+                231, 9, 151, 255, 175, 222, 2, 109, 61, 246, 27, 63, 142, 45,
+            ],
+        ],
+        [
+            [
+                130, 67, 238, 196, 13, 76, 243, 52, 195, 178, 153, 12, 74, 64, 139, 120, 107, 255,
+                10, 72, 101, 5, 102, 41, 214, 26, 14, 207, 136, 51, 4, 39,
+            ],
+            [
+                107, 73, 45, 36, 151, 239, 90, 183, 57, 247, 139, 204, 116, 159, 195, 49, 135, 144,
+                226, 52, 182, 166, 189, 108, 67, 88, 212, 14, 248, 228, 91, 49,
+            ],
+            [
+                216, 31, 194, 96, 42, 101, 172, 96, 177, 195, 4, 224, 94, 155, 0, 203, 116, 138,
+                45, 91, 132, 223, 218, 201, 171, 235, 94, 53, 205, 53, 108, 17,
+            ],
+            [
+                131, 245, 164, 230, 199, 167, 212, 110, 37, 4, 157, 2, 54, 120, 165, 99, 61, 209,
+                183, 233, 120, 72, 163, 237, 194, 135, 236, 98, 131, 69, 117, 48,
+            ],
+            [
+                27, 121, 133, 117, 87, 103, 174, 81, 185, 129, 81, 155, 116, 248, 210, 133, 179,
+                97, 246, 11, 138, 193, 237, 105, 227, 81, 91, 203, 240, 15, 118, 49,
+            ],
+            [
+                181, 107, 40, 9, 77, 152, 107, 101, 44, 157, 234, 106, 94, 137, 241, 239, 104, 56,
+                64, 165, 198, 7, 196, 50, 125, 73, 115, 223, 122, 176, 249, 27,
+            ],
+            [
+                1, 140, 151, 178, 168, 223, 254, 48, 222, 49, 237, 207, 100, 181, 84, 249, 198,
+                141, 175, 164, 11, 128, 64, 73, 225, 92, 181, 117, 25, 165, 17, 2,
+            ],
+            [
+                10, 14, 177, 42, 25, 71, 129, 183, 201, 227, 23, 68, 98, 213, 120, 109, 159, 171,
+                61, 152, 20, 98, 252, 4, 132, 10, 157, 243, 38, 154, 121, 29,
+            ],
+        ],
+        [
+            [
+                225, 19, 254, 248, 171, 99, 211, 140, 34, 9, 26, 117, 115, 34, 25, 78, 202, 255, 7,
+                150, 252, 51, 57, 142, 22, 110, 59, 173, 8, 149, 31, 8,
+            ],
+            [
+                59, 29, 211, 103, 142, 78, 11, 45, 13, 151, 31, 214, 166, 189, 236, 190, 65, 182,
+                212, 50, 104, 250, 128, 250, 214, 135, 96, 38, 199, 110, 165, 25,
+            ],
+            [
+                6, 43, 57, 35, 38, 153, 46, 8, 58, 6, 39, 76, 242, 142, 95, 60, 145, 152, 120, 70,
+                178, 122, 191, 110, 192, 205, 241, 104, 107, 201, 108, 61,
+            ],
+            [
+                64, 18, 150, 64, 15, 236, 69, 214, 121, 188, 21, 42, 38, 146, 201, 127, 71, 46,
+                184, 212, 194, 151, 63, 137, 150, 140, 228, 206, 198, 71, 140, 50,
+            ],
+            [
+                18, 139, 34, 229, 184, 240, 198, 5, 44, 121, 254, 83, 190, 42, 62, 192, 91, 234,
+                116, 94, 36, 189, 106, 114, 182, 203, 126, 176, 69, 92, 130, 60,
+            ],
+            [
+                12, 236, 117, 106, 37, 6, 8, 220, 30, 25, 47, 110, 125, 42, 57, 41, 63, 237, 207,
+                174, 211, 127, 153, 169, 128, 47, 3, 98, 213, 84, 96, 22,
+            ],
+            [
+                156, 241, 205, 22, 102, 212, 66, 31, 213, 230, 66, 232, 115, 137, 151, 95, 1, 180,
+                235, 69, 189, 85, 245, 219, 90, 57, 67, 124, 159, 165, 238, 13,
+            ],
+            [
+                213, 127, 158, 219, 142, 100, 101, 78, 138, 132, 206, 9, 1, 172, 166, 219, 227,
+                247, 158, 214, 10, 111, 52, 67, 73, 126, 98, 73, 28, 1, 198, 42,
+            ],
+        ],
+        [
+            [
+                147, 7, 208, 38, 20, 203, 177, 102, 223, 237, 228, 107, 108, 134, 3, 6, 192, 48,
+                191, 65, 127, 206, 195, 121, 210, 58, 132, 22, 250, 233, 163, 11,
+            ],
+            [
+                57, 222, 181, 26, 43, 253, 126, 135, 164, 178, 233, 153, 227, 210, 220, 141, 64,
+                55, 43, 68, 251, 134, 161, 227, 151, 25, 148, 11, 109, 254, 151, 9,
+            ],
+            [
+                225, 4, 238, 4, 129, 131, 244, 43, 35, 52, 140, 223, 202, 72, 105, 49, 65, 209,
+                129, 61, 74, 210, 60, 207, 186, 57, 1, 222, 248, 204, 103, 40,
+            ],
+            [
+                144, 247, 189, 141, 206, 108, 113, 230, 183, 94, 125, 101, 70, 193, 25, 255, 242,
+                255, 111, 139, 36, 199, 229, 138, 198, 76, 178, 13, 57, 152, 100, 6,
+            ],
+            [
+                143, 110, 22, 50, 48, 185, 167, 65, 16, 146, 38, 175, 243, 22, 129, 27, 176, 7,
+                211, 31, 55, 167, 220, 211, 145, 172, 186, 205, 179, 207, 10, 53,
+            ],
+            [
+                49, 130, 181, 104, 192, 68, 207, 252, 72, 214, 30, 204, 53, 152, 123, 185, 194,
+                216, 126, 217, 245, 46, 40, 53, 46, 44, 155, 177, 60, 236, 225, 41,
+            ],
+            [
+                80, 146, 9, 120, 67, 126, 85, 89, 253, 49, 109, 137, 135, 173, 103, 155, 252, 156,
+                71, 73, 174, 82, 158, 12, 179, 225, 238, 112, 54, 220, 196, 63,
+            ],
+            [
+                198, 194, 62, 102, 45, 250, 232, 236, 194, 225, 178, 3, 122, 171, 236, 127, 110,
+                87, 136, 71, 111, 71, 171, 82, 205, 167, 31, 132, 144, 234, 177, 61,
+            ],
+        ],
+        [
+            [
+                162, 250, 181, 68, 200, 206, 253, 10, 111, 22, 141, 110, 123, 116, 216, 182, 4,
+                129, 248, 154, 161, 58, 6, 10, 247, 61, 52, 63, 103, 119, 10, 26,
+            ],
+            [
+                252, 186, 28, 190, 243, 244, 231, 82, 90, 140, 255, 17, 73, 58, 171, 157, 221, 151,
+                21, 120, 186, 103, 233, 122, 219, 118, 167, 13, 134, 215, 168, 40,
+            ],
+            [
+                254, 244, 43, 96, 148, 50, 80, 89, 17, 229, 92, 2, 25, 197, 252, 198, 224, 34, 91,
+                3, 62, 21, 82, 93, 44, 198, 249, 195, 246, 228, 107, 24,
+            ],
+            [
+                211, 23, 95, 248, 62, 157, 119, 191, 163, 95, 142, 181, 75, 93, 68, 1, 172, 214,
+                188, 131, 225, 135, 213, 164, 68, 101, 100, 38, 106, 222, 106, 49,
+            ],
+            [
+                141, 218, 71, 103, 9, 197, 181, 150, 7, 179, 207, 136, 104, 167, 107, 13, 252, 69,
+                56, 187, 65, 148, 79, 157, 60, 44, 124, 136, 168, 168, 245, 52,
+            ],
+            [
+                134, 4, 141, 45, 106, 24, 10, 104, 225, 251, 168, 80, 160, 144, 28, 112, 148, 200,
+                166, 50, 235, 209, 147, 218, 106, 114, 80, 252, 171, 166, 218, 59,
+            ],
+            [
+                123, 60, 129, 33, 110, 125, 140, 152, 223, 24, 163, 198, 225, 201, 188, 95, 154,
+                189, 37, 91, 57, 141, 220, 104, 226, 209, 84, 162, 76, 109, 105, 47,
+            ],
+            [
+                121, 75, 17, 176, 68, 161, 148, 220, 81, 236, 115, 12, 97, 26, 101, 9, 102, 105,
+                61, 79, 148, 116, 132, 141, 187, 201, 59, 117, 150, 92, 168, 49,
+            ],
+        ],
+        [
+            [
+                181, 63, 124, 66, 208, 77, 217, 81, 222, 96, 191, 152, 41, 112, 13, 180, 242, 107,
+                191, 93, 117, 51, 16, 165, 92, 216, 33, 134, 229, 133, 1, 12,
+            ],
+            [
+                15, 205, 250, 253, 245, 57, 145, 243, 11, 250, 212, 3, 233, 166, 187, 154, 201,
+                245, 26, 78, 53, 206, 99, 240, 110, 240, 185, 214, 247, 162, 203, 46,
+            ],
+            [
+                209, 157, 73, 128, 119, 59, 110, 119, 166, 181, 14, 76, 151, 234, 110, 102, 168,
+                21, 111, 220, 185, 193, 234, 92, 165, 157, 78, 232, 237, 80, 221, 22,
+            ],
+            [
+                158, 199, 0, 100, 174, 105, 249, 128, 59, 210, 75, 145, 50, 100, 148, 170, 68, 43,
+                97, 95, 6, 209, 234, 129, 61, 24, 148, 158, 63, 52, 248, 43,
+            ],
+            [
+                148, 156, 176, 167, 231, 137, 41, 198, 148, 139, 187, 175, 48, 179, 189, 84, 8, 82,
+                78, 145, 116, 189, 110, 208, 130, 146, 62, 187, 249, 35, 108, 58,
+            ],
+            [
+                232, 150, 191, 166, 37, 99, 232, 53, 43, 166, 200, 75, 98, 239, 90, 173, 100, 47,
+                1, 143, 152, 41, 13, 47, 215, 223, 175, 65, 33, 1, 233, 54,
+            ],
+            [
+                197, 197, 129, 106, 240, 230, 147, 88, 187, 147, 150, 28, 160, 226, 67, 176, 0, 35,
+                255, 59, 74, 111, 236, 130, 169, 67, 99, 250, 102, 63, 167, 52,
+            ],
+            [
+                8, 58, 172, 162, 37, 126, 93, 28, 113, 58, 63, 255, 188, 249, 125, 63, 12, 85, 156,
+                45, 95, 108, 107, 247, 24, 121, 159, 232, 128, 223, 188, 22,
+            ],
+        ],
+        [
+            [
+                70, 153, 87, 15, 86, 195, 94, 106, 5, 27, 71, 252, 151, 220, 178, 27, 188, 109,
+                242, 17, 242, 242, 11, 232, 65, 222, 20, 146, 12, 156, 65, 26,
+            ],
+            [
+                110, 51, 216, 49, 125, 237, 146, 37, 216, 70, 242, 162, 176, 102, 119, 213, 225,
+                20, 207, 221, 152, 180, 149, 28, 129, 88, 195, 143, 122, 48, 217, 59,
+            ],
+            [
+                129, 101, 147, 89, 194, 36, 32, 236, 228, 50, 102, 203, 164, 178, 154, 250, 61,
+                104, 139, 38, 163, 10, 176, 34, 65, 53, 144, 201, 220, 157, 173, 7,
+            ],
+            [
+                27, 203, 32, 102, 144, 212, 179, 121, 78, 127, 225, 200, 108, 117, 43, 18, 134,
+                184, 130, 119, 23, 8, 16, 69, 86, 37, 239, 86, 120, 171, 94, 12,
+            ],
+            [
+                60, 21, 102, 55, 226, 101, 16, 50, 14, 248, 125, 27, 166, 153, 200, 231, 57, 77,
+                68, 18, 157, 123, 98, 182, 254, 81, 230, 254, 146, 196, 93, 3,
+            ],
+            [
+                81, 15, 253, 229, 48, 91, 154, 193, 4, 166, 214, 127, 4, 142, 225, 15, 184, 7, 248,
+                84, 168, 18, 220, 244, 28, 226, 61, 48, 213, 205, 11, 28,
+            ],
+            [
+                233, 241, 142, 79, 177, 121, 59, 191, 195, 181, 189, 237, 118, 47, 149, 205, 82,
+                155, 119, 235, 185, 214, 1, 192, 160, 217, 103, 222, 73, 231, 8, 52,
+            ],
+            [
+                24, 89, 110, 23, 195, 231, 158, 3, 139, 243, 169, 149, 42, 255, 211, 8, 234, 138,
+                111, 6, 25, 60, 60, 168, 68, 93, 244, 78, 31, 88, 246, 21,
+            ],
+        ],
+        [
+            [
+                6, 20, 127, 253, 255, 185, 18, 78, 77, 104, 105, 66, 231, 15, 41, 100, 88, 69, 58,
+                174, 178, 120, 160, 189, 108, 81, 234, 221, 190, 66, 217, 59,
+            ],
+            [
+                239, 175, 127, 142, 228, 74, 139, 48, 185, 111, 92, 164, 51, 42, 107, 6, 203, 6,
+                146, 186, 52, 67, 55, 180, 59, 94, 190, 204, 50, 99, 143, 57,
+            ],
+            [
+                222, 46, 255, 3, 37, 81, 75, 229, 85, 36, 169, 37, 158, 164, 198, 91, 124, 58, 134,
+                12, 167, 234, 192, 79, 80, 116, 9, 43, 115, 96, 253, 15,
+            ],
+            [
+                104, 41, 218, 110, 231, 56, 69, 230, 174, 184, 240, 88, 86, 201, 246, 109, 2, 229,
+                210, 24, 163, 0, 67, 244, 239, 169, 214, 44, 119, 16, 86, 60,
+            ],
+            [
+                252, 247, 28, 225, 24, 232, 242, 107, 133, 43, 240, 180, 232, 43, 95, 17, 106, 76,
+                241, 78, 82, 22, 66, 178, 63, 43, 157, 184, 103, 220, 75, 0,
+            ],
+            [
+                40, 15, 8, 226, 215, 137, 252, 87, 217, 180, 163, 155, 11, 243, 34, 21, 131, 73,
+                237, 9, 137, 44, 9, 244, 87, 214, 17, 160, 214, 212, 230, 11,
+            ],
+            [
+                149, 128, 217, 71, 32, 33, 215, 167, 38, 56, 126, 164, 209, 197, 185, 201, 87, 203,
+                80, 60, 84, 156, 223, 60, 235, 28, 116, 238, 114, 130, 10, 1,
+            ],
+            [
+                169, 168, 71, 233, 233, 40, 66, 76, 198, 11, 144, 113, 100, 137, 31, 197, 74, 209,
+                228, 169, 194, 168, 162, 31, 228, 192, 220, 140, 189, 60, 136, 14,
+            ],
+        ],
+        [
+            [
+                231, 1, 218, 251, 100, 243, 36, 25, 96, 125, 205, 52, 74, 67, 23, 238, 59, 110, 86,
+                29, 187, 79, 38, 134, 195, 220, 129, 58, 250, 220, 176, 63,
+            ],
+            [
+                141, 112, 248, 222, 142, 19, 206, 43, 30, 228, 13, 132, 154, 26, 135, 164, 219, 26,
+                166, 163, 87, 51, 37, 4, 19, 21, 218, 96, 220, 131, 213, 0,
+            ],
+            [
+                115, 133, 166, 114, 182, 157, 221, 187, 11, 104, 161, 8, 90, 192, 201, 106, 8, 141,
+                127, 139, 18, 210, 179, 207, 110, 204, 169, 190, 105, 117, 128, 45,
+            ],
+            [
+                159, 164, 217, 120, 114, 103, 212, 34, 189, 177, 146, 19, 127, 117, 56, 208, 109,
+                135, 230, 254, 60, 114, 232, 99, 208, 129, 33, 32, 117, 110, 224, 60,
+            ],
+            [
+                132, 123, 179, 40, 75, 89, 187, 89, 249, 42, 8, 232, 107, 253, 7, 205, 122, 248,
+                30, 224, 144, 231, 28, 176, 177, 26, 131, 98, 110, 141, 2, 53,
+            ],
+            [
+                211, 38, 172, 48, 164, 65, 140, 29, 52, 144, 14, 190, 2, 98, 74, 251, 183, 68, 26,
+                158, 32, 100, 113, 226, 146, 161, 218, 96, 44, 53, 231, 62,
+            ],
+            [
+                12, 191, 164, 30, 175, 35, 223, 163, 174, 126, 229, 19, 8, 155, 119, 130, 17, 195,
+                75, 102, 128, 57, 52, 140, 102, 138, 220, 4, 81, 19, 18, 21,
+            ],
+            [
+                193, 243, 92, 67, 163, 87, 70, 131, 46, 86, 114, 177, 210, 154, 67, 229, 58, 78,
+                211, 186, 255, 227, 232, 113, 64, 178, 135, 180, 11, 240, 169, 22,
+            ],
+        ],
+        [
+            [
+                199, 158, 218, 235, 131, 137, 226, 214, 136, 83, 234, 128, 64, 65, 123, 53, 94, 55,
+                49, 68, 116, 96, 4, 160, 197, 109, 176, 69, 225, 165, 214, 63,
+            ],
+            [
+                190, 202, 188, 69, 168, 45, 160, 102, 91, 251, 219, 131, 173, 168, 47, 72, 90, 244,
+                4, 131, 58, 64, 90, 76, 12, 34, 176, 100, 143, 4, 123, 52,
+            ],
+            [
+                197, 182, 80, 131, 68, 84, 82, 39, 93, 4, 223, 181, 187, 127, 30, 169, 119, 200,
+                241, 182, 177, 9, 168, 246, 78, 82, 103, 54, 96, 116, 208, 34,
+            ],
+            [
+                6, 12, 217, 88, 28, 8, 203, 210, 147, 138, 175, 32, 27, 144, 88, 190, 158, 193, 61,
+                171, 70, 8, 225, 133, 39, 125, 64, 169, 246, 150, 135, 26,
+            ],
+            [
+                135, 155, 107, 141, 199, 50, 219, 116, 225, 244, 89, 192, 234, 149, 61, 244, 3, 38,
+                221, 53, 215, 221, 140, 178, 150, 101, 153, 113, 97, 158, 35, 46,
+            ],
+            [
+                221, 51, 244, 255, 189, 175, 252, 135, 220, 31, 72, 112, 83, 174, 0, 13, 152, 174,
+                84, 91, 237, 21, 231, 18, 90, 185, 243, 152, 244, 135, 83, 21,
+            ],
+            [
+                165, 75, 59, 8, 2, 141, 134, 34, 131, 128, 135, 39, 254, 198, 201, 44, 242, 191,
+                116, 59, 241, 96, 138, 145, 229, 231, 67, 5, 180, 152, 239, 44,
+            ],
+            [
+                47, 139, 206, 223, 77, 120, 198, 229, 176, 67, 203, 109, 139, 137, 28, 78, 223,
+                212, 180, 132, 148, 6, 121, 120, 130, 32, 73, 201, 14, 222, 90, 11,
+            ],
+        ],
+        [
+            [
+                176, 94, 23, 156, 190, 244, 83, 165, 111, 102, 251, 195, 24, 92, 87, 1, 90, 3, 187,
+                155, 168, 149, 84, 30, 237, 166, 236, 165, 251, 145, 202, 25,
+            ],
+            [
+                117, 14, 219, 101, 243, 5, 50, 113, 1, 17, 67, 191, 140, 12, 161, 111, 236, 125,
+                63, 208, 130, 190, 238, 231, 208, 81, 119, 107, 222, 21, 188, 49,
+            ],
+            [
+                103, 245, 253, 92, 241, 153, 146, 0, 243, 177, 114, 112, 135, 223, 132, 11, 88, 82,
+                85, 126, 107, 168, 220, 239, 111, 110, 160, 124, 190, 56, 209, 13,
+            ],
+            [
+                182, 168, 222, 247, 153, 200, 39, 162, 147, 4, 255, 178, 101, 222, 93, 71, 32, 29,
+                92, 95, 181, 241, 73, 81, 196, 247, 219, 93, 65, 184, 6, 39,
+            ],
+            [
+                223, 195, 37, 10, 230, 165, 145, 91, 116, 8, 56, 155, 71, 114, 118, 80, 78, 175,
+                136, 4, 249, 59, 6, 22, 3, 60, 50, 53, 232, 109, 183, 8,
+            ],
+            [
+                85, 180, 240, 194, 7, 175, 74, 118, 55, 170, 151, 179, 169, 169, 227, 210, 55, 185,
+                35, 203, 189, 218, 172, 171, 51, 208, 5, 53, 77, 225, 85, 12,
+            ],
+            [
+                217, 182, 105, 26, 73, 182, 169, 225, 227, 173, 186, 234, 72, 146, 102, 139, 200,
+                214, 238, 210, 65, 141, 137, 109, 199, 225, 0, 76, 23, 161, 82, 41,
+            ],
+            [
+                216, 152, 220, 114, 239, 228, 236, 93, 165, 106, 62, 61, 127, 195, 114, 216, 205,
+                152, 130, 253, 194, 108, 98, 174, 191, 165, 103, 126, 82, 121, 12, 46,
+            ],
+        ],
+        [
+            [
+                92, 73, 168, 249, 125, 89, 100, 131, 5, 249, 108, 28, 42, 0, 229, 225, 64, 58, 190,
+                63, 90, 67, 22, 22, 36, 179, 49, 221, 127, 88, 2, 21,
+            ],
+            [
+                64, 138, 97, 193, 98, 151, 227, 1, 9, 136, 210, 229, 242, 27, 134, 185, 134, 218,
+                63, 231, 186, 211, 101, 157, 72, 255, 157, 136, 80, 210, 79, 31,
+            ],
+            [
+                119, 170, 157, 94, 14, 221, 48, 252, 12, 11, 155, 181, 47, 89, 135, 84, 233, 181,
+                172, 37, 238, 82, 43, 110, 115, 37, 199, 39, 217, 112, 158, 29,
+            ],
+            [
+                246, 32, 71, 152, 86, 19, 52, 149, 145, 100, 236, 28, 195, 73, 217, 64, 42, 72,
+                194, 65, 179, 238, 97, 118, 154, 121, 37, 242, 71, 38, 185, 26,
+            ],
+            [
+                229, 95, 116, 238, 113, 8, 196, 61, 56, 241, 18, 168, 55, 50, 7, 110, 71, 110, 186,
+                138, 170, 253, 213, 78, 36, 182, 29, 67, 172, 173, 73, 52,
+            ],
+            [
+                21, 38, 147, 52, 213, 147, 219, 79, 165, 129, 111, 125, 164, 64, 30, 233, 153, 36,
+                90, 27, 184, 174, 70, 249, 130, 227, 139, 236, 202, 111, 27, 27,
+            ],
+            [
+                224, 198, 15, 37, 203, 159, 75, 58, 224, 130, 9, 169, 2, 89, 141, 76, 233, 93, 5,
+                240, 42, 99, 63, 255, 178, 6, 31, 240, 56, 166, 158, 32,
+            ],
+            [
+                211, 86, 224, 80, 211, 106, 58, 207, 236, 85, 125, 0, 135, 147, 59, 125, 84, 246,
+                159, 152, 93, 203, 225, 64, 167, 157, 24, 203, 151, 43, 171, 7,
+            ],
+        ],
+        [
+            [
+                176, 125, 60, 137, 3, 160, 240, 145, 14, 137, 142, 137, 160, 133, 162, 145, 74, 49,
+                140, 150, 44, 158, 62, 59, 212, 252, 5, 209, 48, 5, 157, 39,
+            ],
+            [
+                44, 195, 125, 53, 57, 55, 173, 254, 82, 242, 92, 170, 180, 52, 198, 91, 35, 11, 81,
+                10, 184, 88, 28, 37, 46, 109, 104, 251, 240, 146, 229, 27,
+            ],
+            [
+                11, 31, 11, 89, 123, 234, 228, 253, 123, 74, 142, 218, 220, 103, 167, 233, 103,
+                226, 216, 248, 77, 203, 156, 242, 241, 0, 81, 124, 85, 115, 150, 2,
+            ],
+            [
+                189, 167, 44, 33, 75, 76, 89, 248, 36, 64, 147, 155, 145, 202, 77, 226, 201, 40,
+                226, 105, 85, 24, 163, 101, 194, 177, 47, 173, 216, 157, 134, 27,
+            ],
+            [
+                154, 253, 84, 212, 173, 147, 225, 174, 239, 149, 76, 0, 128, 2, 136, 145, 45, 198,
+                100, 187, 79, 69, 183, 193, 30, 137, 236, 88, 56, 23, 31, 49,
+            ],
+            [
+                227, 30, 193, 5, 201, 149, 214, 191, 82, 103, 248, 242, 105, 237, 30, 127, 4, 178,
+                188, 89, 120, 75, 28, 138, 22, 124, 177, 182, 14, 189, 21, 16,
+            ],
+            [
+                148, 104, 75, 176, 74, 169, 171, 181, 52, 15, 82, 7, 212, 97, 8, 252, 157, 84, 115,
+                201, 41, 76, 179, 93, 175, 221, 148, 166, 126, 146, 15, 30,
+            ],
+            [
+                11, 141, 114, 86, 216, 131, 212, 120, 26, 88, 200, 193, 226, 22, 252, 86, 34, 8,
+                195, 108, 169, 211, 254, 90, 146, 101, 225, 186, 177, 213, 136, 2,
+            ],
+        ],
+        [
+            [
+                230, 113, 37, 225, 167, 72, 92, 32, 207, 67, 65, 94, 120, 167, 138, 52, 91, 66,
+                152, 77, 134, 250, 117, 79, 80, 177, 18, 52, 9, 194, 186, 59,
+            ],
+            [
+                21, 51, 103, 96, 223, 59, 162, 57, 42, 9, 133, 211, 89, 195, 233, 64, 251, 254, 48,
+                203, 93, 20, 131, 197, 186, 108, 164, 197, 12, 124, 192, 58,
+            ],
+            [
+                27, 63, 241, 253, 29, 249, 148, 150, 164, 28, 135, 186, 128, 200, 104, 174, 103,
+                114, 183, 30, 30, 140, 219, 217, 239, 136, 108, 179, 2, 35, 138, 16,
+            ],
+            [
+                39, 128, 167, 190, 64, 246, 109, 149, 50, 67, 180, 192, 74, 202, 117, 45, 72, 110,
+                115, 150, 70, 177, 239, 217, 46, 129, 46, 184, 52, 127, 80, 11,
+            ],
+            [
+                226, 82, 142, 84, 204, 205, 41, 41, 163, 135, 30, 82, 179, 145, 185, 133, 31, 150,
+                7, 179, 71, 237, 29, 251, 186, 36, 48, 130, 85, 1, 57, 46,
+            ],
+            [
+                140, 178, 192, 109, 51, 229, 86, 104, 150, 191, 58, 167, 230, 10, 138, 42, 212, 75,
+                254, 217, 145, 181, 25, 12, 243, 198, 49, 42, 214, 239, 52, 12,
+            ],
+            [
+                165, 214, 154, 109, 172, 99, 31, 184, 101, 104, 60, 25, 33, 248, 97, 14, 254, 37,
+                175, 1, 140, 149, 30, 3, 115, 193, 245, 10, 42, 107, 159, 62,
+            ],
+            [
+                198, 24, 138, 175, 206, 51, 194, 23, 119, 92, 224, 253, 109, 55, 254, 79, 161, 216,
+                199, 88, 214, 68, 185, 139, 253, 42, 53, 245, 123, 63, 18, 4,
+            ],
+        ],
+        [
+            [
+                152, 194, 228, 253, 156, 226, 17, 160, 100, 241, 145, 75, 29, 246, 245, 181, 211,
+                137, 214, 7, 55, 51, 60, 74, 66, 180, 82, 224, 122, 116, 21, 11,
+            ],
+            [
+                62, 26, 136, 207, 189, 177, 67, 18, 32, 164, 146, 68, 116, 42, 35, 140, 41, 236,
+                128, 64, 156, 198, 220, 44, 187, 103, 209, 241, 205, 207, 143, 30,
+            ],
+            [
+                10, 181, 24, 82, 97, 232, 121, 238, 59, 240, 169, 189, 252, 187, 36, 49, 236, 74,
+                145, 253, 36, 22, 241, 24, 20, 30, 7, 67, 172, 192, 30, 32,
+            ],
+            [
+                26, 68, 118, 74, 94, 36, 48, 26, 175, 99, 216, 237, 30, 193, 52, 248, 173, 1, 82,
+                203, 18, 157, 253, 225, 175, 137, 1, 28, 6, 213, 71, 52,
+            ],
+            [
+                246, 83, 15, 151, 65, 233, 59, 236, 123, 78, 51, 80, 78, 239, 48, 74, 199, 120, 44,
+                180, 212, 78, 109, 131, 246, 192, 232, 40, 28, 152, 223, 35,
+            ],
+            [
+                6, 68, 86, 198, 112, 131, 49, 248, 22, 40, 84, 98, 74, 8, 34, 240, 111, 160, 92,
+                48, 33, 165, 4, 186, 184, 67, 248, 52, 136, 81, 86, 52,
+            ],
+            [
+                172, 144, 205, 162, 136, 235, 87, 190, 222, 184, 16, 144, 22, 6, 96, 7, 87, 16,
+                132, 106, 42, 175, 32, 57, 40, 75, 13, 139, 201, 151, 110, 56,
+            ],
+            [
+                0, 184, 88, 9, 64, 25, 9, 136, 90, 3, 126, 238, 72, 24, 197, 63, 210, 218, 12, 21,
+                216, 139, 156, 51, 22, 88, 118, 50, 60, 240, 207, 33,
+            ],
+        ],
+        [
+            [
+                96, 124, 115, 180, 113, 209, 232, 30, 184, 94, 58, 25, 121, 64, 5, 45, 149, 50,
+                170, 61, 85, 195, 181, 68, 115, 15, 21, 97, 5, 164, 10, 46,
+            ],
+            [
+                234, 228, 195, 77, 136, 4, 198, 92, 65, 90, 205, 40, 36, 31, 27, 97, 222, 122, 92,
+                65, 52, 44, 93, 209, 185, 95, 151, 237, 252, 60, 241, 33,
+            ],
+            [
+                119, 177, 189, 20, 241, 199, 111, 229, 68, 132, 41, 185, 223, 190, 159, 135, 56,
+                19, 24, 139, 131, 77, 57, 189, 207, 141, 241, 55, 1, 152, 225, 27,
+            ],
+            [
+                179, 193, 118, 210, 172, 143, 81, 190, 217, 183, 136, 140, 99, 110, 188, 141, 108,
+                94, 42, 59, 71, 15, 230, 94, 36, 12, 125, 15, 129, 143, 233, 41,
+            ],
+            [
+                153, 149, 56, 134, 79, 173, 39, 115, 171, 196, 235, 236, 193, 155, 92, 67, 21, 123,
+                43, 147, 104, 175, 213, 26, 38, 214, 2, 3, 219, 131, 140, 16,
+            ],
+            [
+                124, 13, 55, 183, 65, 46, 77, 65, 126, 212, 80, 255, 90, 125, 169, 55, 232, 32,
+                228, 12, 91, 128, 204, 233, 31, 24, 42, 228, 202, 191, 163, 35,
+            ],
+            [
+                64, 39, 44, 195, 168, 181, 10, 207, 186, 161, 252, 79, 193, 232, 109, 181, 11, 23,
+                215, 146, 223, 250, 26, 57, 140, 58, 207, 165, 246, 118, 25, 3,
+            ],
+            [
+                110, 155, 235, 82, 151, 65, 243, 203, 15, 32, 162, 175, 47, 164, 38, 120, 36, 231,
+                99, 98, 253, 149, 133, 192, 133, 161, 131, 231, 210, 227, 28, 53,
+            ],
+        ],
+        [
+            [
+                72, 134, 102, 134, 122, 93, 11, 47, 24, 36, 238, 47, 103, 217, 236, 109, 61, 97,
+                115, 20, 112, 170, 3, 46, 216, 82, 92, 82, 32, 103, 211, 59,
+            ],
+            [
+                196, 17, 34, 62, 164, 138, 194, 11, 11, 116, 152, 148, 103, 173, 236, 49, 121, 69,
+                54, 72, 32, 68, 230, 109, 27, 182, 57, 194, 216, 67, 135, 13,
+            ],
+            [
+                116, 114, 135, 128, 177, 140, 138, 125, 28, 186, 87, 154, 30, 93, 233, 140, 27,
+                204, 149, 150, 140, 112, 110, 111, 177, 57, 32, 107, 101, 161, 129, 34,
+            ],
+            [
+                185, 35, 118, 175, 36, 152, 222, 91, 241, 250, 109, 19, 195, 17, 113, 226, 196,
+                153, 180, 7, 94, 140, 98, 107, 201, 56, 241, 140, 190, 168, 72, 11,
+            ],
+            [
+                9, 68, 244, 56, 224, 116, 171, 57, 81, 61, 247, 226, 187, 128, 175, 252, 156, 1,
+                30, 183, 168, 220, 139, 101, 55, 36, 30, 2, 242, 160, 28, 61,
+            ],
+            [
+                108, 97, 87, 227, 200, 6, 74, 50, 93, 233, 195, 119, 254, 222, 147, 236, 30, 191,
+                217, 143, 172, 33, 121, 116, 183, 189, 7, 141, 119, 137, 212, 41,
+            ],
+            [
+                250, 183, 167, 199, 162, 131, 23, 54, 132, 36, 0, 240, 98, 179, 240, 131, 50, 207,
+                193, 126, 188, 158, 246, 8, 241, 101, 190, 71, 146, 156, 24, 54,
+            ],
+            [
+                39, 32, 136, 178, 13, 181, 235, 146, 169, 82, 94, 37, 166, 98, 224, 141, 75, 154,
+                118, 55, 253, 239, 64, 134, 22, 55, 172, 252, 238, 185, 9, 2,
+            ],
+        ],
+        [
+            [
+                191, 43, 172, 192, 162, 251, 98, 152, 167, 119, 14, 26, 253, 27, 74, 219, 103, 76,
+                39, 144, 181, 7, 175, 126, 147, 5, 174, 117, 91, 96, 9, 42,
+            ],
+            [
+                212, 25, 109, 239, 149, 233, 105, 25, 149, 67, 38, 136, 13, 130, 23, 201, 220, 22,
+                74, 39, 166, 213, 157, 227, 124, 65, 244, 213, 82, 54, 179, 5,
+            ],
+            [
+                47, 249, 7, 154, 113, 156, 207, 192, 51, 59, 198, 98, 102, 25, 240, 53, 46, 25, 67,
+                58, 43, 43, 5, 225, 117, 207, 131, 151, 229, 253, 22, 34,
+            ],
+            [
+                39, 197, 160, 155, 180, 26, 138, 141, 146, 39, 175, 40, 212, 105, 214, 188, 138,
+                105, 254, 219, 80, 57, 163, 6, 8, 88, 10, 124, 72, 30, 189, 56,
+            ],
+            [
+                172, 50, 80, 251, 241, 194, 237, 47, 100, 60, 80, 223, 164, 33, 229, 60, 7, 63,
+                171, 165, 127, 128, 19, 95, 207, 213, 75, 90, 36, 125, 226, 20,
+            ],
+            [
+                114, 92, 221, 84, 178, 210, 173, 226, 186, 227, 1, 122, 242, 215, 170, 51, 110,
+                171, 248, 66, 217, 34, 97, 62, 150, 108, 250, 233, 22, 228, 37, 46,
+            ],
+            [
+                183, 26, 12, 2, 237, 37, 33, 208, 96, 238, 220, 162, 130, 236, 200, 95, 139, 221,
+                191, 198, 180, 124, 173, 138, 207, 246, 91, 159, 212, 3, 111, 31,
+            ],
+            [
+                155, 103, 145, 224, 204, 178, 75, 141, 222, 40, 197, 217, 167, 209, 157, 222, 84,
+                137, 82, 194, 193, 163, 117, 219, 96, 150, 204, 142, 120, 205, 162, 50,
+            ],
+        ],
+        [
+            [
+                41, 81, 35, 132, 134, 45, 189, 206, 147, 207, 0, 22, 21, 86, 7, 208, 147, 105, 213,
+                209, 12, 100, 179, 218, 4, 136, 45, 68, 116, 109, 15, 7,
+            ],
+            [
+                121, 102, 37, 6, 225, 139, 100, 22, 66, 105, 69, 59, 218, 189, 6, 21, 154, 236,
+                156, 85, 188, 49, 17, 60, 72, 174, 56, 149, 224, 85, 29, 26,
+            ],
+            [
+                109, 67, 201, 45, 252, 216, 100, 17, 120, 218, 255, 58, 186, 105, 84, 164, 158, 5,
+                77, 203, 136, 93, 141, 34, 85, 153, 77, 205, 5, 111, 52, 61,
+            ],
+            [
+                189, 78, 162, 148, 2, 185, 8, 161, 164, 175, 235, 140, 119, 245, 29, 36, 57, 94,
+                77, 105, 21, 254, 66, 131, 92, 97, 232, 29, 8, 88, 237, 56,
+            ],
+            [
+                211, 139, 115, 176, 61, 31, 101, 164, 241, 192, 100, 61, 154, 7, 3, 33, 14, 36,
+                255, 91, 123, 102, 152, 60, 104, 216, 242, 83, 207, 228, 233, 30,
+            ],
+            [
+                130, 150, 154, 24, 244, 100, 87, 163, 156, 189, 116, 36, 16, 199, 152, 8, 130, 69,
+                208, 200, 72, 153, 251, 3, 236, 44, 204, 2, 3, 167, 54, 3,
+            ],
+            [
+                142, 55, 195, 142, 241, 200, 203, 143, 22, 70, 215, 220, 200, 179, 17, 193, 107,
+                156, 128, 169, 86, 2, 68, 212, 52, 43, 166, 237, 138, 78, 46, 24,
+            ],
+            [
+                62, 133, 16, 46, 54, 53, 103, 168, 31, 135, 209, 109, 226, 197, 33, 205, 160, 73,
+                39, 241, 111, 230, 67, 149, 137, 123, 72, 113, 104, 134, 169, 45,
+            ],
+        ],
+        [
+            [
+                130, 162, 50, 217, 180, 184, 114, 15, 128, 69, 156, 75, 181, 142, 62, 225, 131, 74,
+                49, 38, 97, 196, 77, 20, 105, 196, 111, 224, 236, 8, 210, 14,
+            ],
+            [
+                41, 239, 62, 135, 52, 212, 51, 34, 132, 123, 219, 112, 44, 88, 192, 83, 27, 45,
+                229, 198, 43, 203, 193, 237, 218, 198, 199, 42, 178, 151, 178, 3,
+            ],
+            [
+                70, 205, 16, 155, 32, 194, 56, 133, 78, 35, 67, 178, 174, 165, 11, 50, 148, 173,
+                225, 137, 43, 255, 121, 43, 4, 60, 104, 250, 62, 96, 224, 23,
+            ],
+            [
+                178, 204, 192, 76, 236, 152, 41, 74, 18, 67, 134, 217, 226, 29, 132, 169, 241, 70,
+                66, 169, 109, 35, 77, 39, 233, 192, 246, 50, 70, 40, 128, 7,
+            ],
+            [
+                81, 233, 255, 67, 181, 115, 37, 240, 249, 94, 1, 189, 34, 119, 46, 136, 174, 28,
+                221, 147, 89, 219, 63, 24, 179, 80, 46, 249, 116, 130, 223, 29,
+            ],
+            [
+                40, 141, 110, 174, 242, 98, 76, 206, 200, 40, 85, 127, 248, 7, 61, 110, 225, 135,
+                37, 20, 142, 134, 57, 4, 168, 26, 79, 183, 169, 61, 38, 48,
+            ],
+            [
+                16, 195, 146, 12, 121, 215, 58, 238, 13, 133, 50, 19, 189, 154, 162, 200, 6, 195,
+                163, 134, 25, 188, 239, 112, 75, 126, 216, 245, 32, 142, 56, 28,
+            ],
+            [
+                84, 204, 125, 78, 37, 6, 92, 96, 243, 3, 13, 179, 98, 5, 221, 47, 108, 51, 190,
+                235, 77, 33, 217, 185, 162, 64, 70, 165, 1, 4, 206, 55,
+            ],
+        ],
+        [
+            [
+                242, 120, 67, 81, 160, 156, 107, 45, 154, 80, 168, 4, 43, 133, 43, 90, 93, 143,
+                208, 213, 56, 89, 244, 111, 48, 74, 38, 210, 228, 49, 20, 15,
+            ],
+            [
+                207, 146, 51, 68, 237, 230, 109, 127, 64, 22, 12, 95, 67, 217, 31, 29, 167, 1, 133,
+                225, 31, 170, 205, 204, 223, 53, 140, 229, 70, 48, 190, 32,
+            ],
+            [
+                237, 202, 36, 247, 160, 44, 204, 207, 231, 164, 21, 245, 224, 156, 255, 85, 154,
+                65, 136, 166, 218, 95, 60, 118, 141, 225, 29, 130, 194, 88, 100, 19,
+            ],
+            [
+                251, 56, 161, 62, 133, 99, 102, 65, 210, 167, 182, 45, 47, 127, 74, 26, 244, 219,
+                182, 3, 114, 237, 92, 47, 206, 70, 252, 176, 220, 9, 84, 46,
+            ],
+            [
+                108, 238, 78, 196, 12, 149, 6, 19, 118, 215, 9, 212, 66, 151, 202, 155, 46, 58, 41,
+                66, 218, 49, 10, 47, 83, 252, 46, 175, 93, 81, 18, 42,
+            ],
+            [
+                217, 213, 113, 17, 217, 166, 68, 9, 202, 152, 74, 36, 119, 106, 3, 161, 129, 221,
+                16, 47, 8, 155, 118, 23, 83, 34, 103, 242, 80, 85, 251, 60,
+            ],
+            [
+                139, 89, 226, 34, 187, 93, 189, 128, 99, 94, 59, 1, 85, 180, 89, 76, 229, 192, 83,
+                197, 216, 171, 41, 194, 155, 110, 58, 124, 93, 112, 156, 3,
+            ],
+            [
+                160, 225, 58, 143, 69, 229, 203, 250, 82, 112, 169, 186, 197, 117, 199, 175, 160,
+                116, 9, 113, 170, 180, 13, 130, 159, 114, 37, 42, 67, 116, 213, 29,
+            ],
+        ],
+        [
+            [
+                151, 33, 84, 118, 133, 51, 153, 151, 19, 8, 194, 238, 234, 50, 114, 24, 223, 155,
+                51, 37, 243, 91, 147, 95, 218, 130, 219, 221, 73, 164, 15, 20,
+            ],
+            [
+                140, 244, 154, 138, 2, 34, 28, 106, 206, 85, 229, 238, 1, 211, 65, 89, 39, 235,
+                199, 174, 111, 6, 147, 166, 86, 250, 73, 147, 141, 218, 157, 45,
+            ],
+            [
+                125, 162, 149, 165, 62, 74, 42, 187, 125, 129, 109, 152, 201, 35, 81, 241, 38, 206,
+                132, 23, 27, 150, 118, 98, 120, 133, 144, 97, 248, 236, 42, 51,
+            ],
+            [
+                81, 140, 212, 97, 232, 128, 232, 254, 131, 115, 239, 136, 217, 221, 197, 17, 248,
+                20, 176, 34, 156, 166, 133, 182, 83, 238, 228, 113, 168, 34, 132, 9,
+            ],
+            [
+                153, 11, 221, 142, 23, 121, 206, 247, 199, 48, 207, 194, 212, 49, 172, 228, 138,
+                42, 243, 71, 142, 141, 37, 62, 188, 130, 153, 73, 97, 86, 235, 45,
+            ],
+            [
+                203, 99, 179, 170, 167, 170, 161, 255, 87, 215, 158, 10, 47, 125, 163, 251, 95, 86,
+                75, 220, 97, 239, 237, 245, 179, 191, 16, 102, 5, 96, 165, 41,
+            ],
+            [
+                243, 73, 158, 196, 175, 218, 28, 81, 147, 129, 216, 149, 20, 123, 72, 219, 42, 6,
+                152, 174, 45, 164, 247, 169, 163, 123, 131, 13, 167, 34, 39, 41,
+            ],
+            [
+                215, 165, 86, 198, 229, 58, 26, 73, 37, 50, 211, 67, 133, 251, 92, 51, 97, 216,
+                242, 172, 48, 253, 143, 104, 141, 28, 176, 198, 242, 140, 3, 59,
+            ],
+        ],
+        [
+            [
+                213, 119, 159, 93, 51, 108, 48, 97, 189, 222, 164, 45, 129, 139, 41, 34, 3, 155,
+                140, 128, 222, 130, 110, 204, 103, 211, 53, 249, 167, 117, 129, 52,
+            ],
+            [
+                24, 254, 157, 1, 16, 65, 141, 159, 107, 21, 125, 14, 41, 43, 37, 75, 87, 230, 230,
+                187, 11, 249, 158, 250, 124, 106, 45, 5, 27, 7, 112, 36,
+            ],
+            [
+                11, 49, 240, 216, 168, 188, 185, 31, 112, 53, 97, 230, 4, 202, 40, 207, 225, 232,
+                249, 115, 249, 157, 166, 74, 104, 71, 52, 157, 26, 226, 200, 44,
+            ],
+            [
+                157, 27, 13, 219, 167, 8, 34, 255, 208, 223, 9, 50, 159, 39, 101, 127, 136, 166,
+                17, 86, 178, 235, 47, 51, 58, 20, 199, 178, 73, 135, 46, 53,
+            ],
+            [
+                215, 17, 45, 193, 64, 178, 6, 78, 203, 249, 240, 147, 37, 215, 225, 155, 138, 71,
+                80, 187, 197, 124, 75, 77, 105, 243, 213, 230, 230, 109, 185, 62,
+            ],
+            [
+                160, 181, 28, 146, 229, 26, 138, 12, 131, 40, 247, 160, 218, 44, 105, 29, 242, 102,
+                3, 92, 183, 104, 5, 164, 249, 204, 31, 157, 55, 163, 85, 61,
+            ],
+            [
+                172, 36, 160, 186, 28, 250, 111, 45, 17, 226, 121, 111, 175, 203, 146, 126, 199, 3,
+                235, 28, 247, 65, 192, 132, 201, 172, 99, 245, 108, 175, 69, 63,
+            ],
+            [
+                68, 120, 187, 47, 186, 85, 166, 116, 115, 75, 37, 96, 201, 119, 189, 1, 191, 215,
+                3, 56, 247, 42, 49, 41, 174, 110, 159, 101, 117, 128, 74, 10,
+            ],
+        ],
+        [
+            [
+                32, 37, 59, 188, 161, 21, 22, 128, 152, 186, 18, 224, 55, 20, 89, 119, 34, 228, 25,
+                97, 196, 56, 31, 228, 238, 17, 39, 17, 115, 121, 133, 38,
+            ],
+            [
+                228, 12, 131, 1, 134, 80, 179, 244, 193, 45, 254, 241, 221, 205, 120, 204, 173, 28,
+                68, 34, 112, 88, 132, 76, 220, 183, 85, 34, 38, 239, 223, 21,
+            ],
+            [
+                152, 20, 79, 52, 154, 27, 114, 218, 6, 78, 241, 105, 176, 191, 45, 216, 72, 227,
+                183, 55, 189, 139, 181, 125, 145, 107, 214, 157, 114, 32, 253, 0,
+            ],
+            [
+                218, 175, 87, 154, 98, 193, 255, 189, 144, 185, 155, 81, 187, 201, 237, 60, 198,
+                174, 91, 203, 252, 232, 205, 177, 44, 177, 109, 12, 31, 162, 8, 8,
+            ],
+            [
+                156, 99, 122, 248, 52, 186, 8, 54, 167, 93, 112, 218, 186, 52, 189, 142, 11, 241,
+                47, 42, 45, 132, 175, 123, 112, 245, 175, 83, 184, 70, 69, 54,
+            ],
+            [
+                7, 46, 23, 126, 81, 240, 23, 216, 120, 21, 18, 187, 184, 19, 127, 105, 133, 243,
+                56, 27, 174, 166, 77, 72, 88, 143, 78, 241, 27, 222, 196, 34,
+            ],
+            [
+                201, 174, 253, 246, 254, 231, 67, 112, 66, 66, 124, 8, 194, 31, 61, 93, 227, 16,
+                126, 109, 36, 215, 206, 78, 70, 30, 78, 159, 29, 248, 110, 8,
+            ],
+            [
+                66, 115, 105, 183, 161, 29, 243, 25, 29, 36, 61, 139, 57, 236, 120, 68, 36, 233,
+                73, 251, 114, 225, 221, 91, 121, 64, 199, 80, 48, 137, 202, 23,
+            ],
+        ],
+        [
+            [
+                74, 4, 58, 104, 134, 161, 106, 143, 73, 184, 126, 206, 189, 213, 224, 83, 253, 211,
+                56, 192, 2, 166, 235, 186, 143, 55, 77, 113, 183, 40, 246, 15,
+            ],
+            [
+                47, 233, 125, 210, 173, 60, 7, 207, 84, 103, 89, 148, 166, 171, 193, 252, 39, 41,
+                51, 185, 75, 2, 66, 7, 26, 98, 224, 243, 43, 137, 133, 11,
+            ],
+            [
+                110, 164, 1, 106, 92, 70, 223, 151, 10, 29, 54, 73, 14, 201, 81, 147, 249, 60, 5,
+                107, 36, 110, 109, 5, 140, 139, 211, 100, 123, 125, 128, 61,
+            ],
+            [
+                112, 211, 50, 206, 193, 72, 67, 194, 32, 161, 145, 67, 38, 138, 90, 237, 82, 57,
+                81, 69, 90, 9, 51, 230, 213, 158, 159, 26, 55, 132, 114, 33,
+            ],
+            [
+                233, 82, 154, 29, 12, 151, 35, 149, 93, 29, 128, 137, 159, 206, 1, 171, 231, 20,
+                243, 138, 89, 3, 199, 229, 15, 173, 87, 6, 197, 62, 159, 63,
+            ],
+            [
+                232, 112, 87, 116, 194, 155, 168, 245, 42, 27, 203, 238, 154, 123, 49, 81, 251, 3,
+                176, 88, 157, 7, 109, 27, 238, 146, 223, 120, 248, 234, 215, 15,
+            ],
+            [
+                49, 211, 203, 136, 88, 126, 46, 52, 121, 173, 6, 89, 173, 25, 64, 29, 49, 168, 186,
+                106, 21, 236, 90, 115, 92, 48, 166, 219, 209, 243, 65, 18,
+            ],
+            [
+                79, 154, 140, 202, 149, 241, 156, 219, 115, 183, 35, 250, 105, 63, 123, 82, 222,
+                88, 76, 2, 97, 249, 23, 124, 240, 111, 6, 91, 98, 224, 242, 53,
+            ],
+        ],
+        [
+            [
+                183, 45, 211, 222, 74, 132, 25, 115, 93, 4, 144, 75, 154, 30, 247, 13, 196, 33, 88,
+                243, 230, 37, 251, 181, 82, 229, 22, 234, 112, 116, 106, 44,
+            ],
+            [
+                207, 104, 144, 244, 111, 6, 235, 70, 177, 44, 192, 138, 232, 166, 41, 98, 10, 209,
+                45, 87, 234, 4, 2, 14, 4, 145, 37, 179, 130, 204, 121, 32,
+            ],
+            [
+                120, 55, 89, 114, 146, 201, 146, 112, 185, 26, 38, 16, 190, 228, 34, 75, 106, 102,
+                67, 154, 98, 45, 8, 72, 120, 74, 140, 6, 180, 165, 4, 13,
+            ],
+            [
+                249, 181, 229, 64, 12, 100, 35, 57, 132, 102, 30, 44, 9, 159, 196, 73, 71, 206, 32,
+                87, 194, 197, 235, 174, 182, 50, 198, 93, 138, 130, 85, 52,
+            ],
+            [
+                239, 116, 216, 98, 178, 147, 232, 209, 74, 249, 52, 78, 253, 48, 206, 150, 140,
+                120, 100, 240, 44, 52, 22, 232, 187, 161, 79, 47, 218, 94, 30, 32,
+            ],
+            [
+                25, 127, 48, 49, 94, 111, 34, 90, 30, 253, 235, 45, 56, 69, 67, 74, 132, 171, 203,
+                116, 6, 14, 109, 106, 247, 135, 155, 129, 185, 35, 183, 35,
+            ],
+            [
+                133, 49, 86, 91, 248, 44, 78, 35, 18, 56, 167, 29, 154, 169, 166, 86, 141, 133,
+                213, 105, 243, 213, 163, 155, 53, 27, 4, 175, 13, 234, 125, 4,
+            ],
+            [
+                142, 153, 138, 211, 103, 219, 6, 233, 206, 158, 17, 91, 115, 243, 255, 210, 247,
+                93, 172, 113, 175, 142, 192, 222, 105, 193, 157, 228, 191, 155, 77, 23,
+            ],
+        ],
+        [
+            [
+                155, 64, 201, 109, 237, 205, 211, 194, 162, 234, 169, 133, 74, 203, 3, 14, 87, 108,
+                104, 142, 21, 164, 22, 121, 38, 70, 63, 166, 56, 125, 55, 38,
+            ],
+            [
+                176, 77, 85, 29, 125, 221, 227, 102, 50, 216, 111, 120, 139, 81, 7, 122, 194, 83,
+                98, 106, 223, 145, 177, 200, 73, 109, 243, 220, 239, 177, 184, 21,
+            ],
+            [
+                205, 109, 34, 30, 89, 46, 226, 47, 115, 140, 182, 0, 124, 160, 242, 41, 37, 120,
+                228, 125, 187, 14, 22, 3, 128, 228, 46, 100, 86, 10, 246, 63,
+            ],
+            [
+                37, 198, 143, 188, 84, 46, 100, 51, 178, 132, 13, 119, 48, 59, 163, 212, 140, 148,
+                121, 185, 214, 162, 110, 112, 9, 36, 45, 59, 171, 217, 228, 60,
+            ],
+            [
+                204, 47, 95, 18, 223, 224, 88, 253, 220, 235, 143, 145, 96, 148, 125, 10, 92, 90,
+                224, 37, 116, 235, 127, 28, 4, 27, 159, 207, 252, 140, 132, 8,
+            ],
+            [
+                21, 92, 180, 129, 183, 160, 36, 38, 209, 105, 218, 48, 198, 106, 154, 69, 79, 74,
+                101, 75, 55, 249, 53, 175, 184, 225, 254, 174, 132, 8, 131, 23,
+            ],
+            [
+                19, 140, 38, 191, 166, 59, 254, 239, 92, 141, 145, 57, 175, 24, 110, 86, 9, 30,
+                112, 193, 239, 88, 232, 189, 78, 175, 214, 215, 7, 86, 98, 8,
+            ],
+            [
+                218, 57, 238, 112, 171, 134, 156, 217, 93, 21, 191, 50, 54, 29, 82, 107, 111, 104,
+                171, 251, 170, 185, 31, 181, 206, 180, 221, 26, 250, 122, 241, 19,
+            ],
+        ],
+        [
+            [
+                206, 105, 26, 249, 66, 86, 173, 221, 185, 203, 98, 129, 252, 241, 75, 223, 102,
+                127, 71, 61, 182, 115, 150, 17, 130, 239, 101, 119, 59, 207, 51, 41,
+            ],
+            [
+                121, 64, 233, 38, 31, 61, 248, 3, 200, 67, 172, 196, 148, 216, 80, 62, 213, 228,
+                42, 87, 214, 68, 180, 30, 207, 42, 152, 164, 98, 58, 88, 34,
+            ],
+            [
+                213, 136, 143, 220, 199, 139, 209, 44, 167, 153, 168, 106, 65, 80, 177, 90, 215,
+                240, 164, 161, 181, 134, 137, 156, 18, 127, 34, 131, 218, 106, 232, 1,
+            ],
+            [
+                88, 198, 26, 116, 201, 141, 62, 92, 89, 159, 146, 181, 227, 81, 180, 28, 102, 243,
+                243, 142, 91, 14, 127, 85, 71, 244, 244, 89, 22, 46, 218, 27,
+            ],
+            [
+                108, 238, 222, 238, 27, 239, 21, 179, 67, 9, 151, 232, 191, 180, 161, 173, 202,
+                106, 11, 117, 204, 112, 85, 173, 55, 107, 205, 123, 238, 168, 255, 44,
+            ],
+            [
+                98, 3, 214, 58, 8, 226, 208, 17, 211, 143, 38, 11, 60, 73, 44, 164, 52, 151, 198,
+                237, 189, 72, 227, 58, 4, 136, 190, 37, 165, 182, 154, 57,
+            ],
+            [
+                172, 232, 202, 68, 212, 216, 245, 41, 226, 8, 42, 191, 123, 183, 135, 246, 235,
+                252, 40, 19, 6, 64, 251, 26, 11, 101, 59, 0, 74, 48, 149, 54,
+            ],
+            [
+                207, 86, 68, 96, 102, 28, 187, 193, 27, 54, 142, 37, 64, 91, 19, 224, 242, 70, 8,
+                135, 165, 21, 209, 197, 168, 55, 34, 122, 255, 112, 187, 58,
+            ],
+        ],
+        [
+            [
+                111, 247, 27, 70, 212, 148, 44, 134, 247, 87, 27, 23, 132, 79, 233, 180, 51, 10,
+                215, 176, 241, 197, 103, 218, 24, 148, 70, 162, 33, 208, 222, 7,
+            ],
+            [
+                12, 42, 121, 244, 26, 18, 206, 249, 43, 174, 151, 96, 233, 34, 58, 214, 129, 190,
+                121, 80, 105, 177, 146, 179, 33, 116, 185, 242, 162, 138, 125, 54,
+            ],
+            [
+                56, 68, 81, 82, 174, 234, 216, 50, 10, 182, 68, 106, 65, 68, 27, 218, 210, 164,
+                227, 138, 19, 118, 202, 35, 86, 7, 239, 247, 22, 152, 206, 50,
+            ],
+            [
+                52, 198, 61, 86, 3, 98, 233, 47, 150, 240, 252, 87, 248, 98, 229, 240, 147, 138,
+                244, 241, 235, 49, 188, 7, 35, 188, 209, 174, 73, 158, 252, 0,
+            ],
+            [
+                139, 187, 226, 27, 174, 225, 212, 177, 69, 67, 13, 2, 238, 34, 192, 206, 96, 37,
+                27, 158, 212, 132, 235, 176, 214, 167, 78, 37, 175, 190, 2, 51,
+            ],
+            [
+                130, 30, 249, 108, 130, 146, 150, 34, 247, 38, 40, 74, 162, 213, 77, 26, 228, 20,
+                50, 129, 133, 215, 195, 195, 125, 196, 234, 125, 202, 55, 245, 5,
+            ],
+            [
+                69, 130, 71, 74, 29, 183, 50, 163, 155, 176, 202, 71, 197, 219, 147, 2, 65, 10, 60,
+                125, 138, 78, 19, 61, 175, 29, 161, 193, 207, 116, 155, 34,
+            ],
+            [
+                106, 25, 65, 182, 34, 122, 159, 160, 179, 117, 4, 112, 74, 33, 169, 197, 136, 229,
+                42, 215, 82, 39, 5, 130, 212, 216, 180, 116, 89, 190, 4, 1,
+            ],
+        ],
+        [
+            [
+                113, 77, 48, 163, 86, 147, 96, 17, 221, 194, 101, 160, 115, 99, 172, 155, 172, 188,
+                102, 190, 33, 10, 23, 86, 13, 209, 225, 149, 79, 124, 36, 9,
+            ],
+            [
+                26, 232, 24, 27, 17, 255, 114, 101, 149, 101, 15, 115, 29, 164, 222, 46, 169, 35,
+                231, 198, 65, 88, 243, 156, 148, 201, 109, 179, 52, 85, 209, 0,
+            ],
+            [
+                74, 18, 156, 116, 153, 171, 213, 51, 192, 147, 146, 180, 126, 51, 43, 111, 110,
+                120, 67, 8, 216, 184, 216, 161, 95, 131, 207, 72, 97, 86, 226, 58,
+            ],
+            [
+                146, 95, 43, 145, 71, 205, 58, 200, 34, 164, 109, 146, 209, 149, 185, 222, 246,
+                105, 251, 7, 118, 224, 113, 223, 87, 72, 134, 34, 111, 95, 229, 2,
+            ],
+            [
+                183, 172, 39, 56, 158, 190, 197, 28, 75, 77, 150, 129, 77, 163, 71, 242, 59, 103,
+                199, 186, 77, 193, 19, 146, 59, 224, 21, 93, 58, 77, 229, 63,
+            ],
+            [
+                120, 242, 7, 129, 115, 150, 53, 98, 171, 224, 95, 10, 211, 66, 239, 246, 135, 134,
+                226, 167, 97, 148, 6, 102, 185, 114, 231, 64, 215, 117, 90, 12,
+            ],
+            [
+                31, 131, 95, 5, 54, 126, 21, 202, 138, 163, 178, 4, 179, 248, 1, 37, 25, 125, 204,
+                149, 254, 124, 14, 208, 179, 70, 134, 191, 35, 90, 169, 27,
+            ],
+            [
+                179, 220, 239, 246, 38, 106, 73, 16, 25, 163, 61, 226, 143, 161, 43, 74, 221, 110,
+                237, 108, 156, 37, 4, 212, 255, 190, 185, 128, 98, 135, 167, 6,
+            ],
+        ],
+        [
+            [
+                225, 216, 181, 82, 127, 203, 151, 253, 131, 128, 42, 44, 122, 60, 130, 90, 208, 97,
+                204, 208, 126, 133, 246, 151, 248, 136, 250, 246, 89, 115, 232, 55,
+            ],
+            [
+                121, 161, 120, 62, 218, 201, 170, 242, 251, 52, 85, 130, 66, 145, 240, 108, 90,
+                247, 154, 96, 86, 33, 225, 73, 155, 28, 181, 129, 216, 155, 218, 25,
+            ],
+            [
+                209, 87, 121, 227, 88, 248, 131, 233, 36, 244, 156, 218, 8, 66, 163, 229, 186, 62,
+                139, 249, 164, 166, 61, 47, 123, 177, 6, 249, 244, 115, 129, 1,
+            ],
+            [
+                201, 79, 66, 125, 126, 247, 82, 183, 153, 135, 171, 196, 50, 134, 168, 238, 221,
+                206, 16, 126, 130, 217, 244, 56, 199, 103, 191, 233, 190, 115, 140, 50,
+            ],
+            [
+                119, 207, 10, 177, 248, 246, 164, 73, 193, 252, 121, 121, 57, 244, 156, 159, 149,
+                119, 86, 227, 115, 0, 76, 137, 236, 24, 132, 77, 211, 53, 154, 27,
+            ],
+            [
+                166, 125, 245, 187, 247, 191, 55, 242, 126, 25, 12, 127, 14, 91, 227, 231, 103, 15,
+                209, 234, 209, 95, 4, 106, 10, 248, 73, 156, 107, 224, 182, 53,
+            ],
+            [
+                177, 26, 144, 34, 158, 178, 24, 57, 35, 176, 223, 234, 198, 254, 91, 153, 80, 205,
+                25, 72, 236, 65, 157, 227, 21, 40, 82, 189, 225, 223, 194, 34,
+            ],
+            [
+                60, 150, 252, 8, 187, 240, 208, 70, 208, 9, 205, 215, 255, 164, 58, 20, 9, 162,
+                165, 4, 52, 121, 172, 128, 163, 160, 94, 113, 247, 252, 144, 24,
+            ],
+        ],
+        [
+            [
+                1, 174, 46, 147, 9, 169, 66, 197, 148, 192, 87, 147, 112, 142, 167, 211, 216, 115,
+                11, 16, 217, 178, 188, 95, 247, 248, 255, 184, 135, 109, 104, 37,
+            ],
+            [
+                247, 249, 241, 196, 179, 54, 140, 183, 11, 196, 149, 121, 184, 238, 94, 81, 154,
+                225, 147, 24, 91, 146, 60, 139, 233, 11, 208, 236, 114, 65, 85, 49,
+            ],
+            [
+                245, 244, 110, 140, 48, 192, 11, 139, 173, 219, 84, 48, 245, 238, 235, 252, 196,
+                17, 31, 208, 154, 50, 194, 118, 210, 203, 244, 70, 77, 215, 49, 2,
+            ],
+            [
+                106, 88, 182, 165, 143, 235, 1, 236, 249, 96, 195, 203, 9, 147, 247, 203, 89, 103,
+                198, 21, 240, 70, 25, 19, 122, 81, 244, 206, 170, 215, 95, 58,
+            ],
+            [
+                44, 12, 177, 150, 83, 155, 137, 87, 18, 224, 191, 147, 163, 157, 19, 123, 48, 26,
+                61, 106, 212, 205, 164, 31, 41, 21, 181, 99, 169, 240, 83, 12,
+            ],
+            [
+                9, 42, 145, 218, 93, 170, 145, 106, 59, 153, 226, 241, 136, 14, 10, 237, 119, 242,
+                113, 216, 61, 218, 113, 187, 40, 129, 28, 4, 94, 244, 159, 38,
+            ],
+            [
+                119, 100, 196, 253, 38, 233, 165, 163, 226, 37, 111, 207, 72, 48, 6, 145, 226, 240,
+                13, 200, 56, 249, 4, 67, 84, 158, 228, 27, 65, 165, 190, 39,
+            ],
+            [
+                158, 37, 147, 52, 219, 73, 218, 255, 90, 125, 239, 128, 205, 217, 45, 226, 193, 21,
+                70, 140, 36, 252, 18, 107, 121, 14, 6, 195, 232, 142, 42, 21,
+            ],
+        ],
+        [
+            [
+                97, 62, 17, 156, 126, 221, 0, 150, 164, 121, 185, 193, 111, 56, 22, 92, 114, 186,
+                90, 192, 96, 60, 198, 213, 47, 107, 21, 249, 199, 47, 152, 32,
+            ],
+            [
+                56, 149, 110, 148, 133, 186, 65, 253, 186, 210, 245, 170, 242, 241, 210, 144, 194,
+                95, 96, 136, 33, 245, 57, 132, 224, 84, 222, 99, 29, 179, 59, 53,
+            ],
+            [
+                228, 201, 4, 236, 155, 199, 36, 102, 228, 95, 158, 190, 131, 38, 126, 193, 44, 158,
+                49, 31, 166, 10, 167, 121, 167, 110, 49, 31, 104, 214, 27, 6,
+            ],
+            [
+                247, 180, 248, 229, 166, 141, 35, 90, 254, 95, 250, 60, 246, 218, 205, 241, 23,
+                228, 87, 101, 98, 153, 41, 148, 100, 74, 70, 255, 155, 93, 158, 32,
+            ],
+            [
+                101, 58, 118, 198, 173, 11, 51, 192, 217, 97, 200, 251, 189, 160, 47, 68, 233, 238,
+                119, 255, 64, 133, 160, 4, 83, 104, 175, 235, 187, 142, 236, 29,
+            ],
+            [
+                14, 195, 138, 108, 59, 208, 100, 81, 233, 236, 113, 230, 144, 175, 24, 11, 52, 253,
+                3, 192, 226, 131, 57, 9, 130, 196, 133, 55, 4, 169, 126, 37,
+            ],
+            [
+                163, 116, 176, 10, 236, 17, 7, 87, 249, 234, 58, 229, 2, 14, 177, 217, 102, 128,
+                226, 19, 223, 238, 110, 49, 193, 23, 65, 90, 68, 58, 2, 19,
+            ],
+            [
+                78, 169, 17, 239, 82, 4, 100, 24, 166, 248, 11, 94, 52, 21, 198, 245, 72, 231, 205,
+                105, 71, 50, 122, 186, 254, 253, 175, 231, 97, 59, 240, 41,
+            ],
+        ],
+        [
+            [
+                22, 164, 175, 74, 166, 34, 118, 148, 160, 171, 51, 23, 30, 194, 179, 64, 176, 197,
+                7, 55, 145, 45, 49, 89, 86, 250, 213, 179, 74, 215, 66, 56,
+            ],
+            [
+                15, 248, 250, 99, 222, 173, 254, 173, 45, 155, 149, 58, 199, 129, 29, 251, 125,
+                123, 23, 96, 136, 75, 156, 101, 59, 255, 18, 215, 189, 31, 213, 9,
+            ],
+            [
+                37, 167, 207, 98, 235, 31, 231, 79, 246, 86, 74, 194, 110, 224, 202, 85, 207, 247,
+                9, 124, 227, 125, 126, 235, 163, 44, 207, 254, 158, 161, 91, 23,
+            ],
+            [
+                154, 99, 91, 43, 43, 208, 75, 9, 43, 21, 86, 56, 3, 18, 45, 215, 89, 232, 47, 189,
+                64, 143, 63, 73, 87, 67, 9, 98, 167, 46, 44, 16,
+            ],
+            [
+                59, 0, 138, 205, 149, 99, 239, 46, 57, 81, 186, 110, 22, 136, 135, 127, 34, 218,
+                232, 231, 199, 136, 119, 160, 88, 23, 192, 241, 156, 182, 174, 17,
+            ],
+            [
+                92, 129, 141, 150, 95, 150, 201, 188, 91, 179, 165, 40, 125, 164, 32, 75, 13, 28,
+                254, 225, 13, 188, 99, 142, 178, 91, 86, 234, 7, 16, 73, 39,
+            ],
+            [
+                243, 41, 193, 226, 43, 253, 213, 229, 240, 233, 137, 156, 20, 152, 80, 99, 27, 251,
+                239, 217, 219, 220, 110, 98, 207, 86, 85, 27, 118, 151, 177, 5,
+            ],
+            [
+                66, 70, 49, 38, 13, 211, 140, 28, 222, 153, 1, 151, 218, 108, 15, 133, 114, 254,
+                19, 133, 126, 119, 126, 35, 25, 222, 69, 110, 95, 53, 14, 26,
+            ],
+        ],
+    ],
+    [
+        [
+            [
+                106, 83, 187, 134, 202, 179, 53, 8, 144, 168, 168, 142, 96, 152, 250, 230, 14, 120,
+                154, 90, 119, 10, 211, 180, 7, 6, 3, 188, 125, 187, 60, 10,
+            ],
+            [
+                149, 232, 49, 44, 124, 44, 42, 203, 249, 197, 134, 197, 31, 130, 102, 36, 202, 69,
+                5, 158, 253, 219, 139, 222, 197, 246, 76, 139, 15, 183, 220, 54,
+            ],
+            [
+                156, 9, 241, 40, 120, 145, 94, 181, 250, 66, 110, 100, 102, 235, 188, 185, 56, 186,
+                190, 188, 152, 191, 108, 70, 170, 97, 45, 114, 54, 70, 185, 12,
+            ],
+            [
+                251, 179, 189, 234, 78, 65, 139, 190, 207, 5, 170, 33, 63, 81, 121, 129, 179, 136,
+                29, 118, 107, 91, 248, 138, 135, 170, 230, 113, 19, 3, 236, 50,
+            ],
+            [
+                190, 40, 194, 125, 108, 249, 30, 187, 186, 169, 197, 246, 19, 129, 121, 83, 71,
+                201, 207, 200, 167, 236, 9, 97, 221, 233, 206, 218, 71, 159, 222, 44,
+            ],
+            [
+                252, 233, 118, 198, 8, 151, 86, 53, 244, 236, 44, 161, 127, 38, 245, 97, 7, 204,
+                85, 228, 21, 151, 219, 129, 195, 197, 84, 80, 100, 71, 46, 27,
+            ],
+            [
+                175, 163, 251, 223, 216, 123, 40, 243, 165, 112, 22, 132, 139, 12, 70, 165, 65, 7,
+                207, 196, 175, 211, 100, 170, 242, 6, 24, 21, 168, 96, 75, 52,
+            ],
+            [
+                242, 252, 140, 147, 160, 101, 72, 162, 232, 254, 91, 57, 153, 138, 107, 135, 215,
+                84, 20, 216, 144, 97, 245, 0, 44, 212, 49, 6, 252, 34, 129, 41,
+            ],
+        ],
+        [
+            [
+                44, 252, 105, 176, 122, 154, 86, 15, 32, 178, 151, 235, 175, 238, 34, 106, 15, 219,
+                139, 42, 224, 202, 175, 3, 8, 109, 161, 61, 10, 29, 195, 20,
+            ],
+            [
+                13, 104, 167, 109, 13, 175, 147, 217, 193, 80, 103, 180, 214, 235, 40, 5, 38, 193,
+                214, 114, 93, 43, 119, 228, 123, 123, 176, 154, 63, 166, 236, 46,
+            ],
+            [
+                50, 64, 231, 62, 31, 84, 194, 113, 19, 95, 220, 118, 193, 177, 183, 66, 46, 196,
+                74, 76, 55, 250, 147, 10, 122, 122, 120, 171, 239, 22, 124, 58,
+            ],
+            [
+                42, 224, 169, 199, 232, 5, 72, 72, 82, 132, 211, 36, 145, 137, 157, 37, 122, 207,
+                133, 57, 95, 76, 28, 36, 227, 77, 5, 213, 133, 255, 174, 26,
+            ],
+            [
+                219, 13, 147, 241, 120, 22, 99, 115, 38, 130, 127, 84, 252, 12, 101, 223, 62, 10,
+                50, 224, 69, 30, 188, 236, 222, 179, 251, 200, 254, 77, 72, 56,
+            ],
+            [
+                141, 180, 187, 149, 191, 124, 34, 193, 56, 171, 136, 68, 102, 109, 198, 240, 87,
+                233, 8, 78, 28, 237, 218, 53, 111, 39, 93, 171, 180, 154, 46, 61,
+            ],
+            [
+                47, 110, 220, 217, 38, 45, 149, 116, 165, 36, 96, 248, 5, 105, 229, 204, 10, 4,
+                208, 161, 197, 105, 176, 44, 197, 232, 149, 204, 224, 33, 141, 23,
+            ],
+            [
+                30, 6, 70, 172, 235, 175, 231, 76, 183, 55, 29, 196, 101, 141, 70, 105, 233, 45,
+                237, 16, 66, 112, 139, 138, 27, 239, 235, 169, 224, 117, 210, 27,
+            ],
+        ],
+        [
+            [
+                232, 142, 65, 116, 65, 115, 93, 87, 162, 160, 180, 34, 133, 146, 136, 13, 27, 34,
+                239, 223, 55, 91, 78, 24, 35, 13, 118, 28, 221, 200, 140, 62,
+            ],
+            [
+                200, 25, 32, 251, 225, 96, 145, 181, 225, 15, 67, 117, 101, 211, 40, 179, 76, 162,
+                254, 231, 225, 127, 106, 126, 210, 25, 79, 60, 155, 160, 122, 58,
+            ],
+            [
+                186, 217, 113, 124, 17, 27, 59, 193, 21, 86, 150, 9, 76, 100, 190, 20, 78, 95, 162,
+                128, 137, 242, 17, 0, 114, 203, 11, 177, 166, 4, 54, 6,
+            ],
+            [
+                249, 149, 190, 39, 15, 210, 127, 5, 104, 145, 109, 103, 82, 253, 54, 98, 72, 138,
+                76, 224, 30, 155, 142, 157, 151, 39, 104, 153, 188, 55, 156, 19,
+            ],
+            [
+                77, 123, 60, 32, 171, 32, 54, 90, 95, 152, 100, 136, 113, 245, 97, 166, 252, 41,
+                186, 93, 29, 250, 12, 247, 111, 126, 203, 46, 235, 47, 193, 54,
+            ],
+            [
+                175, 98, 190, 86, 253, 66, 145, 221, 246, 242, 231, 213, 170, 243, 220, 25, 242,
+                168, 175, 252, 67, 73, 14, 21, 247, 254, 167, 129, 227, 136, 1, 31,
+            ],
+            [
+                99, 195, 238, 85, 132, 80, 115, 195, 41, 194, 182, 80, 17, 21, 64, 208, 202, 216,
+                245, 143, 124, 71, 4, 186, 211, 121, 30, 98, 160, 50, 108, 31,
+            ],
+            [
+                141, 108, 134, 9, 90, 106, 229, 154, 159, 227, 3, 29, 190, 225, 243, 152, 111, 156,
+                109, 162, 25, 205, 90, 56, 230, 235, 90, 212, 195, 148, 193, 32,
+            ],
+        ],
+        [
+            [
+                154, 14, 80, 27, 193, 117, 174, 122, 146, 5, 44, 174, 101, 237, 104, 152, 42, 27,
+                190, 255, 146, 160, 225, 100, 48, 184, 225, 45, 83, 198, 143, 48,
+            ],
+            [
+                206, 145, 63, 35, 230, 110, 128, 131, 194, 248, 156, 31, 42, 45, 241, 51, 96, 198,
+                0, 53, 33, 69, 74, 32, 190, 214, 116, 75, 173, 69, 235, 29,
+            ],
+            [
+                124, 22, 76, 68, 81, 0, 1, 216, 128, 143, 193, 218, 106, 243, 159, 109, 110, 244,
+                103, 11, 207, 1, 99, 47, 61, 38, 135, 241, 125, 47, 113, 9,
+            ],
+            [
+                37, 183, 237, 250, 110, 54, 0, 168, 158, 248, 217, 244, 225, 29, 210, 87, 63, 182,
+                199, 230, 232, 183, 212, 192, 94, 64, 28, 42, 118, 200, 209, 55,
+            ],
+            [
+                157, 123, 109, 237, 19, 234, 216, 134, 255, 32, 153, 244, 189, 88, 68, 141, 212,
+                77, 83, 218, 51, 162, 229, 223, 174, 63, 9, 234, 247, 224, 69, 32,
+            ],
+            [
+                51, 214, 143, 87, 3, 174, 149, 228, 43, 104, 173, 91, 157, 22, 127, 254, 144, 30,
+                227, 167, 133, 124, 165, 225, 187, 121, 246, 31, 193, 168, 221, 34,
+            ],
+            [
+                158, 93, 192, 149, 102, 23, 233, 197, 23, 225, 57, 229, 182, 25, 169, 39, 9, 128,
+                88, 21, 119, 59, 196, 41, 95, 12, 155, 191, 17, 228, 239, 39,
+            ],
+            [
+                237, 88, 52, 17, 13, 208, 157, 142, 175, 113, 154, 127, 78, 125, 33, 2, 194, 29,
+                78, 63, 78, 175, 47, 188, 196, 114, 178, 95, 153, 191, 123, 7,
+            ],
+        ],
+        [
+            [
+                197, 171, 85, 229, 44, 76, 79, 197, 118, 86, 23, 112, 227, 252, 160, 45, 128, 26,
+                172, 69, 37, 87, 94, 143, 93, 218, 28, 6, 37, 9, 187, 49,
+            ],
+            [
+                133, 187, 174, 38, 123, 66, 108, 84, 59, 193, 78, 131, 114, 229, 84, 204, 209, 108,
+                160, 145, 90, 140, 245, 164, 247, 205, 193, 5, 120, 218, 209, 19,
+            ],
+            [
+                120, 48, 11, 0, 105, 128, 56, 84, 116, 22, 185, 223, 136, 80, 116, 108, 213, 186,
+                59, 205, 134, 160, 188, 175, 91, 153, 5, 237, 176, 35, 43, 45,
+            ],
+            [
+                95, 163, 1, 243, 44, 44, 124, 113, 206, 243, 71, 107, 2, 109, 6, 192, 227, 249,
+                231, 214, 200, 230, 139, 99, 73, 3, 119, 222, 42, 184, 197, 62,
+            ],
+            [
+                184, 154, 19, 142, 162, 103, 4, 100, 167, 237, 202, 74, 155, 134, 59, 5, 141, 126,
+                55, 160, 179, 122, 26, 26, 209, 50, 64, 202, 6, 21, 116, 26,
+            ],
+            [
+                54, 227, 252, 235, 104, 237, 61, 217, 169, 109, 82, 18, 183, 165, 0, 107, 156, 210,
+                64, 17, 99, 222, 221, 172, 86, 202, 9, 34, 5, 46, 148, 42,
+            ],
+            [
+                50, 124, 247, 26, 243, 14, 166, 2, 120, 86, 207, 244, 6, 244, 111, 237, 32, 222,
+                97, 234, 132, 218, 113, 93, 95, 252, 228, 123, 249, 47, 142, 44,
+            ],
+            [
+                186, 43, 244, 48, 231, 55, 87, 32, 119, 228, 159, 171, 174, 4, 230, 148, 84, 60,
+                15, 44, 211, 227, 245, 224, 171, 127, 45, 87, 18, 40, 51, 34,
+            ],
+        ],
+        [
+            [
+                33, 63, 149, 165, 72, 212, 135, 212, 234, 172, 227, 125, 183, 243, 63, 223, 207,
+                229, 219, 103, 207, 161, 13, 125, 66, 102, 151, 197, 249, 136, 172, 32,
+            ],
+            [
+                224, 121, 94, 226, 137, 1, 48, 242, 143, 114, 138, 8, 69, 172, 177, 196, 95, 44,
+                207, 141, 155, 157, 31, 51, 196, 19, 6, 34, 112, 82, 105, 20,
+            ],
+            [
+                39, 122, 20, 120, 224, 203, 3, 18, 164, 245, 199, 185, 107, 15, 177, 129, 247, 18,
+                44, 206, 140, 98, 91, 110, 16, 242, 118, 112, 43, 133, 62, 39,
+            ],
+            [
+                210, 154, 172, 46, 131, 43, 144, 231, 176, 148, 190, 218, 205, 218, 173, 135, 153,
+                154, 32, 44, 44, 173, 18, 151, 67, 164, 183, 93, 95, 75, 121, 19,
+            ],
+            [
+                16, 34, 248, 67, 174, 107, 38, 206, 73, 229, 225, 127, 33, 58, 91, 38, 81, 163,
+                128, 214, 43, 36, 137, 201, 233, 58, 219, 47, 227, 128, 104, 17,
+            ],
+            [
+                129, 51, 61, 201, 141, 52, 106, 129, 167, 109, 252, 0, 43, 35, 119, 149, 245, 210,
+                45, 206, 206, 29, 224, 32, 254, 98, 216, 15, 159, 170, 62, 9,
+            ],
+            [
+                19, 0, 49, 226, 55, 21, 7, 100, 187, 240, 232, 89, 148, 224, 159, 38, 76, 114, 229,
+                14, 207, 55, 90, 250, 249, 225, 20, 122, 86, 45, 181, 35,
+            ],
+            [
+                24, 100, 163, 37, 244, 108, 192, 149, 141, 195, 165, 116, 0, 100, 115, 229, 215,
+                119, 131, 74, 231, 88, 236, 4, 52, 113, 24, 19, 77, 204, 115, 5,
+            ],
+        ],
+        [
+            [
+                54, 218, 232, 117, 183, 76, 240, 106, 196, 94, 219, 107, 141, 202, 148, 79, 69, 65,
+                34, 242, 113, 100, 184, 0, 114, 114, 188, 122, 132, 161, 234, 28,
+            ],
+            [
+                178, 51, 152, 50, 174, 83, 225, 79, 44, 81, 136, 156, 8, 34, 61, 198, 169, 230,
+                168, 189, 82, 82, 16, 117, 247, 72, 148, 163, 177, 144, 111, 58,
+            ],
+            [
+                247, 62, 128, 28, 193, 33, 202, 20, 100, 119, 89, 27, 139, 37, 164, 0, 157, 57,
+                115, 105, 202, 107, 97, 131, 163, 213, 222, 113, 56, 254, 190, 58,
+            ],
+            [
+                185, 97, 141, 71, 231, 248, 41, 220, 61, 205, 187, 18, 172, 67, 112, 39, 94, 36,
+                161, 205, 127, 46, 42, 86, 16, 96, 23, 194, 53, 136, 146, 57,
+            ],
+            [
+                142, 236, 250, 36, 106, 88, 121, 239, 21, 148, 131, 213, 181, 221, 74, 124, 82, 58,
+                218, 5, 65, 122, 204, 9, 54, 78, 167, 9, 90, 244, 108, 17,
+            ],
+            [
+                153, 253, 228, 0, 32, 14, 162, 193, 29, 147, 195, 66, 48, 250, 138, 245, 141, 217,
+                132, 93, 187, 3, 210, 48, 237, 71, 79, 66, 27, 56, 253, 36,
+            ],
+            [
+                175, 3, 222, 192, 121, 28, 160, 90, 8, 47, 196, 255, 126, 202, 251, 87, 36, 29, 34,
+                190, 152, 185, 66, 20, 173, 61, 197, 159, 91, 226, 242, 38,
+            ],
+            [
+                177, 86, 180, 169, 61, 109, 61, 148, 191, 181, 51, 87, 123, 44, 208, 133, 250, 34,
+                150, 138, 128, 45, 218, 150, 141, 80, 29, 97, 126, 138, 9, 23,
+            ],
+        ],
+        [
+            [
+                246, 132, 48, 4, 253, 96, 176, 230, 124, 16, 178, 152, 124, 66, 36, 2, 129, 70, 80,
+                131, 74, 62, 190, 64, 51, 44, 217, 128, 10, 22, 169, 44,
+            ],
+            [
+                62, 89, 170, 57, 174, 99, 111, 122, 23, 237, 41, 202, 134, 49, 208, 93, 222, 250,
+                142, 115, 244, 0, 138, 58, 99, 136, 236, 197, 243, 146, 222, 30,
+            ],
+            [
+                137, 6, 117, 178, 191, 223, 240, 57, 64, 167, 68, 2, 49, 250, 188, 171, 46, 210,
+                209, 2, 193, 58, 61, 165, 106, 23, 232, 77, 63, 137, 118, 3,
+            ],
+            [
+                147, 29, 142, 78, 155, 154, 194, 163, 135, 121, 84, 226, 125, 255, 172, 175, 48,
+                232, 160, 178, 63, 110, 64, 92, 22, 145, 235, 34, 48, 104, 240, 49,
+            ],
+            [
+                23, 215, 198, 135, 253, 203, 14, 219, 28, 129, 127, 226, 0, 55, 243, 118, 212, 215,
+                85, 84, 0, 126, 242, 240, 218, 150, 46, 34, 50, 94, 69, 10,
+            ],
+            [
+                30, 185, 204, 131, 124, 163, 92, 23, 57, 193, 50, 193, 160, 71, 55, 111, 32, 217,
+                236, 225, 103, 225, 160, 241, 22, 220, 237, 121, 73, 71, 47, 31,
+            ],
+            [
+                98, 42, 173, 71, 8, 232, 146, 58, 119, 131, 181, 103, 154, 80, 3, 222, 10, 223,
+                134, 89, 41, 216, 18, 29, 247, 19, 208, 52, 149, 88, 184, 60,
+            ],
+            [
+                177, 243, 130, 86, 97, 32, 55, 176, 192, 240, 55, 19, 164, 141, 195, 63, 69, 57,
+                54, 136, 238, 248, 24, 199, 20, 182, 157, 172, 80, 63, 249, 38,
+            ],
+        ],
+        [
+            [
+                158, 153, 85, 14, 104, 219, 137, 232, 135, 24, 236, 30, 110, 141, 179, 83, 179,
+                243, 189, 254, 203, 95, 53, 201, 219, 183, 75, 63, 139, 97, 173, 20,
+            ],
+            [
+                95, 6, 147, 29, 88, 142, 158, 207, 91, 167, 146, 50, 58, 55, 163, 167, 249, 128,
+                162, 141, 244, 172, 46, 181, 188, 227, 9, 99, 213, 255, 84, 6,
+            ],
+            [
+                65, 79, 88, 107, 83, 107, 243, 188, 89, 227, 214, 127, 4, 183, 56, 151, 49, 106,
+                104, 160, 118, 146, 173, 173, 77, 75, 162, 13, 162, 220, 20, 19,
+            ],
+            [
+                208, 137, 9, 94, 147, 23, 207, 98, 137, 163, 241, 104, 215, 97, 41, 207, 184, 189,
+                237, 187, 63, 235, 167, 136, 215, 188, 83, 139, 124, 200, 137, 29,
+            ],
+            [
+                46, 58, 168, 249, 137, 90, 229, 234, 204, 157, 57, 57, 34, 136, 182, 68, 15, 226,
+                214, 124, 47, 215, 24, 146, 214, 216, 208, 148, 10, 208, 3, 0,
+            ],
+            [
+                65, 53, 237, 208, 81, 113, 1, 231, 188, 229, 245, 38, 209, 211, 43, 109, 41, 216,
+                53, 77, 171, 181, 253, 241, 214, 18, 112, 177, 151, 198, 7, 16,
+            ],
+            [
+                113, 106, 183, 104, 126, 116, 83, 65, 187, 233, 33, 97, 250, 61, 108, 176, 112,
+                156, 219, 209, 36, 105, 51, 156, 30, 130, 106, 94, 29, 212, 214, 51,
+            ],
+            [
+                112, 124, 23, 71, 102, 65, 236, 144, 83, 227, 192, 90, 35, 97, 98, 189, 206, 177,
+                152, 97, 105, 245, 234, 101, 250, 127, 117, 51, 71, 72, 235, 5,
+            ],
+        ],
+        [
+            [
+                199, 60, 235, 107, 168, 17, 1, 163, 39, 227, 76, 4, 85, 166, 13, 178, 33, 94, 254,
+                184, 250, 43, 100, 52, 163, 87, 28, 81, 194, 49, 181, 27,
+            ],
+            [
+                243, 96, 214, 94, 124, 154, 161, 193, 79, 207, 148, 160, 79, 31, 178, 36, 2, 250,
+                62, 49, 1, 40, 8, 56, 13, 158, 53, 90, 141, 7, 236, 24,
+            ],
+            [
+                203, 235, 180, 173, 191, 56, 184, 216, 36, 237, 39, 94, 53, 64, 232, 108, 110, 54,
+                174, 169, 102, 117, 137, 121, 122, 242, 20, 241, 156, 207, 94, 26,
+            ],
+            [
+                93, 196, 224, 59, 192, 182, 209, 174, 81, 88, 32, 18, 252, 235, 73, 65, 187, 97,
+                108, 252, 56, 16, 38, 45, 231, 172, 36, 22, 97, 215, 248, 49,
+            ],
+            [
+                92, 241, 34, 210, 205, 105, 89, 146, 116, 131, 234, 243, 136, 154, 21, 193, 185,
+                29, 51, 203, 117, 176, 150, 119, 115, 222, 88, 233, 126, 106, 251, 19,
+            ],
+            [
+                84, 24, 80, 210, 23, 152, 81, 172, 245, 236, 100, 63, 28, 236, 47, 147, 90, 22, 53,
+                27, 87, 36, 38, 81, 74, 151, 10, 60, 194, 241, 201, 20,
+            ],
+            [
+                13, 61, 24, 89, 233, 201, 86, 28, 67, 134, 182, 245, 177, 9, 21, 131, 17, 116, 224,
+                206, 61, 223, 187, 65, 232, 250, 212, 3, 3, 243, 60, 21,
+            ],
+            [
+                21, 130, 72, 86, 24, 62, 202, 202, 243, 180, 64, 230, 218, 94, 118, 143, 231, 45,
+                164, 243, 217, 47, 212, 78, 255, 164, 41, 10, 221, 178, 37, 7,
+            ],
+        ],
+        [
+            [
+                109, 144, 126, 48, 117, 206, 218, 41, 87, 16, 158, 175, 23, 237, 46, 11, 30, 125,
+                135, 119, 248, 125, 56, 9, 18, 137, 93, 122, 233, 255, 95, 62,
+            ],
+            [
+                52, 148, 45, 98, 36, 141, 249, 242, 57, 40, 125, 252, 219, 49, 135, 44, 127, 85,
+                237, 99, 255, 211, 74, 8, 157, 157, 9, 200, 232, 155, 1, 33,
+            ],
+            [
+                21, 193, 222, 95, 11, 128, 166, 168, 200, 79, 158, 14, 138, 93, 223, 90, 162, 236,
+                146, 164, 66, 129, 234, 155, 89, 65, 62, 8, 82, 160, 248, 46,
+            ],
+            [
+                118, 32, 131, 123, 102, 149, 129, 126, 57, 210, 2, 44, 38, 93, 55, 178, 190, 196,
+                50, 159, 216, 98, 235, 208, 89, 96, 8, 89, 0, 16, 75, 51,
+            ],
+            [
+                180, 192, 217, 172, 226, 196, 165, 112, 163, 152, 138, 219, 151, 186, 140, 70, 103,
+                59, 146, 246, 158, 128, 120, 218, 92, 67, 176, 146, 153, 1, 106, 31,
+            ],
+            [
+                117, 51, 3, 54, 217, 215, 175, 31, 32, 41, 78, 139, 73, 240, 158, 42, 21, 49, 48,
+                98, 193, 104, 104, 171, 178, 123, 247, 118, 176, 140, 118, 29,
+            ],
+            [
+                142, 132, 64, 221, 92, 253, 76, 38, 227, 154, 132, 147, 252, 59, 222, 252, 1, 80,
+                89, 58, 159, 150, 61, 240, 247, 148, 18, 216, 218, 107, 160, 27,
+            ],
+            [
+                182, 32, 159, 5, 129, 69, 229, 4, 119, 71, 75, 135, 212, 212, 229, 176, 101, 91,
+                45, 184, 31, 173, 166, 76, 52, 115, 236, 75, 14, 201, 245, 38,
+            ],
+        ],
+        [
+            [
+                238, 153, 17, 249, 37, 54, 223, 210, 125, 182, 10, 56, 234, 85, 115, 228, 12, 30,
+                208, 253, 222, 141, 78, 84, 188, 3, 171, 67, 3, 42, 117, 42,
+            ],
+            [
+                238, 219, 202, 164, 58, 201, 194, 232, 11, 238, 91, 16, 170, 155, 65, 106, 75, 171,
+                100, 213, 122, 154, 93, 230, 154, 179, 89, 193, 140, 161, 225, 12,
+            ],
+            [
+                113, 78, 44, 1, 73, 158, 24, 157, 195, 112, 18, 61, 135, 204, 97, 220, 96, 221,
+                103, 48, 126, 161, 45, 107, 204, 12, 192, 159, 83, 169, 88, 36,
+            ],
+            [
+                142, 33, 22, 27, 39, 149, 63, 253, 29, 13, 116, 63, 37, 110, 137, 13, 199, 169,
+                219, 153, 209, 137, 109, 66, 227, 118, 115, 202, 132, 97, 103, 7,
+            ],
+            [
+                18, 223, 233, 201, 120, 210, 75, 241, 188, 212, 221, 225, 133, 126, 253, 50, 117,
+                62, 6, 139, 250, 41, 17, 48, 11, 233, 113, 98, 178, 141, 33, 11,
+            ],
+            [
+                115, 44, 222, 46, 102, 219, 98, 176, 37, 188, 214, 164, 143, 54, 252, 138, 247,
+                238, 66, 7, 14, 241, 186, 224, 73, 32, 158, 246, 37, 181, 44, 29,
+            ],
+            [
+                31, 64, 25, 208, 179, 115, 236, 209, 162, 170, 52, 115, 240, 35, 193, 21, 98, 68,
+                127, 10, 72, 165, 76, 31, 50, 167, 12, 184, 199, 153, 239, 17,
+            ],
+            [
+                189, 71, 154, 243, 94, 106, 168, 96, 37, 118, 108, 20, 36, 150, 14, 77, 112, 58,
+                246, 25, 157, 60, 116, 199, 230, 167, 123, 117, 146, 250, 174, 55,
+            ],
+        ],
+        [
+            [
+                112, 188, 137, 180, 167, 189, 186, 211, 83, 179, 33, 206, 212, 52, 161, 138, 103,
+                51, 41, 105, 92, 125, 91, 158, 235, 51, 127, 101, 18, 157, 51, 11,
+            ],
+            [
+                240, 188, 8, 37, 66, 213, 34, 175, 187, 222, 226, 167, 58, 181, 141, 111, 66, 207,
+                210, 88, 158, 176, 46, 80, 227, 14, 178, 203, 98, 0, 103, 6,
+            ],
+            [
+                222, 189, 189, 233, 42, 240, 206, 214, 14, 12, 16, 134, 88, 185, 11, 190, 212, 179,
+                98, 56, 233, 11, 209, 218, 80, 124, 187, 72, 227, 253, 243, 60,
+            ],
+            [
+                175, 108, 51, 132, 245, 91, 79, 8, 106, 75, 36, 177, 173, 29, 28, 66, 226, 14, 226,
+                175, 215, 35, 86, 4, 191, 118, 246, 180, 223, 8, 37, 38,
+            ],
+            [
+                181, 103, 248, 175, 180, 33, 252, 171, 30, 221, 9, 129, 128, 234, 234, 62, 243,
+                173, 99, 227, 5, 139, 134, 17, 63, 101, 98, 124, 68, 233, 3, 52,
+            ],
+            [
+                9, 89, 74, 251, 231, 50, 78, 34, 131, 16, 226, 89, 245, 101, 155, 109, 119, 155,
+                96, 127, 192, 238, 191, 140, 83, 240, 236, 219, 56, 207, 232, 27,
+            ],
+            [
+                225, 174, 234, 133, 253, 9, 15, 47, 124, 244, 235, 71, 22, 71, 201, 91, 102, 202,
+                193, 255, 71, 183, 32, 112, 138, 175, 48, 33, 39, 208, 39, 47,
+            ],
+            [
+                173, 207, 188, 50, 97, 153, 93, 111, 133, 138, 95, 209, 71, 225, 18, 222, 49, 44,
+                210, 134, 70, 146, 49, 90, 202, 24, 32, 8, 62, 29, 111, 42,
+            ],
+        ],
+        [
+            [
+                32, 154, 208, 12, 21, 13, 245, 148, 141, 159, 112, 120, 177, 136, 210, 246, 96, 24,
+                208, 217, 248, 240, 228, 212, 32, 208, 182, 155, 117, 135, 174, 3,
+            ],
+            [
+                7, 231, 71, 5, 22, 195, 71, 6, 24, 59, 37, 87, 119, 112, 39, 134, 241, 114, 185,
+                201, 8, 130, 175, 202, 247, 224, 238, 192, 156, 196, 247, 50,
+            ],
+            [
+                188, 77, 129, 248, 186, 194, 133, 158, 38, 141, 245, 249, 236, 66, 239, 70, 190,
+                199, 63, 165, 72, 167, 195, 212, 58, 137, 55, 12, 23, 123, 80, 49,
+            ],
+            [
+                12, 33, 184, 63, 215, 61, 4, 141, 20, 132, 178, 57, 167, 78, 217, 106, 126, 204,
+                44, 19, 143, 170, 84, 224, 123, 11, 158, 106, 146, 219, 85, 4,
+            ],
+            [
+                34, 77, 158, 171, 37, 156, 254, 84, 42, 198, 72, 247, 204, 43, 158, 165, 92, 168,
+                234, 153, 92, 78, 130, 88, 57, 199, 245, 59, 54, 72, 142, 13,
+            ],
+            [
+                64, 226, 83, 192, 204, 119, 155, 84, 93, 102, 42, 207, 245, 156, 146, 212, 6, 141,
+                107, 32, 253, 48, 178, 162, 203, 192, 203, 62, 255, 65, 105, 50,
+            ],
+            [
+                108, 50, 96, 251, 1, 199, 33, 182, 185, 59, 73, 50, 182, 99, 41, 140, 56, 200, 71,
+                130, 131, 3, 30, 98, 249, 146, 183, 3, 60, 31, 220, 50,
+            ],
+            [
+                60, 228, 0, 94, 149, 92, 148, 247, 240, 24, 192, 51, 243, 94, 48, 143, 253, 118,
+                217, 247, 137, 47, 131, 220, 219, 87, 5, 111, 18, 108, 143, 30,
+            ],
+        ],
+        [
+            [
+                241, 121, 29, 43, 3, 53, 1, 2, 229, 26, 62, 107, 141, 103, 211, 191, 43, 216, 175,
+                201, 149, 94, 114, 142, 236, 6, 149, 69, 64, 46, 182, 12,
+            ],
+            [
+                51, 23, 127, 40, 120, 7, 143, 245, 0, 73, 5, 46, 80, 194, 10, 80, 95, 91, 237, 164,
+                243, 52, 145, 113, 56, 81, 29, 219, 146, 214, 245, 43,
+            ],
+            [
+                189, 114, 254, 79, 120, 21, 30, 223, 149, 118, 250, 60, 92, 36, 98, 10, 60, 129,
+                84, 227, 199, 135, 147, 60, 46, 16, 108, 25, 6, 88, 212, 44,
+            ],
+            [
+                208, 56, 145, 141, 67, 184, 206, 182, 190, 226, 34, 151, 85, 100, 177, 80, 101, 33,
+                193, 21, 87, 136, 7, 83, 13, 193, 162, 20, 59, 140, 160, 16,
+            ],
+            [
+                111, 124, 56, 73, 199, 111, 232, 230, 71, 202, 154, 230, 223, 227, 41, 195, 185,
+                248, 163, 112, 161, 112, 206, 231, 92, 139, 219, 43, 231, 213, 176, 47,
+            ],
+            [
+                184, 102, 169, 151, 61, 246, 181, 155, 163, 222, 48, 88, 22, 160, 35, 94, 92, 198,
+                133, 232, 24, 243, 230, 250, 242, 185, 43, 46, 72, 230, 51, 53,
+            ],
+            [
+                19, 164, 11, 189, 133, 121, 152, 241, 146, 95, 33, 190, 61, 49, 19, 4, 132, 236,
+                51, 7, 89, 3, 27, 52, 211, 143, 55, 197, 221, 239, 183, 16,
+            ],
+            [
+                0, 216, 176, 142, 181, 78, 102, 53, 71, 227, 10, 202, 26, 132, 115, 102, 93, 20, 2,
+                201, 239, 13, 244, 37, 212, 190, 219, 132, 77, 165, 91, 46,
+            ],
+        ],
+        [
+            [
+                2, 88, 216, 127, 101, 32, 7, 144, 16, 179, 247, 67, 8, 254, 236, 66, 132, 30, 87,
+                222, 86, 81, 94, 219, 148, 55, 213, 112, 39, 201, 200, 55,
+            ],
+            [
+                156, 218, 226, 48, 163, 251, 83, 68, 21, 223, 85, 141, 231, 72, 214, 84, 153, 128,
+                246, 55, 209, 121, 183, 199, 78, 15, 18, 55, 104, 94, 79, 44,
+            ],
+            [
+                158, 189, 122, 238, 44, 30, 12, 177, 174, 209, 205, 78, 154, 190, 88, 157, 96, 29,
+                35, 252, 129, 69, 121, 94, 165, 248, 23, 222, 19, 220, 242, 26,
+            ],
+            [
+                37, 185, 197, 84, 168, 139, 148, 202, 135, 78, 191, 178, 24, 31, 157, 40, 248, 182,
+                183, 50, 84, 108, 147, 31, 107, 6, 236, 74, 61, 101, 236, 52,
+            ],
+            [
+                128, 93, 144, 54, 142, 81, 222, 195, 240, 19, 173, 252, 205, 222, 240, 184, 98, 79,
+                4, 29, 218, 15, 122, 213, 240, 17, 6, 254, 180, 127, 231, 20,
+            ],
+            [
+                175, 121, 115, 225, 234, 249, 185, 136, 30, 107, 163, 48, 37, 126, 41, 82, 86, 168,
+                100, 172, 60, 143, 34, 238, 62, 205, 211, 239, 82, 234, 86, 57,
+            ],
+            [
+                153, 45, 83, 21, 130, 160, 242, 247, 190, 235, 94, 129, 86, 227, 19, 211, 83, 70,
+                227, 144, 176, 219, 213, 16, 58, 155, 117, 36, 33, 122, 239, 57,
+            ],
+            [
+                8, 171, 86, 215, 229, 197, 98, 110, 226, 93, 25, 10, 90, 96, 102, 199, 224, 43, 18,
+                245, 26, 43, 35, 160, 164, 75, 58, 115, 215, 2, 186, 7,
+            ],
+        ],
+        [
+            [
+                118, 139, 116, 79, 3, 236, 72, 166, 206, 3, 97, 219, 142, 66, 60, 215, 139, 81,
+                186, 99, 46, 138, 124, 20, 40, 252, 48, 144, 34, 213, 98, 57,
+            ],
+            [
+                119, 245, 166, 110, 218, 200, 220, 4, 190, 7, 217, 201, 225, 178, 28, 142, 42, 126,
+                157, 94, 155, 33, 101, 230, 11, 124, 7, 61, 247, 33, 138, 36,
+            ],
+            [
+                60, 24, 225, 113, 237, 124, 135, 170, 136, 216, 8, 30, 146, 238, 99, 25, 108, 0,
+                193, 161, 10, 90, 201, 205, 254, 75, 120, 83, 52, 108, 195, 48,
+            ],
+            [
+                99, 113, 231, 0, 65, 53, 216, 128, 220, 105, 131, 24, 0, 189, 145, 79, 233, 121,
+                91, 159, 14, 92, 118, 237, 141, 88, 105, 54, 198, 119, 177, 31,
+            ],
+            [
+                110, 26, 64, 154, 29, 168, 108, 157, 188, 197, 66, 147, 213, 223, 163, 199, 178,
+                198, 210, 189, 192, 34, 217, 249, 193, 220, 166, 60, 69, 180, 84, 17,
+            ],
+            [
+                119, 39, 55, 20, 244, 60, 247, 173, 6, 170, 138, 135, 222, 92, 89, 164, 229, 94,
+                154, 34, 8, 32, 66, 175, 1, 68, 136, 161, 97, 202, 136, 23,
+            ],
+            [
+                27, 32, 202, 41, 147, 210, 96, 189, 20, 60, 121, 7, 181, 188, 233, 152, 188, 181,
+                19, 76, 137, 84, 110, 238, 239, 87, 137, 127, 250, 119, 51, 49,
+            ],
+            [
+                89, 138, 142, 207, 126, 136, 147, 160, 92, 111, 129, 222, 164, 8, 222, 118, 40,
+                172, 179, 186, 214, 61, 193, 43, 9, 34, 232, 230, 253, 9, 122, 54,
+            ],
+        ],
+        [
+            [
+                35, 63, 184, 43, 191, 147, 155, 36, 93, 54, 105, 1, 218, 195, 21, 127, 240, 243,
+                255, 250, 215, 175, 44, 137, 185, 46, 53, 230, 198, 164, 186, 54,
+            ],
+            [
+                118, 95, 18, 190, 98, 139, 117, 58, 202, 228, 84, 159, 196, 221, 9, 175, 184, 190,
+                172, 78, 88, 155, 237, 50, 9, 107, 24, 222, 239, 172, 191, 9,
+            ],
+            [
+                143, 80, 20, 114, 173, 227, 18, 19, 126, 184, 251, 254, 68, 14, 31, 113, 67, 80,
+                210, 106, 253, 100, 77, 150, 82, 62, 242, 179, 231, 196, 137, 58,
+            ],
+            [
+                204, 5, 176, 169, 198, 8, 110, 213, 215, 46, 142, 60, 123, 28, 116, 156, 242, 196,
+                83, 12, 101, 209, 227, 217, 105, 86, 175, 181, 39, 210, 47, 59,
+            ],
+            [
+                242, 118, 201, 115, 222, 189, 185, 241, 22, 208, 164, 74, 196, 90, 145, 104, 153,
+                61, 33, 47, 149, 187, 74, 234, 11, 214, 196, 111, 250, 2, 241, 61,
+            ],
+            [
+                118, 32, 89, 127, 181, 240, 80, 123, 115, 212, 163, 107, 150, 124, 222, 159, 182,
+                173, 228, 101, 131, 6, 175, 29, 219, 106, 145, 212, 34, 243, 157, 36,
+            ],
+            [
+                70, 73, 53, 100, 211, 86, 238, 41, 125, 38, 247, 91, 6, 181, 62, 40, 177, 59, 4,
+                170, 84, 7, 100, 228, 18, 135, 146, 187, 159, 64, 81, 8,
+            ],
+            [
+                165, 55, 209, 122, 13, 172, 155, 32, 126, 58, 93, 156, 41, 107, 19, 192, 253, 207,
+                35, 193, 57, 208, 80, 58, 65, 243, 57, 232, 89, 107, 40, 25,
+            ],
+        ],
+        [
+            [
+                86, 55, 75, 158, 34, 174, 175, 124, 228, 106, 70, 20, 232, 50, 17, 167, 144, 208,
+                27, 51, 148, 251, 53, 209, 235, 184, 27, 91, 73, 217, 101, 5,
+            ],
+            [
+                80, 95, 87, 83, 32, 114, 34, 18, 187, 203, 150, 108, 158, 26, 142, 188, 21, 52, 47,
+                25, 17, 30, 181, 0, 212, 40, 229, 9, 94, 171, 139, 13,
+            ],
+            [
+                147, 8, 22, 131, 6, 228, 107, 247, 147, 169, 24, 87, 240, 8, 195, 27, 14, 201, 124,
+                219, 33, 113, 4, 42, 116, 85, 122, 225, 44, 56, 210, 58,
+            ],
+            [
+                186, 197, 90, 82, 92, 33, 64, 35, 14, 151, 249, 56, 99, 217, 10, 142, 142, 190,
+                225, 121, 211, 42, 171, 227, 42, 28, 148, 220, 113, 79, 13, 56,
+            ],
+            [
+                81, 26, 0, 30, 194, 56, 126, 229, 100, 15, 57, 182, 188, 224, 164, 101, 179, 54,
+                217, 207, 66, 242, 154, 141, 135, 133, 17, 245, 50, 202, 253, 20,
+            ],
+            [
+                170, 243, 170, 80, 97, 70, 153, 123, 129, 67, 44, 23, 252, 43, 17, 249, 77, 51,
+                191, 193, 171, 179, 67, 53, 19, 102, 212, 14, 43, 83, 206, 3,
+            ],
+            [
+                12, 12, 209, 190, 226, 162, 107, 156, 90, 170, 128, 63, 102, 58, 199, 37, 232, 154,
+                116, 214, 31, 20, 186, 67, 126, 105, 106, 196, 157, 211, 119, 14,
+            ],
+            [
+                164, 173, 116, 56, 22, 199, 226, 154, 54, 46, 52, 168, 45, 142, 110, 51, 230, 251,
+                100, 182, 100, 65, 87, 169, 49, 199, 59, 37, 165, 164, 9, 33,
+            ],
+        ],
+        [
+            [
+                62, 85, 44, 15, 116, 108, 175, 6, 144, 32, 91, 226, 219, 167, 219, 14, 96, 25, 217,
+                243, 169, 177, 209, 179, 86, 17, 191, 0, 55, 1, 97, 6,
+            ],
+            [
+                196, 87, 13, 183, 106, 125, 91, 198, 112, 158, 176, 145, 163, 125, 133, 121, 89,
+                220, 140, 69, 73, 224, 148, 68, 212, 73, 212, 121, 65, 51, 228, 39,
+            ],
+            [
+                52, 9, 191, 24, 112, 215, 31, 121, 85, 72, 9, 94, 218, 45, 41, 220, 62, 27, 123,
+                119, 85, 73, 0, 148, 176, 210, 161, 0, 177, 115, 120, 28,
+            ],
+            [
+                177, 101, 23, 95, 129, 228, 130, 17, 144, 85, 186, 140, 30, 7, 112, 35, 54, 36, 6,
+                206, 194, 45, 114, 59, 125, 189, 102, 183, 102, 246, 135, 53,
+            ],
+            [
+                197, 98, 242, 18, 147, 79, 58, 157, 45, 178, 146, 146, 237, 34, 41, 186, 213, 67,
+                90, 176, 152, 248, 20, 63, 8, 26, 192, 226, 54, 177, 155, 17,
+            ],
+            [
+                131, 118, 65, 102, 120, 126, 117, 123, 79, 92, 210, 62, 195, 250, 211, 213, 196,
+                237, 193, 156, 121, 217, 178, 223, 61, 228, 233, 232, 1, 66, 7, 9,
+            ],
+            [
+                175, 91, 131, 148, 60, 59, 156, 109, 53, 37, 10, 177, 184, 6, 249, 3, 218, 106, 86,
+                106, 79, 75, 242, 230, 181, 226, 136, 108, 181, 132, 125, 25,
+            ],
+            [
+                203, 97, 171, 105, 216, 242, 47, 5, 226, 160, 207, 12, 157, 85, 99, 195, 149, 243,
+                222, 224, 45, 222, 109, 33, 186, 19, 175, 212, 165, 43, 65, 6,
+            ],
+        ],
+        [
+            [
+                73, 48, 154, 122, 229, 179, 85, 78, 45, 223, 241, 165, 85, 48, 174, 78, 49, 85, 48,
+                117, 154, 88, 143, 185, 154, 69, 155, 101, 249, 27, 149, 49,
+            ],
+            [
+                121, 17, 41, 6, 184, 112, 130, 255, 154, 43, 73, 216, 59, 3, 148, 12, 144, 230, 61,
+                192, 31, 239, 132, 32, 241, 229, 248, 239, 189, 139, 125, 48,
+            ],
+            [
+                58, 90, 102, 250, 156, 189, 66, 38, 193, 114, 206, 119, 6, 6, 35, 53, 111, 230, 10,
+                118, 78, 201, 229, 139, 214, 49, 100, 133, 205, 117, 85, 59,
+            ],
+            [
+                112, 49, 213, 84, 97, 36, 85, 34, 77, 160, 216, 173, 228, 114, 178, 144, 187, 209,
+                152, 244, 231, 186, 11, 116, 201, 104, 7, 107, 88, 48, 111, 53,
+            ],
+            [
+                154, 202, 150, 116, 177, 179, 22, 32, 20, 153, 194, 38, 142, 190, 251, 251, 23,
+                120, 243, 231, 199, 110, 56, 233, 106, 201, 156, 104, 54, 142, 46, 14,
+            ],
+            [
+                108, 248, 120, 118, 214, 211, 120, 7, 50, 2, 240, 168, 144, 158, 60, 23, 146, 86,
+                254, 231, 79, 220, 36, 88, 146, 223, 214, 222, 101, 247, 79, 29,
+            ],
+            [
+                95, 161, 228, 244, 207, 192, 10, 220, 180, 189, 185, 120, 65, 39, 82, 73, 223, 67,
+                220, 108, 117, 91, 161, 178, 13, 255, 53, 96, 101, 220, 134, 35,
+            ],
+            [
+                237, 220, 142, 200, 179, 250, 202, 118, 123, 48, 203, 23, 137, 108, 181, 32, 230,
+                231, 249, 56, 82, 6, 194, 8, 59, 138, 212, 80, 142, 179, 121, 14,
+            ],
+        ],
+        [
+            [
+                119, 119, 110, 149, 182, 21, 3, 216, 66, 20, 153, 133, 233, 52, 157, 164, 104, 161,
+                3, 119, 120, 0, 60, 116, 137, 46, 168, 44, 115, 130, 92, 5,
+            ],
+            [
+                14, 50, 172, 183, 254, 7, 142, 239, 96, 103, 191, 249, 240, 70, 91, 108, 144, 219,
+                140, 18, 150, 49, 149, 58, 102, 246, 232, 227, 125, 159, 23, 19,
+            ],
+            [
+                77, 104, 246, 89, 21, 245, 97, 69, 50, 32, 94, 202, 79, 30, 43, 177, 45, 46, 94,
+                233, 182, 246, 194, 137, 117, 47, 250, 219, 0, 208, 36, 4,
+            ],
+            [
+                69, 79, 6, 76, 151, 78, 87, 100, 45, 67, 152, 248, 99, 239, 233, 159, 236, 151,
+                128, 174, 170, 96, 242, 154, 28, 68, 49, 169, 110, 12, 203, 63,
+            ],
+            [
+                78, 241, 29, 49, 215, 86, 16, 211, 78, 224, 125, 236, 36, 103, 76, 70, 125, 116,
+                218, 128, 96, 20, 2, 251, 175, 210, 86, 242, 248, 23, 90, 50,
+            ],
+            [
+                14, 188, 33, 125, 34, 14, 33, 167, 162, 34, 138, 64, 77, 108, 89, 80, 150, 47, 156,
+                199, 108, 137, 162, 241, 81, 70, 91, 217, 250, 57, 104, 29,
+            ],
+            [
+                182, 203, 46, 77, 227, 83, 153, 127, 251, 15, 131, 240, 254, 255, 230, 174, 206,
+                34, 46, 41, 197, 60, 101, 72, 236, 58, 111, 186, 163, 28, 108, 25,
+            ],
+            [
+                53, 216, 224, 85, 31, 116, 27, 207, 53, 241, 87, 190, 203, 118, 146, 217, 156, 140,
+                201, 112, 128, 104, 212, 139, 22, 183, 52, 223, 238, 223, 61, 11,
+            ],
+        ],
+        [
+            [
+                167, 237, 221, 31, 117, 154, 83, 196, 90, 249, 28, 72, 11, 71, 230, 121, 34, 64,
+                42, 117, 95, 13, 73, 96, 194, 85, 143, 102, 24, 89, 240, 40,
+            ],
+            [
+                136, 217, 221, 1, 70, 159, 249, 139, 111, 226, 99, 20, 22, 209, 88, 46, 122, 205,
+                213, 213, 87, 57, 231, 240, 252, 229, 110, 26, 195, 15, 173, 13,
+            ],
+            [
+                80, 126, 120, 20, 50, 83, 49, 227, 248, 60, 162, 171, 148, 106, 144, 125, 31, 112,
+                182, 236, 149, 128, 189, 130, 217, 1, 241, 56, 172, 73, 67, 43,
+            ],
+            [
+                0, 129, 228, 142, 42, 156, 230, 248, 18, 139, 211, 208, 63, 66, 112, 149, 62, 196,
+                100, 82, 1, 183, 54, 140, 187, 42, 190, 213, 102, 246, 62, 45,
+            ],
+            [
+                7, 114, 7, 211, 38, 248, 93, 186, 73, 153, 52, 5, 14, 205, 64, 179, 59, 242, 166,
+                69, 220, 32, 169, 147, 85, 209, 122, 58, 69, 147, 55, 22,
+            ],
+            [
+                71, 18, 130, 144, 247, 204, 150, 74, 189, 55, 62, 199, 6, 220, 114, 117, 156, 47,
+                131, 200, 40, 156, 4, 204, 11, 169, 67, 14, 155, 149, 32, 28,
+            ],
+            [
+                2, 208, 182, 150, 190, 210, 238, 169, 252, 22, 247, 61, 143, 189, 156, 187, 163,
+                99, 206, 196, 178, 40, 90, 217, 86, 150, 72, 209, 225, 2, 224, 15,
+            ],
+            [
+                94, 138, 73, 146, 145, 55, 124, 241, 104, 192, 0, 39, 167, 154, 158, 141, 235, 173,
+                88, 84, 244, 70, 57, 83, 120, 158, 76, 219, 102, 76, 146, 32,
+            ],
+        ],
+        [
+            [
+                95, 170, 97, 115, 125, 115, 200, 164, 62, 255, 78, 224, 71, 154, 234, 76, 53, 39,
+                127, 13, 4, 9, 133, 111, 221, 225, 246, 208, 182, 238, 181, 36,
+            ],
+            [
+                62, 31, 110, 82, 142, 242, 98, 194, 108, 72, 34, 233, 142, 253, 57, 65, 194, 102,
+                176, 78, 231, 32, 14, 51, 140, 132, 192, 250, 165, 216, 206, 29,
+            ],
+            [
+                96, 124, 230, 226, 153, 10, 166, 186, 160, 216, 18, 239, 57, 175, 174, 250, 209,
+                76, 251, 8, 206, 174, 75, 173, 33, 130, 79, 147, 39, 95, 174, 32,
+            ],
+            [
+                191, 221, 251, 157, 213, 150, 229, 193, 133, 128, 99, 76, 11, 133, 172, 125, 239,
+                235, 198, 122, 227, 157, 22, 181, 133, 71, 19, 128, 16, 199, 199, 24,
+            ],
+            [
+                138, 133, 112, 10, 72, 100, 219, 167, 19, 135, 16, 95, 109, 218, 116, 63, 23, 150,
+                192, 26, 251, 206, 74, 89, 70, 39, 242, 202, 169, 103, 66, 7,
+            ],
+            [
+                41, 18, 111, 75, 47, 9, 133, 237, 147, 173, 127, 4, 8, 146, 234, 2, 240, 196, 191,
+                86, 0, 141, 20, 187, 20, 5, 237, 220, 200, 141, 170, 16,
+            ],
+            [
+                57, 238, 216, 159, 219, 228, 68, 136, 224, 30, 132, 228, 60, 0, 199, 210, 188, 176,
+                75, 171, 255, 27, 22, 231, 89, 147, 130, 11, 66, 51, 163, 28,
+            ],
+            [
+                107, 221, 23, 170, 74, 182, 64, 242, 253, 207, 40, 2, 90, 107, 168, 194, 47, 187,
+                95, 197, 87, 106, 29, 209, 169, 66, 78, 122, 170, 49, 31, 49,
+            ],
+        ],
+        [
+            [
+                118, 218, 61, 247, 39, 172, 139, 103, 41, 124, 223, 19, 159, 36, 71, 206, 157, 143,
+                86, 44, 185, 130, 1, 140, 159, 140, 189, 237, 97, 205, 111, 0,
+            ],
+            [
+                244, 191, 200, 66, 252, 243, 90, 104, 238, 242, 17, 238, 76, 208, 33, 72, 180, 160,
+                20, 20, 157, 8, 130, 92, 35, 207, 236, 39, 67, 162, 42, 21,
+            ],
+            [
+                160, 247, 87, 123, 44, 232, 125, 44, 151, 66, 250, 0, 76, 232, 93, 249, 224, 232,
+                77, 145, 72, 70, 36, 1, 1, 5, 135, 75, 90, 189, 185, 31,
+            ],
+            [
+                234, 197, 136, 105, 186, 109, 59, 99, 180, 48, 228, 193, 143, 155, 149, 245, 195,
+                65, 46, 28, 174, 64, 253, 209, 46, 250, 44, 87, 184, 241, 61, 40,
+            ],
+            [
+                185, 224, 225, 74, 217, 168, 0, 115, 163, 105, 218, 122, 35, 9, 206, 155, 186, 37,
+                48, 78, 195, 150, 48, 198, 60, 206, 123, 252, 135, 84, 45, 50,
+            ],
+            [
+                64, 36, 74, 92, 197, 197, 167, 78, 107, 61, 96, 115, 219, 209, 106, 236, 226, 33,
+                198, 171, 241, 161, 201, 111, 47, 8, 23, 31, 253, 119, 197, 62,
+            ],
+            [
+                122, 24, 153, 245, 6, 206, 242, 250, 95, 49, 76, 194, 220, 71, 185, 70, 44, 251,
+                72, 178, 52, 241, 54, 222, 77, 233, 228, 68, 111, 109, 95, 12,
+            ],
+            [
+                170, 203, 153, 164, 10, 14, 18, 150, 139, 46, 172, 100, 96, 53, 93, 81, 135, 0,
+                149, 172, 156, 95, 129, 225, 121, 137, 235, 107, 122, 212, 216, 56,
+            ],
+        ],
+        [
+            [
+                73, 108, 214, 42, 166, 94, 175, 27, 63, 251, 83, 33, 70, 241, 235, 37, 70, 142,
+                254, 23, 98, 76, 166, 123, 255, 53, 74, 97, 44, 2, 83, 17,
+            ],
+            [
+                155, 90, 214, 101, 153, 196, 150, 247, 240, 71, 83, 93, 54, 253, 175, 128, 15, 248,
+                229, 240, 223, 239, 215, 167, 3, 139, 19, 113, 180, 92, 47, 39,
+            ],
+            [
+                38, 19, 161, 75, 163, 28, 94, 40, 249, 54, 239, 135, 181, 7, 194, 196, 72, 113, 49,
+                57, 216, 234, 235, 205, 22, 79, 160, 208, 201, 243, 245, 28,
+            ],
+            [
+                149, 48, 123, 144, 113, 86, 247, 128, 50, 205, 209, 167, 63, 37, 210, 164, 54, 167,
+                35, 14, 220, 229, 114, 254, 77, 155, 140, 155, 149, 84, 86, 37,
+            ],
+            [
+                138, 64, 55, 180, 24, 145, 26, 60, 75, 220, 169, 77, 84, 207, 182, 235, 78, 182,
+                184, 173, 37, 183, 145, 130, 119, 84, 222, 182, 14, 177, 1, 30,
+            ],
+            [
+                129, 196, 50, 145, 134, 133, 71, 125, 249, 180, 241, 73, 139, 161, 187, 254, 15,
+                112, 17, 64, 15, 105, 190, 127, 8, 116, 24, 197, 83, 210, 23, 59,
+            ],
+            [
+                69, 25, 119, 193, 205, 167, 182, 78, 105, 187, 121, 5, 149, 215, 19, 89, 34, 76,
+                160, 158, 232, 137, 133, 62, 95, 138, 153, 194, 129, 254, 250, 54,
+            ],
+            [
+                164, 140, 182, 250, 58, 76, 255, 16, 155, 103, 224, 126, 225, 19, 240, 213, 8, 79,
+                50, 1, 149, 42, 236, 173, 146, 107, 151, 170, 52, 217, 209, 61,
+            ],
+        ],
+        [
+            [
+                48, 22, 113, 187, 45, 6, 236, 253, 61, 186, 238, 253, 57, 130, 105, 93, 7, 216,
+                209, 140, 110, 122, 215, 143, 222, 224, 218, 194, 110, 16, 62, 30,
+            ],
+            [
+                71, 134, 242, 142, 83, 196, 5, 242, 255, 190, 142, 34, 175, 122, 226, 109, 27, 10,
+                175, 105, 135, 112, 97, 191, 250, 32, 77, 43, 114, 217, 222, 11,
+            ],
+            [
+                100, 192, 63, 73, 84, 38, 135, 178, 81, 38, 28, 67, 216, 201, 21, 94, 221, 71, 8,
+                101, 123, 216, 123, 142, 208, 100, 127, 104, 123, 229, 152, 29,
+            ],
+            [
+                11, 114, 220, 69, 14, 208, 44, 200, 236, 111, 207, 134, 158, 51, 96, 159, 171, 4,
+                36, 8, 246, 204, 84, 200, 217, 13, 163, 162, 1, 121, 146, 55,
+            ],
+            [
+                149, 255, 221, 162, 35, 169, 121, 40, 82, 146, 188, 191, 235, 227, 37, 61, 172,
+                200, 238, 188, 237, 1, 103, 68, 145, 29, 29, 120, 179, 52, 210, 5,
+            ],
+            [
+                212, 220, 229, 254, 165, 213, 181, 240, 228, 145, 220, 125, 123, 26, 68, 15, 250,
+                166, 204, 126, 93, 129, 49, 205, 189, 112, 150, 5, 86, 198, 120, 45,
+            ],
+            [
+                193, 233, 141, 214, 179, 30, 248, 41, 139, 183, 214, 7, 13, 38, 87, 89, 151, 59,
+                235, 227, 209, 94, 57, 90, 86, 44, 105, 219, 219, 43, 236, 9,
+            ],
+            [
+                25, 75, 137, 231, 103, 70, 50, 121, 11, 177, 118, 67, 144, 40, 68, 109, 38, 53, 27,
+                36, 149, 189, 60, 140, 124, 228, 90, 159, 247, 190, 86, 6,
+            ],
+        ],
+        [
+            [
+                99, 162, 246, 92, 107, 62, 251, 216, 140, 138, 107, 224, 69, 122, 233, 207, 32,
+                159, 198, 187, 31, 134, 155, 113, 71, 38, 0, 116, 81, 58, 210, 40,
+            ],
+            [
+                127, 207, 49, 117, 206, 86, 31, 1, 250, 135, 28, 96, 121, 5, 221, 40, 240, 22, 43,
+                62, 221, 81, 233, 39, 160, 189, 108, 97, 187, 159, 249, 9,
+            ],
+            [
+                194, 78, 226, 148, 239, 193, 77, 123, 161, 121, 248, 24, 163, 124, 164, 81, 211,
+                53, 19, 39, 5, 241, 234, 88, 137, 80, 213, 237, 50, 128, 228, 19,
+            ],
+            [
+                217, 30, 143, 74, 159, 174, 33, 227, 227, 26, 248, 96, 9, 168, 65, 160, 78, 152,
+                42, 245, 186, 178, 122, 198, 194, 192, 230, 101, 242, 141, 95, 39,
+            ],
+            [
+                69, 214, 169, 101, 56, 213, 128, 249, 165, 159, 164, 204, 211, 252, 121, 236, 85,
+                81, 12, 218, 61, 137, 62, 100, 152, 86, 114, 153, 248, 9, 222, 1,
+            ],
+            [
+                220, 72, 63, 36, 178, 93, 119, 97, 100, 220, 123, 164, 80, 87, 245, 60, 164, 143,
+                70, 184, 80, 218, 21, 21, 156, 183, 65, 134, 178, 195, 208, 18,
+            ],
+            [
+                212, 19, 109, 53, 82, 236, 128, 174, 211, 140, 241, 117, 125, 141, 111, 0, 124,
+                215, 194, 101, 97, 75, 25, 43, 54, 124, 206, 199, 237, 203, 31, 58,
+            ],
+            [
+                158, 249, 83, 234, 58, 152, 104, 67, 71, 55, 169, 221, 231, 38, 135, 5, 157, 229,
+                81, 106, 172, 190, 89, 119, 187, 51, 152, 16, 238, 153, 217, 10,
+            ],
+        ],
+        [
+            [
+                122, 26, 154, 34, 249, 234, 189, 205, 195, 93, 129, 13, 117, 224, 233, 134, 96,
+                168, 255, 67, 130, 58, 90, 120, 252, 249, 190, 158, 110, 228, 187, 41,
+            ],
+            [
+                115, 250, 251, 197, 1, 27, 80, 124, 219, 13, 43, 212, 27, 86, 138, 22, 238, 98, 55,
+                183, 168, 16, 171, 198, 19, 117, 168, 40, 88, 40, 154, 50,
+            ],
+            [
+                82, 43, 93, 195, 21, 2, 20, 187, 59, 46, 191, 141, 22, 149, 79, 52, 223, 136, 251,
+                1, 236, 247, 127, 247, 144, 39, 83, 165, 95, 130, 108, 26,
+            ],
+            [
+                113, 88, 48, 42, 51, 76, 94, 243, 181, 125, 118, 144, 206, 30, 9, 58, 27, 237, 67,
+                220, 170, 247, 150, 23, 110, 246, 29, 41, 71, 205, 193, 8,
+            ],
+            [
+                83, 62, 97, 105, 80, 113, 175, 245, 91, 27, 40, 179, 142, 57, 53, 224, 65, 108, 86,
+                222, 17, 11, 171, 200, 49, 53, 11, 66, 9, 225, 198, 21,
+            ],
+            [
+                9, 146, 170, 41, 178, 219, 215, 78, 67, 1, 1, 160, 125, 83, 223, 200, 53, 153, 205,
+                222, 214, 180, 149, 145, 231, 128, 218, 186, 108, 45, 28, 50,
+            ],
+            [
+                39, 163, 81, 184, 11, 120, 181, 55, 48, 118, 146, 109, 33, 235, 214, 157, 51, 41,
+                191, 118, 87, 252, 191, 3, 161, 105, 97, 204, 234, 210, 62, 44,
+            ],
+            [
+                236, 27, 109, 216, 241, 14, 55, 142, 188, 147, 209, 142, 216, 81, 169, 26, 69, 207,
+                47, 146, 105, 13, 241, 40, 59, 51, 52, 198, 44, 128, 16, 15,
+            ],
+        ],
+        [
+            [
+                218, 185, 44, 245, 185, 72, 20, 177, 65, 86, 34, 3, 186, 166, 177, 31, 233, 226,
+                198, 65, 163, 158, 82, 158, 56, 195, 205, 49, 148, 241, 192, 57,
+            ],
+            [
+                172, 137, 114, 53, 200, 83, 33, 168, 135, 242, 174, 203, 147, 40, 163, 190, 32, 42,
+                57, 231, 237, 149, 179, 146, 116, 204, 98, 41, 149, 0, 3, 30,
+            ],
+            [
+                15, 149, 77, 11, 220, 108, 105, 195, 208, 221, 21, 94, 211, 30, 61, 182, 237, 198,
+                185, 146, 108, 149, 31, 134, 120, 27, 116, 173, 223, 91, 150, 11,
+            ],
+            [
+                75, 243, 173, 54, 190, 72, 74, 14, 16, 201, 73, 66, 227, 59, 90, 152, 155, 187, 29,
+                255, 206, 24, 238, 143, 159, 92, 181, 250, 225, 175, 182, 37,
+            ],
+            [
+                150, 135, 246, 79, 13, 126, 52, 207, 17, 84, 202, 206, 1, 35, 0, 78, 221, 210, 107,
+                45, 149, 184, 100, 189, 169, 112, 243, 43, 119, 78, 207, 63,
+            ],
+            [
+                63, 212, 44, 56, 114, 250, 124, 19, 66, 204, 94, 26, 32, 70, 237, 72, 22, 247, 194,
+                83, 53, 155, 205, 230, 106, 207, 98, 72, 156, 157, 202, 54,
+            ],
+            [
+                40, 62, 213, 229, 107, 27, 223, 118, 105, 127, 157, 177, 105, 129, 32, 10, 36, 61,
+                198, 145, 153, 191, 34, 45, 60, 152, 182, 0, 230, 132, 149, 12,
+            ],
+            [
+                220, 130, 112, 102, 194, 122, 178, 251, 7, 85, 219, 24, 120, 221, 223, 158, 213,
+                44, 44, 55, 83, 27, 19, 240, 158, 0, 162, 39, 72, 211, 197, 21,
+            ],
+        ],
+        [
+            [
+                216, 35, 236, 77, 45, 238, 156, 47, 36, 92, 201, 189, 140, 180, 184, 148, 143, 228,
+                173, 50, 42, 11, 0, 231, 44, 22, 30, 85, 74, 71, 251, 12,
+            ],
+            [
+                40, 131, 159, 176, 190, 85, 22, 82, 47, 251, 84, 70, 8, 65, 140, 233, 126, 85, 65,
+                167, 36, 134, 164, 204, 18, 34, 109, 159, 58, 255, 7, 49,
+            ],
+            [
+                241, 16, 86, 33, 29, 154, 14, 74, 159, 25, 239, 86, 226, 19, 98, 240, 163, 32, 134,
+                232, 234, 96, 251, 85, 116, 29, 34, 34, 184, 84, 120, 56,
+            ],
+            [
+                216, 247, 57, 126, 59, 18, 183, 78, 38, 122, 136, 129, 133, 55, 115, 167, 120, 178,
+                192, 198, 21, 174, 229, 77, 9, 72, 163, 159, 34, 238, 251, 2,
+            ],
+            [
+                238, 158, 25, 229, 185, 192, 63, 5, 15, 35, 170, 117, 165, 126, 160, 217, 135, 58,
+                71, 36, 152, 202, 2, 53, 44, 191, 145, 17, 143, 132, 125, 58,
+            ],
+            [
+                8, 112, 38, 222, 120, 157, 216, 156, 166, 231, 120, 20, 166, 12, 145, 153, 37, 6,
+                80, 232, 92, 137, 38, 228, 29, 21, 101, 4, 36, 193, 105, 57,
+            ],
+            [
+                91, 112, 108, 219, 52, 27, 131, 224, 204, 34, 112, 208, 235, 159, 206, 238, 140,
+                206, 0, 94, 145, 238, 133, 107, 104, 235, 16, 117, 164, 74, 205, 56,
+            ],
+            [
+                116, 21, 204, 171, 177, 163, 126, 78, 17, 121, 52, 87, 124, 92, 98, 188, 81, 185,
+                10, 99, 125, 126, 61, 191, 177, 97, 248, 140, 32, 78, 105, 49,
+            ],
+        ],
+        [
+            [
+                154, 207, 159, 255, 231, 177, 157, 155, 174, 99, 87, 97, 195, 129, 141, 38, 37, 41,
+                8, 170, 203, 56, 82, 81, 202, 146, 134, 144, 226, 126, 174, 29,
+            ],
+            [
+                130, 215, 84, 180, 20, 153, 174, 253, 68, 149, 231, 13, 22, 155, 212, 251, 192,
+                244, 105, 244, 47, 3, 5, 66, 126, 155, 245, 204, 241, 20, 17, 33,
+            ],
+            [
+                193, 187, 202, 103, 118, 150, 187, 152, 167, 91, 72, 225, 144, 234, 68, 175, 196,
+                157, 201, 165, 77, 177, 172, 181, 171, 212, 82, 18, 63, 93, 247, 54,
+            ],
+            [
+                137, 69, 187, 146, 69, 10, 155, 76, 149, 105, 45, 54, 32, 166, 73, 186, 147, 101,
+                14, 222, 39, 115, 86, 109, 149, 4, 206, 139, 251, 46, 168, 15,
+            ],
+            [
+                5, 121, 206, 86, 230, 203, 214, 164, 8, 204, 177, 216, 170, 20, 148, 139, 210, 34,
+                106, 17, 66, 43, 132, 10, 119, 234, 201, 254, 121, 116, 41, 55,
+            ],
+            [
+                145, 196, 134, 67, 70, 127, 173, 194, 9, 219, 2, 2, 16, 204, 11, 206, 78, 192, 114,
+                106, 2, 81, 103, 97, 108, 186, 245, 122, 139, 224, 220, 49,
+            ],
+            [
+                232, 183, 7, 41, 128, 11, 122, 50, 253, 246, 88, 133, 43, 18, 167, 96, 66, 244,
+                250, 90, 206, 129, 50, 185, 155, 11, 72, 168, 161, 109, 35, 16,
+            ],
+            [
+                207, 111, 213, 112, 158, 250, 147, 135, 12, 251, 63, 94, 180, 38, 30, 211, 84, 138,
+                220, 100, 137, 129, 222, 228, 250, 142, 203, 184, 163, 233, 147, 57,
+            ],
+        ],
+        [
+            [
+                215, 12, 222, 140, 19, 112, 153, 233, 54, 143, 210, 104, 120, 146, 90, 98, 6, 191,
+                246, 243, 177, 120, 217, 87, 185, 188, 242, 47, 95, 135, 231, 31,
+            ],
+            [
+                125, 157, 87, 132, 164, 88, 240, 174, 232, 217, 179, 191, 53, 140, 253, 173, 71,
+                207, 31, 44, 115, 119, 229, 210, 10, 198, 119, 71, 207, 202, 163, 29,
+            ],
+            [
+                85, 193, 29, 30, 7, 221, 12, 112, 81, 212, 78, 73, 159, 244, 25, 140, 178, 12, 82,
+                241, 49, 213, 24, 167, 112, 43, 242, 170, 244, 144, 88, 2,
+            ],
+            [
+                128, 233, 94, 199, 28, 218, 2, 119, 41, 151, 231, 42, 195, 18, 225, 32, 60, 141,
+                48, 182, 35, 85, 16, 112, 19, 154, 87, 5, 11, 86, 223, 54,
+            ],
+            [
+                8, 112, 247, 61, 66, 134, 53, 198, 190, 91, 1, 136, 114, 90, 140, 67, 22, 153, 134,
+                108, 206, 104, 168, 80, 115, 200, 238, 93, 43, 75, 5, 6,
+            ],
+            [
+                149, 237, 41, 235, 177, 229, 194, 65, 208, 203, 69, 141, 95, 61, 25, 227, 90, 183,
+                29, 178, 255, 43, 5, 212, 202, 182, 74, 85, 96, 71, 111, 27,
+            ],
+            [
+                145, 83, 183, 189, 5, 125, 215, 1, 236, 126, 114, 89, 158, 220, 155, 118, 123, 99,
+                75, 201, 114, 143, 117, 126, 97, 235, 83, 47, 131, 225, 248, 62,
+            ],
+            [
+                11, 12, 254, 163, 167, 98, 249, 186, 119, 8, 81, 60, 113, 170, 246, 75, 40, 158,
+                34, 32, 55, 13, 221, 221, 107, 32, 158, 255, 151, 164, 93, 17,
+            ],
+        ],
+        [
+            [
+                141, 241, 218, 217, 190, 227, 213, 103, 247, 76, 143, 215, 213, 163, 169, 250, 45,
+                54, 54, 38, 64, 22, 179, 151, 88, 137, 84, 149, 135, 144, 137, 20,
+            ],
+            [
+                226, 67, 86, 255, 193, 200, 34, 186, 69, 77, 48, 165, 96, 156, 200, 197, 34, 13,
+                194, 6, 171, 152, 73, 184, 39, 41, 106, 33, 136, 54, 218, 43,
+            ],
+            [
+                108, 96, 78, 225, 168, 216, 141, 40, 232, 88, 238, 146, 208, 68, 87, 125, 116, 140,
+                94, 243, 101, 51, 220, 180, 113, 121, 214, 33, 167, 103, 70, 27,
+            ],
+            [
+                58, 13, 203, 214, 209, 177, 240, 40, 94, 178, 90, 113, 75, 180, 123, 149, 79, 156,
+                125, 25, 151, 17, 2, 52, 29, 42, 118, 23, 166, 147, 145, 34,
+            ],
+            [
+                215, 249, 65, 60, 53, 251, 165, 135, 138, 154, 95, 23, 170, 119, 52, 231, 164, 209,
+                75, 231, 139, 80, 131, 143, 133, 58, 98, 189, 131, 131, 156, 37,
+            ],
+            [
+                181, 176, 4, 194, 154, 95, 139, 84, 94, 162, 156, 236, 216, 83, 161, 244, 9, 2,
+                196, 200, 69, 75, 159, 18, 15, 127, 68, 253, 174, 26, 131, 57,
+            ],
+            [
+                218, 119, 227, 194, 177, 254, 31, 85, 130, 171, 157, 31, 82, 249, 76, 184, 202,
+                170, 6, 6, 27, 219, 109, 183, 89, 116, 151, 102, 199, 201, 178, 4,
+            ],
+            [
+                171, 51, 200, 24, 173, 118, 96, 96, 186, 217, 47, 219, 49, 26, 95, 59, 64, 214, 25,
+                110, 121, 3, 252, 150, 35, 213, 30, 111, 166, 80, 9, 15,
+            ],
+        ],
+        [
+            [
+                194, 141, 39, 133, 75, 56, 220, 185, 188, 203, 77, 109, 34, 69, 249, 141, 8, 119,
+                237, 145, 74, 235, 227, 165, 225, 227, 215, 82, 121, 75, 213, 22,
+            ],
+            [
+                188, 62, 206, 69, 249, 205, 60, 87, 109, 84, 39, 146, 140, 248, 49, 232, 133, 191,
+                152, 99, 153, 100, 197, 0, 73, 152, 33, 158, 211, 215, 30, 28,
+            ],
+            [
+                118, 34, 156, 116, 240, 249, 137, 95, 208, 114, 82, 236, 154, 101, 0, 114, 112,
+                106, 236, 171, 46, 46, 15, 200, 91, 215, 48, 171, 192, 139, 182, 18,
+            ],
+            [
+                232, 114, 184, 154, 159, 156, 63, 246, 48, 204, 215, 56, 226, 10, 118, 66, 80, 216,
+                32, 216, 9, 94, 188, 57, 163, 111, 178, 53, 148, 213, 238, 34,
+            ],
+            [
+                74, 7, 211, 14, 172, 241, 196, 51, 177, 77, 8, 128, 173, 21, 182, 158, 194, 162,
+                85, 67, 66, 209, 82, 124, 125, 19, 103, 145, 151, 153, 113, 2,
+            ],
+            [
+                181, 251, 82, 129, 242, 212, 208, 38, 85, 10, 19, 189, 141, 79, 65, 35, 95, 199,
+                247, 60, 89, 36, 55, 60, 211, 49, 166, 46, 246, 154, 37, 58,
+            ],
+            [
+                81, 232, 99, 43, 130, 181, 102, 220, 68, 108, 131, 216, 126, 75, 213, 68, 216, 209,
+                139, 200, 181, 95, 216, 218, 46, 85, 144, 105, 36, 29, 247, 14,
+            ],
+            [
+                81, 31, 82, 57, 226, 211, 216, 119, 239, 45, 48, 162, 200, 226, 123, 143, 96, 53,
+                2, 48, 142, 254, 213, 24, 15, 156, 169, 212, 38, 85, 185, 23,
+            ],
+        ],
+        [
+            [
+                42, 140, 237, 16, 23, 178, 41, 236, 64, 164, 135, 205, 158, 127, 147, 137, 166, 44,
+                237, 139, 2, 110, 44, 87, 148, 10, 9, 58, 117, 176, 175, 59,
+            ],
+            [
+                77, 247, 214, 77, 142, 162, 149, 119, 42, 214, 221, 45, 90, 174, 238, 14, 146, 193,
+                98, 78, 72, 187, 193, 214, 83, 202, 18, 244, 94, 115, 40, 41,
+            ],
+            [
+                125, 155, 220, 99, 102, 156, 128, 198, 28, 184, 104, 154, 147, 67, 162, 161, 186,
+                93, 198, 25, 234, 197, 14, 246, 165, 218, 47, 12, 64, 118, 187, 34,
+            ],
+            [
+                49, 11, 17, 250, 171, 238, 168, 144, 106, 117, 23, 143, 130, 24, 254, 32, 93, 234,
+                180, 5, 119, 71, 13, 242, 224, 158, 142, 144, 98, 190, 152, 40,
+            ],
+            [
+                7, 114, 195, 125, 253, 242, 121, 234, 121, 178, 20, 226, 90, 90, 207, 149, 174,
+                100, 60, 210, 149, 208, 207, 234, 228, 249, 24, 244, 161, 207, 195, 45,
+            ],
+            [
+                195, 164, 109, 236, 194, 107, 29, 112, 15, 47, 140, 222, 39, 187, 124, 241, 78, 75,
+                129, 0, 80, 167, 1, 60, 132, 155, 123, 45, 118, 38, 54, 44,
+            ],
+            [
+                76, 109, 174, 239, 148, 38, 41, 197, 107, 1, 175, 120, 210, 242, 128, 190, 183, 7,
+                216, 143, 47, 122, 222, 10, 99, 95, 159, 42, 17, 75, 59, 61,
+            ],
+            [
+                36, 211, 147, 180, 201, 61, 52, 82, 73, 226, 190, 127, 248, 125, 43, 155, 150, 86,
+                150, 147, 228, 115, 193, 51, 72, 73, 196, 253, 53, 35, 157, 6,
+            ],
+        ],
+        [
+            [
+                71, 86, 93, 93, 166, 122, 133, 176, 244, 216, 48, 55, 176, 136, 251, 197, 25, 161,
+                90, 133, 81, 154, 16, 250, 40, 157, 223, 138, 152, 156, 86, 59,
+            ],
+            [
+                57, 193, 234, 140, 53, 20, 0, 97, 101, 30, 142, 234, 203, 17, 105, 216, 78, 24,
+                111, 113, 219, 45, 230, 145, 19, 235, 57, 114, 142, 183, 252, 14,
+            ],
+            [
+                106, 169, 104, 190, 177, 239, 236, 182, 194, 73, 152, 167, 70, 113, 253, 192, 127,
+                162, 45, 176, 196, 225, 133, 124, 62, 105, 227, 105, 226, 255, 154, 38,
+            ],
+            [
+                61, 136, 79, 98, 54, 117, 33, 44, 56, 101, 251, 223, 189, 117, 218, 53, 102, 125,
+                120, 29, 61, 47, 129, 1, 66, 10, 184, 241, 112, 46, 202, 34,
+            ],
+            [
+                137, 166, 49, 34, 151, 16, 165, 5, 33, 37, 59, 128, 93, 167, 106, 16, 177, 50, 229,
+                208, 180, 197, 118, 98, 64, 216, 168, 101, 143, 92, 44, 48,
+            ],
+            [
+                183, 88, 49, 203, 139, 3, 138, 217, 78, 208, 17, 218, 43, 174, 58, 161, 132, 149,
+                45, 18, 53, 53, 168, 37, 225, 18, 150, 141, 212, 40, 218, 2,
+            ],
+            [
+                250, 165, 3, 48, 207, 98, 166, 123, 250, 30, 225, 118, 180, 24, 55, 127, 129, 78,
+                16, 138, 177, 219, 24, 131, 197, 146, 49, 195, 195, 5, 227, 47,
+            ],
+            [
+                44, 58, 173, 160, 28, 93, 154, 189, 4, 198, 182, 253, 34, 70, 209, 112, 35, 58, 78,
+                218, 105, 190, 154, 0, 82, 44, 252, 29, 39, 155, 78, 0,
+            ],
+        ],
+        [
+            [
+                213, 190, 165, 201, 151, 75, 103, 230, 140, 20, 33, 3, 89, 158, 207, 74, 143, 139,
+                122, 203, 211, 181, 59, 167, 247, 77, 206, 176, 13, 180, 220, 54,
+            ],
+            [
+                51, 241, 202, 33, 242, 138, 183, 73, 126, 103, 14, 175, 43, 218, 226, 226, 46, 98,
+                133, 223, 12, 223, 87, 109, 76, 65, 154, 0, 21, 132, 117, 48,
+            ],
+            [
+                133, 241, 229, 222, 242, 78, 206, 131, 28, 94, 154, 196, 242, 13, 133, 1, 99, 137,
+                59, 0, 191, 40, 10, 162, 0, 180, 57, 49, 183, 77, 161, 35,
+            ],
+            [
+                87, 74, 99, 217, 130, 138, 227, 121, 132, 15, 229, 168, 48, 226, 243, 80, 26, 107,
+                123, 167, 122, 247, 47, 58, 63, 45, 163, 124, 45, 57, 191, 38,
+            ],
+            [
+                115, 190, 153, 72, 39, 18, 5, 73, 69, 145, 50, 67, 150, 27, 153, 200, 127, 215,
+                113, 213, 42, 241, 236, 179, 153, 226, 217, 145, 74, 69, 134, 6,
+            ],
+            [
+                214, 38, 197, 20, 94, 54, 160, 94, 94, 72, 47, 180, 57, 24, 204, 249, 83, 23, 213,
+                4, 61, 183, 222, 54, 48, 8, 220, 234, 65, 198, 109, 16,
+            ],
+            [
+                2, 225, 161, 216, 212, 59, 111, 247, 177, 230, 164, 46, 212, 30, 77, 180, 103, 11,
+                99, 248, 186, 138, 245, 109, 94, 251, 72, 65, 33, 64, 140, 51,
+            ],
+            [
+                3, 98, 135, 248, 235, 228, 131, 165, 166, 140, 124, 2, 139, 120, 86, 196, 79, 44,
+                203, 126, 242, 109, 184, 26, 138, 73, 45, 133, 219, 168, 56, 10,
+            ],
+        ],
+        [
+            [
+                27, 59, 228, 63, 189, 255, 44, 68, 216, 90, 47, 114, 71, 205, 170, 38, 92, 44, 121,
+                73, 250, 217, 60, 15, 97, 55, 124, 245, 27, 77, 157, 22,
+            ],
+            [
+                7, 169, 157, 250, 61, 145, 245, 204, 118, 113, 158, 169, 137, 109, 180, 30, 52,
+                224, 216, 87, 171, 108, 247, 101, 164, 38, 84, 26, 206, 99, 104, 13,
+            ],
+            [
+                51, 252, 240, 52, 142, 9, 129, 48, 117, 94, 9, 20, 95, 169, 39, 111, 20, 33, 199,
+                50, 170, 39, 162, 130, 199, 148, 210, 143, 224, 219, 206, 2,
+            ],
+            [
+                151, 114, 90, 239, 222, 188, 230, 134, 42, 3, 81, 227, 24, 190, 62, 108, 51, 47,
+                191, 13, 8, 222, 35, 199, 198, 73, 60, 157, 87, 243, 250, 31,
+            ],
+            [
+                93, 246, 18, 195, 167, 196, 77, 185, 47, 9, 223, 10, 9, 232, 57, 47, 106, 67, 103,
+                157, 234, 162, 166, 60, 142, 95, 65, 28, 229, 255, 56, 12,
+            ],
+            [
+                93, 135, 100, 80, 173, 11, 225, 101, 35, 43, 107, 236, 251, 121, 1, 37, 60, 234,
+                62, 146, 74, 67, 189, 85, 153, 112, 198, 4, 226, 220, 78, 12,
+            ],
+            [
+                86, 85, 242, 53, 248, 128, 52, 64, 98, 184, 70, 103, 207, 148, 125, 188, 247, 100,
+                120, 108, 225, 72, 255, 160, 49, 167, 49, 205, 101, 109, 154, 24,
+            ],
+            [
+                229, 134, 168, 29, 250, 132, 229, 190, 216, 115, 22, 91, 69, 22, 145, 164, 137,
+                105, 69, 89, 29, 11, 103, 14, 53, 234, 157, 58, 225, 81, 108, 59,
+            ],
+        ],
+        [
+            [
+                182, 37, 176, 217, 253, 195, 220, 20, 3, 68, 92, 254, 25, 58, 131, 45, 130, 193,
+                39, 129, 24, 230, 93, 120, 224, 89, 129, 73, 2, 17, 73, 16,
+            ],
+            [
+                55, 66, 111, 185, 249, 94, 251, 81, 110, 158, 33, 163, 251, 238, 78, 23, 146, 62,
+                145, 113, 189, 16, 210, 128, 217, 29, 166, 198, 185, 115, 61, 41,
+            ],
+            [
+                119, 156, 243, 66, 173, 66, 246, 33, 204, 248, 101, 102, 65, 202, 159, 79, 227, 65,
+                61, 187, 2, 239, 217, 145, 56, 91, 143, 132, 75, 81, 96, 20,
+            ],
+            [
+                92, 177, 87, 121, 86, 158, 148, 57, 196, 154, 253, 82, 14, 233, 89, 237, 192, 4,
+                64, 91, 178, 221, 243, 132, 53, 135, 3, 90, 111, 7, 186, 54,
+            ],
+            [
+                27, 234, 161, 89, 168, 61, 34, 203, 177, 165, 220, 101, 58, 201, 93, 84, 4, 203,
+                191, 34, 20, 159, 121, 239, 235, 246, 157, 148, 90, 108, 8, 37,
+            ],
+            [
+                137, 161, 159, 218, 26, 140, 51, 100, 44, 185, 86, 100, 235, 170, 14, 141, 54, 34,
+                188, 250, 190, 99, 168, 183, 53, 91, 40, 218, 127, 162, 182, 26,
+            ],
+            [
+                44, 77, 209, 214, 175, 45, 237, 2, 156, 237, 127, 43, 29, 2, 36, 12, 228, 121, 250,
+                9, 92, 140, 97, 145, 79, 89, 228, 81, 116, 33, 102, 52,
+            ],
+            [
+                17, 164, 238, 14, 137, 16, 87, 57, 161, 210, 141, 54, 167, 241, 222, 223, 112, 61,
+                200, 141, 57, 152, 183, 211, 214, 80, 103, 57, 4, 128, 182, 62,
+            ],
+        ],
+        [
+            [
+                216, 189, 98, 44, 162, 20, 113, 114, 35, 110, 239, 147, 180, 133, 242, 58, 215,
+                242, 188, 137, 85, 95, 97, 128, 243, 132, 11, 192, 102, 169, 216, 48,
+            ],
+            [
+                252, 21, 239, 154, 12, 217, 3, 104, 194, 211, 129, 163, 169, 178, 233, 39, 190,
+                225, 163, 208, 118, 85, 13, 80, 206, 252, 190, 189, 217, 19, 136, 10,
+            ],
+            [
+                174, 231, 141, 99, 64, 194, 60, 132, 82, 165, 53, 104, 30, 33, 17, 197, 31, 235,
+                24, 174, 100, 99, 229, 114, 121, 197, 54, 66, 252, 108, 0, 38,
+            ],
+            [
+                16, 158, 122, 249, 179, 202, 153, 124, 155, 17, 128, 103, 32, 14, 90, 132, 98, 84,
+                82, 243, 87, 173, 116, 235, 233, 46, 196, 145, 105, 91, 226, 61,
+            ],
+            [
+                130, 229, 101, 58, 56, 30, 49, 75, 35, 180, 57, 179, 30, 126, 81, 219, 87, 61, 239,
+                11, 213, 72, 75, 145, 243, 221, 40, 20, 59, 185, 165, 28,
+            ],
+            [
+                59, 244, 210, 215, 226, 229, 17, 229, 106, 14, 172, 215, 252, 161, 36, 59, 240,
+                244, 223, 72, 161, 6, 202, 141, 251, 182, 2, 160, 136, 158, 19, 6,
+            ],
+            [
+                148, 181, 166, 9, 124, 251, 84, 95, 129, 223, 197, 137, 11, 163, 143, 26, 189, 104,
+                242, 66, 237, 13, 171, 50, 155, 222, 150, 129, 24, 213, 208, 6,
+            ],
+            [
+                135, 54, 91, 78, 116, 200, 174, 255, 25, 214, 7, 114, 50, 253, 254, 215, 213, 115,
+                77, 215, 163, 152, 176, 140, 103, 23, 209, 247, 186, 122, 47, 57,
+            ],
+        ],
+        [
+            [
+                125, 221, 136, 189, 135, 9, 35, 91, 247, 171, 219, 11, 46, 238, 64, 136, 129, 68,
+                91, 163, 77, 10, 83, 190, 134, 112, 6, 250, 246, 101, 88, 59,
+            ],
+            [
+                71, 102, 63, 195, 30, 67, 111, 184, 227, 37, 171, 248, 35, 19, 5, 105, 156, 158,
+                226, 246, 27, 181, 131, 206, 109, 242, 152, 196, 33, 169, 162, 25,
+            ],
+            [
+                161, 106, 19, 17, 130, 193, 205, 223, 76, 65, 177, 211, 52, 60, 109, 159, 89, 247,
+                92, 254, 96, 85, 22, 143, 213, 95, 143, 34, 59, 50, 235, 3,
+            ],
+            [
+                108, 143, 40, 133, 212, 27, 200, 26, 154, 44, 139, 213, 203, 113, 206, 158, 39,
+                237, 51, 122, 120, 249, 72, 31, 252, 196, 100, 9, 25, 242, 147, 2,
+            ],
+            [
+                218, 94, 21, 98, 217, 138, 154, 220, 109, 123, 206, 211, 209, 220, 255, 67, 206,
+                250, 223, 190, 193, 164, 153, 212, 145, 78, 201, 47, 191, 78, 253, 30,
+            ],
+            [
+                172, 153, 122, 38, 138, 126, 27, 154, 111, 24, 99, 39, 112, 205, 249, 250, 125,
+                222, 107, 159, 108, 1, 54, 62, 227, 10, 16, 31, 75, 197, 252, 43,
+            ],
+            [
+                47, 158, 115, 219, 125, 168, 168, 222, 254, 207, 129, 75, 148, 246, 77, 144, 130,
+                205, 68, 108, 220, 105, 186, 136, 155, 58, 46, 28, 146, 56, 21, 26,
+            ],
+            [
+                113, 23, 72, 150, 63, 0, 143, 27, 119, 244, 154, 6, 116, 196, 185, 193, 55, 23,
+                217, 9, 79, 90, 171, 178, 38, 196, 66, 206, 40, 243, 164, 19,
+            ],
+        ],
+        [
+            [
+                180, 59, 253, 25, 196, 182, 24, 129, 30, 46, 11, 195, 171, 201, 56, 141, 14, 224,
+                149, 177, 33, 5, 245, 240, 44, 72, 132, 238, 146, 248, 195, 57,
+            ],
+            [
+                242, 138, 163, 109, 232, 161, 170, 212, 85, 83, 223, 137, 247, 139, 163, 244, 125,
+                108, 189, 83, 249, 239, 15, 56, 203, 184, 244, 224, 185, 121, 28, 37,
+            ],
+            [
+                237, 254, 83, 94, 234, 207, 224, 222, 192, 226, 34, 214, 47, 227, 244, 213, 244,
+                101, 158, 55, 194, 174, 151, 91, 13, 38, 157, 254, 191, 133, 150, 58,
+            ],
+            [
+                253, 181, 42, 251, 23, 158, 202, 19, 155, 149, 151, 147, 124, 209, 32, 0, 157, 201,
+                72, 221, 137, 197, 167, 66, 148, 80, 131, 111, 247, 2, 123, 12,
+            ],
+            [
+                228, 0, 130, 153, 186, 130, 230, 248, 137, 182, 204, 233, 15, 81, 198, 161, 157, 9,
+                144, 212, 100, 122, 13, 43, 169, 37, 62, 158, 165, 126, 189, 9,
+            ],
+            [
+                84, 129, 30, 13, 98, 234, 176, 200, 212, 12, 226, 197, 56, 150, 4, 127, 45, 169,
+                173, 167, 200, 255, 234, 190, 195, 199, 13, 202, 159, 192, 84, 7,
+            ],
+            [
+                156, 6, 36, 126, 135, 160, 133, 19, 37, 136, 240, 238, 106, 164, 77, 103, 131, 94,
+                215, 56, 234, 208, 252, 59, 73, 10, 102, 130, 58, 93, 36, 39,
+            ],
+            [
+                57, 128, 127, 212, 149, 113, 146, 30, 87, 141, 179, 21, 9, 66, 109, 139, 124, 224,
+                137, 25, 223, 147, 163, 205, 31, 141, 2, 216, 154, 117, 71, 28,
+            ],
+        ],
+        [
+            [
+                23, 185, 216, 88, 245, 88, 11, 139, 9, 208, 7, 213, 235, 187, 94, 157, 120, 159,
+                197, 42, 132, 216, 140, 189, 54, 122, 140, 195, 70, 253, 150, 63,
+            ],
+            [
+                167, 126, 150, 28, 136, 51, 223, 185, 101, 11, 58, 53, 43, 55, 230, 213, 111, 182,
+                157, 207, 25, 96, 133, 215, 79, 67, 14, 200, 21, 148, 36, 63,
+            ],
+            [
+                183, 170, 82, 102, 193, 134, 37, 156, 54, 235, 35, 110, 209, 81, 182, 67, 232, 43,
+                25, 228, 254, 97, 128, 128, 217, 133, 66, 87, 217, 146, 96, 12,
+            ],
+            [
+                135, 221, 78, 233, 38, 53, 101, 141, 162, 154, 77, 19, 7, 99, 179, 175, 192, 149,
+                94, 62, 230, 236, 235, 37, 183, 251, 0, 213, 232, 27, 197, 53,
+            ],
+            [
+                188, 132, 5, 133, 0, 19, 111, 153, 67, 186, 16, 204, 153, 153, 143, 67, 252, 156,
+                128, 189, 152, 32, 52, 145, 41, 110, 253, 111, 255, 179, 187, 9,
+            ],
+            [
+                55, 90, 254, 193, 169, 231, 206, 33, 45, 94, 3, 49, 125, 204, 117, 112, 106, 97, 0,
+                147, 45, 197, 181, 191, 231, 16, 52, 154, 107, 114, 37, 17,
+            ],
+            [
+                146, 186, 139, 48, 143, 179, 140, 67, 169, 85, 6, 72, 26, 73, 37, 227, 38, 237,
+                177, 243, 197, 248, 132, 31, 54, 204, 154, 94, 223, 212, 39, 63,
+            ],
+            [
+                194, 18, 65, 1, 127, 197, 80, 84, 89, 105, 27, 89, 129, 164, 219, 170, 64, 177, 99,
+                98, 68, 99, 62, 167, 52, 70, 21, 77, 116, 0, 119, 6,
+            ],
+        ],
+        [
+            [
+                3, 22, 250, 243, 243, 168, 113, 94, 225, 53, 11, 87, 152, 211, 179, 149, 164, 58,
+                106, 85, 231, 169, 5, 50, 255, 129, 30, 38, 178, 80, 247, 22,
+            ],
+            [
+                103, 217, 6, 47, 164, 65, 2, 192, 96, 235, 169, 49, 222, 107, 111, 254, 68, 189,
+                49, 157, 25, 2, 167, 221, 76, 131, 202, 201, 30, 230, 0, 37,
+            ],
+            [
+                183, 220, 138, 178, 151, 26, 8, 105, 124, 146, 56, 253, 179, 246, 192, 241, 75, 11,
+                22, 146, 38, 191, 168, 147, 98, 36, 208, 112, 89, 166, 77, 31,
+            ],
+            [
+                66, 130, 45, 86, 67, 133, 62, 239, 200, 40, 95, 0, 138, 30, 85, 115, 217, 169, 131,
+                165, 165, 179, 126, 13, 32, 127, 119, 249, 77, 159, 212, 35,
+            ],
+            [
+                37, 192, 29, 227, 54, 83, 16, 212, 205, 32, 38, 223, 23, 126, 66, 204, 135, 251,
+                93, 162, 87, 99, 103, 243, 64, 19, 204, 65, 62, 33, 49, 28,
+            ],
+            [
+                78, 126, 138, 75, 128, 148, 193, 137, 163, 138, 105, 90, 181, 86, 195, 163, 63, 65,
+                127, 10, 175, 2, 183, 232, 72, 154, 28, 105, 230, 189, 102, 14,
+            ],
+            [
+                189, 128, 72, 255, 216, 0, 111, 221, 82, 1, 83, 42, 116, 188, 177, 84, 127, 250,
+                51, 230, 134, 115, 140, 196, 4, 36, 237, 24, 100, 248, 69, 61,
+            ],
+            [
+                66, 191, 167, 131, 150, 149, 197, 219, 149, 169, 54, 241, 194, 113, 215, 249, 233,
+                97, 90, 12, 91, 123, 91, 11, 90, 104, 43, 8, 182, 138, 208, 9,
+            ],
+        ],
+        [
+            [
+                141, 202, 229, 235, 60, 190, 199, 33, 177, 43, 43, 67, 133, 168, 76, 43, 54, 251,
+                5, 251, 63, 50, 18, 65, 228, 173, 43, 169, 46, 102, 81, 22,
+            ],
+            [
+                134, 28, 202, 135, 201, 87, 196, 46, 43, 46, 205, 222, 30, 143, 74, 253, 81, 98,
+                224, 199, 99, 172, 12, 70, 8, 63, 205, 189, 246, 189, 121, 38,
+            ],
+            [
+                168, 62, 184, 125, 77, 102, 220, 211, 252, 198, 169, 64, 20, 234, 26, 52, 19, 44,
+                95, 188, 198, 60, 143, 226, 0, 188, 34, 12, 21, 96, 86, 9,
+            ],
+            [
+                224, 156, 218, 26, 99, 37, 251, 35, 90, 201, 206, 251, 101, 48, 228, 135, 150, 162,
+                45, 59, 155, 58, 189, 173, 219, 145, 119, 129, 25, 188, 128, 58,
+            ],
+            [
+                123, 173, 125, 228, 8, 22, 84, 231, 45, 54, 115, 247, 51, 244, 80, 164, 44, 168,
+                175, 142, 116, 150, 84, 186, 244, 20, 143, 105, 11, 111, 59, 54,
+            ],
+            [
+                77, 0, 8, 235, 145, 166, 22, 179, 48, 54, 119, 43, 208, 226, 138, 48, 60, 165, 111,
+                196, 96, 3, 168, 243, 179, 228, 91, 232, 244, 104, 249, 13,
+            ],
+            [
+                158, 251, 55, 105, 62, 144, 227, 209, 29, 76, 54, 159, 118, 92, 253, 20, 115, 2,
+                251, 85, 226, 83, 109, 15, 250, 2, 247, 157, 156, 4, 43, 7,
+            ],
+            [
+                18, 153, 170, 224, 246, 184, 88, 104, 153, 0, 146, 243, 46, 63, 100, 62, 182, 54,
+                203, 45, 200, 246, 137, 132, 254, 177, 111, 216, 103, 6, 23, 29,
+            ],
+        ],
+        [
+            [
+                108, 105, 164, 70, 4, 119, 47, 29, 5, 20, 2, 54, 166, 207, 139, 162, 15, 91, 112,
+                201, 209, 212, 69, 238, 175, 81, 246, 147, 71, 243, 115, 24,
+            ],
+            [
+                83, 76, 235, 85, 86, 5, 1, 47, 121, 148, 179, 217, 163, 228, 97, 27, 182, 125, 12,
+                10, 184, 164, 149, 142, 100, 68, 22, 137, 152, 1, 53, 56,
+            ],
+            [
+                124, 80, 157, 118, 55, 18, 144, 71, 41, 136, 246, 221, 80, 9, 46, 208, 175, 22, 64,
+                124, 169, 236, 223, 193, 162, 211, 217, 76, 247, 106, 34, 38,
+            ],
+            [
+                124, 250, 130, 179, 159, 117, 223, 49, 252, 247, 34, 70, 2, 247, 32, 223, 255, 194,
+                185, 3, 157, 187, 65, 196, 242, 156, 246, 74, 239, 125, 102, 15,
+            ],
+            [
+                84, 122, 65, 125, 180, 96, 33, 80, 111, 222, 109, 212, 40, 213, 160, 30, 117, 79,
+                61, 37, 214, 223, 113, 246, 241, 200, 150, 198, 111, 26, 29, 42,
+            ],
+            [
+                31, 26, 228, 238, 82, 213, 145, 66, 61, 53, 209, 217, 54, 121, 186, 103, 27, 19,
+                236, 13, 197, 38, 195, 185, 197, 179, 55, 81, 238, 36, 195, 29,
+            ],
+            [
+                153, 154, 154, 193, 250, 173, 140, 190, 49, 66, 24, 236, 202, 90, 189, 182, 83,
+                152, 223, 249, 214, 133, 248, 74, 152, 233, 194, 237, 219, 126, 227, 59,
+            ],
+            [
+                203, 56, 241, 190, 201, 39, 110, 172, 140, 40, 238, 59, 177, 80, 190, 41, 111, 124,
+                179, 202, 117, 143, 10, 165, 243, 90, 30, 3, 69, 23, 9, 36,
+            ],
+        ],
+        [
+            [
+                13, 224, 147, 188, 165, 159, 45, 89, 27, 117, 250, 40, 60, 211, 27, 153, 176, 196,
+                99, 180, 207, 234, 231, 230, 60, 101, 146, 210, 219, 128, 35, 52,
+            ],
+            [
+                73, 151, 249, 229, 151, 219, 165, 251, 235, 237, 113, 212, 174, 198, 67, 249, 163,
+                164, 126, 10, 253, 159, 168, 117, 169, 33, 2, 247, 47, 29, 198, 63,
+            ],
+            [
+                249, 52, 67, 236, 202, 1, 228, 202, 70, 159, 115, 136, 212, 217, 27, 239, 92, 240,
+                230, 200, 241, 232, 27, 109, 55, 74, 201, 251, 174, 10, 3, 14,
+            ],
+            [
+                86, 120, 1, 88, 48, 174, 86, 111, 63, 171, 42, 156, 153, 36, 237, 194, 196, 231,
+                132, 5, 239, 3, 127, 52, 114, 162, 39, 121, 162, 56, 175, 37,
+            ],
+            [
+                41, 209, 187, 213, 112, 68, 254, 22, 5, 196, 253, 213, 236, 122, 222, 156, 19, 10,
+                244, 85, 200, 30, 98, 0, 222, 179, 95, 227, 12, 156, 85, 53,
+            ],
+            [
+                124, 170, 100, 174, 59, 133, 103, 46, 42, 52, 147, 64, 145, 2, 157, 29, 85, 226,
+                237, 58, 70, 72, 5, 227, 209, 119, 43, 252, 49, 23, 250, 61,
+            ],
+            [
+                246, 131, 21, 25, 38, 144, 193, 206, 236, 42, 229, 113, 183, 124, 195, 136, 51, 68,
+                115, 37, 68, 57, 254, 46, 159, 123, 237, 153, 163, 150, 148, 45,
+            ],
+            [
+                195, 210, 242, 9, 207, 0, 213, 245, 6, 252, 124, 27, 36, 231, 74, 212, 226, 157,
+                126, 163, 13, 100, 70, 48, 97, 39, 122, 11, 219, 224, 181, 0,
+            ],
+        ],
+        [
+            [
+                1, 85, 143, 81, 208, 247, 226, 31, 197, 190, 158, 87, 135, 225, 212, 53, 235, 201,
+                108, 246, 70, 216, 187, 208, 248, 145, 22, 193, 49, 152, 92, 50,
+            ],
+            [
+                142, 157, 222, 21, 74, 86, 4, 214, 44, 177, 242, 118, 67, 55, 170, 97, 106, 111,
+                47, 213, 201, 83, 183, 22, 12, 237, 223, 44, 226, 173, 241, 60,
+            ],
+            [
+                35, 229, 134, 249, 87, 132, 244, 160, 104, 251, 200, 245, 169, 21, 45, 56, 88, 157,
+                159, 186, 90, 178, 69, 56, 255, 39, 36, 233, 193, 150, 190, 63,
+            ],
+            [
+                167, 104, 135, 169, 223, 152, 251, 157, 74, 44, 153, 99, 83, 100, 178, 220, 8, 224,
+                76, 100, 220, 23, 209, 131, 59, 11, 71, 140, 2, 61, 226, 55,
+            ],
+            [
+                193, 61, 77, 167, 218, 244, 176, 65, 136, 21, 78, 92, 54, 201, 179, 103, 225, 73,
+                231, 246, 68, 108, 206, 229, 178, 195, 197, 9, 230, 235, 111, 27,
+            ],
+            [
+                164, 43, 79, 136, 142, 126, 50, 82, 22, 221, 251, 122, 9, 15, 230, 193, 35, 157,
+                243, 193, 147, 233, 118, 107, 40, 186, 92, 189, 2, 43, 164, 1,
+            ],
+            [
+                82, 33, 205, 85, 12, 11, 223, 46, 132, 44, 236, 190, 188, 131, 194, 78, 79, 75, 20,
+                224, 130, 30, 16, 72, 119, 24, 169, 212, 69, 169, 33, 43,
+            ],
+            [
+                136, 180, 50, 213, 255, 250, 122, 204, 123, 8, 219, 141, 124, 68, 198, 44, 107,
+                251, 59, 173, 27, 16, 206, 64, 32, 201, 59, 245, 196, 105, 106, 63,
+            ],
+        ],
+        [
+            [
+                8, 163, 38, 33, 64, 120, 116, 88, 98, 204, 36, 87, 35, 253, 183, 16, 45, 175, 216,
+                97, 75, 47, 36, 110, 41, 77, 159, 134, 135, 206, 95, 53,
+            ],
+            [
+                159, 253, 148, 76, 159, 119, 20, 252, 101, 168, 241, 85, 240, 79, 72, 216, 45, 25,
+                105, 239, 97, 207, 205, 130, 158, 183, 135, 108, 232, 124, 51, 19,
+            ],
+            [
+                174, 117, 39, 40, 195, 75, 126, 189, 252, 60, 146, 69, 77, 59, 93, 11, 64, 196, 59,
+                204, 42, 122, 240, 64, 79, 196, 231, 146, 73, 254, 102, 11,
+            ],
+            [
+                129, 230, 75, 8, 48, 172, 180, 95, 120, 26, 202, 75, 219, 155, 96, 67, 64, 120,
+                108, 92, 12, 10, 13, 211, 31, 208, 229, 225, 29, 128, 71, 30,
+            ],
+            [
+                29, 241, 183, 221, 214, 24, 15, 117, 80, 96, 230, 88, 177, 145, 254, 18, 193, 153,
+                234, 33, 103, 136, 111, 80, 199, 61, 47, 38, 113, 145, 158, 21,
+            ],
+            [
+                131, 97, 50, 25, 237, 33, 221, 172, 146, 205, 176, 163, 184, 61, 196, 176, 242,
+                242, 27, 92, 204, 157, 244, 75, 223, 16, 210, 58, 13, 148, 213, 41,
+            ],
+            [
+                193, 167, 172, 251, 67, 27, 119, 150, 128, 229, 207, 105, 163, 233, 255, 205, 18,
+                181, 33, 187, 125, 97, 119, 18, 224, 35, 121, 46, 133, 247, 172, 43,
+            ],
+            [
+                245, 114, 65, 250, 209, 114, 21, 66, 2, 239, 44, 106, 86, 202, 16, 164, 246, 19,
+                176, 206, 212, 42, 58, 136, 48, 164, 189, 144, 46, 36, 29, 29,
+            ],
+        ],
+        [
+            [
+                162, 167, 4, 96, 229, 200, 9, 31, 209, 77, 146, 252, 31, 87, 155, 148, 48, 216,
+                245, 80, 53, 90, 14, 151, 96, 233, 190, 60, 99, 164, 106, 42,
+            ],
+            [
+                81, 124, 95, 139, 237, 176, 159, 217, 22, 69, 7, 44, 191, 102, 48, 230, 127, 250,
+                142, 1, 231, 34, 68, 183, 203, 208, 226, 79, 221, 51, 32, 1,
+            ],
+            [
+                25, 152, 94, 44, 186, 81, 37, 128, 209, 67, 79, 65, 147, 219, 86, 204, 16, 160,
+                149, 143, 53, 139, 102, 142, 246, 15, 228, 73, 91, 26, 243, 16,
+            ],
+            [
+                206, 210, 106, 131, 207, 153, 224, 18, 148, 134, 19, 80, 83, 81, 4, 192, 117, 63,
+                84, 62, 172, 155, 22, 85, 195, 165, 9, 164, 140, 253, 23, 30,
+            ],
+            [
+                168, 115, 24, 67, 31, 211, 224, 97, 69, 50, 96, 29, 47, 77, 94, 23, 22, 111, 69,
+                178, 4, 27, 139, 68, 92, 114, 73, 10, 126, 100, 213, 8,
+            ],
+            [
+                146, 164, 242, 184, 77, 246, 181, 80, 79, 79, 230, 22, 203, 4, 172, 233, 235, 121,
+                242, 188, 133, 188, 111, 224, 196, 250, 251, 47, 176, 45, 224, 11,
+            ],
+            [
+                83, 171, 56, 233, 69, 170, 193, 44, 108, 72, 126, 14, 27, 146, 207, 164, 212, 249,
+                109, 0, 201, 139, 113, 209, 90, 232, 60, 227, 65, 96, 153, 18,
+            ],
+            [
+                12, 28, 127, 207, 238, 40, 211, 22, 172, 170, 137, 174, 171, 39, 175, 237, 50, 40,
+                10, 8, 137, 170, 4, 252, 153, 81, 142, 124, 238, 192, 203, 40,
+            ],
+        ],
+        [
+            [
+                3, 148, 19, 178, 82, 170, 144, 33, 31, 49, 245, 181, 62, 237, 129, 57, 0, 181, 189,
+                84, 218, 158, 54, 192, 239, 166, 131, 138, 170, 83, 89, 17,
+            ],
+            [
+                104, 60, 250, 142, 2, 123, 235, 30, 218, 222, 173, 248, 55, 244, 210, 62, 199, 19,
+                69, 97, 148, 116, 113, 185, 70, 52, 18, 124, 104, 170, 173, 14,
+            ],
+            [
+                113, 123, 167, 219, 71, 33, 65, 240, 52, 113, 237, 141, 16, 113, 222, 215, 52, 48,
+                27, 130, 190, 68, 94, 111, 77, 35, 203, 114, 167, 237, 188, 6,
+            ],
+            [
+                238, 215, 210, 175, 181, 111, 210, 19, 110, 41, 143, 180, 131, 113, 90, 32, 233,
+                36, 129, 232, 82, 94, 195, 173, 95, 118, 52, 215, 245, 163, 80, 21,
+            ],
+            [
+                160, 76, 92, 186, 129, 159, 213, 246, 88, 86, 117, 159, 193, 250, 32, 68, 134, 28,
+                83, 191, 84, 104, 27, 163, 81, 128, 25, 133, 194, 84, 160, 21,
+            ],
+            [
+                171, 95, 194, 198, 138, 117, 211, 128, 50, 246, 41, 102, 109, 180, 135, 27, 143,
+                142, 43, 122, 236, 11, 106, 5, 233, 207, 165, 134, 119, 178, 14, 28,
+            ],
+            [
+                124, 98, 163, 223, 48, 253, 176, 255, 149, 79, 163, 172, 249, 57, 63, 133, 36, 183,
+                17, 55, 215, 252, 230, 220, 227, 106, 19, 195, 137, 150, 208, 17,
+            ],
+            [
+                254, 168, 12, 138, 104, 33, 251, 3, 232, 72, 61, 170, 161, 111, 96, 114, 124, 31,
+                180, 67, 20, 24, 189, 108, 19, 181, 105, 1, 62, 194, 176, 15,
+            ],
+        ],
+        [
+            [
+                199, 119, 78, 200, 155, 61, 205, 14, 141, 62, 100, 118, 57, 124, 78, 239, 91, 21,
+                22, 4, 39, 244, 15, 64, 98, 249, 252, 180, 180, 168, 21, 56,
+            ],
+            [
+                56, 105, 195, 159, 23, 21, 86, 94, 31, 55, 233, 129, 215, 35, 207, 110, 162, 144,
+                75, 114, 217, 229, 232, 112, 66, 250, 153, 154, 178, 71, 16, 7,
+            ],
+            [
+                44, 202, 191, 151, 95, 251, 68, 247, 29, 54, 131, 33, 17, 247, 22, 115, 32, 96,
+                166, 54, 138, 103, 134, 71, 139, 188, 173, 129, 49, 197, 169, 0,
+            ],
+            [
+                173, 83, 67, 65, 2, 217, 201, 14, 118, 76, 93, 155, 120, 234, 27, 128, 215, 146,
+                40, 117, 161, 174, 201, 142, 123, 38, 185, 220, 207, 253, 59, 29,
+            ],
+            [
+                199, 117, 46, 143, 177, 69, 145, 162, 215, 115, 54, 169, 107, 148, 254, 130, 167,
+                0, 165, 151, 157, 227, 17, 251, 113, 205, 175, 113, 126, 242, 96, 23,
+            ],
+            [
+                213, 44, 118, 196, 255, 28, 132, 130, 97, 93, 151, 42, 102, 205, 190, 112, 156, 92,
+                227, 77, 165, 41, 84, 209, 68, 114, 65, 242, 235, 16, 66, 12,
+            ],
+            [
+                94, 215, 161, 27, 31, 18, 236, 59, 61, 119, 229, 31, 28, 51, 135, 147, 155, 19,
+                203, 29, 42, 161, 3, 77, 0, 36, 168, 49, 165, 234, 83, 52,
+            ],
+            [
+                40, 234, 29, 236, 119, 140, 81, 112, 14, 12, 85, 170, 25, 104, 238, 115, 255, 253,
+                83, 36, 144, 80, 190, 11, 98, 31, 237, 121, 170, 31, 160, 47,
+            ],
+        ],
+        [
+            [
+                107, 63, 125, 202, 208, 80, 74, 230, 101, 15, 111, 44, 181, 72, 110, 43, 221, 49,
+                186, 169, 128, 144, 84, 185, 117, 36, 49, 54, 255, 225, 41, 60,
+            ],
+            [
+                177, 106, 180, 185, 176, 53, 165, 223, 214, 49, 38, 227, 66, 199, 230, 26, 56, 85,
+                193, 182, 151, 102, 163, 126, 232, 68, 37, 113, 15, 219, 180, 29,
+            ],
+            [
+                132, 95, 164, 52, 13, 155, 84, 188, 96, 123, 140, 193, 50, 129, 67, 23, 198, 82,
+                54, 125, 117, 239, 94, 171, 220, 3, 131, 95, 92, 46, 145, 30,
+            ],
+            [
+                167, 110, 172, 96, 241, 212, 213, 159, 30, 202, 255, 103, 33, 139, 111, 50, 239,
+                239, 97, 74, 72, 204, 200, 104, 253, 114, 90, 113, 122, 253, 230, 8,
+            ],
+            [
+                37, 213, 39, 49, 36, 210, 225, 101, 177, 236, 145, 226, 25, 138, 126, 69, 13, 220,
+                125, 181, 31, 17, 69, 41, 84, 28, 199, 86, 9, 243, 179, 16,
+            ],
+            [
+                95, 230, 241, 224, 237, 126, 79, 32, 114, 41, 237, 115, 133, 104, 220, 193, 101,
+                103, 165, 159, 221, 116, 79, 66, 49, 228, 216, 160, 198, 197, 60, 51,
+            ],
+            [
+                133, 56, 186, 213, 11, 53, 22, 73, 165, 76, 83, 157, 126, 20, 73, 141, 48, 89, 233,
+                189, 130, 201, 136, 225, 36, 133, 133, 243, 211, 121, 15, 17,
+            ],
+            [
+                15, 252, 32, 14, 124, 128, 126, 56, 223, 30, 226, 128, 218, 35, 202, 103, 180, 245,
+                231, 144, 95, 36, 102, 255, 39, 68, 229, 84, 88, 215, 187, 11,
+            ],
+        ],
+        [
+            [
+                80, 183, 140, 251, 76, 108, 142, 64, 43, 100, 9, 99, 214, 202, 114, 66, 3, 217, 45,
+                42, 200, 43, 85, 210, 174, 158, 22, 152, 61, 2, 88, 1,
+            ],
+            [
+                82, 76, 64, 36, 0, 16, 31, 204, 180, 77, 238, 141, 128, 171, 199, 82, 168, 88, 128,
+                195, 196, 190, 47, 89, 85, 225, 29, 204, 180, 37, 196, 8,
+            ],
+            [
+                120, 152, 145, 224, 53, 6, 13, 119, 66, 241, 5, 91, 126, 94, 246, 197, 166, 127,
+                27, 82, 158, 255, 20, 245, 151, 240, 125, 53, 102, 80, 83, 59,
+            ],
+            [
+                189, 180, 159, 90, 39, 217, 205, 72, 119, 148, 105, 53, 54, 50, 2, 83, 180, 31, 40,
+                177, 229, 57, 10, 240, 104, 101, 224, 7, 219, 226, 75, 22,
+            ],
+            [
+                141, 166, 17, 106, 229, 179, 249, 211, 51, 25, 107, 237, 83, 235, 173, 233, 120,
+                223, 255, 51, 111, 220, 254, 216, 124, 216, 188, 225, 166, 117, 112, 45,
+            ],
+            [
+                82, 231, 43, 121, 87, 109, 242, 209, 74, 172, 39, 14, 156, 76, 222, 114, 161, 136,
+                174, 123, 83, 24, 30, 173, 6, 101, 19, 141, 208, 90, 75, 62,
+            ],
+            [
+                165, 237, 54, 72, 246, 122, 214, 183, 143, 110, 80, 51, 43, 52, 250, 233, 131, 163,
+                65, 194, 183, 90, 45, 220, 51, 156, 2, 53, 105, 241, 206, 7,
+            ],
+            [
+                74, 108, 126, 150, 6, 11, 43, 41, 56, 193, 89, 152, 8, 40, 244, 78, 38, 171, 206,
+                242, 102, 85, 127, 63, 194, 63, 67, 207, 28, 230, 169, 27,
+            ],
+        ],
+        [
+            [
+                38, 0, 235, 100, 195, 212, 245, 181, 194, 166, 87, 162, 248, 156, 122, 10, 182, 87,
+                184, 25, 127, 82, 185, 184, 189, 40, 81, 170, 118, 97, 128, 56,
+            ],
+            [
+                65, 34, 238, 155, 202, 15, 165, 127, 0, 244, 91, 253, 245, 85, 90, 140, 245, 222,
+                109, 219, 2, 203, 206, 147, 183, 13, 144, 226, 159, 254, 108, 42,
+            ],
+            [
+                238, 147, 39, 47, 98, 239, 66, 123, 242, 137, 18, 135, 41, 55, 146, 164, 41, 243,
+                135, 221, 182, 133, 133, 252, 146, 65, 152, 190, 153, 130, 132, 54,
+            ],
+            [
+                154, 27, 135, 221, 110, 47, 129, 139, 128, 17, 144, 224, 143, 225, 53, 172, 200,
+                251, 115, 152, 95, 196, 217, 79, 11, 150, 252, 7, 109, 16, 71, 49,
+            ],
+            [
+                163, 187, 210, 153, 224, 121, 14, 9, 217, 181, 148, 1, 81, 98, 245, 254, 93, 172,
+                34, 128, 86, 12, 193, 101, 122, 51, 211, 147, 6, 232, 150, 26,
+            ],
+            [
+                20, 60, 158, 241, 203, 206, 246, 70, 105, 19, 12, 161, 247, 9, 98, 126, 243, 185,
+                88, 125, 158, 227, 91, 245, 158, 93, 183, 52, 57, 239, 227, 58,
+            ],
+            [
+                130, 152, 83, 189, 115, 192, 169, 161, 240, 255, 129, 220, 168, 28, 164, 242, 54,
+                139, 125, 222, 192, 133, 47, 14, 221, 159, 134, 199, 141, 180, 82, 31,
+            ],
+            [
+                203, 244, 233, 75, 88, 28, 86, 32, 19, 138, 221, 229, 222, 25, 144, 139, 185, 226,
+                231, 200, 234, 86, 227, 201, 127, 177, 185, 227, 30, 108, 232, 20,
+            ],
+        ],
+        [
+            [
+                234, 216, 189, 248, 64, 195, 225, 250, 95, 142, 103, 64, 214, 152, 93, 132, 124,
+                107, 189, 100, 67, 232, 218, 37, 81, 15, 63, 91, 44, 243, 242, 37,
+            ],
+            [
+                93, 13, 175, 203, 242, 43, 217, 235, 166, 13, 98, 118, 90, 144, 52, 194, 186, 26,
+                139, 68, 42, 105, 12, 102, 2, 177, 80, 109, 136, 89, 231, 30,
+            ],
+            [
+                98, 10, 213, 136, 235, 118, 41, 24, 240, 248, 80, 192, 139, 247, 191, 21, 185, 99,
+                174, 137, 98, 239, 189, 157, 250, 241, 229, 60, 164, 236, 104, 49,
+            ],
+            [
+                209, 105, 175, 162, 20, 2, 164, 64, 100, 32, 8, 53, 5, 68, 214, 209, 95, 82, 18,
+                158, 16, 19, 189, 192, 124, 69, 51, 111, 146, 211, 123, 45,
+            ],
+            [
+                203, 72, 129, 215, 43, 116, 144, 80, 94, 236, 113, 232, 251, 149, 42, 33, 17, 79,
+                4, 41, 41, 212, 96, 117, 53, 16, 31, 105, 196, 114, 29, 63,
+            ],
+            [
+                51, 47, 45, 242, 30, 109, 191, 24, 107, 114, 93, 52, 123, 202, 146, 16, 18, 20,
+                130, 136, 241, 149, 95, 14, 104, 96, 128, 100, 78, 241, 94, 61,
+            ],
+            [
+                223, 172, 88, 87, 26, 39, 184, 199, 135, 53, 108, 183, 13, 135, 24, 40, 252, 254,
+                139, 212, 16, 253, 82, 173, 1, 173, 101, 127, 42, 115, 32, 17,
+            ],
+            [
+                0, 59, 44, 223, 101, 117, 31, 55, 69, 38, 232, 107, 9, 178, 65, 230, 148, 178, 58,
+                75, 230, 177, 238, 43, 158, 171, 121, 72, 59, 43, 113, 47,
+            ],
+        ],
+        [
+            [
+                176, 231, 184, 36, 191, 191, 151, 107, 188, 59, 239, 91, 13, 21, 139, 182, 184, 65,
+                123, 46, 147, 168, 95, 202, 230, 171, 208, 46, 4, 94, 72, 8,
+            ],
+            [
+                61, 51, 33, 230, 135, 242, 134, 165, 180, 225, 240, 40, 150, 166, 196, 115, 243,
+                96, 45, 249, 194, 198, 89, 133, 177, 83, 200, 124, 180, 184, 226, 20,
+            ],
+            [
+                161, 184, 110, 65, 77, 99, 166, 219, 56, 174, 235, 165, 112, 51, 2, 73, 181, 42,
+                58, 148, 239, 137, 133, 212, 254, 19, 167, 220, 30, 213, 70, 38,
+            ],
+            [
+                84, 57, 15, 243, 140, 68, 130, 202, 121, 69, 199, 68, 144, 206, 33, 216, 136, 206,
+                81, 209, 86, 234, 177, 232, 94, 139, 135, 185, 33, 5, 24, 45,
+            ],
+            [
+                13, 190, 89, 136, 226, 66, 128, 125, 115, 96, 198, 145, 31, 113, 100, 186, 212,
+                220, 44, 170, 247, 19, 41, 244, 238, 130, 171, 85, 74, 216, 57, 61,
+            ],
+            [
+                102, 38, 83, 70, 254, 152, 135, 190, 164, 115, 189, 115, 12, 11, 208, 250, 207,
+                162, 67, 167, 119, 202, 39, 25, 239, 233, 237, 229, 27, 30, 62, 41,
+            ],
+            [
+                174, 152, 124, 109, 127, 129, 100, 197, 13, 250, 241, 209, 238, 134, 64, 64, 79,
+                119, 184, 10, 50, 200, 208, 228, 185, 156, 161, 48, 86, 14, 106, 8,
+            ],
+            [
+                216, 108, 239, 10, 109, 234, 3, 108, 87, 83, 181, 193, 128, 242, 50, 251, 174, 12,
+                114, 175, 153, 116, 192, 183, 77, 168, 93, 166, 8, 68, 187, 12,
+            ],
+        ],
+        [
+            [
+                170, 115, 186, 229, 88, 26, 186, 243, 173, 245, 228, 211, 225, 48, 146, 137, 139,
+                244, 91, 16, 203, 108, 198, 175, 203, 106, 78, 117, 197, 109, 122, 7,
+            ],
+            [
+                93, 28, 150, 52, 135, 132, 182, 108, 32, 61, 35, 181, 174, 104, 76, 10, 212, 118,
+                74, 48, 97, 109, 88, 186, 10, 202, 41, 253, 139, 203, 163, 53,
+            ],
+            [
+                175, 95, 157, 91, 116, 119, 181, 177, 38, 182, 228, 151, 88, 52, 235, 199, 214, 83,
+                208, 238, 98, 72, 182, 144, 37, 86, 73, 141, 182, 235, 63, 63,
+            ],
+            [
+                208, 56, 208, 238, 98, 138, 134, 122, 135, 245, 128, 194, 25, 97, 15, 192, 200, 32,
+                102, 35, 7, 177, 14, 89, 61, 49, 240, 224, 75, 205, 89, 47,
+            ],
+            [
+                127, 227, 57, 9, 131, 135, 234, 224, 135, 216, 69, 180, 100, 208, 224, 67, 231, 88,
+                221, 229, 121, 47, 8, 2, 119, 47, 233, 33, 161, 56, 79, 5,
+            ],
+            [
+                81, 113, 141, 26, 185, 166, 189, 239, 239, 173, 130, 76, 54, 224, 177, 15, 96, 140,
+                29, 25, 85, 55, 57, 244, 198, 191, 214, 174, 166, 167, 1, 52,
+            ],
+            [
+                11, 66, 254, 17, 110, 13, 255, 108, 143, 213, 23, 227, 116, 175, 72, 167, 41, 204,
+                92, 141, 180, 81, 132, 153, 70, 118, 54, 34, 59, 229, 155, 35,
+            ],
+            [
+                37, 50, 250, 194, 241, 217, 20, 157, 59, 158, 203, 253, 45, 205, 91, 46, 1, 134,
+                244, 161, 126, 235, 72, 245, 88, 16, 130, 43, 24, 214, 207, 62,
+            ],
+        ],
+        [
+            [
+                79, 100, 59, 216, 160, 248, 249, 164, 213, 32, 55, 96, 80, 234, 118, 136, 255, 157,
+                159, 166, 44, 87, 137, 76, 23, 9, 151, 182, 172, 105, 242, 27,
+            ],
+            [
+                183, 245, 250, 126, 200, 100, 117, 179, 63, 10, 188, 44, 101, 97, 64, 46, 26, 84,
+                156, 245, 115, 128, 193, 208, 241, 28, 21, 223, 255, 94, 232, 6,
+            ],
+            [
+                126, 34, 120, 79, 192, 177, 217, 143, 244, 249, 87, 150, 208, 2, 19, 199, 247, 169,
+                1, 215, 228, 30, 203, 129, 146, 142, 162, 119, 122, 205, 14, 30,
+            ],
+            [
+                147, 133, 11, 61, 252, 93, 4, 132, 251, 106, 122, 120, 239, 148, 138, 233, 108,
+                245, 104, 152, 221, 135, 148, 249, 213, 2, 172, 64, 143, 86, 165, 17,
+            ],
+            [
+                5, 31, 194, 133, 140, 253, 214, 169, 130, 211, 73, 169, 161, 189, 126, 212, 50,
+                157, 72, 40, 146, 19, 251, 120, 253, 167, 1, 111, 19, 95, 22, 10,
+            ],
+            [
+                16, 87, 15, 75, 136, 84, 60, 127, 28, 118, 234, 90, 163, 66, 174, 99, 250, 128, 12,
+                72, 136, 61, 204, 30, 27, 188, 78, 52, 128, 58, 83, 35,
+            ],
+            [
+                229, 80, 237, 58, 164, 116, 98, 117, 122, 6, 120, 186, 235, 140, 14, 231, 227, 229,
+                195, 254, 1, 5, 124, 167, 15, 206, 243, 64, 85, 30, 20, 18,
+            ],
+            [
+                53, 186, 101, 12, 159, 101, 224, 60, 171, 216, 149, 95, 210, 40, 129, 198, 84, 205,
+                35, 50, 188, 109, 102, 227, 57, 56, 27, 211, 125, 173, 18, 46,
+            ],
+        ],
+        [
+            [
+                241, 46, 119, 46, 196, 181, 119, 159, 195, 222, 140, 115, 200, 172, 88, 210, 73,
+                185, 7, 216, 49, 127, 40, 215, 205, 59, 74, 143, 204, 204, 234, 6,
+            ],
+            [
+                161, 134, 12, 233, 7, 37, 231, 203, 219, 222, 236, 99, 145, 197, 150, 122, 64, 40,
+                15, 113, 236, 255, 229, 245, 184, 232, 78, 119, 97, 206, 179, 55,
+            ],
+            [
+                175, 61, 70, 15, 227, 189, 45, 95, 11, 155, 212, 121, 159, 140, 67, 118, 223, 128,
+                33, 186, 70, 43, 240, 232, 189, 250, 4, 159, 204, 233, 226, 56,
+            ],
+            [
+                53, 120, 161, 172, 207, 17, 255, 240, 223, 137, 164, 99, 46, 193, 139, 26, 125,
+                184, 186, 119, 98, 202, 181, 109, 123, 62, 150, 224, 111, 226, 162, 2,
+            ],
+            [
+                8, 254, 131, 12, 55, 222, 5, 76, 87, 120, 83, 209, 100, 195, 90, 150, 140, 161,
+                200, 147, 64, 123, 157, 129, 146, 102, 100, 222, 238, 103, 123, 5,
+            ],
+            [
+                214, 253, 94, 204, 194, 121, 114, 140, 175, 186, 80, 213, 212, 56, 187, 238, 41,
+                135, 70, 42, 56, 231, 240, 242, 92, 136, 197, 71, 17, 193, 107, 7,
+            ],
+            [
+                114, 29, 180, 213, 79, 120, 121, 156, 137, 148, 4, 49, 69, 88, 84, 199, 53, 87,
+                248, 199, 191, 96, 24, 191, 116, 50, 76, 202, 152, 243, 32, 21,
+            ],
+            [
+                142, 88, 199, 241, 64, 225, 254, 125, 114, 123, 38, 246, 193, 179, 243, 8, 150,
+                147, 176, 29, 28, 166, 166, 245, 15, 217, 165, 123, 252, 171, 132, 24,
+            ],
+        ],
+        [
+            [
+                148, 115, 191, 64, 121, 155, 58, 173, 40, 30, 14, 44, 75, 183, 179, 22, 162, 215,
+                99, 80, 5, 30, 5, 86, 240, 167, 65, 135, 57, 151, 108, 61,
+            ],
+            [
+                74, 127, 108, 58, 219, 136, 164, 48, 233, 170, 40, 40, 6, 24, 139, 242, 42, 253,
+                253, 202, 72, 199, 246, 119, 109, 241, 232, 255, 218, 105, 6, 44,
+            ],
+            [
+                48, 149, 244, 71, 231, 255, 216, 76, 105, 139, 102, 36, 26, 65, 135, 127, 60, 86,
+                245, 163, 68, 120, 130, 53, 97, 155, 152, 54, 255, 223, 142, 57,
+            ],
+            [
+                14, 162, 25, 224, 30, 230, 107, 12, 118, 137, 104, 101, 21, 168, 96, 247, 31, 186,
+                187, 249, 145, 190, 181, 247, 6, 172, 216, 177, 98, 4, 237, 33,
+            ],
+            [
+                98, 102, 115, 53, 86, 91, 102, 201, 38, 43, 173, 59, 170, 147, 35, 142, 249, 11,
+                247, 132, 12, 33, 190, 141, 73, 51, 176, 36, 135, 107, 39, 36,
+            ],
+            [
+                45, 147, 234, 46, 98, 239, 135, 14, 84, 180, 216, 28, 113, 18, 185, 193, 41, 228,
+                97, 60, 97, 110, 162, 87, 217, 207, 238, 24, 183, 163, 196, 57,
+            ],
+            [
+                102, 158, 44, 189, 234, 10, 218, 101, 207, 14, 136, 127, 153, 170, 229, 48, 17,
+                181, 254, 198, 36, 99, 190, 76, 134, 93, 196, 158, 217, 164, 210, 53,
+            ],
+            [
+                45, 113, 150, 35, 211, 242, 225, 35, 12, 8, 26, 180, 196, 210, 164, 184, 105, 146,
+                174, 122, 191, 175, 201, 36, 113, 137, 43, 195, 194, 216, 28, 32,
+            ],
+        ],
+        [
+            [
+                102, 196, 39, 27, 46, 216, 113, 73, 62, 103, 183, 105, 33, 228, 16, 225, 142, 97,
+                61, 157, 74, 14, 198, 173, 98, 161, 46, 5, 28, 224, 221, 53,
+            ],
+            [
+                0, 94, 219, 165, 84, 223, 162, 214, 176, 155, 221, 21, 125, 129, 186, 35, 254, 246,
+                46, 106, 202, 176, 82, 216, 166, 26, 162, 186, 82, 185, 150, 8,
+            ],
+            [
+                237, 86, 59, 25, 121, 206, 120, 30, 201, 50, 100, 122, 113, 125, 187, 88, 174, 180,
+                143, 150, 235, 247, 22, 116, 88, 29, 14, 57, 178, 188, 129, 16,
+            ],
+            [
+                254, 114, 149, 138, 194, 177, 9, 15, 185, 187, 13, 177, 215, 196, 194, 119, 224,
+                39, 230, 44, 85, 211, 152, 90, 219, 140, 239, 204, 170, 60, 25, 37,
+            ],
+            [
+                87, 114, 219, 160, 111, 250, 182, 210, 34, 159, 37, 186, 127, 44, 46, 147, 216, 57,
+                34, 196, 238, 169, 167, 13, 23, 125, 101, 68, 2, 197, 5, 30,
+            ],
+            [
+                241, 177, 174, 245, 27, 60, 29, 41, 140, 226, 113, 194, 140, 38, 32, 226, 219, 227,
+                28, 56, 27, 55, 237, 111, 18, 23, 159, 198, 127, 79, 115, 38,
+            ],
+            [
+                5, 252, 225, 175, 232, 227, 41, 248, 161, 242, 13, 94, 134, 41, 47, 105, 185, 145,
+                252, 29, 210, 14, 124, 207, 2, 204, 249, 188, 166, 99, 219, 5,
+            ],
+            [
+                52, 14, 160, 118, 215, 211, 3, 109, 72, 11, 121, 196, 103, 33, 201, 164, 43, 229,
+                155, 183, 248, 122, 195, 109, 202, 6, 188, 195, 8, 53, 174, 13,
+            ],
+        ],
+        [
+            [
+                6, 39, 83, 240, 183, 65, 163, 214, 87, 243, 118, 29, 119, 83, 187, 58, 214, 157,
+                212, 199, 76, 76, 234, 73, 215, 33, 22, 90, 71, 71, 155, 2,
+            ],
+            [
+                192, 248, 161, 132, 80, 72, 219, 202, 44, 148, 40, 54, 29, 181, 168, 208, 250, 239,
+                223, 176, 22, 9, 232, 33, 232, 209, 122, 53, 101, 58, 217, 51,
+            ],
+            [
+                114, 14, 93, 55, 168, 82, 36, 119, 193, 75, 179, 72, 236, 158, 233, 111, 32, 231,
+                97, 171, 141, 71, 198, 248, 153, 204, 141, 96, 136, 42, 235, 48,
+            ],
+            [
+                222, 57, 150, 173, 139, 166, 18, 100, 31, 113, 227, 175, 145, 73, 227, 235, 161,
+                228, 133, 101, 35, 231, 243, 216, 222, 15, 158, 111, 40, 243, 23, 42,
+            ],
+            [
+                138, 22, 151, 217, 133, 117, 57, 148, 118, 120, 128, 104, 68, 33, 185, 224, 99, 29,
+                17, 143, 61, 86, 35, 188, 182, 20, 240, 106, 177, 184, 55, 12,
+            ],
+            [
+                97, 233, 191, 10, 178, 106, 15, 217, 185, 166, 236, 122, 224, 25, 19, 75, 101, 117,
+                80, 182, 136, 117, 125, 124, 225, 232, 178, 22, 130, 207, 162, 39,
+            ],
+            [
+                70, 85, 59, 187, 116, 134, 220, 30, 45, 62, 194, 191, 92, 95, 52, 130, 54, 189, 95,
+                117, 87, 129, 112, 74, 20, 72, 173, 233, 74, 93, 109, 6,
+            ],
+            [
+                59, 73, 84, 106, 175, 185, 211, 144, 125, 155, 6, 185, 166, 208, 105, 238, 36, 15,
+                93, 231, 97, 173, 78, 235, 19, 86, 25, 20, 11, 115, 173, 39,
+            ],
+        ],
+        [
+            [
+                148, 178, 61, 214, 70, 126, 204, 165, 229, 112, 45, 29, 196, 119, 212, 186, 68, 24,
+                182, 54, 191, 78, 226, 147, 23, 20, 203, 53, 176, 3, 173, 30,
+            ],
+            [
+                112, 6, 66, 254, 151, 94, 143, 243, 131, 150, 119, 215, 167, 233, 192, 97, 125,
+                116, 43, 94, 117, 134, 12, 37, 214, 213, 168, 254, 25, 65, 80, 56,
+            ],
+            [
+                13, 38, 53, 189, 217, 163, 83, 149, 243, 91, 108, 165, 252, 123, 74, 204, 246, 218,
+                87, 225, 193, 121, 78, 154, 72, 135, 219, 196, 1, 64, 89, 6,
+            ],
+            [
+                117, 180, 51, 218, 191, 159, 240, 78, 238, 113, 68, 108, 46, 20, 28, 35, 0, 251,
+                68, 138, 9, 203, 36, 173, 253, 131, 14, 66, 11, 181, 84, 1,
+            ],
+            [
+                212, 58, 91, 236, 131, 240, 78, 232, 201, 157, 248, 41, 61, 13, 226, 106, 213, 227,
+                48, 179, 153, 24, 90, 142, 137, 103, 215, 186, 139, 49, 104, 1,
+            ],
+            [
+                122, 192, 87, 204, 210, 187, 144, 69, 251, 143, 209, 99, 35, 112, 188, 168, 52, 32,
+                206, 163, 200, 130, 134, 178, 159, 145, 139, 55, 217, 59, 191, 10,
+            ],
+            [
+                210, 95, 112, 170, 216, 20, 228, 119, 149, 67, 2, 127, 139, 61, 218, 128, 244, 116,
+                249, 246, 9, 83, 90, 21, 117, 124, 231, 245, 25, 92, 185, 33,
+            ],
+            [
+                155, 171, 60, 108, 135, 31, 103, 163, 87, 204, 179, 219, 59, 225, 231, 122, 124,
+                24, 37, 150, 99, 165, 205, 81, 18, 167, 95, 5, 122, 122, 181, 25,
+            ],
+        ],
+        [
+            [
+                93, 22, 100, 76, 235, 206, 44, 215, 175, 44, 90, 227, 64, 161, 168, 231, 80, 86,
+                188, 21, 70, 101, 85, 103, 28, 225, 8, 4, 82, 225, 136, 15,
+            ],
+            [
+                250, 3, 56, 89, 14, 35, 21, 14, 183, 42, 93, 60, 84, 240, 101, 92, 179, 127, 122,
+                137, 28, 211, 34, 198, 66, 41, 86, 172, 54, 30, 228, 50,
+            ],
+            [
+                115, 21, 87, 14, 77, 21, 218, 137, 183, 4, 127, 92, 236, 84, 243, 181, 159, 86,
+                254, 211, 227, 82, 150, 103, 4, 185, 147, 186, 93, 128, 192, 38,
+            ],
+            [
+                254, 154, 238, 205, 168, 175, 132, 241, 107, 142, 212, 94, 185, 18, 160, 14, 10, 7,
+                124, 125, 133, 125, 42, 116, 17, 238, 41, 156, 252, 90, 29, 14,
+            ],
+            [
+                207, 227, 85, 184, 118, 244, 41, 165, 132, 34, 115, 191, 157, 144, 141, 109, 248,
+                139, 61, 77, 107, 52, 246, 84, 3, 121, 169, 136, 92, 134, 97, 30,
+            ],
+            [
+                42, 137, 153, 95, 39, 97, 48, 83, 8, 56, 44, 251, 24, 42, 202, 101, 40, 156, 209,
+                162, 188, 112, 237, 33, 229, 12, 115, 233, 199, 6, 35, 29,
+            ],
+            [
+                92, 148, 48, 254, 188, 14, 153, 74, 255, 92, 111, 110, 136, 244, 80, 21, 249, 116,
+                234, 238, 46, 209, 219, 212, 155, 123, 131, 55, 142, 161, 125, 17,
+            ],
+            [
+                223, 107, 192, 165, 115, 87, 67, 49, 10, 47, 90, 221, 121, 35, 171, 75, 24, 255,
+                89, 238, 135, 242, 215, 19, 214, 222, 239, 198, 27, 137, 93, 6,
+            ],
+        ],
+        [
+            [
+                193, 110, 141, 108, 112, 9, 173, 50, 25, 6, 71, 134, 124, 156, 35, 163, 30, 49, 91,
+                179, 243, 18, 63, 188, 23, 180, 241, 106, 160, 8, 165, 3,
+            ],
+            [
+                95, 146, 183, 254, 135, 21, 160, 122, 11, 121, 112, 128, 148, 81, 148, 228, 96, 55,
+                96, 110, 219, 254, 189, 82, 158, 214, 209, 157, 204, 179, 229, 62,
+            ],
+            [
+                215, 204, 153, 36, 164, 107, 17, 111, 224, 140, 116, 91, 71, 131, 231, 116, 235,
+                107, 163, 68, 228, 170, 105, 117, 94, 119, 190, 78, 124, 147, 168, 58,
+            ],
+            [
+                192, 111, 102, 41, 34, 15, 125, 29, 220, 174, 74, 100, 132, 59, 152, 245, 110, 95,
+                139, 180, 176, 101, 73, 170, 47, 125, 120, 141, 137, 194, 43, 31,
+            ],
+            [
+                116, 152, 156, 139, 168, 197, 97, 207, 221, 243, 1, 26, 49, 164, 217, 205, 132, 27,
+                241, 77, 233, 236, 41, 198, 245, 10, 152, 47, 254, 46, 232, 45,
+            ],
+            [
+                5, 39, 199, 245, 41, 202, 175, 248, 3, 63, 147, 138, 82, 52, 9, 6, 17, 0, 37, 241,
+                116, 218, 65, 49, 29, 141, 189, 159, 115, 37, 36, 3,
+            ],
+            [
+                255, 29, 191, 220, 169, 84, 96, 159, 48, 239, 161, 246, 11, 10, 128, 31, 26, 89,
+                139, 251, 45, 73, 3, 21, 28, 68, 193, 247, 149, 203, 187, 33,
+            ],
+            [
+                201, 22, 165, 27, 235, 129, 212, 132, 24, 253, 189, 48, 166, 121, 187, 15, 62, 61,
+                158, 129, 235, 98, 43, 92, 17, 19, 239, 213, 185, 182, 50, 14,
+            ],
+        ],
+        [
+            [
+                10, 140, 219, 99, 56, 126, 150, 209, 116, 219, 241, 152, 253, 107, 238, 68, 77, 11,
+                18, 70, 42, 125, 191, 105, 160, 254, 128, 159, 66, 61, 39, 11,
+            ],
+            [
+                234, 13, 217, 59, 185, 105, 249, 80, 242, 240, 75, 206, 202, 131, 145, 116, 54,
+                212, 114, 145, 56, 87, 58, 219, 189, 221, 224, 211, 194, 48, 33, 38,
+            ],
+            [
+                248, 179, 35, 86, 155, 77, 84, 59, 121, 43, 13, 152, 44, 66, 112, 112, 206, 0, 74,
+                157, 89, 187, 52, 176, 125, 167, 210, 243, 42, 202, 187, 5,
+            ],
+            [
+                194, 176, 213, 130, 61, 39, 54, 89, 252, 69, 22, 65, 110, 47, 215, 115, 49, 211,
+                222, 146, 10, 187, 67, 74, 101, 120, 17, 180, 133, 111, 249, 47,
+            ],
+            [
+                57, 115, 233, 225, 241, 36, 128, 1, 173, 131, 205, 238, 226, 40, 12, 68, 188, 160,
+                212, 170, 3, 119, 107, 100, 128, 32, 18, 100, 139, 68, 204, 0,
+            ],
+            [
+                221, 50, 222, 108, 121, 176, 82, 46, 230, 206, 236, 221, 166, 49, 30, 50, 107, 68,
+                183, 108, 182, 248, 171, 194, 73, 163, 195, 132, 24, 212, 8, 50,
+            ],
+            [
+                252, 230, 8, 45, 115, 255, 211, 152, 188, 89, 64, 147, 179, 28, 158, 121, 172, 84,
+                95, 215, 122, 200, 72, 4, 34, 173, 225, 254, 25, 2, 132, 47,
+            ],
+            [
+                20, 183, 103, 17, 209, 118, 154, 62, 226, 167, 255, 7, 201, 168, 143, 18, 252, 252,
+                115, 179, 89, 158, 96, 83, 172, 5, 93, 35, 160, 253, 75, 30,
+            ],
+        ],
+        [
+            [
+                112, 110, 155, 216, 80, 228, 89, 44, 212, 111, 191, 94, 255, 202, 77, 127, 138,
+                227, 9, 150, 4, 81, 40, 28, 62, 188, 6, 145, 171, 162, 224, 44,
+            ],
+            [
+                154, 183, 212, 209, 167, 248, 84, 82, 96, 92, 124, 2, 174, 254, 73, 95, 175, 109,
+                24, 91, 178, 63, 193, 144, 245, 83, 32, 148, 199, 168, 81, 49,
+            ],
+            [
+                244, 162, 33, 54, 121, 50, 131, 129, 147, 225, 198, 18, 84, 232, 153, 121, 97, 157,
+                26, 169, 243, 18, 163, 208, 131, 124, 123, 110, 187, 230, 180, 49,
+            ],
+            [
+                152, 66, 85, 99, 78, 51, 88, 28, 38, 211, 49, 202, 76, 217, 240, 189, 77, 36, 171,
+                230, 192, 149, 76, 5, 119, 128, 54, 111, 42, 115, 73, 21,
+            ],
+            [
+                243, 14, 9, 54, 246, 212, 230, 23, 104, 23, 206, 245, 212, 217, 189, 30, 155, 234,
+                221, 122, 9, 126, 7, 238, 174, 107, 6, 151, 251, 74, 181, 46,
+            ],
+            [
+                197, 39, 38, 251, 160, 216, 43, 247, 26, 20, 227, 12, 83, 21, 161, 213, 74, 66,
+                105, 180, 53, 76, 123, 29, 199, 60, 236, 248, 255, 212, 160, 5,
+            ],
+            [
+                188, 156, 139, 9, 58, 255, 245, 48, 121, 11, 213, 40, 38, 38, 252, 188, 229, 134,
+                78, 133, 75, 106, 162, 241, 9, 12, 65, 230, 135, 57, 108, 9,
+            ],
+            [
+                117, 132, 189, 217, 224, 30, 177, 151, 243, 249, 136, 141, 69, 241, 96, 147, 221,
+                246, 93, 243, 156, 76, 252, 70, 15, 164, 147, 88, 70, 10, 39, 56,
+            ],
+        ],
+        [
+            [
+                33, 112, 66, 184, 72, 61, 45, 80, 245, 199, 198, 78, 29, 59, 191, 29, 90, 88, 213,
+                220, 166, 83, 7, 212, 123, 253, 179, 14, 31, 140, 160, 28,
+            ],
+            [
+                191, 155, 184, 163, 181, 131, 116, 64, 51, 37, 26, 149, 225, 39, 187, 136, 150,
+                127, 39, 217, 212, 25, 8, 23, 250, 12, 9, 83, 5, 181, 147, 54,
+            ],
+            [
+                93, 117, 195, 103, 248, 152, 250, 174, 38, 161, 8, 108, 161, 83, 81, 197, 144, 25,
+                16, 197, 59, 136, 14, 112, 115, 217, 91, 213, 167, 99, 129, 16,
+            ],
+            [
+                233, 154, 223, 23, 0, 88, 192, 188, 56, 89, 55, 214, 235, 111, 121, 192, 184, 231,
+                6, 32, 18, 147, 73, 202, 229, 56, 4, 60, 162, 101, 243, 44,
+            ],
+            [
+                129, 109, 251, 75, 19, 41, 238, 230, 94, 165, 99, 25, 170, 98, 162, 231, 141, 116,
+                58, 30, 166, 93, 147, 117, 194, 106, 161, 249, 237, 252, 74, 52,
+            ],
+            [
+                159, 154, 21, 145, 158, 47, 200, 137, 157, 14, 125, 16, 13, 138, 240, 12, 85, 130,
+                248, 60, 105, 237, 213, 1, 130, 196, 91, 151, 240, 178, 65, 49,
+            ],
+            [
+                195, 128, 196, 255, 186, 103, 234, 88, 134, 244, 7, 100, 228, 213, 213, 110, 107,
+                132, 226, 68, 214, 129, 238, 183, 83, 151, 125, 34, 220, 208, 140, 43,
+            ],
+            [
+                61, 155, 91, 107, 79, 95, 42, 180, 147, 247, 54, 155, 252, 243, 2, 137, 201, 218,
+                74, 144, 227, 239, 29, 54, 31, 76, 97, 240, 38, 95, 229, 19,
+            ],
+        ],
+        [
+            [
+                247, 45, 22, 228, 51, 246, 250, 44, 79, 206, 208, 194, 23, 21, 240, 110, 18, 150,
+                147, 39, 82, 125, 186, 140, 71, 27, 20, 17, 232, 123, 238, 36,
+            ],
+            [
+                133, 160, 220, 141, 20, 248, 230, 39, 188, 114, 214, 186, 127, 134, 81, 50, 180,
+                178, 5, 109, 232, 237, 68, 113, 42, 73, 118, 228, 31, 93, 83, 53,
+            ],
+            [
+                138, 169, 20, 75, 213, 155, 108, 241, 197, 243, 12, 217, 62, 214, 217, 2, 247, 167,
+                251, 33, 70, 185, 160, 11, 28, 42, 1, 96, 124, 139, 53, 62,
+            ],
+            [
+                118, 204, 163, 3, 87, 43, 81, 69, 162, 172, 74, 173, 4, 197, 104, 36, 246, 182, 35,
+                181, 41, 17, 14, 80, 92, 169, 182, 56, 25, 81, 215, 36,
+            ],
+            [
+                38, 10, 215, 173, 213, 224, 152, 244, 157, 124, 174, 50, 93, 35, 193, 195, 199,
+                143, 242, 255, 241, 135, 70, 132, 229, 72, 224, 134, 173, 22, 135, 43,
+            ],
+            [
+                197, 250, 13, 4, 195, 37, 176, 30, 13, 73, 109, 217, 12, 38, 81, 248, 223, 161, 91,
+                72, 84, 96, 134, 95, 142, 156, 52, 134, 227, 94, 83, 37,
+            ],
+            [
+                217, 57, 126, 66, 216, 87, 78, 224, 150, 95, 165, 52, 64, 34, 130, 65, 239, 228,
+                25, 76, 105, 61, 224, 58, 23, 191, 45, 206, 206, 244, 122, 48,
+            ],
+            [
+                230, 101, 234, 70, 158, 136, 191, 48, 21, 85, 174, 11, 21, 36, 127, 37, 245, 251,
+                104, 236, 232, 143, 72, 184, 16, 170, 154, 156, 158, 48, 227, 56,
+            ],
+        ],
+        [
+            [
+                2, 72, 54, 253, 87, 192, 40, 159, 143, 29, 119, 54, 117, 137, 75, 176, 187, 24,
+                199, 97, 110, 195, 79, 197, 162, 243, 229, 114, 131, 186, 161, 33,
+            ],
+            [
+                206, 215, 112, 76, 204, 175, 41, 237, 91, 173, 61, 129, 120, 76, 138, 190, 111,
+                194, 133, 191, 141, 21, 205, 164, 216, 197, 177, 38, 99, 176, 180, 43,
+            ],
+            [
+                212, 93, 146, 197, 229, 40, 192, 103, 98, 245, 66, 214, 231, 215, 87, 82, 199, 187,
+                54, 125, 254, 9, 107, 193, 41, 98, 91, 99, 186, 79, 119, 1,
+            ],
+            [
+                171, 231, 47, 42, 103, 155, 117, 103, 93, 113, 193, 96, 93, 33, 178, 41, 216, 100,
+                132, 80, 182, 220, 94, 201, 230, 112, 196, 79, 35, 179, 148, 30,
+            ],
+            [
+                126, 119, 70, 226, 228, 69, 75, 151, 237, 115, 81, 60, 203, 165, 84, 216, 132, 28,
+                184, 233, 220, 114, 27, 20, 250, 44, 84, 14, 78, 232, 39, 48,
+            ],
+            [
+                253, 26, 47, 192, 58, 144, 77, 206, 148, 221, 141, 180, 58, 186, 98, 227, 56, 79,
+                91, 219, 83, 104, 72, 152, 198, 197, 9, 200, 246, 97, 69, 54,
+            ],
+            [
+                225, 77, 204, 132, 238, 99, 150, 109, 210, 241, 157, 14, 194, 79, 165, 82, 228,
+                180, 122, 120, 12, 194, 152, 188, 36, 141, 80, 141, 18, 122, 165, 16,
+            ],
+            [
+                157, 145, 237, 170, 242, 174, 133, 87, 226, 171, 51, 223, 240, 144, 208, 107, 164,
+                1, 22, 49, 154, 11, 12, 59, 20, 110, 168, 122, 250, 232, 157, 29,
+            ],
+        ],
+        [
+            [
+                51, 150, 210, 180, 10, 196, 20, 202, 252, 49, 42, 34, 117, 68, 14, 242, 113, 37,
+                199, 25, 220, 164, 15, 145, 222, 98, 94, 206, 5, 199, 110, 0,
+            ],
+            [
+                161, 11, 43, 120, 163, 211, 87, 100, 183, 147, 139, 121, 65, 234, 48, 72, 108, 109,
+                87, 241, 1, 167, 89, 43, 126, 160, 149, 166, 81, 233, 41, 26,
+            ],
+            [
+                164, 252, 64, 244, 15, 44, 253, 3, 9, 186, 36, 26, 190, 178, 144, 64, 9, 119, 29,
+                157, 86, 49, 187, 158, 151, 234, 221, 159, 186, 78, 88, 30,
+            ],
+            [
+                204, 173, 19, 208, 146, 255, 236, 122, 105, 114, 131, 201, 29, 44, 13, 206, 168,
+                11, 44, 139, 136, 25, 229, 1, 7, 9, 161, 210, 21, 245, 20, 58,
+            ],
+            [
+                119, 201, 89, 55, 29, 55, 92, 27, 5, 253, 157, 79, 234, 151, 245, 51, 54, 63, 220,
+                212, 5, 158, 173, 228, 237, 194, 90, 107, 176, 241, 111, 18,
+            ],
+            [
+                42, 252, 164, 89, 18, 28, 173, 192, 254, 119, 91, 26, 41, 92, 20, 24, 147, 225, 87,
+                244, 141, 113, 58, 116, 37, 200, 215, 72, 51, 108, 160, 21,
+            ],
+            [
+                242, 130, 133, 37, 126, 111, 78, 1, 95, 67, 2, 150, 87, 143, 165, 239, 123, 39,
+                139, 251, 130, 231, 79, 7, 152, 20, 75, 194, 77, 150, 71, 7,
+            ],
+            [
+                16, 43, 135, 104, 60, 108, 245, 248, 67, 223, 156, 192, 124, 218, 3, 203, 206, 173,
+                243, 79, 173, 121, 168, 179, 107, 35, 11, 147, 39, 185, 206, 44,
+            ],
+        ],
+        [
+            [
+                102, 63, 199, 78, 74, 231, 217, 72, 63, 177, 8, 67, 64, 39, 248, 218, 57, 128, 230,
+                179, 218, 165, 233, 70, 208, 21, 37, 114, 63, 54, 187, 30,
+            ],
+            [
+                101, 250, 93, 6, 161, 26, 33, 209, 143, 37, 115, 84, 207, 15, 148, 137, 5, 124,
+                152, 92, 225, 202, 175, 137, 57, 126, 164, 26, 85, 243, 90, 58,
+            ],
+            [
+                2, 82, 8, 155, 97, 216, 125, 56, 192, 146, 93, 37, 32, 243, 92, 43, 112, 47, 107,
+                233, 187, 88, 191, 17, 74, 4, 228, 229, 217, 222, 149, 61,
+            ],
+            [
+                216, 216, 212, 27, 68, 168, 238, 199, 176, 124, 174, 153, 2, 247, 211, 23, 72, 193,
+                150, 89, 174, 62, 70, 144, 207, 13, 224, 253, 184, 242, 118, 44,
+            ],
+            [
+                237, 79, 227, 64, 96, 224, 37, 83, 77, 163, 151, 26, 1, 243, 206, 155, 139, 240,
+                85, 81, 202, 66, 69, 65, 171, 173, 230, 255, 131, 89, 116, 29,
+            ],
+            [
+                25, 156, 6, 107, 40, 150, 60, 144, 57, 226, 100, 187, 68, 195, 228, 26, 151, 250,
+                83, 171, 182, 98, 30, 229, 181, 91, 147, 250, 114, 231, 183, 24,
+            ],
+            [
+                110, 148, 138, 126, 178, 54, 182, 88, 34, 191, 10, 16, 94, 165, 45, 186, 240, 55,
+                109, 65, 43, 252, 61, 96, 3, 79, 214, 6, 24, 190, 67, 57,
+            ],
+            [
+                21, 35, 91, 62, 241, 240, 151, 106, 152, 4, 54, 49, 161, 39, 149, 154, 123, 113,
+                68, 12, 224, 225, 106, 52, 53, 173, 90, 83, 54, 51, 147, 15,
+            ],
+        ],
+        [
+            [
+                226, 73, 39, 215, 200, 187, 133, 119, 197, 101, 208, 129, 115, 12, 39, 156, 35,
+                250, 60, 47, 222, 220, 109, 204, 69, 20, 129, 251, 200, 173, 210, 62,
+            ],
+            [
+                36, 238, 194, 201, 110, 251, 232, 234, 231, 189, 68, 209, 252, 18, 21, 236, 161,
+                26, 159, 96, 14, 13, 67, 32, 42, 7, 47, 142, 34, 191, 210, 49,
+            ],
+            [
+                223, 109, 231, 25, 169, 167, 6, 245, 11, 43, 90, 142, 10, 100, 126, 114, 61, 43,
+                105, 94, 197, 159, 73, 135, 254, 91, 195, 34, 169, 125, 125, 63,
+            ],
+            [
+                71, 96, 167, 205, 96, 82, 36, 192, 110, 240, 204, 91, 197, 233, 116, 239, 224, 68,
+                62, 27, 94, 206, 60, 90, 85, 79, 141, 115, 45, 155, 18, 28,
+            ],
+            [
+                60, 78, 238, 72, 226, 7, 125, 127, 249, 219, 92, 42, 22, 241, 9, 16, 239, 153, 211,
+                175, 111, 127, 119, 55, 168, 247, 197, 30, 81, 245, 47, 7,
+            ],
+            [
+                185, 88, 87, 22, 8, 156, 232, 14, 165, 89, 143, 119, 245, 104, 69, 13, 62, 205, 75,
+                50, 32, 234, 182, 210, 221, 119, 126, 101, 62, 227, 109, 21,
+            ],
+            [
+                71, 66, 99, 250, 223, 217, 185, 14, 62, 4, 42, 131, 234, 225, 26, 168, 53, 200, 13,
+                41, 23, 118, 198, 203, 153, 214, 124, 92, 150, 244, 200, 41,
+            ],
+            [
+                138, 138, 217, 153, 36, 187, 3, 166, 137, 117, 116, 239, 66, 109, 25, 8, 252, 182,
+                70, 137, 235, 222, 151, 186, 77, 166, 177, 80, 155, 21, 192, 6,
+            ],
+        ],
+        [
+            [
+                222, 144, 148, 139, 16, 135, 142, 81, 164, 232, 29, 210, 12, 138, 29, 165, 109, 68,
+                165, 113, 236, 191, 124, 219, 35, 15, 123, 224, 39, 15, 121, 13,
+            ],
+            [
+                122, 217, 101, 113, 137, 150, 85, 181, 156, 107, 61, 95, 156, 91, 40, 76, 165, 217,
+                7, 215, 108, 105, 209, 135, 108, 51, 240, 79, 21, 18, 203, 12,
+            ],
+            [
+                38, 17, 190, 182, 54, 7, 38, 109, 145, 232, 204, 179, 135, 184, 240, 140, 59, 52,
+                103, 177, 58, 243, 1, 50, 67, 73, 59, 225, 90, 125, 255, 61,
+            ],
+            [
+                138, 213, 254, 136, 245, 175, 57, 11, 62, 40, 1, 239, 117, 32, 8, 56, 219, 92, 70,
+                232, 227, 113, 69, 215, 70, 16, 188, 147, 39, 12, 186, 37,
+            ],
+            [
+                110, 55, 141, 76, 169, 128, 16, 235, 77, 66, 124, 246, 243, 193, 143, 106, 126, 52,
+                21, 140, 119, 199, 29, 247, 53, 54, 182, 192, 245, 122, 211, 30,
+            ],
+            [
+                39, 125, 113, 223, 198, 238, 2, 145, 128, 216, 150, 101, 199, 148, 214, 124, 47, 1,
+                30, 42, 157, 63, 219, 38, 59, 155, 166, 106, 32, 44, 163, 29,
+            ],
+            [
+                180, 196, 234, 196, 101, 80, 249, 202, 124, 174, 57, 153, 79, 11, 188, 234, 86,
+                117, 150, 55, 194, 31, 253, 129, 247, 196, 17, 24, 189, 193, 30, 61,
+            ],
+            [
+                10, 57, 101, 222, 241, 162, 28, 228, 96, 7, 68, 232, 12, 97, 76, 97, 46, 172, 152,
+                101, 92, 46, 28, 200, 86, 126, 223, 153, 190, 23, 248, 24,
+            ],
+        ],
+        [
+            [
+                42, 22, 10, 126, 58, 139, 223, 105, 225, 43, 95, 122, 113, 91, 32, 228, 149, 255,
+                241, 99, 58, 130, 1, 7, 163, 12, 215, 196, 60, 7, 147, 15,
+            ],
+            [
+                196, 51, 23, 77, 244, 100, 226, 80, 128, 107, 13, 121, 103, 149, 204, 75, 87, 245,
+                222, 146, 240, 179, 107, 230, 61, 225, 95, 87, 217, 141, 220, 57,
+            ],
+            [
+                122, 186, 123, 58, 181, 8, 224, 252, 110, 34, 52, 108, 121, 165, 126, 91, 148, 107,
+                101, 47, 77, 132, 88, 202, 206, 58, 4, 104, 20, 196, 79, 52,
+            ],
+            [
+                181, 192, 236, 128, 90, 245, 225, 153, 144, 90, 216, 97, 199, 217, 187, 131, 5,
+                250, 115, 188, 41, 22, 209, 184, 232, 247, 139, 144, 84, 232, 151, 22,
+            ],
+            [
+                148, 82, 236, 15, 150, 127, 232, 206, 210, 76, 168, 26, 81, 55, 79, 135, 38, 208,
+                16, 160, 137, 102, 218, 152, 249, 172, 98, 209, 13, 35, 11, 61,
+            ],
+            [
+                169, 234, 140, 169, 162, 119, 24, 95, 235, 44, 134, 41, 226, 204, 152, 174, 88,
+                120, 240, 214, 50, 156, 171, 156, 252, 162, 238, 35, 4, 83, 165, 51,
+            ],
+            [
+                59, 96, 188, 30, 76, 194, 231, 74, 70, 231, 224, 229, 166, 181, 194, 136, 114, 192,
+                235, 136, 209, 152, 160, 226, 148, 105, 8, 121, 94, 127, 81, 2,
+            ],
+            [
+                245, 1, 115, 54, 130, 149, 119, 102, 88, 211, 151, 126, 131, 132, 0, 67, 205, 82,
+                254, 134, 3, 75, 212, 199, 56, 10, 200, 88, 92, 30, 136, 33,
+            ],
+        ],
+        [
+            [
+                221, 50, 154, 243, 137, 165, 168, 105, 180, 26, 9, 96, 149, 86, 199, 254, 208, 39,
+                254, 241, 160, 154, 163, 242, 213, 197, 248, 132, 185, 187, 187, 13,
+            ],
+            [
+                126, 76, 153, 66, 79, 110, 45, 203, 38, 84, 246, 111, 215, 43, 221, 68, 6, 231,
+                166, 232, 154, 201, 84, 21, 27, 83, 199, 180, 22, 191, 242, 5,
+            ],
+            [
+                12, 147, 156, 146, 119, 55, 60, 143, 58, 26, 120, 152, 152, 19, 122, 48, 223, 132,
+                190, 233, 166, 170, 115, 133, 28, 33, 29, 139, 57, 2, 179, 27,
+            ],
+            [
+                210, 254, 219, 99, 112, 88, 114, 120, 38, 75, 226, 164, 167, 197, 233, 128, 39,
+                251, 239, 173, 60, 21, 108, 77, 112, 108, 181, 134, 59, 217, 169, 51,
+            ],
+            [
+                179, 7, 212, 186, 213, 25, 163, 236, 252, 27, 49, 167, 41, 210, 138, 60, 26, 183,
+                58, 50, 2, 92, 148, 223, 200, 92, 210, 37, 4, 178, 137, 18,
+            ],
+            [
+                34, 122, 211, 32, 19, 123, 56, 207, 156, 122, 1, 189, 11, 99, 231, 213, 253, 181,
+                156, 27, 12, 189, 23, 229, 200, 107, 33, 31, 95, 58, 61, 14,
+            ],
+            [
+                241, 209, 45, 41, 60, 159, 103, 161, 69, 178, 157, 60, 149, 1, 2, 239, 131, 227,
+                135, 236, 41, 253, 47, 121, 68, 246, 227, 35, 111, 238, 47, 24,
+            ],
+            [
+                83, 112, 12, 204, 125, 187, 121, 93, 200, 108, 178, 227, 174, 113, 133, 253, 150,
+                22, 225, 115, 26, 23, 198, 216, 195, 208, 171, 224, 188, 153, 242, 63,
+            ],
+        ],
+        [
+            [
+                201, 248, 246, 246, 95, 219, 249, 79, 154, 251, 90, 107, 86, 67, 157, 64, 61, 78,
+                190, 241, 182, 203, 79, 201, 180, 160, 185, 66, 163, 216, 21, 60,
+            ],
+            [
+                110, 76, 125, 159, 202, 174, 80, 133, 102, 14, 95, 191, 195, 210, 75, 27, 101, 118,
+                228, 115, 49, 170, 111, 21, 14, 101, 75, 45, 31, 100, 103, 14,
+            ],
+            [
+                120, 105, 194, 146, 183, 61, 136, 19, 22, 52, 251, 12, 96, 28, 236, 8, 67, 25, 118,
+                121, 60, 239, 199, 201, 1, 238, 153, 135, 214, 190, 177, 3,
+            ],
+            [
+                62, 128, 207, 200, 182, 193, 2, 178, 211, 223, 88, 152, 237, 2, 135, 132, 172, 202,
+                71, 242, 105, 148, 42, 49, 222, 250, 129, 218, 206, 54, 228, 44,
+            ],
+            [
+                27, 33, 255, 35, 51, 110, 86, 162, 248, 56, 250, 63, 208, 138, 84, 118, 35, 243,
+                101, 130, 88, 227, 252, 237, 183, 207, 129, 99, 155, 112, 118, 44,
+            ],
+            [
+                215, 227, 64, 231, 143, 5, 50, 17, 20, 160, 36, 201, 153, 152, 167, 5, 217, 188,
+                216, 0, 92, 147, 130, 4, 196, 91, 158, 221, 157, 36, 185, 14,
+            ],
+            [
+                146, 227, 87, 62, 169, 135, 81, 5, 96, 133, 242, 114, 87, 145, 90, 3, 89, 234, 160,
+                2, 31, 255, 64, 119, 31, 106, 249, 58, 184, 67, 5, 7,
+            ],
+            [
+                254, 100, 201, 111, 59, 205, 0, 77, 217, 69, 206, 45, 7, 205, 200, 193, 215, 26, 6,
+                142, 115, 42, 200, 137, 120, 255, 75, 12, 161, 220, 246, 51,
+            ],
+        ],
+        [
+            [
+                4, 20, 50, 37, 168, 119, 21, 189, 211, 89, 85, 158, 14, 141, 134, 205, 15, 230, 58,
+                214, 133, 232, 92, 43, 10, 129, 151, 60, 129, 165, 137, 41,
+            ],
+            [
+                194, 184, 47, 126, 86, 227, 209, 196, 43, 44, 225, 204, 7, 25, 188, 63, 93, 205,
+                26, 41, 108, 11, 103, 123, 35, 12, 243, 136, 195, 129, 148, 62,
+            ],
+            [
+                181, 40, 16, 16, 23, 247, 114, 178, 17, 51, 34, 219, 137, 212, 185, 239, 109, 217,
+                126, 249, 141, 175, 220, 175, 103, 147, 70, 62, 116, 7, 135, 50,
+            ],
+            [
+                59, 139, 92, 221, 53, 170, 175, 110, 180, 210, 155, 199, 0, 69, 83, 146, 253, 57,
+                45, 21, 103, 40, 74, 17, 45, 189, 254, 100, 61, 159, 4, 49,
+            ],
+            [
+                122, 77, 51, 231, 206, 212, 96, 83, 144, 169, 25, 137, 130, 19, 246, 205, 62, 39,
+                70, 77, 121, 228, 231, 57, 159, 143, 91, 103, 170, 115, 94, 60,
+            ],
+            [
+                168, 97, 25, 79, 154, 177, 149, 194, 33, 149, 217, 62, 72, 201, 159, 97, 64, 12,
+                93, 70, 255, 152, 63, 52, 69, 62, 213, 135, 103, 71, 38, 26,
+            ],
+            [
+                88, 101, 8, 172, 184, 106, 217, 9, 42, 19, 139, 188, 175, 161, 20, 76, 35, 60, 225,
+                4, 135, 213, 138, 209, 237, 212, 1, 240, 251, 77, 50, 42,
+            ],
+            [
+                98, 205, 80, 222, 250, 97, 216, 55, 127, 36, 162, 162, 227, 157, 53, 99, 153, 35,
+                79, 201, 128, 77, 197, 35, 58, 13, 189, 223, 133, 166, 245, 52,
+            ],
+        ],
+        [
+            [
+                204, 79, 154, 56, 121, 148, 65, 166, 67, 192, 82, 196, 230, 234, 161, 217, 233, 54,
+                113, 253, 163, 41, 84, 105, 230, 203, 96, 99, 136, 135, 72, 15,
+            ],
+            [
+                204, 224, 230, 49, 223, 218, 130, 150, 158, 236, 95, 9, 15, 169, 172, 159, 53, 10,
+                101, 12, 44, 2, 21, 86, 168, 24, 7, 14, 211, 20, 162, 55,
+            ],
+            [
+                13, 24, 219, 207, 184, 200, 49, 62, 140, 2, 246, 9, 110, 224, 242, 139, 192, 93,
+                187, 163, 27, 14, 199, 69, 125, 93, 252, 61, 134, 242, 233, 11,
+            ],
+            [
+                81, 160, 108, 251, 1, 119, 126, 64, 198, 152, 134, 125, 108, 92, 104, 252, 54, 50,
+                24, 1, 131, 11, 183, 88, 37, 139, 72, 172, 194, 138, 4, 26,
+            ],
+            [
+                162, 148, 104, 165, 167, 249, 10, 10, 59, 172, 202, 16, 240, 114, 206, 96, 43, 16,
+                45, 228, 64, 192, 184, 115, 23, 9, 106, 89, 186, 159, 176, 51,
+            ],
+            [
+                27, 64, 69, 134, 29, 191, 39, 108, 82, 112, 255, 120, 99, 248, 144, 172, 173, 76,
+                230, 180, 44, 211, 111, 154, 27, 87, 171, 60, 46, 73, 35, 5,
+            ],
+            [
+                65, 24, 174, 92, 118, 176, 52, 233, 137, 155, 231, 24, 42, 221, 157, 237, 178, 92,
+                59, 155, 97, 199, 104, 181, 237, 224, 44, 79, 44, 55, 180, 49,
+            ],
+            [
+                248, 213, 6, 48, 27, 145, 26, 65, 194, 135, 117, 194, 177, 35, 180, 80, 236, 46,
+                61, 152, 187, 40, 118, 60, 33, 17, 251, 54, 111, 143, 52, 1,
+            ],
+        ],
+        [
+            [
+                70, 119, 41, 83, 120, 26, 132, 90, 31, 165, 39, 79, 187, 59, 232, 255, 108, 7, 196,
+                145, 129, 135, 71, 66, 246, 46, 251, 219, 17, 22, 246, 23,
+            ],
+            [
+                118, 97, 52, 176, 194, 55, 134, 221, 185, 224, 57, 159, 240, 104, 214, 231, 54, 0,
+                102, 223, 198, 163, 41, 255, 222, 27, 174, 150, 157, 231, 240, 20,
+            ],
+            [
+                186, 175, 44, 175, 165, 97, 20, 204, 98, 168, 127, 134, 223, 249, 176, 58, 183,
+                108, 192, 217, 182, 99, 17, 8, 58, 225, 19, 132, 68, 115, 50, 10,
+            ],
+            [
+                234, 7, 109, 211, 121, 246, 96, 75, 117, 56, 20, 222, 85, 180, 158, 41, 5, 1, 239,
+                242, 18, 121, 125, 218, 0, 163, 228, 158, 212, 210, 197, 47,
+            ],
+            [
+                227, 0, 212, 166, 84, 32, 240, 251, 254, 25, 59, 105, 190, 166, 215, 255, 79, 170,
+                230, 184, 187, 165, 128, 48, 206, 248, 215, 84, 180, 223, 242, 53,
+            ],
+            [
+                222, 9, 242, 203, 135, 154, 12, 73, 79, 122, 99, 66, 132, 155, 55, 159, 219, 17,
+                71, 134, 190, 137, 103, 69, 10, 76, 26, 244, 174, 122, 4, 18,
+            ],
+            [
+                95, 10, 249, 31, 190, 45, 212, 93, 31, 29, 189, 230, 25, 196, 174, 32, 200, 41,
+                114, 43, 30, 11, 107, 156, 90, 141, 222, 124, 203, 170, 245, 24,
+            ],
+            [
+                11, 174, 112, 63, 111, 94, 188, 177, 254, 49, 93, 208, 91, 55, 98, 102, 78, 185,
+                161, 105, 155, 61, 65, 131, 244, 84, 189, 193, 85, 148, 101, 31,
+            ],
+        ],
+        [
+            [
+                108, 139, 206, 52, 146, 38, 176, 159, 237, 57, 173, 239, 89, 15, 195, 188, 143,
+                248, 161, 134, 140, 97, 15, 176, 94, 228, 239, 200, 238, 73, 175, 34,
+            ],
+            [
+                242, 18, 155, 157, 229, 87, 166, 123, 169, 115, 38, 158, 206, 107, 5, 7, 160, 242,
+                161, 162, 37, 127, 42, 247, 134, 160, 128, 130, 148, 13, 167, 10,
+            ],
+            [
+                81, 183, 201, 113, 47, 205, 173, 2, 175, 245, 116, 177, 201, 139, 108, 141, 221,
+                13, 225, 199, 229, 197, 91, 231, 18, 153, 35, 123, 87, 222, 21, 20,
+            ],
+            [
+                99, 185, 1, 124, 109, 194, 143, 193, 102, 149, 171, 56, 100, 110, 95, 128, 232,
+                160, 0, 231, 189, 18, 150, 102, 139, 52, 178, 177, 64, 123, 95, 1,
+            ],
+            [
+                25, 246, 136, 127, 24, 91, 73, 157, 138, 163, 49, 228, 223, 148, 23, 177, 177, 142,
+                225, 110, 173, 126, 61, 21, 59, 158, 254, 133, 177, 200, 113, 51,
+            ],
+            [
+                198, 75, 177, 62, 125, 136, 31, 4, 25, 41, 232, 172, 123, 239, 238, 173, 57, 232,
+                96, 23, 198, 18, 243, 172, 121, 22, 131, 104, 132, 57, 71, 54,
+            ],
+            [
+                126, 124, 118, 208, 133, 139, 85, 28, 220, 151, 166, 168, 81, 13, 97, 238, 16, 162,
+                196, 7, 107, 244, 183, 220, 212, 248, 246, 72, 116, 191, 25, 4,
+            ],
+            [
+                65, 85, 71, 119, 244, 54, 12, 155, 123, 180, 152, 90, 249, 98, 205, 109, 57, 18,
+                123, 13, 251, 62, 98, 20, 223, 150, 3, 134, 73, 71, 249, 62,
+            ],
+        ],
+        [
+            [
+                29, 195, 126, 245, 198, 231, 199, 233, 135, 44, 98, 134, 113, 224, 74, 165, 20,
+                230, 63, 110, 148, 66, 253, 240, 223, 35, 9, 34, 185, 1, 123, 55,
+            ],
+            [
+                117, 40, 229, 36, 154, 75, 146, 140, 199, 13, 105, 242, 128, 159, 190, 115, 244, 7,
+                189, 54, 74, 124, 56, 65, 11, 82, 123, 205, 61, 52, 239, 63,
+            ],
+            [
+                110, 48, 16, 17, 81, 240, 136, 142, 137, 59, 111, 61, 226, 145, 211, 126, 187, 149,
+                225, 179, 8, 152, 255, 20, 145, 125, 253, 241, 181, 81, 26, 30,
+            ],
+            [
+                242, 190, 251, 209, 33, 145, 175, 230, 3, 114, 130, 126, 230, 71, 70, 141, 142, 86,
+                29, 30, 56, 81, 200, 177, 64, 245, 159, 27, 66, 80, 174, 7,
+            ],
+            [
+                35, 76, 175, 53, 197, 82, 23, 135, 136, 151, 89, 6, 205, 176, 247, 117, 55, 187, 9,
+                99, 86, 204, 101, 84, 102, 69, 58, 166, 207, 219, 120, 10,
+            ],
+            [
+                173, 152, 41, 246, 14, 116, 206, 80, 124, 134, 58, 98, 236, 61, 58, 109, 61, 167,
+                68, 247, 101, 77, 219, 244, 163, 232, 80, 65, 192, 154, 80, 61,
+            ],
+            [
+                63, 132, 111, 99, 232, 33, 107, 177, 1, 180, 33, 96, 202, 157, 153, 34, 89, 110,
+                161, 80, 139, 92, 158, 219, 144, 87, 158, 14, 158, 186, 115, 40,
+            ],
+            [
+                90, 198, 97, 248, 104, 87, 209, 99, 234, 218, 191, 211, 137, 32, 78, 161, 214, 169,
+                68, 133, 106, 22, 9, 90, 103, 55, 155, 182, 191, 137, 233, 30,
+            ],
+        ],
+        [
+            [
+                236, 153, 185, 208, 229, 244, 52, 6, 154, 3, 50, 189, 10, 232, 210, 109, 51, 7,
+                151, 76, 10, 55, 172, 45, 71, 30, 230, 134, 244, 76, 217, 9,
+            ],
+            [
+                12, 120, 153, 174, 85, 3, 120, 101, 17, 121, 232, 213, 39, 60, 177, 18, 155, 209,
+                68, 23, 196, 15, 7, 188, 28, 115, 74, 77, 184, 220, 28, 23,
+            ],
+            [
+                185, 117, 141, 82, 192, 45, 184, 129, 212, 62, 179, 238, 213, 121, 14, 30, 86, 167,
+                129, 11, 126, 199, 229, 138, 214, 225, 154, 83, 231, 224, 26, 32,
+            ],
+            [
+                228, 140, 170, 33, 220, 189, 63, 254, 65, 90, 144, 163, 248, 173, 14, 79, 99, 52,
+                23, 222, 218, 32, 135, 109, 102, 63, 130, 222, 43, 197, 216, 4,
+            ],
+            [
+                117, 39, 253, 83, 248, 202, 211, 34, 159, 231, 227, 21, 143, 0, 121, 207, 10, 197,
+                76, 161, 75, 173, 123, 39, 205, 65, 52, 123, 21, 39, 211, 61,
+            ],
+            [
+                74, 212, 233, 250, 127, 103, 89, 142, 3, 92, 107, 241, 180, 42, 79, 196, 212, 232,
+                167, 190, 173, 119, 245, 176, 148, 218, 179, 62, 67, 126, 235, 24,
+            ],
+            [
+                251, 199, 105, 6, 188, 55, 89, 156, 105, 152, 209, 141, 152, 3, 173, 177, 105, 164,
+                144, 76, 26, 144, 132, 25, 87, 251, 251, 85, 16, 108, 243, 10,
+            ],
+            [
+                238, 131, 44, 124, 56, 241, 200, 96, 83, 48, 101, 46, 9, 173, 136, 34, 223, 141,
+                111, 195, 100, 244, 97, 239, 216, 252, 52, 128, 247, 29, 46, 2,
+            ],
+        ],
+    ],
+    [
+        [
+            [
+                121, 219, 206, 22, 29, 93, 100, 21, 14, 29, 205, 175, 161, 66, 39, 214, 115, 184,
+                116, 35, 2, 68, 63, 165, 232, 9, 145, 59, 24, 51, 48, 1,
+            ],
+            [
+                192, 74, 57, 183, 147, 143, 39, 140, 254, 185, 20, 172, 66, 255, 202, 102, 75, 66,
+                4, 134, 220, 232, 172, 16, 92, 114, 97, 180, 117, 193, 186, 7,
+            ],
+            [
+                74, 64, 255, 14, 163, 64, 163, 162, 109, 202, 17, 116, 198, 70, 219, 46, 110, 110,
+                247, 10, 103, 216, 228, 45, 209, 174, 232, 225, 139, 175, 76, 51,
+            ],
+            [
+                201, 119, 104, 168, 41, 161, 202, 24, 143, 151, 112, 201, 45, 20, 201, 220, 111,
+                215, 30, 226, 17, 57, 124, 159, 240, 36, 38, 83, 31, 251, 172, 40,
+            ],
+            [
+                207, 197, 85, 124, 4, 180, 74, 142, 216, 174, 43, 151, 71, 140, 235, 212, 64, 44,
+                50, 178, 144, 70, 43, 91, 252, 143, 14, 138, 138, 107, 61, 0,
+            ],
+            [
+                167, 147, 68, 202, 36, 66, 59, 30, 209, 192, 163, 137, 45, 197, 190, 49, 187, 110,
+                66, 171, 73, 70, 79, 168, 220, 120, 206, 82, 120, 228, 43, 15,
+            ],
+            [
+                116, 235, 160, 30, 114, 145, 26, 54, 12, 21, 31, 76, 57, 157, 152, 68, 181, 187,
+                95, 86, 146, 24, 205, 227, 14, 31, 83, 179, 173, 69, 232, 25,
+            ],
+            [
+                225, 217, 43, 49, 191, 28, 188, 74, 97, 4, 9, 192, 219, 145, 254, 52, 182, 7, 2,
+                122, 195, 144, 162, 160, 108, 213, 91, 24, 219, 248, 199, 27,
+            ],
+        ],
+        [
+            [
+                104, 147, 0, 1, 110, 24, 1, 116, 226, 237, 126, 171, 132, 88, 141, 23, 146, 47,
+                245, 52, 143, 24, 35, 69, 51, 142, 9, 158, 252, 155, 192, 36,
+            ],
+            [
+                185, 206, 233, 187, 253, 239, 134, 146, 119, 82, 12, 244, 140, 43, 9, 229, 179, 16,
+                98, 123, 215, 37, 164, 54, 103, 144, 182, 171, 35, 41, 82, 49,
+            ],
+            [
+                120, 134, 13, 119, 96, 107, 165, 138, 166, 3, 87, 46, 212, 191, 49, 35, 233, 249,
+                119, 13, 30, 197, 91, 181, 111, 122, 70, 62, 64, 7, 19, 57,
+            ],
+            [
+                197, 242, 5, 70, 179, 103, 237, 249, 168, 109, 12, 187, 159, 234, 3, 249, 126, 244,
+                27, 123, 65, 51, 31, 143, 195, 252, 75, 90, 128, 161, 190, 13,
+            ],
+            [
+                1, 145, 126, 23, 167, 175, 23, 208, 223, 17, 16, 215, 112, 119, 248, 253, 78, 62,
+                103, 201, 55, 68, 4, 14, 18, 13, 226, 72, 133, 21, 136, 57,
+            ],
+            [
+                102, 51, 157, 97, 101, 169, 58, 142, 6, 234, 42, 4, 56, 22, 250, 79, 45, 147, 199,
+                6, 223, 85, 175, 138, 182, 55, 41, 225, 210, 29, 149, 28,
+            ],
+            [
+                234, 142, 112, 82, 108, 124, 79, 98, 210, 181, 22, 201, 130, 145, 118, 61, 3, 218,
+                38, 239, 114, 209, 223, 22, 69, 66, 86, 166, 77, 135, 203, 5,
+            ],
+            [
+                88, 214, 111, 228, 113, 113, 25, 118, 27, 202, 133, 239, 156, 220, 117, 168, 48,
+                45, 105, 191, 129, 232, 49, 139, 9, 207, 214, 59, 114, 8, 232, 27,
+            ],
+        ],
+        [
+            [
+                85, 30, 130, 183, 53, 175, 99, 69, 171, 20, 196, 231, 176, 196, 2, 38, 194, 31, 66,
+                214, 242, 162, 146, 114, 5, 154, 255, 155, 135, 17, 173, 3,
+            ],
+            [
+                80, 21, 114, 32, 128, 35, 130, 133, 156, 199, 103, 99, 152, 198, 131, 132, 179,
+                170, 205, 209, 226, 7, 144, 83, 4, 182, 123, 189, 46, 195, 168, 56,
+            ],
+            [
+                80, 70, 146, 87, 116, 49, 177, 196, 177, 14, 140, 140, 47, 90, 222, 47, 24, 224,
+                40, 148, 220, 151, 131, 84, 165, 132, 43, 130, 215, 173, 18, 13,
+            ],
+            [
+                103, 214, 235, 82, 105, 100, 110, 188, 107, 13, 39, 226, 158, 202, 230, 217, 113,
+                232, 71, 131, 151, 36, 193, 245, 71, 213, 8, 47, 1, 76, 169, 32,
+            ],
+            [
+                89, 60, 2, 157, 235, 166, 134, 110, 248, 255, 143, 140, 155, 254, 175, 193, 251,
+                61, 177, 162, 217, 138, 11, 33, 224, 208, 247, 223, 236, 195, 47, 53,
+            ],
+            [
+                123, 142, 20, 31, 37, 186, 12, 241, 211, 120, 194, 16, 130, 54, 183, 193, 146, 122,
+                19, 37, 202, 19, 93, 12, 40, 123, 176, 106, 247, 196, 223, 53,
+            ],
+            [
+                252, 164, 187, 22, 204, 100, 68, 21, 65, 235, 99, 173, 42, 179, 68, 20, 24, 201,
+                156, 104, 225, 156, 11, 65, 208, 145, 55, 230, 234, 9, 49, 9,
+            ],
+            [
+                83, 30, 112, 43, 160, 106, 190, 152, 182, 252, 84, 165, 31, 21, 167, 118, 82, 128,
+                169, 242, 170, 240, 154, 99, 106, 120, 184, 161, 36, 48, 233, 33,
+            ],
+        ],
+        [
+            [
+                32, 44, 198, 116, 199, 149, 41, 80, 115, 197, 134, 109, 193, 166, 195, 146, 153,
+                144, 170, 254, 190, 174, 66, 246, 4, 102, 116, 157, 89, 78, 108, 48,
+            ],
+            [
+                125, 89, 215, 77, 253, 253, 95, 134, 255, 241, 150, 67, 167, 65, 74, 191, 42, 227,
+                214, 64, 47, 83, 36, 117, 165, 97, 55, 61, 162, 254, 157, 15,
+            ],
+            [
+                71, 187, 242, 229, 177, 59, 19, 157, 187, 137, 197, 148, 111, 113, 12, 160, 228,
+                89, 152, 91, 75, 163, 177, 68, 119, 71, 142, 54, 12, 220, 216, 9,
+            ],
+            [
+                174, 42, 78, 29, 160, 35, 42, 61, 132, 77, 37, 76, 55, 93, 223, 243, 185, 250, 162,
+                92, 242, 111, 55, 142, 5, 27, 120, 31, 26, 201, 189, 37,
+            ],
+            [
+                192, 150, 116, 44, 182, 131, 180, 27, 215, 191, 111, 171, 104, 226, 179, 73, 223,
+                255, 111, 155, 219, 101, 73, 221, 250, 4, 10, 98, 18, 236, 35, 53,
+            ],
+            [
+                175, 187, 85, 87, 214, 58, 50, 145, 108, 225, 239, 233, 237, 167, 33, 168, 61, 107,
+                217, 239, 49, 236, 142, 15, 84, 249, 26, 205, 247, 147, 86, 58,
+            ],
+            [
+                133, 200, 72, 7, 36, 243, 78, 236, 31, 240, 158, 190, 142, 175, 45, 180, 93, 136,
+                237, 67, 146, 83, 10, 110, 124, 29, 73, 53, 22, 184, 141, 52,
+            ],
+            [
+                11, 172, 81, 115, 129, 19, 144, 126, 18, 72, 32, 32, 197, 220, 54, 79, 89, 140, 70,
+                155, 67, 37, 107, 32, 8, 182, 129, 48, 11, 67, 48, 16,
+            ],
+        ],
+        [
+            [
+                48, 74, 40, 98, 70, 202, 19, 82, 169, 172, 219, 135, 214, 146, 81, 147, 112, 73,
+                186, 137, 104, 147, 36, 48, 4, 73, 243, 245, 141, 65, 210, 57,
+            ],
+            [
+                123, 0, 177, 33, 91, 159, 151, 13, 20, 78, 166, 32, 63, 87, 84, 96, 167, 187, 149,
+                95, 79, 75, 250, 120, 71, 207, 92, 237, 130, 29, 48, 15,
+            ],
+            [
+                1, 57, 197, 239, 193, 186, 111, 145, 251, 73, 30, 174, 153, 6, 159, 192, 251, 43,
+                233, 59, 41, 154, 107, 216, 24, 40, 115, 146, 184, 187, 40, 44,
+            ],
+            [
+                223, 227, 55, 128, 232, 192, 34, 170, 147, 64, 90, 224, 16, 201, 165, 99, 54, 27,
+                65, 194, 210, 22, 184, 206, 121, 103, 175, 5, 197, 155, 67, 39,
+            ],
+            [
+                202, 178, 35, 41, 183, 73, 192, 208, 104, 171, 207, 232, 149, 110, 237, 195, 165,
+                231, 192, 37, 27, 217, 75, 208, 180, 214, 212, 0, 2, 201, 165, 23,
+            ],
+            [
+                214, 195, 168, 140, 43, 156, 211, 79, 211, 195, 7, 125, 178, 121, 41, 232, 54, 166,
+                237, 168, 124, 242, 152, 22, 188, 228, 211, 60, 35, 145, 85, 9,
+            ],
+            [
+                204, 108, 14, 29, 185, 92, 84, 181, 251, 0, 244, 216, 251, 132, 24, 217, 54, 138,
+                171, 80, 15, 183, 213, 124, 75, 76, 61, 102, 181, 169, 241, 25,
+            ],
+            [
+                191, 110, 59, 221, 209, 174, 185, 222, 96, 29, 150, 199, 100, 214, 172, 194, 194,
+                239, 145, 130, 131, 64, 85, 120, 176, 25, 82, 205, 171, 192, 246, 8,
+            ],
+        ],
+        [
+            [
+                100, 63, 39, 10, 179, 121, 221, 166, 28, 152, 163, 78, 71, 38, 254, 67, 80, 154,
+                19, 176, 151, 88, 184, 252, 161, 24, 161, 0, 138, 49, 189, 25,
+            ],
+            [
+                199, 160, 222, 187, 206, 87, 42, 59, 79, 239, 235, 105, 200, 112, 55, 106, 220,
+                217, 161, 237, 163, 3, 91, 91, 211, 47, 17, 8, 6, 159, 211, 25,
+            ],
+            [
+                234, 154, 118, 111, 169, 65, 8, 58, 77, 166, 30, 52, 23, 110, 213, 34, 8, 182, 132,
+                239, 101, 100, 103, 166, 207, 220, 27, 170, 99, 21, 146, 22,
+            ],
+            [
+                69, 101, 245, 155, 47, 33, 134, 84, 240, 141, 251, 10, 38, 42, 186, 241, 18, 127,
+                38, 189, 77, 17, 144, 19, 221, 182, 151, 97, 24, 103, 31, 1,
+            ],
+            [
+                207, 80, 10, 131, 100, 73, 94, 167, 154, 188, 81, 45, 223, 143, 232, 113, 193, 53,
+                80, 206, 43, 13, 3, 180, 223, 42, 154, 199, 40, 62, 212, 40,
+            ],
+            [
+                94, 36, 169, 133, 215, 85, 57, 43, 168, 18, 201, 22, 2, 220, 143, 81, 170, 50, 220,
+                158, 193, 22, 107, 81, 92, 124, 213, 212, 85, 149, 202, 1,
+            ],
+            [
+                191, 110, 212, 183, 105, 194, 167, 194, 31, 195, 215, 247, 131, 228, 191, 82, 210,
+                179, 250, 170, 78, 178, 206, 139, 84, 120, 54, 183, 55, 93, 155, 6,
+            ],
+            [
+                244, 230, 108, 119, 127, 191, 77, 117, 94, 235, 20, 110, 222, 230, 69, 8, 231, 151,
+                54, 250, 239, 54, 206, 220, 6, 14, 232, 159, 68, 138, 135, 58,
+            ],
+        ],
+        [
+            [
+                248, 106, 146, 173, 65, 122, 145, 245, 205, 52, 3, 166, 142, 171, 219, 44, 20, 245,
+                71, 213, 107, 190, 74, 221, 31, 139, 30, 75, 213, 243, 233, 43,
+            ],
+            [
+                17, 150, 13, 58, 87, 85, 77, 229, 15, 142, 208, 101, 169, 247, 40, 32, 69, 223, 44,
+                81, 163, 239, 167, 183, 192, 243, 55, 9, 78, 41, 135, 19,
+            ],
+            [
+                175, 146, 57, 15, 63, 155, 39, 5, 94, 47, 56, 61, 228, 108, 71, 118, 226, 221, 95,
+                229, 2, 51, 237, 248, 204, 2, 43, 9, 226, 219, 129, 28,
+            ],
+            [
+                24, 75, 171, 7, 99, 15, 138, 27, 28, 237, 130, 219, 108, 134, 222, 139, 67, 168,
+                24, 62, 185, 63, 184, 68, 165, 225, 65, 156, 247, 124, 177, 58,
+            ],
+            [
+                54, 65, 68, 208, 71, 105, 173, 146, 70, 213, 23, 187, 70, 220, 249, 61, 146, 38,
+                126, 47, 110, 140, 215, 251, 168, 151, 224, 4, 129, 49, 87, 11,
+            ],
+            [
+                243, 32, 249, 51, 210, 241, 88, 136, 54, 239, 59, 82, 115, 63, 5, 138, 118, 76, 73,
+                108, 3, 11, 204, 53, 11, 206, 83, 34, 237, 97, 157, 52,
+            ],
+            [
+                54, 91, 241, 39, 255, 106, 140, 206, 228, 11, 142, 11, 163, 72, 74, 245, 173, 172,
+                37, 214, 61, 106, 195, 186, 0, 41, 136, 41, 11, 52, 2, 4,
+            ],
+            [
+                166, 54, 115, 3, 200, 67, 163, 73, 107, 187, 164, 44, 250, 4, 41, 59, 55, 79, 154,
+                58, 133, 148, 74, 65, 115, 46, 118, 78, 233, 20, 220, 54,
+            ],
+        ],
+        [
+            [
+                145, 243, 151, 231, 13, 253, 45, 13, 40, 6, 163, 133, 238, 84, 124, 76, 151, 135,
+                29, 211, 82, 136, 167, 193, 182, 128, 163, 202, 110, 199, 120, 13,
+            ],
+            [
+                161, 60, 76, 18, 39, 231, 76, 90, 1, 100, 6, 9, 37, 124, 176, 194, 179, 223, 220,
+                133, 249, 86, 163, 54, 197, 54, 44, 228, 250, 117, 116, 11,
+            ],
+            [
+                100, 136, 199, 116, 94, 78, 243, 183, 103, 19, 189, 241, 188, 150, 118, 27, 97,
+                134, 194, 133, 96, 186, 37, 123, 206, 43, 160, 142, 34, 163, 223, 8,
+            ],
+            [
+                17, 95, 68, 146, 193, 4, 154, 5, 204, 104, 220, 144, 43, 66, 248, 117, 128, 9, 98,
+                140, 92, 134, 146, 56, 149, 137, 0, 249, 221, 38, 193, 36,
+            ],
+            [
+                80, 21, 75, 3, 88, 80, 6, 94, 21, 252, 188, 129, 29, 228, 147, 101, 136, 184, 58,
+                1, 240, 49, 199, 90, 239, 60, 78, 62, 138, 45, 188, 13,
+            ],
+            [
+                141, 98, 247, 170, 34, 194, 185, 186, 118, 6, 179, 24, 120, 40, 179, 18, 192, 8,
+                179, 74, 131, 206, 252, 103, 226, 159, 211, 102, 19, 247, 211, 21,
+            ],
+            [
+                217, 85, 24, 108, 201, 219, 11, 10, 204, 80, 153, 255, 76, 36, 167, 41, 102, 174,
+                33, 66, 248, 174, 15, 4, 38, 216, 87, 41, 58, 182, 11, 8,
+            ],
+            [
+                185, 192, 111, 135, 38, 37, 20, 243, 100, 203, 73, 183, 179, 11, 243, 148, 79, 239,
+                162, 143, 118, 48, 60, 52, 203, 167, 148, 64, 239, 110, 200, 26,
+            ],
+        ],
+        [
+            [
+                105, 54, 203, 141, 133, 125, 209, 243, 4, 116, 152, 249, 46, 254, 132, 91, 161, 79,
+                68, 68, 64, 151, 187, 37, 112, 6, 255, 199, 118, 59, 208, 4,
+            ],
+            [
+                249, 201, 75, 61, 254, 91, 37, 83, 151, 1, 73, 227, 140, 102, 143, 247, 229, 61,
+                142, 157, 242, 184, 71, 64, 182, 62, 85, 137, 65, 254, 83, 51,
+            ],
+            [
+                172, 228, 141, 228, 133, 186, 178, 160, 98, 72, 48, 60, 151, 149, 115, 190, 227,
+                66, 138, 154, 212, 140, 207, 186, 155, 17, 57, 87, 98, 42, 133, 54,
+            ],
+            [
+                121, 140, 224, 163, 105, 96, 198, 225, 239, 49, 75, 22, 140, 220, 132, 229, 14,
+                226, 13, 19, 109, 233, 170, 12, 225, 253, 127, 215, 180, 19, 64, 59,
+            ],
+            [
+                3, 153, 225, 216, 210, 115, 236, 108, 218, 42, 24, 152, 251, 148, 85, 116, 151,
+                133, 183, 60, 30, 216, 204, 16, 105, 230, 98, 190, 140, 77, 179, 53,
+            ],
+            [
+                244, 213, 107, 205, 112, 188, 110, 105, 242, 194, 245, 147, 247, 201, 81, 186, 227,
+                55, 66, 1, 33, 116, 244, 208, 113, 109, 87, 203, 239, 6, 166, 24,
+            ],
+            [
+                172, 234, 63, 229, 173, 67, 34, 49, 14, 223, 92, 86, 78, 212, 117, 176, 226, 235,
+                162, 75, 8, 175, 50, 231, 239, 187, 200, 138, 42, 42, 197, 61,
+            ],
+            [
+                110, 197, 186, 30, 101, 66, 117, 46, 201, 223, 175, 110, 1, 119, 210, 69, 115, 107,
+                224, 193, 115, 186, 161, 15, 45, 164, 151, 10, 244, 183, 46, 19,
+            ],
+        ],
+        [
+            [
+                170, 230, 194, 228, 125, 174, 161, 233, 97, 80, 63, 62, 197, 203, 238, 48, 15, 118,
+                237, 169, 202, 68, 142, 38, 98, 33, 225, 150, 5, 48, 17, 32,
+            ],
+            [
+                242, 210, 84, 63, 110, 235, 147, 219, 26, 54, 169, 118, 155, 219, 170, 154, 95,
+                127, 32, 139, 38, 219, 219, 190, 2, 233, 105, 84, 137, 136, 12, 34,
+            ],
+            [
+                87, 104, 123, 62, 147, 66, 111, 250, 120, 118, 186, 89, 86, 234, 95, 107, 149, 46,
+                38, 7, 87, 116, 27, 192, 21, 151, 91, 48, 166, 205, 224, 62,
+            ],
+            [
+                237, 166, 82, 193, 21, 172, 254, 101, 48, 43, 94, 94, 192, 42, 214, 222, 115, 224,
+                242, 125, 251, 151, 66, 84, 213, 60, 185, 37, 23, 217, 202, 16,
+            ],
+            [
+                60, 61, 40, 161, 13, 22, 243, 12, 187, 130, 109, 195, 25, 172, 145, 25, 55, 79,
+                103, 93, 89, 53, 154, 108, 48, 163, 182, 129, 105, 79, 235, 61,
+            ],
+            [
+                102, 188, 47, 12, 134, 151, 168, 208, 116, 94, 24, 169, 102, 0, 214, 153, 166, 114,
+                236, 148, 233, 46, 156, 88, 193, 142, 179, 9, 133, 115, 88, 30,
+            ],
+            [
+                172, 208, 12, 105, 157, 146, 100, 2, 74, 123, 31, 171, 234, 193, 11, 35, 192, 201,
+                148, 88, 73, 221, 220, 96, 204, 18, 169, 7, 12, 53, 27, 44,
+            ],
+            [
+                130, 88, 178, 251, 96, 228, 164, 29, 2, 45, 125, 153, 236, 193, 73, 98, 142, 236,
+                83, 93, 44, 42, 34, 132, 72, 169, 163, 60, 103, 147, 121, 6,
+            ],
+        ],
+        [
+            [
+                21, 133, 160, 43, 159, 8, 84, 55, 217, 74, 193, 215, 213, 198, 150, 151, 134, 92,
+                198, 54, 101, 214, 49, 162, 177, 229, 55, 232, 138, 7, 151, 13,
+            ],
+            [
+                194, 169, 122, 133, 162, 92, 107, 86, 235, 20, 95, 149, 15, 64, 57, 85, 138, 6,
+                181, 61, 130, 110, 123, 209, 208, 163, 31, 51, 207, 26, 3, 45,
+            ],
+            [
+                111, 8, 113, 94, 135, 251, 127, 40, 130, 217, 151, 50, 155, 201, 245, 228, 206, 3,
+                122, 236, 119, 74, 140, 159, 120, 239, 141, 141, 237, 194, 233, 38,
+            ],
+            [
+                106, 123, 254, 169, 187, 152, 127, 84, 36, 141, 185, 29, 42, 236, 252, 13, 167,
+                227, 66, 247, 178, 203, 113, 128, 240, 101, 76, 78, 220, 85, 105, 41,
+            ],
+            [
+                148, 99, 237, 141, 187, 231, 150, 116, 238, 117, 223, 182, 200, 65, 72, 56, 192,
+                127, 238, 249, 248, 174, 144, 198, 90, 117, 193, 38, 2, 104, 73, 49,
+            ],
+            [
+                230, 243, 68, 113, 26, 222, 37, 130, 139, 147, 47, 200, 72, 43, 9, 8, 38, 74, 80,
+                157, 134, 85, 48, 130, 41, 58, 124, 191, 250, 92, 115, 53,
+            ],
+            [
+                2, 37, 81, 230, 71, 215, 12, 204, 178, 143, 44, 7, 99, 185, 0, 171, 37, 1, 233,
+                130, 136, 239, 133, 118, 104, 214, 76, 95, 94, 39, 65, 5,
+            ],
+            [
+                49, 6, 234, 156, 160, 186, 105, 29, 97, 235, 243, 205, 97, 191, 115, 122, 134, 252,
+                242, 73, 179, 175, 232, 230, 172, 11, 171, 48, 228, 48, 145, 4,
+            ],
+        ],
+        [
+            [
+                201, 53, 166, 129, 64, 223, 185, 72, 24, 236, 57, 72, 134, 87, 1, 74, 120, 248,
+                147, 253, 17, 176, 167, 108, 117, 7, 226, 13, 69, 81, 177, 37,
+            ],
+            [
+                192, 204, 210, 195, 248, 215, 25, 229, 231, 53, 1, 137, 1, 218, 96, 247, 108, 250,
+                241, 112, 184, 175, 2, 142, 226, 4, 147, 124, 17, 44, 248, 6,
+            ],
+            [
+                234, 85, 224, 93, 247, 221, 158, 194, 53, 228, 227, 6, 218, 1, 78, 249, 232, 202,
+                138, 55, 84, 240, 110, 42, 55, 153, 109, 95, 135, 105, 77, 16,
+            ],
+            [
+                17, 76, 223, 1, 28, 136, 229, 192, 167, 235, 166, 253, 43, 149, 245, 130, 22, 55,
+                33, 223, 150, 123, 129, 99, 133, 19, 212, 57, 111, 40, 133, 57,
+            ],
+            [
+                104, 37, 249, 171, 75, 93, 64, 33, 42, 40, 223, 64, 17, 35, 28, 55, 122, 202, 54,
+                233, 29, 225, 98, 195, 215, 35, 29, 144, 206, 229, 162, 24,
+            ],
+            [
+                247, 169, 197, 183, 138, 216, 23, 7, 136, 71, 57, 223, 61, 246, 2, 241, 230, 233,
+                165, 200, 113, 60, 179, 38, 217, 133, 27, 91, 27, 128, 52, 59,
+            ],
+            [
+                230, 252, 110, 90, 226, 162, 252, 86, 167, 3, 83, 194, 205, 242, 18, 185, 56, 222,
+                75, 26, 168, 235, 230, 252, 172, 171, 143, 212, 217, 21, 161, 10,
+            ],
+            [
+                161, 197, 227, 195, 2, 190, 85, 60, 68, 211, 122, 82, 55, 17, 234, 245, 210, 14,
+                153, 34, 178, 7, 174, 179, 172, 127, 71, 194, 143, 12, 194, 28,
+            ],
+        ],
+        [
+            [
+                221, 112, 38, 157, 156, 180, 168, 187, 39, 10, 187, 165, 252, 226, 63, 233, 241,
+                134, 198, 118, 90, 243, 93, 109, 242, 193, 80, 210, 2, 194, 104, 12,
+            ],
+            [
+                176, 154, 63, 37, 61, 170, 152, 83, 101, 65, 42, 119, 0, 111, 109, 252, 243, 194,
+                62, 245, 7, 118, 237, 201, 29, 20, 250, 242, 39, 48, 119, 28,
+            ],
+            [
+                33, 86, 53, 41, 75, 15, 121, 77, 33, 31, 135, 150, 22, 84, 11, 74, 100, 25, 237,
+                44, 118, 28, 160, 23, 136, 175, 75, 33, 106, 198, 50, 52,
+            ],
+            [
+                166, 63, 39, 172, 145, 156, 210, 143, 80, 180, 52, 167, 115, 107, 125, 122, 58, 68,
+                184, 176, 241, 33, 151, 31, 44, 178, 8, 210, 152, 214, 61, 15,
+            ],
+            [
+                24, 25, 80, 5, 23, 212, 237, 104, 115, 170, 202, 24, 78, 109, 16, 204, 144, 68,
+                249, 161, 242, 173, 46, 183, 49, 184, 128, 83, 139, 239, 24, 5,
+            ],
+            [
+                31, 130, 70, 86, 126, 114, 43, 214, 88, 61, 62, 196, 34, 119, 98, 91, 231, 194, 5,
+                110, 143, 18, 84, 67, 192, 206, 220, 203, 211, 131, 217, 12,
+            ],
+            [
+                246, 37, 147, 191, 62, 17, 119, 239, 155, 114, 90, 31, 18, 194, 124, 241, 88, 173,
+                136, 58, 103, 121, 69, 87, 114, 34, 236, 55, 175, 158, 212, 25,
+            ],
+            [
+                242, 53, 163, 148, 122, 168, 225, 141, 65, 20, 34, 252, 131, 197, 145, 176, 148,
+                128, 147, 45, 202, 251, 162, 38, 97, 99, 119, 243, 38, 26, 122, 6,
+            ],
+        ],
+        [
+            [
+                152, 180, 125, 247, 222, 130, 91, 250, 37, 65, 146, 255, 180, 20, 193, 69, 200,
+                161, 18, 55, 167, 15, 4, 9, 218, 134, 25, 200, 78, 114, 13, 14,
+            ],
+            [
+                30, 206, 60, 78, 18, 126, 74, 143, 232, 242, 240, 23, 33, 223, 80, 22, 48, 238,
+                137, 193, 104, 20, 134, 115, 121, 160, 30, 215, 124, 200, 58, 32,
+            ],
+            [
+                201, 45, 104, 210, 119, 133, 94, 230, 250, 14, 53, 182, 201, 222, 39, 128, 139,
+                198, 147, 139, 153, 100, 137, 213, 73, 106, 226, 198, 251, 14, 158, 36,
+            ],
+            [
+                42, 3, 148, 111, 42, 45, 177, 157, 61, 232, 9, 200, 112, 40, 3, 76, 169, 30, 99,
+                193, 255, 53, 162, 67, 131, 108, 72, 78, 87, 130, 65, 18,
+            ],
+            [
+                107, 155, 90, 124, 226, 116, 53, 255, 175, 42, 164, 222, 144, 165, 63, 244, 246,
+                169, 219, 44, 55, 75, 70, 183, 154, 141, 134, 74, 148, 103, 196, 38,
+            ],
+            [
+                66, 100, 172, 71, 27, 89, 222, 185, 122, 189, 12, 203, 178, 19, 238, 51, 215, 18,
+                170, 191, 212, 133, 86, 238, 48, 81, 144, 231, 34, 72, 9, 28,
+            ],
+            [
+                204, 68, 70, 192, 242, 223, 104, 36, 120, 228, 3, 90, 38, 89, 108, 242, 89, 207,
+                71, 43, 15, 46, 211, 148, 225, 79, 203, 210, 89, 64, 114, 30,
+            ],
+            [
+                129, 155, 134, 68, 221, 181, 167, 114, 41, 180, 187, 21, 110, 241, 230, 163, 218,
+                164, 175, 0, 42, 1, 142, 72, 92, 186, 218, 143, 49, 199, 118, 39,
+            ],
+        ],
+        [
+            [
+                43, 159, 135, 36, 220, 154, 62, 159, 236, 246, 46, 161, 232, 242, 210, 181, 192, 0,
+                59, 174, 50, 28, 138, 233, 241, 45, 180, 133, 211, 52, 62, 29,
+            ],
+            [
+                1, 254, 229, 189, 112, 187, 201, 55, 210, 168, 220, 91, 132, 175, 127, 232, 93,
+                113, 47, 245, 166, 155, 146, 71, 245, 251, 77, 182, 141, 66, 8, 24,
+            ],
+            [
+                20, 71, 45, 85, 18, 170, 37, 69, 218, 25, 4, 85, 83, 253, 122, 74, 136, 37, 156,
+                129, 219, 101, 100, 185, 197, 113, 106, 50, 172, 125, 62, 58,
+            ],
+            [
+                16, 141, 41, 60, 214, 219, 14, 106, 185, 140, 16, 10, 1, 107, 109, 42, 124, 251,
+                245, 3, 151, 40, 96, 77, 149, 158, 86, 178, 186, 29, 37, 7,
+            ],
+            [
+                82, 253, 216, 53, 223, 56, 18, 85, 9, 172, 175, 199, 107, 206, 152, 18, 191, 109,
+                184, 88, 152, 33, 209, 112, 61, 27, 187, 142, 242, 215, 45, 48,
+            ],
+            [
+                118, 40, 59, 239, 77, 126, 242, 62, 134, 36, 164, 251, 115, 98, 139, 120, 213, 141,
+                147, 96, 160, 215, 91, 76, 120, 64, 154, 191, 249, 204, 43, 2,
+            ],
+            [
+                236, 36, 230, 55, 132, 216, 117, 185, 107, 53, 212, 221, 219, 77, 145, 0, 98, 151,
+                167, 61, 212, 46, 121, 221, 148, 159, 32, 177, 184, 179, 89, 27,
+            ],
+            [
+                95, 85, 249, 2, 135, 99, 187, 110, 182, 254, 97, 96, 206, 90, 148, 97, 172, 27,
+                164, 89, 138, 24, 241, 20, 40, 36, 242, 49, 193, 59, 192, 61,
+            ],
+        ],
+        [
+            [
+                77, 213, 90, 44, 113, 35, 253, 118, 105, 20, 248, 24, 210, 107, 55, 26, 25, 13,
+                209, 113, 2, 49, 137, 154, 124, 147, 29, 44, 154, 187, 153, 61,
+            ],
+            [
+                183, 57, 132, 218, 42, 62, 59, 19, 66, 148, 255, 229, 135, 225, 192, 46, 244, 101,
+                204, 222, 128, 178, 25, 6, 135, 29, 71, 142, 107, 47, 173, 59,
+            ],
+            [
+                103, 28, 75, 233, 118, 122, 77, 225, 225, 70, 101, 98, 46, 168, 84, 23, 126, 14,
+                179, 135, 182, 133, 53, 98, 194, 55, 91, 2, 16, 66, 194, 8,
+            ],
+            [
+                51, 90, 81, 53, 170, 234, 145, 100, 189, 239, 104, 184, 62, 95, 28, 173, 123, 3,
+                22, 164, 23, 47, 10, 76, 231, 18, 57, 232, 85, 47, 196, 18,
+            ],
+            [
+                55, 200, 102, 9, 103, 223, 142, 83, 61, 50, 4, 48, 179, 204, 50, 41, 18, 114, 141,
+                214, 95, 224, 227, 19, 113, 211, 196, 198, 93, 81, 85, 45,
+            ],
+            [
+                110, 208, 200, 6, 180, 252, 49, 63, 19, 10, 155, 133, 75, 80, 54, 37, 226, 134, 67,
+                56, 167, 119, 198, 108, 29, 58, 65, 72, 63, 15, 234, 7,
+            ],
+            [
+                110, 174, 110, 121, 206, 251, 92, 76, 229, 21, 23, 145, 99, 231, 224, 231, 221, 85,
+                242, 84, 99, 141, 50, 174, 71, 1, 205, 16, 191, 209, 10, 62,
+            ],
+            [
+                239, 54, 156, 240, 62, 249, 112, 165, 130, 46, 128, 14, 121, 41, 22, 66, 149, 192,
+                181, 43, 26, 0, 20, 16, 254, 158, 213, 128, 103, 239, 92, 18,
+            ],
+        ],
+        [
+            [
+                224, 69, 11, 105, 225, 164, 161, 37, 248, 72, 54, 204, 127, 147, 8, 203, 171, 26,
+                61, 1, 187, 18, 192, 201, 17, 188, 48, 27, 8, 139, 3, 7,
+            ],
+            [
+                135, 19, 97, 160, 119, 211, 82, 185, 202, 50, 122, 188, 33, 110, 199, 91, 77, 151,
+                19, 203, 218, 192, 41, 116, 56, 172, 154, 194, 107, 169, 124, 10,
+            ],
+            [
+                54, 239, 111, 201, 64, 86, 231, 52, 25, 69, 74, 124, 116, 168, 78, 167, 66, 69, 21,
+                71, 174, 135, 208, 198, 165, 254, 202, 49, 138, 18, 13, 21,
+            ],
+            [
+                167, 241, 200, 133, 130, 105, 0, 121, 161, 106, 134, 46, 217, 118, 247, 239, 29,
+                174, 230, 43, 99, 218, 81, 41, 99, 151, 33, 215, 89, 57, 33, 25,
+            ],
+            [
+                69, 249, 167, 135, 157, 43, 43, 45, 133, 166, 138, 10, 109, 73, 36, 248, 114, 95,
+                253, 217, 54, 2, 211, 192, 42, 107, 167, 11, 87, 8, 120, 41,
+            ],
+            [
+                200, 195, 103, 16, 198, 145, 10, 116, 246, 49, 64, 219, 59, 196, 240, 136, 37, 132,
+                107, 233, 174, 244, 175, 136, 155, 83, 164, 53, 56, 1, 190, 22,
+            ],
+            [
+                196, 221, 154, 252, 230, 179, 23, 71, 80, 144, 60, 144, 255, 165, 121, 212, 255,
+                129, 161, 69, 119, 135, 1, 84, 222, 178, 168, 168, 226, 209, 123, 43,
+            ],
+            [
+                136, 112, 56, 2, 75, 31, 228, 56, 24, 108, 94, 7, 49, 249, 108, 87, 120, 88, 213,
+                231, 238, 244, 41, 110, 168, 115, 214, 242, 9, 215, 199, 62,
+            ],
+        ],
+        [
+            [
+                119, 23, 78, 50, 236, 221, 202, 19, 90, 194, 162, 184, 147, 125, 119, 78, 46, 167,
+                47, 52, 247, 243, 198, 158, 128, 46, 103, 167, 1, 206, 20, 3,
+            ],
+            [
+                246, 243, 130, 86, 175, 70, 107, 71, 8, 29, 222, 211, 41, 43, 214, 221, 90, 63,
+                171, 131, 85, 253, 41, 161, 251, 169, 131, 151, 97, 255, 233, 36,
+            ],
+            [
+                175, 113, 246, 90, 23, 104, 60, 75, 41, 137, 182, 247, 2, 174, 121, 139, 166, 198,
+                127, 91, 251, 31, 71, 68, 118, 2, 192, 35, 174, 86, 198, 52,
+            ],
+            [
+                215, 75, 20, 169, 53, 128, 78, 130, 154, 54, 71, 47, 36, 122, 89, 43, 80, 111, 58,
+                158, 19, 79, 158, 159, 214, 251, 155, 59, 176, 144, 80, 36,
+            ],
+            [
+                183, 168, 31, 20, 147, 127, 197, 117, 25, 103, 16, 109, 94, 232, 235, 30, 235, 63,
+                127, 224, 215, 145, 180, 114, 5, 152, 26, 176, 49, 211, 68, 40,
+            ],
+            [
+                74, 34, 167, 131, 29, 30, 83, 66, 205, 93, 46, 216, 224, 172, 100, 170, 141, 89,
+                26, 152, 139, 34, 214, 226, 198, 191, 153, 14, 157, 249, 203, 39,
+            ],
+            [
+                218, 247, 181, 2, 41, 46, 12, 255, 96, 110, 169, 107, 88, 64, 243, 233, 196, 141,
+                139, 253, 208, 123, 176, 4, 93, 14, 133, 220, 29, 175, 134, 17,
+            ],
+            [
+                119, 242, 231, 60, 121, 197, 181, 155, 62, 229, 68, 17, 42, 200, 226, 55, 128, 241,
+                14, 245, 192, 100, 29, 91, 249, 31, 63, 57, 121, 71, 21, 7,
+            ],
+        ],
+        [
+            [
+                58, 85, 111, 160, 109, 89, 109, 35, 61, 104, 32, 104, 135, 17, 8, 72, 71, 235, 147,
+                97, 45, 103, 22, 99, 78, 137, 112, 205, 31, 196, 140, 30,
+            ],
+            [
+                17, 87, 135, 133, 127, 168, 56, 90, 18, 43, 79, 119, 244, 77, 71, 200, 149, 57, 64,
+                97, 249, 112, 164, 188, 25, 238, 253, 44, 117, 63, 248, 41,
+            ],
+            [
+                28, 251, 63, 213, 4, 224, 144, 236, 73, 101, 1, 214, 105, 88, 235, 165, 247, 66,
+                125, 93, 185, 38, 121, 181, 87, 242, 249, 254, 69, 82, 92, 61,
+            ],
+            [
+                32, 226, 8, 77, 153, 231, 146, 217, 237, 223, 193, 154, 91, 238, 47, 28, 18, 172,
+                232, 115, 224, 186, 224, 229, 188, 171, 138, 44, 140, 88, 71, 41,
+            ],
+            [
+                156, 118, 1, 14, 145, 225, 87, 124, 112, 212, 112, 173, 243, 208, 240, 53, 20, 80,
+                116, 109, 206, 54, 169, 106, 163, 101, 171, 115, 11, 48, 146, 50,
+            ],
+            [
+                1, 218, 39, 48, 233, 120, 141, 106, 248, 228, 231, 10, 222, 180, 235, 190, 23, 88,
+                123, 128, 29, 120, 191, 88, 86, 50, 212, 3, 143, 32, 32, 16,
+            ],
+            [
+                27, 16, 218, 116, 201, 146, 165, 227, 125, 46, 111, 100, 69, 76, 40, 154, 252, 102,
+                31, 11, 230, 203, 132, 61, 12, 157, 49, 31, 228, 27, 156, 41,
+            ],
+            [
+                14, 143, 133, 66, 245, 71, 191, 199, 23, 34, 238, 129, 253, 17, 82, 247, 70, 177,
+                225, 117, 8, 125, 0, 202, 69, 13, 198, 8, 126, 186, 87, 19,
+            ],
+        ],
+        [
+            [
+                166, 16, 222, 83, 65, 123, 79, 198, 155, 209, 190, 71, 143, 197, 50, 67, 209, 36,
+                22, 155, 119, 110, 183, 108, 192, 126, 22, 226, 229, 78, 95, 36,
+            ],
+            [
+                64, 107, 128, 106, 154, 255, 234, 200, 146, 205, 57, 178, 200, 213, 245, 115, 110,
+                78, 184, 135, 126, 63, 224, 57, 42, 80, 91, 58, 125, 6, 160, 37,
+            ],
+            [
+                129, 44, 161, 173, 30, 134, 217, 33, 55, 175, 166, 9, 35, 80, 154, 158, 223, 63,
+                138, 108, 26, 33, 166, 34, 30, 140, 34, 170, 36, 81, 84, 3,
+            ],
+            [
+                144, 157, 194, 176, 176, 103, 62, 111, 233, 215, 96, 193, 95, 234, 207, 252, 170,
+                13, 149, 96, 118, 70, 174, 172, 84, 213, 80, 159, 228, 129, 175, 36,
+            ],
+            [
+                47, 35, 59, 103, 83, 196, 220, 176, 178, 240, 250, 60, 53, 31, 167, 146, 149, 210,
+                30, 155, 64, 55, 85, 156, 28, 152, 132, 58, 252, 134, 184, 8,
+            ],
+            [
+                46, 162, 229, 11, 223, 49, 208, 119, 72, 76, 121, 167, 254, 222, 236, 205, 48, 65,
+                105, 224, 81, 221, 177, 250, 45, 207, 82, 252, 217, 193, 0, 6,
+            ],
+            [
+                82, 80, 43, 97, 222, 175, 179, 181, 123, 32, 118, 24, 142, 87, 125, 117, 12, 131,
+                25, 20, 43, 192, 242, 119, 76, 153, 126, 245, 219, 199, 203, 56,
+            ],
+            [
+                99, 65, 184, 27, 172, 247, 80, 201, 149, 159, 207, 254, 66, 106, 249, 19, 182, 223,
+                72, 180, 196, 80, 63, 169, 50, 80, 161, 168, 66, 110, 178, 27,
+            ],
+        ],
+        [
+            [
+                146, 46, 243, 128, 166, 131, 61, 70, 15, 180, 83, 47, 79, 233, 157, 219, 88, 33,
+                252, 6, 2, 94, 72, 154, 43, 50, 50, 15, 139, 77, 103, 61,
+            ],
+            [
+                254, 248, 46, 234, 199, 161, 60, 7, 115, 41, 189, 118, 57, 134, 195, 120, 250, 203,
+                120, 194, 20, 121, 162, 44, 109, 0, 245, 183, 207, 86, 158, 27,
+            ],
+            [
+                219, 254, 10, 45, 69, 198, 72, 104, 86, 140, 134, 63, 29, 237, 136, 154, 200, 93,
+                224, 24, 20, 74, 199, 215, 225, 22, 184, 29, 101, 132, 124, 31,
+            ],
+            [
+                199, 110, 112, 25, 238, 48, 234, 172, 193, 175, 247, 132, 216, 239, 43, 239, 117,
+                158, 105, 114, 21, 153, 97, 70, 105, 206, 77, 121, 124, 206, 40, 44,
+            ],
+            [
+                194, 34, 240, 156, 176, 165, 129, 16, 5, 180, 53, 35, 168, 18, 197, 11, 85, 249,
+                225, 58, 91, 230, 191, 81, 153, 251, 84, 4, 247, 121, 215, 9,
+            ],
+            [
+                185, 92, 233, 158, 53, 129, 156, 10, 180, 195, 135, 33, 60, 212, 58, 224, 140, 173,
+                199, 76, 168, 7, 217, 43, 15, 130, 121, 245, 228, 20, 242, 6,
+            ],
+            [
+                152, 102, 206, 137, 73, 231, 65, 223, 165, 199, 19, 111, 185, 227, 78, 185, 166,
+                206, 165, 44, 253, 94, 136, 226, 234, 144, 25, 110, 157, 117, 55, 12,
+            ],
+            [
+                218, 13, 172, 11, 252, 219, 99, 103, 89, 21, 71, 213, 250, 93, 132, 174, 7, 162,
+                62, 22, 161, 249, 122, 209, 212, 193, 159, 210, 201, 141, 75, 57,
+            ],
+        ],
+        [
+            [
+                30, 222, 116, 55, 181, 37, 207, 244, 201, 52, 45, 241, 123, 50, 170, 143, 217, 40,
+                56, 123, 140, 24, 112, 232, 56, 209, 149, 115, 209, 39, 31, 27,
+            ],
+            [
+                24, 116, 217, 216, 135, 114, 12, 100, 21, 172, 84, 172, 182, 25, 249, 237, 228, 26,
+                213, 200, 203, 45, 161, 218, 25, 149, 49, 122, 195, 90, 219, 47,
+            ],
+            [
+                35, 151, 66, 154, 52, 75, 20, 226, 29, 151, 99, 23, 84, 78, 77, 187, 149, 184, 28,
+                24, 84, 100, 194, 163, 11, 50, 235, 235, 92, 237, 254, 24,
+            ],
+            [
+                139, 204, 123, 203, 227, 158, 6, 193, 74, 140, 99, 251, 233, 12, 36, 17, 194, 115,
+                245, 68, 4, 152, 97, 192, 86, 45, 193, 200, 12, 4, 153, 63,
+            ],
+            [
+                195, 49, 121, 71, 220, 10, 231, 199, 240, 249, 229, 117, 152, 58, 2, 105, 140, 37,
+                1, 59, 218, 148, 62, 102, 195, 69, 157, 74, 61, 156, 98, 13,
+            ],
+            [
+                196, 208, 57, 79, 90, 237, 180, 92, 135, 186, 61, 8, 220, 238, 10, 105, 179, 222,
+                157, 77, 246, 184, 187, 71, 38, 137, 150, 36, 110, 207, 135, 63,
+            ],
+            [
+                255, 23, 159, 160, 32, 72, 137, 55, 99, 234, 88, 118, 241, 5, 147, 137, 232, 78,
+                192, 211, 6, 11, 208, 0, 113, 235, 17, 3, 178, 136, 23, 57,
+            ],
+            [
+                243, 181, 117, 95, 167, 95, 201, 193, 191, 222, 249, 166, 208, 176, 53, 82, 54, 51,
+                56, 130, 37, 107, 83, 179, 186, 27, 134, 48, 149, 220, 10, 50,
+            ],
+        ],
+        [
+            [
+                247, 31, 211, 167, 35, 221, 49, 229, 23, 89, 24, 208, 88, 56, 236, 212, 176, 173,
+                215, 21, 193, 25, 71, 132, 217, 12, 50, 77, 111, 220, 253, 21,
+            ],
+            [
+                244, 168, 74, 83, 134, 160, 247, 223, 217, 196, 196, 31, 19, 199, 179, 109, 74,
+                186, 87, 175, 67, 62, 17, 56, 197, 40, 4, 164, 0, 122, 101, 28,
+            ],
+            [
+                58, 192, 236, 26, 101, 51, 96, 107, 4, 226, 75, 151, 180, 36, 148, 58, 237, 103,
+                231, 36, 81, 183, 198, 145, 187, 184, 184, 137, 155, 46, 63, 45,
+            ],
+            [
+                173, 235, 144, 150, 224, 231, 130, 202, 181, 189, 209, 78, 177, 198, 90, 64, 65,
+                52, 193, 211, 83, 9, 143, 25, 176, 119, 133, 222, 92, 194, 27, 45,
+            ],
+            [
+                41, 63, 92, 113, 253, 125, 234, 154, 141, 218, 91, 246, 215, 25, 109, 181, 238,
+                194, 116, 54, 151, 219, 108, 132, 123, 17, 152, 139, 255, 5, 109, 52,
+            ],
+            [
+                154, 229, 28, 170, 12, 34, 61, 46, 204, 81, 113, 129, 201, 237, 34, 193, 213, 115,
+                193, 138, 36, 180, 154, 231, 126, 200, 221, 77, 87, 134, 176, 10,
+            ],
+            [
+                67, 169, 197, 197, 1, 209, 95, 13, 183, 228, 77, 43, 211, 147, 183, 13, 203, 225,
+                136, 6, 217, 69, 213, 71, 23, 234, 37, 51, 212, 188, 150, 44,
+            ],
+            [
+                216, 232, 239, 132, 218, 71, 241, 29, 112, 91, 158, 165, 42, 58, 156, 37, 33, 217,
+                80, 243, 140, 115, 190, 38, 129, 112, 26, 247, 24, 158, 159, 20,
+            ],
+        ],
+        [
+            [
+                136, 103, 27, 128, 92, 6, 106, 198, 50, 92, 204, 97, 49, 9, 157, 166, 9, 122, 122,
+                56, 217, 67, 21, 106, 174, 123, 31, 253, 64, 120, 129, 34,
+            ],
+            [
+                59, 11, 105, 252, 75, 102, 57, 251, 175, 171, 148, 96, 168, 54, 57, 67, 121, 83,
+                204, 156, 2, 76, 168, 192, 75, 240, 43, 16, 77, 47, 149, 50,
+            ],
+            [
+                41, 28, 60, 181, 244, 157, 207, 135, 224, 62, 129, 4, 245, 47, 208, 41, 195, 16,
+                198, 126, 170, 154, 242, 24, 180, 55, 113, 209, 120, 2, 18, 51,
+            ],
+            [
+                221, 6, 201, 126, 90, 199, 36, 209, 35, 212, 185, 163, 62, 68, 70, 255, 41, 8, 40,
+                151, 139, 135, 137, 110, 162, 154, 150, 49, 210, 221, 39, 12,
+            ],
+            [
+                213, 250, 20, 219, 33, 231, 214, 98, 129, 68, 72, 111, 154, 126, 237, 90, 133, 168,
+                10, 164, 161, 31, 32, 22, 159, 97, 243, 235, 51, 177, 189, 15,
+            ],
+            [
+                132, 132, 16, 165, 21, 29, 158, 83, 13, 135, 246, 151, 88, 154, 47, 139, 190, 171,
+                89, 97, 175, 42, 253, 129, 171, 210, 147, 61, 241, 36, 10, 33,
+            ],
+            [
+                198, 203, 213, 21, 250, 63, 3, 214, 113, 40, 218, 129, 253, 217, 44, 198, 139, 222,
+                255, 84, 42, 76, 63, 191, 38, 217, 32, 146, 241, 128, 249, 40,
+            ],
+            [
+                106, 63, 69, 4, 58, 220, 119, 45, 20, 76, 85, 219, 72, 181, 157, 22, 63, 110, 236,
+                42, 79, 22, 96, 205, 95, 65, 219, 139, 31, 27, 196, 62,
+            ],
+        ],
+        [
+            [
+                101, 189, 216, 128, 162, 155, 43, 72, 157, 202, 115, 19, 118, 70, 127, 26, 209, 84,
+                52, 133, 19, 110, 6, 118, 59, 91, 226, 22, 89, 31, 122, 53,
+            ],
+            [
+                230, 190, 109, 4, 127, 180, 52, 177, 17, 111, 69, 98, 28, 238, 26, 218, 241, 120,
+                61, 180, 243, 205, 219, 33, 58, 166, 155, 197, 240, 80, 39, 9,
+            ],
+            [
+                5, 62, 43, 110, 170, 78, 99, 193, 131, 22, 153, 66, 152, 72, 140, 68, 218, 204, 48,
+                72, 100, 20, 13, 38, 64, 110, 226, 101, 165, 83, 88, 25,
+            ],
+            [
+                107, 126, 223, 68, 142, 127, 162, 59, 52, 136, 196, 208, 208, 1, 236, 87, 36, 57,
+                112, 210, 210, 15, 126, 61, 219, 229, 149, 46, 252, 57, 8, 10,
+            ],
+            [
+                110, 11, 238, 126, 69, 245, 46, 200, 234, 174, 242, 44, 164, 227, 43, 9, 27, 37,
+                30, 70, 120, 234, 41, 75, 42, 30, 91, 225, 54, 122, 49, 20,
+            ],
+            [
+                82, 152, 62, 40, 141, 163, 48, 202, 90, 178, 244, 12, 165, 185, 136, 60, 18, 88,
+                203, 108, 26, 136, 86, 172, 31, 234, 114, 160, 68, 206, 52, 48,
+            ],
+            [
+                165, 129, 204, 98, 44, 38, 167, 208, 200, 26, 10, 197, 100, 191, 251, 232, 31, 172,
+                22, 83, 237, 174, 210, 107, 174, 190, 79, 141, 136, 118, 89, 16,
+            ],
+            [
+                24, 82, 49, 53, 209, 140, 10, 10, 222, 93, 32, 17, 39, 25, 184, 219, 153, 96, 48,
+                162, 12, 152, 21, 157, 248, 250, 124, 121, 109, 42, 242, 14,
+            ],
+        ],
+        [
+            [
+                231, 92, 52, 16, 232, 240, 219, 76, 211, 10, 102, 33, 130, 130, 216, 199, 237, 63,
+                234, 62, 153, 196, 215, 222, 94, 174, 90, 71, 32, 9, 80, 62,
+            ],
+            [
+                141, 143, 157, 247, 195, 78, 37, 159, 197, 245, 163, 97, 121, 235, 20, 2, 243, 99,
+                59, 170, 116, 115, 123, 73, 224, 248, 111, 252, 141, 207, 158, 54,
+            ],
+            [
+                233, 81, 182, 129, 17, 166, 182, 184, 181, 62, 227, 20, 14, 27, 27, 5, 36, 189, 43,
+                99, 2, 45, 50, 42, 59, 67, 65, 129, 123, 199, 13, 23,
+            ],
+            [
+                25, 58, 129, 163, 5, 43, 252, 241, 58, 65, 3, 209, 184, 29, 229, 171, 163, 213,
+                143, 83, 22, 97, 50, 198, 35, 50, 70, 235, 84, 129, 85, 1,
+            ],
+            [
+                184, 102, 78, 214, 135, 104, 57, 18, 242, 104, 8, 190, 67, 97, 178, 222, 101, 234,
+                107, 78, 20, 100, 103, 32, 189, 217, 223, 137, 104, 236, 71, 14,
+            ],
+            [
+                35, 255, 183, 76, 153, 196, 170, 205, 136, 136, 109, 50, 149, 166, 143, 122, 183,
+                145, 231, 150, 229, 76, 20, 207, 190, 249, 143, 251, 161, 159, 190, 3,
+            ],
+            [
+                99, 15, 178, 209, 96, 34, 88, 216, 169, 94, 198, 196, 166, 77, 18, 54, 217, 184,
+                69, 242, 188, 144, 206, 73, 224, 193, 94, 102, 64, 46, 112, 31,
+            ],
+            [
+                212, 71, 192, 104, 42, 86, 104, 41, 193, 209, 220, 120, 147, 87, 216, 75, 252, 5,
+                208, 220, 243, 99, 0, 123, 68, 101, 237, 136, 150, 54, 38, 32,
+            ],
+        ],
+        [
+            [
+                188, 151, 109, 64, 109, 102, 86, 255, 246, 210, 199, 166, 164, 203, 99, 224, 226,
+                205, 69, 111, 121, 174, 53, 103, 114, 233, 174, 138, 104, 160, 30, 63,
+            ],
+            [
+                104, 182, 4, 36, 214, 110, 248, 89, 147, 51, 137, 45, 66, 27, 39, 12, 114, 229,
+                189, 124, 116, 216, 169, 65, 54, 203, 216, 86, 125, 103, 62, 36,
+            ],
+            [
+                186, 87, 202, 219, 117, 31, 69, 158, 231, 237, 192, 220, 236, 1, 118, 87, 16, 1,
+                58, 126, 54, 165, 55, 137, 249, 166, 40, 71, 56, 248, 219, 17,
+            ],
+            [
+                67, 88, 195, 211, 104, 235, 168, 124, 148, 140, 222, 152, 139, 144, 131, 17, 55,
+                20, 249, 143, 55, 227, 176, 20, 82, 71, 117, 152, 6, 90, 20, 30,
+            ],
+            [
+                242, 236, 31, 21, 175, 149, 30, 245, 111, 75, 35, 241, 97, 127, 212, 188, 202, 138,
+                236, 212, 132, 224, 31, 189, 249, 177, 210, 235, 50, 146, 249, 6,
+            ],
+            [
+                145, 211, 60, 116, 150, 103, 209, 144, 67, 225, 76, 147, 43, 177, 201, 19, 197, 17,
+                210, 102, 189, 203, 189, 66, 86, 45, 235, 31, 246, 151, 26, 7,
+            ],
+            [
+                129, 231, 176, 15, 135, 200, 177, 185, 23, 43, 117, 80, 250, 179, 229, 182, 166,
+                229, 129, 95, 90, 244, 117, 148, 195, 184, 98, 72, 162, 132, 200, 18,
+            ],
+            [
+                11, 221, 13, 159, 217, 97, 209, 27, 216, 65, 179, 58, 52, 126, 242, 203, 100, 165,
+                41, 185, 69, 20, 232, 164, 178, 245, 104, 168, 228, 215, 52, 59,
+            ],
+        ],
+        [
+            [
+                158, 188, 120, 67, 192, 61, 113, 31, 151, 11, 20, 54, 169, 140, 154, 73, 111, 15,
+                188, 203, 105, 101, 196, 134, 9, 240, 238, 167, 43, 199, 138, 50,
+            ],
+            [
+                223, 181, 8, 23, 21, 195, 216, 98, 175, 22, 182, 150, 20, 89, 247, 191, 6, 101, 92,
+                140, 68, 166, 76, 45, 147, 120, 241, 110, 223, 148, 233, 43,
+            ],
+            [
+                85, 31, 14, 156, 49, 178, 19, 11, 158, 219, 105, 246, 125, 204, 202, 83, 163, 101,
+                164, 226, 122, 72, 97, 4, 81, 119, 45, 211, 6, 43, 39, 29,
+            ],
+            [
+                243, 92, 83, 227, 28, 2, 43, 230, 144, 50, 208, 21, 28, 131, 56, 21, 179, 46, 178,
+                86, 144, 211, 243, 114, 136, 91, 246, 245, 150, 2, 38, 42,
+            ],
+            [
+                65, 208, 187, 150, 153, 104, 250, 84, 127, 187, 210, 227, 210, 61, 180, 12, 91,
+                143, 218, 43, 210, 221, 223, 36, 224, 254, 68, 212, 212, 154, 86, 13,
+            ],
+            [
+                89, 7, 207, 67, 213, 115, 144, 25, 40, 56, 93, 84, 103, 227, 80, 21, 100, 102, 80,
+                171, 228, 132, 100, 15, 155, 187, 129, 247, 44, 56, 205, 49,
+            ],
+            [
+                212, 23, 76, 95, 222, 87, 40, 249, 220, 58, 246, 46, 168, 44, 190, 13, 61, 165, 14,
+                254, 51, 249, 202, 182, 15, 23, 153, 85, 145, 80, 24, 12,
+            ],
+            [
+                178, 68, 108, 155, 89, 94, 241, 217, 22, 53, 86, 101, 128, 175, 57, 52, 235, 254,
+                227, 182, 224, 164, 85, 212, 38, 6, 119, 125, 42, 50, 36, 32,
+            ],
+        ],
+        [
+            [
+                174, 161, 6, 36, 192, 13, 234, 77, 5, 219, 211, 206, 21, 76, 48, 238, 22, 126, 171,
+                198, 10, 240, 196, 17, 86, 7, 156, 36, 204, 140, 49, 58,
+            ],
+            [
+                254, 31, 156, 14, 232, 234, 86, 85, 207, 172, 222, 214, 73, 93, 135, 118, 10, 119,
+                139, 52, 99, 87, 19, 251, 97, 254, 241, 163, 188, 139, 58, 23,
+            ],
+            [
+                3, 223, 112, 213, 225, 108, 155, 223, 83, 160, 128, 248, 100, 19, 211, 37, 68, 197,
+                55, 70, 147, 139, 94, 44, 224, 155, 145, 80, 44, 84, 116, 23,
+            ],
+            [
+                64, 121, 63, 94, 193, 19, 92, 162, 185, 43, 71, 146, 176, 241, 32, 244, 104, 206,
+                5, 247, 168, 72, 15, 223, 190, 253, 118, 232, 42, 129, 90, 47,
+            ],
+            [
+                39, 180, 169, 11, 239, 69, 169, 158, 177, 51, 214, 159, 167, 52, 136, 63, 35, 170,
+                109, 239, 113, 101, 18, 113, 250, 191, 154, 65, 183, 202, 125, 27,
+            ],
+            [
+                138, 14, 72, 186, 79, 128, 249, 117, 248, 187, 217, 77, 161, 146, 73, 139, 99, 65,
+                97, 154, 126, 62, 251, 233, 162, 153, 102, 174, 14, 179, 33, 16,
+            ],
+            [
+                47, 18, 18, 67, 20, 135, 56, 128, 236, 0, 15, 75, 86, 113, 114, 99, 49, 64, 161,
+                225, 140, 184, 205, 120, 61, 21, 222, 240, 163, 199, 18, 62,
+            ],
+            [
+                8, 111, 161, 172, 24, 206, 54, 55, 10, 113, 193, 200, 74, 209, 214, 52, 181, 72,
+                176, 30, 73, 72, 12, 196, 92, 208, 141, 81, 185, 8, 12, 54,
+            ],
+        ],
+        [
+            [
+                46, 185, 19, 96, 231, 201, 115, 230, 223, 114, 171, 167, 190, 183, 250, 23, 3, 180,
+                204, 50, 226, 135, 113, 0, 230, 131, 43, 3, 194, 154, 32, 59,
+            ],
+            [
+                50, 28, 139, 62, 135, 248, 154, 191, 140, 126, 9, 153, 22, 88, 31, 3, 151, 28, 32,
+                30, 9, 235, 167, 125, 45, 133, 240, 36, 136, 104, 48, 7,
+            ],
+            [
+                226, 136, 131, 0, 238, 35, 126, 173, 142, 49, 163, 179, 24, 22, 174, 244, 64, 237,
+                25, 251, 0, 234, 180, 193, 247, 201, 123, 109, 207, 228, 93, 39,
+            ],
+            [
+                61, 85, 141, 27, 188, 200, 10, 54, 199, 18, 227, 104, 166, 236, 109, 133, 44, 86,
+                23, 102, 233, 67, 227, 130, 55, 21, 167, 100, 210, 14, 160, 30,
+            ],
+            [
+                54, 116, 177, 195, 135, 3, 85, 244, 33, 4, 85, 36, 219, 93, 245, 32, 114, 38, 196,
+                66, 187, 50, 149, 188, 224, 191, 149, 38, 7, 71, 84, 51,
+            ],
+            [
+                121, 228, 157, 117, 98, 4, 135, 104, 34, 203, 198, 124, 176, 89, 10, 91, 108, 148,
+                92, 117, 173, 113, 93, 150, 190, 227, 156, 252, 171, 176, 185, 50,
+            ],
+            [
+                28, 127, 3, 90, 104, 32, 225, 56, 93, 40, 78, 255, 55, 109, 139, 16, 185, 67, 47,
+                68, 19, 56, 122, 154, 153, 162, 96, 187, 242, 203, 120, 60,
+            ],
+            [
+                14, 225, 203, 164, 194, 212, 69, 234, 172, 166, 182, 11, 132, 21, 242, 195, 90,
+                198, 176, 112, 242, 67, 98, 212, 1, 12, 216, 130, 244, 228, 225, 61,
+            ],
+        ],
+        [
+            [
+                210, 191, 203, 174, 136, 40, 90, 112, 100, 241, 52, 48, 112, 253, 96, 199, 163,
+                147, 178, 219, 41, 210, 162, 167, 17, 87, 125, 218, 69, 140, 136, 22,
+            ],
+            [
+                234, 203, 239, 236, 92, 121, 191, 78, 217, 245, 231, 245, 28, 61, 118, 31, 46, 89,
+                219, 88, 181, 2, 96, 87, 45, 47, 201, 154, 173, 83, 49, 11,
+            ],
+            [
+                234, 44, 17, 112, 229, 170, 36, 236, 249, 33, 220, 175, 231, 157, 92, 246, 174,
+                200, 184, 42, 57, 159, 218, 214, 221, 66, 67, 118, 104, 214, 63, 10,
+            ],
+            [
+                133, 62, 55, 96, 138, 221, 224, 233, 201, 87, 18, 33, 83, 5, 194, 205, 73, 16, 10,
+                190, 172, 50, 240, 166, 43, 7, 147, 103, 229, 10, 111, 45,
+            ],
+            [
+                157, 114, 142, 96, 51, 162, 213, 7, 50, 248, 250, 90, 100, 11, 2, 254, 195, 242,
+                95, 76, 59, 138, 147, 38, 140, 7, 1, 235, 205, 211, 89, 44,
+            ],
+            [
+                143, 94, 35, 83, 41, 197, 89, 33, 216, 115, 24, 246, 156, 195, 213, 213, 1, 91, 95,
+                75, 231, 128, 35, 137, 8, 95, 121, 144, 83, 255, 131, 15,
+            ],
+            [
+                150, 216, 110, 208, 254, 158, 164, 47, 110, 150, 157, 71, 138, 10, 147, 46, 141,
+                164, 223, 60, 40, 89, 251, 204, 224, 227, 49, 119, 233, 69, 198, 15,
+            ],
+            [
+                210, 49, 7, 90, 83, 242, 128, 124, 14, 95, 187, 72, 85, 21, 76, 190, 154, 137, 180,
+                71, 217, 55, 100, 2, 133, 209, 228, 230, 221, 175, 217, 61,
+            ],
+        ],
+        [
+            [
+                32, 232, 226, 96, 11, 164, 169, 85, 49, 115, 185, 35, 101, 58, 238, 198, 149, 196,
+                169, 12, 33, 209, 53, 130, 136, 114, 91, 63, 195, 28, 48, 34,
+            ],
+            [
+                152, 157, 30, 53, 83, 106, 122, 86, 232, 113, 55, 184, 246, 34, 124, 166, 202, 241,
+                160, 23, 224, 54, 237, 124, 102, 48, 160, 203, 134, 61, 220, 24,
+            ],
+            [
+                231, 229, 87, 36, 88, 156, 21, 191, 54, 19, 22, 30, 56, 9, 72, 112, 153, 201, 132,
+                139, 120, 211, 7, 169, 210, 209, 126, 225, 113, 118, 15, 45,
+            ],
+            [
+                94, 181, 162, 198, 83, 4, 62, 31, 158, 43, 208, 35, 242, 163, 99, 76, 118, 224,
+                212, 127, 181, 200, 173, 169, 144, 174, 199, 129, 159, 146, 36, 26,
+            ],
+            [
+                138, 95, 69, 218, 102, 107, 180, 232, 163, 144, 99, 11, 165, 105, 7, 45, 90, 31,
+                104, 243, 19, 91, 31, 75, 61, 54, 204, 221, 101, 54, 148, 35,
+            ],
+            [
+                55, 174, 116, 125, 217, 52, 65, 134, 125, 101, 114, 133, 100, 44, 160, 254, 18, 31,
+                182, 154, 28, 198, 87, 247, 134, 243, 173, 245, 82, 45, 182, 14,
+            ],
+            [
+                71, 45, 244, 163, 158, 66, 219, 36, 30, 28, 13, 93, 93, 219, 82, 196, 200, 170, 78,
+                97, 238, 211, 143, 184, 247, 212, 188, 177, 214, 141, 47, 11,
+            ],
+            [
+                197, 143, 225, 145, 250, 74, 243, 113, 57, 172, 207, 1, 104, 179, 218, 168, 121,
+                110, 212, 236, 220, 8, 156, 69, 70, 83, 31, 63, 10, 253, 125, 52,
+            ],
+        ],
+        [
+            [
+                202, 114, 28, 87, 1, 180, 9, 197, 113, 53, 67, 129, 189, 165, 252, 132, 88, 41, 63,
+                217, 206, 30, 105, 130, 149, 3, 156, 75, 252, 23, 163, 51,
+            ],
+            [
+                113, 248, 230, 51, 55, 168, 52, 76, 121, 172, 247, 87, 217, 179, 67, 219, 199, 236,
+                191, 76, 88, 178, 91, 215, 200, 248, 126, 249, 159, 8, 149, 6,
+            ],
+            [
+                113, 18, 39, 2, 168, 20, 7, 201, 91, 64, 219, 246, 196, 2, 12, 255, 253, 69, 29,
+                197, 72, 7, 57, 39, 125, 96, 193, 215, 169, 179, 148, 0,
+            ],
+            [
+                89, 55, 90, 115, 145, 177, 50, 94, 45, 3, 243, 147, 1, 196, 165, 246, 201, 124,
+                108, 166, 196, 82, 35, 135, 68, 246, 207, 225, 251, 203, 190, 10,
+            ],
+            [
+                239, 127, 98, 165, 183, 215, 83, 242, 165, 193, 210, 17, 238, 113, 245, 220, 196,
+                208, 71, 172, 3, 239, 83, 111, 19, 89, 250, 198, 179, 188, 113, 56,
+            ],
+            [
+                213, 179, 191, 200, 247, 170, 118, 86, 180, 69, 201, 145, 85, 85, 69, 187, 185, 71,
+                24, 186, 49, 182, 138, 210, 28, 17, 21, 121, 139, 9, 235, 57,
+            ],
+            [
+                126, 235, 128, 181, 162, 245, 97, 50, 144, 222, 116, 150, 31, 64, 163, 54, 26, 83,
+                231, 88, 158, 96, 118, 120, 125, 221, 107, 161, 207, 31, 152, 2,
+            ],
+            [
+                152, 20, 175, 26, 1, 31, 206, 90, 134, 244, 216, 242, 30, 233, 200, 107, 179, 174,
+                205, 170, 207, 167, 96, 18, 192, 28, 132, 51, 85, 24, 205, 38,
+            ],
+        ],
+        [
+            [
+                219, 204, 163, 243, 39, 117, 49, 54, 35, 22, 123, 174, 39, 119, 213, 189, 66, 248,
+                134, 227, 126, 72, 101, 208, 250, 188, 169, 30, 189, 148, 94, 35,
+            ],
+            [
+                165, 210, 243, 232, 6, 209, 247, 187, 75, 147, 78, 145, 178, 160, 195, 98, 150,
+                246, 129, 125, 154, 202, 49, 232, 167, 34, 0, 18, 210, 35, 52, 17,
+            ],
+            [
+                225, 248, 36, 199, 173, 39, 228, 146, 218, 170, 130, 68, 11, 123, 152, 217, 189,
+                159, 187, 26, 192, 179, 46, 10, 94, 138, 111, 175, 52, 87, 183, 0,
+            ],
+            [
+                141, 249, 145, 88, 118, 229, 236, 24, 82, 71, 107, 152, 77, 187, 159, 103, 219,
+                215, 56, 59, 53, 193, 129, 225, 43, 90, 249, 119, 149, 120, 244, 61,
+            ],
+            [
+                103, 86, 64, 42, 98, 32, 110, 102, 186, 30, 253, 193, 230, 217, 159, 81, 152, 133,
+                10, 95, 201, 249, 160, 54, 75, 47, 62, 158, 51, 156, 7, 6,
+            ],
+            [
+                87, 23, 47, 1, 96, 168, 78, 242, 177, 255, 204, 61, 126, 23, 196, 60, 168, 147,
+                234, 88, 244, 72, 145, 204, 97, 112, 219, 155, 13, 171, 68, 7,
+            ],
+            [
+                174, 144, 97, 213, 156, 46, 227, 227, 240, 211, 195, 189, 193, 115, 231, 153, 89,
+                246, 46, 56, 55, 56, 237, 188, 86, 161, 59, 209, 17, 103, 23, 44,
+            ],
+            [
+                57, 55, 232, 112, 156, 243, 13, 17, 19, 174, 67, 56, 171, 5, 79, 51, 20, 101, 164,
+                95, 173, 223, 6, 106, 28, 152, 229, 188, 111, 218, 5, 4,
+            ],
+        ],
+        [
+            [
+                165, 113, 86, 51, 251, 22, 81, 227, 187, 174, 169, 120, 224, 227, 103, 117, 92,
+                198, 141, 254, 70, 154, 124, 6, 107, 88, 101, 242, 250, 135, 56, 61,
+            ],
+            [
+                38, 31, 26, 139, 196, 111, 154, 207, 237, 132, 209, 125, 196, 250, 244, 150, 180,
+                108, 36, 139, 157, 120, 84, 56, 91, 58, 24, 105, 94, 254, 202, 34,
+            ],
+            [
+                88, 150, 227, 201, 51, 163, 29, 26, 232, 190, 108, 215, 117, 155, 118, 178, 118,
+                137, 124, 163, 72, 147, 189, 199, 123, 218, 231, 19, 158, 90, 189, 15,
+            ],
+            [
+                72, 156, 203, 144, 223, 142, 221, 222, 255, 253, 184, 50, 20, 246, 181, 73, 250,
+                180, 206, 47, 126, 133, 0, 204, 55, 43, 111, 234, 214, 122, 148, 35,
+            ],
+            [
+                229, 60, 87, 46, 216, 144, 218, 18, 229, 5, 230, 101, 33, 180, 22, 88, 80, 234,
+                197, 200, 125, 102, 45, 130, 235, 111, 224, 213, 234, 27, 211, 41,
+            ],
+            [
+                129, 39, 57, 222, 245, 149, 141, 120, 27, 7, 2, 40, 191, 189, 25, 244, 238, 36,
+                230, 33, 235, 126, 48, 7, 186, 44, 68, 121, 93, 74, 243, 16,
+            ],
+            [
+                111, 209, 22, 221, 190, 145, 0, 182, 16, 238, 32, 2, 83, 59, 119, 223, 143, 72,
+                137, 93, 51, 103, 250, 117, 114, 222, 250, 1, 64, 64, 218, 8,
+            ],
+            [
+                129, 35, 74, 60, 51, 22, 183, 181, 130, 242, 31, 194, 178, 8, 11, 91, 156, 223, 0,
+                146, 134, 189, 135, 12, 46, 124, 222, 154, 166, 24, 98, 10,
+            ],
+        ],
+        [
+            [
+                165, 107, 237, 66, 102, 27, 125, 27, 255, 32, 30, 195, 3, 43, 165, 13, 33, 236,
+                141, 21, 48, 233, 75, 196, 225, 221, 48, 75, 39, 31, 126, 42,
+            ],
+            [
+                79, 141, 160, 70, 53, 147, 175, 217, 74, 80, 35, 200, 242, 136, 249, 39, 89, 72,
+                210, 111, 161, 60, 22, 184, 64, 243, 181, 239, 192, 158, 243, 41,
+            ],
+            [
+                204, 53, 255, 104, 94, 49, 200, 21, 49, 94, 17, 233, 49, 178, 44, 187, 95, 230, 20,
+                128, 227, 179, 128, 132, 220, 143, 220, 59, 223, 119, 227, 24,
+            ],
+            [
+                240, 141, 124, 52, 108, 190, 18, 228, 109, 175, 112, 79, 234, 171, 61, 209, 184,
+                150, 106, 175, 13, 93, 188, 173, 134, 232, 217, 182, 223, 184, 229, 28,
+            ],
+            [
+                133, 240, 102, 239, 15, 73, 190, 239, 76, 61, 227, 211, 75, 176, 221, 202, 95, 186,
+                61, 3, 250, 211, 83, 137, 251, 108, 232, 195, 208, 11, 58, 27,
+            ],
+            [
+                181, 7, 123, 127, 250, 76, 240, 34, 199, 31, 240, 93, 167, 19, 214, 93, 20, 99,
+                233, 133, 55, 10, 185, 64, 114, 90, 242, 34, 234, 211, 110, 30,
+            ],
+            [
+                101, 250, 91, 214, 44, 247, 7, 181, 110, 253, 79, 43, 104, 246, 171, 188, 90, 106,
+                136, 151, 133, 99, 46, 47, 235, 251, 221, 127, 140, 164, 59, 32,
+            ],
+            [
+                126, 191, 146, 21, 27, 70, 71, 137, 130, 16, 56, 205, 73, 237, 2, 185, 108, 128,
+                147, 144, 36, 199, 17, 195, 129, 228, 166, 69, 17, 255, 174, 12,
+            ],
+        ],
+        [
+            [
+                31, 126, 90, 117, 177, 129, 144, 90, 13, 174, 243, 28, 150, 110, 152, 209, 51, 105,
+                73, 36, 102, 144, 204, 154, 156, 221, 28, 223, 117, 163, 80, 1,
+            ],
+            [
+                151, 29, 214, 223, 160, 37, 69, 158, 187, 17, 233, 193, 27, 66, 243, 76, 163, 113,
+                216, 95, 188, 50, 132, 61, 117, 180, 87, 16, 33, 221, 154, 9,
+            ],
+            [
+                116, 147, 189, 150, 35, 140, 10, 110, 157, 49, 12, 207, 31, 90, 57, 138, 145, 212,
+                14, 248, 33, 62, 235, 217, 78, 196, 113, 104, 23, 89, 226, 20,
+            ],
+            [
+                230, 32, 113, 247, 126, 225, 18, 158, 43, 77, 46, 106, 119, 143, 169, 215, 105,
+                186, 215, 6, 67, 147, 243, 201, 213, 100, 39, 72, 43, 17, 130, 63,
+            ],
+            [
+                239, 217, 219, 42, 45, 31, 32, 44, 116, 115, 173, 147, 71, 23, 39, 163, 99, 127,
+                75, 73, 199, 170, 2, 196, 62, 255, 224, 107, 243, 149, 65, 20,
+            ],
+            [
+                111, 215, 37, 162, 254, 223, 145, 248, 105, 42, 165, 81, 86, 221, 178, 231, 243,
+                248, 9, 10, 193, 163, 79, 16, 106, 255, 37, 200, 111, 250, 170, 0,
+            ],
+            [
+                29, 52, 5, 60, 43, 37, 253, 49, 199, 157, 166, 32, 71, 44, 113, 230, 31, 110, 229,
+                144, 179, 114, 162, 10, 57, 25, 31, 153, 235, 149, 92, 32,
+            ],
+            [
+                67, 245, 12, 231, 233, 94, 217, 207, 2, 113, 233, 87, 101, 84, 179, 99, 47, 147,
+                167, 201, 11, 152, 245, 200, 119, 118, 246, 132, 33, 223, 152, 24,
+            ],
+        ],
+        [
+            [
+                65, 202, 187, 246, 202, 36, 158, 65, 177, 146, 149, 145, 215, 110, 247, 172, 82,
+                165, 76, 95, 249, 227, 1, 135, 50, 30, 9, 29, 230, 82, 56, 17,
+            ],
+            [
+                139, 101, 59, 130, 246, 2, 188, 80, 77, 81, 230, 91, 247, 30, 159, 176, 224, 67,
+                43, 112, 38, 86, 226, 213, 185, 218, 128, 110, 226, 179, 157, 50,
+            ],
+            [
+                14, 168, 4, 168, 243, 22, 72, 196, 144, 90, 80, 14, 42, 41, 255, 177, 204, 122,
+                228, 59, 127, 106, 15, 96, 170, 1, 0, 71, 250, 255, 116, 5,
+            ],
+            [
+                133, 168, 225, 128, 67, 158, 87, 204, 67, 75, 49, 46, 147, 124, 14, 170, 78, 201,
+                111, 97, 142, 166, 132, 119, 140, 185, 36, 119, 214, 65, 179, 50,
+            ],
+            [
+                71, 41, 117, 118, 237, 36, 65, 76, 111, 221, 2, 31, 125, 91, 41, 119, 154, 110, 39,
+                213, 157, 131, 146, 48, 159, 106, 23, 128, 217, 220, 203, 13,
+            ],
+            [
+                238, 172, 171, 168, 4, 35, 36, 237, 168, 119, 10, 130, 217, 55, 192, 179, 20, 59,
+                204, 9, 178, 161, 138, 83, 61, 19, 55, 162, 74, 206, 167, 41,
+            ],
+            [
+                112, 223, 193, 230, 187, 31, 144, 238, 37, 220, 131, 77, 88, 71, 46, 112, 178, 244,
+                132, 16, 251, 4, 51, 246, 157, 77, 94, 176, 237, 63, 237, 59,
+            ],
+            [
+                151, 157, 171, 1, 170, 209, 0, 146, 105, 100, 88, 174, 133, 158, 249, 228, 79, 149,
+                210, 129, 122, 144, 254, 248, 176, 54, 249, 119, 195, 231, 7, 25,
+            ],
+        ],
+        [
+            [
+                104, 126, 217, 147, 87, 171, 240, 201, 141, 147, 217, 111, 74, 94, 107, 110, 50,
+                136, 214, 45, 130, 34, 34, 199, 113, 155, 223, 48, 90, 250, 47, 26,
+            ],
+            [
+                24, 66, 92, 66, 186, 2, 19, 200, 65, 71, 135, 191, 175, 249, 57, 45, 184, 70, 1,
+                190, 136, 126, 216, 86, 143, 184, 56, 95, 22, 211, 127, 52,
+            ],
+            [
+                254, 155, 20, 183, 198, 111, 169, 7, 145, 142, 37, 14, 219, 95, 81, 35, 217, 194,
+                94, 17, 128, 122, 249, 48, 22, 72, 240, 247, 163, 143, 54, 24,
+            ],
+            [
+                37, 100, 145, 198, 140, 143, 82, 22, 181, 255, 105, 178, 222, 195, 37, 112, 94,
+                101, 157, 151, 214, 53, 67, 215, 84, 220, 210, 81, 39, 27, 3, 1,
+            ],
+            [
+                137, 218, 75, 190, 164, 173, 11, 200, 249, 202, 179, 139, 158, 34, 116, 2, 206,
+                189, 246, 113, 102, 225, 217, 13, 152, 235, 179, 91, 4, 38, 31, 20,
+            ],
+            [
+                223, 239, 44, 155, 0, 191, 74, 141, 202, 194, 191, 196, 216, 234, 2, 105, 139, 94,
+                47, 96, 81, 26, 58, 29, 129, 87, 165, 163, 101, 153, 76, 28,
+            ],
+            [
+                216, 251, 168, 49, 6, 210, 51, 179, 30, 178, 4, 222, 43, 46, 159, 52, 187, 107,
+                250, 176, 187, 73, 26, 230, 146, 52, 23, 130, 158, 218, 3, 47,
+            ],
+            [
+                84, 115, 254, 143, 234, 126, 195, 142, 157, 118, 71, 215, 167, 81, 235, 131, 220,
+                98, 55, 203, 124, 162, 62, 150, 50, 123, 213, 166, 37, 72, 6, 42,
+            ],
+        ],
+        [
+            [
+                214, 28, 62, 172, 13, 38, 101, 50, 82, 57, 27, 160, 213, 208, 121, 167, 147, 72,
+                43, 26, 195, 128, 241, 104, 57, 213, 241, 171, 208, 108, 38, 49,
+            ],
+            [
+                31, 174, 55, 77, 5, 0, 21, 245, 6, 30, 97, 114, 189, 25, 158, 249, 101, 223, 191,
+                227, 6, 104, 131, 128, 122, 156, 209, 91, 194, 233, 120, 19,
+            ],
+            [
+                238, 145, 224, 167, 81, 137, 16, 148, 209, 242, 242, 79, 174, 40, 22, 155, 203,
+                240, 175, 181, 28, 225, 41, 138, 237, 147, 200, 112, 155, 80, 145, 9,
+            ],
+            [
+                38, 134, 243, 200, 186, 225, 52, 147, 222, 51, 177, 16, 45, 142, 153, 93, 52, 109,
+                137, 239, 39, 249, 170, 92, 80, 189, 170, 137, 213, 210, 191, 22,
+            ],
+            [
+                235, 177, 234, 131, 226, 174, 54, 1, 233, 35, 222, 221, 62, 4, 57, 203, 39, 236,
+                156, 38, 120, 66, 41, 200, 63, 91, 2, 177, 122, 94, 75, 63,
+            ],
+            [
+                12, 111, 16, 70, 41, 40, 84, 41, 66, 54, 94, 207, 173, 97, 218, 5, 252, 199, 99,
+                135, 103, 166, 180, 156, 42, 123, 156, 80, 79, 8, 233, 57,
+            ],
+            [
+                201, 162, 132, 17, 17, 188, 20, 213, 182, 113, 96, 15, 196, 194, 89, 181, 168, 22,
+                21, 129, 142, 233, 178, 52, 152, 85, 26, 118, 10, 252, 79, 15,
+            ],
+            [
+                143, 157, 135, 77, 220, 125, 199, 21, 115, 33, 145, 153, 216, 61, 96, 2, 144, 66,
+                196, 164, 87, 185, 242, 112, 201, 126, 188, 40, 28, 234, 60, 58,
+            ],
+        ],
+        [
+            [
+                97, 237, 63, 32, 185, 90, 235, 210, 29, 237, 83, 89, 12, 165, 240, 176, 100, 76,
+                142, 87, 55, 23, 62, 243, 185, 221, 133, 5, 20, 65, 223, 1,
+            ],
+            [
+                218, 248, 55, 226, 93, 167, 68, 106, 58, 218, 122, 150, 209, 9, 77, 30, 37, 239,
+                182, 154, 104, 230, 25, 147, 135, 78, 169, 149, 39, 176, 120, 57,
+            ],
+            [
+                250, 26, 51, 253, 211, 154, 192, 144, 1, 137, 94, 13, 230, 14, 25, 220, 98, 3, 10,
+                32, 107, 66, 45, 166, 58, 232, 28, 36, 198, 190, 52, 31,
+            ],
+            [
+                153, 152, 165, 101, 123, 119, 199, 253, 87, 185, 43, 252, 179, 97, 124, 226, 11,
+                145, 237, 22, 141, 107, 15, 42, 151, 115, 168, 242, 11, 32, 230, 9,
+            ],
+            [
+                65, 225, 118, 57, 133, 107, 119, 139, 89, 58, 249, 226, 232, 177, 157, 186, 63,
+                238, 240, 21, 166, 66, 161, 159, 7, 165, 13, 25, 40, 45, 46, 55,
+            ],
+            [
+                9, 170, 104, 61, 45, 225, 188, 72, 54, 203, 252, 175, 186, 57, 180, 155, 50, 21,
+                14, 135, 41, 95, 133, 163, 229, 36, 29, 187, 246, 238, 32, 23,
+            ],
+            [
+                203, 107, 189, 182, 215, 210, 161, 0, 31, 132, 201, 10, 132, 75, 205, 133, 56, 116,
+                22, 172, 56, 157, 253, 30, 245, 231, 192, 166, 96, 209, 244, 42,
+            ],
+            [
+                124, 57, 40, 120, 33, 255, 45, 65, 169, 215, 236, 211, 242, 155, 84, 249, 97, 95,
+                194, 111, 188, 144, 114, 217, 148, 209, 48, 118, 73, 210, 166, 44,
+            ],
+        ],
+        [
+            [
+                205, 107, 231, 165, 252, 185, 49, 70, 42, 111, 106, 41, 37, 45, 126, 227, 237, 57,
+                157, 68, 211, 254, 189, 249, 214, 120, 166, 189, 13, 248, 211, 29,
+            ],
+            [
+                31, 7, 15, 177, 57, 227, 215, 7, 4, 183, 78, 138, 5, 250, 21, 17, 180, 134, 221,
+                200, 116, 117, 41, 110, 201, 69, 199, 71, 219, 16, 232, 6,
+            ],
+            [
+                171, 237, 145, 130, 219, 188, 123, 226, 143, 183, 176, 87, 11, 148, 105, 90, 166,
+                1, 115, 151, 76, 89, 7, 103, 207, 255, 131, 232, 170, 227, 94, 49,
+            ],
+            [
+                230, 214, 201, 71, 192, 193, 211, 113, 145, 168, 254, 138, 160, 198, 4, 107, 237,
+                235, 15, 214, 115, 28, 36, 3, 134, 255, 202, 195, 198, 124, 137, 56,
+            ],
+            [
+                93, 163, 239, 12, 169, 72, 100, 235, 131, 67, 211, 46, 204, 182, 171, 237, 123, 41,
+                97, 253, 25, 200, 190, 9, 24, 18, 86, 233, 127, 1, 157, 3,
+            ],
+            [
+                84, 211, 115, 150, 18, 59, 197, 56, 53, 176, 122, 167, 127, 129, 120, 191, 80, 110,
+                172, 36, 244, 108, 36, 6, 249, 242, 248, 15, 133, 96, 24, 9,
+            ],
+            [
+                209, 164, 237, 186, 3, 121, 82, 59, 147, 53, 29, 134, 143, 81, 122, 46, 198, 212,
+                186, 105, 219, 21, 212, 129, 173, 166, 201, 104, 145, 86, 2, 34,
+            ],
+            [
+                78, 170, 216, 3, 251, 208, 7, 240, 53, 187, 222, 173, 221, 74, 179, 146, 126, 50,
+                109, 17, 6, 85, 16, 194, 225, 164, 170, 130, 79, 9, 151, 35,
+            ],
+        ],
+        [
+            [
+                232, 61, 81, 163, 100, 123, 240, 78, 155, 42, 231, 97, 230, 63, 230, 251, 65, 69,
+                220, 76, 249, 183, 76, 239, 8, 131, 234, 247, 221, 45, 47, 16,
+            ],
+            [
+                179, 244, 198, 225, 58, 21, 45, 246, 249, 136, 147, 102, 254, 79, 92, 162, 97, 107,
+                12, 178, 48, 223, 108, 252, 64, 245, 194, 187, 224, 30, 238, 30,
+            ],
+            [
+                146, 123, 189, 50, 226, 15, 148, 217, 236, 233, 56, 31, 50, 203, 157, 83, 252, 253,
+                175, 130, 229, 215, 43, 135, 106, 142, 90, 205, 217, 202, 24, 37,
+            ],
+            [
+                81, 245, 83, 0, 39, 177, 27, 212, 178, 151, 58, 8, 18, 176, 25, 150, 78, 192, 185,
+                190, 185, 41, 246, 72, 125, 155, 167, 49, 177, 97, 75, 54,
+            ],
+            [
+                10, 142, 198, 137, 113, 49, 21, 155, 107, 70, 244, 37, 200, 225, 129, 230, 179,
+                189, 217, 240, 243, 91, 104, 160, 112, 68, 219, 194, 192, 116, 243, 23,
+            ],
+            [
+                75, 147, 144, 59, 228, 54, 190, 54, 195, 223, 21, 162, 187, 158, 196, 65, 219, 216,
+                92, 155, 214, 138, 94, 220, 61, 149, 103, 246, 112, 153, 94, 35,
+            ],
+            [
+                182, 220, 194, 190, 92, 111, 48, 179, 63, 131, 89, 203, 242, 157, 96, 250, 37, 126,
+                45, 186, 123, 67, 149, 240, 14, 33, 73, 67, 12, 90, 25, 41,
+            ],
+            [
+                180, 41, 18, 234, 175, 68, 186, 53, 121, 225, 249, 245, 179, 33, 221, 238, 188,
+                206, 182, 59, 81, 163, 22, 60, 214, 11, 1, 123, 67, 168, 135, 58,
+            ],
+        ],
+        [
+            [
+                73, 89, 6, 35, 84, 46, 160, 136, 141, 70, 243, 117, 95, 198, 145, 1, 162, 64, 245,
+                235, 92, 113, 214, 177, 243, 215, 107, 46, 253, 157, 114, 41,
+            ],
+            [
+                100, 247, 147, 58, 147, 15, 217, 50, 236, 92, 22, 107, 168, 38, 12, 90, 69, 175,
+                219, 255, 183, 96, 39, 9, 43, 228, 10, 136, 207, 201, 60, 52,
+            ],
+            [
+                210, 68, 250, 106, 163, 10, 104, 69, 116, 183, 114, 244, 230, 45, 79, 236, 9, 119,
+                109, 21, 57, 19, 187, 164, 191, 35, 51, 136, 91, 219, 140, 49,
+            ],
+            [
+                68, 140, 74, 216, 45, 3, 8, 27, 199, 128, 223, 65, 18, 24, 255, 153, 23, 222, 100,
+                233, 25, 144, 178, 91, 153, 40, 66, 14, 117, 187, 174, 39,
+            ],
+            [
+                47, 140, 125, 187, 62, 226, 137, 71, 42, 69, 156, 23, 15, 94, 251, 56, 181, 74, 83,
+                110, 248, 184, 123, 239, 198, 211, 184, 116, 116, 80, 113, 1,
+            ],
+            [
+                39, 16, 177, 165, 97, 112, 232, 85, 221, 118, 4, 250, 246, 34, 142, 216, 2, 12, 47,
+                126, 104, 220, 167, 64, 211, 241, 1, 173, 188, 154, 226, 44,
+            ],
+            [
+                195, 243, 8, 139, 94, 193, 180, 198, 217, 244, 95, 243, 119, 90, 124, 108, 185,
+                180, 234, 165, 255, 247, 156, 88, 14, 121, 131, 85, 201, 60, 196, 20,
+            ],
+            [
+                129, 152, 238, 85, 105, 141, 100, 5, 73, 27, 141, 14, 102, 79, 100, 106, 148, 234,
+                66, 154, 158, 14, 37, 104, 187, 142, 7, 128, 228, 45, 118, 20,
+            ],
+        ],
+        [
+            [
+                197, 179, 246, 70, 239, 29, 111, 255, 241, 45, 47, 93, 128, 16, 204, 145, 197, 189,
+                151, 210, 182, 195, 27, 42, 147, 13, 213, 206, 237, 176, 244, 44,
+            ],
+            [
+                248, 144, 17, 191, 224, 208, 36, 47, 130, 29, 155, 179, 5, 117, 215, 159, 44, 37,
+                177, 45, 27, 92, 187, 248, 153, 98, 117, 163, 25, 238, 72, 13,
+            ],
+            [
+                204, 36, 112, 245, 63, 207, 38, 57, 126, 138, 247, 167, 216, 139, 155, 138, 146,
+                52, 174, 242, 210, 17, 220, 15, 42, 167, 22, 86, 219, 245, 5, 39,
+            ],
+            [
+                208, 37, 188, 16, 110, 249, 147, 107, 59, 31, 4, 218, 86, 184, 94, 135, 253, 89,
+                184, 82, 69, 131, 50, 64, 47, 254, 3, 206, 78, 18, 51, 42,
+            ],
+            [
+                1, 7, 246, 228, 220, 49, 246, 102, 134, 29, 87, 34, 163, 10, 239, 26, 82, 160, 150,
+                149, 202, 146, 158, 39, 100, 52, 249, 162, 106, 144, 64, 29,
+            ],
+            [
+                59, 95, 228, 134, 27, 226, 173, 69, 156, 54, 105, 212, 112, 167, 222, 182, 157,
+                236, 145, 65, 159, 121, 14, 108, 168, 131, 149, 49, 211, 17, 132, 2,
+            ],
+            [
+                209, 113, 160, 143, 131, 129, 155, 11, 168, 105, 185, 87, 126, 49, 67, 91, 81, 147,
+                12, 114, 145, 36, 146, 118, 10, 15, 239, 218, 42, 192, 89, 28,
+            ],
+            [
+                250, 137, 106, 178, 214, 81, 233, 33, 50, 92, 150, 183, 179, 33, 250, 252, 8, 211,
+                78, 176, 144, 11, 40, 209, 184, 51, 164, 230, 53, 199, 83, 17,
+            ],
+        ],
+        [
+            [
+                24, 65, 156, 35, 119, 222, 55, 14, 21, 88, 8, 68, 165, 84, 128, 255, 69, 170, 204,
+                6, 123, 118, 88, 124, 229, 172, 61, 98, 80, 111, 92, 0,
+            ],
+            [
+                215, 172, 85, 47, 169, 62, 82, 81, 170, 203, 108, 40, 218, 187, 6, 109, 252, 198,
+                171, 17, 171, 102, 101, 29, 233, 163, 228, 80, 112, 195, 27, 17,
+            ],
+            [
+                35, 53, 142, 92, 157, 234, 130, 109, 149, 37, 108, 223, 32, 232, 149, 49, 196, 213,
+                157, 91, 168, 202, 168, 21, 109, 5, 167, 61, 11, 59, 39, 5,
+            ],
+            [
+                153, 215, 15, 200, 127, 253, 89, 199, 187, 139, 248, 166, 9, 127, 153, 228, 21, 52,
+                134, 125, 110, 149, 128, 109, 117, 87, 80, 147, 73, 94, 94, 57,
+            ],
+            [
+                8, 32, 19, 252, 47, 35, 1, 250, 85, 245, 237, 204, 97, 73, 191, 3, 168, 71, 65,
+                254, 58, 214, 143, 33, 33, 48, 30, 253, 177, 112, 186, 16,
+            ],
+            [
+                78, 40, 188, 97, 240, 224, 9, 103, 75, 62, 229, 219, 102, 31, 54, 192, 48, 255,
+                255, 193, 59, 210, 86, 251, 42, 101, 52, 150, 195, 144, 70, 36,
+            ],
+            [
+                22, 72, 28, 139, 126, 113, 27, 204, 238, 191, 81, 84, 204, 103, 137, 167, 231, 195,
+                63, 124, 102, 153, 243, 52, 209, 252, 59, 204, 32, 41, 99, 6,
+            ],
+            [
+                41, 155, 191, 80, 31, 164, 252, 240, 18, 11, 104, 233, 28, 247, 128, 226, 128, 198,
+                77, 127, 110, 128, 52, 105, 112, 120, 22, 54, 15, 63, 141, 35,
+            ],
+        ],
+        [
+            [
+                236, 215, 218, 244, 150, 8, 44, 178, 248, 164, 118, 59, 77, 66, 160, 109, 248, 218,
+                99, 237, 106, 149, 212, 154, 209, 21, 62, 131, 3, 232, 103, 25,
+            ],
+            [
+                127, 140, 158, 110, 198, 75, 174, 66, 220, 170, 48, 188, 241, 129, 215, 218, 9, 18,
+                221, 81, 102, 126, 138, 50, 129, 181, 255, 2, 164, 22, 252, 51,
+            ],
+            [
+                234, 195, 133, 191, 249, 65, 197, 162, 22, 96, 95, 95, 130, 242, 74, 112, 213, 40,
+                189, 185, 78, 207, 204, 158, 115, 68, 172, 230, 136, 7, 55, 49,
+            ],
+            [
+                175, 242, 65, 50, 6, 139, 187, 100, 227, 251, 233, 169, 197, 39, 33, 174, 213, 70,
+                150, 78, 174, 89, 65, 84, 203, 248, 172, 31, 77, 218, 213, 63,
+            ],
+            [
+                251, 175, 15, 220, 171, 228, 141, 71, 222, 134, 9, 213, 148, 68, 27, 223, 94, 79,
+                155, 225, 157, 53, 115, 74, 238, 110, 167, 204, 152, 200, 241, 20,
+            ],
+            [
+                137, 203, 212, 139, 190, 200, 39, 130, 123, 134, 84, 42, 173, 199, 17, 202, 251,
+                19, 191, 148, 217, 8, 94, 158, 34, 147, 54, 133, 8, 180, 194, 47,
+            ],
+            [
+                250, 151, 172, 246, 114, 97, 204, 26, 41, 202, 212, 212, 170, 222, 46, 149, 25, 73,
+                174, 239, 97, 59, 56, 92, 222, 101, 112, 174, 62, 247, 15, 12,
+            ],
+            [
+                99, 187, 173, 167, 178, 25, 200, 166, 229, 76, 213, 37, 194, 209, 13, 73, 84, 168,
+                42, 113, 166, 168, 215, 40, 28, 189, 255, 92, 49, 181, 91, 17,
+            ],
+        ],
+        [
+            [
+                144, 243, 21, 199, 202, 151, 48, 144, 164, 205, 7, 143, 13, 169, 181, 222, 87, 197,
+                243, 77, 34, 235, 38, 98, 227, 245, 177, 57, 194, 5, 184, 13,
+            ],
+            [
+                215, 76, 237, 24, 167, 118, 52, 119, 175, 56, 250, 150, 161, 199, 30, 79, 238, 123,
+                122, 197, 171, 182, 137, 244, 145, 149, 148, 203, 91, 133, 227, 47,
+            ],
+            [
+                246, 13, 198, 101, 15, 104, 49, 126, 113, 50, 216, 111, 76, 71, 245, 51, 0, 210,
+                28, 19, 83, 44, 70, 240, 107, 180, 11, 65, 78, 68, 132, 51,
+            ],
+            [
+                101, 4, 8, 65, 182, 46, 78, 184, 88, 27, 154, 187, 231, 154, 203, 44, 177, 3, 220,
+                56, 174, 80, 102, 204, 44, 5, 46, 172, 133, 14, 60, 25,
+            ],
+            [
+                232, 30, 36, 116, 76, 110, 147, 202, 242, 215, 233, 16, 113, 129, 43, 214, 129,
+                181, 157, 12, 73, 9, 185, 190, 9, 30, 194, 10, 236, 137, 130, 23,
+            ],
+            [
+                73, 243, 133, 147, 82, 185, 192, 83, 153, 208, 233, 41, 32, 124, 76, 191, 183, 122,
+                11, 20, 193, 183, 239, 51, 42, 247, 95, 12, 182, 108, 161, 52,
+            ],
+            [
+                210, 156, 232, 18, 98, 72, 11, 69, 66, 111, 21, 236, 23, 131, 77, 242, 172, 133,
+                156, 237, 201, 0, 99, 101, 63, 212, 184, 197, 136, 6, 197, 54,
+            ],
+            [
+                107, 215, 181, 145, 8, 187, 71, 137, 63, 13, 72, 145, 210, 172, 125, 104, 84, 117,
+                223, 27, 65, 100, 129, 159, 66, 57, 54, 222, 212, 75, 133, 35,
+            ],
+        ],
+        [
+            [
+                35, 26, 230, 43, 195, 203, 186, 156, 196, 195, 38, 168, 60, 141, 20, 33, 16, 180,
+                172, 187, 132, 29, 117, 239, 213, 0, 139, 231, 240, 104, 128, 2,
+            ],
+            [
+                200, 116, 217, 228, 208, 168, 27, 193, 220, 104, 112, 100, 250, 235, 233, 106, 64,
+                63, 30, 55, 0, 2, 163, 93, 90, 177, 61, 47, 145, 235, 223, 6,
+            ],
+            [
+                130, 101, 12, 163, 146, 38, 71, 204, 27, 71, 144, 173, 113, 113, 220, 208, 29, 19,
+                51, 232, 23, 215, 168, 64, 47, 98, 175, 147, 147, 225, 149, 10,
+            ],
+            [
+                148, 174, 170, 96, 229, 109, 44, 51, 3, 121, 252, 119, 13, 59, 181, 229, 192, 34,
+                175, 95, 255, 11, 35, 44, 50, 207, 209, 78, 144, 42, 239, 12,
+            ],
+            [
+                249, 85, 121, 204, 198, 123, 137, 227, 136, 232, 167, 183, 219, 22, 36, 52, 223,
+                124, 38, 184, 224, 107, 196, 117, 225, 193, 252, 255, 128, 36, 169, 51,
+            ],
+            [
+                26, 196, 85, 93, 26, 44, 250, 237, 185, 135, 237, 157, 185, 134, 74, 144, 12, 26,
+                129, 76, 206, 242, 71, 4, 236, 56, 83, 140, 30, 9, 166, 40,
+            ],
+            [
+                18, 143, 151, 236, 211, 141, 188, 79, 80, 35, 13, 101, 106, 198, 17, 51, 37, 71,
+                15, 13, 18, 33, 53, 43, 37, 113, 150, 233, 22, 48, 196, 33,
+            ],
+            [
+                219, 45, 3, 43, 70, 14, 65, 232, 228, 154, 199, 213, 102, 213, 170, 56, 248, 155,
+                185, 146, 104, 60, 147, 187, 220, 198, 72, 216, 117, 43, 162, 63,
+            ],
+        ],
+        [
+            [
+                149, 90, 105, 13, 63, 161, 195, 179, 12, 14, 172, 57, 119, 206, 126, 105, 246, 139,
+                152, 36, 89, 185, 28, 214, 128, 55, 141, 183, 228, 179, 52, 16,
+            ],
+            [
+                16, 52, 129, 149, 79, 79, 174, 250, 42, 155, 84, 158, 142, 184, 174, 91, 140, 13,
+                2, 180, 33, 177, 89, 11, 242, 252, 143, 223, 135, 111, 188, 53,
+            ],
+            [
+                175, 96, 115, 154, 116, 213, 61, 130, 228, 244, 84, 67, 33, 246, 113, 123, 45, 26,
+                64, 47, 46, 236, 143, 45, 210, 153, 42, 214, 212, 82, 25, 45,
+            ],
+            [
+                180, 74, 78, 73, 6, 222, 58, 251, 66, 110, 11, 35, 37, 54, 214, 76, 151, 114, 48,
+                91, 218, 153, 213, 95, 246, 162, 44, 117, 97, 164, 228, 47,
+            ],
+            [
+                218, 117, 207, 222, 89, 161, 142, 193, 39, 171, 179, 19, 88, 32, 165, 172, 3, 81,
+                233, 48, 55, 118, 175, 94, 134, 138, 228, 193, 11, 173, 31, 17,
+            ],
+            [
+                211, 222, 209, 69, 150, 198, 127, 47, 32, 210, 222, 237, 75, 111, 37, 228, 67, 145,
+                205, 204, 101, 252, 225, 139, 185, 249, 105, 125, 67, 140, 238, 29,
+            ],
+            [
+                58, 197, 108, 97, 231, 40, 70, 64, 55, 169, 255, 178, 245, 13, 212, 44, 13, 92,
+                174, 246, 24, 57, 188, 135, 104, 30, 157, 39, 229, 52, 158, 23,
+            ],
+            [
+                144, 155, 1, 128, 178, 150, 40, 211, 239, 133, 45, 212, 59, 22, 88, 110, 124, 192,
+                221, 218, 186, 10, 157, 64, 12, 40, 87, 170, 195, 164, 65, 61,
+            ],
+        ],
+        [
+            [
+                131, 179, 57, 98, 225, 153, 45, 237, 239, 113, 25, 197, 221, 93, 18, 86, 74, 31,
+                92, 202, 168, 138, 92, 128, 79, 112, 133, 247, 233, 37, 192, 19,
+            ],
+            [
+                213, 52, 210, 99, 215, 157, 58, 113, 144, 221, 160, 138, 32, 220, 37, 180, 126, 29,
+                12, 136, 107, 33, 23, 252, 119, 136, 188, 220, 23, 86, 26, 36,
+            ],
+            [
+                195, 245, 140, 44, 194, 127, 151, 42, 133, 245, 99, 221, 255, 71, 136, 167, 34,
+                162, 78, 113, 101, 18, 253, 249, 101, 210, 57, 90, 42, 83, 182, 30,
+            ],
+            [
+                11, 238, 181, 234, 255, 186, 190, 151, 32, 65, 179, 56, 249, 136, 156, 112, 149,
+                68, 119, 198, 40, 220, 123, 5, 101, 62, 15, 3, 194, 1, 10, 41,
+            ],
+            [
+                160, 32, 64, 211, 242, 91, 176, 172, 18, 29, 12, 5, 19, 167, 99, 22, 152, 127, 207,
+                102, 34, 123, 146, 111, 14, 205, 98, 23, 15, 93, 100, 23,
+            ],
+            [
+                36, 216, 34, 82, 112, 138, 87, 238, 224, 135, 148, 136, 15, 184, 111, 72, 179, 146,
+                128, 98, 10, 248, 193, 62, 183, 35, 55, 73, 42, 101, 151, 33,
+            ],
+            [
+                57, 214, 147, 221, 94, 105, 72, 135, 130, 241, 249, 35, 36, 230, 79, 202, 90, 106,
+                116, 25, 4, 64, 31, 3, 174, 56, 151, 13, 168, 181, 32, 45,
+            ],
+            [
+                147, 119, 15, 234, 208, 92, 231, 239, 62, 158, 109, 153, 70, 52, 107, 102, 161,
+                151, 150, 114, 69, 155, 207, 217, 174, 23, 136, 7, 61, 28, 41, 6,
+            ],
+        ],
+        [
+            [
+                161, 201, 206, 136, 30, 15, 216, 45, 73, 87, 180, 226, 164, 14, 113, 30, 249, 229,
+                179, 206, 0, 129, 156, 251, 192, 68, 53, 93, 207, 229, 186, 17,
+            ],
+            [
+                95, 127, 94, 200, 42, 86, 38, 76, 154, 216, 119, 206, 234, 120, 253, 1, 112, 88,
+                130, 185, 51, 58, 243, 161, 1, 8, 20, 141, 52, 40, 185, 39,
+            ],
+            [
+                218, 169, 43, 136, 129, 77, 59, 193, 62, 106, 212, 217, 42, 215, 161, 234, 220,
+                160, 171, 151, 137, 199, 228, 210, 225, 105, 28, 5, 206, 213, 24, 55,
+            ],
+            [
+                131, 110, 158, 68, 87, 54, 66, 128, 117, 85, 162, 208, 51, 252, 84, 3, 44, 203, 53,
+                30, 95, 222, 211, 185, 134, 13, 116, 46, 18, 193, 125, 63,
+            ],
+            [
+                251, 89, 31, 83, 111, 0, 218, 162, 115, 9, 40, 97, 151, 55, 48, 149, 134, 51, 39,
+                148, 159, 93, 35, 194, 155, 99, 177, 122, 29, 52, 218, 45,
+            ],
+            [
+                0, 187, 96, 207, 6, 251, 199, 72, 50, 158, 197, 58, 160, 103, 101, 124, 77, 186,
+                62, 172, 42, 199, 123, 55, 113, 73, 240, 143, 188, 18, 187, 33,
+            ],
+            [
+                68, 78, 165, 245, 198, 53, 217, 107, 22, 63, 108, 44, 58, 173, 200, 24, 155, 250,
+                163, 68, 127, 203, 15, 71, 101, 123, 237, 98, 90, 106, 87, 12,
+            ],
+            [
+                25, 95, 63, 253, 145, 178, 139, 243, 234, 38, 71, 240, 160, 216, 231, 109, 56, 31,
+                138, 222, 89, 19, 80, 114, 51, 66, 158, 91, 186, 93, 104, 60,
+            ],
+        ],
+        [
+            [
+                196, 141, 133, 161, 19, 134, 145, 141, 70, 101, 37, 11, 239, 74, 28, 157, 85, 160,
+                142, 217, 3, 193, 228, 188, 236, 165, 132, 81, 146, 129, 221, 31,
+            ],
+            [
+                57, 81, 88, 194, 254, 61, 176, 34, 64, 131, 206, 79, 143, 217, 195, 46, 214, 170,
+                43, 124, 57, 78, 236, 19, 201, 223, 101, 169, 200, 109, 21, 34,
+            ],
+            [
+                65, 28, 244, 187, 146, 174, 232, 198, 204, 36, 235, 144, 228, 132, 39, 253, 96, 16,
+                109, 182, 216, 73, 209, 164, 96, 220, 228, 68, 232, 225, 68, 18,
+            ],
+            [
+                206, 141, 44, 169, 34, 155, 83, 254, 25, 183, 165, 3, 172, 57, 30, 12, 193, 8, 21,
+                187, 8, 127, 16, 48, 94, 205, 157, 17, 16, 101, 53, 30,
+            ],
+            [
+                221, 201, 5, 89, 9, 16, 73, 229, 207, 209, 238, 183, 215, 247, 242, 19, 204, 118,
+                203, 83, 175, 141, 85, 181, 139, 1, 113, 219, 45, 132, 188, 5,
+            ],
+            [
+                177, 104, 129, 150, 213, 181, 35, 112, 200, 238, 118, 249, 18, 181, 62, 189, 232,
+                46, 224, 62, 211, 187, 38, 160, 218, 68, 123, 65, 112, 44, 176, 44,
+            ],
+            [
+                8, 132, 151, 69, 100, 18, 105, 116, 90, 236, 124, 134, 126, 204, 177, 54, 56, 123,
+                89, 188, 149, 91, 107, 3, 114, 24, 26, 114, 187, 1, 86, 51,
+            ],
+            [
+                194, 200, 77, 157, 88, 72, 94, 223, 21, 196, 106, 3, 177, 153, 177, 45, 98, 249,
+                245, 166, 38, 139, 9, 116, 176, 239, 41, 47, 172, 84, 176, 14,
+            ],
+        ],
+        [
+            [
+                171, 209, 68, 157, 205, 47, 209, 205, 233, 210, 88, 111, 207, 209, 13, 157, 61,
+                131, 156, 127, 48, 120, 220, 190, 93, 211, 244, 56, 115, 229, 165, 59,
+            ],
+            [
+                241, 121, 215, 217, 94, 188, 64, 83, 135, 202, 92, 211, 207, 241, 144, 214, 186,
+                170, 4, 96, 190, 208, 171, 121, 132, 200, 197, 99, 12, 37, 111, 47,
+            ],
+            [
+                193, 224, 239, 225, 212, 241, 50, 224, 245, 65, 134, 225, 160, 96, 65, 156, 183,
+                34, 31, 80, 101, 139, 63, 84, 183, 212, 51, 148, 178, 253, 101, 45,
+            ],
+            [
+                133, 61, 35, 216, 66, 231, 101, 213, 94, 86, 48, 81, 87, 204, 25, 165, 219, 86,
+                231, 11, 107, 30, 75, 170, 1, 189, 241, 23, 176, 226, 168, 34,
+            ],
+            [
+                229, 181, 163, 150, 146, 183, 74, 103, 31, 171, 210, 118, 155, 72, 253, 96, 146,
+                231, 169, 112, 254, 65, 128, 64, 207, 59, 207, 29, 128, 62, 48, 61,
+            ],
+            [
+                141, 242, 88, 174, 190, 113, 141, 11, 72, 158, 76, 9, 216, 245, 166, 88, 106, 5,
+                255, 233, 141, 20, 32, 240, 56, 114, 13, 225, 219, 84, 166, 42,
+            ],
+            [
+                226, 10, 194, 155, 14, 166, 116, 67, 80, 201, 4, 44, 87, 244, 24, 180, 252, 68,
+                142, 38, 141, 160, 16, 30, 193, 8, 197, 198, 154, 113, 136, 17,
+            ],
+            [
+                252, 180, 104, 184, 231, 155, 98, 216, 28, 249, 214, 247, 13, 174, 129, 210, 102,
+                85, 188, 107, 61, 124, 80, 248, 198, 205, 130, 129, 5, 7, 73, 51,
+            ],
+        ],
+        [
+            [
+                32, 16, 180, 183, 28, 205, 115, 80, 124, 163, 42, 186, 44, 250, 157, 7, 134, 251,
+                240, 78, 198, 233, 112, 108, 38, 162, 9, 32, 85, 250, 195, 14,
+            ],
+            [
+                142, 85, 252, 11, 25, 19, 202, 170, 222, 83, 225, 1, 144, 4, 8, 124, 226, 212, 171,
+                90, 87, 112, 53, 68, 76, 207, 54, 235, 126, 251, 12, 3,
+            ],
+            [
+                142, 181, 243, 49, 217, 188, 138, 41, 84, 130, 51, 53, 54, 76, 44, 177, 145, 236,
+                104, 155, 220, 230, 46, 98, 202, 9, 95, 55, 213, 226, 132, 33,
+            ],
+            [
+                31, 204, 35, 5, 219, 49, 161, 42, 59, 62, 200, 231, 79, 191, 48, 233, 201, 148,
+                103, 115, 17, 78, 163, 28, 18, 194, 111, 158, 162, 125, 55, 63,
+            ],
+            [
+                5, 236, 214, 224, 106, 221, 73, 225, 137, 45, 177, 92, 199, 238, 249, 4, 44, 147,
+                252, 236, 39, 166, 165, 116, 183, 38, 3, 184, 32, 227, 143, 6,
+            ],
+            [
+                240, 35, 99, 189, 186, 82, 190, 31, 25, 131, 49, 78, 116, 49, 186, 67, 231, 53, 60,
+                248, 222, 29, 50, 25, 83, 201, 212, 31, 240, 110, 58, 57,
+            ],
+            [
+                143, 76, 51, 181, 209, 156, 207, 218, 27, 111, 229, 95, 24, 172, 89, 144, 12, 139,
+                251, 121, 217, 213, 125, 88, 199, 71, 248, 142, 184, 15, 211, 5,
+            ],
+            [
+                168, 53, 134, 201, 88, 189, 131, 60, 139, 195, 56, 100, 189, 103, 80, 113, 141,
+                190, 121, 252, 117, 127, 68, 162, 153, 107, 130, 204, 73, 122, 6, 23,
+            ],
+        ],
+        [
+            [
+                40, 161, 83, 96, 226, 250, 24, 84, 116, 116, 64, 85, 221, 147, 237, 96, 52, 69,
+                107, 163, 120, 97, 11, 47, 146, 193, 163, 178, 183, 129, 53, 19,
+            ],
+            [
+                194, 177, 200, 159, 170, 179, 132, 151, 80, 250, 147, 15, 46, 69, 159, 208, 154,
+                191, 225, 96, 100, 4, 111, 19, 143, 32, 6, 166, 162, 106, 226, 23,
+            ],
+            [
+                45, 206, 70, 152, 226, 39, 32, 122, 213, 60, 254, 143, 163, 156, 203, 236, 238, 88,
+                255, 77, 175, 133, 125, 234, 182, 67, 159, 255, 176, 39, 58, 26,
+            ],
+            [
+                72, 219, 149, 184, 216, 227, 16, 232, 96, 104, 99, 215, 228, 202, 42, 71, 179, 197,
+                244, 16, 8, 201, 90, 144, 56, 201, 27, 56, 223, 12, 232, 42,
+            ],
+            [
+                196, 125, 88, 102, 91, 46, 64, 37, 178, 46, 64, 172, 156, 58, 190, 203, 108, 109,
+                104, 252, 175, 137, 211, 2, 48, 213, 167, 27, 231, 235, 173, 18,
+            ],
+            [
+                92, 78, 232, 134, 27, 108, 143, 109, 4, 16, 19, 99, 248, 221, 160, 204, 234, 165,
+                231, 118, 238, 15, 109, 2, 81, 87, 117, 113, 120, 24, 204, 46,
+            ],
+            [
+                6, 121, 138, 149, 62, 33, 196, 141, 136, 255, 132, 138, 45, 113, 170, 242, 50, 160,
+                190, 200, 121, 153, 14, 95, 29, 81, 240, 113, 32, 252, 148, 46,
+            ],
+            [
+                27, 21, 34, 29, 67, 3, 73, 190, 143, 198, 158, 157, 102, 95, 178, 198, 76, 137,
+                132, 226, 34, 167, 115, 203, 47, 116, 188, 254, 196, 39, 107, 18,
+            ],
+        ],
+        [
+            [
+                86, 49, 56, 4, 192, 33, 223, 116, 133, 236, 43, 91, 204, 54, 251, 143, 33, 185, 14,
+                33, 139, 18, 130, 199, 53, 175, 56, 79, 104, 23, 16, 5,
+            ],
+            [
+                108, 109, 225, 248, 102, 195, 71, 239, 210, 131, 68, 92, 140, 244, 132, 217, 27,
+                36, 126, 246, 229, 184, 40, 253, 249, 33, 247, 109, 149, 170, 36, 23,
+            ],
+            [
+                196, 16, 39, 242, 132, 35, 185, 133, 185, 100, 138, 116, 181, 190, 16, 143, 31,
+                240, 10, 132, 119, 224, 100, 82, 255, 160, 42, 238, 107, 188, 61, 45,
+            ],
+            [
+                157, 88, 213, 17, 168, 146, 41, 252, 202, 240, 38, 58, 193, 103, 63, 73, 224, 4,
+                113, 53, 197, 1, 174, 142, 153, 46, 79, 15, 255, 131, 75, 38,
+            ],
+            [
+                246, 232, 125, 99, 43, 47, 33, 231, 14, 51, 20, 205, 230, 80, 179, 170, 198, 166,
+                228, 231, 164, 182, 64, 196, 97, 229, 233, 125, 119, 73, 74, 28,
+            ],
+            [
+                163, 207, 116, 154, 212, 147, 7, 4, 192, 107, 97, 34, 178, 29, 104, 174, 83, 57,
+                82, 102, 122, 203, 24, 144, 175, 15, 211, 17, 232, 93, 212, 23,
+            ],
+            [
+                105, 144, 170, 12, 69, 83, 141, 226, 58, 27, 102, 176, 232, 217, 241, 188, 153, 56,
+                31, 184, 189, 101, 23, 173, 157, 237, 221, 121, 74, 236, 142, 48,
+            ],
+            [
+                161, 183, 70, 208, 67, 0, 102, 88, 181, 236, 218, 40, 70, 203, 216, 214, 118, 178,
+                40, 241, 204, 192, 51, 197, 96, 8, 79, 117, 81, 35, 9, 55,
+            ],
+        ],
+        [
+            [
+                174, 59, 60, 100, 169, 66, 134, 129, 62, 70, 183, 146, 204, 110, 250, 1, 127, 131,
+                66, 104, 33, 154, 181, 144, 173, 184, 33, 218, 191, 243, 203, 20,
+            ],
+            [
+                149, 108, 34, 70, 76, 148, 207, 236, 140, 41, 160, 245, 177, 8, 120, 198, 160, 43,
+                146, 30, 128, 56, 144, 124, 39, 154, 206, 217, 246, 165, 27, 41,
+            ],
+            [
+                225, 100, 227, 228, 53, 159, 144, 62, 194, 216, 22, 166, 85, 156, 7, 171, 247, 7,
+                131, 66, 138, 66, 47, 153, 223, 156, 188, 205, 85, 103, 94, 45,
+            ],
+            [
+                131, 184, 184, 236, 94, 81, 253, 251, 95, 45, 250, 212, 170, 83, 117, 110, 121, 58,
+                226, 235, 56, 218, 176, 201, 30, 116, 175, 174, 186, 21, 26, 43,
+            ],
+            [
+                227, 69, 196, 134, 81, 37, 241, 182, 63, 93, 37, 220, 198, 123, 168, 154, 229, 105,
+                243, 141, 154, 123, 182, 17, 187, 254, 22, 32, 178, 77, 151, 15,
+            ],
+            [
+                75, 183, 205, 144, 249, 53, 243, 66, 75, 222, 32, 238, 252, 1, 226, 5, 81, 86, 199,
+                120, 122, 232, 81, 219, 186, 31, 243, 176, 220, 116, 132, 58,
+            ],
+            [
+                16, 56, 86, 26, 36, 250, 59, 71, 63, 128, 104, 80, 130, 174, 201, 62, 15, 98, 175,
+                24, 28, 38, 152, 79, 240, 139, 161, 215, 179, 99, 113, 54,
+            ],
+            [
+                65, 237, 210, 86, 146, 21, 40, 65, 241, 57, 53, 177, 11, 160, 242, 196, 70, 13,
+                224, 148, 119, 208, 207, 215, 84, 214, 146, 21, 237, 2, 8, 36,
+            ],
+        ],
+        [
+            [
+                194, 120, 51, 35, 251, 217, 253, 200, 85, 67, 248, 179, 243, 159, 104, 180, 163,
+                32, 195, 105, 108, 109, 121, 236, 114, 65, 18, 160, 159, 145, 3, 49,
+            ],
+            [
+                213, 17, 186, 208, 175, 165, 184, 34, 1, 218, 74, 55, 31, 185, 221, 36, 24, 26, 72,
+                238, 10, 84, 33, 253, 251, 125, 41, 30, 103, 8, 221, 60,
+            ],
+            [
+                201, 64, 218, 232, 203, 95, 97, 4, 87, 19, 96, 100, 219, 242, 114, 145, 129, 174,
+                77, 26, 226, 0, 59, 53, 251, 255, 203, 86, 184, 172, 28, 7,
+            ],
+            [
+                117, 236, 107, 61, 176, 46, 32, 68, 241, 117, 86, 161, 64, 132, 3, 68, 213, 80,
+                141, 133, 53, 210, 197, 62, 206, 95, 53, 189, 171, 160, 23, 36,
+            ],
+            [
+                52, 6, 55, 240, 199, 124, 10, 185, 131, 202, 67, 182, 71, 183, 249, 160, 80, 242,
+                159, 106, 36, 157, 139, 138, 116, 249, 237, 103, 145, 27, 35, 39,
+            ],
+            [
+                153, 133, 248, 230, 15, 87, 120, 98, 93, 42, 118, 174, 38, 155, 204, 181, 143, 246,
+                62, 87, 216, 77, 133, 144, 83, 245, 193, 231, 185, 168, 205, 12,
+            ],
+            [
+                184, 157, 203, 86, 59, 132, 61, 166, 189, 201, 192, 48, 235, 236, 196, 70, 154, 5,
+                39, 72, 14, 158, 73, 228, 150, 106, 168, 207, 96, 175, 57, 30,
+            ],
+            [
+                131, 216, 153, 208, 158, 226, 179, 204, 118, 53, 67, 227, 100, 189, 78, 241, 107,
+                58, 130, 137, 95, 166, 182, 1, 134, 214, 156, 88, 229, 226, 153, 39,
+            ],
+        ],
+        [
+            [
+                101, 206, 176, 186, 71, 40, 148, 9, 153, 89, 255, 143, 96, 182, 67, 158, 191, 27,
+                89, 205, 187, 229, 117, 62, 59, 4, 87, 109, 45, 158, 116, 47,
+            ],
+            [
+                211, 116, 251, 66, 203, 40, 77, 92, 234, 49, 171, 119, 168, 40, 143, 66, 29, 182,
+                108, 157, 133, 241, 79, 156, 94, 19, 227, 124, 13, 192, 209, 62,
+            ],
+            [
+                247, 100, 167, 58, 30, 181, 19, 129, 188, 43, 96, 54, 21, 216, 128, 65, 226, 238,
+                72, 71, 183, 124, 18, 235, 201, 43, 226, 179, 153, 35, 114, 52,
+            ],
+            [
+                229, 148, 16, 206, 45, 205, 145, 145, 185, 125, 168, 83, 118, 31, 95, 2, 217, 62,
+                179, 100, 175, 39, 197, 209, 221, 61, 154, 81, 114, 140, 47, 42,
+            ],
+            [
+                123, 136, 224, 70, 194, 9, 7, 43, 48, 73, 226, 140, 244, 142, 131, 197, 84, 182,
+                135, 183, 174, 193, 103, 197, 28, 228, 168, 19, 74, 223, 20, 42,
+            ],
+            [
+                19, 119, 139, 79, 102, 188, 21, 251, 57, 34, 107, 48, 61, 146, 168, 21, 244, 135,
+                245, 180, 5, 246, 182, 127, 80, 131, 156, 72, 80, 27, 249, 13,
+            ],
+            [
+                85, 23, 31, 124, 143, 162, 25, 223, 242, 149, 128, 4, 161, 125, 180, 80, 111, 173,
+                37, 31, 173, 48, 184, 108, 22, 168, 27, 63, 136, 159, 113, 53,
+            ],
+            [
+                187, 231, 154, 240, 59, 133, 130, 204, 83, 124, 2, 123, 24, 13, 109, 189, 15, 45,
+                217, 228, 130, 63, 39, 157, 150, 72, 172, 102, 90, 254, 155, 9,
+            ],
+        ],
+        [
+            [
+                252, 6, 71, 0, 9, 102, 160, 85, 44, 104, 221, 74, 152, 237, 116, 112, 97, 58, 139,
+                177, 218, 130, 209, 114, 35, 208, 108, 187, 19, 167, 71, 24,
+            ],
+            [
+                128, 196, 58, 158, 112, 225, 186, 56, 239, 143, 130, 152, 72, 179, 87, 157, 80,
+                181, 100, 123, 82, 12, 232, 6, 242, 148, 182, 63, 121, 92, 173, 20,
+            ],
+            [
+                163, 233, 37, 2, 223, 26, 2, 117, 177, 32, 136, 133, 160, 63, 243, 80, 220, 149,
+                166, 98, 160, 232, 252, 72, 216, 32, 36, 200, 123, 140, 26, 1,
+            ],
+            [
+                4, 136, 242, 130, 105, 224, 7, 186, 49, 46, 222, 202, 30, 200, 222, 93, 231, 253,
+                227, 246, 42, 50, 88, 128, 158, 2, 72, 82, 249, 178, 125, 43,
+            ],
+            [
+                197, 227, 226, 125, 28, 83, 157, 134, 83, 145, 21, 73, 167, 88, 112, 111, 135, 138,
+                136, 130, 57, 6, 7, 228, 109, 207, 87, 18, 188, 25, 219, 33,
+            ],
+            [
+                143, 178, 58, 74, 113, 81, 153, 240, 32, 156, 28, 209, 34, 191, 199, 82, 63, 64,
+                161, 84, 216, 187, 99, 167, 81, 64, 37, 170, 17, 99, 14, 3,
+            ],
+            [
+                225, 153, 180, 32, 151, 82, 228, 182, 3, 239, 6, 11, 93, 203, 211, 220, 64, 58,
+                150, 174, 134, 46, 99, 176, 59, 68, 206, 4, 5, 213, 99, 42,
+            ],
+            [
+                6, 237, 63, 152, 131, 243, 233, 103, 47, 124, 165, 229, 122, 188, 112, 2, 174, 135,
+                74, 127, 108, 48, 196, 47, 149, 179, 49, 235, 33, 90, 123, 46,
+            ],
+        ],
+        [
+            [
+                67, 240, 144, 192, 103, 155, 5, 81, 247, 51, 207, 155, 105, 48, 151, 213, 57, 220,
+                96, 132, 252, 132, 134, 206, 107, 191, 108, 21, 98, 132, 219, 51,
+            ],
+            [
+                164, 201, 144, 53, 22, 74, 70, 119, 203, 93, 160, 99, 224, 66, 130, 253, 128, 155,
+                228, 219, 92, 59, 158, 48, 168, 68, 189, 157, 211, 14, 37, 9,
+            ],
+            [
+                56, 110, 118, 208, 23, 47, 226, 161, 203, 170, 9, 98, 68, 78, 255, 24, 58, 255,
+                187, 194, 191, 19, 168, 116, 159, 227, 228, 33, 159, 56, 230, 22,
+            ],
+            [
+                227, 120, 72, 27, 152, 28, 34, 199, 0, 64, 185, 228, 74, 162, 203, 20, 13, 220,
+                169, 16, 36, 81, 79, 35, 233, 252, 197, 180, 11, 0, 169, 11,
+            ],
+            [
+                115, 89, 249, 255, 32, 16, 246, 208, 237, 186, 130, 43, 22, 118, 160, 116, 191,
+                184, 163, 58, 35, 24, 2, 119, 76, 251, 193, 161, 136, 205, 3, 12,
+            ],
+            [
+                47, 201, 203, 12, 140, 3, 224, 159, 42, 149, 181, 105, 225, 223, 120, 221, 246, 92,
+                236, 89, 146, 233, 2, 11, 118, 28, 253, 197, 53, 149, 94, 44,
+            ],
+            [
+                203, 60, 74, 134, 110, 222, 90, 215, 177, 134, 85, 79, 34, 28, 48, 100, 219, 81,
+                188, 252, 89, 173, 100, 78, 245, 29, 152, 31, 8, 253, 116, 13,
+            ],
+            [
+                158, 52, 169, 87, 246, 204, 221, 145, 243, 217, 238, 129, 34, 97, 178, 235, 158,
+                235, 22, 98, 160, 5, 186, 64, 201, 137, 17, 206, 181, 247, 51, 50,
+            ],
+        ],
+        [
+            [
+                102, 58, 112, 141, 153, 236, 162, 212, 189, 33, 153, 0, 35, 95, 153, 3, 74, 203,
+                125, 59, 83, 157, 185, 5, 152, 19, 239, 150, 96, 52, 73, 25,
+            ],
+            [
+                163, 204, 154, 142, 199, 48, 187, 147, 238, 33, 71, 142, 104, 183, 155, 253, 195,
+                108, 167, 101, 51, 190, 101, 49, 110, 116, 50, 155, 205, 191, 190, 59,
+            ],
+            [
+                26, 94, 42, 221, 38, 247, 1, 150, 223, 147, 255, 194, 197, 184, 98, 188, 249, 36,
+                38, 213, 131, 167, 171, 163, 196, 64, 39, 73, 104, 253, 33, 61,
+            ],
+            [
+                57, 131, 137, 151, 137, 69, 77, 65, 158, 253, 130, 239, 115, 235, 52, 223, 180, 6,
+                78, 142, 71, 70, 21, 146, 86, 189, 152, 215, 162, 28, 187, 12,
+            ],
+            [
+                79, 92, 168, 176, 5, 17, 51, 172, 172, 141, 26, 133, 129, 94, 192, 250, 235, 128,
+                34, 98, 83, 63, 218, 159, 223, 52, 20, 89, 242, 247, 214, 29,
+            ],
+            [
+                49, 51, 35, 149, 57, 65, 9, 229, 149, 168, 187, 127, 226, 223, 207, 195, 143, 72,
+                69, 202, 105, 91, 213, 94, 208, 236, 180, 69, 191, 148, 231, 55,
+            ],
+            [
+                67, 126, 230, 95, 163, 161, 150, 116, 57, 59, 48, 74, 236, 52, 145, 53, 244, 222,
+                199, 44, 13, 149, 68, 86, 162, 46, 68, 73, 118, 235, 75, 14,
+            ],
+            [
+                224, 207, 8, 118, 57, 57, 186, 35, 76, 46, 5, 247, 201, 93, 46, 44, 163, 78, 105,
+                64, 38, 134, 81, 129, 184, 198, 245, 5, 80, 231, 53, 52,
+            ],
+        ],
+        [
+            [
+                109, 150, 241, 154, 194, 34, 8, 136, 241, 227, 40, 182, 159, 58, 197, 51, 90, 133,
+                208, 182, 186, 11, 235, 115, 194, 229, 170, 158, 181, 42, 78, 52,
+            ],
+            [
+                33, 24, 204, 132, 68, 141, 41, 224, 11, 72, 5, 119, 49, 101, 67, 242, 187, 66, 20,
+                62, 1, 134, 224, 184, 131, 133, 141, 34, 4, 29, 137, 40,
+            ],
+            [
+                227, 98, 252, 169, 149, 126, 104, 63, 159, 202, 188, 17, 22, 212, 78, 170, 92, 241,
+                24, 137, 55, 90, 182, 53, 86, 145, 74, 218, 82, 114, 129, 31,
+            ],
+            [
+                57, 65, 48, 3, 141, 220, 137, 29, 170, 154, 133, 50, 1, 184, 105, 132, 104, 171,
+                111, 226, 255, 8, 207, 19, 101, 143, 99, 148, 75, 197, 103, 10,
+            ],
+            [
+                140, 207, 125, 162, 140, 82, 46, 139, 28, 98, 225, 169, 225, 228, 202, 28, 50, 58,
+                230, 30, 45, 199, 117, 48, 156, 106, 213, 37, 104, 14, 127, 40,
+            ],
+            [
+                245, 18, 250, 20, 156, 41, 134, 72, 24, 107, 135, 129, 86, 105, 25, 49, 32, 225,
+                78, 115, 165, 108, 52, 235, 219, 59, 120, 221, 83, 189, 166, 28,
+            ],
+            [
+                189, 63, 127, 192, 72, 76, 217, 7, 200, 12, 244, 254, 33, 49, 204, 219, 84, 109,
+                155, 79, 255, 222, 203, 147, 26, 207, 132, 203, 211, 249, 19, 12,
+            ],
+            [
+                6, 131, 242, 91, 151, 91, 50, 152, 114, 22, 216, 165, 48, 73, 10, 119, 116, 88, 81,
+                250, 135, 129, 209, 239, 180, 154, 29, 158, 239, 220, 178, 17,
+            ],
+        ],
+        [
+            [
+                88, 73, 188, 70, 116, 136, 162, 141, 77, 97, 135, 32, 241, 102, 104, 119, 57, 149,
+                159, 17, 1, 156, 176, 102, 188, 228, 144, 92, 63, 186, 124, 34,
+            ],
+            [
+                46, 238, 223, 4, 242, 194, 48, 19, 124, 54, 42, 143, 71, 228, 91, 93, 182, 38, 249,
+                90, 173, 14, 31, 5, 255, 92, 190, 26, 230, 60, 121, 31,
+            ],
+            [
+                201, 211, 48, 91, 19, 199, 240, 24, 86, 224, 100, 140, 97, 47, 112, 138, 32, 239,
+                149, 238, 35, 210, 106, 184, 135, 42, 86, 249, 113, 31, 76, 56,
+            ],
+            [
+                22, 248, 248, 100, 165, 217, 177, 167, 8, 169, 147, 228, 148, 2, 126, 146, 124,
+                226, 242, 248, 244, 11, 57, 190, 62, 162, 149, 195, 102, 10, 23, 44,
+            ],
+            [
+                122, 17, 32, 116, 174, 36, 79, 151, 133, 84, 197, 146, 130, 37, 124, 162, 100, 146,
+                208, 104, 44, 0, 208, 125, 2, 74, 94, 79, 20, 16, 55, 3,
+            ],
+            [
+                210, 239, 168, 108, 72, 149, 84, 159, 164, 122, 40, 76, 12, 223, 201, 228, 45, 29,
+                82, 200, 235, 167, 226, 79, 230, 54, 17, 188, 208, 74, 19, 63,
+            ],
+            [
+                80, 163, 237, 121, 74, 145, 86, 92, 188, 69, 132, 245, 152, 123, 44, 177, 217, 141,
+                19, 115, 12, 167, 163, 243, 210, 230, 26, 128, 173, 238, 236, 0,
+            ],
+            [
+                197, 59, 246, 195, 142, 114, 104, 235, 139, 133, 91, 74, 163, 166, 128, 190, 52,
+                142, 14, 142, 18, 121, 78, 29, 115, 66, 70, 173, 120, 203, 16, 17,
+            ],
+        ],
+        [
+            [
+                102, 133, 224, 213, 178, 161, 112, 35, 39, 164, 242, 176, 125, 40, 36, 112, 28,
+                226, 148, 254, 115, 82, 118, 142, 47, 182, 19, 65, 169, 47, 87, 48,
+            ],
+            [
+                212, 61, 253, 140, 167, 153, 239, 50, 55, 221, 148, 227, 204, 119, 172, 3, 80, 151,
+                141, 194, 170, 193, 243, 184, 126, 170, 161, 205, 131, 251, 119, 13,
+            ],
+            [
+                231, 73, 143, 13, 208, 127, 167, 158, 192, 16, 216, 26, 126, 158, 71, 62, 4, 25,
+                207, 235, 205, 57, 247, 171, 222, 72, 119, 14, 120, 96, 248, 2,
+            ],
+            [
+                191, 89, 66, 123, 233, 190, 157, 82, 253, 59, 21, 206, 207, 142, 105, 212, 29, 84,
+                91, 70, 98, 203, 81, 22, 241, 83, 151, 144, 20, 232, 84, 23,
+            ],
+            [
+                96, 190, 180, 109, 188, 23, 151, 128, 70, 90, 146, 184, 242, 54, 241, 229, 74, 23,
+                213, 27, 141, 162, 216, 177, 205, 108, 97, 191, 67, 42, 232, 58,
+            ],
+            [
+                60, 81, 232, 130, 192, 145, 63, 232, 67, 229, 105, 204, 194, 32, 82, 89, 52, 4, 8,
+                66, 91, 224, 101, 73, 26, 14, 227, 8, 151, 165, 198, 27,
+            ],
+            [
+                97, 9, 68, 72, 221, 118, 222, 185, 25, 140, 206, 83, 20, 239, 148, 5, 36, 193, 88,
+                178, 87, 225, 169, 252, 121, 157, 36, 34, 230, 7, 15, 11,
+            ],
+            [
+                112, 218, 127, 141, 72, 249, 85, 245, 64, 178, 120, 254, 18, 31, 149, 27, 212, 54,
+                18, 50, 217, 141, 91, 83, 80, 208, 233, 160, 231, 193, 92, 60,
+            ],
+        ],
+        [
+            [
+                30, 1, 137, 67, 169, 193, 160, 185, 16, 211, 228, 130, 105, 61, 220, 227, 38, 49,
+                74, 211, 171, 199, 179, 159, 1, 232, 218, 165, 125, 232, 100, 10,
+            ],
+            [
+                203, 135, 212, 158, 210, 169, 51, 12, 155, 164, 255, 216, 65, 125, 126, 31, 54, 41,
+                63, 202, 216, 148, 171, 123, 43, 190, 127, 97, 70, 187, 171, 3,
+            ],
+            [
+                209, 105, 200, 50, 249, 3, 148, 72, 3, 71, 49, 72, 90, 96, 239, 202, 205, 230, 104,
+                143, 181, 138, 114, 108, 237, 59, 145, 103, 15, 135, 104, 5,
+            ],
+            [
+                81, 66, 5, 217, 131, 226, 16, 159, 52, 14, 84, 25, 157, 202, 74, 74, 87, 81, 133,
+                245, 68, 89, 63, 162, 244, 47, 98, 147, 85, 34, 57, 28,
+            ],
+            [
+                136, 247, 15, 47, 247, 245, 254, 77, 43, 199, 151, 222, 56, 150, 11, 167, 69, 127,
+                224, 178, 233, 62, 21, 112, 120, 101, 201, 114, 130, 59, 202, 56,
+            ],
+            [
+                44, 231, 154, 12, 217, 152, 238, 4, 221, 91, 173, 96, 225, 17, 150, 51, 12, 55,
+                117, 192, 230, 5, 238, 149, 201, 209, 36, 217, 21, 232, 239, 26,
+            ],
+            [
+                128, 171, 108, 106, 131, 70, 105, 244, 218, 187, 3, 189, 17, 32, 173, 202, 37, 91,
+                241, 199, 174, 23, 156, 233, 182, 150, 237, 214, 23, 164, 142, 10,
+            ],
+            [
+                91, 173, 165, 190, 49, 107, 218, 23, 104, 153, 32, 98, 38, 12, 95, 106, 120, 254,
+                94, 56, 244, 91, 195, 40, 241, 131, 83, 97, 163, 99, 45, 6,
+            ],
+        ],
+        [
+            [
+                151, 47, 43, 253, 249, 129, 7, 55, 10, 124, 27, 156, 203, 126, 117, 82, 113, 88,
+                82, 23, 115, 186, 206, 179, 248, 50, 159, 76, 212, 201, 199, 38,
+            ],
+            [
+                57, 22, 148, 90, 90, 179, 154, 214, 85, 221, 89, 217, 65, 93, 200, 217, 103, 130,
+                117, 24, 50, 32, 47, 110, 90, 20, 17, 3, 183, 122, 22, 2,
+            ],
+            [
+                25, 244, 207, 98, 157, 20, 20, 84, 113, 232, 118, 135, 29, 243, 28, 194, 84, 89,
+                183, 87, 87, 64, 119, 254, 21, 154, 89, 208, 203, 28, 173, 62,
+            ],
+            [
+                235, 157, 54, 228, 90, 189, 131, 150, 191, 112, 106, 126, 137, 60, 188, 230, 158,
+                97, 177, 47, 179, 52, 207, 162, 37, 101, 71, 13, 139, 226, 27, 57,
+            ],
+            [
+                28, 79, 124, 244, 184, 162, 188, 194, 158, 74, 21, 129, 218, 81, 222, 52, 8, 15,
+                48, 77, 175, 64, 42, 223, 28, 159, 120, 172, 246, 35, 157, 28,
+            ],
+            [
+                28, 157, 162, 198, 241, 253, 218, 4, 32, 235, 190, 222, 20, 195, 34, 146, 201, 186,
+                235, 222, 250, 162, 129, 212, 31, 100, 34, 203, 79, 0, 180, 52,
+            ],
+            [
+                145, 170, 140, 27, 53, 183, 107, 69, 89, 101, 11, 162, 222, 49, 100, 158, 245, 250,
+                124, 164, 190, 144, 99, 151, 10, 223, 214, 162, 250, 94, 191, 54,
+            ],
+            [
+                206, 180, 56, 120, 182, 228, 132, 242, 78, 64, 93, 78, 206, 141, 6, 236, 205, 171,
+                202, 22, 19, 143, 100, 13, 83, 244, 11, 43, 40, 61, 178, 55,
+            ],
+        ],
+        [
+            [
+                112, 239, 159, 115, 1, 203, 16, 56, 88, 231, 212, 2, 2, 15, 83, 173, 160, 116, 79,
+                100, 191, 196, 195, 237, 70, 253, 26, 222, 103, 58, 138, 3,
+            ],
+            [
+                121, 29, 55, 255, 109, 245, 126, 59, 18, 170, 55, 208, 203, 49, 194, 128, 210, 4,
+                241, 1, 19, 253, 180, 76, 52, 191, 69, 228, 130, 202, 153, 35,
+            ],
+            [
+                129, 217, 209, 193, 67, 169, 60, 6, 180, 155, 70, 103, 50, 155, 183, 63, 88, 67,
+                251, 7, 81, 88, 35, 133, 2, 207, 68, 105, 52, 155, 245, 21,
+            ],
+            [
+                221, 68, 175, 79, 221, 210, 60, 172, 189, 255, 10, 81, 250, 51, 220, 92, 136, 191,
+                111, 159, 108, 29, 181, 43, 28, 151, 143, 202, 120, 125, 129, 3,
+            ],
+            [
+                32, 82, 59, 160, 79, 237, 129, 152, 104, 68, 218, 238, 0, 31, 87, 180, 157, 27,
+                212, 250, 24, 51, 198, 203, 99, 104, 129, 174, 15, 250, 223, 37,
+            ],
+            [
+                123, 107, 163, 229, 69, 46, 144, 190, 232, 14, 129, 130, 139, 184, 12, 96, 163,
+                121, 136, 125, 14, 27, 13, 26, 18, 42, 193, 15, 187, 207, 92, 22,
+            ],
+            [
+                13, 110, 164, 167, 26, 240, 169, 200, 13, 23, 18, 163, 73, 147, 184, 210, 70, 56,
+                141, 98, 242, 16, 162, 199, 105, 112, 82, 38, 37, 67, 108, 59,
+            ],
+            [
+                205, 155, 126, 252, 106, 214, 140, 146, 128, 168, 111, 254, 54, 135, 82, 62, 72,
+                241, 228, 74, 143, 208, 206, 12, 185, 25, 223, 56, 193, 107, 214, 29,
+            ],
+        ],
+        [
+            [
+                66, 53, 159, 159, 150, 194, 77, 153, 136, 60, 185, 95, 127, 136, 201, 206, 215, 15,
+                163, 149, 138, 219, 88, 98, 153, 202, 219, 114, 126, 11, 139, 17,
+            ],
+            [
+                32, 199, 234, 164, 98, 233, 159, 158, 6, 233, 111, 195, 74, 51, 95, 13, 45, 57,
+                231, 87, 154, 39, 171, 68, 89, 92, 207, 15, 12, 36, 0, 52,
+            ],
+            [
+                173, 81, 136, 190, 142, 211, 181, 119, 158, 159, 179, 100, 8, 128, 227, 48, 215,
+                112, 74, 225, 183, 136, 24, 5, 19, 8, 28, 244, 217, 138, 108, 44,
+            ],
+            [
+                148, 145, 227, 68, 124, 67, 101, 157, 12, 104, 185, 41, 232, 75, 33, 116, 122, 80,
+                158, 105, 30, 3, 126, 27, 95, 122, 225, 161, 148, 251, 204, 46,
+            ],
+            [
+                206, 132, 93, 244, 162, 160, 132, 209, 97, 70, 7, 140, 171, 31, 53, 29, 249, 177,
+                252, 247, 203, 214, 216, 87, 168, 25, 67, 41, 227, 115, 78, 43,
+            ],
+            [
+                247, 228, 37, 129, 30, 109, 14, 16, 156, 30, 101, 163, 157, 149, 255, 108, 135,
+                231, 233, 31, 210, 104, 49, 120, 186, 213, 91, 191, 203, 202, 105, 52,
+            ],
+            [
+                49, 210, 139, 8, 218, 21, 89, 160, 66, 69, 29, 75, 248, 88, 36, 112, 161, 185, 94,
+                92, 4, 114, 239, 241, 159, 218, 34, 144, 252, 63, 108, 22,
+            ],
+            [
+                254, 73, 231, 115, 231, 21, 193, 132, 99, 128, 79, 127, 4, 103, 240, 71, 109, 150,
+                218, 157, 74, 90, 20, 173, 207, 123, 255, 96, 215, 96, 203, 18,
+            ],
+        ],
+        [
+            [
+                58, 90, 139, 100, 247, 31, 71, 0, 208, 107, 100, 177, 100, 162, 168, 164, 234, 82,
+                52, 153, 7, 74, 205, 126, 159, 93, 36, 51, 173, 62, 129, 2,
+            ],
+            [
+                223, 193, 112, 144, 171, 86, 193, 75, 168, 148, 224, 164, 79, 147, 41, 237, 21,
+                115, 198, 90, 226, 15, 242, 151, 37, 164, 140, 139, 25, 71, 174, 23,
+            ],
+            [
+                19, 43, 184, 222, 119, 54, 5, 69, 157, 125, 33, 77, 75, 99, 50, 176, 29, 40, 183,
+                166, 63, 125, 112, 228, 234, 92, 159, 210, 232, 245, 93, 15,
+            ],
+            [
+                143, 175, 96, 97, 94, 43, 217, 206, 238, 177, 211, 5, 88, 233, 80, 188, 239, 162,
+                83, 232, 44, 212, 131, 191, 110, 131, 184, 61, 50, 154, 118, 61,
+            ],
+            [
+                181, 111, 207, 133, 186, 170, 93, 71, 55, 185, 197, 109, 38, 117, 123, 175, 49, 17,
+                136, 151, 155, 145, 34, 211, 176, 251, 90, 21, 198, 10, 162, 52,
+            ],
+            [
+                176, 5, 193, 188, 175, 185, 203, 29, 223, 173, 225, 231, 251, 68, 39, 153, 189,
+                158, 99, 47, 126, 146, 155, 78, 174, 183, 104, 255, 30, 87, 65, 2,
+            ],
+            [
+                40, 8, 147, 98, 221, 80, 233, 44, 96, 212, 167, 133, 160, 81, 174, 95, 160, 220,
+                174, 3, 67, 28, 221, 27, 26, 10, 65, 110, 99, 78, 81, 48,
+            ],
+            [
+                16, 105, 253, 74, 158, 67, 148, 60, 10, 216, 170, 25, 13, 167, 183, 203, 170, 212,
+                65, 159, 172, 106, 143, 62, 93, 238, 162, 179, 136, 172, 173, 1,
+            ],
+        ],
+        [
+            [
+                159, 91, 64, 167, 240, 118, 142, 55, 189, 30, 168, 165, 200, 131, 106, 158, 56,
+                113, 51, 184, 147, 244, 61, 82, 195, 122, 108, 35, 11, 100, 208, 6,
+            ],
+            [
+                13, 96, 51, 55, 144, 133, 72, 178, 23, 86, 14, 239, 87, 16, 62, 105, 44, 211, 36,
+                4, 106, 28, 249, 173, 96, 190, 132, 221, 174, 153, 26, 62,
+            ],
+            [
+                101, 220, 28, 9, 188, 248, 112, 177, 47, 234, 120, 158, 11, 117, 73, 91, 62, 26,
+                196, 52, 23, 113, 84, 107, 71, 6, 239, 204, 212, 45, 16, 49,
+            ],
+            [
+                80, 63, 141, 62, 203, 67, 126, 229, 165, 249, 173, 179, 230, 100, 131, 3, 106, 132,
+                157, 71, 234, 72, 65, 136, 25, 82, 204, 128, 139, 134, 244, 44,
+            ],
+            [
+                189, 54, 138, 68, 72, 160, 253, 8, 65, 130, 218, 246, 58, 93, 23, 104, 206, 19,
+                214, 77, 143, 184, 92, 42, 148, 182, 149, 211, 110, 127, 36, 46,
+            ],
+            [
+                115, 204, 87, 235, 130, 101, 44, 96, 105, 199, 33, 134, 6, 170, 1, 183, 49, 108,
+                61, 49, 102, 48, 39, 214, 204, 107, 158, 32, 198, 177, 41, 11,
+            ],
+            [
+                168, 197, 72, 77, 82, 121, 152, 199, 130, 91, 230, 121, 223, 242, 19, 175, 144, 38,
+                181, 160, 207, 224, 56, 123, 146, 139, 127, 219, 71, 38, 123, 56,
+            ],
+            [
+                23, 121, 1, 40, 9, 72, 236, 242, 108, 15, 148, 62, 249, 147, 70, 194, 188, 103, 93,
+                40, 218, 19, 175, 167, 90, 235, 33, 51, 51, 246, 81, 41,
+            ],
+        ],
+        [
+            [
+                209, 23, 50, 47, 171, 20, 100, 1, 75, 38, 169, 106, 163, 143, 73, 87, 118, 103, 16,
+                172, 176, 174, 134, 161, 240, 62, 103, 120, 106, 167, 196, 24,
+            ],
+            [
+                87, 26, 170, 39, 112, 184, 20, 20, 240, 182, 54, 159, 246, 36, 106, 18, 203, 190,
+                197, 10, 231, 235, 143, 3, 168, 226, 45, 131, 211, 109, 147, 20,
+            ],
+            [
+                48, 142, 149, 227, 87, 99, 209, 132, 227, 228, 229, 246, 171, 176, 215, 34, 133,
+                232, 79, 216, 9, 251, 169, 224, 104, 90, 19, 137, 203, 193, 207, 30,
+            ],
+            [
+                82, 36, 232, 169, 35, 5, 0, 34, 67, 215, 159, 190, 255, 205, 10, 66, 36, 192, 246,
+                115, 82, 92, 190, 76, 149, 63, 140, 11, 221, 91, 100, 9,
+            ],
+            [
+                101, 136, 121, 161, 255, 175, 194, 142, 60, 183, 193, 129, 0, 24, 29, 13, 22, 103,
+                173, 75, 44, 205, 46, 209, 103, 0, 63, 228, 107, 147, 183, 20,
+            ],
+            [
+                29, 169, 76, 51, 215, 207, 118, 117, 202, 216, 217, 147, 166, 255, 131, 74, 153,
+                45, 29, 120, 15, 38, 112, 73, 205, 85, 226, 44, 127, 192, 206, 38,
+            ],
+            [
+                133, 190, 65, 126, 97, 116, 160, 246, 240, 205, 40, 103, 228, 203, 179, 21, 106,
+                24, 183, 17, 172, 208, 184, 173, 126, 111, 163, 243, 66, 77, 40, 59,
+            ],
+            [
+                207, 8, 35, 128, 182, 161, 161, 144, 37, 156, 229, 204, 194, 180, 85, 101, 234,
+                179, 40, 200, 195, 164, 86, 34, 229, 85, 171, 216, 15, 81, 92, 14,
+            ],
+        ],
+        [
+            [
+                198, 52, 251, 79, 132, 123, 246, 11, 88, 92, 129, 88, 112, 128, 188, 170, 136, 181,
+                98, 219, 171, 183, 217, 225, 150, 173, 149, 108, 148, 175, 172, 48,
+            ],
+            [
+                110, 174, 149, 188, 190, 217, 102, 41, 236, 50, 58, 99, 127, 100, 105, 49, 179, 96,
+                238, 98, 13, 100, 205, 81, 216, 55, 233, 230, 199, 208, 34, 15,
+            ],
+            [
+                173, 89, 72, 65, 131, 169, 92, 0, 126, 116, 224, 251, 7, 153, 151, 23, 121, 10, 20,
+                210, 92, 69, 81, 48, 146, 122, 125, 22, 87, 126, 187, 21,
+            ],
+            [
+                32, 163, 94, 156, 160, 77, 7, 8, 136, 213, 143, 201, 8, 112, 102, 176, 108, 136,
+                112, 173, 248, 186, 6, 235, 47, 95, 187, 47, 115, 15, 168, 51,
+            ],
+            [
+                135, 175, 75, 250, 67, 182, 200, 65, 35, 251, 82, 251, 235, 90, 66, 122, 130, 15,
+                120, 192, 171, 106, 167, 130, 228, 205, 214, 48, 217, 38, 139, 5,
+            ],
+            [
+                154, 72, 53, 19, 96, 44, 119, 113, 69, 67, 183, 211, 171, 221, 132, 228, 32, 166,
+                204, 116, 62, 51, 185, 217, 201, 168, 116, 63, 188, 40, 166, 15,
+            ],
+            [
+                77, 122, 184, 25, 189, 114, 208, 214, 79, 152, 10, 163, 162, 26, 229, 16, 241, 152,
+                38, 79, 202, 122, 44, 50, 46, 236, 71, 73, 205, 45, 88, 48,
+            ],
+            [
+                45, 65, 217, 157, 162, 228, 0, 174, 159, 168, 229, 243, 20, 145, 247, 14, 113, 162,
+                97, 14, 95, 185, 248, 216, 111, 168, 98, 84, 56, 22, 70, 2,
+            ],
+        ],
+        [
+            [
+                9, 216, 223, 61, 126, 252, 187, 0, 59, 44, 149, 178, 220, 97, 194, 188, 162, 14,
+                60, 253, 205, 118, 80, 139, 114, 42, 167, 237, 201, 167, 30, 9,
+            ],
+            [
+                223, 20, 137, 245, 133, 49, 63, 179, 241, 4, 148, 72, 102, 23, 21, 156, 75, 170,
+                132, 87, 160, 145, 46, 216, 236, 219, 206, 221, 213, 245, 213, 48,
+            ],
+            [
+                133, 240, 156, 52, 28, 6, 53, 138, 25, 182, 216, 145, 5, 240, 176, 111, 52, 160,
+                95, 135, 238, 158, 176, 183, 234, 49, 103, 24, 178, 181, 223, 59,
+            ],
+            [
+                68, 15, 236, 136, 116, 6, 57, 87, 89, 112, 230, 73, 244, 66, 129, 21, 73, 84, 50,
+                39, 59, 38, 0, 92, 81, 9, 254, 101, 241, 124, 4, 5,
+            ],
+            [
+                46, 148, 59, 121, 101, 157, 198, 21, 71, 52, 226, 26, 89, 105, 197, 94, 158, 236,
+                149, 124, 4, 190, 78, 238, 217, 27, 99, 3, 34, 71, 7, 59,
+            ],
+            [
+                232, 152, 59, 240, 38, 117, 92, 90, 37, 84, 203, 0, 14, 181, 152, 199, 198, 146,
+                112, 231, 205, 199, 157, 94, 109, 244, 241, 38, 42, 100, 94, 0,
+            ],
+            [
+                231, 237, 7, 22, 209, 134, 192, 15, 225, 60, 249, 28, 241, 28, 69, 237, 224, 122,
+                218, 216, 142, 239, 110, 154, 152, 253, 152, 145, 19, 222, 129, 40,
+            ],
+            [
+                85, 136, 177, 154, 145, 49, 150, 192, 114, 192, 158, 215, 68, 235, 222, 219, 225,
+                30, 121, 68, 124, 37, 38, 59, 219, 8, 244, 82, 25, 64, 108, 21,
+            ],
+        ],
+        [
+            [
+                37, 199, 224, 185, 116, 94, 119, 45, 179, 67, 201, 13, 238, 50, 198, 80, 65, 92,
+                19, 2, 3, 3, 30, 211, 29, 101, 17, 15, 208, 167, 192, 16,
+            ],
+            [
+                131, 213, 71, 158, 206, 126, 185, 132, 133, 43, 85, 203, 163, 122, 240, 165, 28,
+                40, 216, 211, 223, 6, 10, 73, 137, 1, 53, 121, 164, 55, 231, 56,
+            ],
+            [
+                232, 175, 64, 139, 216, 72, 250, 135, 164, 139, 115, 196, 205, 25, 153, 100, 144,
+                67, 214, 165, 96, 52, 110, 211, 117, 122, 147, 14, 31, 190, 46, 34,
+            ],
+            [
+                111, 142, 207, 45, 134, 22, 129, 124, 92, 16, 173, 2, 93, 66, 44, 124, 68, 194, 85,
+                199, 45, 152, 240, 89, 191, 204, 227, 159, 237, 235, 225, 8,
+            ],
+            [
+                115, 173, 122, 244, 25, 228, 24, 194, 161, 253, 82, 106, 145, 235, 46, 24, 115,
+                138, 222, 64, 67, 79, 154, 165, 54, 184, 24, 222, 65, 212, 17, 38,
+            ],
+            [
+                13, 133, 77, 193, 140, 152, 220, 210, 20, 215, 48, 186, 141, 239, 199, 153, 96,
+                157, 124, 152, 178, 99, 228, 144, 139, 126, 171, 41, 73, 90, 247, 35,
+            ],
+            [
+                4, 207, 181, 53, 22, 32, 123, 114, 44, 223, 107, 227, 32, 4, 223, 151, 182, 214,
+                147, 123, 150, 20, 209, 221, 103, 206, 2, 183, 89, 0, 57, 1,
+            ],
+            [
+                83, 64, 147, 139, 61, 254, 254, 230, 117, 255, 223, 117, 35, 33, 145, 104, 130,
+                160, 14, 11, 227, 107, 74, 135, 250, 71, 118, 178, 96, 81, 132, 12,
+            ],
+        ],
+        [
+            [
+                240, 2, 233, 71, 201, 88, 160, 19, 40, 90, 223, 66, 224, 89, 185, 57, 173, 131,
+                158, 91, 123, 188, 62, 125, 147, 104, 1, 54, 48, 2, 215, 18,
+            ],
+            [
+                196, 23, 47, 183, 38, 250, 51, 103, 127, 172, 189, 248, 208, 88, 250, 242, 82, 5,
+                52, 110, 32, 8, 190, 77, 255, 91, 49, 149, 207, 80, 253, 4,
+            ],
+            [
+                13, 218, 71, 214, 24, 228, 72, 236, 62, 65, 235, 168, 255, 15, 87, 120, 3, 185, 39,
+                86, 10, 74, 245, 97, 219, 135, 88, 61, 84, 251, 230, 12,
+            ],
+            [
+                130, 119, 5, 25, 67, 225, 240, 177, 244, 189, 159, 237, 20, 103, 74, 111, 228, 245,
+                48, 4, 245, 224, 219, 119, 99, 157, 208, 155, 237, 95, 70, 11,
+            ],
+            [
+                180, 223, 154, 136, 139, 121, 153, 236, 233, 51, 184, 6, 169, 16, 41, 188, 105,
+                210, 56, 29, 161, 34, 73, 9, 11, 63, 126, 16, 29, 246, 92, 43,
+            ],
+            [
+                179, 177, 233, 178, 202, 72, 27, 213, 204, 150, 156, 198, 242, 225, 62, 244, 179,
+                85, 150, 7, 68, 189, 154, 147, 201, 235, 130, 225, 99, 102, 156, 19,
+            ],
+            [
+                30, 69, 55, 64, 249, 171, 255, 108, 38, 86, 103, 202, 123, 173, 174, 124, 7, 26,
+                147, 9, 3, 34, 115, 236, 110, 82, 175, 247, 165, 222, 15, 61,
+            ],
+            [
+                10, 143, 58, 2, 187, 53, 238, 98, 84, 225, 172, 152, 158, 12, 79, 239, 55, 203,
+                155, 10, 90, 192, 116, 13, 208, 100, 201, 31, 195, 135, 109, 2,
+            ],
+        ],
+        [
+            [
+                215, 85, 90, 108, 7, 210, 175, 188, 14, 19, 30, 95, 177, 85, 149, 188, 4, 213, 95,
+                210, 92, 58, 213, 181, 14, 229, 80, 73, 247, 82, 74, 55,
+            ],
+            [
+                45, 228, 199, 130, 153, 157, 58, 102, 89, 227, 133, 26, 196, 16, 103, 128, 32, 63,
+                176, 150, 198, 182, 254, 185, 131, 55, 126, 68, 24, 76, 103, 47,
+            ],
+            [
+                110, 245, 18, 134, 136, 41, 34, 112, 149, 138, 240, 233, 232, 103, 57, 176, 41, 19,
+                48, 112, 108, 228, 62, 184, 104, 222, 41, 150, 212, 69, 24, 45,
+            ],
+            [
+                254, 195, 120, 144, 97, 148, 121, 228, 221, 26, 124, 141, 16, 113, 164, 216, 198,
+                118, 140, 196, 30, 232, 108, 34, 59, 77, 154, 242, 146, 75, 33, 57,
+            ],
+            [
+                53, 63, 242, 24, 154, 126, 49, 163, 65, 120, 215, 17, 184, 50, 1, 123, 84, 75, 24,
+                170, 97, 241, 52, 107, 158, 115, 96, 244, 218, 30, 55, 18,
+            ],
+            [
+                79, 121, 152, 22, 176, 184, 102, 196, 9, 52, 63, 232, 30, 73, 244, 182, 67, 146,
+                96, 76, 158, 169, 40, 20, 122, 32, 20, 232, 53, 135, 131, 5,
+            ],
+            [
+                103, 216, 220, 67, 10, 65, 12, 101, 141, 57, 118, 153, 215, 16, 90, 35, 28, 67,
+                220, 212, 161, 196, 11, 29, 254, 6, 110, 138, 64, 231, 41, 20,
+            ],
+            [
+                20, 151, 253, 172, 230, 107, 219, 227, 213, 166, 22, 67, 162, 201, 148, 132, 143,
+                171, 138, 120, 122, 190, 33, 161, 203, 51, 223, 33, 79, 40, 0, 2,
+            ],
+        ],
+        [
+            [
+                28, 69, 127, 149, 98, 167, 252, 178, 52, 56, 122, 9, 4, 135, 38, 217, 222, 242,
+                169, 229, 80, 149, 235, 31, 48, 220, 212, 192, 97, 249, 170, 63,
+            ],
+            [
+                78, 174, 13, 78, 115, 181, 191, 185, 9, 24, 201, 164, 252, 5, 58, 129, 176, 5, 79,
+                66, 58, 203, 126, 125, 10, 142, 244, 30, 174, 152, 185, 24,
+            ],
+            [
+                187, 50, 253, 133, 143, 36, 195, 175, 195, 55, 248, 92, 41, 79, 249, 21, 14, 20,
+                229, 53, 130, 123, 3, 233, 23, 209, 32, 48, 106, 164, 146, 32,
+            ],
+            [
+                11, 254, 90, 12, 61, 16, 23, 69, 156, 39, 14, 178, 184, 99, 88, 32, 179, 169, 212,
+                35, 62, 154, 214, 1, 178, 248, 20, 145, 220, 255, 238, 59,
+            ],
+            [
+                74, 197, 79, 3, 153, 130, 160, 111, 245, 91, 217, 110, 150, 214, 184, 64, 112, 92,
+                84, 70, 196, 111, 137, 216, 216, 128, 215, 172, 140, 250, 40, 33,
+            ],
+            [
+                219, 31, 67, 31, 143, 102, 139, 65, 222, 138, 173, 163, 133, 137, 54, 17, 58, 5,
+                222, 91, 246, 17, 137, 179, 183, 109, 78, 74, 133, 176, 133, 63,
+            ],
+            [
+                113, 183, 60, 95, 234, 64, 255, 206, 172, 137, 214, 119, 169, 91, 203, 199, 52,
+                194, 82, 112, 229, 191, 14, 43, 27, 58, 63, 97, 204, 144, 60, 61,
+            ],
+            [
+                231, 220, 87, 28, 22, 52, 64, 221, 103, 73, 139, 43, 185, 6, 247, 121, 148, 67, 79,
+                7, 111, 145, 204, 26, 92, 31, 219, 238, 34, 222, 31, 35,
+            ],
+        ],
+        [
+            [
+                176, 110, 19, 41, 59, 235, 31, 22, 99, 158, 89, 95, 191, 139, 87, 221, 135, 15,
+                136, 97, 100, 222, 15, 3, 113, 90, 61, 98, 67, 2, 160, 16,
+            ],
+            [
+                41, 120, 166, 168, 125, 180, 159, 242, 193, 97, 103, 251, 114, 8, 36, 37, 167, 135,
+                140, 59, 129, 14, 184, 57, 242, 75, 149, 219, 17, 89, 190, 1,
+            ],
+            [
+                129, 149, 74, 95, 131, 133, 86, 44, 26, 8, 251, 39, 55, 78, 248, 78, 237, 120, 33,
+                176, 245, 4, 78, 144, 160, 218, 159, 126, 63, 140, 153, 39,
+            ],
+            [
+                98, 21, 20, 38, 212, 159, 97, 225, 69, 164, 123, 250, 215, 143, 164, 232, 242, 171,
+                187, 219, 35, 25, 88, 107, 239, 163, 188, 176, 62, 220, 156, 19,
+            ],
+            [
+                161, 5, 18, 186, 218, 158, 190, 158, 244, 172, 8, 242, 206, 135, 48, 7, 81, 210,
+                40, 143, 127, 79, 239, 57, 55, 206, 15, 44, 105, 88, 148, 49,
+            ],
+            [
+                23, 203, 206, 211, 127, 90, 239, 78, 88, 41, 211, 19, 63, 121, 26, 11, 131, 135,
+                85, 76, 227, 104, 38, 41, 236, 33, 43, 220, 29, 217, 24, 63,
+            ],
+            [
+                159, 111, 106, 239, 155, 122, 110, 6, 135, 177, 209, 178, 81, 17, 28, 173, 38, 144,
+                96, 75, 156, 66, 202, 73, 156, 121, 235, 87, 113, 125, 46, 54,
+            ],
+            [
+                178, 48, 27, 218, 80, 135, 197, 167, 176, 65, 67, 77, 128, 132, 219, 186, 152, 101,
+                10, 144, 200, 56, 213, 121, 199, 117, 88, 135, 106, 140, 4, 33,
+            ],
+        ],
+        [
+            [
+                52, 177, 36, 225, 182, 235, 40, 16, 114, 61, 140, 61, 26, 27, 223, 97, 44, 147,
+                202, 103, 200, 200, 181, 69, 195, 121, 247, 59, 214, 205, 174, 59,
+            ],
+            [
+                0, 16, 186, 10, 9, 50, 249, 26, 200, 62, 126, 63, 252, 58, 39, 28, 49, 246, 225,
+                252, 27, 163, 202, 82, 195, 61, 75, 162, 156, 89, 112, 2,
+            ],
+            [
+                182, 140, 152, 191, 29, 156, 25, 72, 151, 165, 107, 51, 211, 128, 119, 91, 26, 7,
+                90, 34, 72, 155, 156, 75, 121, 124, 228, 206, 18, 153, 44, 51,
+            ],
+            [
+                139, 34, 34, 92, 195, 61, 89, 97, 2, 120, 84, 199, 28, 15, 88, 44, 117, 73, 122,
+                252, 64, 179, 47, 190, 14, 203, 185, 25, 175, 160, 243, 40,
+            ],
+            [
+                233, 29, 138, 135, 162, 75, 216, 78, 119, 124, 77, 165, 190, 34, 94, 6, 26, 151,
+                107, 208, 208, 74, 1, 22, 114, 54, 160, 38, 163, 63, 7, 62,
+            ],
+            [
+                109, 191, 93, 71, 138, 99, 54, 37, 7, 160, 219, 193, 222, 16, 245, 159, 89, 130,
+                74, 81, 39, 66, 255, 64, 170, 161, 37, 121, 54, 224, 67, 6,
+            ],
+            [
+                10, 148, 102, 60, 171, 86, 199, 155, 91, 65, 105, 117, 193, 99, 85, 145, 197, 8,
+                250, 40, 50, 232, 205, 230, 170, 92, 214, 47, 17, 85, 6, 34,
+            ],
+            [
+                135, 83, 225, 73, 207, 32, 61, 169, 186, 114, 113, 248, 95, 51, 112, 74, 241, 15,
+                151, 242, 206, 221, 250, 2, 2, 205, 185, 141, 106, 144, 28, 63,
+            ],
+        ],
+        [
+            [
+                108, 133, 54, 93, 118, 200, 23, 57, 17, 233, 33, 143, 249, 5, 95, 198, 172, 198,
+                29, 44, 161, 156, 171, 163, 83, 27, 1, 91, 114, 7, 132, 33,
+            ],
+            [
+                67, 239, 127, 56, 156, 107, 61, 227, 205, 218, 77, 1, 37, 60, 9, 39, 187, 29, 56,
+                141, 152, 188, 223, 52, 245, 164, 49, 161, 27, 159, 161, 50,
+            ],
+            [
+                29, 5, 216, 60, 110, 66, 98, 16, 200, 215, 243, 168, 55, 66, 94, 84, 1, 64, 204,
+                58, 164, 233, 122, 64, 163, 132, 120, 202, 41, 200, 206, 19,
+            ],
+            [
+                64, 169, 33, 181, 159, 177, 206, 18, 95, 99, 52, 184, 231, 55, 38, 153, 13, 99, 6,
+                233, 239, 21, 188, 100, 21, 103, 239, 66, 88, 167, 160, 3,
+            ],
+            [
+                97, 174, 39, 211, 11, 89, 104, 111, 224, 145, 68, 179, 243, 207, 173, 14, 45, 231,
+                158, 196, 209, 58, 25, 127, 72, 63, 189, 233, 89, 14, 56, 39,
+            ],
+            [
+                193, 172, 75, 233, 165, 209, 197, 124, 171, 46, 144, 113, 208, 225, 93, 21, 73,
+                220, 75, 62, 96, 161, 187, 180, 31, 122, 67, 221, 116, 220, 94, 48,
+            ],
+            [
+                230, 16, 48, 128, 22, 84, 43, 33, 213, 47, 216, 230, 69, 86, 4, 0, 231, 220, 220,
+                67, 134, 144, 200, 124, 29, 64, 203, 31, 198, 207, 17, 50,
+            ],
+            [
+                216, 162, 66, 190, 208, 241, 223, 15, 41, 159, 227, 246, 150, 41, 102, 208, 159,
+                80, 61, 196, 164, 219, 1, 201, 169, 155, 55, 146, 144, 170, 46, 33,
+            ],
+        ],
+        [
+            [
+                195, 193, 59, 179, 164, 118, 125, 143, 71, 78, 44, 131, 193, 0, 118, 16, 244, 83,
+                212, 28, 239, 219, 235, 118, 238, 101, 110, 42, 216, 223, 228, 34,
+            ],
+            [
+                252, 117, 115, 218, 28, 220, 5, 13, 245, 148, 183, 125, 165, 248, 8, 206, 176, 252,
+                243, 55, 204, 64, 210, 4, 9, 199, 150, 107, 39, 158, 109, 6,
+            ],
+            [
+                46, 198, 2, 249, 98, 89, 185, 197, 50, 218, 229, 13, 163, 109, 198, 235, 206, 221,
+                84, 50, 148, 230, 106, 196, 161, 91, 150, 161, 12, 227, 184, 48,
+            ],
+            [
+                240, 204, 23, 206, 82, 6, 161, 121, 38, 110, 78, 84, 105, 128, 14, 97, 118, 150,
+                53, 59, 143, 62, 41, 42, 1, 173, 242, 233, 140, 174, 96, 43,
+            ],
+            [
+                47, 54, 112, 168, 137, 1, 52, 122, 69, 76, 136, 140, 62, 34, 62, 42, 56, 166, 145,
+                65, 71, 158, 179, 34, 34, 30, 165, 36, 191, 68, 137, 57,
+            ],
+            [
+                230, 134, 114, 53, 240, 36, 28, 253, 86, 249, 43, 126, 101, 104, 96, 58, 36, 202,
+                16, 172, 21, 119, 235, 48, 209, 235, 33, 153, 222, 70, 222, 37,
+            ],
+            [
+                215, 167, 152, 58, 85, 155, 142, 215, 195, 210, 37, 108, 254, 154, 36, 76, 175, 49,
+                169, 96, 253, 145, 20, 96, 108, 193, 47, 112, 164, 176, 108, 3,
+            ],
+            [
+                70, 14, 102, 32, 61, 133, 19, 105, 156, 197, 31, 162, 121, 190, 75, 148, 101, 131,
+                160, 135, 249, 135, 107, 245, 129, 168, 189, 85, 123, 160, 7, 51,
+            ],
+        ],
+        [
+            [
+                72, 184, 100, 162, 117, 135, 157, 251, 142, 248, 154, 20, 66, 208, 132, 95, 222,
+                71, 144, 183, 217, 92, 89, 166, 1, 47, 21, 162, 51, 220, 74, 24,
+            ],
+            [
+                131, 114, 48, 133, 217, 177, 0, 196, 54, 12, 223, 233, 213, 134, 216, 74, 118, 134,
+                226, 93, 27, 178, 10, 93, 242, 178, 120, 203, 129, 103, 1, 23,
+            ],
+            [
+                237, 209, 146, 29, 117, 69, 204, 221, 102, 104, 240, 12, 26, 183, 23, 218, 36, 191,
+                226, 100, 239, 53, 245, 207, 57, 115, 31, 75, 232, 156, 177, 58,
+            ],
+            [
+                44, 215, 47, 246, 66, 251, 138, 236, 139, 174, 236, 13, 202, 237, 1, 218, 212, 80,
+                248, 191, 242, 162, 147, 73, 76, 57, 131, 170, 151, 47, 130, 8,
+            ],
+            [
+                166, 62, 213, 211, 194, 112, 51, 158, 194, 55, 145, 79, 32, 64, 11, 90, 32, 131,
+                104, 3, 164, 121, 118, 0, 228, 179, 167, 42, 4, 91, 210, 31,
+            ],
+            [
+                66, 134, 35, 146, 27, 12, 116, 249, 225, 125, 149, 75, 65, 41, 3, 188, 157, 126,
+                59, 124, 175, 185, 9, 197, 239, 216, 121, 209, 87, 77, 169, 6,
+            ],
+            [
+                33, 114, 196, 148, 245, 51, 32, 136, 32, 83, 237, 192, 67, 2, 46, 109, 216, 166,
+                75, 177, 223, 221, 170, 62, 62, 168, 57, 13, 138, 163, 230, 30,
+            ],
+            [
+                73, 81, 218, 253, 111, 170, 91, 178, 93, 127, 234, 140, 5, 120, 241, 136, 208, 119,
+                89, 109, 146, 32, 42, 202, 82, 19, 180, 204, 70, 17, 5, 38,
+            ],
+        ],
+        [
+            [
+                68, 39, 131, 41, 114, 243, 5, 20, 143, 3, 59, 185, 245, 25, 47, 219, 91, 99, 78,
+                174, 75, 244, 123, 249, 74, 215, 68, 49, 105, 177, 254, 41,
+            ],
+            [
+                62, 157, 28, 202, 252, 120, 169, 252, 98, 232, 210, 49, 190, 145, 205, 167, 125,
+                63, 214, 230, 194, 143, 37, 142, 168, 11, 58, 11, 189, 38, 9, 50,
+            ],
+            [
+                43, 162, 88, 234, 182, 234, 126, 102, 166, 0, 247, 227, 34, 92, 75, 89, 211, 112,
+                249, 71, 90, 133, 170, 240, 12, 198, 227, 198, 206, 63, 64, 21,
+            ],
+            [
+                190, 61, 239, 225, 9, 177, 175, 58, 209, 2, 122, 17, 142, 26, 101, 105, 214, 132,
+                118, 183, 18, 190, 212, 132, 117, 22, 242, 211, 235, 158, 73, 53,
+            ],
+            [
+                201, 16, 89, 51, 89, 36, 252, 17, 235, 215, 255, 191, 132, 92, 163, 225, 128, 208,
+                84, 231, 193, 240, 213, 149, 42, 157, 69, 221, 47, 80, 113, 13,
+            ],
+            [
+                206, 216, 117, 212, 9, 123, 64, 173, 92, 115, 64, 194, 250, 196, 95, 242, 236, 18,
+                4, 44, 84, 205, 31, 78, 195, 192, 170, 145, 162, 99, 112, 22,
+            ],
+            [
+                56, 17, 215, 255, 41, 174, 91, 110, 60, 213, 68, 35, 176, 110, 61, 73, 6, 122, 243,
+                99, 99, 209, 82, 252, 26, 235, 142, 43, 235, 201, 134, 6,
+            ],
+            [
+                153, 141, 127, 182, 129, 186, 12, 55, 243, 168, 135, 76, 250, 127, 122, 162, 56,
+                82, 203, 242, 19, 251, 34, 221, 131, 166, 36, 96, 2, 232, 223, 20,
+            ],
+        ],
+    ],
+];
+
+pub fn generator(index: usize) -> pallas::Affine {
+    pallas::Affine::from_xy(
+        pallas::Base::from_repr(GENERATORS[index].0).unwrap(),
+        pallas::Base::from_repr(GENERATORS[index].1).unwrap(),
+    )
+    .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::{vector_commitment_v_bytes, BOARD_COMMITMENT_PERSONALIZATION};
+    use super::*;
+    use halo2_gadgets::ecc::chip::constants::{test_lagrange_coeffs, test_zs_and_us};
+    use halo2_proofs::pasta::{
+        arithmetic::{CurveAffine, CurveExt},
+        group::Curve,
+        pallas,
+    };
+
+    #[test]
+    fn generator() {
+        let hasher = pallas::Point::hash_to_curve(BOARD_COMMITMENT_PERSONALIZATION);
+        for (index, generator) in GENERATORS.iter().enumerate() {
+            let point = hasher(&vector_commitment_v_bytes(index));
+            let coords = point.to_affine().coordinates().unwrap();
+
+            assert_eq!(*coords.x(), pallas::Base::from_repr(generator.0).unwrap());
+            assert_eq!(*coords.y(), pallas::Base::from_repr(generator.1).unwrap());
+        }
+    }
+
+    #[test]
+    fn lagrange_coeffs() {
+        for index in 0..VECTOR_COMMITMENT_SIZE {
+            let base = super::generator(index);
+            test_lagrange_coeffs(base, NUM_WINDOWS);
+        }
+    }
+
+    #[test]
+    fn z() {
+        for (index, (z, u)) in Z.iter().zip(U.iter()).enumerate() {
+            let base = super::generator(index);
+            test_zs_and_us(base, z, u, NUM_WINDOWS);
+        }
+    }
+}
@@ -1,26 +1,126 @@
 use {
-    crate::constants::fixed_bases::{BoardCommitR, BoardCommitV, BoardFixedBases},
-    halo2_gadgets::ecc::{EccInstructions, FixedPoint, FixedPointBaseField, Point, ScalarFixed},
+    crate::constants::{
+        fixed_bases::{
+            domain::{CommitmentDomain, DomainFixedBases},
+            BoardCommitQ, BoardCommitR, BoardCommitV, BoardFixedBases, VectorCommitV,
+            VectorFixedBases,
+        },
+        VECTOR_COMMITMENT_SIZE,
+    },
+    halo2_gadgets::ecc::{
+        BaseFitsInScalarInstructions, EccInstructions, FixedPoint, FixedPointBaseField,
+        FixedPointShort, FixedPoints, NonIdentityPoint, Point, ScalarFixed, ScalarFixedShort,
+        ScalarVar,
+    },
     halo2_proofs::{
-        circuit::{AssignedCell, Layouter},
+        arithmetic::CurveAffine,
+        circuit::{AssignedCell, Chip, Layouter, Value},
         pasta::pallas,
-        plonk::Error,
+        plonk::{Column, Error, Instance},
     },
 };
 
+/// The set of circuit instructions required to commit to a value. Host circuits written against
+/// this trait work with any commitment backend, such as PedersenCommitmentChip over the
+/// BoardFixedBases tables or a variable-base chip.
+pub trait PedersenInstructions<C: CurveAffine>: Chip<C::Base> {
+    /// A value to commit to, witnessed in the circuit
+    type Value: Clone;
+    /// A trapdoor witnessed in the circuit
+    type Trapdoor;
+    /// A commitment point
+    type Commitment: Clone;
+
+    /**
+     * Witness a value to commit to
+     *
+     * @param value - the value to commit to
+     * @return - the assigned value
+     */
+    fn witness_value(
+        &self,
+        layouter: impl Layouter<C::Base>,
+        value: Value<C::Base>,
+    ) -> Result<Self::Value, Error>;
+
+    /**
+     * Witness the blinding factor of a commitment
+     *
+     * @param trapdoor - the trapdoor as a full-width scalar
+     * @return - the assigned trapdoor
+     */
+    fn witness_trapdoor(
+        &self,
+        layouter: impl Layouter<C::Base>,
+        trapdoor: Value<C::Scalar>,
+    ) -> Result<Self::Trapdoor, Error>;
+
+    /**
+     * Commit to a witnessed value
+     *
+     * @param value - the committed value
+     * @param trapdoor - the blinding factor, consumed by the commitment
+     * @return - the commitment point
+     */
+    fn commit(
+        &self,
+        layouter: impl Layouter<C::Base>,
+        value: &Self::Value,
+        trapdoor: Self::Trapdoor,
+    ) -> Result<Self::Commitment, Error>;
+
+    /**
+     * Constrain two commitments to be the same point
+     *
+     * @param a - the first commitment
+     * @param b - the second commitment
+     */
+    fn constrain_equal_commitment(
+        &self,
+        layouter: impl Layouter<C::Base>,
+        a: &Self::Commitment,
+        b: &Self::Commitment,
+    ) -> Result<(), Error>;
+
+    /**
+     * Export a commitment to the instance column
+     *
+     * @param commitment - the commitment to expose
+     * @param instance - the instance column
+     * @param row - the row of the x coordinate, y is exposed at row + 1
+     */
+    fn expose_public(
+        &self,
+        layouter: impl Layouter<C::Base>,
+        commitment: &Self::Commitment,
+        instance: Column<Instance>,
+        row: usize,
+    ) -> Result<(), Error>;
+}
+
+/**
+ * Commit to a base field element in-circuit, [v] BoardCommitV + [rcv] BoardCommitR
+ * @dev generic over EccInstructions, but BoardFixedBases only has tables for pallas, the one
+ *      curve halo2_gadgets' EccChip supports
+ *
+ * @param ecc_chip - chip implementing EccInstructions on curve C with BoardFixedBases
+ * @param v - the committed value
+ * @param rcv - the trapdoor
+ * @return - the commitment point
+ */
 pub fn pedersen_commitment<
-    EccChip: EccInstructions<
-        pallas::Affine,
-        FixedPoints = BoardFixedBases,
-        Var = AssignedCell<pallas::Base, pallas::Base>,
-    >,
+    C: CurveAffine,
+    EccChip: EccInstructions<C, FixedPoints = BoardFixedBases, Var = AssignedCell<C::Base, C::Base>>,
 >(
-    mut layouter: impl Layouter<pallas::Base>,
+    mut layouter: impl Layouter<C::Base>,
     ecc_chip: EccChip,
-    // v: FixedPointBaseField<pallas::Affine, EccChip>,
-    v: AssignedCell<pallas::Base, pallas::Base>,
-    rcv: ScalarFixed<pallas::Affine, EccChip>,
-) -> Result<Point<pallas::Affine, EccChip>, Error> {
+    // v: FixedPointBaseField<C, EccChip>,
+    v: AssignedCell<C::Base, C::Base>,
+    rcv: ScalarFixed<C, EccChip>,
+) -> Result<Point<C, EccChip>, Error>
+where
+    BoardFixedBases: FixedPoints<C, Base = BoardCommitV, FullScalar = BoardCommitR>,
+{
     // commitment = [v] ValueCommitV
     let commitment = {
         let board_commit_v = FixedPointBaseField::from_inner(ecc_chip.clone(), BoardCommitV);
@@ -39,3 +139,540 @@ pub fn pedersen_commitment<
     // [v] ValueCommitV + [rcv] ValueCommitR
     commitment.add(layouter.namespace(|| "cv"), &blind)
 }
+
+/**
+ * Commit to a signed 64 bit value in-circuit, [±m] BoardCommitQ + [rcv] BoardCommitR
+ * @dev the magnitude is decomposed into NUM_WINDOWS_SHORT three bit windows, which also
+ *      constrains it to 64 bits, so the commitment takes far fewer rows than pedersen_commitment
+ *
+ * @param ecc_chip - chip implementing EccInstructions on curve C with BoardFixedBases
+ * @param magnitude - the absolute value of the committed value, less than 2^64
+ * @param sign - 1 or -1, the sign of the committed value
+ * @param rcv - the trapdoor
+ * @return - the commitment point
+ */
+pub fn short_pedersen_commitment<
+    C: CurveAffine,
+    EccChip: EccInstructions<C, FixedPoints = BoardFixedBases, Var = AssignedCell<C::Base, C::Base>>,
+>(
+    mut layouter: impl Layouter<C::Base>,
+    ecc_chip: EccChip,
+    magnitude: AssignedCell<C::Base, C::Base>,
+    sign: AssignedCell<C::Base, C::Base>,
+    rcv: ScalarFixed<C, EccChip>,
+) -> Result<Point<C, EccChip>, Error>
+where
+    BoardFixedBases: FixedPoints<C, FullScalar = BoardCommitR, ShortScalar = BoardCommitQ>,
+{
+    // commitment = [±m] BoardCommitQ
+    let commitment = {
+        let magnitude_sign = ScalarFixedShort::new(
+            ecc_chip.clone(),
+            layouter.namespace(|| "magnitude sign"),
+            (magnitude, sign),
+        )?;
+        let board_commit_q = FixedPointShort::from_inner(ecc_chip.clone(), BoardCommitQ);
+        board_commit_q
+            .mul(layouter.namespace(|| "[±m] BoardCommitQ"), magnitude_sign)?
+            .0
+    };
+
+    // blind = [rcv] BoardCommitR
+    let (blind, _rcv) = {
+        let board_commit_r = FixedPoint::from_inner(ecc_chip, BoardCommitR);
+        board_commit_r.mul(layouter.namespace(|| "[rcv]BoardCommitR"), rcv)?
+    };
+
+    // [±m] BoardCommitQ + [rcv] BoardCommitR
+    commitment.add(layouter.namespace(|| "cv"), &blind)
+}
+
+/**
+ * Commit to a base field element in-circuit under generators known only at proving time,
+ * [v] V + [rcv] R
+ * @dev uses variable-base scalar multiplication, which only takes base field scalars, so the
+ *      trapdoor is a base field element. Given BoardCommitV and BoardCommitR as V and R the
+ *      commitment agrees with pedersen_commitment for trapdoors below the base field modulus
+ *
+ * @param ecc_chip - chip implementing variable-base scalar multiplication on curve C
+ * @param value_generator - the witnessed or public generator V
+ * @param blinding_generator - the witnessed or public generator R
+ * @param v - the committed value
+ * @param rcv - the trapdoor
+ * @return - the commitment point
+ */
+pub fn variable_pedersen_commitment<
+    C: CurveAffine,
+    EccChip: BaseFitsInScalarInstructions<C, Var = AssignedCell<C::Base, C::Base>>,
+>(
+    mut layouter: impl Layouter<C::Base>,
+    ecc_chip: EccChip,
+    value_generator: &NonIdentityPoint<C, EccChip>,
+    blinding_generator: &NonIdentityPoint<C, EccChip>,
+    v: AssignedCell<C::Base, C::Base>,
+    rcv: AssignedCell<C::Base, C::Base>,
+) -> Result<Point<C, EccChip>, Error> {
+    // commitment = [v] V
+    let commitment = {
+        let v = ScalarVar::from_base(ecc_chip.clone(), layouter.namespace(|| "v"), &v)?;
+        value_generator.mul(layouter.namespace(|| "[v] V"), v)?.0
+    };
+
+    // blind = [rcv] R
+    let blind = {
+        let rcv = ScalarVar::from_base(ecc_chip, layouter.namespace(|| "rcv"), &rcv)?;
+        blinding_generator
+            .mul(layouter.namespace(|| "[rcv] R"), rcv)?
+            .0
+    };
+
+    // [v] V + [rcv] R
+    commitment.add(layouter.namespace(|| "cv"), &blind)
+}
+
+/**
+ * Homomorphically add two commitments in-circuit, C1 + C2
+ * @dev the sum opens to (v1 + v2, r1 + r2) whenever Opening::add of the two openings is Some
+ *
+ * @param a - the commitment C1
+ * @param b - the commitment C2
+ * @return - the summed commitment C1 + C2
+ */
+pub fn add_commitments<EccChip: EccInstructions<pallas::Affine>>(
+    mut layouter: impl Layouter<pallas::Base>,
+    a: &Point<pallas::Affine, EccChip>,
+    b: &Point<pallas::Affine, EccChip>,
+) -> Result<Point<pallas::Affine, EccChip>, Error> {
+    // ([v1] BoardCommitV + [r1] BoardCommitR) + ([v2] BoardCommitV + [r2] BoardCommitR)
+    // = [v1 + v2] BoardCommitV + [r1 + r2] BoardCommitR
+    a.add(layouter.namespace(|| "c1 + c2"), b)
+}
+
+/**
+ * Re-randomize a commitment in-circuit, C' = C + [delta] BoardCommitR
+ * @dev C' opens to the same value as C with trapdoor r + delta
+ *
+ * @param ecc_chip - chip implementing EccInstructions with BoardFixedBases
+ * @param commitment - the commitment C to refresh
+ * @param delta - the trapdoor offset
+ * @return - the re-randomized commitment C'
+ */
+pub fn rerandomize_commitment<
+    EccChip: EccInstructions<
+        pallas::Affine,
+        FixedPoints = BoardFixedBases,
+        Var = AssignedCell<pallas::Base, pallas::Base>,
+    >,
+>(
+    mut layouter: impl Layouter<pallas::Base>,
+    ecc_chip: EccChip,
+    commitment: &Point<pallas::Affine, EccChip>,
+    delta: ScalarFixed<pallas::Affine, EccChip>,
+) -> Result<Point<pallas::Affine, EccChip>, Error> {
+    // blind = [delta] BoardCommitR
+    let (blind, _delta) = {
+        let board_commit_r = FixedPoint::from_inner(ecc_chip, BoardCommitR);
+        board_commit_r.mul(layouter.namespace(|| "[delta]BoardCommitR"), delta)?
+    };
+
+    // C + [delta] BoardCommitR
+    commitment.add(layouter.namespace(|| "C'"), &blind)
+}
+
+pub fn domain_pedersen_commitment<
+    EccChip: EccInstructions<
+        pallas::Affine,
+        FixedPoints = DomainFixedBases,
+        Var = AssignedCell<pallas::Base, pallas::Base>,
+    >,
+>(
+    mut layouter: impl Layouter<pallas::Base>,
+    ecc_chip: EccChip,
+    domain: &CommitmentDomain,
+    v: AssignedCell<pallas::Base, pallas::Base>,
+    rcv: ScalarFixed<pallas::Affine, EccChip>,
+) -> Result<Point<pallas::Affine, EccChip>, Error> {
+    // commitment = [v] DomainCommitV
+    let commitment = {
+        let domain_commit_v =
+            FixedPointBaseField::from_inner(ecc_chip.clone(), domain.value_base());
+        domain_commit_v.mul(layouter.namespace(|| "[v] DomainCommitV"), v)?
+    };
+
+    // blind = [rcv] DomainCommitR
+    let (blind, _rcv) = {
+        let domain_commit_r = FixedPoint::from_inner(ecc_chip, domain.blinding_base());
+        domain_commit_r.mul(layouter.namespace(|| "[rcv]DomainCommitR"), rcv)?
+    };
+
+    // [v] DomainCommitV + [rcv] DomainCommitR
+    commitment.add(layouter.namespace(|| "cv"), &blind)
+}
+
+/**
+ * Commit in-circuit to a vector of values, each over its own fixed generator
+ * @dev only the first VECTOR_COMMITMENT_SIZE generators have fixed base tables
+ *
+ * @param ecc_chip - the chip configured with VectorFixedBases
+ * @param v - the committed values, at most VECTOR_COMMITMENT_SIZE of them
+ * @param rcv - the trapdoor
+ * @return - sum_i [v_i] VectorCommitV_i + [rcv] BoardCommitR, or Error::BoundsFailure if v is
+ *           longer than VECTOR_COMMITMENT_SIZE
+ */
+pub fn vector_pedersen_commitment<
+    EccChip: EccInstructions<
+        pallas::Affine,
+        FixedPoints = VectorFixedBases,
+        Var = AssignedCell<pallas::Base, pallas::Base>,
+    >,
+>(
+    mut layouter: impl Layouter<pallas::Base>,
+    ecc_chip: EccChip,
+    v: &[AssignedCell<pallas::Base, pallas::Base>],
+    rcv: ScalarFixed<pallas::Affine, EccChip>,
+) -> Result<Point<pallas::Affine, EccChip>, Error> {
+    // only VECTOR_COMMITMENT_SIZE generators have precomputed fixed base tables
+    if v.len() > VECTOR_COMMITMENT_SIZE {
+        return Err(Error::BoundsFailure);
+    }
+
+    // blind = [rcv] BoardCommitR
+    let (blind, _rcv) = {
+        let board_commit_r = FixedPoint::from_inner(ecc_chip.clone(), BoardCommitR);
+        board_commit_r.mul(layouter.namespace(|| "[rcv]BoardCommitR"), rcv)?
+    };
+
+    // sum_i [v_i] VectorCommitV_i + [rcv] BoardCommitR
+    v.iter()
+        .enumerate()
+        .try_fold(blind, |commitment, (i, v_i)| {
+            let vector_commit_v =
+                FixedPointBaseField::from_inner(ecc_chip.clone(), VectorCommitV(i));
+            let term = vector_commit_v.mul(
+                layouter.namespace(|| format!("[v_{}] VectorCommitV_{}", i, i)),
+                v_i.clone(),
+            )?;
+            commitment.add(layouter.namespace(|| format!("cv_{}", i)), &term)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            constants::{fixed_bases::domain::test_domain, LOOKUP_SIZE},
+            utils::{
+                commit::{derive_commitment, derive_vector_commitment, Opening, Trapdoor},
+                get_coordinates,
+            },
+        },
+        halo2_gadgets::{
+            ecc::{
+                chip::{EccChip, EccConfig},
+                FixedPoints,
+            },
+            utilities::lookup_range_check::LookupRangeCheckConfig,
+        },
+        halo2_proofs::{
+            arithmetic::Field,
+            circuit::{SimpleFloorPlanner, Value},
+            dev::MockProver,
+            pasta::group::Curve,
+            plonk::{Circuit, Column, ConstraintSystem, Instance, TableColumn},
+        },
+        rand::rngs::OsRng,
+    };
+
+    #[derive(Clone, Debug)]
+    struct TestCircuitConfig<F: FixedPoints<pallas::Affine>> {
+        instance: Column<Instance>,
+        table_idx: TableColumn,
+        ecc: EccConfig<F>,
+    }
+
+    impl<F: FixedPoints<pallas::Affine>> TestCircuitConfig<F> {
+        fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self {
+            let advice = [(); 10].map(|_| meta.advice_column());
+            let lagrange_coeffs = [(); 8].map(|_| meta.fixed_column());
+            let table_idx = meta.lookup_table_column();
+            let constants = meta.fixed_column();
+            meta.enable_constant(constants);
+            let instance = meta.instance_column();
+            meta.enable_equality(instance);
+            let range_check: LookupRangeCheckConfig<pallas::Base, LOOKUP_SIZE> =
+                LookupRangeCheckConfig::configure(meta, advice[9], table_idx);
+            let ecc = EccChip::<F>::configure(meta, advice, lagrange_coeffs, range_check);
+            TestCircuitConfig {
+                instance,
+                table_idx,
+                ecc,
+            }
+        }
+
+        // load the range check lookup table and witness messages in the first advice column
+        fn load(
+            &self,
+            layouter: &mut impl Layouter<pallas::Base>,
+            messages: &[Value<pallas::Base>],
+        ) -> Result<Vec<AssignedCell<pallas::Base, pallas::Base>>, Error> {
+            layouter.assign_table(
+                || "table_idx",
+                |mut table| {
+                    for index in 0..(1 << LOOKUP_SIZE) {
+                        table.assign_cell(
+                            || "table_idx",
+                            self.table_idx,
+                            index,
+                            || Value::known(pallas::Base::from(index as u64)),
+                        )?;
+                    }
+                    Ok(())
+                },
+            )?;
+            layouter.assign_region(
+                || "witness messages",
+                |mut region| {
+                    messages
+                        .iter()
+                        .enumerate()
+                        .map(|(i, message)| {
+                            region.assign_advice(
+                                || "witness message",
+                                self.ecc.advices[0],
+                                i,
+                                || *message,
+                            )
+                        })
+                        .collect()
+                },
+            )
+        }
+    }
+
+    #[derive(Clone, Debug, Default)]
+    struct VectorCommitmentCircuit {
+        messages: Vec<Value<pallas::Base>>,
+        trapdoor: Value<pallas::Scalar>,
+    }
+
+    impl Circuit<pallas::Base> for VectorCommitmentCircuit {
+        type Config = TestCircuitConfig<VectorFixedBases>;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            VectorCommitmentCircuit {
+                messages: vec![Value::unknown(); self.messages.len()],
+                trapdoor: Value::unknown(),
+            }
+        }
+
+        fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+            TestCircuitConfig::configure(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<pallas::Base>,
+        ) -> Result<(), Error> {
+            let messages = config.load(&mut layouter, &self.messages)?;
+            let ecc_chip = EccChip::construct(config.ecc.clone());
+            let trapdoor = ScalarFixed::new(
+                ecc_chip.clone(),
+                layouter.namespace(|| "trapdoor"),
+                self.trapdoor,
+            )?;
+            let commitment = vector_pedersen_commitment(
+                layouter.namespace(|| "vector pedersen commitment"),
+                ecc_chip,
+                &messages,
+                trapdoor,
+            )?;
+            layouter.constrain_instance(commitment.inner().x().cell(), config.instance, 0)?;
+            layouter.constrain_instance(commitment.inner().y().cell(), config.instance, 1)?;
+            Ok(())
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    struct DomainCommitmentCircuit {
+        domain: CommitmentDomain,
+        message: Value<pallas::Base>,
+        trapdoor: Value<pallas::Scalar>,
+    }
+
+    impl Circuit<pallas::Base> for DomainCommitmentCircuit {
+        type Config = TestCircuitConfig<DomainFixedBases>;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            DomainCommitmentCircuit {
+                domain: self.domain.clone(),
+                message: Value::unknown(),
+                trapdoor: Value::unknown(),
+            }
+        }
+
+        fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+            TestCircuitConfig::configure(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<pallas::Base>,
+        ) -> Result<(), Error> {
+            let message = config.load(&mut layouter, &[self.message])?.remove(0);
+            let ecc_chip = EccChip::construct(config.ecc.clone());
+            let trapdoor = ScalarFixed::new(
+                ecc_chip.clone(),
+                layouter.namespace(|| "trapdoor"),
+                self.trapdoor,
+            )?;
+            let commitment = domain_pedersen_commitment(
+                layouter.namespace(|| "domain pedersen commitment"),
+                ecc_chip,
+                &self.domain,
+                message,
+                trapdoor,
+            )?;
+            layouter.constrain_instance(commitment.inner().x().cell(), config.instance, 0)?;
+            layouter.constrain_instance(commitment.inner().y().cell(), config.instance, 1)?;
+            Ok(())
+        }
+    }
+
+    #[derive(Clone, Debug, Default)]
+    struct AddCommitmentsCircuit {
+        messages: [Value<pallas::Base>; 2],
+        trapdoors: [Value<pallas::Scalar>; 2],
+    }
+
+    impl Circuit<pallas::Base> for AddCommitmentsCircuit {
+        type Config = TestCircuitConfig<BoardFixedBases>;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+            TestCircuitConfig::configure(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<pallas::Base>,
+        ) -> Result<(), Error> {
+            let messages = config.load(&mut layouter, &self.messages)?;
+            let ecc_chip = EccChip::construct(config.ecc.clone());
+            let commitments = messages
+                .into_iter()
+                .zip(self.trapdoors)
+                .enumerate()
+                .map(|(i, (message, trapdoor))| {
+                    let trapdoor = ScalarFixed::new(
+                        ecc_chip.clone(),
+                        layouter.namespace(|| format!("trapdoor {}", i)),
+                        trapdoor,
+                    )?;
+                    pedersen_commitment(
+                        layouter.namespace(|| format!("pedersen commitment {}", i)),
+                        ecc_chip.clone(),
+                        message,
+                        trapdoor,
+                    )
+                })
+                .collect::<Result<Vec<_>, Error>>()?;
+            let sum = add_commitments(
+                layouter.namespace(|| "add commitments"),
+                &commitments[0],
+                &commitments[1],
+            )?;
+            layouter.constrain_instance(sum.inner().x().cell(), config.instance, 0)?;
+            layouter.constrain_instance(sum.inner().y().cell(), config.instance, 1)?;
+            Ok(())
+        }
+    }
+
+    fn check_domain_commitment(domain: CommitmentDomain) {
+        let message = pallas::Base::random(&mut OsRng);
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        let (x, y) = get_coordinates(derive_commitment(&domain, &message, &trapdoor).to_affine());
+        let circuit = DomainCommitmentCircuit {
+            domain,
+            message: Value::known(message),
+            trapdoor: Value::known(trapdoor),
+        };
+        let prover = MockProver::run(11, &circuit, vec![vec![x, y]]).unwrap();
+        prover.assert_satisfied();
+    }
+
+    #[test]
+    fn vector_commitment() {
+        let messages = (0..VECTOR_COMMITMENT_SIZE)
+            .map(|_| pallas::Base::random(&mut OsRng))
+            .collect::<Vec<_>>();
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        let commitment = derive_vector_commitment(&messages, &trapdoor).unwrap();
+        let (x, y) = get_coordinates(commitment.to_affine());
+        let circuit = VectorCommitmentCircuit {
+            messages: messages.iter().map(|m| Value::known(*m)).collect(),
+            trapdoor: Value::known(trapdoor),
+        };
+        let prover = MockProver::run(11, &circuit, vec![vec![x, y]]).unwrap();
+        prover.assert_satisfied();
+        // commitment to a permuted vector does not match
+        let mut permuted = messages.clone();
+        permuted.swap(0, 1);
+        let commitment = derive_vector_commitment(&permuted, &trapdoor).unwrap();
+        let (x, y) = get_coordinates(commitment.to_affine());
+        let prover = MockProver::run(11, &circuit, vec![vec![x, y]]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    fn vector_commitment_too_long() {
+        // vectors past the fixed base tables are rejected during synthesis
+        let circuit = VectorCommitmentCircuit {
+            messages: vec![Value::known(pallas::Base::one()); VECTOR_COMMITMENT_SIZE + 1],
+            trapdoor: Value::known(pallas::Scalar::one()),
+        };
+        let result = MockProver::run(11, &circuit, vec![vec![pallas::Base::zero(); 2]]);
+        assert!(matches!(result, Err(Error::BoundsFailure)));
+    }
+
+    #[test]
+    fn add_commitment() {
+        let o1 = Opening::new(pallas::Base::from(40), Trapdoor::random(OsRng));
+        let o2 = Opening::new(pallas::Base::from(2), Trapdoor::random(OsRng));
+        let sum = o1.add(&o2).unwrap();
+        let circuit = AddCommitmentsCircuit {
+            messages: [Value::known(o1.value), Value::known(o2.value)],
+            trapdoors: [
+                Value::known(o1.trapdoor.inner()),
+                Value::known(o2.trapdoor.inner()),
+            ],
+        };
+        // the in-circuit sum matches the native sum
+        let (x, y) = o1.commit().add(&o2.commit()).coordinates().unwrap();
+        let prover = MockProver::run(11, &circuit, vec![vec![x, y]]).unwrap();
+        prover.assert_satisfied();
+        // and opens to the summed value and trapdoor
+        let (x, y) = sum.commit().coordinates().unwrap();
+        let prover = MockProver::run(11, &circuit, vec![vec![x, y]]).unwrap();
+        prover.assert_satisfied();
+    }
+
+    #[test]
+    fn domain_commitment() {
+        check_domain_commitment(CommitmentDomain::default());
+    }
+
+    #[test]
+    fn custom_domain_commitment() {
+        check_domain_commitment(test_domain());
+    }
+}
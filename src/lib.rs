@@ -1,5 +1,10 @@
 pub mod gadget;
 pub mod constants;
+pub mod board;
 pub mod chip;
 pub mod utils;
-pub mod circuit;
\ No newline at end of file
+pub mod circuit;
+pub mod prover;
+
+#[cfg(test)]
+mod consistency;
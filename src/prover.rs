@@ -0,0 +1,353 @@
+pub mod batch;
+pub mod envelope;
+pub mod params_file;
+
+use {
+    self::envelope::{EnvelopeError, ProofEnvelope},
+    crate::{
+        circuit::PedersenCommitmentCircuit,
+        constants::fixed_bases::domain::CommitmentDomain,
+        utils::commit::{derive_commitment, Commitment},
+    },
+    halo2_proofs::{
+        circuit::Value,
+        pasta::{pallas, vesta},
+        plonk::{
+            self, create_proof, keygen_pk, keygen_vk, verify_proof, ProvingKey, SingleVerifier,
+            VerifyingKey,
+        },
+        poly::commitment::Params,
+        transcript::{Blake2bRead, Blake2bWrite, Challenge255},
+    },
+    rand::RngCore,
+    std::{fmt, io},
+};
+
+/// Number of rows (2^K) needed to synthesize PedersenCommitmentCircuit
+pub const K: u32 = 11;
+
+/// Circuit identifier stored in proof envelopes for PedersenCommitmentCircuit
+pub const CIRCUIT_ID: &str = "pedersen-commitment";
+
+/// Errors returned when generating keys, proving or verifying a pedersen commitment opening
+#[derive(Debug)]
+pub enum ProverError {
+    /// halo2 failed during keygen, synthesis, or transcript handling
+    Plonk(plonk::Error),
+    /// the proof is well formed but does not open the supplied commitment
+    VerificationFailed,
+    /// the proof envelope does not hold a proof for this circuit and parameters
+    Envelope(EnvelopeError),
+    /// reading or writing a params file failed
+    Io(io::Error),
+    /// the params file was written for a different circuit, k or verifying key
+    StaleParams(&'static str),
+    /// the proofs at these indices in a batch failed verification
+    BatchFailed(Vec<usize>),
+    /// the opening commits to the identity, which has no (x, y) to expose as public input
+    IdentityCommitment,
+}
+
+impl fmt::Display for ProverError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProverError::Plonk(e) => write!(f, "halo2 error: {:?}", e),
+            ProverError::VerificationFailed => {
+                write!(f, "proof does not verify against commitment")
+            }
+            ProverError::Envelope(e) => write!(f, "{}", e),
+            ProverError::Io(e) => write!(f, "io error: {}", e),
+            ProverError::StaleParams(reason) => write!(f, "stale params file: {}", reason),
+            ProverError::BatchFailed(failed) => {
+                write!(f, "proofs {:?} in batch failed verification", failed)
+            }
+            ProverError::IdentityCommitment => {
+                write!(f, "opening commits to the identity point")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ProverError {}
+
+impl From<plonk::Error> for ProverError {
+    fn from(error: plonk::Error) -> Self {
+        ProverError::Plonk(error)
+    }
+}
+
+impl From<EnvelopeError> for ProverError {
+    fn from(error: EnvelopeError) -> Self {
+        ProverError::Envelope(error)
+    }
+}
+
+impl From<io::Error> for ProverError {
+    fn from(error: io::Error) -> Self {
+        ProverError::Io(error)
+    }
+}
+
+/**
+ * Generate IPA commitment parameters for proofs over the pallas base field
+ *
+ * @param k - log2 of the number of rows in the circuit
+ * @return - public parameters used by keygen, proving and verifying
+ */
+pub fn setup_params(k: u32) -> Params<vesta::Affine> {
+    Params::new(k)
+}
+
+/**
+ * Generate the verifying key for PedersenCommitmentCircuit
+ *
+ * @param params - public parameters from setup_params
+ * @return - verifying key for the commitment opening circuit
+ */
+pub fn generate_vk(
+    params: &Params<vesta::Affine>,
+) -> Result<VerifyingKey<vesta::Affine>, ProverError> {
+    let circuit = PedersenCommitmentCircuit {
+        message: Value::unknown(),
+        trapdoor: Value::unknown(),
+    };
+    Ok(keygen_vk(params, &circuit)?)
+}
+
+/**
+ * Generate the proving key for PedersenCommitmentCircuit
+ *
+ * @param params - public parameters from setup_params
+ * @param vk - verifying key from generate_vk
+ * @return - proving key for the commitment opening circuit
+ */
+pub fn generate_pk(
+    params: &Params<vesta::Affine>,
+    vk: VerifyingKey<vesta::Affine>,
+) -> Result<ProvingKey<vesta::Affine>, ProverError> {
+    let circuit = PedersenCommitmentCircuit {
+        message: Value::unknown(),
+        trapdoor: Value::unknown(),
+    };
+    Ok(keygen_pk(params, vk, &circuit)?)
+}
+
+/**
+ * Prove knowledge of an opening (message, trapdoor) for a pedersen commitment
+ * @dev the commitment exposed as public input is derived natively with derive_commitment
+ *
+ * @param params - public parameters from setup_params
+ * @param pk - proving key from generate_pk
+ * @param message - the committed value
+ * @param trapdoor - the blinding factor for the commitment
+ * @param rng - source of randomness for the proof
+ * @return - serialized proof transcript
+ */
+pub fn prove(
+    params: &Params<vesta::Affine>,
+    pk: &ProvingKey<vesta::Affine>,
+    message: pallas::Base,
+    trapdoor: pallas::Scalar,
+    rng: impl RngCore,
+) -> Result<Vec<u8>, ProverError> {
+    let (x, y) = commitment_coordinates(message, trapdoor)?;
+    let circuit = PedersenCommitmentCircuit {
+        message: Value::known(message),
+        trapdoor: Value::known(trapdoor),
+    };
+    let mut transcript = Blake2bWrite::<_, vesta::Affine, Challenge255<_>>::init(vec![]);
+    create_proof(params, pk, &[circuit], &[&[&[x, y]]], rng, &mut transcript)?;
+    Ok(transcript.finalize())
+}
+
+/**
+ * Verify a proof of knowledge of an opening for a pedersen commitment
+ *
+ * @param params - public parameters from setup_params
+ * @param vk - verifying key from generate_vk
+ * @param proof - serialized proof transcript from prove
+ * @param commitment - (x, y) coordinates of the commitment the proof should open
+ * @return - Ok if the proof verifies against the commitment
+ */
+pub fn verify(
+    params: &Params<vesta::Affine>,
+    vk: &VerifyingKey<vesta::Affine>,
+    proof: &[u8],
+    commitment: (pallas::Base, pallas::Base),
+) -> Result<(), ProverError> {
+    let (x, y) = commitment;
+    let strategy = SingleVerifier::new(params);
+    let mut transcript = Blake2bRead::<_, vesta::Affine, Challenge255<_>>::init(proof);
+    verify_proof(params, vk, strategy, &[&[&[x, y]]], &mut transcript).map_err(|e| match e {
+        plonk::Error::ConstraintSystemFailure => ProverError::VerificationFailed,
+        e => ProverError::Plonk(e),
+    })
+}
+
+// the commitment (x, y) exposed as public input for an opening
+fn commitment_coordinates(
+    message: pallas::Base,
+    trapdoor: pallas::Scalar,
+) -> Result<(pallas::Base, pallas::Base), ProverError> {
+    Commitment::from(derive_commitment(
+        &CommitmentDomain::default(),
+        &message,
+        &trapdoor,
+    ))
+    .coordinates()
+    .ok_or(ProverError::IdentityCommitment)
+}
+
+// params do not expose k, recover it from the 2^k generators
+fn params_k(params: &Params<vesta::Affine>) -> u32 {
+    params.get_g().len().trailing_zeros()
+}
+
+/**
+ * Prove knowledge of an opening and wrap the proof in an envelope for storage or transport
+ *
+ * @param params - public parameters from setup_params
+ * @param pk - proving key from generate_pk
+ * @param message - the committed value
+ * @param trapdoor - the blinding factor for the commitment
+ * @param rng - source of randomness for the proof
+ * @return - proof envelope holding the commitment (x, y) and the proof transcript
+ */
+pub fn prove_envelope(
+    params: &Params<vesta::Affine>,
+    pk: &ProvingKey<vesta::Affine>,
+    message: pallas::Base,
+    trapdoor: pallas::Scalar,
+    rng: impl RngCore,
+) -> Result<ProofEnvelope, ProverError> {
+    let (x, y) = commitment_coordinates(message, trapdoor)?;
+    let proof = prove(params, pk, message, trapdoor, rng)?;
+    Ok(ProofEnvelope::new(
+        CIRCUIT_ID,
+        params_k(params),
+        vec![x, y],
+        proof,
+    ))
+}
+
+/**
+ * Verify a proof envelope against the commitment it carries
+ *
+ * @param params - public parameters from setup_params
+ * @param vk - verifying key from generate_vk
+ * @param envelope - proof envelope from prove_envelope
+ * @return - Ok if the envelope is for this circuit and params and the proof verifies
+ */
+pub fn verify_envelope(
+    params: &Params<vesta::Affine>,
+    vk: &VerifyingKey<vesta::Affine>,
+    envelope: &ProofEnvelope,
+) -> Result<(), ProverError> {
+    check_envelope(params, envelope)?;
+    verify(params, vk, &envelope.proof, envelope_commitment(envelope)?)
+}
+
+// check an envelope holds a proof for this circuit created with the k of the params
+fn check_envelope(
+    params: &Params<vesta::Affine>,
+    envelope: &ProofEnvelope,
+) -> Result<(), ProverError> {
+    envelope.check(CIRCUIT_ID)?;
+    let k = params_k(params);
+    if envelope.k != k {
+        return Err(EnvelopeError::KMismatch {
+            expected: k,
+            found: envelope.k,
+        }
+        .into());
+    }
+    Ok(())
+}
+
+// the commitment (x, y) exposed as public input by the proof in an envelope
+fn envelope_commitment(
+    envelope: &ProofEnvelope,
+) -> Result<(pallas::Base, pallas::Base), ProverError> {
+    match envelope.instance[..] {
+        [x, y] => Ok((x, y)),
+        _ => Err(EnvelopeError::Malformed("expected commitment (x, y)".to_string()).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::utils::get_coordinates,
+        halo2_proofs::{arithmetic::Field, pasta::group::Curve},
+        rand::rngs::OsRng,
+    };
+
+    #[test]
+    fn prove_and_verify() {
+        let params = setup_params(K);
+        let vk = generate_vk(&params).unwrap();
+        let pk = generate_pk(&params, vk.clone()).unwrap();
+        // prove knowledge of opening
+        let message = pallas::Base::from(88675409);
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        let proof = prove(&params, &pk, message, trapdoor, OsRng).unwrap();
+        // verify against the natively computed commitment
+        let commitment = get_coordinates(
+            derive_commitment(&CommitmentDomain::default(), &message, &trapdoor).to_affine(),
+        );
+        verify(&params, &vk, &proof, commitment).unwrap();
+        // verify against a commitment to a different message fails
+        let other = get_coordinates(
+            derive_commitment(
+                &CommitmentDomain::default(),
+                &pallas::Base::from(1),
+                &trapdoor,
+            )
+            .to_affine(),
+        );
+        assert!(matches!(
+            verify(&params, &vk, &proof, other),
+            Err(ProverError::VerificationFailed)
+        ));
+        // the zero opening commits to the identity, which has no public input
+        let (zero, trapdoor) = (pallas::Base::zero(), pallas::Scalar::zero());
+        assert!(matches!(
+            prove(&params, &pk, zero, trapdoor, OsRng),
+            Err(ProverError::IdentityCommitment)
+        ));
+    }
+
+    #[test]
+    fn prove_and_verify_envelope() {
+        let params = setup_params(K);
+        let vk = generate_vk(&params).unwrap();
+        let pk = generate_pk(&params, vk.clone()).unwrap();
+        let message = pallas::Base::from(88675409);
+        let trapdoor = pallas::Scalar::random(&mut OsRng);
+        let envelope = prove_envelope(&params, &pk, message, trapdoor, OsRng).unwrap();
+        // envelopes verify after a round trip through either encoding
+        let decoded = ProofEnvelope::from_bytes(&envelope.to_bytes().unwrap(), CIRCUIT_ID).unwrap();
+        verify_envelope(&params, &vk, &decoded).unwrap();
+        let decoded = ProofEnvelope::from_json(&envelope.to_json(), CIRCUIT_ID).unwrap();
+        verify_envelope(&params, &vk, &decoded).unwrap();
+        // a tampered commitment fails verification
+        let mut tampered = envelope.clone();
+        tampered.instance[0] += pallas::Base::one();
+        assert!(matches!(
+            verify_envelope(&params, &vk, &tampered),
+            Err(ProverError::VerificationFailed)
+        ));
+        // envelopes for other circuits or k are rejected
+        let other = ProofEnvelope::new("other", K, envelope.instance.clone(), vec![]);
+        assert!(matches!(
+            verify_envelope(&params, &vk, &other),
+            Err(ProverError::Envelope(EnvelopeError::CircuitMismatch { .. }))
+        ));
+        let other = ProofEnvelope::new(CIRCUIT_ID, K + 1, envelope.instance, envelope.proof);
+        assert!(matches!(
+            verify_envelope(&params, &vk, &other),
+            Err(ProverError::Envelope(EnvelopeError::KMismatch { expected: K, found })) if found == K + 1
+        ));
+    }
+}
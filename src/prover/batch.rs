@@ -0,0 +1,158 @@
+use {
+    super::{check_envelope, envelope::ProofEnvelope, envelope_commitment, verify, ProverError},
+    halo2_proofs::{
+        pasta::{pallas, vesta},
+        plonk::{BatchVerifier as PlonkBatchVerifier, VerifyingKey},
+        poly::commitment::Params,
+    },
+};
+
+/// Accumulates proofs of opening for PedersenCommitmentCircuit and checks them with a single
+/// multi-scalar multiplication
+#[derive(Clone, Debug, Default)]
+pub struct BatchVerifier {
+    items: Vec<(Vec<u8>, (pallas::Base, pallas::Base))>,
+}
+
+impl BatchVerifier {
+    pub fn new() -> Self {
+        BatchVerifier { items: vec![] }
+    }
+
+    /**
+     * Add a proof to the batch
+     *
+     * @param proof - serialized proof transcript from prove
+     * @param commitment - (x, y) coordinates of the commitment the proof should open
+     * @return - index of the proof in the batch, used to report failures
+     */
+    pub fn add_proof(&mut self, proof: Vec<u8>, commitment: (pallas::Base, pallas::Base)) -> usize {
+        self.items.push((proof, commitment));
+        self.items.len() - 1
+    }
+
+    /**
+     * Add a proof envelope to the batch
+     *
+     * @param params - public parameters the batch will be finalized with
+     * @param envelope - proof envelope from prove_envelope
+     * @return - index of the proof in the batch, or an error if the envelope is not for
+     *           PedersenCommitmentCircuit or was created with a different k than params
+     */
+    pub fn add_envelope(
+        &mut self,
+        params: &Params<vesta::Affine>,
+        envelope: ProofEnvelope,
+    ) -> Result<usize, ProverError> {
+        check_envelope(params, &envelope)?;
+        let commitment = envelope_commitment(&envelope)?;
+        Ok(self.add_proof(envelope.proof, commitment))
+    }
+
+    /// Returns the number of proofs in the batch
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns true if no proofs have been added
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /**
+     * Verify every proof in the batch
+     * @dev proofs are accumulated into one MSM with halo2's BatchVerifier. The batch only reports
+     *      whether every proof passed, so on failure each proof is verified individually to
+     *      find the ones that failed
+     *
+     * @param params - public parameters from setup_params
+     * @param vk - verifying key from generate_vk
+     * @return - Ok if every proof verifies, or ProverError::BatchFailed with the failing indices
+     */
+    pub fn finalize(
+        self,
+        params: &Params<vesta::Affine>,
+        vk: &VerifyingKey<vesta::Affine>,
+    ) -> Result<(), ProverError> {
+        let mut batch = PlonkBatchVerifier::new();
+        for (proof, (x, y)) in self.items.iter() {
+            batch.add_proof(vec![vec![vec![*x, *y]]], proof.clone());
+        }
+        if batch.finalize(params, vk) {
+            return Ok(());
+        }
+        let failed = self
+            .items
+            .iter()
+            .enumerate()
+            .filter(|(_, (proof, commitment))| verify(params, vk, proof, *commitment).is_err())
+            .map(|(index, _)| index)
+            .collect();
+        Err(ProverError::BatchFailed(failed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{
+            super::{
+                envelope::EnvelopeError, generate_pk, generate_vk, prove, prove_envelope,
+                setup_params, K,
+            },
+            *,
+        },
+        crate::utils::commit::{Opening, Trapdoor},
+        rand::rngs::OsRng,
+    };
+
+    #[test]
+    fn batch_verify() {
+        let params = setup_params(K);
+        let vk = generate_vk(&params).unwrap();
+        let pk = generate_pk(&params, vk.clone()).unwrap();
+        let openings = (0..3)
+            .map(|i| Opening::new(pallas::Base::from(i), Trapdoor::random(OsRng)))
+            .collect::<Vec<_>>();
+        let proofs = openings
+            .iter()
+            .map(|o| prove(&params, &pk, o.value, o.trapdoor.inner(), OsRng).unwrap())
+            .collect::<Vec<_>>();
+        let commitments = openings
+            .iter()
+            .map(|o| o.commit().coordinates().unwrap())
+            .collect::<Vec<_>>();
+        // empty batch passes
+        BatchVerifier::new().finalize(&params, &vk).unwrap();
+        // every proof opens its commitment
+        let mut batch = BatchVerifier::new();
+        for (commitment, proof) in commitments.iter().zip(proofs.iter()) {
+            batch.add_proof(proof.clone(), *commitment);
+        }
+        let trapdoor = Trapdoor::random(OsRng).inner();
+        let envelope = prove_envelope(&params, &pk, pallas::Base::from(7), trapdoor, OsRng);
+        let envelope = envelope.unwrap();
+        // envelopes for another k are rejected before they reach the batch
+        let mut other = envelope.clone();
+        other.k = K + 1;
+        assert!(matches!(
+            batch.add_envelope(&params, other),
+            Err(ProverError::Envelope(EnvelopeError::KMismatch {
+                expected: K,
+                ..
+            }))
+        ));
+        assert_eq!(batch.add_envelope(&params, envelope).unwrap(), 3);
+        assert_eq!(batch.len(), 4);
+        batch.finalize(&params, &vk).unwrap();
+        // proofs checked against the wrong commitment are reported by index
+        let mut batch = BatchVerifier::new();
+        batch.add_proof(proofs[0].clone(), commitments[0]);
+        batch.add_proof(proofs[1].clone(), commitments[2]);
+        batch.add_proof(proofs[2].clone(), commitments[1]);
+        assert!(matches!(
+            batch.finalize(&params, &vk),
+            Err(ProverError::BatchFailed(failed)) if failed == vec![1, 2]
+        ));
+    }
+}
@@ -0,0 +1,354 @@
+use {
+    crate::utils::{from_hex, to_hex},
+    halo2_proofs::pasta::{group::ff::PrimeField, pallas},
+    serde::{de, Deserialize, Deserializer, Serialize, Serializer},
+    std::fmt,
+};
+
+/// Version of the proof envelope encoding, bumped on any change to the layout
+pub const PROOF_VERSION: u16 = 1;
+
+/// Magic bytes prefixing the binary encoding of a proof envelope
+pub const PROOF_MAGIC: [u8; 4] = *b"PCPF";
+
+/// Errors returned when loading a proof envelope
+#[derive(Debug)]
+pub enum EnvelopeError {
+    /// the bytes or json could not be parsed as a proof envelope
+    Malformed(String),
+    /// the envelope was written with an unsupported encoding version
+    UnsupportedVersion { expected: u16, found: u16 },
+    /// the envelope holds a proof for a different circuit
+    CircuitMismatch { expected: String, found: String },
+    /// the proof was created with a different k than the params it is verified with
+    KMismatch { expected: u32, found: u32 },
+    /// a field is too long for its length prefix in the binary encoding
+    TooLong(&'static str),
+}
+
+impl fmt::Display for EnvelopeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EnvelopeError::Malformed(reason) => write!(f, "malformed proof envelope: {}", reason),
+            EnvelopeError::UnsupportedVersion { expected, found } => write!(
+                f,
+                "unsupported proof envelope version {}, expected {}",
+                found, expected
+            ),
+            EnvelopeError::CircuitMismatch { expected, found } => write!(
+                f,
+                "proof envelope is for circuit {:?}, expected {:?}",
+                found, expected
+            ),
+            EnvelopeError::KMismatch { expected, found } => write!(
+                f,
+                "proof was created with k = {}, params have k = {}",
+                found, expected
+            ),
+            EnvelopeError::TooLong(field) => {
+                write!(f, "{} is too long for the proof envelope encoding", field)
+            }
+        }
+    }
+}
+
+impl std::error::Error for EnvelopeError {}
+
+/// A serialized proof along with everything needed to verify it besides the keys
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ProofEnvelope {
+    /// encoding version, see PROOF_VERSION
+    pub version: u16,
+    /// identifies the circuit the proof was created for
+    pub circuit_id: String,
+    /// log2 of the number of rows the proof was created with
+    pub k: u32,
+    /// public inputs of the proof, for PedersenCommitmentCircuit the commitment (x, y)
+    #[serde(with = "serde_instance")]
+    pub instance: Vec<pallas::Base>,
+    /// the proof transcript
+    #[serde(with = "serde_proof")]
+    pub proof: Vec<u8>,
+}
+
+impl ProofEnvelope {
+    /**
+     * Wrap a proof transcript in an envelope at the current version
+     *
+     * @param circuit_id - identifies the circuit the proof was created for
+     * @param k - log2 of the number of rows the proof was created with
+     * @param instance - public inputs of the proof
+     * @param proof - the proof transcript
+     * @return - the proof envelope
+     */
+    pub fn new(circuit_id: &str, k: u32, instance: Vec<pallas::Base>, proof: Vec<u8>) -> Self {
+        ProofEnvelope {
+            version: PROOF_VERSION,
+            circuit_id: circuit_id.to_string(),
+            k,
+            instance,
+            proof,
+        }
+    }
+
+    /**
+     * Check the envelope was written at the current version for the expected circuit
+     *
+     * @param circuit_id - the circuit the proof should be for
+     * @return - Ok if the version and circuit id match
+     */
+    pub fn check(&self, circuit_id: &str) -> Result<(), EnvelopeError> {
+        if self.version != PROOF_VERSION {
+            return Err(EnvelopeError::UnsupportedVersion {
+                expected: PROOF_VERSION,
+                found: self.version,
+            });
+        }
+        if self.circuit_id != circuit_id {
+            return Err(EnvelopeError::CircuitMismatch {
+                expected: circuit_id.to_string(),
+                found: self.circuit_id.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    /**
+     * Encode the envelope as bytes
+     * @dev integers are little endian and variable length fields are prefixed with their length:
+     *      magic (4) | version (u16) | circuit id (u16 len + utf8) | k (u32)
+     *      | instance (u32 len + 32 bytes each) | proof (u32 len + bytes)
+     *
+     * @return - the binary encoding of the envelope, or EnvelopeError::TooLong if a field
+     *           overflows its length prefix
+     */
+    pub fn to_bytes(&self) -> Result<Vec<u8>, EnvelopeError> {
+        let circuit_id_len = u16::try_from(self.circuit_id.len())
+            .map_err(|_| EnvelopeError::TooLong("circuit id"))?;
+        let instance_len =
+            u32::try_from(self.instance.len()).map_err(|_| EnvelopeError::TooLong("instance"))?;
+        let proof_len =
+            u32::try_from(self.proof.len()).map_err(|_| EnvelopeError::TooLong("proof"))?;
+        let mut bytes = PROOF_MAGIC.to_vec();
+        bytes.extend_from_slice(&self.version.to_le_bytes());
+        bytes.extend_from_slice(&circuit_id_len.to_le_bytes());
+        bytes.extend_from_slice(self.circuit_id.as_bytes());
+        bytes.extend_from_slice(&self.k.to_le_bytes());
+        bytes.extend_from_slice(&instance_len.to_le_bytes());
+        for value in self.instance.iter() {
+            bytes.extend_from_slice(&value.to_repr());
+        }
+        bytes.extend_from_slice(&proof_len.to_le_bytes());
+        bytes.extend_from_slice(&self.proof);
+        Ok(bytes)
+    }
+
+    /**
+     * Decode an envelope from bytes written by to_bytes
+     * @dev the version is checked before the rest of the envelope is parsed
+     *
+     * @param bytes - the binary encoding of the envelope
+     * @param circuit_id - the circuit the proof should be for
+     * @return - the envelope, or an error if it is malformed or the version or circuit mismatch
+     */
+    pub fn from_bytes(bytes: &[u8], circuit_id: &str) -> Result<Self, EnvelopeError> {
+        let mut reader = Reader(bytes);
+        if reader.take(4)? != PROOF_MAGIC {
+            return Err(EnvelopeError::Malformed("missing magic bytes".to_string()));
+        }
+        let version = u16::from_le_bytes(reader.array()?);
+        if version != PROOF_VERSION {
+            return Err(EnvelopeError::UnsupportedVersion {
+                expected: PROOF_VERSION,
+                found: version,
+            });
+        }
+        let len = u16::from_le_bytes(reader.array()?) as usize;
+        let id = String::from_utf8(reader.take(len)?.to_vec())
+            .map_err(|_| EnvelopeError::Malformed("circuit id is not utf8".to_string()))?;
+        let k = u32::from_le_bytes(reader.array()?);
+        let len = u32::from_le_bytes(reader.array()?) as usize;
+        let mut instance = Vec::new();
+        for _ in 0..len {
+            let value =
+                Option::from(pallas::Base::from_repr(reader.array()?)).ok_or_else(|| {
+                    EnvelopeError::Malformed("invalid base field element".to_string())
+                })?;
+            instance.push(value);
+        }
+        let len = u32::from_le_bytes(reader.array()?) as usize;
+        let proof = reader.take(len)?.to_vec();
+        if !reader.0.is_empty() {
+            return Err(EnvelopeError::Malformed("trailing bytes".to_string()));
+        }
+        let envelope = ProofEnvelope {
+            version,
+            circuit_id: id,
+            k,
+            instance,
+            proof,
+        };
+        envelope.check(circuit_id)?;
+        Ok(envelope)
+    }
+
+    /// Encode the envelope as json, field elements and the proof are hex strings
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+
+    /**
+     * Decode an envelope from json written by to_json
+     *
+     * @param json - the json encoding of the envelope
+     * @param circuit_id - the circuit the proof should be for
+     * @return - the envelope, or an error if it is malformed or the version or circuit mismatch
+     */
+    pub fn from_json(json: &str, circuit_id: &str) -> Result<Self, EnvelopeError> {
+        let envelope: ProofEnvelope =
+            serde_json::from_str(json).map_err(|e| EnvelopeError::Malformed(e.to_string()))?;
+        envelope.check(circuit_id)?;
+        Ok(envelope)
+    }
+}
+
+// cursor over the binary encoding of an envelope
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], EnvelopeError> {
+        if self.0.len() < len {
+            return Err(EnvelopeError::Malformed(
+                "unexpected end of input".to_string(),
+            ));
+        }
+        let (head, tail) = self.0.split_at(len);
+        self.0 = tail;
+        Ok(head)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], EnvelopeError> {
+        Ok(self.take(N)?.try_into().unwrap())
+    }
+}
+
+mod serde_instance {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        instance: &[pallas::Base],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(instance.iter().map(|value| to_hex(&value.to_repr())))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<pallas::Base>, D::Error> {
+        Vec::<String>::deserialize(deserializer)?
+            .iter()
+            .map(|hex| {
+                from_hex(hex)
+                    .and_then(|bytes| bytes.try_into().ok())
+                    .and_then(|bytes| Option::from(pallas::Base::from_repr(bytes)))
+                    .ok_or_else(|| de::Error::custom("invalid base field element"))
+            })
+            .collect()
+    }
+}
+
+mod serde_proof {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(proof: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&to_hex(proof))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        from_hex(&String::deserialize(deserializer)?)
+            .ok_or_else(|| de::Error::custom("invalid hex encoded proof"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn envelope() -> ProofEnvelope {
+        ProofEnvelope::new(
+            "test",
+            11,
+            vec![pallas::Base::from(3), -pallas::Base::one()],
+            vec![1, 2, 3, 4, 5],
+        )
+    }
+
+    #[test]
+    fn binary_round_trip() {
+        let envelope = envelope();
+        let bytes = envelope.to_bytes().unwrap();
+        assert_eq!(ProofEnvelope::from_bytes(&bytes, "test").unwrap(), envelope);
+        // truncated or extended encodings are malformed
+        assert!(matches!(
+            ProofEnvelope::from_bytes(&bytes[..bytes.len() - 1], "test"),
+            Err(EnvelopeError::Malformed(_))
+        ));
+        let mut extended = bytes.clone();
+        extended.push(0);
+        assert!(matches!(
+            ProofEnvelope::from_bytes(&extended, "test"),
+            Err(EnvelopeError::Malformed(_))
+        ));
+        // circuit ids beyond the u16 length prefix are rejected rather than truncated
+        let long = "a".repeat(u16::MAX as usize + 1);
+        let envelope = ProofEnvelope::new(&long, 11, vec![], vec![]);
+        assert!(matches!(
+            envelope.to_bytes(),
+            Err(EnvelopeError::TooLong("circuit id"))
+        ));
+        let envelope = ProofEnvelope::new(&long[1..], 11, vec![], vec![]);
+        let bytes = envelope.to_bytes().unwrap();
+        assert_eq!(
+            ProofEnvelope::from_bytes(&bytes, &long[1..]).unwrap(),
+            envelope
+        );
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let envelope = envelope();
+        let json = envelope.to_json();
+        assert_eq!(ProofEnvelope::from_json(&json, "test").unwrap(), envelope);
+        assert!(matches!(
+            ProofEnvelope::from_json("{}", "test"),
+            Err(EnvelopeError::Malformed(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_mismatch() {
+        // other circuit
+        let envelope = envelope();
+        assert!(matches!(
+            ProofEnvelope::from_bytes(&envelope.to_bytes().unwrap(), "other"),
+            Err(EnvelopeError::CircuitMismatch { .. })
+        ));
+        assert!(matches!(
+            ProofEnvelope::from_json(&envelope.to_json(), "other"),
+            Err(EnvelopeError::CircuitMismatch { .. })
+        ));
+        // other version
+        let envelope = ProofEnvelope {
+            version: PROOF_VERSION + 1,
+            ..envelope
+        };
+        assert!(matches!(
+            ProofEnvelope::from_bytes(&envelope.to_bytes().unwrap(), "test"),
+            Err(EnvelopeError::UnsupportedVersion { found, .. }) if found == PROOF_VERSION + 1
+        ));
+        assert!(matches!(
+            ProofEnvelope::from_json(&envelope.to_json(), "test"),
+            Err(EnvelopeError::UnsupportedVersion { .. })
+        ));
+    }
+}
@@ -0,0 +1,276 @@
+use {
+    super::{generate_pk, generate_vk, setup_params, ProverError},
+    crate::{circuit::PedersenCommitmentCircuit, constants::fixed_bases::BoardCurve},
+    blake2b_simd::Params as Blake2bParams,
+    halo2_proofs::{
+        pasta::{group::GroupEncoding, pallas, vesta},
+        plonk::{Circuit, ConstraintSystem, ProvingKey, VerifyingKey},
+        poly::commitment::Params,
+    },
+    std::{
+        fs::File,
+        io::{self, BufReader, BufWriter, Read, Write},
+        path::Path,
+    },
+};
+
+/// Version of the params file encoding, bumped on any change to the layout
+pub const PARAMS_FILE_VERSION: u16 = 2;
+
+/// Magic bytes prefixing a params file, distinct from the proof envelope's PROOF_MAGIC
+pub const PARAMS_FILE_MAGIC: [u8; 4] = *b"PCPM";
+
+/// Largest k accepted from a params file, the params hold 2^(k+1) points read into memory
+pub const MAX_PARAMS_K: u32 = 28;
+
+/**
+ * Fingerprint the shape of PedersenCommitmentCircuit for a given k
+ * @dev hashes the pinned ConstraintSystem (column counts, gates, lookups, queries), the
+ *      BoardFixedBases generators and k, so any change to the circuit changes the fingerprint
+ *
+ * @param k - log2 of the number of rows in the circuit
+ * @return - 32 byte blake2b digest of the circuit
+ */
+pub fn circuit_fingerprint(k: u32) -> [u8; 32] {
+    let mut cs = ConstraintSystem::<pallas::Base>::default();
+    PedersenCommitmentCircuit::configure(&mut cs);
+    let mut hasher = Blake2bParams::new()
+        .hash_length(32)
+        .personal(b"PedersenCircuit")
+        .to_state();
+    hasher.update(&k.to_le_bytes());
+    hasher.update(format!("{:?}", cs.pinned()).as_bytes());
+    hasher.update(&pallas::Affine::value_generator().to_bytes());
+    hasher.update(&pallas::Affine::blinding_generator().to_bytes());
+    hasher.finalize().as_bytes().try_into().unwrap()
+}
+
+/**
+ * Fingerprint a verifying key
+ * @dev hashes the pinned verifying key, which includes the fixed column commitments
+ *
+ * @param vk - verifying key from generate_vk
+ * @return - 32 byte blake2b digest of the verifying key
+ */
+pub fn vk_fingerprint(vk: &VerifyingKey<vesta::Affine>) -> [u8; 32] {
+    let mut hasher = Blake2bParams::new()
+        .hash_length(32)
+        .personal(b"PedersenVerifKey")
+        .to_state();
+    hasher.update(format!("{:?}", vk.pinned()).as_bytes());
+    hasher.finalize().as_bytes().try_into().unwrap()
+}
+
+/// A fingerprinted params file for PedersenCommitmentCircuit, along with the keys derived from it.
+/// This is a params cache, not a key cache: halo2 0.2 cannot serialize verifying or proving keys,
+/// so loading a file skips the params setup and detects a stale circuit, but keygen still runs
+/// on every load.
+#[derive(Clone, Debug)]
+pub struct ParamsFile {
+    pub k: u32,
+    pub params: Params<vesta::Affine>,
+    pub vk: VerifyingKey<vesta::Affine>,
+    pub pk: ProvingKey<vesta::Affine>,
+}
+
+impl ParamsFile {
+    /**
+     * Generate fresh params and derive the keys from them
+     *
+     * @param k - log2 of the number of rows in the circuit
+     * @return - params, verifying key and proving key
+     */
+    pub fn generate(k: u32) -> Result<Self, ProverError> {
+        ParamsFile::from_params(k, setup_params(k))
+    }
+
+    // derive the keys for existing params
+    fn from_params(k: u32, params: Params<vesta::Affine>) -> Result<Self, ProverError> {
+        let vk = generate_vk(&params)?;
+        let pk = generate_pk(&params, vk.clone())?;
+        Ok(ParamsFile { k, params, vk, pk })
+    }
+
+    /**
+     * Write the params to a params file
+     * @dev the file holds no keys, only the params along with fingerprints of the circuit and
+     *      verifying key. Integers are little endian:
+     *      magic (4) | version (u16) | k (u32) | circuit fingerprint (32) | vk fingerprint (32)
+     *      | params
+     *
+     * @param writer - destination for the params file
+     * @return - Ok if the params were written
+     */
+    pub fn write<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&PARAMS_FILE_MAGIC)?;
+        writer.write_all(&PARAMS_FILE_VERSION.to_le_bytes())?;
+        writer.write_all(&self.k.to_le_bytes())?;
+        writer.write_all(&circuit_fingerprint(self.k))?;
+        writer.write_all(&vk_fingerprint(&self.vk))?;
+        self.params.write(writer)
+    }
+
+    /**
+     * Read a params file written by ParamsFile::write and derive the keys from it
+     * @dev runs keygen, then checks the derived verifying key against the file's fingerprint
+     *
+     * @param reader - source of the params file
+     * @return - the params and keys, or ProverError::StaleParams if the file does not match the
+     *           circuit
+     */
+    pub fn read<R: Read>(reader: &mut R) -> Result<Self, ProverError> {
+        ParamsFile::read_checked(reader, None)
+    }
+
+    // read a params file, optionally requiring it to be for a given k
+    fn read_checked<R: Read>(reader: &mut R, expected: Option<u32>) -> Result<Self, ProverError> {
+        let mut header = [0u8; 74];
+        reader.read_exact(&mut header)?;
+        if header[..4] != PARAMS_FILE_MAGIC {
+            return Err(ProverError::StaleParams("missing magic bytes"));
+        }
+        if u16::from_le_bytes(header[4..6].try_into().unwrap()) != PARAMS_FILE_VERSION {
+            return Err(ProverError::StaleParams("unsupported params file version"));
+        }
+        let k = u32::from_le_bytes(header[6..10].try_into().unwrap());
+        if k > MAX_PARAMS_K {
+            return Err(ProverError::StaleParams(
+                "params file k exceeds MAX_PARAMS_K",
+            ));
+        }
+        if expected.is_some_and(|expected| expected != k) {
+            return Err(ProverError::StaleParams("params file is for a different k"));
+        }
+        // check the circuit before paying for keygen
+        if header[10..42] != circuit_fingerprint(k) {
+            return Err(ProverError::StaleParams("circuit fingerprint mismatch"));
+        }
+        // Params::read allocates 2^k points for the k it finds, so check it against the header
+        let mut params_k = [0u8; 4];
+        reader.read_exact(&mut params_k)?;
+        if u32::from_le_bytes(params_k) != k {
+            return Err(ProverError::StaleParams(
+                "params k does not match the header",
+            ));
+        }
+        let params = Params::read(&mut params_k.chain(reader))?;
+        let file = ParamsFile::from_params(k, params)?;
+        if header[42..74] != vk_fingerprint(&file.vk) {
+            return Err(ProverError::StaleParams(
+                "verifying key fingerprint mismatch",
+            ));
+        }
+        Ok(file)
+    }
+
+    /**
+     * Derive keys from a params file, generating the params and writing the file if it does not
+     * exist
+     * @dev keygen runs on every call, the file only saves the params setup
+     *
+     * @param path - location of the params file
+     * @param k - log2 of the number of rows in the circuit
+     * @return - the params and keys, or an error if an existing file is stale or unreadable
+     */
+    pub fn load_or_generate(path: impl AsRef<Path>, k: u32) -> Result<Self, ProverError> {
+        let path = path.as_ref();
+        match File::open(path) {
+            Ok(file) => ParamsFile::read_checked(&mut BufReader::new(file), Some(k)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                let file = ParamsFile::generate(k)?;
+                let mut writer = BufWriter::new(File::create(path)?);
+                file.write(&mut writer)?;
+                writer.flush()?;
+                Ok(file)
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{
+            super::{envelope::PROOF_MAGIC, K},
+            *,
+        },
+        std::{env, fs},
+    };
+
+    #[test]
+    fn params_file_round_trip() {
+        let file = ParamsFile::generate(K).unwrap();
+        let mut bytes = vec![];
+        file.write(&mut bytes).unwrap();
+        let read = ParamsFile::read(&mut bytes.as_slice()).unwrap();
+        assert_eq!(read.k, K);
+        assert_eq!(vk_fingerprint(&read.vk), vk_fingerprint(&file.vk));
+        // files for another k are rejected
+        assert!(matches!(
+            ParamsFile::read_checked(&mut bytes.as_slice(), Some(K + 1)),
+            Err(ProverError::StaleParams("params file is for a different k"))
+        ));
+        // a stale circuit fingerprint is rejected
+        let mut stale = bytes.clone();
+        stale[10] ^= 1;
+        assert!(matches!(
+            ParamsFile::read(&mut stale.as_slice()),
+            Err(ProverError::StaleParams("circuit fingerprint mismatch"))
+        ));
+        // a stale verifying key is rejected
+        let mut stale = bytes.clone();
+        stale[42] ^= 1;
+        assert!(matches!(
+            ParamsFile::read(&mut stale.as_slice()),
+            Err(ProverError::StaleParams(
+                "verifying key fingerprint mismatch"
+            ))
+        ));
+        // a proof envelope is not mistaken for a params file
+        let mut envelope = bytes.clone();
+        envelope[..4].copy_from_slice(&PROOF_MAGIC);
+        assert!(matches!(
+            ParamsFile::read(&mut envelope.as_slice()),
+            Err(ProverError::StaleParams("missing magic bytes"))
+        ));
+        // an oversized or inconsistent k is rejected before the params are read
+        let mut huge = bytes.clone();
+        huge[6..10].copy_from_slice(&(MAX_PARAMS_K + 1).to_le_bytes());
+        assert!(matches!(
+            ParamsFile::read(&mut huge.as_slice()),
+            Err(ProverError::StaleParams(
+                "params file k exceeds MAX_PARAMS_K"
+            ))
+        ));
+        let mut inconsistent = bytes.clone();
+        inconsistent[74..78].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(matches!(
+            ParamsFile::read(&mut inconsistent.as_slice()),
+            Err(ProverError::StaleParams(
+                "params k does not match the header"
+            ))
+        ));
+        // truncated files are an io error
+        assert!(matches!(
+            ParamsFile::read(&mut &bytes[..40]),
+            Err(ProverError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn load_or_generate() {
+        let path = env::temp_dir().join(format!("pedersen-params-{}.bin", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let generated = ParamsFile::load_or_generate(&path, K).unwrap();
+        let loaded = ParamsFile::load_or_generate(&path, K).unwrap();
+        assert_eq!(vk_fingerprint(&generated.vk), vk_fingerprint(&loaded.vk));
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn fingerprint_depends_on_k() {
+        assert_eq!(circuit_fingerprint(K), circuit_fingerprint(K));
+        assert_ne!(circuit_fingerprint(K), circuit_fingerprint(K + 1));
+    }
+}
@@ -1,13 +1,16 @@
 use halo2_proofs::{
     arithmetic::CurveAffine,
-    pasta::{group::ff::PrimeField, EpAffine, Fp},
+    pasta::{EpAffine, Fp},
 };
 
 pub mod commit;
 pub mod fixed_bases;
+pub mod merkle;
+pub mod message;
 
 /**
  * Given an affine point on the base field, parse x and y coordinates
+ * @dev panics on the identity, use Commitment::coordinates for untrusted points
  *
  * @return (x, y) - 256-bit x and y coordinates for pedersen commitment
  */
@@ -16,3 +19,40 @@ pub fn get_coordinates(point: EpAffine) -> (Fp, Fp) {
     let y = point.clone().coordinates().unwrap().y().to_owned();
     (x, y)
 }
+
+/// Encode bytes as a lowercase hex string
+pub fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Decode a hex string, returning None if it has odd length or non-hex characters
+pub fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    // from_str_radix also accepts a leading sign, so check the digits explicitly
+    if !hex.len().is_multiple_of(2) || !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hex_round_trip() {
+        let bytes = [0x00, 0x0f, 0xab, 0xff];
+        assert_eq!(to_hex(&bytes), "000fabff");
+        assert_eq!(from_hex("000fabff"), Some(bytes.to_vec()));
+        assert_eq!(from_hex("000FABFF"), Some(bytes.to_vec()));
+        assert_eq!(from_hex(""), Some(vec![]));
+        // odd lengths, signs and other non-hex characters are rejected
+        assert_eq!(from_hex("abc"), None);
+        assert_eq!(from_hex("+f"), None);
+        assert_eq!(from_hex("-f"), None);
+        assert_eq!(from_hex("0g"), None);
+        assert_eq!(from_hex("é0"), None);
+    }
+}
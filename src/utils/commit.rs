@@ -1,27 +1,398 @@
 use {
+    super::{from_hex, to_hex},
     crate::constants::{
-        BOARD_COMMITMENT_PERSONALIZATION, BOARD_COMMITMENT_R_BYTES, BOARD_COMMITMENT_V_BYTES,
+        fixed_bases::domain::CommitmentDomain,
+        vector_commitment_v_bytes, BOARD_COMMITMENT_PERSONALIZATION, BOARD_COMMITMENT_Q_BYTES,
+        BOARD_COMMITMENT_R_BYTES, BOARD_COMMITMENT_V_BYTES, VECTOR_COMMITMENT_SIZE,
     },
-    halo2_proofs::{arithmetic::CurveExt, pasta::{pallas, group::ff::PrimeField}},
+    halo2_gadgets::poseidon::primitives::{self as poseidon, ConstantLength, P128Pow5T3},
+    halo2_proofs::{
+        arithmetic::{Coordinates, CurveAffine, CurveExt, Field, FieldExt},
+        pasta::{
+            group::{ff::PrimeField, Curve, Group, GroupEncoding},
+            pallas,
+        },
+    },
+    rand::RngCore,
+    serde::{de, Deserialize, Deserializer, Serialize, Serializer},
 };
 
+/**
+ * Compute a pedersen commitment under the generators of a commitment domain
+ *
+ * @param domain - the commitment domain, CommitmentDomain::default() for the battlezips
+ *                 generators or the domain given to domain_pedersen_commitment
+ * @param value - the committed base field element
+ * @param rcv - the trapdoor
+ * @return - [value] V + [rcv] R for the domain's value and blinding generators
+ */
+pub fn derive_commitment(
+    domain: &CommitmentDomain,
+    value: &pallas::Base,
+    rcv: &pallas::Scalar,
+) -> pallas::Point {
+    // get curve points used in scalar multiplication from the commitment domain
+    let v = pallas::Point::from(domain.value_generator());
+    let r = pallas::Point::from(domain.blinding_generator());
+    // the pallas base field is smaller than its scalar field, so the value converts exactly
+    let value = pallas::Scalar::from_repr(value.to_repr()).unwrap();
+
+    // compute the pedersen commitment for the given value + trapdoor
+    v * value + r * rcv
+}
 
-pub fn derive_commitment(value: &pallas::Base, rcv: &pallas::Scalar) -> pallas::Point {
+/**
+ * Compute a pedersen commitment on either curve of the Pasta cycle
+ * @dev the vesta base field is larger than its scalar field, so vesta values are reduced
+ *      modulo the scalar field as fixed-base scalar multiplication by a base field element does
+ *
+ * @param value - the committed base field element
+ * @param rcv - the trapdoor
+ * @return - [value] BoardCommitV + [rcv] BoardCommitR on curve C
+ */
+pub fn derive_curve_commitment<C: CurveAffine>(value: &C::Base, rcv: &C::Scalar) -> C::CurveExt {
     // get curve points used in scalar multiplication
-    let hasher = pallas::Point::hash_to_curve(BOARD_COMMITMENT_PERSONALIZATION);
-    let V = hasher(&BOARD_COMMITMENT_V_BYTES);
-    let R = hasher(&BOARD_COMMITMENT_R_BYTES);
+    let hasher = C::CurveExt::hash_to_curve(BOARD_COMMITMENT_PERSONALIZATION);
+    let v = hasher(&BOARD_COMMITMENT_V_BYTES);
+    let r = hasher(&BOARD_COMMITMENT_R_BYTES);
     // convert base field element to scalar
     // https://github.com/zcash/orchard/blob/d05b6cee9df7c4019509e2f54899b5979fb641b5/src/spec.rs#L195
+    let mut bytes = [0u8; 64];
+    bytes[..32].copy_from_slice(value.to_repr().as_ref());
+    let value = C::Scalar::from_bytes_wide(&bytes);
+
+    // compute the pedersen commitment for the given value + trapdoor
+    v * value + r * rcv
+}
+
+/**
+ * Re-randomize a commitment so it can be republished unlinkably
+ *
+ * @param commitment - the commitment C = [v] BoardCommitV + [r] BoardCommitR
+ * @param delta - the trapdoor offset
+ * @return - C + [delta] BoardCommitR, which opens to v with trapdoor r + delta
+ */
+pub fn rerandomize(commitment: &Commitment, delta: &Trapdoor) -> Commitment {
+    let hasher = pallas::Point::hash_to_curve(BOARD_COMMITMENT_PERSONALIZATION);
+    let r = hasher(&BOARD_COMMITMENT_R_BYTES);
+    Commitment(commitment.0 + r * delta.0)
+}
+
+/// A pedersen commitment [v] BoardCommitV + [r] BoardCommitR
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Commitment(pallas::Point);
+
+impl Commitment {
+    /// Returns the commitment point
+    pub fn inner(&self) -> pallas::Point {
+        self.0
+    }
+
+    /**
+     * Returns the (x, y) coordinates exposed as public inputs by PedersenCommitmentCircuit
+     * @dev the zero opening, or C + (-C), commits to the identity, which has no coordinates
+     *
+     * @return - (x, y), or None if the commitment is the identity
+     */
+    pub fn coordinates(&self) -> Option<(pallas::Base, pallas::Base)> {
+        Option::<Coordinates<_>>::from(self.0.to_affine().coordinates())
+            .map(|coordinates| (*coordinates.x(), *coordinates.y()))
+    }
+
+    /**
+     * Compress the commitment to the public inputs exposed by CompressedCommitmentCircuit
+     * @dev the sign is the parity of y, the same bit set in the top of to_bytes
+     *
+     * @return - (x, sign) with sign either 0 or 1, or None if the commitment is the identity
+     */
+    pub fn compressed_coordinates(&self) -> Option<(pallas::Base, pallas::Base)> {
+        let (x, y) = self.coordinates()?;
+        let sign = y.to_repr()[0] & 1;
+        Some((x, pallas::Base::from(sign as u64)))
+    }
+
+    /// Returns Poseidon(x, y), the public input exposed by HashedCommitmentCircuit, or None if
+    /// the commitment is the identity
+    pub fn hash(&self) -> Option<pallas::Base> {
+        let (x, y) = self.coordinates()?;
+        Some(poseidon::Hash::<_, P128Pow5T3, ConstantLength<2>, 3, 2>::init().hash([x, y]))
+    }
+
+    /**
+     * Homomorphically add two commitments, C1 + C2 opens to (v1 + v2, r1 + r2)
+     *
+     * @param other - the commitment to add
+     * @return - the summed commitment
+     */
+    pub fn add(&self, other: &Commitment) -> Commitment {
+        Commitment(self.0 + other.0)
+    }
+
+    /// Returns the compressed 32 byte encoding of the commitment
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0.to_bytes()
+    }
+
+    /// Parses a compressed 32 byte encoding, returning None if it is not a curve point or is the
+    /// identity, which no circuit can expose as a commitment
+    pub fn from_bytes(bytes: &[u8; 32]) -> Option<Commitment> {
+        Option::<pallas::Point>::from(pallas::Point::from_bytes(bytes))
+            .filter(|point| !bool::from(point.is_identity()))
+            .map(Commitment)
+    }
+}
+
+impl From<pallas::Point> for Commitment {
+    fn from(point: pallas::Point) -> Self {
+        Commitment(point)
+    }
+}
+
+/// The blinding factor r of a pedersen commitment
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Trapdoor(pallas::Scalar);
+
+impl Trapdoor {
+    /// Samples a uniformly random trapdoor
+    pub fn random(rng: impl RngCore) -> Self {
+        Trapdoor(pallas::Scalar::random(rng))
+    }
+
+    /// Returns the trapdoor as a scalar
+    pub fn inner(&self) -> pallas::Scalar {
+        self.0
+    }
+
+    /// Returns the 32 byte little endian encoding of the trapdoor
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0.to_repr()
+    }
+
+    /// Parses a 32 byte encoding, returning None if it is not a canonical scalar
+    pub fn from_bytes(bytes: &[u8; 32]) -> Option<Trapdoor> {
+        Option::from(pallas::Scalar::from_repr(*bytes)).map(Trapdoor)
+    }
+}
+
+impl From<pallas::Scalar> for Trapdoor {
+    fn from(scalar: pallas::Scalar) -> Self {
+        Trapdoor(scalar)
+    }
+}
+
+/// The value and trapdoor that open a pedersen commitment
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Opening {
+    #[serde(with = "serde_base")]
+    pub value: pallas::Base,
+    pub trapdoor: Trapdoor,
+}
+
+impl Opening {
+    pub fn new(value: pallas::Base, trapdoor: Trapdoor) -> Self {
+        Opening { value, trapdoor }
+    }
+
+    /// Returns the commitment to this opening
+    pub fn commit(&self) -> Commitment {
+        Commitment(derive_commitment(
+            &CommitmentDomain::default(),
+            &self.value,
+            &self.trapdoor.0,
+        ))
+    }
+
+    /// Returns true if this opening opens the commitment
+    pub fn verify(&self, commitment: &Commitment) -> bool {
+        self.commit() == *commitment
+    }
+
+    /**
+     * Add two openings so that the sum opens the sum of their commitments
+     * @dev C1 + C2 commits to the integer sum v1 + v2 reduced modulo the scalar field, which only
+     *      has an opening in the base field if v1 + v2 is below the base field modulus p
+     *
+     * @param other - the opening to add
+     * @return - (v1 + v2, r1 + r2), or None if the integer sum v1 + v2 is at least p
+     */
+    pub fn add(&self, other: &Opening) -> Option<Opening> {
+        let value = self.value + other.value;
+        // the sum wrapped modulo p exactly when it is smaller than a summand
+        let wrapped = value
+            .to_repr()
+            .iter()
+            .rev()
+            .lt(self.value.to_repr().iter().rev());
+        if wrapped {
+            return None;
+        }
+        Some(Opening {
+            value,
+            trapdoor: Trapdoor(self.trapdoor.0 + other.trapdoor.0),
+        })
+    }
+
+    /// Returns the opening of rerandomize(self.commit(), delta)
+    pub fn rerandomize(&self, delta: &Trapdoor) -> Opening {
+        Opening::new(self.value, Trapdoor(self.trapdoor.0 + delta.0))
+    }
+
+    /// Returns the 64 byte encoding of the value followed by the trapdoor
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&self.value.to_repr());
+        bytes[32..].copy_from_slice(&self.trapdoor.to_bytes());
+        bytes
+    }
+
+    /// Parses a 64 byte encoding, returning None if either half is not canonical
+    pub fn from_bytes(bytes: &[u8; 64]) -> Option<Opening> {
+        let value = Option::from(pallas::Base::from_repr(bytes[..32].try_into().unwrap()))?;
+        let trapdoor = Trapdoor::from_bytes(bytes[32..].try_into().unwrap())?;
+        Some(Opening { value, trapdoor })
+    }
+}
+
+// commitments, trapdoors and opening values are serialized as hex strings of their bytes
+impl Serialize for Commitment {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&to_hex(&self.to_bytes()))
+    }
+}
+
+impl<'de> Deserialize<'de> for Commitment {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Commitment::from_bytes(&deserialize_bytes(deserializer)?)
+            .ok_or_else(|| de::Error::custom("invalid commitment"))
+    }
+}
+
+impl Serialize for Trapdoor {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&to_hex(&self.to_bytes()))
+    }
+}
+
+impl<'de> Deserialize<'de> for Trapdoor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Trapdoor::from_bytes(&deserialize_bytes(deserializer)?)
+            .ok_or_else(|| de::Error::custom("invalid trapdoor"))
+    }
+}
+
+mod serde_base {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(value: &pallas::Base, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&to_hex(&value.to_repr()))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<pallas::Base, D::Error> {
+        Option::from(pallas::Base::from_repr(deserialize_bytes(deserializer)?))
+            .ok_or_else(|| de::Error::custom("invalid base field element"))
+    }
+}
+
+// parse a hex string holding exactly 32 bytes
+fn deserialize_bytes<'de, D: Deserializer<'de>>(deserializer: D) -> Result<[u8; 32], D::Error> {
+    let hex = String::deserialize(deserializer)?;
+    from_hex(&hex)
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| de::Error::custom("expected 32 hex encoded bytes"))
+}
+
+/**
+ * Compute a pedersen commitment to a vector of values, each over its own generator
+ *
+ * @param values - the committed base field elements, at most VECTOR_COMMITMENT_SIZE
+ * @param rcv - the trapdoor
+ * @return - the commitment point, or None if there are more values than generators
+ */
+pub fn derive_vector_commitment(
+    values: &[pallas::Base],
+    rcv: &pallas::Scalar,
+) -> Option<pallas::Point> {
+    // vector commitments can only be opened in-circuit for generators with fixed base tables
+    if values.len() > VECTOR_COMMITMENT_SIZE {
+        return None;
+    }
+    // get blinding curve point used in scalar multiplication
+    let hasher = pallas::Point::hash_to_curve(BOARD_COMMITMENT_PERSONALIZATION);
+    let r = hasher(&BOARD_COMMITMENT_R_BYTES);
+    // place each value over its own generator and sum with the blinding term
+    Some(
+        values
+            .iter()
+            .enumerate()
+            .fold(r * rcv, |commitment, (i, value)| {
+                let v = hasher(&vector_commitment_v_bytes(i));
+                let value = pallas::Scalar::from_repr(value.to_repr()).unwrap();
+                commitment + v * value
+            }),
+    )
+}
+
+/**
+ * Compute a pedersen commitment under generators known only at proving time
+ * @dev the trapdoor is a base field element as variable_pedersen_commitment expects in-circuit,
+ *      the pallas base field is smaller than its scalar field so it maps into it unchanged
+ *
+ * @param value_generator - the generator V the value is placed over
+ * @param blinding_generator - the generator R the trapdoor is placed over
+ * @param value - the committed base field element
+ * @param rcv - the trapdoor
+ * @return - [value] V + [rcv] R
+ */
+pub fn derive_variable_commitment(
+    value_generator: pallas::Affine,
+    blinding_generator: pallas::Affine,
+    value: &pallas::Base,
+    rcv: &pallas::Base,
+) -> pallas::Point {
+    // convert base field elements to scalars
     let value = pallas::Scalar::from_repr(value.to_repr()).unwrap();
+    let rcv = pallas::Scalar::from_repr(rcv.to_repr()).unwrap();
+
+    // compute the pedersen commitment for the given value + trapdoor
+    value_generator * value + blinding_generator * rcv
+}
+
+/**
+ * Compute a short value commitment to a signed 64 bit value
+ * @dev BoardCommitQ only has short scalar tables, so the value is given as magnitude and sign
+ *      as ScalarFixedShort expects in-circuit
+ *
+ * @param magnitude - the absolute value of the committed value
+ * @param negative - whether the committed value is negative
+ * @param rcv - the trapdoor
+ * @return - [±magnitude] BoardCommitQ + [rcv] BoardCommitR
+ */
+pub fn derive_short_commitment(
+    magnitude: u64,
+    negative: bool,
+    rcv: &pallas::Scalar,
+) -> pallas::Point {
+    // get curve points used in scalar multiplication
+    let hasher = pallas::Point::hash_to_curve(BOARD_COMMITMENT_PERSONALIZATION);
+    let q = hasher(&BOARD_COMMITMENT_Q_BYTES);
+    let r = hasher(&BOARD_COMMITMENT_R_BYTES);
+    // apply the sign to the magnitude in the scalar field
+    let value = match negative {
+        true => -pallas::Scalar::from(magnitude),
+        false => pallas::Scalar::from(magnitude),
+    };
 
     // compute the pedersen commitment for the given value + trapdoor
-    V * value + R * rcv
+    q * value + r * rcv
 }
 
 #[cfg(test)]
 mod test {
-    use halo2_proofs::pasta::group::Curve;
+    use {
+        crate::{constants::fixed_bases::BoardCurve, utils::merkle::MerkleTree},
+        halo2_proofs::pasta::vesta,
+        rand::rngs::OsRng,
+    };
 
     use super::*;
 
@@ -29,7 +400,180 @@ mod test {
     fn test_pedersen() {
         let value = pallas::Base::from(100);
         let rcv = pallas::Scalar::from(300);
-        let commitment = derive_commitment(&value, &rcv).to_affine();
+        let commitment = derive_commitment(&CommitmentDomain::default(), &value, &rcv).to_affine();
         println!("commitment: {:?}", commitment);
     }
+
+    #[test]
+    fn test_vesta_pedersen() {
+        let rcv = vesta::Scalar::random(OsRng);
+        let v = vesta::Affine::value_generator();
+        let r = vesta::Affine::blinding_generator();
+        // values that fit in the scalar field are used as is
+        let small = vesta::Base::from(88675409);
+        let expected = v * vesta::Scalar::from(88675409) + r * rcv;
+        assert_eq!(derive_curve_commitment::<vesta::Affine>(&small, &rcv), expected);
+        // larger values are reduced modulo the scalar field
+        let modulus = vesta::Base::from_repr((-vesta::Scalar::one()).to_repr()).unwrap()
+            + vesta::Base::one();
+        assert_eq!(
+            derive_curve_commitment::<vesta::Affine>(&(small + modulus), &rcv),
+            expected
+        );
+        // the pallas commitment is unchanged
+        let value = pallas::Base::random(OsRng);
+        let rcv = pallas::Scalar::random(OsRng);
+        let scalar = pallas::Scalar::from_repr(value.to_repr()).unwrap();
+        let expected = pallas::Affine::value_generator() * scalar
+            + pallas::Affine::blinding_generator() * rcv;
+        assert_eq!(
+            derive_commitment(&CommitmentDomain::default(), &value, &rcv),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_add_commitments() {
+        let o1 = Opening::new(pallas::Base::from(100), pallas::Scalar::from(300).into());
+        let o2 = Opening::new(pallas::Base::from(25), pallas::Scalar::from(7).into());
+        let sum = o1.add(&o2).unwrap();
+        assert_eq!(sum.value, pallas::Base::from(125));
+        assert_eq!(sum.trapdoor, pallas::Scalar::from(307).into());
+        // the summed commitment opens to the summed value and trapdoor
+        assert!(sum.verify(&o1.commit().add(&o2.commit())));
+        // sums of at least p have no opening, whether or not they also reach the scalar modulus
+        let max = Opening::new(-pallas::Base::one(), pallas::Scalar::from(1).into());
+        assert!(max.add(&o1).is_none());
+        assert!(max.add(&max).is_none());
+        let one = Opening::new(pallas::Base::one(), pallas::Scalar::from(1).into());
+        assert!(max.add(&one).is_none());
+        let below = Opening::new(-pallas::Base::from(2), pallas::Scalar::from(1).into());
+        assert_eq!(below.add(&one).unwrap().value, -pallas::Base::one());
+    }
+
+    #[test]
+    fn test_rerandomize() {
+        let opening = Opening::new(pallas::Base::from(100), Trapdoor::random(OsRng));
+        let delta = Trapdoor::random(OsRng);
+        let refreshed = rerandomize(&opening.commit(), &delta);
+        assert_ne!(refreshed, opening.commit());
+        // the refreshed commitment opens to the same value with the offset trapdoor
+        assert!(opening.rerandomize(&delta).verify(&refreshed));
+        assert_eq!(opening.rerandomize(&delta).value, opening.value);
+    }
+
+    #[test]
+    fn test_opening() {
+        let opening = Opening::new(pallas::Base::from(100), Trapdoor::random(OsRng));
+        let commitment = opening.commit();
+        assert!(opening.verify(&commitment));
+        // a different value or trapdoor does not open the commitment
+        let other = Opening::new(pallas::Base::from(101), opening.trapdoor);
+        assert!(!other.verify(&commitment));
+        let other = Opening::new(opening.value, Trapdoor::random(OsRng));
+        assert!(!other.verify(&commitment));
+    }
+
+    #[test]
+    fn test_encoding() {
+        let opening = Opening::new(pallas::Base::random(OsRng), Trapdoor::random(OsRng));
+        let commitment = opening.commit();
+        // compressed bytes
+        assert_eq!(Commitment::from_bytes(&commitment.to_bytes()), Some(commitment));
+        assert_eq!(Opening::from_bytes(&opening.to_bytes()), Some(opening));
+        assert_eq!(Opening::from_bytes(&[0xff; 64]), None);
+        // compressed public inputs carry the same x and sign bit as the byte encoding
+        let (x, sign) = commitment.compressed_coordinates().unwrap();
+        let mut bytes = x.to_repr();
+        bytes[31] |= (sign == pallas::Base::one()) as u8 * 0x80;
+        assert_eq!(bytes, commitment.to_bytes());
+        // hex json
+        let json = serde_json::to_string(&commitment).unwrap();
+        assert_eq!(json, format!("\"{}\"", to_hex(&commitment.to_bytes())));
+        assert_eq!(serde_json::from_str::<Commitment>(&json).unwrap(), commitment);
+        let json = serde_json::to_string(&opening).unwrap();
+        assert_eq!(serde_json::from_str::<Opening>(&json).unwrap(), opening);
+        // non-canonical and malformed encodings are rejected
+        let invalid = format!("\"{}\"", to_hex(&[0xff; 32]));
+        assert!(serde_json::from_str::<Trapdoor>(&invalid).is_err());
+        assert!(serde_json::from_str::<Commitment>("\"00\"").is_err());
+        assert!(serde_json::from_str::<Commitment>("\"zz\"").is_err());
+    }
+
+    #[test]
+    fn test_identity() {
+        // the identity encodes as zero bytes and is rejected when parsed
+        assert_eq!(Commitment::from_bytes(&[0; 32]), None);
+        let json = format!("\"{}\"", to_hex(&[0; 32]));
+        assert!(serde_json::from_str::<Commitment>(&json).is_err());
+        // commitments that reach the identity have no coordinates
+        let zero = Opening::new(pallas::Base::zero(), Trapdoor::from(pallas::Scalar::zero()));
+        let identity = zero.commit();
+        assert_eq!(identity.coordinates(), None);
+        assert_eq!(identity.compressed_coordinates(), None);
+        assert_eq!(identity.hash(), None);
+        let commitment = Opening::new(pallas::Base::one(), Trapdoor::random(OsRng)).commit();
+        let cancelled = commitment.add(&Commitment::from(-commitment.inner()));
+        assert_eq!(cancelled.coordinates(), None);
+        assert!(MerkleTree::<1>::from_commitments(&[commitment, cancelled]).is_none());
+    }
+
+    #[test]
+    fn test_short_pedersen() {
+        let r1 = pallas::Scalar::random(OsRng);
+        let r2 = pallas::Scalar::random(OsRng);
+        // opposite values cancel, leaving only the blinding terms
+        let sum = derive_short_commitment(u64::MAX, false, &r1)
+            + derive_short_commitment(u64::MAX, true, &r2);
+        assert_eq!(sum, derive_short_commitment(0, false, &(r1 + r2)));
+        assert_eq!(
+            derive_short_commitment(0, true, &r1),
+            derive_short_commitment(0, false, &r1)
+        );
+        // the short commitment is placed over its own generator
+        assert_ne!(
+            derive_short_commitment(100, false, &r1),
+            derive_commitment(&CommitmentDomain::default(), &pallas::Base::from(100), &r1)
+        );
+    }
+
+    #[test]
+    fn test_domain_pedersen() {
+        let value = pallas::Base::from(100);
+        let rcv = pallas::Scalar::from(300);
+        // the default domain matches the battlezips generators on either curve
+        assert_eq!(
+            derive_commitment(&CommitmentDomain::default(), &value, &rcv),
+            derive_curve_commitment::<pallas::Affine>(&value, &rcv)
+        );
+        // other domains produce unrelated commitments
+        assert_ne!(
+            derive_commitment(&CommitmentDomain::new("battlezips:test"), &value, &rcv),
+            derive_commitment(&CommitmentDomain::default(), &value, &rcv)
+        );
+    }
+
+    #[test]
+    fn test_vector_pedersen() {
+        let value = pallas::Base::from(100);
+        let rcv = pallas::Scalar::from(300);
+        // an empty vector commits only to the blinding term
+        let blind = pallas::Point::hash_to_curve(BOARD_COMMITMENT_PERSONALIZATION)(
+            &BOARD_COMMITMENT_R_BYTES,
+        ) * rcv;
+        assert_eq!(derive_vector_commitment(&[], &rcv), Some(blind));
+        // vector generators are independent of the single value generator
+        let single = derive_commitment(&CommitmentDomain::default(), &value, &rcv);
+        assert_ne!(derive_vector_commitment(&[value], &rcv), Some(single));
+        // element order is bound by the commitment
+        let other = pallas::Base::from(200);
+        assert_ne!(
+            derive_vector_commitment(&[value, other], &rcv),
+            derive_vector_commitment(&[other, value], &rcv)
+        );
+        // vectors longer than the generated tables are rejected
+        let values = [value; VECTOR_COMMITMENT_SIZE + 1];
+        assert!(derive_vector_commitment(&values[..VECTOR_COMMITMENT_SIZE], &rcv).is_some());
+        assert_eq!(derive_vector_commitment(&values, &rcv), None);
+    }
 }
\ No newline at end of file
@@ -1,91 +1,630 @@
-// use std::fs::File;
-
-// use halo2_gadgets::ecc::chip::constants::{compute_lagrange_coeffs, find_zs_and_us};
-// use halo2_proofs::{
-//     arithmetic::{CurveAffine, CurveExt},
-//     pasta::{
-//         group::{ff::PrimeField, Curve, Group},
-//         pallas,
-//     },
-// };
-
-// use serde::{Deserialize, Serialize};
-// use serde_json::*;
-
-// const NUM_WINDOWS: usize = 85; // 3 bit windows for 255 bit number = 85 windows
-// const H: usize = 1 << 3; // idfk
-
-// #[derive(Serialize, Deserialize)]
-// struct Data {
-//     data: Vec<Vec<Vec<u8>>>,
-// }
-
-// fn main() {
-//     let point = pallas::Point::hash_to_curve("battlezips:hash2curve")(b"v");
-//     let x = point
-//         .to_affine()
-//         .coordinates()
-//         .unwrap()
-//         .x()
-//         .to_repr()
-//         .as_ref();
-//     let y = point
-//         .to_affine()
-//         .coordinates()
-//         .unwrap()
-//         .y()
-//         .to_repr()
-//         .as_ref();
-//     // println!("x: {:?}",);
-//     println!(
-//         "y: {:?}",
-//         point
-//             .to_affine()
-//             .coordinates()
-//             .unwrap()
-//             .y()
-//             .to_repr()
-//             .as_ref()
-//     );
-//     // let (z, u): (Vec<u64>, Vec<[pallas::Base; 8]>) = find_zs_and_us(point.to_affine(), NUM_WINDOWS)
-//     //     .unwrap()
-//     //     .into_iter()
-//     //     .unzip();
-
-//     // let u: Vec<Vec<Vec<u8>>> = u
-//     //     .iter()
-//     //     .map(|x: &[Fp; 8]| {
-//     //         x.iter()
-//     //             .map(|y: &Fp| y.to_repr().as_ref().to_owned().try_into().unwrap())
-//     //             .collect::<Vec<_>>()
-//     //     })
-//     //     .collect::<Vec<_>>();
-
-//     // let data = serde_json::json!({
-//     //     "z": z,
-//     //     "u": u
-//     // });
-//     // to_writer(&File::create("data.json").unwrap(), &data).unwrap();
-// }
-
-// /**
-//  * Get a generator point with random distribution on pallas curve
-//  * @dev point chosen by using hash2curve to pallas with domain separator + personalization
-//  *
-//  * @param separator - the domain separator specifying the protocol using the commitment
-//  * @param personalization - personalization used to differentiate between commitments within protocol
-//  * @return - affine (x, y) representation of the poin
-//  */
-// fn get_generator(separator: &str, personalization: &[u8; 1]) -> pallas::Affine {
-//     pallas::Point::hash_to_curve("battlezips:hash2curve")(b"v").to_affine()
-// }
-
-// /**
-//  * Given an affine point on the pallas curve, return the x and y coordinates
-//  * @dev x and y are returned as vectors
-//  */
-// fn get_generator_coordinates(point: pallas::Affine) -> (Vec<u8>, Vec<u8>) {
-//     let x = point.clone().coordinates().unwrap().x().to_repr().as_ref();
-//     let y = point.coordinates().unwrap().y().to_repr().as_ref();
-//     (x.to_vec(), y.to_vec())
-// }
+use {
+    crate::constants::{
+        fixed_bases::BoardCurve, vector_commitment_v_bytes, BOARD_COMMITMENT_PERSONALIZATION,
+        BOARD_COMMITMENT_Q_BYTES, BOARD_COMMITMENT_R_BYTES, BOARD_COMMITMENT_V_BYTES,
+        VECTOR_COMMITMENT_SIZE,
+    },
+    halo2_gadgets::ecc::chip::constants::{find_zs_and_us, H, NUM_WINDOWS, NUM_WINDOWS_SHORT},
+    halo2_proofs::{
+        arithmetic::{CurveAffine, CurveExt, Field},
+        pasta::{
+            group::{ff::PrimeField, Curve},
+            pallas,
+        },
+    },
+    std::{fs, io, path::Path},
+};
+
+// rustfmt max_width, generated arrays are laid out to stay below it as rustfmt does
+const MAX_WIDTH: usize = 100;
+
+/// Modules in constants::fixed_bases that are generated from hash to curve
+pub const FIXED_BASE_MODULES: [&str; 4] = [
+    "board_commit_v",
+    "board_commit_r",
+    "board_commit_q",
+    "vector_commit_v",
+];
+
+// names of the window tables rendered into a fixed base module
+struct WindowTables {
+    kind: &'static str,
+    num_windows: &'static str,
+    z: &'static str,
+    u: &'static str,
+}
+
+// tables for multiplication by a full-width scalar or base field element
+const FULL_WIDTH: WindowTables = WindowTables {
+    kind: "Full-width",
+    num_windows: "NUM_WINDOWS",
+    z: "Z",
+    u: "U",
+};
+
+// tables for multiplication by a signed 64 bit short scalar
+const SHORT: WindowTables = WindowTables {
+    kind: "Short signed",
+    num_windows: "NUM_WINDOWS_SHORT",
+    z: "Z_SHORT",
+    u: "U_SHORT",
+};
+
+/// Generator for a fixed base and its precomputed window tables
+#[derive(Clone, Debug)]
+pub struct FixedBaseTable<C: CurveAffine = pallas::Affine> {
+    pub generator: C,
+    pub z: Vec<u64>,
+    pub u: Vec<[[u8; 32]; H]>,
+}
+
+impl<C: CurveAffine> FixedBaseTable<C> {
+    /**
+     * Derive a generator with SWU hash to curve and compute its fixed-base window tables
+     * @dev find_zs_and_us is a brute force search that takes minutes per generator
+     *
+     * @param personalization - the domain separator for hash to curve
+     * @param bytes - the value hashed to the curve to select the generator
+     * @return - generator with z and u values for each of the NUM_WINDOWS windows
+     */
+    pub fn derive(personalization: &str, bytes: &[u8]) -> Self {
+        Self::derive_windows(personalization, bytes, NUM_WINDOWS)
+    }
+
+    /**
+     * Derive a generator with SWU hash to curve and compute its short signed window tables
+     *
+     * @param personalization - the domain separator for hash to curve
+     * @param bytes - the value hashed to the curve to select the generator
+     * @return - generator with z and u values for each of the NUM_WINDOWS_SHORT windows
+     */
+    pub fn derive_short(personalization: &str, bytes: &[u8]) -> Self {
+        Self::derive_windows(personalization, bytes, NUM_WINDOWS_SHORT)
+    }
+
+    /**
+     * Recompute the u-values of a generator from known z-values
+     * @dev only the search for z is expensive, given z each u is a single square root
+     *
+     * @param generator - the fixed base
+     * @param z - a z-value for each of the NUM_WINDOWS windows, i.e. from an earlier derive
+     * @return - the window tables, or None if a z-value is not valid for its window
+     */
+    pub fn from_zs(generator: C, z: Vec<u64>) -> Option<Self>
+    where
+        C::Base: PrimeField<Repr = [u8; 32]>,
+    {
+        let u = window_table(generator, z.len())
+            .iter()
+            .zip(z.iter())
+            .map(|(window, z)| {
+                let z = C::Base::from(*z);
+                let mut us = [[0u8; 32]; H];
+                for (u, point) in us.iter_mut().zip(window.iter()) {
+                    let y = *point.coordinates().unwrap().y();
+                    if bool::from((z - y).sqrt().is_some()) {
+                        return None;
+                    }
+                    *u = Option::<C::Base>::from((z + y).sqrt())?.to_repr();
+                }
+                Some(us)
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(FixedBaseTable { generator, z, u })
+    }
+
+    fn derive_windows(personalization: &str, bytes: &[u8], num_windows: usize) -> Self {
+        let generator = C::CurveExt::hash_to_curve(personalization)(bytes).to_affine();
+        let (z, u) = find_zs_and_us(generator, num_windows)
+            .expect("no z and u values found for generator")
+            .into_iter()
+            .map(|(z, us)| (z, us.map(|u| u.to_repr().as_ref().try_into().unwrap())))
+            .unzip();
+        FixedBaseTable { generator, z, u }
+    }
+}
+
+// the multiples of a fixed base in each window, as computed by halo2_gadgets for find_zs_and_us
+fn window_table<C: CurveAffine>(base: C, num_windows: usize) -> Vec<[C; H]> {
+    let eight = C::Scalar::from(H as u64);
+    // sum of 2^(3j + 1) over every window but the last
+    let sum = (0..num_windows - 1).fold(C::Scalar::zero(), |acc, j| {
+        acc + C::Scalar::from(2).pow_vartime([3 * j as u64 + 1])
+    });
+    (0..num_windows)
+        .map(|w| {
+            let power = eight.pow_vartime([w as u64]);
+            let mut window = [base; H];
+            for (k, point) in window.iter_mut().enumerate() {
+                // [(k + 2) * 8^w] B, or [k * 8^w - sum] B in the last window
+                let scalar = match w == num_windows - 1 {
+                    true => C::Scalar::from(k as u64) * power - sum,
+                    false => C::Scalar::from(k as u64 + 2) * power,
+                };
+                *point = (base * scalar).to_affine();
+            }
+            window
+        })
+        .collect()
+}
+
+/**
+ * Derive and render the source of one of the FIXED_BASE_MODULES
+ *
+ * @param name - the module name, i.e. "board_commit_v"
+ * @return - the module source, or None if the module is not generated
+ */
+pub fn generate_fixed_base_module(name: &str) -> Option<String> {
+    match name {
+        "board_commit_v" => Some(render_fixed_base_module(
+            &FixedBaseTable::<pallas::Affine>::derive(
+                BOARD_COMMITMENT_PERSONALIZATION,
+                &BOARD_COMMITMENT_V_BYTES,
+            ),
+            BOARD_COMMIT_V_DOC,
+            "BOARD_COMMITMENT_V_BYTES",
+        )),
+        "board_commit_r" => Some(render_fixed_base_module(
+            &FixedBaseTable::<pallas::Affine>::derive(
+                BOARD_COMMITMENT_PERSONALIZATION,
+                &BOARD_COMMITMENT_R_BYTES,
+            ),
+            BOARD_COMMIT_R_DOC,
+            "BOARD_COMMITMENT_R_BYTES",
+        )),
+        "board_commit_q" => Some(render_short_fixed_base_module(
+            &FixedBaseTable::<pallas::Affine>::derive_short(
+                BOARD_COMMITMENT_PERSONALIZATION,
+                &BOARD_COMMITMENT_Q_BYTES,
+            ),
+            BOARD_COMMIT_Q_DOC,
+            "BOARD_COMMITMENT_Q_BYTES",
+        )),
+        "vector_commit_v" => {
+            let tables = (0..VECTOR_COMMITMENT_SIZE)
+                .map(|index| {
+                    FixedBaseTable::derive(
+                        BOARD_COMMITMENT_PERSONALIZATION,
+                        &vector_commitment_v_bytes(index),
+                    )
+                })
+                .collect::<Vec<_>>();
+            Some(render_vector_fixed_base_module(&tables))
+        }
+        _ => None,
+    }
+}
+
+/**
+ * Regenerate FIXED_BASE_MODULES from hash to curve and write them to a directory
+ * @dev each generator takes minutes to derive, run with --release
+ *
+ * @param out_dir - directory the modules are written to, usually src/constants/fixed_bases
+ * @param modules - modules to regenerate, all of FIXED_BASE_MODULES if empty
+ * @param progress - called with each module name before it is generated
+ * @return - Ok once every module is written, or an InvalidInput error for an unknown module
+ */
+pub fn write_fixed_base_modules(
+    out_dir: &Path,
+    modules: &[String],
+    mut progress: impl FnMut(&str),
+) -> io::Result<()> {
+    let modules = match modules.is_empty() {
+        true => FIXED_BASE_MODULES
+            .iter()
+            .map(|name| name.to_string())
+            .collect(),
+        false => modules.to_vec(),
+    };
+    for name in modules {
+        progress(&name);
+        let source = generate_fixed_base_module(&name).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "unknown module {}, expected one of {:?}",
+                    name, FIXED_BASE_MODULES
+                ),
+            )
+        })?;
+        let path = out_dir.join(format!("{}.rs", name));
+        fs::write(&path, source).map_err(|e| {
+            io::Error::new(
+                e.kind(),
+                format!("failed to write {}: {}", path.display(), e),
+            )
+        })?;
+    }
+    Ok(())
+}
+
+const BOARD_COMMIT_V_DOC: &str = "\
+/// The ship commitment is used to bind a board configuration. The value is placed over this
+/// generator.
+";
+
+const BOARD_COMMIT_R_DOC: &str = "\
+/// The blinding generator hides a board configuration in the ship commitment. The trapdoor is
+/// placed over this generator.
+";
+
+const BOARD_COMMIT_Q_DOC: &str = "\
+/// The short value commitment places a signed 64 bit value over this generator, which only has
+/// tables for short scalars.
+";
+
+const VECTOR_COMMIT_V_DOC: &str = "\
+/// The vector commitment generators bind each element of a board state vector. The i-th value
+/// is placed over the i-th generator.
+";
+
+/**
+ * Render a module exposing GENERATOR, Z and U for a single fixed base
+ *
+ * @param table - the generator and window tables to render, on a BoardCurve
+ * @param doc - doc comment placed on GENERATOR
+ * @param bytes - name of the constant hashed to the curve to derive the generator
+ * @return - rustfmt formatted module source
+ */
+pub fn render_fixed_base_module<C: BoardCurve>(
+    table: &FixedBaseTable<C>,
+    doc: &str,
+    bytes: &str,
+) -> String {
+    render_module(table, doc, bytes, &FULL_WIDTH)
+}
+
+/**
+ * Render a module exposing GENERATOR, Z_SHORT and U_SHORT for a short signed fixed base
+ *
+ * @param table - the generator and short window tables to render, on a BoardCurve
+ * @param doc - doc comment placed on GENERATOR
+ * @param bytes - name of the constant hashed to the curve to derive the generator
+ * @return - rustfmt formatted module source
+ */
+pub fn render_short_fixed_base_module<C: BoardCurve>(
+    table: &FixedBaseTable<C>,
+    doc: &str,
+    bytes: &str,
+) -> String {
+    render_module(table, doc, bytes, &SHORT)
+}
+
+fn render_module<C: BoardCurve>(
+    table: &FixedBaseTable<C>,
+    doc: &str,
+    bytes: &str,
+    windows: &WindowTables,
+) -> String {
+    let curve = C::NAME;
+    let WindowTables {
+        kind,
+        num_windows,
+        z,
+        u,
+    } = windows;
+    let (x, y) = coordinate_bytes(table.generator);
+    let mut out = String::new();
+    out.push_str(&format!(
+        "\
+use halo2_gadgets::ecc::chip::constants::{{H, {num_windows}}};
+use halo2_proofs::pasta::{{arithmetic::CurveAffine, group::ff::PrimeField, {curve}}};
+
+"
+    ));
+    out.push_str(doc);
+    push_line(&mut out, 0, "pub const GENERATOR: ([u8; 32], [u8; 32]) = (");
+    push_bytes(&mut out, 4, &x);
+    push_bytes(&mut out, 4, &y);
+    push_line(&mut out, 0, ");");
+    out.push('\n');
+    push_line(&mut out, 0, &format!("/// {kind} z-values for GENERATOR"));
+    push_line(
+        &mut out,
+        0,
+        &format!("pub const {z}: [u64; {num_windows}] = ["),
+    );
+    push_list(&mut out, 4, table.z.iter().map(u64::to_string));
+    push_line(&mut out, 0, "];");
+    out.push('\n');
+    push_line(&mut out, 0, &format!("// {kind} u-values for GENERATOR"));
+    push_line(
+        &mut out,
+        0,
+        &format!("pub static {u}: [[[u8; 32]; H]; {num_windows}] = ["),
+    );
+    push_windows(&mut out, 4, &table.u);
+    push_line(&mut out, 0, "];");
+    out.push_str(&format!(
+        "
+pub fn generator() -> {curve}::Affine {{
+    {curve}::Affine::from_xy(
+        {curve}::Base::from_repr(GENERATOR.0).unwrap(),
+        {curve}::Base::from_repr(GENERATOR.1).unwrap(),
+    )
+    .unwrap()
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::super::super::{{BOARD_COMMITMENT_PERSONALIZATION, {bytes}}};
+    use super::*;
+    use halo2_gadgets::ecc::chip::constants::{{test_lagrange_coeffs, test_zs_and_us}};
+    use halo2_proofs::pasta::{{
+        arithmetic::{{CurveAffine, CurveExt}},
+        group::Curve,
+        {curve},
+    }};
+
+    #[test]
+    fn generator() {{
+        let hasher = {curve}::Point::hash_to_curve(BOARD_COMMITMENT_PERSONALIZATION);
+        let point = hasher(&{bytes});
+        let coords = point.to_affine().coordinates().unwrap();
+
+        assert_eq!(*coords.x(), {curve}::Base::from_repr(GENERATOR.0).unwrap());
+        assert_eq!(*coords.y(), {curve}::Base::from_repr(GENERATOR.1).unwrap());
+    }}
+
+    #[test]
+    fn lagrange_coeffs() {{
+        let base = super::generator();
+        test_lagrange_coeffs(base, {num_windows});
+    }}
+
+    #[test]
+    fn z() {{
+        let base = super::generator();
+        test_zs_and_us(base, &{z}, &{u}, {num_windows});
+    }}
+}}
+"
+    ));
+    out
+}
+
+/**
+ * Render a module exposing GENERATORS, Z and U for the vector commitment fixed bases
+ *
+ * @param tables - the generators and window tables to render, one per vector element
+ * @return - rustfmt formatted module source
+ */
+pub fn render_vector_fixed_base_module(tables: &[FixedBaseTable]) -> String {
+    let mut out = String::new();
+    out.push_str(
+        "\
+use halo2_gadgets::ecc::chip::constants::{H, NUM_WINDOWS};
+use halo2_proofs::pasta::{arithmetic::CurveAffine, group::ff::PrimeField, pallas};
+
+use super::super::VECTOR_COMMITMENT_SIZE;
+
+",
+    );
+    out.push_str(VECTOR_COMMIT_V_DOC);
+    push_line(
+        &mut out,
+        0,
+        "pub const GENERATORS: [([u8; 32], [u8; 32]); VECTOR_COMMITMENT_SIZE] = [",
+    );
+    for table in tables {
+        let (x, y) = coordinate_bytes(table.generator);
+        push_line(&mut out, 4, "(");
+        push_bytes(&mut out, 8, &x);
+        push_bytes(&mut out, 8, &y);
+        push_line(&mut out, 4, "),");
+    }
+    push_line(&mut out, 0, "];");
+    out.push('\n');
+    push_line(
+        &mut out,
+        0,
+        "/// Full-width z-values for each of GENERATORS",
+    );
+    push_line(
+        &mut out,
+        0,
+        "pub const Z: [[u64; NUM_WINDOWS]; VECTOR_COMMITMENT_SIZE] = [",
+    );
+    for table in tables {
+        push_line(&mut out, 4, "[");
+        push_list(&mut out, 8, table.z.iter().map(u64::to_string));
+        push_line(&mut out, 4, "],");
+    }
+    push_line(&mut out, 0, "];");
+    out.push('\n');
+    push_line(
+        &mut out,
+        0,
+        "/// Full-width u-values for each of GENERATORS",
+    );
+    push_line(
+        &mut out,
+        0,
+        "pub static U: [[[[u8; 32]; H]; NUM_WINDOWS]; VECTOR_COMMITMENT_SIZE] = [",
+    );
+    for table in tables {
+        push_line(&mut out, 4, "[");
+        push_windows(&mut out, 8, &table.u);
+        push_line(&mut out, 4, "],");
+    }
+    push_line(&mut out, 0, "];");
+    out.push_str(
+        "
+pub fn generator(index: usize) -> pallas::Affine {
+    pallas::Affine::from_xy(
+        pallas::Base::from_repr(GENERATORS[index].0).unwrap(),
+        pallas::Base::from_repr(GENERATORS[index].1).unwrap(),
+    )
+    .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::{vector_commitment_v_bytes, BOARD_COMMITMENT_PERSONALIZATION};
+    use super::*;
+    use halo2_gadgets::ecc::chip::constants::{test_lagrange_coeffs, test_zs_and_us};
+    use halo2_proofs::pasta::{
+        arithmetic::{CurveAffine, CurveExt},
+        group::Curve,
+        pallas,
+    };
+
+    #[test]
+    fn generator() {
+        let hasher = pallas::Point::hash_to_curve(BOARD_COMMITMENT_PERSONALIZATION);
+        for (index, generator) in GENERATORS.iter().enumerate() {
+            let point = hasher(&vector_commitment_v_bytes(index));
+            let coords = point.to_affine().coordinates().unwrap();
+
+            assert_eq!(*coords.x(), pallas::Base::from_repr(generator.0).unwrap());
+            assert_eq!(*coords.y(), pallas::Base::from_repr(generator.1).unwrap());
+        }
+    }
+
+    #[test]
+    fn lagrange_coeffs() {
+        for index in 0..VECTOR_COMMITMENT_SIZE {
+            let base = super::generator(index);
+            test_lagrange_coeffs(base, NUM_WINDOWS);
+        }
+    }
+
+    #[test]
+    fn z() {
+        for (index, (z, u)) in Z.iter().zip(U.iter()).enumerate() {
+            let base = super::generator(index);
+            test_zs_and_us(base, z, u, NUM_WINDOWS);
+        }
+    }
+}
+",
+    );
+    out
+}
+
+// serialize the affine coordinates of a generator
+fn coordinate_bytes<C: CurveAffine>(generator: C) -> ([u8; 32], [u8; 32]) {
+    let coords = generator.coordinates().unwrap();
+    (
+        coords.x().to_repr().as_ref().try_into().unwrap(),
+        coords.y().to_repr().as_ref().try_into().unwrap(),
+    )
+}
+
+fn push_line(out: &mut String, indent: usize, line: &str) {
+    out.push_str(&" ".repeat(indent));
+    out.push_str(line);
+    out.push('\n');
+}
+
+// pack comma separated items into as few lines as fit within MAX_WIDTH, as rustfmt does
+fn push_list(out: &mut String, indent: usize, items: impl IntoIterator<Item = String>) {
+    let mut line = String::new();
+    for item in items {
+        if !line.is_empty() && indent + line.len() + item.len() + 3 >= MAX_WIDTH {
+            push_line(out, indent, &format!("{},", line));
+            line.clear();
+        }
+        if !line.is_empty() {
+            line.push_str(", ");
+        }
+        line.push_str(&item);
+    }
+    if !line.is_empty() {
+        push_line(out, indent, &format!("{},", line));
+    }
+}
+
+fn push_bytes(out: &mut String, indent: usize, bytes: &[u8; 32]) {
+    push_line(out, indent, "[");
+    push_list(out, indent + 4, bytes.iter().map(u8::to_string));
+    push_line(out, indent, "],");
+}
+
+fn push_windows(out: &mut String, indent: usize, u: &[[[u8; 32]; H]]) {
+    for window in u {
+        push_line(out, indent, "[");
+        for bytes in window {
+            push_bytes(out, indent + 4, bytes);
+        }
+        push_line(out, indent, "],");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::constants::fixed_bases::{
+            board_commit_q, board_commit_r, board_commit_v, vector_commit_v,
+        },
+    };
+
+    #[test]
+    fn tables_from_zs() {
+        let table =
+            FixedBaseTable::from_zs(board_commit_v::generator(), board_commit_v::Z.to_vec());
+        assert_eq!(table.unwrap().u, board_commit_v::U.to_vec());
+        let generator = board_commit_q::generator();
+        let table = FixedBaseTable::from_zs(generator, board_commit_q::Z_SHORT.to_vec());
+        assert_eq!(table.unwrap().u, board_commit_q::U_SHORT.to_vec());
+        // z-values for another generator are rejected
+        let table =
+            FixedBaseTable::from_zs(board_commit_r::generator(), board_commit_v::Z.to_vec());
+        assert!(table.is_none());
+    }
+
+    #[test]
+    fn render_board_commit_v() {
+        let table = FixedBaseTable {
+            generator: board_commit_v::generator(),
+            z: board_commit_v::Z.to_vec(),
+            u: board_commit_v::U.to_vec(),
+        };
+        assert_eq!(
+            render_fixed_base_module(&table, BOARD_COMMIT_V_DOC, "BOARD_COMMITMENT_V_BYTES"),
+            include_str!("../constants/fixed_bases/board_commit_v.rs")
+        );
+    }
+
+    #[test]
+    fn render_board_commit_r() {
+        let table = FixedBaseTable {
+            generator: board_commit_r::generator(),
+            z: board_commit_r::Z.to_vec(),
+            u: board_commit_r::U.to_vec(),
+        };
+        assert_eq!(
+            render_fixed_base_module(&table, BOARD_COMMIT_R_DOC, "BOARD_COMMITMENT_R_BYTES"),
+            include_str!("../constants/fixed_bases/board_commit_r.rs")
+        );
+    }
+
+    #[test]
+    fn render_board_commit_q() {
+        let table = FixedBaseTable {
+            generator: board_commit_q::generator(),
+            z: board_commit_q::Z_SHORT.to_vec(),
+            u: board_commit_q::U_SHORT.to_vec(),
+        };
+        assert_eq!(
+            render_short_fixed_base_module(&table, BOARD_COMMIT_Q_DOC, "BOARD_COMMITMENT_Q_BYTES"),
+            include_str!("../constants/fixed_bases/board_commit_q.rs")
+        );
+    }
+
+    #[test]
+    fn render_vector_commit_v() {
+        let tables = (0..VECTOR_COMMITMENT_SIZE)
+            .map(|index| FixedBaseTable {
+                generator: vector_commit_v::generator(index),
+                z: vector_commit_v::Z[index].to_vec(),
+                u: vector_commit_v::U[index].to_vec(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            render_vector_fixed_base_module(&tables),
+            include_str!("../constants/fixed_bases/vector_commit_v.rs")
+        );
+    }
+}
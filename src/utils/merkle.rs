@@ -0,0 +1,162 @@
+use {
+    super::commit::Commitment,
+    halo2_gadgets::poseidon::primitives::{self as poseidon, ConstantLength, P128Pow5T3},
+    halo2_proofs::pasta::pallas,
+};
+
+/// Leaf value filling the unused slots of a MerkleTree
+pub const EMPTY_LEAF: pallas::Base = pallas::Base::zero();
+
+/// Deepest supported tree, leaf positions are u32 so every level must be addressable by one bit
+pub const MAX_DEPTH: usize = u32::BITS as usize;
+
+/// Hash two child nodes into their parent, Poseidon(left, right)
+pub fn hash_nodes(left: pallas::Base, right: pallas::Base) -> pallas::Base {
+    poseidon::Hash::<_, P128Pow5T3, ConstantLength<2>, 3, 2>::init().hash([left, right])
+}
+
+/// A Poseidon Merkle tree of DEPTH levels over hashed commitments, padded with EMPTY_LEAF
+#[derive(Clone, Debug)]
+pub struct MerkleTree<const DEPTH: usize> {
+    // layers[0] holds the 2^DEPTH leaves and layers[DEPTH] holds the root
+    layers: Vec<Vec<pallas::Base>>,
+}
+
+impl<const DEPTH: usize> MerkleTree<DEPTH> {
+    /**
+     * Build a tree with the given leaves at positions 0..leaves.len()
+     * @dev every level is materialized, so DEPTH should stay small enough to hold 2^DEPTH leaves.
+     *      Panics if DEPTH exceeds MAX_DEPTH
+     *
+     * @param leaves - the leaf values, at most 2^DEPTH
+     * @return - the tree, or None if there are too many leaves
+     */
+    pub fn new(leaves: &[pallas::Base]) -> Option<Self> {
+        assert!(DEPTH <= MAX_DEPTH, "merkle tree depth exceeds MAX_DEPTH");
+        if leaves.len() > 1 << DEPTH {
+            return None;
+        }
+        let mut layer = leaves.to_vec();
+        layer.resize(1 << DEPTH, EMPTY_LEAF);
+        let mut layers = vec![layer];
+        for level in 0..DEPTH {
+            let parents = layers[level]
+                .chunks(2)
+                .map(|pair| hash_nodes(pair[0], pair[1]))
+                .collect();
+            layers.push(parents);
+        }
+        Some(MerkleTree { layers })
+    }
+
+    /**
+     * Build a tree whose leaves are the Poseidon hashes of the given commitments
+     *
+     * @param commitments - the committed leaves, at most 2^DEPTH
+     * @return - the tree, or None if there are too many commitments or one is the identity
+     */
+    pub fn from_commitments(commitments: &[Commitment]) -> Option<Self> {
+        let leaves = commitments
+            .iter()
+            .map(Commitment::hash)
+            .collect::<Option<Vec<_>>>()?;
+        Self::new(&leaves)
+    }
+
+    /// Returns the root of the tree
+    pub fn root(&self) -> pallas::Base {
+        self.layers[DEPTH][0]
+    }
+
+    /// Returns the leaf at position, or None if position is outside the tree
+    pub fn leaf(&self, position: usize) -> Option<pallas::Base> {
+        self.layers[0].get(position).copied()
+    }
+
+    /**
+     * Generate the authentication path for a leaf
+     *
+     * @param position - the position of the leaf
+     * @return - the siblings from the leaf up to the root, or None if position is outside the tree
+     */
+    pub fn path(&self, position: usize) -> Option<MerklePath<DEPTH>> {
+        if position >= 1 << DEPTH {
+            return None;
+        }
+        let mut siblings = [EMPTY_LEAF; DEPTH];
+        for (level, sibling) in siblings.iter_mut().enumerate() {
+            *sibling = self.layers[level][(position >> level) ^ 1];
+        }
+        Some(MerklePath {
+            position: position as u32,
+            siblings,
+        })
+    }
+}
+
+/// Authentication path of a leaf, the sibling at each level from the leaves up
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MerklePath<const DEPTH: usize> {
+    /// position of the leaf, bit i is set when the node at level i is a right child
+    pub position: u32,
+    pub siblings: [pallas::Base; DEPTH],
+}
+
+impl<const DEPTH: usize> MerklePath<DEPTH> {
+    /**
+     * Recompute the root the path leads to from a leaf
+     * @dev panics if DEPTH exceeds MAX_DEPTH
+     *
+     * @param leaf - the leaf value at the path's position
+     * @return - the root of a tree containing leaf at position
+     */
+    pub fn root(&self, leaf: pallas::Base) -> pallas::Base {
+        assert!(DEPTH <= MAX_DEPTH, "merkle path depth exceeds MAX_DEPTH");
+        self.siblings
+            .iter()
+            .enumerate()
+            .fold(leaf, |node, (level, sibling)| {
+                match (self.position >> level) & 1 == 1 {
+                    true => hash_nodes(*sibling, node),
+                    false => hash_nodes(node, *sibling),
+                }
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::utils::commit::{Opening, Trapdoor},
+        rand::rngs::OsRng,
+    };
+
+    #[test]
+    fn test_paths() {
+        let commitments = (0..5u64)
+            .map(|value| Opening::new(pallas::Base::from(value), Trapdoor::random(OsRng)).commit())
+            .collect::<Vec<_>>();
+        let tree = MerkleTree::<3>::from_commitments(&commitments).unwrap();
+        // every leaf, including padding, authenticates to the root
+        for position in 0..8 {
+            let path = tree.path(position).unwrap();
+            assert_eq!(path.root(tree.leaf(position).unwrap()), tree.root());
+        }
+        assert_eq!(tree.leaf(7), Some(EMPTY_LEAF));
+        assert!(tree.path(8).is_none());
+        // a leaf does not authenticate at another position
+        let mut path = tree.path(1).unwrap();
+        assert_ne!(path.root(commitments[0].hash().unwrap()), tree.root());
+        path.position = 0;
+        assert_ne!(path.root(commitments[1].hash().unwrap()), tree.root());
+        // too many leaves
+        assert!(MerkleTree::<1>::from_commitments(&commitments).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "merkle tree depth exceeds MAX_DEPTH")]
+    fn oversized_depth() {
+        MerkleTree::<{ MAX_DEPTH + 1 }>::new(&[]);
+    }
+}
@@ -0,0 +1,139 @@
+use {
+    super::commit::{Opening, Trapdoor},
+    halo2_gadgets::poseidon::primitives::{self as poseidon, ConstantLength, P128Pow5T3},
+    halo2_proofs::pasta::{group::ff::PrimeField, pallas},
+};
+
+/// Number of message bytes packed into each field element, 31 bytes always fit below the modulus
+pub const BYTES_PER_ELEMENT: usize = 31;
+
+// marks the end of the message bytes so trailing zero bytes survive encoding
+const TERMINATOR: u8 = 0x01;
+
+/**
+ * Encode bytes as field elements for hash_message
+ * @dev the bytes are followed by a 0x01 terminator, zero padded to a multiple of
+ *      BYTES_PER_ELEMENT and packed little endian, so distinct byte strings never share an encoding
+ *
+ * @param bytes - the message bytes
+ * @return - ceil((bytes.len() + 1) / BYTES_PER_ELEMENT) field elements
+ */
+pub fn encode_bytes(bytes: &[u8]) -> Vec<pallas::Base> {
+    let mut bytes = bytes.to_vec();
+    bytes.push(TERMINATOR);
+    bytes
+        .chunks(BYTES_PER_ELEMENT)
+        .map(|chunk| {
+            let mut repr = [0u8; 32];
+            repr[..chunk.len()].copy_from_slice(chunk);
+            pallas::Base::from_repr(repr).unwrap()
+        })
+        .collect()
+}
+
+/**
+ * Decode field elements produced by encode_bytes
+ *
+ * @param elements - the encoded message
+ * @return - the message bytes, or None if elements is not a canonical encoding
+ */
+pub fn decode_bytes(elements: &[pallas::Base]) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(elements.len() * BYTES_PER_ELEMENT);
+    for element in elements {
+        let repr = element.to_repr();
+        if repr[BYTES_PER_ELEMENT] != 0 {
+            return None;
+        }
+        bytes.extend_from_slice(&repr[..BYTES_PER_ELEMENT]);
+    }
+    // strip the zero padding and the terminator, which must sit in the last element
+    let end = bytes.iter().rposition(|byte| *byte != 0)?;
+    if bytes[end] != TERMINATOR || bytes.len() - end > BYTES_PER_ELEMENT {
+        return None;
+    }
+    bytes.truncate(end);
+    Some(bytes)
+}
+
+/**
+ * Hash a message of up to MAX_LEN field elements into a single committable value
+ * @dev the message is zero padded to MAX_LEN elements and hashed with Poseidon, then the digest
+ *      is hashed with the message length so messages differing only by trailing zeros differ.
+ *      MAX_LEN must be at least 1
+ *
+ * @param elements - the message
+ * @return - Poseidon(length, Poseidon(padded message)), or None if the message is too long
+ */
+pub fn hash_message<const MAX_LEN: usize>(elements: &[pallas::Base]) -> Option<pallas::Base> {
+    if elements.len() > MAX_LEN {
+        return None;
+    }
+    let mut padded = [pallas::Base::zero(); MAX_LEN];
+    padded[..elements.len()].copy_from_slice(elements);
+    let digest =
+        poseidon::Hash::<_, P128Pow5T3, ConstantLength<MAX_LEN>, 3, 2>::init().hash(padded);
+    let length = pallas::Base::from(elements.len() as u64);
+    Some(poseidon::Hash::<_, P128Pow5T3, ConstantLength<2>, 3, 2>::init().hash([length, digest]))
+}
+
+/**
+ * Open a pedersen commitment to a message of up to MAX_LEN field elements
+ *
+ * @param elements - the message
+ * @param trapdoor - the blinding factor of the commitment
+ * @return - the opening to hash_message(elements), or None if the message is too long
+ */
+pub fn commit_message<const MAX_LEN: usize>(
+    elements: &[pallas::Base],
+    trapdoor: Trapdoor,
+) -> Option<Opening> {
+    hash_message::<MAX_LEN>(elements).map(|digest| Opening::new(digest, trapdoor))
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, rand::rngs::OsRng};
+
+    #[test]
+    fn byte_encoding() {
+        for length in [0, 1, 30, 31, 32, 61, 62, 100] {
+            let bytes = (0..length).map(|i| i as u8).collect::<Vec<_>>();
+            let elements = encode_bytes(&bytes);
+            assert_eq!(elements.len(), length / BYTES_PER_ELEMENT + 1);
+            assert_eq!(decode_bytes(&elements), Some(bytes));
+        }
+        // trailing zero bytes are part of the message
+        assert_ne!(encode_bytes(b"a"), encode_bytes(b"a\0"));
+        assert_eq!(
+            decode_bytes(&encode_bytes(b"a\0\0")),
+            Some(b"a\0\0".to_vec())
+        );
+        // encodings that encode_bytes does not produce are rejected
+        let mut padded = encode_bytes(b"a");
+        padded.push(pallas::Base::zero());
+        assert_eq!(decode_bytes(&padded), None);
+        assert_eq!(decode_bytes(&[]), None);
+        assert_eq!(decode_bytes(&[pallas::Base::from(2)]), None);
+        assert_eq!(decode_bytes(&[-pallas::Base::one()]), None);
+    }
+
+    #[test]
+    fn message_hash() {
+        let message = encode_bytes(b"battlezips");
+        let digest = hash_message::<4>(&message).unwrap();
+        // the digest depends on the message, its length and the maximum length
+        assert_ne!(hash_message::<4>(&encode_bytes(b"battlezip")), Some(digest));
+        let mut padded = message.clone();
+        padded.push(pallas::Base::zero());
+        assert_ne!(hash_message::<4>(&padded), Some(digest));
+        assert_ne!(hash_message::<5>(&message), Some(digest));
+        assert_eq!(hash_message::<0>(&message), None);
+        // the commitment opens to the digest
+        let opening = commit_message::<4>(&message, Trapdoor::random(OsRng)).unwrap();
+        assert_eq!(opening.value, digest);
+        assert_eq!(
+            commit_message::<4>(&[pallas::Base::zero(); 5], opening.trapdoor),
+            None
+        );
+    }
+}